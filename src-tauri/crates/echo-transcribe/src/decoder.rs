@@ -16,6 +16,7 @@ use symphonia::core::{
 /// Supported audio formats (including video files with audio tracks)
 pub enum AudioFormat {
     Wav,
+    Flac,
     Mp3,
     M4a,
     Ogg,
@@ -34,6 +35,7 @@ impl AudioFormat {
 
         match extension.as_str() {
             "wav" | "wave" => AudioFormat::Wav,
+            "flac" => AudioFormat::Flac,
             "mp3" => AudioFormat::Mp3,
             "m4a" | "aac" => AudioFormat::M4a,
             "ogg" | "oga" => AudioFormat::Ogg,
@@ -46,13 +48,16 @@ impl AudioFormat {
 /// Target sample rate for transcription (Whisper requires 16kHz)
 const TARGET_SAMPLE_RATE: u32 = 16000;
 
+/// Input frames per resampler call when decoding files
+const RESAMPLE_CHUNK_SIZE: usize = 4096;
+
 /// Decode an audio file to 16kHz mono f32 samples
 pub fn decode_audio_file<P: AsRef<Path>>(file_path: P) -> Result<Vec<f32>> {
     let format = AudioFormat::from_path(&file_path);
 
     match format {
         AudioFormat::Wav => decode_wav_file(&file_path),
-        AudioFormat::Mp3 | AudioFormat::M4a | AudioFormat::Ogg => {
+        AudioFormat::Flac | AudioFormat::Mp3 | AudioFormat::M4a | AudioFormat::Ogg => {
             decode_with_symphonia(&file_path)
         }
         AudioFormat::Video => {
@@ -60,7 +65,7 @@ pub fn decode_audio_file<P: AsRef<Path>>(file_path: P) -> Result<Vec<f32>> {
             decode_video_with_ffmpeg(&file_path)
         }
         AudioFormat::Unsupported => Err(anyhow::anyhow!(
-            "Unsupported file format. Please provide an audio file (wav, mp3, m4a, ogg) or a video file with an audio track (mp4, mov, mkv, webm)."
+            "Unsupported file format. Please provide an audio file (wav, flac, mp3, m4a, ogg) or a video file with an audio track (mp4, mov, mkv, webm)."
         )),
     }
 }
@@ -128,6 +133,11 @@ fn pcm_s16le_to_f32(bytes: &[u8]) -> Vec<f32> {
         .collect()
 }
 
+/// Whether FFmpeg is installed, so video files can be transcribed.
+pub fn ffmpeg_available() -> bool {
+    find_ffmpeg().is_ok()
+}
+
 /// Find FFmpeg executable in common locations
 fn find_ffmpeg() -> Result<String> {
     // Check if ffmpeg is in PATH
@@ -426,75 +436,46 @@ fn stereo_to_mono(samples: Vec<f32>, channels: usize) -> Vec<f32> {
 
 /// Resample audio from one sample rate to another using rubato
 fn resample_audio(samples: Vec<f32>, from_rate: u32, to_rate: u32) -> Result<Vec<f32>> {
-    if from_rate == to_rate {
+    if from_rate == to_rate || samples.is_empty() {
         return Ok(samples);
     }
 
-    // Calculate resampling parameters
-    let ratio = to_rate as f64 / from_rate as f64;
+    use rubato::{FftFixedIn, Resampler};
 
-    // Use rubato's FftFixedInOut for faster, high-quality resampling
-    use rubato::{FftFixedInOut, Resampler};
-
-    // Calculate chunk size for processing
-    let chunk_size = samples.len().min(4096);
-    let expected_output = (samples.len() as f64 * ratio).ceil() as usize;
-
-    // Create resampler with appropriate parameters
-    let mut resampler = FftFixedInOut::<f64>::new(
+    // Mono, fixed-size input chunks; the last partial chunk is zero-padded
+    let mut resampler = FftFixedIn::<f32>::new(
         from_rate as usize,
         to_rate as usize,
-        chunk_size,
-        2, // num_channels (we're working with mono)
+        RESAMPLE_CHUNK_SIZE,
+        1,
+        1,
     )?;
-
-    // Resample in chunks
-    let mut resampled = Vec::with_capacity(expected_output);
-
-    // Convert to f64 for resampler
-    let samples_f64: Vec<f64> = samples.iter().map(|&s| s as f64).collect();
-
-    // Process in chunks
-    let mut input_offset = 0;
-    while input_offset < samples_f64.len() {
-        let end = (input_offset + chunk_size).min(samples_f64.len());
-        let chunk = &samples_f64[input_offset..end];
-
-        if chunk.is_empty() {
-            break;
-        }
-
-        // Process chunk - we need to provide input as a slice of slices (channels)
-        let input_buffer = vec![chunk];
-        match resampler.process(&input_buffer, None) {
-            Ok(output) => {
-                // output is Vec<Vec<f64>>, one per channel
-                for channel_output in output {
-                    for sample in channel_output {
-                        resampled.push(sample as f32);
-                    }
-                }
-            }
-            Err(e) => {
-                debug!("Resampling chunk failed: {}", e);
-                break;
-            }
-        }
-
-        input_offset = end;
+    let delay = resampler.output_delay();
+    let expected_output =
+        (samples.len() as f64 * to_rate as f64 / from_rate as f64).round() as usize;
+    let mut resampled = Vec::with_capacity(expected_output + delay);
+
+    let mut chunks = samples.chunks_exact(resampler.input_frames_next());
+    for chunk in &mut chunks {
+        let output = resampler.process(&[chunk], None)?;
+        resampled.extend_from_slice(&output[0]);
+    }
+    let remainder = chunks.remainder();
+    if !remainder.is_empty() {
+        let output = resampler.process_partial(Some(&[remainder][..]), None)?;
+        resampled.extend_from_slice(&output[0]);
     }
 
-    // Process any remaining samples in the resampler's internal buffer
-    while let Ok(output) = resampler.process(&[&[]], None) {
-        if output.is_empty() || output[0].is_empty() {
+    // Flush the samples still held back by the resampler's delay
+    while resampled.len() < expected_output + delay {
+        let output = resampler.process_partial(None::<&[&[f32]]>, None)?;
+        if output[0].is_empty() {
             break;
         }
-        for channel_output in output {
-            for sample in channel_output {
-                resampled.push(sample as f32);
-            }
-        }
+        resampled.extend_from_slice(&output[0]);
     }
+    resampled.drain(..delay.min(resampled.len()));
+    resampled.truncate(expected_output);
 
     debug!(
         "Resampled audio: {} samples at {}Hz -> {} samples at {}Hz",
@@ -0,0 +1,16 @@
+//! Echo's offline transcription, without the app around it.
+//!
+//! [`transcriber`] loads a local Whisper or Parakeet model and transcribes
+//! samples or files with it. The modules it builds on are public too:
+//! [`decoder`] turns audio and video files into 16 kHz mono samples, [`vad`]
+//! detects and trims silence, and [`text`] applies custom-word corrections.
+//! With the `plugin` feature (on by default), [`plugin`] exposes the
+//! transcriber to Tauri apps as the `echo-transcription` plugin.
+
+pub mod constants;
+pub mod decoder;
+#[cfg(feature = "plugin")]
+pub mod plugin;
+pub mod text;
+pub mod transcriber;
+pub mod vad;
@@ -0,0 +1,109 @@
+//! Echo's transcription as a Tauri plugin, for apps that want speech-to-text
+//! without running Echo itself.
+//!
+//! The host app registers the plugin:
+//!
+//! ```ignore
+//! tauri::Builder::default()
+//!     .plugin(echo_transcribe::plugin::init())
+//! ```
+//!
+//! and allows its commands in `build.rs`:
+//!
+//! ```ignore
+//! tauri_build::try_build(tauri_build::Attributes::new().plugin(
+//!     "echo-transcription",
+//!     tauri_build::InlinedPlugin::new().commands(&["load_model", "transcribe_file", "unload_model"]),
+//! ))
+//! ```
+//!
+//! The frontend then loads a model once and transcribes files with it:
+//!
+//! ```js
+//! await invoke("plugin:echo-transcription|load_model", { path, engine: "whisper" });
+//! const { text, segments } = await invoke("plugin:echo-transcription|transcribe_file", {
+//!   path: "/path/to/meeting.mp3",
+//!   options: { language: "en" },
+//! });
+//! ```
+//!
+//! Rust code that doesn't need the IPC layer can use [`crate::transcriber`]
+//! directly.
+
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+use tauri::plugin::{Builder, TauriPlugin};
+use tauri::{Manager, Runtime, State};
+
+use crate::transcriber::{EngineKind, InferenceOptions, Transcriber, Transcript};
+
+pub const PLUGIN_NAME: &str = "echo-transcription";
+
+/// The model loaded through the plugin, shared by all windows.
+#[derive(Default)]
+pub struct PluginState {
+    transcriber: Arc<Mutex<Option<Transcriber>>>,
+}
+
+pub fn init<R: Runtime>() -> TauriPlugin<R> {
+    Builder::new(PLUGIN_NAME)
+        .invoke_handler(tauri::generate_handler![
+            load_model,
+            transcribe_file,
+            unload_model
+        ])
+        .setup(|app, _api| {
+            app.manage(PluginState::default());
+            Ok(())
+        })
+        .build()
+}
+
+/// Load the model at `path`, replacing any loaded one. `vad_path` points to a
+/// Silero VAD model to trim silence with before inference.
+#[tauri::command]
+async fn load_model(
+    state: State<'_, PluginState>,
+    path: PathBuf,
+    engine: EngineKind,
+    vad_path: Option<PathBuf>,
+) -> Result<(), String> {
+    let slot = Arc::clone(&state.transcriber);
+    tauri::async_runtime::spawn_blocking(move || {
+        let mut transcriber = Transcriber::load(engine, &path)?;
+        if let Some(vad_path) = vad_path {
+            transcriber = transcriber.with_vad_trim(&vad_path)?;
+        }
+        *slot.lock().unwrap() = Some(transcriber);
+        anyhow::Ok(())
+    })
+    .await
+    .map_err(|e| e.to_string())?
+    .map_err(|e| e.to_string())
+}
+
+/// Transcribe an audio or video file with the loaded model.
+#[tauri::command]
+async fn transcribe_file(
+    state: State<'_, PluginState>,
+    path: PathBuf,
+    options: Option<InferenceOptions>,
+) -> Result<Transcript, String> {
+    let slot = Arc::clone(&state.transcriber);
+    tauri::async_runtime::spawn_blocking(move || {
+        let mut guard = slot.lock().unwrap();
+        let transcriber = guard
+            .as_mut()
+            .ok_or_else(|| anyhow::anyhow!("No model loaded; call load_model first"))?;
+        transcriber.transcribe_file(&path, &options.unwrap_or_default())
+    })
+    .await
+    .map_err(|e| e.to_string())?
+    .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+fn unload_model(state: State<'_, PluginState>) {
+    *state.transcriber.lock().unwrap() = None;
+}
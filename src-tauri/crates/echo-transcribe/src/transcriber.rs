@@ -0,0 +1,317 @@
+//! Speech-to-text without the rest of the app.
+//!
+//! [`Transcriber`] loads a Whisper or Parakeet model from disk and turns
+//! 16 kHz mono samples into a [`Transcript`] with timestamps, optionally
+//! trimming silence first. It doesn't touch Echo's settings, model catalog or
+//! windows, so other apps can embed it directly or through the Tauri plugin.
+//!
+//! ```no_run
+//! use echo_transcribe::transcriber::{EngineKind, InferenceOptions, Transcriber};
+//!
+//! let mut transcriber = Transcriber::load(EngineKind::Whisper, "ggml-base.en.bin".as_ref())?;
+//! let transcript =
+//!     transcriber.transcribe_file("meeting.mp3".as_ref(), &InferenceOptions::default())?;
+//! for segment in &transcript.segments {
+//!     println!("[{} ms] {}", segment.start_ms, segment.text);
+//! }
+//! # anyhow::Ok(())
+//! ```
+
+use std::path::Path;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use transcribe_rs::{
+    engines::{
+        parakeet::{
+            ParakeetEngine, ParakeetInferenceParams, ParakeetModelParams, TimestampGranularity,
+        },
+        whisper::{WhisperEngine, WhisperInferenceParams},
+    },
+    TranscriptionEngine, TranscriptionResult,
+};
+
+use crate::constants::WHISPER_SAMPLE_RATE;
+use crate::decoder::decode_audio_file;
+use crate::text::apply_custom_words;
+use crate::vad::{trim_silence_mapped, SileroVad, Trimmed};
+
+/// Speech probability above which a frame counts as speech when trimming.
+pub const VAD_TRIM_THRESHOLD: f32 = 0.3;
+/// Frames (30 ms each) of audio kept around speech when trimming.
+pub const VAD_TRIM_PADDING_FRAMES: usize = 10;
+
+/// A piece of a transcript with its position in the audio: a word where the
+/// engine reports words (Parakeet), otherwise a segment (Whisper).
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct TimedSegment {
+    pub start_ms: i64,
+    pub end_ms: i64,
+    pub text: String,
+    /// Estimated likelihood, 0.0 to 1.0, that the text is right. The engines
+    /// don't report one, so it's `None` unless the caller scores segments.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f32>,
+}
+
+/// Transcribed text together with its timestamps.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct Transcript {
+    pub text: String,
+    pub segments: Vec<TimedSegment>,
+    /// Spoken language as an ISO 639-1 code, when known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum EngineKind {
+    /// A GGML model file.
+    Whisper,
+    /// A directory of ONNX files.
+    Parakeet,
+}
+
+/// Per-call inference settings.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct InferenceOptions {
+    /// Spoken language as an ISO 639-1 code; `None` detects it. Whisper only.
+    pub language: Option<String>,
+    /// Translate the speech to English. Whisper only.
+    pub translate: bool,
+    /// Text that biases Whisper towards the expected vocabulary.
+    pub initial_prompt: Option<String>,
+    /// Words that close misspellings in the transcript are corrected to.
+    pub custom_words: Vec<String>,
+    pub word_correction_threshold: f64,
+}
+
+impl Default for InferenceOptions {
+    fn default() -> Self {
+        Self {
+            language: None,
+            translate: false,
+            initial_prompt: None,
+            custom_words: Vec::new(),
+            word_correction_threshold: 0.18,
+        }
+    }
+}
+
+/// A loaded local model.
+pub enum LocalEngine {
+    Whisper(WhisperEngine),
+    Parakeet(ParakeetEngine),
+}
+
+impl LocalEngine {
+    pub fn load(kind: EngineKind, model_path: &Path) -> Result<Self> {
+        match kind {
+            EngineKind::Whisper => {
+                let mut engine = WhisperEngine::new();
+                engine.load_model(model_path).map_err(|e| {
+                    anyhow::anyhow!("Failed to load whisper model {:?}: {}", model_path, e)
+                })?;
+                Ok(LocalEngine::Whisper(engine))
+            }
+            EngineKind::Parakeet => {
+                let mut engine = ParakeetEngine::new();
+                engine
+                    .load_model_with_params(model_path, ParakeetModelParams::int8())
+                    .map_err(|e| {
+                        anyhow::anyhow!("Failed to load parakeet model {:?}: {}", model_path, e)
+                    })?;
+                Ok(LocalEngine::Parakeet(engine))
+            }
+        }
+    }
+
+    pub fn unload(&mut self) {
+        match self {
+            LocalEngine::Whisper(whisper) => whisper.unload_model(),
+            LocalEngine::Parakeet(parakeet) => parakeet.unload_model(),
+        }
+    }
+
+    /// Whether the engine writes its own punctuation and capitals.
+    pub fn punctuates(&self) -> bool {
+        matches!(self, LocalEngine::Whisper(_))
+    }
+
+    /// Run inference on 16 kHz mono `audio`, returning the engine's raw result.
+    pub fn infer(
+        &mut self,
+        audio: Vec<f32>,
+        options: &InferenceOptions,
+    ) -> Result<TranscriptionResult> {
+        match self {
+            LocalEngine::Whisper(whisper_engine) => {
+                let params = WhisperInferenceParams {
+                    language: options.language.clone(),
+                    translate: options.translate,
+                    initial_prompt: options.initial_prompt.clone(),
+                    ..Default::default()
+                };
+
+                whisper_engine
+                    .transcribe_samples(audio, Some(params))
+                    .map_err(|e| anyhow::anyhow!("Whisper transcription failed: {}", e))
+            }
+            LocalEngine::Parakeet(parakeet_engine) => {
+                let params = ParakeetInferenceParams {
+                    timestamp_granularity: TimestampGranularity::Word,
+                    ..Default::default()
+                };
+
+                parakeet_engine
+                    .transcribe_samples(audio, Some(params))
+                    .map_err(|e| anyhow::anyhow!("Parakeet transcription failed: {}", e))
+            }
+        }
+    }
+}
+
+/// Turn an engine result into a [`Transcript`]: apply the custom words and,
+/// when the audio was trimmed before inference, map the timestamps back onto
+/// the untrimmed audio.
+pub fn build_transcript(
+    result: TranscriptionResult,
+    trimmed: Option<&Trimmed>,
+    options: &InferenceOptions,
+) -> Transcript {
+    let correct = |text: &str| {
+        apply_custom_words(
+            text,
+            &options.custom_words,
+            options.word_correction_threshold,
+        )
+    };
+    let to_ms = |secs: f32| {
+        let pos = (secs.max(0.0) * WHISPER_SAMPLE_RATE as f32) as usize;
+        let pos = trimmed.map_or(pos, |trimmed| trimmed.original_position(pos));
+        pos as i64 * 1000 / WHISPER_SAMPLE_RATE as i64
+    };
+    let segments = result
+        .segments
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|segment| !segment.text.trim().is_empty())
+        .map(|segment| TimedSegment {
+            start_ms: to_ms(segment.start),
+            end_ms: to_ms(segment.end),
+            text: correct(segment.text.trim()),
+            confidence: None,
+        })
+        .collect();
+
+    Transcript {
+        text: correct(&result.text).trim().to_string(),
+        segments,
+        language: options.language.clone(),
+    }
+}
+
+/// A local model plus optional silence trimming.
+pub struct Transcriber {
+    engine: LocalEngine,
+    trim_vad: Option<SileroVad>,
+}
+
+impl Transcriber {
+    pub fn load(kind: EngineKind, model_path: &Path) -> Result<Self> {
+        Ok(Self {
+            engine: LocalEngine::load(kind, model_path)?,
+            trim_vad: None,
+        })
+    }
+
+    /// Trim silence with the Silero VAD model at `vad_path` before inference.
+    /// Timestamps still refer to the untrimmed audio.
+    pub fn with_vad_trim(mut self, vad_path: &Path) -> Result<Self> {
+        self.trim_vad = Some(SileroVad::new(vad_path, VAD_TRIM_THRESHOLD)?);
+        Ok(self)
+    }
+
+    /// Transcribe 16 kHz mono `samples`.
+    pub fn transcribe(
+        &mut self,
+        samples: Vec<f32>,
+        options: &InferenceOptions,
+    ) -> Result<Transcript> {
+        let mut trimmed = match self.trim_vad.as_mut() {
+            Some(vad) => Some(trim_silence_mapped(vad, &samples, VAD_TRIM_PADDING_FRAMES)?),
+            None => None,
+        };
+        let audio = match trimmed.as_mut() {
+            Some(trimmed) => std::mem::take(&mut trimmed.samples),
+            None => samples,
+        };
+        if audio.is_empty() {
+            return Ok(Transcript::default());
+        }
+
+        let result = self.engine.infer(audio, options)?;
+        Ok(build_transcript(result, trimmed.as_ref(), options))
+    }
+
+    /// Decode an audio or video file and transcribe it.
+    pub fn transcribe_file(
+        &mut self,
+        path: &Path,
+        options: &InferenceOptions,
+    ) -> Result<Transcript> {
+        let samples = decode_audio_file(path)?;
+        self.transcribe(samples, options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use transcribe_rs::TranscriptionSegment;
+
+    #[test]
+    fn maps_timestamps_onto_untrimmed_audio() {
+        let result = TranscriptionResult {
+            text: " hello world ".to_string(),
+            segments: Some(vec![
+                TranscriptionSegment {
+                    start: 0.0,
+                    end: 0.5,
+                    text: " hello".to_string(),
+                },
+                TranscriptionSegment {
+                    start: 0.5,
+                    end: 1.0,
+                    text: " ".to_string(),
+                },
+            ]),
+        };
+        // One second of speech kept from 2s into the recording
+        let trimmed = Trimmed {
+            samples: Vec::new(),
+            kept: vec![32000..48000],
+        };
+        let transcript = build_transcript(result, Some(&trimmed), &InferenceOptions::default());
+        assert_eq!(transcript.text, "hello world");
+        assert_eq!(
+            transcript.segments,
+            [TimedSegment {
+                start_ms: 2000,
+                end_ms: 2500,
+                text: "hello".to_string(),
+                confidence: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn options_default_missing_fields() {
+        let options: InferenceOptions = serde_json::from_str(r#"{"language":"de"}"#).unwrap();
+        assert_eq!(options.language.as_deref(), Some("de"));
+        assert!(!options.translate);
+        assert_eq!(options.word_correction_threshold, 0.18);
+    }
+}
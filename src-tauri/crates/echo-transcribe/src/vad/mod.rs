@@ -27,6 +27,8 @@ pub trait VoiceActivityDetector: Send + Sync {
 
 mod silero;
 mod smoothed;
+mod trim;
 
 pub use silero::SileroVad;
 pub use smoothed::SmoothedVad;
+pub use trim::{trim_silence, trim_silence_mapped, Trimmed};
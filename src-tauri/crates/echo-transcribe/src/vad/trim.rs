@@ -0,0 +1,176 @@
+use std::ops::Range;
+
+use anyhow::Result;
+
+use super::VoiceActivityDetector;
+use crate::constants;
+
+/// Frame length the detectors expect: 30 ms at 16 kHz.
+const FRAME_SAMPLES: usize = (constants::WHISPER_SAMPLE_RATE * 30 / 1000) as usize;
+
+/// Strip leading, trailing and internal silence from 16 kHz mono `samples`
+/// before inference.
+///
+/// Every speech frame keeps `padding_frames` of audio on either side, so word
+/// edges survive and long pauses shrink to at most `2 * padding_frames`.
+/// Returns an empty buffer when no speech is detected at all.
+pub fn trim_silence(
+    vad: &mut dyn VoiceActivityDetector,
+    samples: &[f32],
+    padding_frames: usize,
+) -> Result<Vec<f32>> {
+    Ok(trim_silence_mapped(vad, samples, padding_frames)?.samples)
+}
+
+/// Trimmed audio along with the input ranges it was cut from.
+pub struct Trimmed {
+    pub samples: Vec<f32>,
+    /// Sample ranges of the input that were kept, in order.
+    pub kept: Vec<Range<usize>>,
+}
+
+impl Trimmed {
+    /// Map a sample position in the trimmed audio back to the input, so
+    /// timestamps from inference line up with the original recording.
+    pub fn original_position(&self, trimmed_pos: usize) -> usize {
+        let mut offset = 0;
+        for range in &self.kept {
+            if trimmed_pos < offset + range.len() {
+                return range.start + trimmed_pos - offset;
+            }
+            offset += range.len();
+        }
+        // Past the end (e.g. an end timestamp rounded up): clamp to the last kept sample
+        self.kept.last().map_or(trimmed_pos, |range| range.end)
+    }
+}
+
+/// Like [`trim_silence`], but also reports which parts of the input were kept.
+pub fn trim_silence_mapped(
+    vad: &mut dyn VoiceActivityDetector,
+    samples: &[f32],
+    padding_frames: usize,
+) -> Result<Trimmed> {
+    vad.reset();
+
+    let frame_count = samples.len().div_ceil(FRAME_SAMPLES);
+    let mut speech = Vec::with_capacity(frame_count);
+    let mut padded = [0.0f32; FRAME_SAMPLES];
+    for chunk in samples.chunks(FRAME_SAMPLES) {
+        // The last frame is zero-padded to the length the detector expects
+        let frame = if chunk.len() == FRAME_SAMPLES {
+            chunk
+        } else {
+            padded[..chunk.len()].copy_from_slice(chunk);
+            padded[chunk.len()..].fill(0.0);
+            &padded[..]
+        };
+        speech.push(vad.is_voice(frame)?);
+    }
+    vad.reset();
+
+    let mut keep = vec![false; frame_count];
+    for i in (0..frame_count).filter(|&i| speech[i]) {
+        let start = i.saturating_sub(padding_frames);
+        let end = (i + padding_frames + 1).min(frame_count);
+        keep[start..end].fill(true);
+    }
+
+    let mut trimmed = Vec::with_capacity(samples.len());
+    let mut kept: Vec<Range<usize>> = Vec::new();
+    for (i, (chunk, _)) in samples
+        .chunks(FRAME_SAMPLES)
+        .zip(keep)
+        .enumerate()
+        .filter(|(_, (_, keep))| *keep)
+    {
+        trimmed.extend_from_slice(chunk);
+        let start = i * FRAME_SAMPLES;
+        match kept.last_mut() {
+            Some(last) if last.end == start => last.end = start + chunk.len(),
+            _ => kept.push(start..start + chunk.len()),
+        }
+    }
+    Ok(Trimmed {
+        samples: trimmed,
+        kept,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vad::VadFrame;
+
+    /// Calls anything louder than a fixed level speech.
+    struct EnergyVad;
+
+    impl VoiceActivityDetector for EnergyVad {
+        fn push_frame<'a>(&'a mut self, frame: &'a [f32]) -> Result<VadFrame<'a>> {
+            assert_eq!(frame.len(), FRAME_SAMPLES);
+            let peak = frame.iter().fold(0.0f32, |max, s| max.max(s.abs()));
+            Ok(if peak > 0.05 {
+                VadFrame::Speech(frame)
+            } else {
+                VadFrame::Noise
+            })
+        }
+    }
+
+    fn frames(spec: &[(f32, usize)]) -> Vec<f32> {
+        spec.iter()
+            .flat_map(|&(level, count)| std::iter::repeat(level).take(count * FRAME_SAMPLES))
+            .collect()
+    }
+
+    #[test]
+    fn strips_edges_and_shortens_pauses() {
+        // 20 silent, 10 speech, 30 silent, 5 speech, 20 silent frames
+        let audio = frames(&[(0.0, 20), (0.3, 10), (0.0, 30), (0.3, 5), (0.0, 20)]);
+        let trimmed = trim_silence(&mut EnergyVad, &audio, 3).unwrap();
+        // Each speech run keeps 3 frames of context on both sides
+        assert_eq!(trimmed.len(), (3 + 10 + 3 + 3 + 5 + 3) * FRAME_SAMPLES);
+    }
+
+    #[test]
+    fn keeps_short_pauses_whole() {
+        let audio = frames(&[(0.3, 10), (0.0, 4), (0.3, 10)]);
+        let trimmed = trim_silence(&mut EnergyVad, &audio, 3).unwrap();
+        assert_eq!(trimmed, audio);
+    }
+
+    #[test]
+    fn silence_trims_to_nothing() {
+        let audio = frames(&[(0.0, 50)]);
+        assert!(trim_silence(&mut EnergyVad, &audio, 3).unwrap().is_empty());
+    }
+
+    #[test]
+    fn maps_trimmed_positions_back() {
+        // 20 silent, 10 speech, 30 silent, 5 speech frames
+        let audio = frames(&[(0.0, 20), (0.3, 10), (0.0, 30), (0.3, 5)]);
+        let trimmed = trim_silence_mapped(&mut EnergyVad, &audio, 0).unwrap();
+        assert_eq!(
+            trimmed.kept,
+            [
+                20 * FRAME_SAMPLES..30 * FRAME_SAMPLES,
+                60 * FRAME_SAMPLES..65 * FRAME_SAMPLES
+            ]
+        );
+        assert_eq!(trimmed.original_position(0), 20 * FRAME_SAMPLES);
+        // The first sample of the second speech run
+        assert_eq!(
+            trimmed.original_position(10 * FRAME_SAMPLES),
+            60 * FRAME_SAMPLES
+        );
+        assert_eq!(trimmed.original_position(usize::MAX), 65 * FRAME_SAMPLES);
+    }
+
+    #[test]
+    fn handles_partial_last_frame() {
+        let mut audio = frames(&[(0.3, 2)]);
+        audio.extend(std::iter::repeat(0.3).take(100));
+        let trimmed = trim_silence(&mut EnergyVad, &audio, 1).unwrap();
+        assert_eq!(trimmed, audio);
+    }
+}
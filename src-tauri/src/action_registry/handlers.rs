@@ -0,0 +1,157 @@
+//! What each registered action does.
+
+use serde_json::{json, Map, Value};
+use std::sync::Arc;
+use tauri::{AppHandle, Manager};
+
+use crate::managers::captions::CaptionsManager;
+use crate::managers::feature_flags::{FeatureFlag, FeatureFlagManager};
+use crate::managers::focus_session::FocusSessionManager;
+use crate::managers::meeting::MeetingManager;
+use crate::managers::scratchpad::ScratchpadManager;
+use crate::settings;
+
+/// Shortcut string reported to actions started from the registry.
+const SHORTCUT_STR: &str = "action_registry";
+
+fn string_param(params: &Map<String, Value>, name: &str) -> Option<String> {
+    params.get(name).and_then(Value::as_str).map(str::to_string)
+}
+
+pub(super) fn toggle_dictation(app: &AppHandle, _: &Map<String, Value>) -> Result<Value, String> {
+    crate::actions::toggle_binding(app, "transcribe", SHORTCUT_STR);
+    Ok(Value::Null)
+}
+
+pub(super) fn cancel_operation(app: &AppHandle, _: &Map<String, Value>) -> Result<Value, String> {
+    crate::utils::cancel_current_operation(app);
+    Ok(Value::Null)
+}
+
+pub(super) fn start_meeting(app: &AppHandle, params: &Map<String, Value>) -> Result<Value, String> {
+    let manager = app.state::<Arc<MeetingManager>>().inner().clone();
+    let title = string_param(params, "title");
+    let id =
+        tauri::async_runtime::block_on(manager.start_meeting(title)).map_err(|e| e.to_string())?;
+    Ok(json!(id))
+}
+
+pub(super) fn stop_meeting(app: &AppHandle, _: &Map<String, Value>) -> Result<Value, String> {
+    let manager = app.state::<Arc<MeetingManager>>().inner().clone();
+    tauri::async_runtime::block_on(manager.stop_meeting()).map_err(|e| e.to_string())?;
+    Ok(Value::Null)
+}
+
+pub(super) fn toggle_captions(app: &AppHandle, _: &Map<String, Value>) -> Result<Value, String> {
+    let captions = app.state::<Arc<CaptionsManager>>();
+    if captions.is_running() {
+        captions.stop();
+        return Ok(json!(false));
+    }
+    if !app
+        .state::<Arc<FeatureFlagManager>>()
+        .is_enabled(FeatureFlag::LiveCaptions)
+    {
+        return Err("Live captions are a beta feature. Enable them under Beta Features.".into());
+    }
+    captions.start().map_err(|e| e.to_string())?;
+    Ok(json!(true))
+}
+
+pub(super) fn open_scratchpad(app: &AppHandle, _: &Map<String, Value>) -> Result<Value, String> {
+    app.state::<Arc<ScratchpadManager>>()
+        .open()
+        .map_err(|e| e.to_string())?;
+    Ok(Value::Null)
+}
+
+pub(super) fn copy_scratchpad(
+    app: &AppHandle,
+    params: &Map<String, Value>,
+) -> Result<Value, String> {
+    let clear = params
+        .get("clear")
+        .and_then(Value::as_bool)
+        .unwrap_or(false);
+    app.state::<Arc<ScratchpadManager>>()
+        .copy_out(clear)
+        .map_err(|e| e.to_string())?;
+    Ok(Value::Null)
+}
+
+pub(super) fn start_focus_session(
+    app: &AppHandle,
+    params: &Map<String, Value>,
+) -> Result<Value, String> {
+    let name = string_param(params, "name").unwrap_or_default();
+    let session = app
+        .state::<Arc<FocusSessionManager>>()
+        .start(&name)
+        .map_err(|e| e.to_string())?;
+    serde_json::to_value(session).map_err(|e| e.to_string())
+}
+
+pub(super) fn stop_focus_session(app: &AppHandle, _: &Map<String, Value>) -> Result<Value, String> {
+    let session = app
+        .state::<Arc<FocusSessionManager>>()
+        .stop()
+        .map_err(|e| e.to_string())?;
+    serde_json::to_value(session).map_err(|e| e.to_string())
+}
+
+pub(super) fn toggle_post_processing(
+    app: &AppHandle,
+    _: &Map<String, Value>,
+) -> Result<Value, String> {
+    let enabled = !settings::get_settings(app).post_process_enabled;
+    settings::update_settings(app, |s| {
+        s.post_process_enabled = enabled;
+    });
+    Ok(json!(enabled))
+}
+
+pub(super) fn skip_post_processing(_: &AppHandle, _: &Map<String, Value>) -> Result<Value, String> {
+    Ok(json!(crate::actions::skip_post_processing()))
+}
+
+pub(super) fn select_prompt(app: &AppHandle, params: &Map<String, Value>) -> Result<Value, String> {
+    // Voice commands pass the name the user said
+    let prompts = settings::get_settings(app).post_process_prompts;
+    let id = string_param(params, "id").map(|id| {
+        if prompts.iter().any(|prompt| prompt.id == id) {
+            return id;
+        }
+        prompts
+            .iter()
+            .find(|prompt| prompt.name.eq_ignore_ascii_case(&id))
+            .map_or(id, |prompt| prompt.id.clone())
+    });
+    crate::features::shortcut::settings::post_process::set_post_process_selected_prompt(
+        app.clone(),
+        id,
+    )?;
+    Ok(Value::Null)
+}
+
+pub(super) fn show_settings(app: &AppHandle, _: &Map<String, Value>) -> Result<Value, String> {
+    crate::startup::show_main_window(app);
+    Ok(Value::Null)
+}
+
+pub(super) fn launch_app(_: &AppHandle, params: &Map<String, Value>) -> Result<Value, String> {
+    let name = string_param(params, "name").unwrap_or_default();
+    let result = crate::tools::open_application(&name);
+    if result.success {
+        Ok(json!(result.display_message))
+    } else {
+        Err(result.display_message)
+    }
+}
+
+pub(super) fn open_recordings_folder(
+    app: &AppHandle,
+    _: &Map<String, Value>,
+) -> Result<Value, String> {
+    crate::commands::open_recordings_folder(app.clone())?;
+    Ok(Value::Null)
+}
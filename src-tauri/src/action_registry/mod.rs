@@ -0,0 +1,264 @@
+//! Every backend action that can be triggered by name.
+//!
+//! The command palette lists these and runs them through `invoke_action`, and
+//! anything else that triggers actions from outside the UI goes through the
+//! same table, so a new action only has to be added here to be reachable
+//! everywhere.
+
+mod handlers;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+use tauri::AppHandle;
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ActionCategory {
+    Dictation,
+    Meeting,
+    Scratchpad,
+    FocusSession,
+    PostProcessing,
+    App,
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ParamKind {
+    String,
+    Boolean,
+}
+
+#[derive(Serialize, Debug)]
+pub struct ParamDef {
+    pub name: &'static str,
+    pub kind: ParamKind,
+    pub required: bool,
+    pub description: &'static str,
+}
+
+type Handler = fn(&AppHandle, &Map<String, Value>) -> Result<Value, String>;
+
+#[derive(Serialize)]
+pub struct ActionDef {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub category: ActionCategory,
+    pub params: &'static [ParamDef],
+    #[serde(skip)]
+    handler: Handler,
+}
+
+pub static ACTIONS: &[ActionDef] = &[
+    ActionDef {
+        id: "dictation.toggle",
+        name: "Start or stop dictation",
+        category: ActionCategory::Dictation,
+        params: &[],
+        handler: handlers::toggle_dictation,
+    },
+    ActionDef {
+        id: "dictation.cancel",
+        name: "Cancel the current recording",
+        category: ActionCategory::Dictation,
+        params: &[],
+        handler: handlers::cancel_operation,
+    },
+    ActionDef {
+        id: "meeting.start",
+        name: "Start a meeting",
+        category: ActionCategory::Meeting,
+        params: &[ParamDef {
+            name: "title",
+            kind: ParamKind::String,
+            required: false,
+            description: "Title of the meeting",
+        }],
+        handler: handlers::start_meeting,
+    },
+    ActionDef {
+        id: "meeting.stop",
+        name: "Stop the meeting",
+        category: ActionCategory::Meeting,
+        params: &[],
+        handler: handlers::stop_meeting,
+    },
+    ActionDef {
+        id: "meeting.toggle_captions",
+        name: "Toggle live captions",
+        category: ActionCategory::Meeting,
+        params: &[],
+        handler: handlers::toggle_captions,
+    },
+    ActionDef {
+        id: "scratchpad.open",
+        name: "Open the scratchpad",
+        category: ActionCategory::Scratchpad,
+        params: &[],
+        handler: handlers::open_scratchpad,
+    },
+    ActionDef {
+        id: "scratchpad.copy",
+        name: "Copy the scratchpad",
+        category: ActionCategory::Scratchpad,
+        params: &[ParamDef {
+            name: "clear",
+            kind: ParamKind::Boolean,
+            required: false,
+            description: "Empty the scratchpad after copying",
+        }],
+        handler: handlers::copy_scratchpad,
+    },
+    ActionDef {
+        id: "focus_session.start",
+        name: "Start a focus session",
+        category: ActionCategory::FocusSession,
+        params: &[ParamDef {
+            name: "name",
+            kind: ParamKind::String,
+            required: true,
+            description: "What the session is about",
+        }],
+        handler: handlers::start_focus_session,
+    },
+    ActionDef {
+        id: "focus_session.stop",
+        name: "Stop the focus session",
+        category: ActionCategory::FocusSession,
+        params: &[],
+        handler: handlers::stop_focus_session,
+    },
+    ActionDef {
+        id: "post_processing.toggle",
+        name: "Toggle post-processing",
+        category: ActionCategory::PostProcessing,
+        params: &[],
+        handler: handlers::toggle_post_processing,
+    },
+    ActionDef {
+        id: "post_processing.skip",
+        name: "Skip post-processing and paste the raw text",
+        category: ActionCategory::PostProcessing,
+        params: &[],
+        handler: handlers::skip_post_processing,
+    },
+    ActionDef {
+        id: "post_processing.select_prompt",
+        name: "Switch post-processing prompt",
+        category: ActionCategory::PostProcessing,
+        params: &[ParamDef {
+            name: "id",
+            kind: ParamKind::String,
+            required: true,
+            description: "Id or name of the prompt to use",
+        }],
+        handler: handlers::select_prompt,
+    },
+    ActionDef {
+        id: "app.show_settings",
+        name: "Show the settings window",
+        category: ActionCategory::App,
+        params: &[],
+        handler: handlers::show_settings,
+    },
+    ActionDef {
+        id: "app.launch",
+        name: "Open an application",
+        category: ActionCategory::App,
+        params: &[ParamDef {
+            name: "name",
+            kind: ParamKind::String,
+            required: true,
+            description: "Name of the application",
+        }],
+        handler: handlers::launch_app,
+    },
+    ActionDef {
+        id: "app.open_recordings_folder",
+        name: "Open the recordings folder",
+        category: ActionCategory::App,
+        params: &[],
+        handler: handlers::open_recordings_folder,
+    },
+];
+
+/// Run action `id` with `params`, a JSON object matching its parameters.
+/// Blocks until the action is done.
+pub fn invoke(app: &AppHandle, id: &str, params: Value) -> Result<Value, String> {
+    let action = ACTIONS
+        .iter()
+        .find(|action| action.id == id)
+        .ok_or_else(|| format!("Unknown action '{}'", id))?;
+    let params = validate_params(action, params)?;
+    (action.handler)(app, &params)
+}
+
+/// Check `params` against the parameters `action` declares.
+fn validate_params(action: &ActionDef, params: Value) -> Result<Map<String, Value>, String> {
+    let params = match params {
+        Value::Null => Map::new(),
+        Value::Object(map) => map,
+        _ => return Err(format!("Parameters of '{}' must be an object", action.id)),
+    };
+
+    for name in params.keys() {
+        if !action.params.iter().any(|param| param.name == name) {
+            return Err(format!("'{}' has no parameter '{}'", action.id, name));
+        }
+    }
+    for param in action.params {
+        match params.get(param.name) {
+            None | Some(Value::Null) if param.required => {
+                return Err(format!("'{}' needs parameter '{}'", action.id, param.name));
+            }
+            None | Some(Value::Null) => {}
+            Some(value) => {
+                let matches = match param.kind {
+                    ParamKind::String => value.is_string(),
+                    ParamKind::Boolean => value.is_boolean(),
+                };
+                if !matches {
+                    return Err(format!(
+                        "Parameter '{}' of '{}' must be a {:?}",
+                        param.name, action.id, param.kind
+                    ));
+                }
+            }
+        }
+    }
+    Ok(params)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::collections::HashSet;
+
+    fn action(id: &str) -> &'static ActionDef {
+        ACTIONS.iter().find(|action| action.id == id).unwrap()
+    }
+
+    #[test]
+    fn action_ids_are_unique() {
+        let ids: HashSet<_> = ACTIONS.iter().map(|action| action.id).collect();
+        assert_eq!(ids.len(), ACTIONS.len());
+    }
+
+    #[test]
+    fn missing_params_are_an_empty_object() {
+        let params = validate_params(action("dictation.toggle"), Value::Null).unwrap();
+        assert!(params.is_empty());
+        assert!(validate_params(action("scratchpad.copy"), json!({})).is_ok());
+    }
+
+    #[test]
+    fn rejects_params_that_dont_match() {
+        let start = action("focus_session.start");
+        assert!(validate_params(start, json!({ "name": "Writing" })).is_ok());
+        assert!(validate_params(start, json!({})).is_err());
+        assert!(validate_params(start, json!({ "name": 3 })).is_err());
+        assert!(validate_params(start, json!({ "name": "Writing", "extra": 1 })).is_err());
+        assert!(validate_params(start, json!(["Writing"])).is_err());
+    }
+}
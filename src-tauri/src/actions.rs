@@ -1,42 +1,128 @@
+use crate::action_registry;
 use crate::audio_feedback::{play_feedback_sound, play_feedback_sound_blocking, SoundType};
+use crate::audio_toolkit::apply_custom_words;
+use crate::features::shortcut::escape;
+use crate::helpers::{
+    accessibility, app_words, dictation_commands, focused_text, initial_prompt, language_detect,
+    locale_format, pipeline_trace, prompt_command, transcription_confidence, voice_commands,
+};
 use crate::managers::audio::AudioRecordingManager;
-use crate::managers::history::HistoryManager;
-use crate::managers::transcription::TranscriptionManager;
+use crate::managers::confidence_review::{ConfidenceReviewManager, PendingReview};
+use crate::managers::dictation_template::{DictationTemplateManager, SlotFill};
+use crate::managers::feature_flags::{FeatureFlag, FeatureFlagManager};
+use crate::managers::history::{HistoryManager, RecordingSource};
+use crate::managers::input_tracker::get_active_app_info_fast;
+use crate::managers::noise_profile::NoiseProfileManager;
+use crate::managers::scratchpad::ScratchpadManager;
+use crate::managers::text_plugins::TextPluginManager;
+use crate::managers::transcription::{is_engine_failure, Transcript, TranscriptionManager};
 use crate::managers::tts::TtsManager;
+use crate::managers::voice_profile::VoiceProfileManager;
 use crate::overlay::{
-    show_recording_overlay, show_tool_overlay, show_transcribing_overlay, show_warning_overlay,
+    show_countdown_overlay, show_recording_overlay, show_tool_overlay, show_transcribing_overlay,
+    show_warning_overlay,
 };
 use crate::settings::{get_settings, AppSettings};
 use crate::tools::{self, PostProcessOutcome};
 use crate::tray::{change_tray_icon, TrayIconState};
 use crate::utils;
-use crate::ManagedToggleState;
 use async_openai::types::{
     ChatCompletionRequestAssistantMessageArgs, ChatCompletionRequestMessage,
     ChatCompletionRequestSystemMessageArgs, ChatCompletionRequestToolMessageArgs,
     ChatCompletionRequestUserMessageArgs, CreateChatCompletionRequestArgs, FinishReason,
 };
 use ferrous_opencc::{config::BuiltinConfig, OpenCC};
-use log::{debug, error, info};
+use futures_util::future::{self, Either};
+use log::{debug, error, info, warn};
 use once_cell::sync::Lazy;
+use serde::Serialize;
+use serde_json::Value;
 use std::collections::HashMap;
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use tauri::AppHandle;
+use tauri::Emitter;
 use tauri::Manager;
+use tracing::Instrument;
 
 /// Monotonically increasing counter that increments on every `start()` and `cancel()`.
 /// In-flight async tasks capture the current value and bail out when it changes,
 /// preventing stale transcription paste, overlay updates, and mute operations.
 pub(crate) static OPERATION_GENERATION: AtomicU64 = AtomicU64::new(0);
 
+/// Binding that continues the last dictation instead of starting a new one.
+pub const CONTINUE_BINDING: &str = "continue_dictation";
+
+/// Generation of the latest recording started with [`CONTINUE_BINDING`]. Its
+/// transcription is appended to the newest history entry.
+static CONTINUED_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// Whisper initial prompt captured when the recording of a generation
+/// started, taken by `stop()` and handed to that dictation's transcription.
+static INITIAL_PROMPT: Lazy<Mutex<Option<(u64, String)>>> = Lazy::new(|| Mutex::new(None));
+
 /// Handle to the most recent async transcription task spawned by `stop()`.
 /// On a new stop or cancel we abort the previous handle so stale LLM
 /// post-processing API calls don't continue running.
 pub(crate) static TRANSCRIPTION_TASK: Lazy<Mutex<Option<tauri::async_runtime::JoinHandle<()>>>> =
     Lazy::new(|| Mutex::new(None));
 
+/// Wakes the post-processing in flight so it drops its LLM call.
+static POST_PROCESS_SKIP: Lazy<tokio::sync::Notify> = Lazy::new(tokio::sync::Notify::new);
+static POST_PROCESS_IN_FLIGHT: AtomicBool = AtomicBool::new(false);
+
+/// Length of the optional pre-recording countdown, in seconds.
+const COUNTDOWN_SECS: u32 = 3;
+
+/// Toggle presses closer together than this are key bounce and dropped.
+const MIN_PRESS_INTERVAL: Duration = Duration::from_millis(150);
+/// How often a stop is checked for having released the recording.
+const STOP_SETTLE_POLL: Duration = Duration::from_millis(20);
+/// Longest a stop holds back later presses, in case the recording never
+/// reports stopped.
+const STOP_SETTLE_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Whether a recording of `elapsed` length is shorter than the configured
+/// minimum and should be discarded as an accidental tap.
+fn is_accidental_tap(elapsed: Duration, min_duration_ms: u64) -> bool {
+    elapsed < Duration::from_millis(min_duration_ms)
+}
+
+/// Abort the post-processing in flight and paste the raw transcription right
+/// away. Returns false if nothing is being post-processed.
+pub fn skip_post_processing() -> bool {
+    if !POST_PROCESS_IN_FLIGHT.load(Ordering::SeqCst) {
+        return false;
+    }
+    info!("Skipping post-processing, using the raw transcription");
+    POST_PROCESS_SKIP.notify_waiters();
+    true
+}
+
+/// Marks post-processing as in flight and holds the skip shortcut until
+/// dropped, including when the transcription task is aborted.
+struct PostProcessInFlight {
+    app: AppHandle,
+}
+
+impl PostProcessInFlight {
+    fn start(app: &AppHandle) -> Self {
+        POST_PROCESS_IN_FLIGHT.store(true, Ordering::SeqCst);
+        if let Err(e) = escape::register_skip_post_processing_shortcut(app) {
+            warn!("{}", e);
+        }
+        Self { app: app.clone() }
+    }
+}
+
+impl Drop for PostProcessInFlight {
+    fn drop(&mut self) {
+        POST_PROCESS_IN_FLIGHT.store(false, Ordering::SeqCst);
+        escape::unregister_skip_post_processing_shortcut(&self.app);
+    }
+}
+
 // Shortcut Action Trait
 pub trait ShortcutAction: Send + Sync {
     fn start(&self, app: &AppHandle, binding_id: &str, shortcut_str: &str);
@@ -50,6 +136,7 @@ pub async fn maybe_post_process_transcription(
     app: &AppHandle,
     settings: &AppSettings,
     transcription: &str,
+    language: Option<&str>,
 ) -> PostProcessOutcome {
     if !settings.post_process_enabled {
         return PostProcessOutcome::Empty;
@@ -139,6 +226,12 @@ pub async fn maybe_post_process_transcription(
         .replace("${output}", transcription)
         .replace("@output", transcription);
 
+    // ${language} lets one prompt answer in whatever language was spoken
+    let language_name = language
+        .and_then(language_detect::language_name)
+        .unwrap_or("the same language as the transcript");
+    let processed_prompt = processed_prompt.replace("${language}", language_name);
+
     // Log the processed prompt (after variable substitution)
     log::info!(
         "[Post-Process] Prompt with transcript inserted:\n{}",
@@ -156,7 +249,10 @@ pub async fn maybe_post_process_transcription(
         }
     };
 
-    let use_tools = settings.voice_commands_enabled;
+    let use_tools = settings.voice_commands_enabled
+        && app
+            .state::<Arc<FeatureFlagManager>>()
+            .is_enabled(FeatureFlag::AiTools);
     let tool_definitions = if use_tools {
         tools::get_tool_definitions()
     } else {
@@ -421,101 +517,477 @@ async fn maybe_convert_chinese_variant(
     }
 }
 
+/// Apply a spoken prompt switch at the start of `transcription`. Returns the
+/// new prompt's name and the dictation that follows the command.
+fn apply_prompt_command(app: &AppHandle, transcription: &str) -> Option<(String, String)> {
+    let settings = get_settings(app);
+    if !settings.voice_prompt_switching_enabled {
+        return None;
+    }
+    let command = prompt_command::parse(transcription, &settings.post_process_prompts)?;
+    info!(
+        "Switching post-process prompt to '{}' by voice",
+        command.prompt_name
+    );
+
+    let prompt_id = command.prompt_id.clone();
+    crate::settings::update_settings(app, |s| {
+        s.post_process_selected_prompt_id = Some(prompt_id);
+    });
+    let _ = app.emit("post-process-prompt-changed", &command.prompt_id);
+    Some((command.prompt_name, command.rest.to_string()))
+}
+
+/// Run the action of the voice command rule the whole transcription matches.
+/// Returns the message to show, or `None` when the transcription is no
+/// command and should be pasted.
+async fn run_voice_command(app: &AppHandle, transcription: &str) -> Option<String> {
+    let settings = get_settings(app);
+    if !settings.voice_command_rules_enabled {
+        return None;
+    }
+    let (rule, params) = voice_commands::find(transcription, &settings.voice_command_rules)?;
+    info!("Running voice command '{}' ({})", rule.phrase, rule.action);
+
+    let action_id = rule.action.clone();
+    let app_clone = app.clone();
+    // Actions block until they are done
+    let result = tauri::async_runtime::spawn_blocking(move || {
+        action_registry::invoke(&app_clone, &action_id, Value::Object(params))
+    })
+    .await;
+    let message = match result {
+        Ok(Ok(_)) => action_registry::ACTIONS
+            .iter()
+            .find(|action| action.id == rule.action)
+            .map_or_else(|| rule.action.clone(), |action| action.name.to_string()),
+        Ok(Err(e)) => {
+            warn!("Voice command '{}' failed: {}", rule.phrase, e);
+            e
+        }
+        Err(e) => e.to_string(),
+    };
+    Some(message)
+}
+
+/// Turn spoken commands ("new line", "delete that") into the punctuation,
+/// line breaks and edits they stand for, using the commands of the
+/// transcript's language.
+fn apply_dictation_commands(app: &AppHandle, transcript: Transcript) -> Transcript {
+    let settings = get_settings(app);
+    if !settings.dictation_commands_enabled {
+        return transcript;
+    }
+    let language = transcript.language.as_deref().unwrap_or("en");
+    let Some(commands) = settings.dictation_commands.get(language) else {
+        return transcript;
+    };
+    let text = dictation_commands::apply(&transcript.text, commands);
+    if text == transcript.text {
+        return transcript;
+    }
+    // Inserted punctuation gets the language's spacing too
+    let text = if settings.locale_formatting.iter().any(|l| l == language) {
+        locale_format::apply(&text, language)
+    } else {
+        text
+    };
+    // The timestamps no longer line up with the text
+    Transcript {
+        text,
+        segments: Vec::new(),
+        language: transcript.language,
+    }
+}
+
+/// Hold a dictation whose estimated confidence is below the review threshold
+/// so the user can decide what to do with it. `transcribed` is the audio the
+/// transcript came from, `recording` the dictation as recorded. Returns
+/// whether it was held.
+fn hold_low_confidence(
+    app: &AppHandle,
+    binding_id: &str,
+    transcript: &Transcript,
+    transcribed: &[f32],
+    recording: &[f32],
+    source: &RecordingSource,
+    gen: u64,
+) -> bool {
+    let settings = get_settings(app);
+    if !settings.low_confidence_review_enabled {
+        return false;
+    }
+    let confidence = transcription_confidence::estimate(&transcript.text, transcribed);
+    if f64::from(confidence) >= settings.low_confidence_threshold {
+        return false;
+    }
+    let Some(review) = app.try_state::<Arc<ConfidenceReviewManager>>() else {
+        return false;
+    };
+    review.hold(PendingReview {
+        binding_id: binding_id.to_string(),
+        transcript: transcript.clone(),
+        samples: recording.to_vec(),
+        source: source.clone(),
+        confidence,
+        generation: gen,
+    });
+    true
+}
+
+/// The copy of a dictation the transcriber gets, with room noise and
+/// background voices dropped. History keeps the recording as it was made.
+pub(crate) fn prepare_for_transcription(app: &AppHandle, samples: Vec<f32>) -> Vec<f32> {
+    let samples = suppress_ambient_noise(app, samples);
+    filter_to_enrolled_voice(app, samples)
+}
+
+/// Subtract the active noise profile's background noise from a dictation.
+fn suppress_ambient_noise(app: &AppHandle, samples: Vec<f32>) -> Vec<f32> {
+    let Some(profile) = get_settings(app).active_noise_profile else {
+        return samples;
+    };
+    let Some(noise_profiles) = app.try_state::<Arc<NoiseProfileManager>>() else {
+        return samples;
+    };
+    noise_profiles.suppress(&profile, &samples)
+}
+
+/// Drop the parts of a dictation not spoken by the enrolled voice when the
+/// voice filter is on. Keeps the whole recording if filtering fails.
+fn filter_to_enrolled_voice(app: &AppHandle, samples: Vec<f32>) -> Vec<f32> {
+    if !get_settings(app).voice_filter_enabled {
+        return samples;
+    }
+    let Some(voice_profile) = app.try_state::<Arc<VoiceProfileManager>>() else {
+        return samples;
+    };
+    match voice_profile.filter(&samples) {
+        Ok(filtered) => filtered,
+        Err(e) => {
+            warn!("Voice filter failed, using the whole recording: {}", e);
+            samples
+        }
+    }
+}
+
+/// Sent with `transcription-fallback` when a dictation was transcribed with
+/// another model because the selected one failed.
+#[derive(Clone, Debug, Serialize)]
+struct TranscriptionFallbackEvent {
+    model_name: String,
+    message: String,
+}
+
+/// Transcribe a dictation the loaded model failed on (a corrupt file, out of
+/// memory) once more with the smallest other downloaded model, and warn the
+/// user. Cancelled dictations and cloud rejections aren't retried. Returns
+/// the original error if the retry fails too.
+fn retry_with_fallback_model(
+    app: &AppHandle,
+    tm: &TranscriptionManager,
+    samples: &[f32],
+    prompt: Option<String>,
+    err: anyhow::Error,
+) -> anyhow::Result<Transcript> {
+    if !is_engine_failure(&err) {
+        return Err(err);
+    }
+    error!("Transcription failed: {}", err);
+    match tm.transcribe_with_fallback_model(samples.to_vec(), prompt) {
+        Ok((transcript, model_name)) => {
+            let _ = app.emit(
+                "transcription-fallback",
+                TranscriptionFallbackEvent {
+                    message: format!(
+                        "The selected model failed ({}), so this dictation was transcribed with {}.",
+                        err, model_name
+                    ),
+                    model_name,
+                },
+            );
+            Ok(transcript)
+        }
+        Err(retry_err) => {
+            warn!("Retrying with a fallback model failed: {}", retry_err);
+            Err(err)
+        }
+    }
+}
+
+/// Sent with `transcription-language` after each dictation.
+#[derive(Clone, Debug, Serialize)]
+struct TranscriptionLanguageEvent {
+    /// ISO 639-1 code, `None` when it couldn't be told.
+    language: Option<String>,
+    /// Whether the language was detected rather than set in the settings.
+    detected: bool,
+}
+
+fn emit_transcription_language(app: &AppHandle, transcript: &Transcript) {
+    let event = TranscriptionLanguageEvent {
+        language: transcript.language.clone(),
+        detected: get_settings(app).selected_language == "auto",
+    };
+    let _ = app.emit("transcription-language", &event);
+}
+
+/// Save a dictation to history along with its timestamps and language.
+async fn save_history_entry(
+    hm: Arc<HistoryManager>,
+    samples: Vec<f32>,
+    transcript: Transcript,
+    post_processed_text: Option<String>,
+    post_process_prompt: Option<String>,
+    source: RecordingSource,
+    continues: bool,
+) {
+    if continues {
+        match hm
+            .append_to_latest(
+                &samples,
+                &transcript.text,
+                post_processed_text.as_deref(),
+                &transcript.segments,
+            )
+            .await
+        {
+            Ok(Some(_)) => return,
+            // Nothing recent to continue, so this starts a new entry
+            Ok(None) => {}
+            Err(e) => {
+                error!("Failed to continue the last entry, saving a new one: {}", e);
+            }
+        }
+    }
+    let id = match hm
+        .save_transcription(
+            samples,
+            transcript.text,
+            post_processed_text,
+            post_process_prompt,
+            source,
+        )
+        .await
+    {
+        Ok(id) => id,
+        Err(e) => {
+            error!("Failed to save transcription to history: {}", e);
+            return;
+        }
+    };
+    if !transcript.segments.is_empty() {
+        if let Err(e) = hm.save_timestamps(id, &transcript.segments).await {
+            error!("Failed to save timestamps: {}", e);
+        }
+    }
+    if let Some(language) = transcript.language {
+        if let Err(e) = hm.save_language(id, language).await {
+            error!("Failed to save language: {}", e);
+        }
+    }
+}
+
+/// Correct `text` with the custom words of the frontmost app, if it has any.
+fn apply_app_custom_words(settings: &AppSettings, text: &str) -> Option<String> {
+    if settings.app_custom_words.is_empty() {
+        return None;
+    }
+    let app = get_active_app_info_fast();
+    let words = app_words::for_app(
+        &settings.app_custom_words,
+        app.bundle_id.as_deref(),
+        &app.name,
+    );
+    if words.is_empty() {
+        return None;
+    }
+    debug!("Applying {} custom words for {}", words.len(), app.name);
+    Some(apply_custom_words(
+        text,
+        words,
+        settings.word_correction_threshold,
+    ))
+}
+
+/// Revert the UI when a recording fails to start. Leaves it alone if another
+/// binding is still recording.
+fn revert_failed_start(app: &AppHandle, rm: &AudioRecordingManager) {
+    if !rm.is_recording() {
+        utils::hide_recording_overlay(app);
+        change_tray_icon(app, TrayIconState::Idle);
+    }
+}
+
+/// Keep the Whisper initial prompt of the recording started in `gen` for
+/// its transcription, and hand it to the live previews.
+fn store_initial_prompt(tm: &TranscriptionManager, gen: u64, prompt: Option<String>) {
+    tm.set_preview_prompt(gen, prompt.clone());
+    *INITIAL_PROMPT.lock().unwrap() = prompt.map(|prompt| (gen, prompt));
+}
+
+/// The initial prompt captured for the recording started in `gen`, if any.
+fn take_initial_prompt(gen: u64) -> Option<String> {
+    let mut stored = INITIAL_PROMPT.lock().unwrap();
+    match stored.take() {
+        Some((prompt_gen, prompt)) if prompt_gen == gen => Some(prompt),
+        other => {
+            *stored = other;
+            None
+        }
+    }
+}
+
+/// Build the Whisper initial prompt for this recording. The frontmost app
+/// and focused field are read off-thread.
+fn capture_initial_prompt(app: &AppHandle, settings: &AppSettings) {
+    let tm = Arc::clone(&app.state::<Arc<TranscriptionManager>>());
+    let gen = OPERATION_GENERATION.load(Ordering::SeqCst);
+    let vocabulary = initial_prompt::vocabulary(settings);
+    let read_app = settings.initial_prompt_active_app;
+    let read_field = settings.context_prompt_enabled;
+    if !read_app && !read_field {
+        store_initial_prompt(&tm, gen, vocabulary);
+        return;
+    }
+
+    std::thread::spawn(move || {
+        let app_name = read_app.then(|| get_active_app_info_fast().name);
+        let context = read_field.then(focused_text::read_context_prompt).flatten();
+        let prompt = initial_prompt::build(
+            vocabulary.as_deref(),
+            app_name.as_deref(),
+            context.as_deref(),
+        );
+        debug!(
+            "Initial prompt captured: {} chars",
+            prompt.as_ref().map_or(0, |p| p.chars().count())
+        );
+        store_initial_prompt(&tm, gen, prompt);
+    });
+}
+
+/// Start the recording for `binding_id`, playing feedback and muting in the
+/// order the current microphone mode requires.
+fn begin_recording(app: &AppHandle, binding_id: &str) {
+    let binding_id = binding_id.to_string();
+    change_tray_icon(app, TrayIconState::Recording);
+    show_recording_overlay(app, &binding_id);
+
+    let rm = app.state::<Arc<AudioRecordingManager>>();
+
+    // Get the microphone mode to determine audio feedback timing
+    let settings = get_settings(app);
+    capture_initial_prompt(app, &settings);
+    // A stream released for being idle opens like an on-demand one
+    let is_always_on = settings.always_on_microphone && rm.is_microphone_open();
+    debug!("Microphone mode - always_on: {}", is_always_on);
+
+    if is_always_on {
+        // Always-on mode: Play audio feedback immediately, then apply mute after sound finishes
+        debug!("Always-on mode: Playing audio feedback immediately");
+        let rm_clone = Arc::clone(&rm);
+        let app_clone = app.clone();
+        // The blocking helper exits immediately if audio feedback is disabled,
+        // so we can reuse this thread regardless of user settings.
+        let gen = OPERATION_GENERATION.load(Ordering::SeqCst);
+        std::thread::spawn(move || {
+            play_feedback_sound_blocking(&app_clone, SoundType::Start);
+            if OPERATION_GENERATION.load(Ordering::SeqCst) == gen {
+                rm_clone.apply_mute();
+            }
+        });
+
+        let recording_started = rm.try_start_recording(&binding_id);
+        if recording_started {
+            accessibility::announce(app, "Recording");
+        } else {
+            revert_failed_start(app, &rm);
+        }
+        debug!("Recording started: {}", recording_started);
+    } else {
+        // On-demand mode: Start recording first, then play audio feedback, then apply mute
+        // This allows the microphone to be activated before playing the sound
+        debug!("On-demand mode: Starting recording first, then audio feedback");
+        let recording_start_time = Instant::now();
+        if rm.try_start_recording(&binding_id) {
+            debug!("Recording started in {:?}", recording_start_time.elapsed());
+            accessibility::announce(app, "Recording");
+            // Small delay to ensure microphone stream is active
+            let app_clone = app.clone();
+            let rm_clone = Arc::clone(&rm);
+            let gen = OPERATION_GENERATION.load(Ordering::SeqCst);
+            std::thread::spawn(move || {
+                std::thread::sleep(std::time::Duration::from_millis(100));
+                debug!("Handling delayed audio feedback/mute sequence");
+                // Helper handles disabled audio feedback by returning early,
+                // so we reuse it to keep mute sequencing consistent in every mode.
+                play_feedback_sound_blocking(&app_clone, SoundType::Start);
+                if OPERATION_GENERATION.load(Ordering::SeqCst) == gen {
+                    rm_clone.apply_mute();
+                }
+            });
+        } else {
+            revert_failed_start(app, &rm);
+            debug!("Failed to start recording");
+        }
+    }
+}
+
 impl ShortcutAction for TranscribeAction {
     fn start(&self, app: &AppHandle, binding_id: &str, _shortcut_str: &str) {
         let start_time = Instant::now();
         debug!("TranscribeAction::start called for binding: {}", binding_id);
 
         // Increment generation to invalidate any in-flight operations from previous recordings
-        OPERATION_GENERATION.fetch_add(1, Ordering::SeqCst);
+        let gen = OPERATION_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+        if binding_id == CONTINUE_BINDING {
+            CONTINUED_GENERATION.store(gen, Ordering::SeqCst);
+        }
 
         // Check if a file transcription is currently active
         if crate::is_file_transcription_active() {
             debug!("File transcription in progress - showing warning overlay");
             show_warning_overlay(app, "File transcription in progress. Please wait...");
-
-            // Reset the toggle state so next press will call start() again
-            let toggle_state_manager = app.state::<ManagedToggleState>();
-            if let Ok(mut states) = toggle_state_manager.lock() {
-                states.active_toggles.insert(binding_id.to_string(), false);
-            }
             return;
         }
 
+        pipeline_trace::start_pipeline(binding_id);
+
         // Load model in the background
         let tm = app.state::<Arc<TranscriptionManager>>();
         tm.initiate_model_load();
 
-        let binding_id = binding_id.to_string();
-        change_tray_icon(app, TrayIconState::Recording);
-        show_recording_overlay(app);
-
-        let rm = app.state::<Arc<AudioRecordingManager>>();
-
-        // Get the microphone mode to determine audio feedback timing
-        let settings = get_settings(app);
-        let is_always_on = settings.always_on_microphone;
-        debug!("Microphone mode - always_on: {}", is_always_on);
+        if get_settings(app).recording_countdown_enabled {
+            change_tray_icon(app, TrayIconState::Recording);
+            let rm = app.state::<Arc<AudioRecordingManager>>();
+            rm.set_pending_binding(binding_id);
 
-        if is_always_on {
-            // Always-on mode: Play audio feedback immediately, then apply mute after sound finishes
-            debug!("Always-on mode: Playing audio feedback immediately");
-            let rm_clone = Arc::clone(&rm);
-            let app_clone = app.clone();
-            // The blocking helper exits immediately if audio feedback is disabled,
-            // so we can reuse this thread regardless of user settings.
             let gen = OPERATION_GENERATION.load(Ordering::SeqCst);
+            let app_clone = app.clone();
+            let binding_id = binding_id.to_string();
             std::thread::spawn(move || {
-                play_feedback_sound_blocking(&app_clone, SoundType::Start);
-                if OPERATION_GENERATION.load(Ordering::SeqCst) == gen {
-                    rm_clone.apply_mute();
-                }
-            });
-
-            let recording_started = rm.try_start_recording(&binding_id);
-            if !recording_started {
-                // Reset toggle state and revert UI when recording fails to start
-                let toggle_state_manager = app.state::<ManagedToggleState>();
-                if let Ok(mut states) = toggle_state_manager.lock() {
-                    states.active_toggles.insert(binding_id.clone(), false);
-                }
-                utils::hide_recording_overlay(app);
-                change_tray_icon(app, TrayIconState::Idle);
-            }
-            debug!("Recording started: {}", recording_started);
-        } else {
-            // On-demand mode: Start recording first, then play audio feedback, then apply mute
-            // This allows the microphone to be activated before playing the sound
-            debug!("On-demand mode: Starting recording first, then audio feedback");
-            let recording_start_time = Instant::now();
-            if rm.try_start_recording(&binding_id) {
-                debug!("Recording started in {:?}", recording_start_time.elapsed());
-                // Small delay to ensure microphone stream is active
-                let app_clone = app.clone();
-                let rm_clone = Arc::clone(&rm);
-                let gen = OPERATION_GENERATION.load(Ordering::SeqCst);
-                std::thread::spawn(move || {
-                    std::thread::sleep(std::time::Duration::from_millis(100));
-                    debug!("Handling delayed audio feedback/mute sequence");
-                    // Helper handles disabled audio feedback by returning early,
-                    // so we reuse it to keep mute sequencing consistent in every mode.
-                    play_feedback_sound_blocking(&app_clone, SoundType::Start);
-                    if OPERATION_GENERATION.load(Ordering::SeqCst) == gen {
-                        rm_clone.apply_mute();
+                for remaining in (1..=COUNTDOWN_SECS).rev() {
+                    show_countdown_overlay(&app_clone, remaining);
+                    std::thread::sleep(Duration::from_secs(1));
+                    if OPERATION_GENERATION.load(Ordering::SeqCst) != gen {
+                        debug!("Countdown superseded by a newer operation");
+                        return;
                     }
-                });
-            } else {
-                // Reset toggle state and revert UI when recording fails to start
-                let toggle_state_manager = app.state::<ManagedToggleState>();
-                if let Ok(mut states) = toggle_state_manager.lock() {
-                    states.active_toggles.insert(binding_id.clone(), false);
                 }
-                utils::hide_recording_overlay(app);
-                change_tray_icon(app, TrayIconState::Idle);
-                debug!("Failed to start recording");
-            }
+                // Only start if stop() didn't abort the countdown meanwhile
+                let rm = app_clone.state::<Arc<AudioRecordingManager>>();
+                if rm.take_pending_binding(&binding_id) {
+                    begin_recording(&app_clone, &binding_id);
+                } else {
+                    debug!("Countdown aborted before recording started");
+                }
+            });
+            return;
         }
 
+        begin_recording(app, binding_id);
+
         debug!(
             "TranscribeAction::start completed in {:?}",
             start_time.elapsed()
@@ -525,24 +997,21 @@ impl ShortcutAction for TranscribeAction {
     fn stop(&self, app: &AppHandle, binding_id: &str, _shortcut_str: &str) {
         let stop_time = Instant::now();
         debug!("TranscribeAction::stop called for binding: {}", binding_id);
+        let pipeline = pipeline_trace::take_pipeline();
 
-        let ah = app.clone();
-        let rm = Arc::clone(&app.state::<Arc<AudioRecordingManager>>());
-        let tm = Arc::clone(&app.state::<Arc<TranscriptionManager>>());
-        let hm = Arc::clone(&app.state::<Arc<HistoryManager>>());
-        let tts_manager = Arc::clone(&app.state::<Arc<TtsManager>>());
+        if discard_stop(app, binding_id, &pipeline) {
+            return;
+        }
 
         change_tray_icon(app, TrayIconState::Transcribing);
         show_transcribing_overlay(app);
 
         // Unmute before playing audio feedback so the stop sound is audible
-        rm.remove_mute();
+        app.state::<Arc<AudioRecordingManager>>().remove_mute();
 
         // Play audio feedback for recording stop
         play_feedback_sound(app, SoundType::Stop);
-
-        let binding_id = binding_id.to_string(); // Clone binding_id for the async task
-        let rm_for_task = Arc::clone(&rm);
+        accessibility::announce(app, "Recording stopped, transcribing");
 
         // Capture current generation to detect staleness
         let gen = OPERATION_GENERATION.load(Ordering::SeqCst);
@@ -554,171 +1023,12 @@ impl ShortcutAction for TranscribeAction {
             }
         }
 
-        let handle = tauri::async_runtime::spawn(async move {
-            let binding_id = binding_id.clone(); // Clone for the inner async task
-            debug!(
-                "Starting async transcription task for binding: {}",
-                binding_id
-            );
-
-            let stop_recording_time = Instant::now();
-            if let Some(samples) = rm_for_task.stop_recording(&binding_id) {
-                debug!(
-                    "Recording stopped and samples retrieved in {:?}, sample count: {} ({:.1}s audio)",
-                    stop_recording_time.elapsed(),
-                    samples.len(),
-                    samples.len() as f32 / 16000.0
-                );
-
-                // Final transcription: transcribe ALL audio for complete result
-                // (streaming preview is limited, but final result is complete)
-                let transcription_time = Instant::now();
-                let samples_clone = samples.clone(); // Clone full samples for history saving
-
-                match tm.transcribe(samples) {
-                    Ok(transcription) => {
-                        debug!(
-                            "Transcription completed in {:?}: '{}'",
-                            transcription_time.elapsed(),
-                            transcription
-                        );
-                        if !transcription.is_empty() {
-                            let settings = get_settings(&ah);
-                            let mut final_text = transcription.clone();
-                            let mut post_processed_text: Option<String> = None;
-                            let mut post_process_prompt: Option<String> = None;
-
-                            if let Some(converted_text) =
-                                maybe_convert_chinese_variant(&settings, &transcription).await
-                            {
-                                final_text = converted_text.clone();
-                                post_processed_text = Some(converted_text);
-                            } else {
-                                match maybe_post_process_transcription(&ah, &settings, &transcription).await {
-                                    PostProcessOutcome::Text(processed_text) => {
-                                        final_text = processed_text.clone();
-                                        post_processed_text = Some(processed_text);
-
-                                        // Get the prompt that was used
-                                        if let Some(prompt_id) = &settings.post_process_selected_prompt_id {
-                                            if let Some(prompt) = settings
-                                                .post_process_prompts
-                                                .iter()
-                                                .find(|p| &p.id == prompt_id)
-                                            {
-                                                post_process_prompt = Some(prompt.prompt.clone());
-                                            }
-                                        }
-                                    }
-                                    PostProcessOutcome::ToolExecuted(message) => {
-                                        // Save to history (original transcription only)
-                                        let hm_clone = Arc::clone(&hm);
-                                        let transcription_for_history = transcription.clone();
-                                        tauri::async_runtime::spawn(async move {
-                                            if let Err(e) = hm_clone
-                                                .save_transcription(
-                                                    samples_clone,
-                                                    transcription_for_history,
-                                                    None,
-                                                    None,
-                                                )
-                                                .await
-                                            {
-                                                error!("Failed to save transcription to history: {}", e);
-                                            }
-                                        });
-
-                                        // Show tool result in overlay, do NOT paste
-                                        if OPERATION_GENERATION.load(Ordering::SeqCst) == gen {
-                                            show_tool_overlay(&ah, &message);
-                                            change_tray_icon(&ah, TrayIconState::Idle);
-                                        }
-                                        return;
-                                    }
-                                    PostProcessOutcome::Empty => {
-                                        // No-op, original transcription used as final_text
-                                    }
-                                }
-                            }
-
-                            // Trigger TTS if enabled and post-processing was successful
-                            if settings.tts_enabled && post_processed_text.is_some() {
-                                let tts_manager_clone = tts_manager.clone();
-                                let text_to_speak = final_text.clone();
-                                info!("Triggering TTS with text: {}", text_to_speak);
-                                std::thread::spawn(move || {
-                                    if let Err(e) = tts_manager_clone.speak(&text_to_speak) {
-                                        error!("TTS failed: {}", e);
-                                    }
-                                });
-                            }
-
-                            // Save to history with post-processed text and prompt
-                            let hm_clone = Arc::clone(&hm);
-                            let transcription_for_history = transcription.clone();
-                            tauri::async_runtime::spawn(async move {
-                                if let Err(e) = hm_clone
-                                    .save_transcription(
-                                        samples_clone,
-                                        transcription_for_history,
-                                        post_processed_text,
-                                        post_process_prompt,
-                                    )
-                                    .await
-                                {
-                                    error!("Failed to save transcription to history: {}", e);
-                                }
-                            });
-
-                            // Check if this operation is still current before pasting
-                            if OPERATION_GENERATION.load(Ordering::SeqCst) != gen {
-                                debug!("Operation became stale during transcription, skipping paste");
-                                return;
-                            }
-
-                            // Paste the final text (either processed or original)
-                            let ah_clone = ah.clone();
-                            let paste_time = Instant::now();
-                            ah.run_on_main_thread(move || {
-                                match utils::paste(final_text, ah_clone.clone()) {
-                                    Ok(()) => debug!(
-                                        "Text pasted successfully in {:?}",
-                                        paste_time.elapsed()
-                                    ),
-                                    Err(e) => error!("Failed to paste transcription: {}", e),
-                                }
-                                // Hide the overlay after transcription is complete
-                                utils::hide_recording_overlay(&ah_clone);
-                                change_tray_icon(&ah_clone, TrayIconState::Idle);
-                            })
-                            .unwrap_or_else(|e| {
-                                error!("Failed to run paste on main thread: {:?}", e);
-                                if OPERATION_GENERATION.load(Ordering::SeqCst) == gen {
-                                    utils::hide_recording_overlay(&ah);
-                                    change_tray_icon(&ah, TrayIconState::Idle);
-                                }
-                            });
-                        } else if OPERATION_GENERATION.load(Ordering::SeqCst) == gen {
-                            utils::hide_recording_overlay(&ah);
-                            change_tray_icon(&ah, TrayIconState::Idle);
-                        }
-                    }
-                    Err(err) => {
-                        debug!("Global Shortcut Transcription error: {}", err);
-                        if OPERATION_GENERATION.load(Ordering::SeqCst) == gen {
-                            utils::hide_recording_overlay(&ah);
-                            change_tray_icon(&ah, TrayIconState::Idle);
-                        }
-                    }
-                }
-            } else {
-                debug!("No samples retrieved from recording stop");
-                if OPERATION_GENERATION.load(Ordering::SeqCst) == gen {
-                    utils::hide_recording_overlay(&ah);
-                    change_tray_icon(&ah, TrayIconState::Idle);
-                }
-            }
-        });
+        let handle = tauri::async_runtime::spawn(transcribe_recording(
+            app.clone(),
+            binding_id.to_string(),
+            gen,
+            pipeline,
+        ));
 
         // Store the new task handle for potential abortion
         if let Ok(mut task) = TRANSCRIPTION_TASK.lock() {
@@ -732,6 +1042,436 @@ impl ShortcutAction for TranscribeAction {
     }
 }
 
+/// Drop a stop that leaves nothing to transcribe: one during the countdown,
+/// or the end of an accidental tap. Returns whether it was dropped.
+fn discard_stop(app: &AppHandle, binding_id: &str, pipeline: &tracing::Span) -> bool {
+    let rm = app.state::<Arc<AudioRecordingManager>>();
+    if rm.take_pending_binding(binding_id) {
+        debug!("Stop requested during countdown, recording never started");
+        pipeline.record("outcome", "cancelled");
+        utils::hide_recording_overlay(app);
+        change_tray_icon(app, TrayIconState::Idle);
+        return true;
+    }
+
+    // Discard accidental taps before they reach the transcription pipeline
+    if !rm.is_recording_binding(binding_id) {
+        return false;
+    }
+    let min_duration_ms = get_settings(app).min_recording_duration_ms;
+    let Some(elapsed) = rm.recording_duration() else {
+        return false;
+    };
+    if !is_accidental_tap(elapsed, min_duration_ms) {
+        return false;
+    }
+    debug!(
+        "Discarding {:?} recording (minimum {}ms)",
+        elapsed, min_duration_ms
+    );
+    rm.cancel_recording();
+    pipeline.record("outcome", "discarded");
+    change_tray_icon(app, TrayIconState::Idle);
+    show_warning_overlay(app, "Recording too short");
+    true
+}
+
+/// Reset the overlay and tray after a dictation that ends without a paste,
+/// unless a newer operation has taken over.
+fn end_without_paste(app: &AppHandle, pipeline: &tracing::Span, outcome: &str, gen: u64) {
+    pipeline.record("outcome", outcome);
+    if OPERATION_GENERATION.load(Ordering::SeqCst) == gen {
+        utils::hide_recording_overlay(app);
+        change_tray_icon(app, TrayIconState::Idle);
+    }
+}
+
+/// Show what a voice command or tool did in place of a paste, unless a newer
+/// operation has taken over.
+fn show_tool_result(app: &AppHandle, gen: u64, message: &str) {
+    if OPERATION_GENERATION.load(Ordering::SeqCst) == gen {
+        show_tool_overlay(app, message);
+        change_tray_icon(app, TrayIconState::Idle);
+    }
+}
+
+/// Stop `binding_id`'s recording, transcribe it and deliver the transcript.
+async fn transcribe_recording(
+    ah: AppHandle,
+    binding_id: String,
+    gen: u64,
+    pipeline: tracing::Span,
+) {
+    debug!(
+        "Starting async transcription task for binding: {}",
+        binding_id
+    );
+    let rm = Arc::clone(&ah.state::<Arc<AudioRecordingManager>>());
+    let Some((recording, source)) = take_recording(&rm, &binding_id) else {
+        debug!("No samples retrieved from recording stop");
+        end_without_paste(&ah, &pipeline, "no_audio", gen);
+        return;
+    };
+
+    // The transcriber hears the dictation without room noise and
+    // background voices; history keeps it as recorded
+    let samples = prepare_for_transcription(&ah, recording.clone());
+    let transcript = match transcribe_dictation(&ah, &samples, gen, &pipeline) {
+        Ok(transcript) => transcript,
+        Err(err) => {
+            debug!("Global Shortcut Transcription error: {}", err);
+            end_without_paste(&ah, &pipeline, "error", gen);
+            return;
+        }
+    };
+
+    let Some(transcript) = apply_commands(&ah, transcript, gen, &pipeline).await else {
+        return;
+    };
+    // Hold dictations that look misheard until the user decides
+    if hold_low_confidence(
+        &ah,
+        &binding_id,
+        &transcript,
+        &samples,
+        &recording,
+        &source,
+        gen,
+    ) {
+        pipeline.record("outcome", "low_confidence");
+        return;
+    }
+    finish_transcription(ah, transcript, recording, source, gen, pipeline).await;
+}
+
+/// Stop `binding_id`'s recording, returning its audio and where it came from.
+fn take_recording(
+    rm: &AudioRecordingManager,
+    binding_id: &str,
+) -> Option<(Vec<f32>, RecordingSource)> {
+    let stop_recording_time = Instant::now();
+    let samples = rm.stop_recording(binding_id)?;
+    debug!(
+        "Recording stopped and samples retrieved in {:?}, sample count: {} ({:.1}s audio)",
+        stop_recording_time.elapsed(),
+        samples.len(),
+        samples.len() as f32 / 16000.0
+    );
+    Some((samples, rm.recording_source()))
+}
+
+/// Final transcription of a prepared dictation: the text live previews
+/// already committed is kept, and only the rest of the audio is transcribed.
+/// Falls back to another model if the loaded one fails.
+fn transcribe_dictation(
+    app: &AppHandle,
+    samples: &[f32],
+    gen: u64,
+    pipeline: &tracing::Span,
+) -> anyhow::Result<Transcript> {
+    let tm = app.state::<Arc<TranscriptionManager>>();
+    let settings = get_settings(app);
+    // Partials were transcribed from the unprocessed audio, so they only
+    // line up with the recording if nothing was taken out
+    let reuse_partials = settings.active_noise_profile.is_none() && !settings.voice_filter_enabled;
+    let prompt = take_initial_prompt(gen);
+
+    let transcription_time = Instant::now();
+    let transcript =
+        tracing::info_span!(parent: pipeline, "transcription", samples = samples.len())
+            .in_scope(|| tm.transcribe_final(samples.to_vec(), gen, reuse_partials, prompt.clone()))
+            .or_else(|err| retry_with_fallback_model(app, &tm, samples, prompt, err))?;
+    debug!(
+        "Transcription completed in {:?}: '{}'",
+        transcription_time.elapsed(),
+        transcript.text
+    );
+    Ok(transcript)
+}
+
+/// Run a dictation that is a voice command or a prompt switch, and apply the
+/// dictation commands in any other. Returns `None` when nothing is left to
+/// deliver.
+async fn apply_commands(
+    app: &AppHandle,
+    transcript: Transcript,
+    gen: u64,
+    pipeline: &tracing::Span,
+) -> Option<Transcript> {
+    // A whole dictation like "open terminal" runs an action
+    if let Some(message) = run_voice_command(app, &transcript.text).await {
+        pipeline.record("outcome", "voice_command");
+        show_tool_result(app, gen, &message);
+        return None;
+    }
+    let transcript = apply_dictation_commands(app, transcript);
+    if transcript.text.is_empty() {
+        end_without_paste(app, pipeline, "empty", gen);
+        return None;
+    }
+    emit_transcription_language(app, &transcript);
+
+    // "echo, formal mode. …" switches the prompt before post-processing
+    match apply_prompt_command(app, &transcript.text) {
+        Some((prompt_name, rest)) if rest.is_empty() => {
+            pipeline.record("outcome", "prompt_switch");
+            show_tool_result(app, gen, &format!("Prompt: {}", prompt_name));
+            None
+        }
+        // The timestamps covered the command too
+        Some((_, rest)) => Some(Transcript {
+            text: rest,
+            segments: Vec::new(),
+            language: transcript.language,
+        }),
+        None => Some(transcript),
+    }
+}
+
+/// `text` after the enabled text plugins, run off the async runtime since
+/// they block until done.
+async fn run_text_plugins(
+    app: &AppHandle,
+    settings: &AppSettings,
+    text: &str,
+    transcript: &Transcript,
+) -> String {
+    let Some(plugins) = app.try_state::<Arc<TextPluginManager>>() else {
+        return text.to_string();
+    };
+    let plugins = Arc::clone(plugins.inner());
+    let settings = settings.clone();
+    let language = transcript.language.clone();
+    let input = text.to_string();
+    let transformed = tauri::async_runtime::spawn_blocking(move || {
+        plugins.transform(&settings, &input, language.as_deref())
+    })
+    .await
+    .unwrap_or_else(|e| {
+        warn!("Text plugins failed: {}", e);
+        None
+    });
+    transformed.unwrap_or_else(|| text.to_string())
+}
+
+/// Post-process, save and deliver a transcription: fill a template slot,
+/// append to the scratchpad or paste into the focused app. `recording` is
+/// saved to history as recorded, and the transcript's scored timestamps with
+/// it so probable errors can be highlighted.
+pub(crate) async fn finish_transcription(
+    ah: AppHandle,
+    mut transcript: Transcript,
+    recording: Vec<f32>,
+    source: RecordingSource,
+    gen: u64,
+    pipeline: tracing::Span,
+) {
+    let hm = Arc::clone(&ah.state::<Arc<HistoryManager>>());
+    let settings = get_settings(&ah);
+    let continues = CONTINUED_GENERATION.load(Ordering::SeqCst) == gen;
+    let (outcome, post_process_prompt) =
+        process_text(&ah, &settings, &mut transcript, &pipeline).await;
+    let post_processed_text = match outcome {
+        PostProcessOutcome::Text(processed_text) => Some(processed_text),
+        // Only the original transcription is saved, and the tool's result
+        // shown instead of pasted
+        PostProcessOutcome::ToolExecuted(message) => {
+            pipeline.record("outcome", "tool");
+            let save = save_history_entry(hm, recording, transcript, None, None, source, false);
+            spawn_history_save(&settings, &pipeline, save);
+            show_tool_result(&ah, gen, &message);
+            return;
+        }
+        PostProcessOutcome::Empty => None,
+    };
+    // Enabled WebAssembly plugins transform only the text that's pasted;
+    // history and read-back keep the post-processed text
+    let pasted = post_processed_text.as_deref().unwrap_or(&transcript.text);
+    let final_text = run_text_plugins(&ah, &settings, pasted, &transcript)
+        .instrument(tracing::info_span!(parent: &pipeline, "text_plugins"))
+        .await;
+
+    read_back(&ah, &settings, post_processed_text.clone());
+
+    // Save to history with post-processed text and prompt
+    let save = save_history_entry(
+        hm,
+        recording,
+        transcript,
+        post_processed_text,
+        post_process_prompt,
+        source,
+        continues,
+    );
+    spawn_history_save(&settings, &pipeline, save);
+    deliver(ah, final_text, continues, gen, pipeline);
+}
+
+/// Correct `transcript` with the frontmost app's custom words, then convert
+/// it to the selected Chinese variant or else run it through
+/// post-processing, which the user can skip while it's in flight. Returns
+/// the outcome and the post-processing prompt used.
+async fn process_text(
+    ah: &AppHandle,
+    settings: &AppSettings,
+    transcript: &mut Transcript,
+    pipeline: &tracing::Span,
+) -> (PostProcessOutcome, Option<String>) {
+    if let Some(corrected) = apply_app_custom_words(settings, &transcript.text) {
+        transcript.text = corrected;
+    }
+    if let Some(converted_text) = maybe_convert_chinese_variant(settings, &transcript.text)
+        .instrument(tracing::info_span!(parent: pipeline, "chinese_conversion"))
+        .await
+    {
+        return (PostProcessOutcome::Text(converted_text), None);
+    }
+
+    let post_process = maybe_post_process_transcription(
+        ah,
+        settings,
+        &transcript.text,
+        transcript.language.as_deref(),
+    )
+    .instrument(tracing::info_span!(parent: pipeline, "post_process"));
+    let outcome = if settings.post_process_enabled {
+        // Listen before marking it in flight so no skip is missed
+        let skipped = POST_PROCESS_SKIP.notified();
+        let _in_flight = PostProcessInFlight::start(ah);
+        match future::select(std::pin::pin!(post_process), std::pin::pin!(skipped)).await {
+            Either::Left((outcome, _)) => outcome,
+            // Dropping the post-processing future aborts the LLM call
+            Either::Right(_) => PostProcessOutcome::Empty,
+        }
+    } else {
+        post_process.await
+    };
+
+    // Get the prompt that was used
+    let prompt = match &outcome {
+        PostProcessOutcome::Text(_) => settings
+            .post_process_selected_prompt_id
+            .as_ref()
+            .and_then(|id| settings.post_process_prompts.iter().find(|p| &p.id == id))
+            .map(|p| p.prompt.clone()),
+        _ => None,
+    };
+    (outcome, prompt)
+}
+
+/// Save a dictation to history in the background, unless in privacy mode.
+fn spawn_history_save(
+    settings: &AppSettings,
+    pipeline: &tracing::Span,
+    save: impl std::future::Future<Output = ()> + Send + 'static,
+) {
+    if settings.privacy_mode {
+        return;
+    }
+    let history_span = tracing::info_span!(parent: pipeline, "save_history");
+    tauri::async_runtime::spawn(save.instrument(history_span));
+}
+
+/// Read the post-processed text back with the configured voice, off the
+/// async runtime, if TTS is enabled and post-processing was successful.
+fn read_back(app: &AppHandle, settings: &AppSettings, post_processed_text: Option<String>) {
+    let Some(text) = post_processed_text.filter(|_| settings.tts_enabled) else {
+        return;
+    };
+    let tts_manager = Arc::clone(&app.state::<Arc<TtsManager>>());
+    let pronunciations = settings.tts_pronunciations.clone();
+    info!("Triggering TTS with text: {}", text);
+    std::thread::spawn(move || {
+        if let Err(e) = tts_manager.speak(&text, &pronunciations) {
+            error!("TTS failed: {}", e);
+        }
+    });
+}
+
+/// Hand the final text to the template in progress or the open scratchpad,
+/// or else paste it, unless the operation went stale meanwhile. `continues`
+/// is whether it follows the text of the last dictation.
+fn deliver(ah: AppHandle, mut text: String, continues: bool, gen: u64, pipeline: tracing::Span) {
+    // Check if this operation is still current before pasting
+    if OPERATION_GENERATION.load(Ordering::SeqCst) != gen {
+        debug!("Operation became stale during transcription, skipping paste");
+        pipeline.record("outcome", "stale");
+        return;
+    }
+
+    // While a template is in progress, dictations fill its slots and
+    // only the assembled text is pasted
+    if let Some(templates) = ah.try_state::<Arc<DictationTemplateManager>>() {
+        match templates.fill(&text) {
+            Some(SlotFill::Next(progress)) => {
+                pipeline.record("outcome", "template_slot");
+                show_tool_overlay(
+                    &ah,
+                    &format!(
+                        "Next: {} ({}/{})",
+                        progress.slot,
+                        progress.index + 1,
+                        progress.total
+                    ),
+                );
+                change_tray_icon(&ah, TrayIconState::Idle);
+                return;
+            }
+            Some(SlotFill::Complete(assembled)) => text = assembled,
+            None => {}
+        }
+    }
+
+    // While the scratchpad is open, dictations accumulate there instead
+    if let Some(scratchpad) = ah.try_state::<Arc<ScratchpadManager>>() {
+        if scratchpad.is_active() {
+            pipeline.record("outcome", "scratchpad");
+            scratchpad.append(&text);
+            utils::hide_recording_overlay(&ah);
+            change_tray_icon(&ah, TrayIconState::Idle);
+            return;
+        }
+    }
+
+    // Only the new part is pasted, after the text already in place
+    if continues && !text.starts_with(char::is_whitespace) {
+        text.insert(0, ' ');
+    }
+    paste_transcription(ah, text, gen, pipeline);
+}
+
+/// Paste the final text (either processed or original) into the focused app.
+fn paste_transcription(ah: AppHandle, text: String, gen: u64, pipeline: tracing::Span) {
+    pipeline.record("outcome", "pasted");
+    let paste_span = tracing::info_span!(
+        parent: &pipeline,
+        "paste",
+        chars = text.chars().count()
+    );
+    let ah_clone = ah.clone();
+    let paste_time = Instant::now();
+    ah.run_on_main_thread(move || {
+        let _paste = paste_span.enter();
+        match utils::paste(text, ah_clone.clone()) {
+            Ok(()) => {
+                debug!("Text pasted successfully in {:?}", paste_time.elapsed());
+                accessibility::announce(&ah_clone, "Transcription pasted");
+            }
+            Err(e) => error!("Failed to paste transcription: {}", e),
+        }
+        // Hide the overlay after transcription is complete
+        utils::hide_recording_overlay(&ah_clone);
+        change_tray_icon(&ah_clone, TrayIconState::Idle);
+    })
+    .unwrap_or_else(|e| {
+        error!("Failed to run paste on main thread: {:?}", e);
+        if OPERATION_GENERATION.load(Ordering::SeqCst) == gen {
+            utils::hide_recording_overlay(&ah);
+            change_tray_icon(&ah, TrayIconState::Idle);
+        }
+    });
+}
+
 // Test Action
 struct TestAction;
 
@@ -755,6 +1495,148 @@ impl ShortcutAction for TestAction {
     }
 }
 
+/// What happens to a toggle press.
+#[derive(Debug, PartialEq, Eq)]
+enum Press {
+    /// Start or stop now.
+    Run,
+    /// Another start or stop is under way; run after it.
+    Queued,
+    /// Key bounce.
+    Ignored,
+}
+
+/// Serializes toggle transitions. A stop only ends once the transcription
+/// task has taken the recording, so a press in between would otherwise stop
+/// it a second time and interleave with the next start.
+#[derive(Default)]
+struct ToggleGate {
+    busy: bool,
+    /// Presses that arrived during the transition. Two toggles cancel out,
+    /// so only whether there was an odd number of them matters.
+    queued: usize,
+    last_press: Option<Instant>,
+}
+
+impl ToggleGate {
+    fn press(&mut self, now: Instant) -> Press {
+        if self
+            .last_press
+            .is_some_and(|last| now.duration_since(last) < MIN_PRESS_INTERVAL)
+        {
+            return Press::Ignored;
+        }
+        self.last_press = Some(now);
+        if self.busy {
+            self.queued += 1;
+            return Press::Queued;
+        }
+        self.busy = true;
+        Press::Run
+    }
+
+    /// End the running transition. Returns true if a queued toggle is owed,
+    /// in which case the gate stays closed for it.
+    fn finish(&mut self) -> bool {
+        let again = self.queued % 2 == 1;
+        self.queued = 0;
+        self.busy = again;
+        again
+    }
+}
+
+/// A gate per binding, so a press of one shortcut is neither debounced nor
+/// queued by another's.
+#[derive(Default)]
+struct ToggleGates(HashMap<String, ToggleGate>);
+
+impl ToggleGates {
+    fn press(&mut self, binding_id: &str, now: Instant) -> Press {
+        self.0.entry(binding_id.to_string()).or_default().press(now)
+    }
+
+    fn finish(&mut self, binding_id: &str) -> bool {
+        self.0.get_mut(binding_id).is_some_and(ToggleGate::finish)
+    }
+}
+
+static TOGGLE_GATES: Lazy<Mutex<ToggleGates>> = Lazy::new(|| Mutex::new(ToggleGates::default()));
+
+/// Toggle-mode dispatch shared by every shortcut backend: stop the binding if
+/// the recording manager reports it active, otherwise start it. Presses that
+/// arrive while a start or stop is still settling are queued behind it.
+pub fn toggle_binding(app: &AppHandle, binding_id: &str, shortcut_str: &str) {
+    let Some(action) = ACTION_MAP.get(binding_id) else {
+        warn!(
+            "No action defined in ACTION_MAP for binding ID '{}'",
+            binding_id
+        );
+        return;
+    };
+
+    let press = TOGGLE_GATES
+        .lock()
+        .unwrap()
+        .press(binding_id, Instant::now());
+    match press {
+        Press::Run => {}
+        Press::Queued => {
+            debug!(
+                "Press for '{}' queued behind the running toggle",
+                binding_id
+            );
+            return;
+        }
+        Press::Ignored => {
+            debug!("Ignoring bounced press for '{}'", binding_id);
+            return;
+        }
+    }
+    run_toggle(
+        app.clone(),
+        Arc::clone(action),
+        binding_id.to_string(),
+        shortcut_str.to_string(),
+    );
+}
+
+/// Start or stop `binding_id` and hold the gate until that has settled.
+fn run_toggle(
+    app: AppHandle,
+    action: Arc<dyn ShortcutAction>,
+    binding_id: String,
+    shortcut_str: String,
+) {
+    let rm = Arc::clone(&app.state::<Arc<AudioRecordingManager>>());
+    if !rm.is_binding_active(&binding_id) {
+        action.start(&app, &binding_id, &shortcut_str);
+        finish_toggle(app, action, binding_id, shortcut_str);
+        return;
+    }
+
+    action.stop(&app, &binding_id, &shortcut_str);
+    // The transcription task stops the recording, after stop() has returned
+    std::thread::spawn(move || {
+        let deadline = Instant::now() + STOP_SETTLE_TIMEOUT;
+        while rm.is_binding_active(&binding_id) && Instant::now() < deadline {
+            std::thread::sleep(STOP_SETTLE_POLL);
+        }
+        finish_toggle(app, action, binding_id, shortcut_str);
+    });
+}
+
+fn finish_toggle(
+    app: AppHandle,
+    action: Arc<dyn ShortcutAction>,
+    binding_id: String,
+    shortcut_str: String,
+) {
+    if TOGGLE_GATES.lock().unwrap().finish(&binding_id) {
+        debug!("Running the press queued for '{}'", binding_id);
+        run_toggle(app, action, binding_id, shortcut_str);
+    }
+}
+
 // Static Action Map
 pub static ACTION_MAP: Lazy<HashMap<String, Arc<dyn ShortcutAction>>> = Lazy::new(|| {
     let mut map = HashMap::new();
@@ -762,6 +1644,10 @@ pub static ACTION_MAP: Lazy<HashMap<String, Arc<dyn ShortcutAction>>> = Lazy::ne
         "transcribe".to_string(),
         Arc::new(TranscribeAction) as Arc<dyn ShortcutAction>,
     );
+    map.insert(
+        CONTINUE_BINDING.to_string(),
+        Arc::new(TranscribeAction) as Arc<dyn ShortcutAction>,
+    );
     map.insert(
         "test".to_string(),
         Arc::new(TestAction) as Arc<dyn ShortcutAction>,
@@ -873,6 +1759,49 @@ mod tests {
         );
     }
 
+    #[test]
+    fn short_recordings_are_accidental_taps() {
+        assert!(is_accidental_tap(Duration::from_millis(120), 300));
+        assert!(!is_accidental_tap(Duration::from_millis(300), 300));
+        assert!(!is_accidental_tap(Duration::from_millis(120), 0));
+    }
+
+    #[test]
+    fn toggle_presses_are_serialized() {
+        let mut gate = ToggleGate::default();
+        let t0 = Instant::now();
+        assert_eq!(gate.press(t0), Press::Run);
+        assert_eq!(gate.press(t0 + Duration::from_millis(50)), Press::Ignored);
+        assert_eq!(gate.press(t0 + Duration::from_millis(200)), Press::Queued);
+        assert_eq!(gate.press(t0 + Duration::from_millis(400)), Press::Queued);
+        // Two queued presses cancel out
+        assert!(!gate.finish());
+        assert_eq!(gate.press(t0 + Duration::from_millis(600)), Press::Run);
+        assert_eq!(gate.press(t0 + Duration::from_millis(800)), Press::Queued);
+        assert!(gate.finish());
+        assert_eq!(gate.press(t0 + Duration::from_millis(1000)), Press::Queued);
+        assert!(gate.finish());
+        assert!(!gate.finish());
+    }
+
+    #[test]
+    fn toggle_gates_are_per_binding() {
+        let mut gates = ToggleGates::default();
+        let t0 = Instant::now();
+        assert_eq!(gates.press("transcribe", t0), Press::Run);
+        // Neither a bounce nor queued behind the other binding
+        assert_eq!(
+            gates.press(CONTINUE_BINDING, t0 + Duration::from_millis(50)),
+            Press::Run
+        );
+        assert_eq!(
+            gates.press("transcribe", t0 + Duration::from_millis(200)),
+            Press::Queued
+        );
+        assert!(!gates.finish(CONTINUE_BINDING));
+        assert!(gates.finish("transcribe"));
+    }
+
     #[test]
     fn post_process_outcome_variants() {
         // Verify PostProcessOutcome can be constructed
@@ -1,3 +1,4 @@
+use crate::helpers::{focus_mode, haptics};
 use crate::settings::SoundTheme;
 use crate::settings::{self, AppSettings};
 use cpal::traits::{DeviceTrait, HostTrait};
@@ -9,11 +10,30 @@ use std::path::{Path, PathBuf};
 use std::thread;
 use tauri::{AppHandle, Manager};
 
+#[derive(Clone, Copy)]
 pub enum SoundType {
     Start,
     Stop,
 }
 
+impl SoundType {
+    pub fn from_id(id: &str) -> Option<Self> {
+        match id {
+            "start" => Some(SoundType::Start),
+            "stop" => Some(SoundType::Stop),
+            _ => None,
+        }
+    }
+
+    /// Key in the `feedback_sound_devices` setting.
+    pub fn id(self) -> &'static str {
+        match self {
+            SoundType::Start => "start",
+            SoundType::Stop => "stop",
+        }
+    }
+}
+
 fn resolve_sound_path(
     app: &AppHandle,
     settings: &AppSettings,
@@ -47,57 +67,89 @@ fn get_sound_base_dir(settings: &AppSettings) -> tauri::path::BaseDirectory {
     }
 }
 
+/// Flash the overlay and tap the trackpad, if enabled, alongside (or instead
+/// of) the sound.
+fn play_silent_feedback(app: &AppHandle, settings: &AppSettings) {
+    if settings.visual_feedback {
+        crate::overlay::flash_overlay(app);
+    }
+    if settings.haptic_feedback {
+        haptics::tap();
+    }
+}
+
 pub fn play_feedback_sound(app: &AppHandle, sound_type: SoundType) {
     let settings = settings::get_settings(app);
+    if focus_mode::should_suppress_feedback(&settings) {
+        return;
+    }
+    play_silent_feedback(app, &settings);
     if !settings.audio_feedback {
         return;
     }
 
     if let Some(path) = resolve_sound_path(app, &settings, sound_type) {
-        play_sound_async(app, path);
+        play_sound_async(app, path, sound_type);
     }
 }
 
 pub fn play_feedback_sound_blocking(app: &AppHandle, sound_type: SoundType) {
     let settings = settings::get_settings(app);
+    if focus_mode::should_suppress_feedback(&settings) {
+        return;
+    }
+    play_silent_feedback(app, &settings);
     if !settings.audio_feedback {
         return;
     }
 
     if let Some(path) = resolve_sound_path(app, &settings, sound_type) {
-        play_sound_blocking(app, &path);
+        play_sound_blocking(app, &path, sound_type);
     }
 }
 
 pub fn play_test_sound(app: &AppHandle, sound_type: SoundType) {
     let settings = settings::get_settings(app);
     if let Some(path) = resolve_sound_path(app, &settings, sound_type) {
-        play_sound_async(app, path);
+        play_sound_async(app, path, sound_type);
     }
 }
 
-fn play_sound_async(app: &AppHandle, path: PathBuf) {
+fn play_sound_async(app: &AppHandle, path: PathBuf, sound_type: SoundType) {
     let app_handle = app.clone();
     thread::spawn(move || {
-        if let Err(e) = play_sound_at_path(&app_handle, path.as_path()) {
+        if let Err(e) = play_sound_at_path(&app_handle, path.as_path(), sound_type) {
             error!("Failed to play sound '{}': {}", path.display(), e);
         }
     });
 }
 
-fn play_sound_blocking(app: &AppHandle, path: &Path) {
-    if let Err(e) = play_sound_at_path(app, path) {
+fn play_sound_blocking(app: &AppHandle, path: &Path, sound_type: SoundType) {
+    if let Err(e) = play_sound_at_path(app, path, sound_type) {
         error!("Failed to play sound '{}': {}", path.display(), e);
     }
 }
 
-fn play_sound_at_path(app: &AppHandle, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+fn play_sound_at_path(
+    app: &AppHandle,
+    path: &Path,
+    sound_type: SoundType,
+) -> Result<(), Box<dyn std::error::Error>> {
     let settings = settings::get_settings(app);
     let volume = settings.audio_feedback_volume;
-    let selected_device = settings.selected_output_device.clone();
+    let selected_device = output_device_for(&settings, sound_type);
     play_audio_file(path, selected_device, volume)
 }
 
+/// The sound's own output device if one is set, else the feedback output device.
+fn output_device_for(settings: &AppSettings, sound_type: SoundType) -> Option<String> {
+    settings
+        .feedback_sound_devices
+        .get(sound_type.id())
+        .cloned()
+        .or_else(|| settings.selected_output_device.clone())
+}
+
 fn play_audio_file(
     path: &Path,
     selected_device: Option<String>,
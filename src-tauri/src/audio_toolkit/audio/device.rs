@@ -1,5 +1,13 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
 use cpal::traits::{DeviceTrait, HostTrait};
 
+/// How often the hotplug watcher lists the input devices. cpal has no
+/// device events, so plugging in and out is noticed by polling.
+const HOTPLUG_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
 pub struct CpalDeviceInfo {
     pub index: String,
     pub name: String,
@@ -50,3 +58,101 @@ pub fn list_output_devices() -> Result<Vec<CpalDeviceInfo>, Box<dyn std::error::
 
     Ok(out)
 }
+
+/// Input devices that appeared or disappeared between two listings.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct DeviceChanges {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+}
+
+impl DeviceChanges {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Names in `after` but not `before`, and the other way round.
+pub fn diff_devices(before: &[String], after: &[String]) -> DeviceChanges {
+    DeviceChanges {
+        added: after
+            .iter()
+            .filter(|name| !before.contains(name))
+            .cloned()
+            .collect(),
+        removed: before
+            .iter()
+            .filter(|name| !after.contains(name))
+            .cloned()
+            .collect(),
+    }
+}
+
+/// A running hotplug watcher. Dropping it stops the polling.
+pub struct HotplugWatcher {
+    stopped: Arc<AtomicBool>,
+}
+
+impl Drop for HotplugWatcher {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::SeqCst);
+    }
+}
+
+/// Call `on_change` from a background thread whenever an input device is
+/// plugged in or removed, like AirPods connecting or running out of battery,
+/// until the returned watcher is dropped.
+pub fn spawn_hotplug_watcher<F>(on_change: F) -> HotplugWatcher
+where
+    F: Fn(DeviceChanges) + Send + 'static,
+{
+    let stopped = Arc::new(AtomicBool::new(false));
+    let thread_stopped = stopped.clone();
+    std::thread::spawn(move || {
+        let names = || -> Option<Vec<String>> {
+            list_input_devices()
+                .ok()
+                .map(|devices| devices.into_iter().map(|d| d.name).collect())
+        };
+        let mut known = names().unwrap_or_default();
+        loop {
+            std::thread::sleep(HOTPLUG_POLL_INTERVAL);
+            if thread_stopped.load(Ordering::SeqCst) {
+                break;
+            }
+            // A failed listing says nothing about which devices went away
+            let Some(current) = names() else {
+                continue;
+            };
+            let changes = diff_devices(&known, &current);
+            known = current;
+            if !changes.is_empty() {
+                on_change(changes);
+            }
+        }
+    });
+    HotplugWatcher { stopped }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(list: &[&str]) -> Vec<String> {
+        list.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn reports_plugged_and_unplugged_devices() {
+        let before = names(&["MacBook Pro Microphone", "AirPods Pro"]);
+        let after = names(&["MacBook Pro Microphone", "Yeti"]);
+        assert_eq!(
+            diff_devices(&before, &after),
+            DeviceChanges {
+                added: names(&["Yeti"]),
+                removed: names(&["AirPods Pro"]),
+            }
+        );
+        assert!(diff_devices(&before, &before).is_empty());
+    }
+}
@@ -0,0 +1,203 @@
+//! Recording storage formats.
+//!
+//! History recordings are 16 kHz mono. WAV keeps them as raw PCM; FLAC
+//! compresses the same samples losslessly, to roughly half the size for
+//! speech. Loading goes by the file extension, so recordings saved before a
+//! format change keep playing and transcribing.
+
+use anyhow::{anyhow, Result};
+use flacenc::component::BitRepr;
+use flacenc::error::Verify;
+use log::debug;
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+use super::decode_audio_file;
+use super::utils::{load_wav_file, save_wav_file, write_wav_samples};
+
+const SAMPLE_RATE: usize = 16000;
+const BITS_PER_SAMPLE: usize = 16;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordingFormat {
+    /// Uncompressed, readable by anything.
+    #[default]
+    Wav,
+    /// Lossless compression.
+    Flac,
+}
+
+impl RecordingFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            RecordingFormat::Wav => "wav",
+            RecordingFormat::Flac => "flac",
+        }
+    }
+
+    /// The format a recording was saved in, WAV unless it's a `.flac` file.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Self {
+        match path.as_ref().extension().and_then(|e| e.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("flac") => RecordingFormat::Flac,
+            _ => RecordingFormat::Wav,
+        }
+    }
+}
+
+/// Save 16 kHz mono samples as a recording in `format`.
+pub async fn save_recording<P: AsRef<Path>>(
+    file_path: P,
+    samples: &[f32],
+    format: RecordingFormat,
+) -> Result<()> {
+    match format {
+        RecordingFormat::Wav => save_wav_file(file_path, samples).await,
+        RecordingFormat::Flac => {
+            std::fs::write(file_path.as_ref(), encode_flac(samples)?)?;
+            debug!("Saved FLAC file: {:?}", file_path.as_ref());
+            Ok(())
+        }
+    }
+}
+
+/// Load a recording saved by [`save_recording`], whichever format it's in.
+pub fn load_recording<P: AsRef<Path>>(file_path: P) -> Result<Vec<f32>> {
+    match RecordingFormat::from_path(&file_path) {
+        RecordingFormat::Wav => load_wav_file(file_path),
+        RecordingFormat::Flac => decode_audio_file(file_path),
+    }
+}
+
+/// Add 16 kHz mono samples to the end of a recording saved by
+/// [`save_recording`]. WAV recordings are appended to in place; FLAC ones
+/// can't be, so they are decoded and encoded again. Returns how many samples
+/// the recording had before.
+pub fn append_recording<P: AsRef<Path>>(file_path: P, samples: &[f32]) -> Result<usize> {
+    let file_path = file_path.as_ref();
+    match RecordingFormat::from_path(file_path) {
+        RecordingFormat::Wav => {
+            let previous = hound::WavReader::open(file_path)?.duration() as usize;
+            let mut writer = hound::WavWriter::append(file_path)?;
+            write_wav_samples(&mut writer, samples)?;
+            writer.finalize()?;
+            debug!("Appended to WAV file: {:?}", file_path);
+            Ok(previous)
+        }
+        RecordingFormat::Flac => {
+            let mut audio = load_recording(file_path)?;
+            let previous = audio.len();
+            audio.extend_from_slice(samples);
+            std::fs::write(file_path, encode_flac(&audio)?)?;
+            debug!("Re-encoded FLAC file: {:?}", file_path);
+            Ok(previous)
+        }
+    }
+}
+
+/// Length in seconds of a recording saved by [`save_recording`], read from
+/// its header without decoding the audio.
+pub fn recording_duration_secs<P: AsRef<Path>>(file_path: P) -> Result<f64> {
+    match RecordingFormat::from_path(&file_path) {
+        RecordingFormat::Wav => {
+            let reader = hound::WavReader::open(file_path)?;
+            Ok(reader.duration() as f64 / reader.spec().sample_rate as f64)
+        }
+        RecordingFormat::Flac => flac_duration_secs(file_path.as_ref()),
+    }
+}
+
+/// Length of a FLAC stream from its STREAMINFO block, which the spec
+/// requires to come first: total samples over the sample rate.
+fn flac_duration_secs(file_path: &Path) -> Result<f64> {
+    // "fLaC", the 4-byte block header, then STREAMINFO up to the total samples
+    let mut header = [0u8; 26];
+    File::open(file_path)?.read_exact(&mut header)?;
+    if &header[..4] != b"fLaC" || header[4] & 0x7f != 0 {
+        return Err(anyhow!(
+            "{:?} doesn't start with a FLAC STREAMINFO block",
+            file_path
+        ));
+    }
+    // Sample rate (20 bits), channels (3), bits per sample (5), total samples (36)
+    let packed = u64::from_be_bytes(header[18..26].try_into()?);
+    let sample_rate = packed >> 44;
+    let total_samples = packed & ((1 << 36) - 1);
+    if sample_rate == 0 || total_samples == 0 {
+        return Err(anyhow!("{:?} doesn't record its length", file_path));
+    }
+    Ok(total_samples as f64 / sample_rate as f64)
+}
+
+/// Encode 16 kHz mono samples as a FLAC stream, at the same 16-bit depth as
+/// the WAV recordings.
+fn encode_flac(samples: &[f32]) -> Result<Vec<u8>> {
+    let pcm: Vec<i32> = samples
+        .iter()
+        .map(|sample| (sample * i16::MAX as f32) as i16 as i32)
+        .collect();
+
+    let config = flacenc::config::Encoder::default()
+        .into_verified()
+        .map_err(|(_, e)| anyhow!("Invalid FLAC encoder config: {:?}", e))?;
+    let source = flacenc::source::MemSource::from_samples(&pcm, 1, BITS_PER_SAMPLE, SAMPLE_RATE);
+    let stream = flacenc::encode_with_fixed_block_size(&config, source, config.block_size)
+        .map_err(|e| anyhow!("Failed to encode FLAC: {:?}", e))?;
+
+    let mut sink = flacenc::bitsink::ByteSink::new();
+    stream
+        .write(&mut sink)
+        .map_err(|e| anyhow!("Failed to write FLAC: {:?}", e))?;
+    Ok(sink.as_slice().to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flac_recordings_round_trip() {
+        let samples: Vec<f32> = (0..SAMPLE_RATE)
+            .map(|i| (i as f32 * 0.05).sin() * 0.5)
+            .collect();
+        let dir = std::env::temp_dir().join(format!("echo-flac-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("echo-1.flac");
+
+        std::fs::write(&path, encode_flac(&samples).unwrap()).unwrap();
+        assert_eq!(RecordingFormat::from_path(&path), RecordingFormat::Flac);
+        let loaded = load_recording(&path).unwrap();
+        let duration = recording_duration_secs(&path).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(loaded.len(), samples.len());
+        assert_eq!(duration, 1.0);
+        // Lossless up to the 16-bit quantisation WAV recordings also have
+        for (loaded, original) in loaded.iter().zip(&samples) {
+            assert!((loaded - original).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn appending_keeps_the_recording_ahead() {
+        let first = vec![0.25; SAMPLE_RATE];
+        let second = vec![-0.5; SAMPLE_RATE / 2];
+        let dir = std::env::temp_dir().join(format!("echo-append-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        for format in [RecordingFormat::Wav, RecordingFormat::Flac] {
+            let path = dir.join(format!("echo-1.{}", format.extension()));
+            tauri::async_runtime::block_on(save_recording(&path, &first, format)).unwrap();
+            let previous = append_recording(&path, &second).unwrap();
+            let loaded = load_recording(&path).unwrap();
+
+            assert_eq!(previous, first.len());
+            assert_eq!(loaded.len(), first.len() + second.len());
+            assert!((loaded[first.len() - 1] - 0.25).abs() < 1e-3);
+            assert!((loaded[first.len()] + 0.5).abs() < 1e-3);
+        }
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
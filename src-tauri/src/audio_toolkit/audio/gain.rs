@@ -0,0 +1,152 @@
+//! Input gain for quiet microphones.
+//!
+//! A fixed gain set by the user is applied first. Automatic gain control
+//! then follows the level of what is being said and brings it towards a
+//! steady target, rising slowly so a pause isn't pumped up to full volume
+//! and falling quickly so a raised voice doesn't clip.
+
+/// Range of the manual input gain.
+pub const MIN_INPUT_GAIN_DB: f32 = -12.0;
+pub const MAX_INPUT_GAIN_DB: f32 = 24.0;
+
+/// Speech level automatic gain aims for.
+const TARGET_DB: f32 = -20.0;
+/// Blocks quieter than this are silence or room noise and leave the level
+/// estimate alone.
+const GATE_DB: f32 = -55.0;
+/// Most automatic gain may add or take away.
+const MAX_BOOST_DB: f32 = 24.0;
+const MAX_CUT_DB: f32 = 12.0;
+/// How fast the level estimate follows a louder and a quieter input, as
+/// time constants in seconds.
+const ATTACK_SECS: f32 = 0.05;
+const RELEASE_SECS: f32 = 1.0;
+
+/// How the input gain is set up for a recording.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct GainSettings {
+    /// Fixed gain, in dB.
+    pub gain_db: f32,
+    /// Also level the input automatically.
+    pub automatic: bool,
+}
+
+impl GainSettings {
+    /// Whether these settings change the input at all.
+    pub fn is_active(&self) -> bool {
+        self.automatic || self.gain_db != 0.0
+    }
+}
+
+/// Gain stage of one recording, fed the raw input buffers as they arrive.
+pub struct InputGain {
+    settings: GainSettings,
+    sample_rate: f32,
+    /// Estimated level of the speech so far, in dBFS.
+    level_db: f32,
+    /// Gain applied at the end of the last buffer, so the next one ramps
+    /// from it instead of jumping.
+    last_gain: f32,
+}
+
+impl InputGain {
+    pub fn new(settings: GainSettings, sample_rate: u32) -> Self {
+        Self {
+            settings,
+            sample_rate: sample_rate as f32,
+            level_db: TARGET_DB,
+            last_gain: db_to_gain(settings.gain_db),
+        }
+    }
+
+    /// Apply the gain to `samples` in place, limiting them to full scale.
+    pub fn process(&mut self, samples: &mut [f32]) {
+        if samples.is_empty() {
+            return;
+        }
+        let manual = db_to_gain(self.settings.gain_db);
+        let gain = if self.settings.automatic {
+            let block_db = level_db(samples) + self.settings.gain_db;
+            if block_db > GATE_DB {
+                let secs = samples.len() as f32 / self.sample_rate;
+                let tau = if block_db > self.level_db {
+                    ATTACK_SECS
+                } else {
+                    RELEASE_SECS
+                };
+                self.level_db += (1.0 - (-secs / tau).exp()) * (block_db - self.level_db);
+            }
+            let automatic = (TARGET_DB - self.level_db).clamp(-MAX_CUT_DB, MAX_BOOST_DB);
+            manual * db_to_gain(automatic)
+        } else {
+            manual
+        };
+
+        let step = (gain - self.last_gain) / samples.len() as f32;
+        for (i, sample) in samples.iter_mut().enumerate() {
+            let ramped = self.last_gain + step * (i + 1) as f32;
+            *sample = (*sample * ramped).clamp(-1.0, 1.0);
+        }
+        self.last_gain = gain;
+    }
+}
+
+fn db_to_gain(db: f32) -> f32 {
+    10f32.powf(db / 20.0)
+}
+
+/// RMS level of `samples` in dBFS.
+fn level_db(samples: &[f32]) -> f32 {
+    let rms = (samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32).sqrt();
+    20.0 * rms.max(1e-6).log10()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sine(len: usize, amplitude: f32) -> Vec<f32> {
+        (0..len)
+            .map(|i| amplitude * (2.0 * std::f32::consts::PI * 440.0 * i as f32 / 16000.0).sin())
+            .collect()
+    }
+
+    fn run(gain: &mut InputGain, input: &[f32]) -> Vec<f32> {
+        let mut output = input.to_vec();
+        for block in output.chunks_mut(160) {
+            gain.process(block);
+        }
+        output
+    }
+
+    #[test]
+    fn manual_gain_scales_and_limits() {
+        let settings = GainSettings {
+            gain_db: 6.0,
+            automatic: false,
+        };
+        let output = run(&mut InputGain::new(settings, 16000), &sine(1600, 0.1));
+        assert!((level_db(&output) - level_db(&sine(1600, 0.1)) - 6.0).abs() < 0.1);
+
+        let output = run(&mut InputGain::new(settings, 16000), &sine(1600, 0.9));
+        assert!(output.iter().all(|s| s.abs() <= 1.0));
+    }
+
+    #[test]
+    fn automatic_gain_levels_quiet_speech_but_not_silence() {
+        let settings = GainSettings {
+            gain_db: 0.0,
+            automatic: true,
+        };
+        // A quiet voice, around -43 dBFS, is brought up towards the target
+        let quiet = sine(16000 * 3, 0.01);
+        let output = run(&mut InputGain::new(settings, 16000), &quiet);
+        let settled = level_db(&output[16000 * 2..]);
+        assert!((settled - TARGET_DB).abs() < 3.0, "settled at {}", settled);
+
+        // Room noise below the gate isn't boosted
+        let silence = sine(16000, 0.0005);
+        let output = run(&mut InputGain::new(settings, 16000), &silence);
+        assert!((level_db(&output) - level_db(&silence)).abs() < 0.1);
+    }
+}
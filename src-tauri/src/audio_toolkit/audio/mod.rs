@@ -1,15 +1,29 @@
 // Re-export all audio components
-mod decoder;
 mod device;
+mod encoder;
+mod gain;
+mod noise;
+mod pre_roll;
+mod quality;
 pub mod recorder;
 mod resampler;
 pub mod system_capture;
 mod utils;
 mod visualizer;
 
-pub use decoder::{decode_audio_file, AudioFormat};
-pub use device::{list_input_devices, list_output_devices, CpalDeviceInfo};
+pub use device::{
+    list_input_devices, list_output_devices, spawn_hotplug_watcher, CpalDeviceInfo, DeviceChanges,
+    HotplugWatcher,
+};
+pub use echo_transcribe::decoder::{decode_audio_file, ffmpeg_available, AudioFormat};
+pub use encoder::{
+    append_recording, load_recording, recording_duration_secs, save_recording, RecordingFormat,
+};
+pub use gain::{GainSettings, InputGain, MAX_INPUT_GAIN_DB, MIN_INPUT_GAIN_DB};
+pub use noise::{noise_spectrum, suppress_noise, NoiseSuppressor};
+pub use pre_roll::PreRoll;
+pub use quality::{measure_input_quality, InputQuality};
 pub use recorder::AudioRecorder;
 pub use resampler::FrameResampler;
-pub use utils::{load_wav_file, save_wav_file};
+pub use utils::{encode_wav, load_wav_file, save_wav_file};
 pub use visualizer::AudioVisualiser;
@@ -0,0 +1,327 @@
+//! Spectral subtraction of steady background noise.
+//!
+//! A few seconds of ambient sound give the average magnitude of each
+//! frequency band. Recordings are then split into overlapping frames and each
+//! band is attenuated by how much of it that noise accounts for, which takes
+//! out fans, hum and room tone while leaving speech mostly untouched.
+//!
+//! [`NoiseSuppressor`] does the same live, frame by frame, without a
+//! calibration: it follows the quietest level of each band, which between
+//! words is the background noise.
+
+use rustfft::{num_complex::Complex32, Fft, FftPlanner};
+use std::sync::Arc;
+
+/// Frame length of the analysis, 32 ms at 16 kHz.
+pub const NOISE_FFT_SIZE: usize = 512;
+const HOP: usize = NOISE_FFT_SIZE / 2;
+/// Bands are never attenuated below this gain; removing them completely
+/// leaves warbling artifacts ("musical noise").
+const MIN_GAIN: f32 = 0.1;
+/// Weight of the newest frame in the smoothed band levels the live noise
+/// estimate follows.
+const LEVEL_SMOOTHING: f32 = 0.5;
+/// How much the live noise estimate may rise per frame, about 3 dB/s, so
+/// louder noise is picked up while speech is too short-lived to count.
+const NOISE_RISE: f32 = 1.005;
+/// The live estimate follows the quietest level rather than the average, so
+/// more than it is subtracted.
+const LIVE_STRENGTH: f32 = 2.0;
+
+/// Periodic Hann window, which sums to one at 50% overlap.
+fn hann_window() -> Vec<f32> {
+    (0..NOISE_FFT_SIZE)
+        .map(|i| {
+            0.5 * (1.0 - (2.0 * std::f32::consts::PI * i as f32 / NOISE_FFT_SIZE as f32).cos())
+        })
+        .collect()
+}
+
+/// Average magnitude of each frequency bin, DC to Nyquist, over `samples`.
+/// Empty if `samples` is shorter than one frame.
+pub fn noise_spectrum(samples: &[f32]) -> Vec<f32> {
+    if samples.len() < NOISE_FFT_SIZE {
+        return Vec::new();
+    }
+    let fft = FftPlanner::<f32>::new().plan_fft_forward(NOISE_FFT_SIZE);
+    let window = hann_window();
+    let mut spectrum = vec![0.0; NOISE_FFT_SIZE / 2 + 1];
+    let mut buffer = vec![Complex32::new(0.0, 0.0); NOISE_FFT_SIZE];
+    let mut frames = 0;
+
+    for start in (0..=samples.len() - NOISE_FFT_SIZE).step_by(HOP) {
+        for (i, slot) in buffer.iter_mut().enumerate() {
+            *slot = Complex32::new(samples[start + i] * window[i], 0.0);
+        }
+        fft.process(&mut buffer);
+        for (bin, magnitude) in spectrum.iter_mut().enumerate() {
+            *magnitude += buffer[bin].norm();
+        }
+        frames += 1;
+    }
+
+    for magnitude in &mut spectrum {
+        *magnitude /= frames as f32;
+    }
+    spectrum
+}
+
+/// Subtract `noise` (from [`noise_spectrum`]) from `samples`. `strength`
+/// scales the noise estimate: 1.0 removes it as measured, higher values also
+/// take out louder fluctuations of it. Returns the samples unchanged if
+/// `noise` doesn't match the frame size.
+pub fn suppress_noise(samples: &[f32], noise: &[f32], strength: f32) -> Vec<f32> {
+    if noise.len() != NOISE_FFT_SIZE / 2 + 1 || samples.is_empty() {
+        return samples.to_vec();
+    }
+    let mut planner = FftPlanner::<f32>::new();
+    let fft = planner.plan_fft_forward(NOISE_FFT_SIZE);
+    let ifft = planner.plan_fft_inverse(NOISE_FFT_SIZE);
+    let window = hann_window();
+
+    // Pad half a frame on both sides so every sample is covered by two frames
+    let padded_len = (samples.len() + 2 * HOP).div_ceil(HOP) * HOP;
+    let mut padded = vec![0.0; padded_len];
+    padded[HOP..HOP + samples.len()].copy_from_slice(samples);
+    let mut output = vec![0.0; padded_len];
+    let mut buffer = vec![Complex32::new(0.0, 0.0); NOISE_FFT_SIZE];
+
+    for start in (0..=padded_len - NOISE_FFT_SIZE).step_by(HOP) {
+        for (i, slot) in buffer.iter_mut().enumerate() {
+            *slot = Complex32::new(padded[start + i] * window[i], 0.0);
+        }
+        fft.process(&mut buffer);
+        for (k, value) in buffer.iter_mut().enumerate() {
+            // Bins above Nyquist mirror the ones below
+            let bin = k.min(NOISE_FFT_SIZE - k);
+            let magnitude = value.norm();
+            let gain = if magnitude > 0.0 {
+                (1.0 - strength * noise[bin] / magnitude).max(MIN_GAIN)
+            } else {
+                MIN_GAIN
+            };
+            *value *= gain;
+        }
+        ifft.process(&mut buffer);
+        for (i, value) in buffer.iter().enumerate() {
+            output[start + i] += value.re / NOISE_FFT_SIZE as f32;
+        }
+    }
+
+    output[HOP..HOP + samples.len()].to_vec()
+}
+
+/// Streaming noise suppression for a recording. Frames are pushed as they
+/// arrive and come back denoised with the same length, one frame late.
+pub struct NoiseSuppressor {
+    fft: Arc<dyn Fft<f32>>,
+    ifft: Arc<dyn Fft<f32>>,
+    window: Vec<f32>,
+    buffer: Vec<Complex32>,
+    /// Smoothed magnitude of each bin over the recent frames.
+    level: Vec<f32>,
+    /// Estimated noise magnitude of each bin; empty until the first frame.
+    noise: Vec<f32>,
+    /// Samples not yet analysed, starting half a frame before the next hop.
+    input: Vec<f32>,
+    /// Second half of the last analysed frame, added to the next one.
+    overlap: Vec<f32>,
+    /// Denoised samples not yet handed out.
+    output: Vec<f32>,
+    /// Leading output samples that belong to the zero padding.
+    skip: usize,
+    /// Samples pushed and not yet handed out.
+    pending: usize,
+}
+
+impl Default for NoiseSuppressor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NoiseSuppressor {
+    pub fn new() -> Self {
+        let mut planner = FftPlanner::<f32>::new();
+        Self {
+            fft: planner.plan_fft_forward(NOISE_FFT_SIZE),
+            ifft: planner.plan_fft_inverse(NOISE_FFT_SIZE),
+            window: hann_window(),
+            buffer: vec![Complex32::new(0.0, 0.0); NOISE_FFT_SIZE],
+            level: Vec::new(),
+            noise: Vec::new(),
+            input: vec![0.0; HOP],
+            overlap: vec![0.0; HOP],
+            output: Vec::new(),
+            skip: HOP,
+            pending: 0,
+        }
+    }
+
+    /// Denoise `frame`, calling `emit` with every finished frame of the same
+    /// length.
+    pub fn push(&mut self, frame: &[f32], mut emit: impl FnMut(&[f32])) {
+        self.input.extend_from_slice(frame);
+        self.pending += frame.len();
+        self.process();
+        self.emit_frames(frame.len(), &mut emit);
+    }
+
+    /// Flush the rest of the recording, the last frame padded with silence
+    /// to `frame_len`.
+    pub fn finish(&mut self, frame_len: usize, mut emit: impl FnMut(&[f32])) {
+        self.input.resize(self.input.len() + NOISE_FFT_SIZE, 0.0);
+        self.process();
+        self.output.truncate(self.pending);
+        self.emit_frames(frame_len, &mut emit);
+        if !self.output.is_empty() && frame_len > 0 {
+            self.output.resize(frame_len, 0.0);
+            emit(&self.output);
+        }
+        *self = Self::new();
+    }
+
+    fn process(&mut self) {
+        while self.input.len() >= NOISE_FFT_SIZE {
+            for (i, slot) in self.buffer.iter_mut().enumerate() {
+                *slot = Complex32::new(self.input[i] * self.window[i], 0.0);
+            }
+            self.fft.process(&mut self.buffer);
+            self.update_noise();
+            for (k, value) in self.buffer.iter_mut().enumerate() {
+                let bin = k.min(NOISE_FFT_SIZE - k);
+                let magnitude = value.norm();
+                let gain = if magnitude > 0.0 {
+                    (1.0 - LIVE_STRENGTH * self.noise[bin] / magnitude).max(MIN_GAIN)
+                } else {
+                    MIN_GAIN
+                };
+                *value *= gain;
+            }
+            self.ifft.process(&mut self.buffer);
+
+            let scale = NOISE_FFT_SIZE as f32;
+            for i in 0..HOP {
+                let sample = self.overlap[i] + self.buffer[i].re / scale;
+                if self.skip > 0 {
+                    self.skip -= 1;
+                } else {
+                    self.output.push(sample);
+                }
+                self.overlap[i] = self.buffer[HOP + i].re / scale;
+            }
+            self.input.drain(..HOP);
+        }
+    }
+
+    /// Follow each band's smoothed level down at once and up only slowly.
+    fn update_noise(&mut self) {
+        let magnitudes = self.buffer[..=NOISE_FFT_SIZE / 2].iter().map(|v| v.norm());
+        if self.noise.is_empty() {
+            self.level = magnitudes.collect();
+            self.noise = self.level.clone();
+            return;
+        }
+        for ((level, noise), magnitude) in
+            self.level.iter_mut().zip(&mut self.noise).zip(magnitudes)
+        {
+            *level += LEVEL_SMOOTHING * (magnitude - *level);
+            *noise = (*noise * NOISE_RISE).min(*level);
+        }
+    }
+
+    fn emit_frames(&mut self, frame_len: usize, emit: &mut impl FnMut(&[f32])) {
+        if frame_len == 0 {
+            return;
+        }
+        let mut emitted = 0;
+        while self.output.len() - emitted >= frame_len {
+            emit(&self.output[emitted..emitted + frame_len]);
+            emitted += frame_len;
+        }
+        self.output.drain(..emitted);
+        self.pending -= emitted;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Deterministic white noise in -amplitude..amplitude.
+    fn white_noise(len: usize, amplitude: f32) -> Vec<f32> {
+        let mut state: u32 = 12345;
+        (0..len)
+            .map(|_| {
+                state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+                ((state >> 16) as f32 / 32768.0 - 1.0) * amplitude
+            })
+            .collect()
+    }
+
+    fn rms(samples: &[f32]) -> f32 {
+        (samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32).sqrt()
+    }
+
+    #[test]
+    fn zero_strength_reconstructs_the_input() {
+        let samples = white_noise(16000, 0.3);
+        let noise = noise_spectrum(&samples);
+        let output = suppress_noise(&samples, &noise, 0.0);
+        assert_eq!(output.len(), samples.len());
+        let max_error = samples
+            .iter()
+            .zip(&output)
+            .map(|(a, b)| (a - b).abs())
+            .fold(0.0, f32::max);
+        assert!(max_error < 1e-4, "max error {}", max_error);
+    }
+
+    #[test]
+    fn removes_measured_noise_and_keeps_a_tone() {
+        let noise_samples = white_noise(16000 * 2, 0.05);
+        let noise = noise_spectrum(&noise_samples);
+
+        let suppressed = suppress_noise(&noise_samples, &noise, 1.0);
+        assert!(rms(&suppressed) < rms(&noise_samples) * 0.6);
+
+        let tone: Vec<f32> = (0..16000)
+            .map(|i| 0.5 * (2.0 * std::f32::consts::PI * 1000.0 * i as f32 / 16000.0).sin())
+            .collect();
+        let noisy: Vec<f32> = tone
+            .iter()
+            .zip(&noise_samples)
+            .map(|(t, n)| t + n)
+            .collect();
+        let cleaned = suppress_noise(&noisy, &noise, 1.0);
+        assert!((rms(&cleaned) - rms(&tone)).abs() < 0.03);
+    }
+
+    #[test]
+    fn live_suppression_learns_the_noise_and_keeps_speech() {
+        // Two seconds of background noise, then half a second of "speech"
+        let noise_samples = white_noise(16000 * 2 + 8000, 0.05);
+        let tone: Vec<f32> = (0..8000)
+            .map(|i| 0.5 * (2.0 * std::f32::consts::PI * 1000.0 * i as f32 / 16000.0).sin())
+            .collect();
+        let mut input = noise_samples.clone();
+        for (sample, t) in input[32000..].iter_mut().zip(&tone) {
+            *sample += t;
+        }
+
+        let mut suppressor = NoiseSuppressor::new();
+        let mut output = Vec::new();
+        for frame in input.chunks_exact(480) {
+            suppressor.push(frame, |out| {
+                assert_eq!(out.len(), 480);
+                output.extend_from_slice(out);
+            });
+        }
+        suppressor.finish(480, |out| output.extend_from_slice(out));
+        assert_eq!(output.len(), input.len() / 480 * 480);
+
+        // Past the first second the estimate has settled
+        assert!(rms(&output[16000..32000]) < rms(&noise_samples) * 0.6);
+        assert!((rms(&output[32000..39840]) - rms(&tone)).abs() < 0.03);
+    }
+}
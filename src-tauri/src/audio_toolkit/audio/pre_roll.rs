@@ -0,0 +1,55 @@
+//! Audio heard between recordings, so a recording can start with what was
+//! said just before the shortcut.
+
+use std::collections::VecDeque;
+
+/// The last `capacity` samples heard while not recording.
+pub struct PreRoll {
+    capacity: usize,
+    samples: VecDeque<f32>,
+}
+
+impl PreRoll {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            samples: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Keep `frame`, dropping the oldest samples past the capacity.
+    pub fn push(&mut self, frame: &[f32]) {
+        self.samples.extend(frame);
+        let excess = self.samples.len().saturating_sub(self.capacity);
+        self.samples.drain(..excess);
+    }
+
+    /// Everything kept, oldest first, leaving the buffer empty for the next
+    /// gap between recordings.
+    pub fn take(&mut self) -> Vec<f32> {
+        self.samples.drain(..).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_only_the_latest_samples() {
+        let mut pre_roll = PreRoll::new(4);
+        pre_roll.push(&[1.0, 2.0, 3.0]);
+        pre_roll.push(&[4.0, 5.0, 6.0]);
+        assert_eq!(pre_roll.take(), [3.0, 4.0, 5.0, 6.0]);
+    }
+
+    #[test]
+    fn each_gap_starts_empty() {
+        let mut pre_roll = PreRoll::new(4);
+        pre_roll.push(&[1.0, 2.0]);
+        assert_eq!(pre_roll.take(), [1.0, 2.0]);
+        assert!(pre_roll.take().is_empty());
+        pre_roll.push(&[3.0]);
+        assert_eq!(pre_roll.take(), [3.0]);
+    }
+}
@@ -0,0 +1,113 @@
+//! Input quality of a short test recording, for comparing microphones.
+//!
+//! The recording is cut into 30 ms frames. The quietest frames give the
+//! noise floor and the loudest the signal level, so a second with a few
+//! words in it tells a clear headset from a laptop mic next to a fan. Mics
+//! that are barely audible or clip are marked down.
+
+use serde::Serialize;
+
+const FRAME_SAMPLES: usize = 480;
+/// Frame RMS below this is treated as digital silence.
+const SILENCE_DB: f32 = -100.0;
+/// Signal levels below this are too quiet to transcribe well.
+const QUIET_DB: f32 = -50.0;
+/// Samples at or above this magnitude count as clipped.
+const CLIP_LEVEL: f32 = 0.99;
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub struct InputQuality {
+    /// Level of the loudest frames, in dBFS.
+    pub signal_db: f32,
+    /// Level of the quietest frames, in dBFS.
+    pub noise_floor_db: f32,
+    pub snr_db: f32,
+    /// Fraction of samples at full scale.
+    pub clipping: f32,
+    /// Overall rating, higher is better.
+    pub score: f32,
+}
+
+/// Measure `samples` of 16 kHz audio. `None` when it's shorter than a frame.
+pub fn measure_input_quality(samples: &[f32]) -> Option<InputQuality> {
+    let mut levels: Vec<f32> = samples.chunks_exact(FRAME_SAMPLES).map(frame_db).collect();
+    if levels.is_empty() {
+        return None;
+    }
+    levels.sort_by(|a, b| a.total_cmp(b));
+    let noise_floor_db = percentile(&levels, 0.1);
+    let signal_db = percentile(&levels, 0.9);
+    let snr_db = signal_db - noise_floor_db;
+    let clipping =
+        samples.iter().filter(|s| s.abs() >= CLIP_LEVEL).count() as f32 / samples.len() as f32;
+
+    let mut score = snr_db;
+    if signal_db < QUIET_DB {
+        score -= QUIET_DB - signal_db;
+    }
+    // A tenth of a percent clipped already sounds broken
+    score -= clipping * 1000.0;
+    if signal_db <= SILENCE_DB {
+        // Muted or disconnected
+        score = f32::MIN;
+    }
+
+    Some(InputQuality {
+        signal_db,
+        noise_floor_db,
+        snr_db,
+        clipping,
+        score,
+    })
+}
+
+fn frame_db(frame: &[f32]) -> f32 {
+    let rms = (frame.iter().map(|s| s * s).sum::<f32>() / frame.len() as f32).sqrt();
+    (20.0 * rms.max(1e-6).log10()).max(SILENCE_DB)
+}
+
+/// Value at fraction `p` of the sorted `values`.
+fn percentile(values: &[f32], p: f32) -> f32 {
+    values[((values.len() - 1) as f32 * p).round() as usize]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// One second of a tone over a steady hiss, the tone in the middle half.
+    fn recording(tone: f32, hiss: f32) -> Vec<f32> {
+        let mut seed = 1u32;
+        (0..16000)
+            .map(|i| {
+                seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                let noise = (seed >> 8) as f32 / (1 << 24) as f32 * 2.0 - 1.0;
+                let voiced = (4000..12000).contains(&i);
+                let t = i as f32 / 16000.0;
+                let speech = if voiced {
+                    tone * (2.0 * std::f32::consts::PI * 220.0 * t).sin()
+                } else {
+                    0.0
+                };
+                (speech + hiss * noise).clamp(-1.0, 1.0)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn clear_mics_rank_above_noisy_quiet_and_clipping_ones() {
+        let clear = measure_input_quality(&recording(0.3, 0.001)).unwrap();
+        let noisy = measure_input_quality(&recording(0.3, 0.05)).unwrap();
+        let quiet = measure_input_quality(&recording(0.001, 0.00001)).unwrap();
+        let clipping = measure_input_quality(&recording(1.5, 0.001)).unwrap();
+        let muted = measure_input_quality(&[0.0; 16000]).unwrap();
+
+        assert!(clear.snr_db > 40.0, "clear snr {}", clear.snr_db);
+        assert!(clear.score > noisy.score);
+        assert!(clear.score > quiet.score);
+        assert!(clear.score > clipping.score);
+        assert!(clipping.clipping > 0.0);
+        assert_eq!(muted.score, f32::MIN);
+        assert!(measure_input_quality(&[0.1; 100]).is_none());
+    }
+}
@@ -10,8 +10,9 @@ use cpal::{
 };
 
 use crate::audio_toolkit::{
-    audio::{AudioVisualiser, FrameResampler},
+    audio::{AudioVisualiser, FrameResampler, GainSettings, InputGain, NoiseSuppressor, PreRoll},
     constants,
+    thread_priority::{apply_current_thread_priority, ThreadPriority},
     vad::{self, VadFrame},
     VoiceActivityDetector,
 };
@@ -23,12 +24,28 @@ enum Cmd {
     Shutdown,
 }
 
+/// Callbacks the consumer thread reports to and asks at the start of each
+/// recording.
+#[derive(Clone, Default)]
+struct Hooks {
+    level: Option<Arc<dyn Fn(Vec<f32>) + Send + Sync + 'static>>,
+    speech: Option<Arc<dyn Fn(bool) + Send + Sync + 'static>>,
+    peak: Option<Arc<dyn Fn(f32) + Send + Sync + 'static>>,
+    denoise: Option<Arc<dyn Fn() -> bool + Send + Sync + 'static>>,
+    gain: Option<Arc<dyn Fn() -> GainSettings + Send + Sync + 'static>>,
+    /// Samples kept between recordings, and whether to start with them.
+    pre_roll: Option<(usize, Arc<dyn Fn() -> bool + Send + Sync + 'static>)>,
+}
+
 pub struct AudioRecorder {
     device: Option<Device>,
     cmd_tx: Option<mpsc::Sender<Cmd>>,
     worker_handle: Option<std::thread::JoinHandle<()>>,
     vad: Option<Arc<Mutex<Box<dyn vad::VoiceActivityDetector>>>>,
-    level_cb: Option<Arc<dyn Fn(Vec<f32>) + Send + Sync + 'static>>,
+    hooks: Hooks,
+    realtime_priority: bool,
+    /// Native rate of the open input stream, before resampling to 16 kHz.
+    sample_rate: Option<u32>,
 }
 
 impl AudioRecorder {
@@ -38,7 +55,9 @@ impl AudioRecorder {
             cmd_tx: None,
             worker_handle: None,
             vad: None,
-            level_cb: None,
+            hooks: Hooks::default(),
+            realtime_priority: false,
+            sample_rate: None,
         })
     }
 
@@ -51,7 +70,70 @@ impl AudioRecorder {
     where
         F: Fn(Vec<f32>) + Send + Sync + 'static,
     {
-        self.level_cb = Some(Arc::new(cb));
+        self.hooks.level = Some(Arc::new(cb));
+        self
+    }
+
+    /// Register a callback invoked with the VAD decision for every frame
+    /// processed while recording (`true` = speech).
+    pub fn with_speech_callback<F>(mut self, cb: F) -> Self
+    where
+        F: Fn(bool) + Send + Sync + 'static,
+    {
+        self.hooks.speech = Some(Arc::new(cb));
+        self
+    }
+
+    /// Register a callback invoked with the peak amplitude of every raw input
+    /// buffer received while recording, before VAD filtering.
+    pub fn with_peak_callback<F>(mut self, cb: F) -> Self
+    where
+        F: Fn(f32) + Send + Sync + 'static,
+    {
+        self.hooks.peak = Some(Arc::new(cb));
+        self
+    }
+
+    /// Register a callback asked at the start of every recording whether to
+    /// denoise it. Frames are denoised after resampling, so the VAD, the
+    /// streamed chunks and the returned samples all get the cleaned audio.
+    pub fn with_noise_suppression<F>(mut self, cb: F) -> Self
+    where
+        F: Fn() -> bool + Send + Sync + 'static,
+    {
+        self.hooks.denoise = Some(Arc::new(cb));
+        self
+    }
+
+    /// Register a callback asked at the start of every recording for the
+    /// input gain. It is applied to the raw input, so the visualizer and the
+    /// transcription both get the boosted audio; the peak callback still
+    /// sees the device level.
+    pub fn with_input_gain<F>(mut self, cb: F) -> Self
+    where
+        F: Fn() -> GainSettings + Send + Sync + 'static,
+    {
+        self.hooks.gain = Some(Arc::new(cb));
+        self
+    }
+
+    /// Keep the last `duration` of audio while not recording, and start a
+    /// recording with it if `cb` says so, so speech that began just before
+    /// the shortcut isn't cut off. Only fills while the stream stays open
+    /// between recordings.
+    pub fn with_pre_roll<F>(mut self, duration: Duration, cb: F) -> Self
+    where
+        F: Fn() -> bool + Send + Sync + 'static,
+    {
+        let frames = (duration.as_millis() as u64 / FRAME_MS) as usize;
+        self.hooks.pre_roll = Some((frames * FRAME_SAMPLES, Arc::new(cb)));
+        self
+    }
+
+    /// Run the capture callback and the sample consumer at realtime priority,
+    /// so capture keeps up while transcription saturates the CPU.
+    pub fn with_realtime_priority(mut self, enabled: bool) -> Self {
+        self.realtime_priority = enabled;
         self
     }
 
@@ -71,16 +153,19 @@ impl AudioRecorder {
                 .ok_or_else(|| Error::new(std::io::ErrorKind::NotFound, "No input device found"))?,
         };
 
+        let config = AudioRecorder::get_preferred_config(&device)?;
+        let sample_rate = config.sample_rate().0;
+
         let thread_device = device.clone();
         let vad = self.vad.clone();
-        // Move the optional level callback into the worker thread
-        let level_cb = self.level_cb.clone();
+        // Move the optional callbacks into the worker thread
+        let hooks = self.hooks.clone();
+        let realtime_priority = self.realtime_priority;
 
         let worker = std::thread::spawn(move || {
-            let config = AudioRecorder::get_preferred_config(&thread_device)
-                .expect("failed to fetch preferred config");
-
-            let sample_rate = config.sample_rate().0;
+            if realtime_priority {
+                apply_current_thread_priority(ThreadPriority::Realtime);
+            }
             let channels = config.channels() as usize;
 
             debug!(
@@ -92,37 +177,58 @@ impl AudioRecorder {
             );
 
             let stream = match config.sample_format() {
-                cpal::SampleFormat::U8 => {
-                    AudioRecorder::build_stream::<u8>(&thread_device, &config, sample_tx, channels)
-                        .unwrap()
-                }
-                cpal::SampleFormat::I8 => {
-                    AudioRecorder::build_stream::<i8>(&thread_device, &config, sample_tx, channels)
-                        .unwrap()
-                }
-                cpal::SampleFormat::I16 => {
-                    AudioRecorder::build_stream::<i16>(&thread_device, &config, sample_tx, channels)
-                        .unwrap()
-                }
-                cpal::SampleFormat::I32 => {
-                    AudioRecorder::build_stream::<i32>(&thread_device, &config, sample_tx, channels)
-                        .unwrap()
-                }
-                cpal::SampleFormat::F32 => {
-                    AudioRecorder::build_stream::<f32>(&thread_device, &config, sample_tx, channels)
-                        .unwrap()
-                }
+                cpal::SampleFormat::U8 => AudioRecorder::build_stream::<u8>(
+                    &thread_device,
+                    &config,
+                    sample_tx,
+                    channels,
+                    realtime_priority,
+                )
+                .unwrap(),
+                cpal::SampleFormat::I8 => AudioRecorder::build_stream::<i8>(
+                    &thread_device,
+                    &config,
+                    sample_tx,
+                    channels,
+                    realtime_priority,
+                )
+                .unwrap(),
+                cpal::SampleFormat::I16 => AudioRecorder::build_stream::<i16>(
+                    &thread_device,
+                    &config,
+                    sample_tx,
+                    channels,
+                    realtime_priority,
+                )
+                .unwrap(),
+                cpal::SampleFormat::I32 => AudioRecorder::build_stream::<i32>(
+                    &thread_device,
+                    &config,
+                    sample_tx,
+                    channels,
+                    realtime_priority,
+                )
+                .unwrap(),
+                cpal::SampleFormat::F32 => AudioRecorder::build_stream::<f32>(
+                    &thread_device,
+                    &config,
+                    sample_tx,
+                    channels,
+                    realtime_priority,
+                )
+                .unwrap(),
                 _ => panic!("unsupported sample format"),
             };
 
             stream.play().expect("failed to start stream");
 
             // keep the stream alive while we process samples
-            run_consumer(sample_rate, vad, sample_rx, cmd_rx, level_cb);
+            run_consumer(sample_rate, vad, sample_rx, cmd_rx, hooks);
             // stream is dropped here, after run_consumer returns
         });
 
         self.device = Some(device);
+        self.sample_rate = Some(sample_rate);
         self.cmd_tx = Some(cmd_tx);
         self.worker_handle = Some(worker);
 
@@ -155,22 +261,41 @@ impl AudioRecorder {
             let _ = h.join();
         }
         self.device = None;
+        self.sample_rate = None;
         Ok(())
     }
 
+    /// Name of the open input device.
+    pub fn device_name(&self) -> Option<String> {
+        self.device.as_ref().and_then(|device| device.name().ok())
+    }
+
+    /// Native sample rate of the open input stream.
+    pub fn sample_rate(&self) -> Option<u32> {
+        self.sample_rate
+    }
+
     fn build_stream<T>(
         device: &cpal::Device,
         config: &cpal::SupportedStreamConfig,
         sample_tx: mpsc::Sender<Vec<f32>>,
         channels: usize,
+        realtime_priority: bool,
     ) -> Result<cpal::Stream, cpal::BuildStreamError>
     where
         T: Sample + SizedSample + Send + 'static,
         f32: cpal::FromSample<T>,
     {
         let mut output_buffer = Vec::new();
+        // The callback runs on a thread owned by the audio backend, so its
+        // priority can only be set from inside the first callback
+        let mut priority_pending = realtime_priority;
 
         let stream_cb = move |data: &[T], _: &cpal::InputCallbackInfo| {
+            if priority_pending {
+                priority_pending = false;
+                apply_current_thread_priority(ThreadPriority::Realtime);
+            }
             output_buffer.clear();
 
             if channels == 1 {
@@ -226,22 +351,34 @@ impl AudioRecorder {
     }
 }
 
+/// Length of the frames the VAD and the denoiser work on.
+const FRAME_MS: u64 = 30;
+const FRAME_SAMPLES: usize = constants::WHISPER_SAMPLE_RATE as usize * FRAME_MS as usize / 1000;
+
 fn run_consumer(
     in_sample_rate: u32,
     vad: Option<Arc<Mutex<Box<dyn vad::VoiceActivityDetector>>>>,
     sample_rx: mpsc::Receiver<Vec<f32>>,
     cmd_rx: mpsc::Receiver<Cmd>,
-    level_cb: Option<Arc<dyn Fn(Vec<f32>) + Send + Sync + 'static>>,
+    hooks: Hooks,
 ) {
     let mut frame_resampler = FrameResampler::new(
         in_sample_rate as usize,
         constants::WHISPER_SAMPLE_RATE as usize,
-        Duration::from_millis(30),
+        Duration::from_millis(FRAME_MS),
     );
 
     let mut processed_samples = Vec::<f32>::new();
     let mut recording = false;
     let mut chunk_tx: Option<mpsc::Sender<Vec<f32>>> = None;
+    // Only while recording, when enabled for it
+    let mut denoiser: Option<NoiseSuppressor> = None;
+    let mut gain: Option<InputGain> = None;
+    // Whole frames heard since the last recording, up to the pre-roll length
+    let mut pre_roll = hooks
+        .pre_roll
+        .as_ref()
+        .map(|(capacity, _)| PreRoll::new(*capacity));
 
     // ---------- spectrum visualisation setup ---------------------------- //
     const BUCKETS: usize = 64;
@@ -260,6 +397,7 @@ fn run_consumer(
         vad: &Option<Arc<Mutex<Box<dyn vad::VoiceActivityDetector>>>>,
         out_buf: &mut Vec<f32>,
         chunk_tx: &Option<mpsc::Sender<Vec<f32>>>,
+        speech_cb: &Option<Arc<dyn Fn(bool) + Send + Sync + 'static>>,
     ) {
         if !recording {
             return;
@@ -274,7 +412,11 @@ fn run_consumer(
 
         if let Some(vad_arc) = vad {
             let mut det = vad_arc.lock().unwrap();
-            match det.push_frame(samples).unwrap_or(VadFrame::Speech(samples)) {
+            let frame = det.push_frame(samples).unwrap_or(VadFrame::Speech(samples));
+            if let Some(cb) = speech_cb {
+                cb(frame.is_speech());
+            }
+            match frame {
                 VadFrame::Speech(buf) => process_speech(buf),
                 VadFrame::Noise => {}
             }
@@ -292,18 +434,70 @@ fn run_consumer(
                     processed_samples.clear();
                     recording = true;
                     chunk_tx = tx;
+                    denoiser = hooks
+                        .denoise
+                        .as_ref()
+                        .is_some_and(|enabled| enabled())
+                        .then(NoiseSuppressor::new);
+                    gain = hooks
+                        .gain
+                        .as_ref()
+                        .map(|settings| settings())
+                        .filter(GainSettings::is_active)
+                        .map(|settings| InputGain::new(settings, in_sample_rate));
                     visualizer.reset();
                     if let Some(v) = &vad {
                         v.lock().unwrap().reset();
                     }
+
+                    let buffered = pre_roll.as_mut().map(PreRoll::take).unwrap_or_default();
+                    if !buffered.is_empty()
+                        && hooks
+                            .pre_roll
+                            .as_ref()
+                            .is_some_and(|(_, enabled)| enabled())
+                    {
+                        debug!(
+                            "Starting with {:.1}s of pre-roll",
+                            buffered.len() as f32 / constants::WHISPER_SAMPLE_RATE as f32
+                        );
+                        let mut emit = |frame: &[f32]| {
+                            handle_frame(
+                                frame,
+                                true,
+                                &vad,
+                                &mut processed_samples,
+                                &chunk_tx,
+                                &hooks.speech,
+                            )
+                        };
+                        for frame in buffered.chunks(FRAME_SAMPLES) {
+                            denoise_frame(frame, &mut denoiser, &mut emit);
+                        }
+                    }
                 }
                 Cmd::Stop(reply_tx) => {
                     debug!("Cmd::Stop received");
                     recording = false;
 
-                    frame_resampler.finish(&mut |frame: &[f32]| {
-                        handle_frame(frame, true, &vad, &mut processed_samples, &chunk_tx)
-                    });
+                    let mut emit = |frame: &[f32]| {
+                        handle_frame(
+                            frame,
+                            true,
+                            &vad,
+                            &mut processed_samples,
+                            &chunk_tx,
+                            &hooks.speech,
+                        )
+                    };
+                    match denoiser.take() {
+                        Some(mut denoiser) => {
+                            frame_resampler
+                                .finish(&mut |frame: &[f32]| denoiser.push(frame, &mut emit));
+                            denoiser.finish(FRAME_SAMPLES, &mut emit);
+                        }
+                        None => frame_resampler.finish(&mut emit),
+                    }
 
                     let sample_count = processed_samples.len();
                     let audio_duration_secs = sample_count as f32 / 16000.0;
@@ -314,35 +508,70 @@ fn run_consumer(
 
                     let _ = reply_tx.send(std::mem::take(&mut processed_samples));
                     chunk_tx = None;
+                    gain = None;
                 }
                 Cmd::Shutdown => return,
             }
         }
 
-        // Use recv_timeout to allow checking for shutdown commands even when
-        // no audio samples are being received (e.g., if the audio device is unresponsive)
-        let raw = match sample_rx.recv_timeout(Duration::from_millis(100)) {
+        // Use recv_timeout to keep handling commands even when no audio
+        // samples are being received (e.g. the device was unplugged), so a
+        // stop still returns what was recorded
+        let mut raw = match sample_rx.recv_timeout(Duration::from_millis(100)) {
             Ok(s) => s,
-            Err(mpsc::RecvTimeoutError::Timeout) => {
-                // Check for shutdown command on timeout
-                if let Ok(Cmd::Shutdown) = cmd_rx.try_recv() {
-                    return;
-                }
-                continue;
-            }
+            // Commands are handled at the top of the loop
+            Err(mpsc::RecvTimeoutError::Timeout) => continue,
             Err(mpsc::RecvTimeoutError::Disconnected) => break,
         };
 
+        if recording {
+            if let Some(cb) = &hooks.peak {
+                cb(raw.iter().fold(0.0f32, |peak, s| peak.max(s.abs())));
+            }
+        }
+
+        if let Some(gain) = gain.as_mut() {
+            gain.process(&mut raw);
+        }
+
         // ---------- spectrum processing ---------------------------------- //
         if let Some(buckets) = visualizer.feed(&raw) {
-            if let Some(cb) = &level_cb {
+            if let Some(cb) = &hooks.level {
                 cb(buckets);
             }
         }
 
         // ---------- existing pipeline ------------------------------------ //
         frame_resampler.push(&raw, &mut |frame: &[f32]| {
-            handle_frame(frame, recording, &vad, &mut processed_samples, &chunk_tx)
+            if !recording {
+                if let Some(pre_roll) = pre_roll.as_mut() {
+                    pre_roll.push(frame);
+                }
+                return;
+            }
+            let mut emit = |frame: &[f32]| {
+                handle_frame(
+                    frame,
+                    recording,
+                    &vad,
+                    &mut processed_samples,
+                    &chunk_tx,
+                    &hooks.speech,
+                )
+            };
+            denoise_frame(frame, &mut denoiser, &mut emit);
         });
     }
 }
+
+/// Pass `frame` on to `emit`, through the denoiser if there is one.
+fn denoise_frame(
+    frame: &[f32],
+    denoiser: &mut Option<NoiseSuppressor>,
+    emit: &mut impl FnMut(&[f32]),
+) {
+    match denoiser {
+        Some(denoiser) => denoiser.push(frame, emit),
+        None => emit(frame),
+    }
+}
@@ -1,6 +1,7 @@
 use anyhow::{Context, Result};
 use hound::{WavReader, WavSpec, WavWriter};
 use log::debug;
+use std::io::{Cursor, Seek, Write};
 use std::path::Path;
 
 /// Load audio samples from a WAV file
@@ -34,24 +35,52 @@ pub fn load_wav_file<P: AsRef<Path>>(file_path: P) -> Result<Vec<f32>> {
     Ok(samples)
 }
 
-/// Save audio samples as a WAV file
-pub async fn save_wav_file<P: AsRef<Path>>(file_path: P, samples: &[f32]) -> Result<()> {
-    let spec = WavSpec {
-        channels: 1,
-        sample_rate: 16000,
-        bits_per_sample: 16,
-        sample_format: hound::SampleFormat::Int,
-    };
-
-    let mut writer = WavWriter::create(file_path.as_ref(), spec)?;
+/// 16 kHz mono, 16-bit: how recordings are saved and sent.
+const WAV_SPEC: WavSpec = WavSpec {
+    channels: 1,
+    sample_rate: 16000,
+    bits_per_sample: 16,
+    sample_format: hound::SampleFormat::Int,
+};
 
-    // Convert f32 samples to i16 for WAV
+/// Convert f32 samples to i16 and write them
+pub(super) fn write_wav_samples<W: Write + Seek>(
+    writer: &mut WavWriter<W>,
+    samples: &[f32],
+) -> hound::Result<()> {
     for sample in samples {
-        let sample_i16 = (sample * i16::MAX as f32) as i16;
-        writer.write_sample(sample_i16)?;
+        writer.write_sample((sample * i16::MAX as f32) as i16)?;
     }
+    Ok(())
+}
 
+/// Save audio samples as a WAV file
+pub async fn save_wav_file<P: AsRef<Path>>(file_path: P, samples: &[f32]) -> Result<()> {
+    let mut writer = WavWriter::create(file_path.as_ref(), WAV_SPEC)?;
+    write_wav_samples(&mut writer, samples)?;
     writer.finalize()?;
     debug!("Saved WAV file: {:?}", file_path.as_ref());
     Ok(())
 }
+
+/// Encode audio samples as a WAV file in memory
+pub fn encode_wav(samples: &[f32]) -> Result<Vec<u8>> {
+    let mut cursor = Cursor::new(Vec::new());
+    let mut writer = WavWriter::new(&mut cursor, WAV_SPEC)?;
+    write_wav_samples(&mut writer, samples)?;
+    writer.finalize()?;
+    Ok(cursor.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encodes_16khz_wav() {
+        let wav = encode_wav(&[0.0, 0.5, -0.5]).unwrap();
+        let reader = WavReader::new(Cursor::new(wav)).unwrap();
+        assert_eq!(reader.spec().sample_rate, 16000);
+        assert_eq!(reader.len(), 3);
+    }
+}
@@ -0,0 +1,166 @@
+//! Choice of the hardware local models run on.
+//!
+//! whisper.cpp and ONNX Runtime pick their device when a model is loaded,
+//! from what they were built with and what the machine has. These settings
+//! steer that choice through the environment variables the GPU runtimes
+//! read, so they must be applied before the engine loads. CUDA and Vulkan
+//! only read them once per process; switching away from a GPU that has
+//! already been used takes a restart.
+
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum InferenceBackend {
+    /// Whatever the engine prefers: a GPU when one is usable.
+    #[default]
+    Auto,
+    Metal,
+    Cuda,
+    Vulkan,
+    /// Keep the GPU free; run on the CPU only.
+    Cpu,
+}
+
+/// A backend as offered in the settings.
+#[derive(Serialize, Debug, Clone)]
+pub struct BackendInfo {
+    pub backend: InferenceBackend,
+    pub name: &'static str,
+    /// Whether the machine has what the backend needs.
+    pub available: bool,
+    /// Why it isn't available, or what it will use.
+    pub detail: Option<String>,
+}
+
+/// Every backend, with whether it can be used on this machine.
+pub fn available_backends() -> Vec<BackendInfo> {
+    let cores = std::thread::available_parallelism().map_or(1, |n| n.get());
+    vec![
+        BackendInfo {
+            backend: InferenceBackend::Auto,
+            name: "Automatic",
+            available: true,
+            detail: None,
+        },
+        BackendInfo {
+            backend: InferenceBackend::Metal,
+            name: "Metal",
+            available: cfg!(target_os = "macos"),
+            detail: (!cfg!(target_os = "macos")).then(|| "Only on macOS".to_string()),
+        },
+        BackendInfo {
+            backend: InferenceBackend::Cuda,
+            name: "CUDA",
+            available: has_cuda(),
+            detail: (!has_cuda()).then(|| "No NVIDIA driver found".to_string()),
+        },
+        BackendInfo {
+            backend: InferenceBackend::Vulkan,
+            name: "Vulkan",
+            available: has_vulkan(),
+            detail: (!has_vulkan()).then(|| "No Vulkan loader found".to_string()),
+        },
+        BackendInfo {
+            backend: InferenceBackend::Cpu,
+            name: "CPU only",
+            available: true,
+            detail: Some(format!("{} threads available", cores)),
+        },
+    ]
+}
+
+pub fn is_available(backend: InferenceBackend) -> bool {
+    available_backends()
+        .iter()
+        .any(|info| info.backend == backend && info.available)
+}
+
+/// Point the GPU runtimes at `backend` and cap CPU inference at `threads`.
+/// Call before loading a model.
+pub fn apply(backend: InferenceBackend, threads: Option<u32>) {
+    // Hiding a runtime's devices makes the engine skip it
+    let (hide_cuda, hide_vulkan) = match backend {
+        InferenceBackend::Auto | InferenceBackend::Metal => (false, false),
+        InferenceBackend::Cuda => (false, true),
+        InferenceBackend::Vulkan => (true, false),
+        InferenceBackend::Cpu => (true, true),
+    };
+    set_hidden("CUDA_VISIBLE_DEVICES", hide_cuda);
+    set_hidden("GGML_VK_VISIBLE_DEVICES", hide_vulkan);
+
+    match threads.filter(|&n| n > 0) {
+        Some(n) => std::env::set_var("OMP_NUM_THREADS", n.to_string()),
+        None => std::env::remove_var("OMP_NUM_THREADS"),
+    }
+}
+
+fn set_hidden(var: &str, hidden: bool) {
+    if hidden {
+        std::env::set_var(var, "");
+    } else if std::env::var_os(var).is_some_and(|value| value.is_empty()) {
+        // Only undo our own hiding, not a device list the user set
+        std::env::remove_var(var);
+    }
+}
+
+fn has_cuda() -> bool {
+    if cfg!(target_os = "windows") {
+        system_library_exists("nvcuda.dll")
+    } else if cfg!(target_os = "linux") {
+        Path::new("/proc/driver/nvidia/version").exists() || linux_library_exists("libcuda.so.1")
+    } else {
+        false
+    }
+}
+
+fn has_vulkan() -> bool {
+    if cfg!(target_os = "windows") {
+        system_library_exists("vulkan-1.dll")
+    } else if cfg!(target_os = "linux") {
+        linux_library_exists("libvulkan.so.1")
+    } else {
+        false
+    }
+}
+
+fn system_library_exists(name: &str) -> bool {
+    let root = std::env::var("SystemRoot").unwrap_or_else(|_| r"C:\Windows".to_string());
+    Path::new(&root).join("System32").join(name).exists()
+}
+
+fn linux_library_exists(name: &str) -> bool {
+    [
+        "/usr/lib/x86_64-linux-gnu",
+        "/usr/lib/aarch64-linux-gnu",
+        "/usr/lib64",
+        "/usr/lib",
+        "/usr/lib/wsl/lib",
+    ]
+    .iter()
+    .any(|dir| Path::new(dir).join(name).exists())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn automatic_and_cpu_are_always_available() {
+        let backends = available_backends();
+        assert_eq!(backends[0].backend, InferenceBackend::Auto);
+        assert!(is_available(InferenceBackend::Auto));
+        assert!(is_available(InferenceBackend::Cpu));
+    }
+
+    #[test]
+    fn backend_serializes_as_snake_case() {
+        assert_eq!(
+            serde_json::to_string(&InferenceBackend::Cuda).unwrap(),
+            "\"cuda\""
+        );
+        let backend: InferenceBackend = serde_json::from_str("\"cpu\"").unwrap();
+        assert_eq!(backend, InferenceBackend::Cpu);
+    }
+}
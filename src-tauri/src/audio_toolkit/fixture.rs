@@ -0,0 +1,268 @@
+//! Golden fixtures for the audio pipeline.
+//!
+//! A fixture directory holds short clips and a `manifest.json` describing what
+//! each clip should decode to: its duration, and for test tones the frequency
+//! and level, and for speech the expected transcript along with the model it
+//! was verified with. [`run_clip`] pushes a
+//! clip through the same decode → resample → transcribe path as file
+//! transcription and compares the result, so decoder and resampler
+//! regressions show up as failed clips.
+//!
+//! Used by the `audio_pipeline` integration test and the
+//! `run_pipeline_fixture` dev command.
+
+use crate::audio_toolkit::audio::decode_audio_file;
+use crate::managers::entry_edits::word_edit_distance;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+pub const MANIFEST_FILE: &str = "manifest.json";
+
+/// Sample rate of decoded audio.
+const SAMPLE_RATE: f32 = 16000.0;
+/// Allowed duration drift, as a fraction of the expected duration.
+const DURATION_TOLERANCE: f32 = 0.01;
+/// Allowed tone frequency drift, as a fraction of the expected frequency.
+const FREQUENCY_TOLERANCE: f32 = 0.02;
+/// Allowed RMS level drift, as a fraction of the expected level.
+const RMS_TOLERANCE: f32 = 0.05;
+/// Word error rate above which a transcript fails.
+pub const MAX_WORD_ERROR_RATE: f32 = 0.15;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct FixtureClip {
+    /// Path relative to the fixture directory
+    pub file: String,
+    pub duration_secs: f32,
+    /// Frequency of a test tone, measured in the middle of the clip
+    #[serde(default)]
+    pub tone_hz: Option<f32>,
+    /// RMS level in the middle of the clip
+    #[serde(default)]
+    pub rms: Option<f32>,
+    /// Transcript for speech clips. Checked only when a transcriber is given.
+    #[serde(default)]
+    pub expected_text: Option<String>,
+    /// How the transcript of a speech clip was checked. Required with
+    /// `expected_text`.
+    #[serde(default)]
+    pub verified_with: Option<Verification>,
+}
+
+/// The real recording behind a speech clip, and the model its transcript was
+/// checked against.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Verification {
+    /// Where the recording comes from and under which license.
+    pub source: String,
+    /// Model the clip was transcribed with, like `ggml-tiny.en`.
+    pub model: String,
+    /// Word error rate of that model's transcript.
+    pub word_error_rate: f32,
+}
+
+#[derive(Debug, Deserialize)]
+struct Manifest {
+    clips: Vec<FixtureClip>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FixtureResult {
+    pub file: String,
+    pub passed: bool,
+    /// Why the clip failed, one line per check
+    pub failures: Vec<String>,
+    pub duration_secs: f32,
+    pub tone_hz: Option<f32>,
+    pub rms: f32,
+    pub transcript: Option<String>,
+    pub word_error_rate: Option<f32>,
+}
+
+/// Fixtures bundled with the source tree.
+pub fn bundled_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/audio")
+}
+
+pub fn load_manifest(dir: &Path) -> Result<Vec<FixtureClip>> {
+    let path = dir.join(MANIFEST_FILE);
+    let contents = std::fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read fixture manifest {:?}", path))?;
+    parse_manifest(&contents).with_context(|| format!("Invalid fixture manifest {:?}", path))
+}
+
+/// Parse a manifest. Speech clips must say how their transcript was
+/// verified, so a clip nobody ran through a real model can't pass for one.
+fn parse_manifest(contents: &str) -> Result<Vec<FixtureClip>> {
+    let manifest: Manifest = serde_json::from_str(contents)?;
+    if let Some(clip) = manifest
+        .clips
+        .iter()
+        .find(|clip| clip.expected_text.is_some() && clip.verified_with.is_none())
+    {
+        anyhow::bail!(
+            "Speech clip {} has no verified_with: its source, and the model and word error rate its transcript was checked with",
+            clip.file
+        );
+    }
+    Ok(manifest.clips)
+}
+
+/// Decode `clip` and check it against the manifest. With a transcriber,
+/// speech clips are also transcribed and compared with their expected text.
+pub fn run_clip(
+    dir: &Path,
+    clip: &FixtureClip,
+    transcribe: Option<&mut dyn FnMut(Vec<f32>) -> Result<String>>,
+) -> Result<FixtureResult> {
+    let samples = decode_audio_file(dir.join(&clip.file))
+        .with_context(|| format!("Failed to decode {}", clip.file))?;
+    let mut failures = Vec::new();
+
+    let duration_secs = samples.len() as f32 / SAMPLE_RATE;
+    if !within(duration_secs, clip.duration_secs, DURATION_TOLERANCE) {
+        failures.push(format!(
+            "duration {:.3}s, expected {:.3}s",
+            duration_secs, clip.duration_secs
+        ));
+    }
+
+    let middle = middle(&samples);
+    let rms = rms(middle);
+    if let Some(expected) = clip.rms {
+        let ok = if expected == 0.0 {
+            rms < 1e-4
+        } else {
+            within(rms, expected, RMS_TOLERANCE)
+        };
+        if !ok {
+            failures.push(format!("rms {:.4}, expected {:.4}", rms, expected));
+        }
+    }
+
+    let tone_hz = clip.tone_hz.map(|_| zero_crossing_frequency(middle));
+    if let (Some(measured), Some(expected)) = (tone_hz, clip.tone_hz) {
+        if !within(measured, expected, FREQUENCY_TOLERANCE) {
+            failures.push(format!(
+                "tone {:.1}Hz, expected {:.1}Hz",
+                measured, expected
+            ));
+        }
+    }
+
+    let (transcript, word_error_rate) = match (&clip.expected_text, transcribe) {
+        (Some(expected), Some(transcribe)) => {
+            let transcript = transcribe(samples)?;
+            let rate = word_error_rate(expected, &transcript);
+            if rate > MAX_WORD_ERROR_RATE {
+                failures.push(format!(
+                    "transcript '{}' (word error rate {:.2}), expected '{}'",
+                    transcript.trim(),
+                    rate,
+                    expected
+                ));
+            }
+            (Some(transcript), Some(rate))
+        }
+        _ => (None, None),
+    };
+
+    Ok(FixtureResult {
+        file: clip.file.clone(),
+        passed: failures.is_empty(),
+        failures,
+        duration_secs,
+        tone_hz,
+        rms,
+        transcript,
+        word_error_rate,
+    })
+}
+
+/// Word-level edits needed to turn `actual` into `expected`, per expected
+/// word. Case and punctuation are ignored.
+pub fn word_error_rate(expected: &str, actual: &str) -> f32 {
+    let words = expected.split_whitespace().count();
+    let edits = word_edit_distance(expected, actual);
+    if words == 0 {
+        return if edits == 0 { 0.0 } else { 1.0 };
+    }
+    edits as f32 / words as f32
+}
+
+fn within(measured: f32, expected: f32, tolerance: f32) -> bool {
+    (measured - expected).abs() <= expected.abs() * tolerance
+}
+
+/// The middle 80% of the clip, away from resampler edge effects and fades.
+fn middle(samples: &[f32]) -> &[f32] {
+    let margin = samples.len() / 10;
+    &samples[margin..samples.len() - margin]
+}
+
+fn rms(samples: &[f32]) -> f32 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+    (samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32).sqrt()
+}
+
+/// Frequency of a pure tone from its rising zero crossings.
+fn zero_crossing_frequency(samples: &[f32]) -> f32 {
+    let crossings: Vec<usize> = samples
+        .windows(2)
+        .enumerate()
+        .filter(|(_, pair)| pair[0] < 0.0 && pair[1] >= 0.0)
+        .map(|(i, _)| i)
+        .collect();
+    match (crossings.first(), crossings.last()) {
+        (Some(first), Some(last)) if last > first => {
+            (crossings.len() - 1) as f32 * SAMPLE_RATE / (last - first) as f32
+        }
+        _ => 0.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measures_tones() {
+        let tone: Vec<f32> = (0..16000)
+            .map(|i| 0.5 * (2.0 * std::f32::consts::PI * 440.0 * i as f32 / SAMPLE_RATE).sin())
+            .collect();
+        assert!(within(zero_crossing_frequency(&tone), 440.0, 0.005));
+        assert!(within(rms(&tone), 0.5 / 2f32.sqrt(), 0.005));
+    }
+
+    #[test]
+    fn word_error_rate_ignores_case_and_punctuation() {
+        assert_eq!(word_error_rate("Hello, world.", "hello world"), 0.0);
+        assert_eq!(
+            word_error_rate("the quick brown fox", "the quick fox"),
+            0.25
+        );
+        assert_eq!(word_error_rate("", ""), 0.0);
+        assert_eq!(word_error_rate("", "you"), 1.0);
+    }
+
+    #[test]
+    fn speech_clips_need_a_verification() {
+        let unverified = r#"{"clips": [
+            {"file": "speech.wav", "duration_secs": 4.0, "expected_text": "Hello there"}
+        ]}"#;
+        assert!(parse_manifest(unverified).is_err());
+
+        let verified = r#"{"clips": [
+            {"file": "speech.wav", "duration_secs": 4.0, "expected_text": "Hello there",
+             "verified_with": {"source": "LibriSpeech, CC BY 4.0", "model": "ggml-tiny.en", "word_error_rate": 0.0}}
+        ]}"#;
+        let clips = parse_manifest(verified).unwrap();
+        assert_eq!(
+            clips[0].verified_with.as_ref().unwrap().model,
+            "ggml-tiny.en"
+        );
+    }
+}
@@ -1,12 +1,15 @@
 pub mod audio;
-pub mod constants;
-pub mod text;
+pub mod backend;
+pub mod fixture;
+pub mod thread_priority;
 pub mod utils;
-pub mod vad;
+
+pub use echo_transcribe::{constants, text, transcriber, vad};
 
 pub use audio::{
-    list_input_devices, list_output_devices, load_wav_file, save_wav_file, AudioRecorder,
-    CpalDeviceInfo,
+    append_recording, encode_wav, list_input_devices, list_output_devices, load_recording,
+    load_wav_file, recording_duration_secs, save_recording, save_wav_file, AudioRecorder,
+    CpalDeviceInfo, RecordingFormat,
 };
 pub use text::apply_custom_words;
 pub use utils::get_cpal_host;
@@ -0,0 +1,133 @@
+//! Scheduling priority for the calling thread.
+//!
+//! Audio capture has to keep up with the device in real time, while model
+//! inference can saturate every core for seconds. Raising the capture threads
+//! and lowering background work keeps the input buffer from overflowing
+//! (audible as dropouts) while a large model is busy.
+
+use log::{debug, warn};
+use std::io;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ThreadPriority {
+    /// Audio capture: realtime scheduling where the OS allows it, otherwise
+    /// the highest regular priority.
+    Realtime,
+    /// Work nobody is waiting on, like partial transcriptions and indexing.
+    Background,
+}
+
+/// Set the priority of the calling thread.
+pub fn set_current_thread_priority(priority: ThreadPriority) -> io::Result<()> {
+    platform::set_priority(priority)
+}
+
+/// Like [`set_current_thread_priority`], but only logs failures. Raising
+/// priority often needs privileges the app doesn't have, which is not an error.
+pub fn apply_current_thread_priority(priority: ThreadPriority) {
+    let thread = std::thread::current();
+    let name = thread.name().unwrap_or("unnamed");
+    match set_current_thread_priority(priority) {
+        Ok(()) => debug!("Set {:?} priority for thread '{}'", priority, name),
+        Err(e) if priority == ThreadPriority::Realtime => {
+            debug!("Could not raise priority of thread '{}': {}", name, e)
+        }
+        Err(e) => warn!("Could not lower priority of thread '{}': {}", name, e),
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use super::ThreadPriority;
+    use std::io;
+
+    /// SCHED_FIFO priority for capture; low in the 1-99 range so it never
+    /// competes with the audio server itself.
+    const REALTIME_PRIORITY: libc::c_int = 10;
+    const HIGH_NICE: libc::c_int = -10;
+    const BACKGROUND_NICE: libc::c_int = 10;
+
+    pub fn set_priority(priority: ThreadPriority) -> io::Result<()> {
+        match priority {
+            ThreadPriority::Realtime => {
+                let param = libc::sched_param {
+                    sched_priority: REALTIME_PRIORITY,
+                };
+                // SAFETY: pthread_self() is always a valid handle for the calling thread
+                let rc = unsafe {
+                    libc::pthread_setschedparam(libc::pthread_self(), libc::SCHED_FIFO, &param)
+                };
+                if rc == 0 {
+                    return Ok(());
+                }
+                // SCHED_FIFO needs CAP_SYS_NICE or an rtprio limit; a negative
+                // nice value may still be allowed by RLIMIT_NICE
+                set_nice(HIGH_NICE).map_err(|_| io::Error::from_raw_os_error(rc))
+            }
+            ThreadPriority::Background => set_nice(BACKGROUND_NICE),
+        }
+    }
+
+    /// On Linux, setpriority() with a thread id applies to that thread only.
+    fn set_nice(nice: libc::c_int) -> io::Result<()> {
+        // SAFETY: gettid has no preconditions
+        let tid = unsafe { libc::syscall(libc::SYS_gettid) } as libc::id_t;
+        // SAFETY: plain syscall on the calling thread's id
+        if unsafe { libc::setpriority(libc::PRIO_PROCESS, tid, nice) } == 0 {
+            Ok(())
+        } else {
+            Err(io::Error::last_os_error())
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod platform {
+    use super::ThreadPriority;
+    use std::io;
+
+    pub fn set_priority(priority: ThreadPriority) -> io::Result<()> {
+        let class = match priority {
+            ThreadPriority::Realtime => libc::qos_class_t::QOS_CLASS_USER_INTERACTIVE,
+            // QOS_CLASS_BACKGROUND also throttles I/O, which makes partial
+            // transcriptions uselessly slow
+            ThreadPriority::Background => libc::qos_class_t::QOS_CLASS_UTILITY,
+        };
+        // SAFETY: only affects the calling thread
+        let rc = unsafe { libc::pthread_set_qos_class_self_np(class, 0) };
+        if rc == 0 {
+            Ok(())
+        } else {
+            Err(io::Error::from_raw_os_error(rc))
+        }
+    }
+}
+
+#[cfg(windows)]
+mod platform {
+    use super::ThreadPriority;
+    use std::io;
+    use windows::Win32::System::Threading::{
+        GetCurrentThread, SetThreadPriority, THREAD_PRIORITY_LOWEST, THREAD_PRIORITY_TIME_CRITICAL,
+    };
+
+    pub fn set_priority(priority: ThreadPriority) -> io::Result<()> {
+        let level = match priority {
+            ThreadPriority::Realtime => THREAD_PRIORITY_TIME_CRITICAL,
+            ThreadPriority::Background => THREAD_PRIORITY_LOWEST,
+        };
+        // SAFETY: GetCurrentThread returns a pseudo handle that needs no closing
+        unsafe { SetThreadPriority(GetCurrentThread(), level) }
+            .map_err(|e| io::Error::other(e.to_string()))
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", windows)))]
+mod platform {
+    use super::ThreadPriority;
+    use std::io;
+
+    pub fn set_priority(_priority: ThreadPriority) -> io::Result<()> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+}
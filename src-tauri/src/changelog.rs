@@ -0,0 +1,113 @@
+//! In-app changelog, parsed from the bundled `CHANGELOG.md`.
+//!
+//! Releases are `## [x.y.z] - date` headings with `### Section` subheadings
+//! and `- item` bullets (Keep a Changelog format).
+
+use serde::Serialize;
+
+const CHANGELOG: &str = include_str!("../../CHANGELOG.md");
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ChangelogSection {
+    pub title: String,
+    pub items: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ChangelogRelease {
+    pub version: String,
+    pub date: Option<String>,
+    pub sections: Vec<ChangelogSection>,
+}
+
+/// Releases newer than `version`, newest first. All releases without one.
+pub fn releases_since(version: Option<&str>) -> Vec<ChangelogRelease> {
+    let since = version.map(parse_version);
+    parse(CHANGELOG)
+        .into_iter()
+        .filter(|release| {
+            since
+                .as_ref()
+                .map_or(true, |since| parse_version(&release.version) > *since)
+        })
+        .collect()
+}
+
+fn parse(markdown: &str) -> Vec<ChangelogRelease> {
+    let mut releases: Vec<ChangelogRelease> = Vec::new();
+    for line in markdown.lines().map(str::trim_end) {
+        if let Some(heading) = line.strip_prefix("## ") {
+            let (version, date) = heading.split_once(" - ").unwrap_or((heading, ""));
+            releases.push(ChangelogRelease {
+                version: version.trim_matches(|c| c == '[' || c == ']').to_string(),
+                date: (!date.is_empty()).then(|| date.trim().to_string()),
+                sections: Vec::new(),
+            });
+        } else if let Some(title) = line.strip_prefix("### ") {
+            if let Some(release) = releases.last_mut() {
+                release.sections.push(ChangelogSection {
+                    title: title.trim().to_string(),
+                    items: Vec::new(),
+                });
+            }
+        } else if let Some(item) = line.trim_start().strip_prefix("- ") {
+            if let Some(section) = releases
+                .last_mut()
+                .and_then(|release| release.sections.last_mut())
+            {
+                section.items.push(item.trim().to_string());
+            }
+        }
+    }
+    releases
+}
+
+/// Numeric version components, so 0.10.0 sorts after 0.9.0. Anything that
+/// isn't a number (e.g. "Unreleased") sorts as newest.
+fn parse_version(version: &str) -> Vec<u64> {
+    let version = version.trim().trim_start_matches('v');
+    version
+        .split(['.', '-'])
+        .map(|part| part.parse().unwrap_or(u64::MAX))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "# Changelog\n\n\
+        ## [Unreleased]\n\n### Added\n- Something new\n\n\
+        ## [0.10.0] - 2025-08-01\n\n### Added\n- **Bold** feature\n- Another\n\n### Fixed\n- A bug\n\n\
+        ## [0.9.1] - 2025-07-20\n\n### Fixed\n- Crash\n";
+
+    #[test]
+    fn parses_releases_sections_and_items() {
+        let releases = parse(SAMPLE);
+        assert_eq!(releases.len(), 3);
+        assert_eq!(releases[1].version, "0.10.0");
+        assert_eq!(releases[1].date.as_deref(), Some("2025-08-01"));
+        assert_eq!(
+            releases[1].sections[0].items,
+            ["**Bold** feature", "Another"]
+        );
+        assert_eq!(releases[1].sections[1].title, "Fixed");
+        assert_eq!(releases[0].date, None);
+    }
+
+    #[test]
+    fn compares_versions_numerically() {
+        assert!(parse_version("0.10.0") > parse_version("0.9.1"));
+        assert!(parse_version("v1.0.0") > parse_version("0.10.0"));
+        assert!(parse_version("Unreleased") > parse_version("99.0.0"));
+        assert_eq!(parse_version("0.3.0"), parse_version("v0.3.0"));
+    }
+
+    #[test]
+    fn bundled_changelog_parses() {
+        let releases = releases_since(None);
+        assert!(!releases.is_empty());
+        assert!(releases.iter().all(|release| !release.sections.is_empty()));
+        assert!(releases_since(Some("999.0.0")).is_empty());
+    }
+}
@@ -1,9 +1,15 @@
-use crate::settings::{get_settings, ClipboardHandling, PasteMethod};
+use crate::helpers::{focused_text, native_indicator};
+use crate::managers::input_tracker::{
+    get_active_app_info_fast, get_active_window_context, ActiveAppInfo, InputTrackerManager,
+    WindowContext, WindowExclusionRules,
+};
+use crate::settings::{get_settings, AppSettings, ClipboardHandling, PasteMethod};
 use enigo::Enigo;
 use enigo::Key;
 use enigo::Keyboard;
 use enigo::Settings;
-use tauri::AppHandle;
+use serde::Serialize;
+use tauri::{AppHandle, Emitter};
 use tauri_plugin_clipboard_manager::ClipboardExt;
 
 // Wayland auto-paste: not supported.
@@ -81,8 +87,10 @@ fn send_paste_shift_insert() -> Result<(), String> {
     Ok(())
 }
 
-/// Pastes text directly using the enigo text method.
-/// This tries to use system input methods if possible, otherwise simulates keystrokes one by one.
+/// Pastes text directly by typing it.
+/// Characters the active keyboard layout has a key for (without AltGr) are typed with that
+/// key, so apps see the same keystrokes as from the keyboard whatever the layout. The rest
+/// goes through the enigo text method, which uses system input methods where possible.
 /// NOTE: Only available on Linux. On macOS, this causes cascading suffix duplication
 /// in terminals like Ghostty due to CGEvent handling issues.
 /// On Linux, enigo uses X11/libxdo, so this only works on X11 (not Wayland).
@@ -91,8 +99,8 @@ fn paste_via_direct_input(text: &str) -> Result<(), String> {
     log::debug!(
         "paste_via_direct_input: Starting direct input, text length: {}, text: '{}'",
         text.len(),
-        if text.len() > 100 {
-            format!("{}...", &text[..100])
+        if text.chars().count() > 100 {
+            format!("{}...", text.chars().take(100).collect::<String>())
         } else {
             text.to_string()
         }
@@ -101,9 +109,40 @@ fn paste_via_direct_input(text: &str) -> Result<(), String> {
     let mut enigo = Enigo::new(&Settings::default())
         .map_err(|e| format!("Failed to initialize Enigo: {}", e))?;
 
-    enigo
-        .text(text)
-        .map_err(|e| format!("Failed to send text directly: {}", e))?;
+    let mut pending = String::new();
+    for c in text.chars() {
+        let key = crate::keyboard_layout::key_for_char(c).filter(|(_, level)| !level.alt_gr);
+        let Some((code, level)) = key else {
+            pending.push(c);
+            continue;
+        };
+
+        if !pending.is_empty() {
+            enigo
+                .text(&pending)
+                .map_err(|e| format!("Failed to send text directly: {}", e))?;
+            pending.clear();
+        }
+        if level.shift {
+            enigo
+                .key(Key::Shift, enigo::Direction::Press)
+                .map_err(|e| format!("Failed to press Shift key: {}", e))?;
+        }
+        let typed = enigo
+            .raw(code as u16, enigo::Direction::Click)
+            .map_err(|e| format!("Failed to type '{}': {}", c, e));
+        if level.shift {
+            enigo
+                .key(Key::Shift, enigo::Direction::Release)
+                .map_err(|e| format!("Failed to release Shift key: {}", e))?;
+        }
+        typed?;
+    }
+    if !pending.is_empty() {
+        enigo
+            .text(&pending)
+            .map_err(|e| format!("Failed to send text directly: {}", e))?;
+    }
 
     log::debug!("paste_via_direct_input: Text sent successfully");
 
@@ -183,6 +222,159 @@ fn paste_via_clipboard_shift_insert(text: &str, app_handle: &AppHandle) -> Resul
     Ok(())
 }
 
+/// Emitted when a keystroke paste couldn't be performed and the text was copied
+/// to the clipboard instead.
+#[derive(Clone, Debug, Serialize)]
+pub struct PasteFallbackEvent {
+    /// `"no_paste_target"`, `"accessibility_permission_missing"`,
+    /// `"no_text_field"` or `"keystroke_failed"`.
+    pub reason: String,
+    pub message: String,
+}
+
+/// Whether the OS allows simulated keystrokes. On macOS, posting events without
+/// the Accessibility permission is silently dropped, so check up front.
+pub(crate) fn keystroke_paste_permitted() -> bool {
+    #[cfg(target_os = "macos")]
+    {
+        #[link(name = "ApplicationServices", kind = "framework")]
+        extern "C" {
+            fn AXIsProcessTrusted() -> bool;
+        }
+        unsafe { AXIsProcessTrusted() }
+    }
+    #[cfg(not(target_os = "macos"))]
+    {
+        true
+    }
+}
+
+/// Why a paste went to the clipboard instead of the focused app.
+#[derive(Debug, PartialEq)]
+enum PasteFallback {
+    /// The focused app or window, by name, is on the do-not-paste lists.
+    BlockedTarget(String),
+    PermissionMissing,
+    NoTextField,
+    /// Sending the paste keystroke failed, with the error.
+    KeystrokeFailed(String),
+}
+
+impl PasteFallback {
+    fn reason(&self) -> &'static str {
+        match self {
+            Self::BlockedTarget(_) => "no_paste_target",
+            Self::PermissionMissing => "accessibility_permission_missing",
+            Self::NoTextField => "no_text_field",
+            Self::KeystrokeFailed(_) => "keystroke_failed",
+        }
+    }
+
+    fn message(&self) -> String {
+        let cause = match self {
+            Self::BlockedTarget(target) => format!("Echo doesn't paste into {}.", target),
+            Self::PermissionMissing => {
+                "Accessibility permission is required to paste automatically.".to_string()
+            }
+            Self::NoTextField => "No text field has focus.".to_string(),
+            Self::KeystrokeFailed(e) => format!("Automatic paste failed ({}).", e),
+        };
+        format!("{} The text was copied to the clipboard instead.", cause)
+    }
+}
+
+/// Whether a keystroke paste must go to the clipboard instead, checked in
+/// order: the do-not-paste lists, the permission to send keystrokes, then a
+/// focused text field when `verify_target` is set. Clipboard-only pastes
+/// aren't checked.
+fn check_paste(
+    method: PasteMethod,
+    verify_target: bool,
+    blocked_target: impl FnOnce() -> Option<String>,
+    keystrokes_permitted: impl FnOnce() -> bool,
+    takes_text: impl FnOnce() -> Option<bool>,
+) -> Option<PasteFallback> {
+    if method == PasteMethod::ClipboardOnly {
+        return None;
+    }
+    // Never type into apps or windows the user ruled out, like a terminal
+    // with a production SSH session
+    if let Some(target) = blocked_target() {
+        return Some(PasteFallback::BlockedTarget(target));
+    }
+    if !keystrokes_permitted() {
+        return Some(PasteFallback::PermissionMissing);
+    }
+    // A paste keystroke with no text field focused lands in whatever is
+    // frontmost, where it can trigger shortcuts or do nothing at all
+    if verify_target && takes_text() == Some(false) {
+        return Some(PasteFallback::NoTextField);
+    }
+    None
+}
+
+/// Copy the text to the clipboard instead and tell the UI why.
+fn fall_back_to_clipboard(
+    text: &str,
+    app_handle: &AppHandle,
+    fallback: PasteFallback,
+) -> Result<(), String> {
+    let message = fallback.message();
+    log::warn!(
+        "Paste fell back to clipboard ({}): {}",
+        fallback.reason(),
+        message
+    );
+    copy_to_clipboard(text, app_handle)?;
+    if let PasteFallback::BlockedTarget(_) = fallback {
+        // The settings window is usually hidden, so tell the user outside it
+        native_indicator::notify(app_handle, &message);
+    }
+    let _ = app_handle.emit(
+        "paste-fallback",
+        PasteFallbackEvent {
+            reason: fallback.reason().to_string(),
+            message,
+        },
+    );
+    Ok(())
+}
+
+/// Name of the focused app or window when it's on the do-not-paste lists.
+fn blocked_paste_target(settings: &AppSettings) -> Option<String> {
+    find_blocked_target(
+        &settings.no_paste_apps,
+        &settings.no_paste_window_patterns,
+        get_active_app_info_fast,
+        get_active_window_context,
+    )
+}
+
+/// Name of the `app` or `window` that `apps` or window `patterns` rule out.
+/// Each is only looked up when a list could match it.
+fn find_blocked_target(
+    apps: &[String],
+    patterns: &[String],
+    app: impl FnOnce() -> ActiveAppInfo,
+    window: impl FnOnce() -> WindowContext,
+) -> Option<String> {
+    if apps.is_empty() && patterns.is_empty() {
+        return None;
+    }
+    let app = app();
+    if InputTrackerManager::is_app_excluded(apps, &app) {
+        return Some(app.name);
+    }
+    let rules = WindowExclusionRules::compile(patterns);
+    if rules.is_empty() {
+        return None;
+    }
+    let window = window();
+    rules
+        .matches(&window)
+        .then(|| window.title.unwrap_or(app.name))
+}
+
 fn copy_to_clipboard(text: &str, app_handle: &AppHandle) -> Result<(), String> {
     let clipboard = app_handle.clipboard();
     clipboard
@@ -192,6 +384,32 @@ fn copy_to_clipboard(text: &str, app_handle: &AppHandle) -> Result<(), String> {
     Ok(())
 }
 
+/// Verify paste prerequisites without touching the focused app: the clipboard
+/// round-trips a marker string (into a scratch value that is restored
+/// afterwards) and the keyboard simulator can be initialized.
+pub fn self_test_paste(app_handle: &AppHandle) -> Result<(), String> {
+    const MARKER: &str = "echo-self-test";
+    let clipboard = app_handle.clipboard();
+    let original = clipboard.read_text().unwrap_or_default();
+
+    clipboard
+        .write_text(MARKER)
+        .map_err(|e| format!("Failed to write to clipboard: {}", e))?;
+    std::thread::sleep(std::time::Duration::from_millis(50));
+    let read_back = clipboard.read_text().unwrap_or_default();
+
+    clipboard
+        .write_text(&original)
+        .map_err(|e| format!("Failed to restore clipboard: {}", e))?;
+
+    if read_back != MARKER {
+        return Err("Clipboard did not return the written text".to_string());
+    }
+
+    Enigo::new(&Settings::default()).map_err(|e| format!("Failed to initialize Enigo: {}", e))?;
+    Ok(())
+}
+
 pub fn paste(text: String, app_handle: AppHandle) -> Result<(), String> {
     let settings = get_settings(&app_handle);
     #[allow(unused_mut)] // mutated only on Linux/Wayland
@@ -219,16 +437,31 @@ pub fn paste(text: String, app_handle: AppHandle) -> Result<(), String> {
         }
     );
 
+    let fallback = check_paste(
+        paste_method,
+        settings.verify_paste_target,
+        || blocked_paste_target(&settings),
+        keystroke_paste_permitted,
+        focused_text::focused_element_takes_text,
+    );
+    if let Some(fallback) = fallback {
+        return fall_back_to_clipboard(&text, &app_handle, fallback);
+    }
+
     // Perform the paste operation
-    match paste_method {
-        PasteMethod::CtrlV => paste_via_clipboard_ctrl_v(&text, &app_handle)?,
+    let paste_result = match paste_method {
+        PasteMethod::CtrlV => paste_via_clipboard_ctrl_v(&text, &app_handle),
         #[cfg(target_os = "linux")]
-        PasteMethod::Direct => paste_via_direct_input(&text)?,
+        PasteMethod::Direct => paste_via_direct_input(&text),
         #[cfg(not(target_os = "macos"))]
-        PasteMethod::ShiftInsert => paste_via_clipboard_shift_insert(&text, &app_handle)?,
+        PasteMethod::ShiftInsert => paste_via_clipboard_shift_insert(&text, &app_handle),
         PasteMethod::ClipboardOnly => {
             return copy_to_clipboard(&text, &app_handle);
         }
+    };
+
+    if let Err(e) = paste_result {
+        return fall_back_to_clipboard(&text, &app_handle, PasteFallback::KeystrokeFailed(e));
     }
 
     // After pasting, optionally copy to clipboard based on settings
@@ -252,6 +485,7 @@ pub fn paste(text: String, app_handle: AppHandle) -> Result<(), String> {
 /// WORKAROUND: Use CtrlV paste method instead of Direct for terminal applications.
 #[cfg(test)]
 mod tests {
+    use super::*;
     use crate::settings::PasteMethod;
 
     /// Helper function to detect consecutive duplicate words in text.
@@ -459,6 +693,128 @@ mod tests {
         assert!(!detect_cascading_suffix_pattern("short"));
     }
 
+    fn app(name: &str, bundle_id: Option<&str>) -> ActiveAppInfo {
+        ActiveAppInfo {
+            name: name.to_string(),
+            bundle_id: bundle_id.map(str::to_string),
+            pid: None,
+        }
+    }
+
+    fn window(title: &str, url: Option<&str>) -> WindowContext {
+        WindowContext {
+            title: Some(title.to_string()),
+            url: url.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn no_paste_lists_match_apps_then_windows() {
+        let apps = vec!["Terminal".to_string(), "com.example.vault".to_string()];
+        let patterns = vec!["prod-db".to_string()];
+        let find = |app: ActiveAppInfo, window: WindowContext| {
+            find_blocked_target(&apps, &patterns, || app, || window)
+        };
+
+        assert_eq!(
+            find(app("terminal", None), window("ssh", None)),
+            Some("terminal".to_string())
+        );
+        assert_eq!(
+            find(app("Vault", Some("com.example.vault")), window("", None)),
+            Some("Vault".to_string())
+        );
+        assert_eq!(
+            find(app("iTerm2", None), window("ssh prod-db-1", None)),
+            Some("ssh prod-db-1".to_string())
+        );
+        // Untitled windows are named after their app
+        let untitled = WindowContext {
+            title: None,
+            url: Some("https://prod-db.example.com".to_string()),
+        };
+        assert_eq!(
+            find(app("Safari", None), untitled),
+            Some("Safari".to_string())
+        );
+        assert_eq!(find(app("Notes", None), window("Groceries", None)), None);
+    }
+
+    #[test]
+    fn nothing_is_looked_up_without_no_paste_lists() {
+        let target = find_blocked_target(
+            &[],
+            &[],
+            || panic!("app looked up"),
+            || panic!("window looked up"),
+        );
+        assert_eq!(target, None);
+    }
+
+    #[test]
+    fn paste_checks_stop_at_the_first_fallback() {
+        let check = |blocked: Option<&str>, permitted: bool, takes_text: Option<bool>| {
+            check_paste(
+                PasteMethod::CtrlV,
+                true,
+                || blocked.map(str::to_string),
+                || permitted,
+                || takes_text,
+            )
+        };
+
+        assert_eq!(check(None, true, Some(true)), None);
+        // Unknown focus (no accessibility API) pastes anyway
+        assert_eq!(check(None, true, None), None);
+        assert_eq!(
+            check(Some("Terminal"), false, Some(false)),
+            Some(PasteFallback::BlockedTarget("Terminal".to_string()))
+        );
+        assert_eq!(
+            check(None, false, Some(false)),
+            Some(PasteFallback::PermissionMissing)
+        );
+        assert_eq!(
+            check(None, true, Some(false)),
+            Some(PasteFallback::NoTextField)
+        );
+    }
+
+    #[test]
+    fn clipboard_only_and_unverified_pastes_skip_checks() {
+        let clipboard_only = check_paste(
+            PasteMethod::ClipboardOnly,
+            true,
+            || panic!("lists checked"),
+            || panic!("permission checked"),
+            || panic!("focus checked"),
+        );
+        assert_eq!(clipboard_only, None);
+
+        let unverified = check_paste(
+            PasteMethod::CtrlV,
+            false,
+            || None,
+            || true,
+            || panic!("focus checked"),
+        );
+        assert_eq!(unverified, None);
+    }
+
+    #[test]
+    fn fallback_messages_say_where_the_text_went() {
+        let fallback = PasteFallback::BlockedTarget("Terminal".to_string());
+        assert_eq!(fallback.reason(), "no_paste_target");
+        assert_eq!(
+            fallback.message(),
+            "Echo doesn't paste into Terminal. The text was copied to the clipboard instead."
+        );
+        assert_eq!(
+            PasteFallback::KeystrokeFailed("no display".to_string()).message(),
+            "Automatic paste failed (no display). The text was copied to the clipboard instead."
+        );
+    }
+
     /// FIXED: Direct paste is no longer available on macOS.
     ///
     /// The fix was to disable Direct paste option on macOS entirely (Option 1).
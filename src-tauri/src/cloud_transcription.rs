@@ -0,0 +1,294 @@
+//! Cloud speech-to-text for machines too slow for local inference.
+//!
+//! The recording is uploaded as a 16 kHz WAV to the active transcription
+//! provider: OpenAI and Groq share the OpenAI `/audio/transcriptions` API,
+//! Deepgram takes the raw WAV on `/listen`.
+
+use crate::audio_toolkit::encode_wav;
+use crate::settings::{AppSettings, TranscriptionProvider};
+use serde::Deserialize;
+use std::time::Duration;
+use transcribe_rs::{TranscriptionResult, TranscriptionSegment};
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(60);
+const BOUNDARY: &str = "----echo-transcription-boundary";
+
+#[derive(Debug)]
+pub enum CloudError {
+    /// The provider couldn't be reached or is having trouble; the local model
+    /// should take over.
+    Unreachable(String),
+    /// The provider answered but refused the request (bad key, bad model).
+    Rejected(String),
+}
+
+impl std::fmt::Display for CloudError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CloudError::Unreachable(msg) | CloudError::Rejected(msg) => f.write_str(msg),
+        }
+    }
+}
+
+impl std::error::Error for CloudError {}
+
+/// Transcribe `samples` with the active provider. Blocks until the provider
+/// answers.
+pub fn transcribe(
+    settings: &AppSettings,
+    samples: &[f32],
+) -> Result<TranscriptionResult, CloudError> {
+    let provider = settings
+        .active_transcription_provider()
+        .ok_or_else(|| CloudError::Rejected("No transcription provider configured".to_string()))?
+        .clone();
+    let api_key = settings
+        .transcription_api_keys
+        .get(&provider.id)
+        .cloned()
+        .unwrap_or_default();
+    if api_key.is_empty() {
+        return Err(CloudError::Rejected(format!(
+            "No API key set for {}",
+            provider.label
+        )));
+    }
+    let model = settings
+        .transcription_models
+        .get(&provider.id)
+        .cloned()
+        .unwrap_or_default();
+    let language = settings.transcription_language();
+    let wav = encode_wav(samples).map_err(|e| CloudError::Rejected(e.to_string()))?;
+
+    // Transcription runs on blocking threads and inside async commands alike,
+    // so the request gets a thread of its own to block on
+    std::thread::scope(|scope| {
+        scope
+            .spawn(|| {
+                tauri::async_runtime::block_on(async {
+                    if provider.id == "deepgram" {
+                        transcribe_deepgram(&provider, &api_key, &model, language, wav).await
+                    } else {
+                        transcribe_openai(&provider, &api_key, &model, language, wav).await
+                    }
+                })
+            })
+            .join()
+            .unwrap_or_else(|_| Err(CloudError::Unreachable("Upload thread panicked".into())))
+    })
+}
+
+#[derive(Deserialize)]
+struct OpenAiTranscription {
+    text: String,
+    #[serde(default)]
+    segments: Option<Vec<OpenAiSegment>>,
+}
+
+#[derive(Deserialize)]
+struct OpenAiSegment {
+    start: f32,
+    end: f32,
+    text: String,
+}
+
+async fn transcribe_openai(
+    provider: &TranscriptionProvider,
+    api_key: &str,
+    model: &str,
+    language: Option<&str>,
+    wav: Vec<u8>,
+) -> Result<TranscriptionResult, CloudError> {
+    let mut fields = vec![("model", model), ("response_format", "verbose_json")];
+    if let Some(language) = language {
+        fields.push(("language", language));
+    }
+    let endpoint = format!(
+        "{}/audio/transcriptions",
+        provider.base_url.trim_end_matches('/')
+    );
+    let response = client()?
+        .post(&endpoint)
+        .bearer_auth(api_key)
+        .header(
+            reqwest::header::CONTENT_TYPE,
+            format!("multipart/form-data; boundary={}", BOUNDARY),
+        )
+        .body(multipart_body(&fields, &wav))
+        .send()
+        .await
+        .map_err(|e| {
+            CloudError::Unreachable(format!("{} request failed: {}", provider.label, e))
+        })?;
+
+    let parsed: OpenAiTranscription = read_json(provider, response).await?;
+    Ok(TranscriptionResult {
+        text: parsed.text.trim().to_string(),
+        segments: parsed.segments.map(|segments| {
+            segments
+                .into_iter()
+                .map(|segment| TranscriptionSegment {
+                    start: segment.start,
+                    end: segment.end,
+                    text: segment.text,
+                })
+                .collect()
+        }),
+    })
+}
+
+#[derive(Deserialize)]
+struct DeepgramResponse {
+    results: DeepgramResults,
+}
+
+#[derive(Deserialize)]
+struct DeepgramResults {
+    channels: Vec<DeepgramChannel>,
+}
+
+#[derive(Deserialize)]
+struct DeepgramChannel {
+    alternatives: Vec<DeepgramAlternative>,
+}
+
+#[derive(Deserialize)]
+struct DeepgramAlternative {
+    transcript: String,
+    #[serde(default)]
+    words: Vec<DeepgramWord>,
+}
+
+#[derive(Deserialize)]
+struct DeepgramWord {
+    word: String,
+    #[serde(default)]
+    punctuated_word: Option<String>,
+    start: f32,
+    end: f32,
+}
+
+async fn transcribe_deepgram(
+    provider: &TranscriptionProvider,
+    api_key: &str,
+    model: &str,
+    language: Option<&str>,
+    wav: Vec<u8>,
+) -> Result<TranscriptionResult, CloudError> {
+    let mut query = vec![("model", model), ("smart_format", "true")];
+    match language {
+        Some(language) => query.push(("language", language)),
+        None => query.push(("detect_language", "true")),
+    }
+    let endpoint = format!("{}/listen", provider.base_url.trim_end_matches('/'));
+    let response = client()?
+        .post(&endpoint)
+        .query(&query)
+        .header(reqwest::header::AUTHORIZATION, format!("Token {}", api_key))
+        .header(reqwest::header::CONTENT_TYPE, "audio/wav")
+        .body(wav)
+        .send()
+        .await
+        .map_err(|e| {
+            CloudError::Unreachable(format!("{} request failed: {}", provider.label, e))
+        })?;
+
+    let parsed: DeepgramResponse = read_json(provider, response).await?;
+    let Some(alternative) = parsed
+        .results
+        .channels
+        .into_iter()
+        .next()
+        .and_then(|channel| channel.alternatives.into_iter().next())
+    else {
+        return Ok(TranscriptionResult {
+            text: String::new(),
+            segments: None,
+        });
+    };
+    Ok(TranscriptionResult {
+        text: alternative.transcript.trim().to_string(),
+        segments: Some(
+            alternative
+                .words
+                .into_iter()
+                .map(|word| TranscriptionSegment {
+                    start: word.start,
+                    end: word.end,
+                    text: word.punctuated_word.unwrap_or(word.word),
+                })
+                .collect(),
+        ),
+    })
+}
+
+fn client() -> Result<reqwest::Client, CloudError> {
+    reqwest::Client::builder()
+        .timeout(REQUEST_TIMEOUT)
+        .build()
+        .map_err(|e| CloudError::Unreachable(format!("Failed to build HTTP client: {}", e)))
+}
+
+/// Parse a successful reply. Server errors and rate limits count as the
+/// provider being unavailable; other failures are the request's fault.
+async fn read_json<T: serde::de::DeserializeOwned>(
+    provider: &TranscriptionProvider,
+    response: reqwest::Response,
+) -> Result<T, CloudError> {
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        let msg = format!("{} returned {}: {}", provider.label, status, body.trim());
+        return Err(
+            if status.is_server_error() || status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+                CloudError::Unreachable(msg)
+            } else {
+                CloudError::Rejected(msg)
+            },
+        );
+    }
+    response.json().await.map_err(|e| {
+        CloudError::Unreachable(format!("Failed to read {} response: {}", provider.label, e))
+    })
+}
+
+/// ISO 639-1 code for the selected language, `None` to let the provider detect it.
+/// A `multipart/form-data` body with text `fields` and the recording as `file`.
+fn multipart_body(fields: &[(&str, &str)], wav: &[u8]) -> Vec<u8> {
+    let mut body = Vec::with_capacity(wav.len() + 512);
+    for (name, value) in fields {
+        body.extend_from_slice(
+            format!(
+                "--{}\r\nContent-Disposition: form-data; name=\"{}\"\r\n\r\n{}\r\n",
+                BOUNDARY, name, value
+            )
+            .as_bytes(),
+        );
+    }
+    body.extend_from_slice(
+        format!(
+            "--{}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"audio.wav\"\r\nContent-Type: audio/wav\r\n\r\n",
+            BOUNDARY
+        )
+        .as_bytes(),
+    );
+    body.extend_from_slice(wav);
+    body.extend_from_slice(format!("\r\n--{}--\r\n", BOUNDARY).as_bytes());
+    body
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_multipart_body() {
+        let body = multipart_body(&[("model", "whisper-1")], b"RIFF");
+        let text = String::from_utf8(body).unwrap();
+        assert!(text.starts_with(&format!("--{}\r\n", BOUNDARY)));
+        assert!(text.contains("name=\"model\"\r\n\r\nwhisper-1\r\n"));
+        assert!(text.contains("filename=\"audio.wav\"\r\nContent-Type: audio/wav\r\n\r\nRIFF"));
+        assert!(text.ends_with(&format!("\r\n--{}--\r\n", BOUNDARY)));
+    }
+}
@@ -0,0 +1,26 @@
+//! Tauri command handlers for the backend action registry.
+
+use crate::action_registry::{self, ActionDef};
+use serde_json::Value;
+use tauri::AppHandle;
+
+/// Every action `invoke_action` can run, for the command palette.
+#[tauri::command]
+pub fn list_actions() -> &'static [ActionDef] {
+    action_registry::ACTIONS
+}
+
+/// Run the registered action `id`. `params` is a JSON object matching the
+/// action's parameters and may be omitted when it has none.
+#[tauri::command]
+pub async fn invoke_action(
+    app: AppHandle,
+    id: String,
+    params: Option<Value>,
+) -> Result<Value, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        action_registry::invoke(&app, &id, params.unwrap_or(Value::Null))
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
@@ -1,11 +1,18 @@
 use crate::audio_feedback;
-use crate::audio_toolkit::audio::{list_input_devices, list_output_devices};
+use crate::audio_toolkit::audio::{
+    list_input_devices, list_output_devices, measure_input_quality, AudioRecorder, InputQuality,
+};
 use crate::managers::audio::{AudioRecordingManager, MicrophoneMode};
 use crate::settings;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
 use tauri::{AppHandle, Manager};
 
+/// How long each microphone is recorded when they're compared.
+const SCORE_RECORDING: Duration = Duration::from_secs(1);
+
 #[derive(Serialize)]
 pub struct CustomSounds {
     start: bool,
@@ -54,6 +61,13 @@ pub fn update_microphone_mode(app: AppHandle, always_on: bool) -> Result<(), Str
         .map_err(|e| format!("Failed to update microphone mode: {}", e))
 }
 
+/// List the binding ids that are currently recording (or about to).
+#[tauri::command]
+pub fn get_active_recordings(app: AppHandle) -> Vec<String> {
+    let rm = app.state::<Arc<AudioRecordingManager>>();
+    rm.active_bindings()
+}
+
 #[tauri::command]
 pub fn get_microphone_mode(app: AppHandle) -> Result<bool, String> {
     let s = settings::get_settings(&app);
@@ -80,6 +94,73 @@ pub fn get_available_microphones() -> Result<Vec<AudioDevice>, String> {
     Ok(result)
 }
 
+/// How one microphone did in `score_input_devices`.
+#[derive(Serialize, Debug, Clone)]
+pub struct InputDeviceScore {
+    pub name: String,
+    pub is_default: bool,
+    /// `None` when nothing could be recorded from it.
+    pub quality: Option<InputQuality>,
+    pub error: Option<String>,
+}
+
+/// Record a second from every input device and rank them, best first, so
+/// the settings can recommend one. Devices that couldn't be recorded from
+/// come last.
+#[tauri::command]
+pub async fn score_input_devices(app: AppHandle) -> Result<Vec<InputDeviceScore>, String> {
+    if app.state::<Arc<AudioRecordingManager>>().is_recording() {
+        return Err("Finish recording before testing microphones".to_string());
+    }
+    tauri::async_runtime::spawn_blocking(score_devices)
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+fn score_devices() -> Result<Vec<InputDeviceScore>, String> {
+    let devices =
+        list_input_devices().map_err(|e| format!("Failed to list audio devices: {}", e))?;
+
+    let mut scores: Vec<InputDeviceScore> = devices
+        .into_iter()
+        .map(|d| {
+            let measured = record_sample(d.device).and_then(|samples| {
+                measure_input_quality(&samples).ok_or_else(|| "No audio was captured".to_string())
+            });
+            if let Err(e) = &measured {
+                log::warn!("Failed to test microphone '{}': {}", d.name, e);
+            }
+            InputDeviceScore {
+                name: d.name,
+                is_default: d.is_default,
+                quality: measured.as_ref().ok().copied(),
+                error: measured.err(),
+            }
+        })
+        .collect();
+
+    let score = |s: &InputDeviceScore| s.quality.map_or(f32::NEG_INFINITY, |q| q.score);
+    scores.sort_by(|a, b| score(b).total_cmp(&score(a)));
+    Ok(scores)
+}
+
+/// A short recording from `device`, without voice detection.
+fn record_sample(device: cpal::Device) -> Result<Vec<f32>, String> {
+    let mut recorder = AudioRecorder::new().map_err(|e| e.to_string())?;
+    recorder
+        .open(Some(device))
+        .map_err(|e| format!("Failed to open device: {}", e))?;
+    let samples = recorder
+        .start(None)
+        .and_then(|_| {
+            thread::sleep(SCORE_RECORDING);
+            recorder.stop()
+        })
+        .map_err(|e| format!("Failed to record: {}", e));
+    let _ = recorder.close();
+    samples
+}
+
 #[tauri::command]
 pub fn set_selected_microphone(app: AppHandle, device_name: String) -> Result<(), String> {
     settings::update_settings(&app, |s| {
@@ -169,15 +250,32 @@ pub fn get_selected_output_device(app: AppHandle) -> Result<String, String> {
         .unwrap_or_else(|| "default".to_string()))
 }
 
+/// Set the output device for one feedback sound. "default" removes the
+/// override so the sound follows the feedback output device again.
 #[tauri::command]
-pub fn play_test_sound(app: AppHandle, sound_type: String) {
-    let sound = match sound_type.as_str() {
-        "start" => audio_feedback::SoundType::Start,
-        "stop" => audio_feedback::SoundType::Stop,
-        _ => {
-            log::warn!("Unknown sound type: {}", sound_type);
-            return;
+pub fn set_feedback_sound_device(
+    app: AppHandle,
+    sound_type: String,
+    device_name: String,
+) -> Result<(), String> {
+    let sound = audio_feedback::SoundType::from_id(&sound_type)
+        .ok_or_else(|| format!("Unknown sound type: {}", sound_type))?;
+    settings::update_settings(&app, |s| {
+        if device_name == "default" {
+            s.feedback_sound_devices.remove(sound.id());
+        } else {
+            s.feedback_sound_devices
+                .insert(sound.id().to_string(), device_name.clone());
         }
+    });
+    Ok(())
+}
+
+#[tauri::command]
+pub fn play_test_sound(app: AppHandle, sound_type: String) {
+    let Some(sound) = audio_feedback::SoundType::from_id(&sound_type) else {
+        log::warn!("Unknown sound type: {}", sound_type);
+        return;
     };
     audio_feedback::play_test_sound(&app, sound);
 }
@@ -0,0 +1,41 @@
+//! Tauri command handlers for time-of-day automation rules.
+
+use crate::managers::automation::{AutomationManager, AutomationRule, AutomationRuleInput};
+use std::sync::Arc;
+use tauri::State;
+
+#[tauri::command]
+pub fn list_automation_rules(
+    automation_manager: State<'_, Arc<AutomationManager>>,
+) -> Vec<AutomationRule> {
+    automation_manager.list()
+}
+
+#[tauri::command]
+pub fn create_automation_rule(
+    automation_manager: State<'_, Arc<AutomationManager>>,
+    rule: AutomationRuleInput,
+) -> Result<AutomationRule, String> {
+    automation_manager.create(rule).map_err(|e| e.to_string())
+}
+
+/// Replace a rule. It's applied again on the next check, even if its window
+/// was already open.
+#[tauri::command]
+pub fn update_automation_rule(
+    automation_manager: State<'_, Arc<AutomationManager>>,
+    id: u64,
+    rule: AutomationRuleInput,
+) -> Result<AutomationRule, String> {
+    automation_manager
+        .update(id, rule)
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn delete_automation_rule(
+    automation_manager: State<'_, Arc<AutomationManager>>,
+    id: u64,
+) -> Result<(), String> {
+    automation_manager.delete(id).map_err(|e| e.to_string())
+}
@@ -0,0 +1,49 @@
+//! Tauri command handlers for live captions.
+
+use crate::managers::captions::{CaptionLine, CaptionsManager, InterviewQuestion};
+use crate::managers::feature_flags::{FeatureFlag, FeatureFlagManager};
+use std::sync::Arc;
+use tauri::State;
+
+#[tauri::command]
+pub async fn start_live_captions(
+    captions_manager: State<'_, Arc<CaptionsManager>>,
+    feature_flag_manager: State<'_, Arc<FeatureFlagManager>>,
+) -> Result<(), String> {
+    if !feature_flag_manager.is_enabled(FeatureFlag::LiveCaptions) {
+        return Err("Live captions are a beta feature. Enable them under Beta Features.".into());
+    }
+    captions_manager.start().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn stop_live_captions(
+    captions_manager: State<'_, Arc<CaptionsManager>>,
+) -> Result<(), String> {
+    let manager = captions_manager.inner().clone();
+    tauri::async_runtime::spawn_blocking(move || manager.stop())
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn get_live_captions_status(captions_manager: State<'_, Arc<CaptionsManager>>) -> bool {
+    captions_manager.is_running()
+}
+
+#[tauri::command]
+pub fn get_caption_history(captions_manager: State<'_, Arc<CaptionsManager>>) -> Vec<CaptionLine> {
+    captions_manager.history()
+}
+
+#[tauri::command]
+pub fn get_interview_questions(
+    captions_manager: State<'_, Arc<CaptionsManager>>,
+) -> Vec<InterviewQuestion> {
+    captions_manager.questions()
+}
+
+#[tauri::command]
+pub fn clear_caption_history(captions_manager: State<'_, Arc<CaptionsManager>>) {
+    captions_manager.clear_history();
+}
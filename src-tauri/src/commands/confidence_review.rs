@@ -0,0 +1,15 @@
+//! Tauri command handlers for low-confidence dictation review.
+
+use crate::managers::confidence_review::{ConfidenceReviewManager, ReviewChoice};
+use std::sync::Arc;
+use tauri::State;
+
+/// Paste the held dictation anyway, record it again, or re-run it with a
+/// larger model.
+#[tauri::command]
+pub fn resolve_low_confidence(
+    review_manager: State<'_, Arc<ConfidenceReviewManager>>,
+    choice: ReviewChoice,
+) -> Result<(), String> {
+    review_manager.resolve(choice).map_err(|e| e.to_string())
+}
@@ -0,0 +1,15 @@
+//! Tauri command handlers for the read-only data API.
+
+use crate::managers::data_api::{DataApiManager, DataApiStatus};
+use std::sync::Arc;
+use tauri::State;
+
+#[tauri::command]
+pub fn get_data_api_status(data_api_manager: State<'_, Arc<DataApiManager>>) -> DataApiStatus {
+    data_api_manager.status()
+}
+
+#[tauri::command]
+pub fn regenerate_data_api_token(data_api_manager: State<'_, Arc<DataApiManager>>) -> String {
+    data_api_manager.regenerate_token()
+}
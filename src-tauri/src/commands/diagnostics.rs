@@ -0,0 +1,235 @@
+use crate::audio_toolkit::audio::ffmpeg_available;
+use crate::audio_toolkit::fixture::{self, FixtureResult};
+use crate::managers::audio::AudioRecordingManager;
+use crate::managers::database::{self, CURRENT_SCHEMA_VERSION};
+use crate::managers::history::HistoryManager;
+use crate::managers::model::MODEL_CATALOG_VERSION;
+use crate::managers::transcription::TranscriptionManager;
+use crate::settings::SETTINGS_VERSION;
+use log::info;
+use rusqlite::{Connection, OpenFlags};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Instant;
+use tauri::{AppHandle, Manager};
+
+/// One second of silence at the 16kHz sample rate the engines expect.
+const SILENT_SAMPLES: usize = 16000;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SelfTestCheck {
+    pub name: String,
+    pub passed: bool,
+    pub duration_ms: u64,
+    pub detail: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SelfTestReport {
+    pub passed: bool,
+    pub checks: Vec<SelfTestCheck>,
+}
+
+impl SelfTestReport {
+    fn from_checks(checks: Vec<SelfTestCheck>) -> Self {
+        Self {
+            passed: checks.iter().all(|c| c.passed),
+            checks,
+        }
+    }
+}
+
+/// Versions and platform capabilities, for the About and diagnostics
+/// screens.
+#[derive(Debug, Clone, Serialize)]
+pub struct AppRuntimeInfo {
+    pub app_version: String,
+    pub os: String,
+    pub arch: String,
+    /// Schema version of the history database, if it could be read.
+    pub history_schema_version: Option<u32>,
+    /// Schema version this build migrates the history database to.
+    pub history_schema_target: u32,
+    /// `user_version` of the input tracking database, if it exists. Its
+    /// table is upgraded in place without setting one, so this is 0 for now.
+    pub input_schema_version: Option<u32>,
+    pub model_catalog_version: u32,
+    pub settings_version: u32,
+    pub wayland: bool,
+    /// Whether simulated keystrokes are allowed. Only macOS asks for the
+    /// Accessibility permission; elsewhere this is always true.
+    pub accessibility_granted: bool,
+    pub ffmpeg_found: bool,
+}
+
+/// `user_version` of the existing SQLite database at `path`.
+fn read_user_version(path: &Path) -> Option<u32> {
+    let conn = Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_ONLY).ok()?;
+    conn.query_row("PRAGMA user_version", [], |row| row.get(0))
+        .ok()
+}
+
+fn run_check(name: &str, check: impl FnOnce() -> Result<String, String>) -> SelfTestCheck {
+    let start = Instant::now();
+    let result = check();
+    let duration_ms = start.elapsed().as_millis() as u64;
+    let (passed, detail) = match result {
+        Ok(detail) => (true, detail),
+        Err(detail) => (false, detail),
+    };
+    SelfTestCheck {
+        name: name.to_string(),
+        passed,
+        duration_ms,
+        detail,
+    }
+}
+
+fn run_checks(app: &AppHandle) -> Vec<SelfTestCheck> {
+    let rm = app.state::<Arc<AudioRecordingManager>>();
+    let tm = app.state::<Arc<TranscriptionManager>>();
+    let hm = app.state::<Arc<HistoryManager>>();
+
+    let microphone = run_check("microphone", || {
+        if rm.is_recording() {
+            return Ok("Skipped: a recording is in progress".to_string());
+        }
+        rm.probe_microphone()
+            .map(|_| "Microphone opened".to_string())
+            .map_err(|e| e.to_string())
+    });
+
+    let model_check = run_check("model_load", || {
+        tm.ensure_model_loaded().map_err(|e| e.to_string())?;
+        Ok(format!(
+            "Loaded '{}'",
+            tm.get_current_model().unwrap_or_default()
+        ))
+    });
+    let model_loaded = model_check.passed;
+
+    let transcription = run_check("transcription", || {
+        if !model_loaded {
+            return Err("Skipped: model failed to load".to_string());
+        }
+        tm.transcribe(vec![0.0; SILENT_SAMPLES])
+            .map(|text| format!("Transcribed 1s of silence ({} chars)", text.len()))
+            .map_err(|e| e.to_string())
+    });
+
+    let paste = run_check("paste", || {
+        crate::clipboard::self_test_paste(app).map(|_| "Clipboard and keyboard ready".to_string())
+    });
+
+    let database = run_check("database", || {
+        hm.check_writable()
+            .map(|_| "History database writable".to_string())
+            .map_err(|e| e.to_string())
+    });
+
+    vec![microphone, model_check, transcription, paste, database]
+}
+
+/// Run an end-to-end self-test of the dictation pipeline and report each step.
+#[tauri::command]
+pub async fn run_self_test(app: AppHandle) -> Result<SelfTestReport, String> {
+    let report =
+        tauri::async_runtime::spawn_blocking(move || SelfTestReport::from_checks(run_checks(&app)))
+            .await
+            .map_err(|e| format!("Self-test failed to run: {}", e))?;
+
+    info!(
+        "Self-test finished: {}",
+        if report.passed { "passed" } else { "failed" }
+    );
+    Ok(report)
+}
+
+/// Report the app, database, catalog and settings versions and what the
+/// platform supports, in one call.
+#[tauri::command]
+pub async fn get_app_runtime_info(app: AppHandle) -> Result<AppRuntimeInfo, String> {
+    let data_dir = crate::profile::data_dir(&app).map_err(|e| e.to_string())?;
+    let app_version = app.package_info().version.to_string();
+
+    // Finding FFmpeg may spawn a process
+    tauri::async_runtime::spawn_blocking(move || AppRuntimeInfo {
+        app_version,
+        os: std::env::consts::OS.to_string(),
+        arch: std::env::consts::ARCH.to_string(),
+        history_schema_version: database::read_schema_version(&data_dir.join("history.db")).ok(),
+        history_schema_target: CURRENT_SCHEMA_VERSION,
+        input_schema_version: read_user_version(&data_dir.join("echo.db")),
+        model_catalog_version: MODEL_CATALOG_VERSION,
+        settings_version: SETTINGS_VERSION,
+        wayland: crate::wayland::is_wayland(),
+        accessibility_granted: crate::clipboard::keystroke_paste_permitted(),
+        ffmpeg_found: ffmpeg_available(),
+    })
+    .await
+    .map_err(|e| format!("Failed to gather runtime info: {}", e))
+}
+
+/// Development only: run the audio pipeline fixtures (the bundled ones, or
+/// those in `dir`) through decoding and the loaded transcription model.
+#[tauri::command]
+pub async fn run_pipeline_fixture(
+    app: AppHandle,
+    dir: Option<String>,
+) -> Result<Vec<FixtureResult>, String> {
+    if !cfg!(debug_assertions) {
+        return Err("Pipeline fixtures are only available in development builds".to_string());
+    }
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let dir = dir.map(PathBuf::from).unwrap_or_else(fixture::bundled_dir);
+        let clips = fixture::load_manifest(&dir).map_err(|e| e.to_string())?;
+
+        let tm = app.state::<Arc<TranscriptionManager>>();
+        tm.ensure_model_loaded().map_err(|e| e.to_string())?;
+        let mut transcribe = |samples: Vec<f32>| tm.transcribe(samples);
+
+        let results = clips
+            .iter()
+            .map(|clip| fixture::run_clip(&dir, clip, Some(&mut transcribe)))
+            .collect::<anyhow::Result<Vec<_>>>()
+            .map_err(|e| e.to_string())?;
+        info!(
+            "Pipeline fixtures: {}/{} clips passed",
+            results.iter().filter(|r| r.passed).count(),
+            results.len()
+        );
+        Ok(results)
+    })
+    .await
+    .map_err(|e| format!("Pipeline fixtures failed to run: {}", e))?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_records_success_and_failure() {
+        let ok = run_check("ok", || Ok("fine".to_string()));
+        assert!(ok.passed);
+        assert_eq!(ok.detail, "fine");
+
+        let failed = run_check("failed", || Err("broken".to_string()));
+        assert!(!failed.passed);
+        assert_eq!(failed.detail, "broken");
+    }
+
+    #[test]
+    fn report_fails_if_any_check_fails() {
+        let report = SelfTestReport::from_checks(vec![
+            run_check("a", || Ok(String::new())),
+            run_check("b", || Err(String::new())),
+        ]);
+        assert!(!report.passed);
+
+        let report = SelfTestReport::from_checks(vec![run_check("a", || Ok(String::new()))]);
+        assert!(report.passed);
+    }
+}
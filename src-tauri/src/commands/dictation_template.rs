@@ -0,0 +1,69 @@
+//! Tauri command handlers for dictation templates.
+
+use crate::managers::dictation_template::{DictationTemplateManager, TemplateProgress};
+use crate::settings::{self, DictationTemplate};
+use std::sync::Arc;
+use tauri::{AppHandle, State};
+
+/// Add a template, or update the one with the same id.
+#[tauri::command]
+pub fn save_dictation_template(
+    app: AppHandle,
+    id: Option<String>,
+    name: String,
+    template: String,
+) -> Result<DictationTemplate, String> {
+    if name.trim().is_empty() {
+        return Err("Template name cannot be empty".to_string());
+    }
+    let saved = DictationTemplate {
+        id: id.unwrap_or_else(|| format!("template_{}", chrono::Utc::now().timestamp_millis())),
+        name: name.trim().to_string(),
+        template,
+    };
+    let result = saved.clone();
+    settings::update_settings(&app, |s| {
+        match s.dictation_templates.iter_mut().find(|t| t.id == saved.id) {
+            Some(existing) => *existing = saved,
+            None => s.dictation_templates.push(saved),
+        }
+    });
+    Ok(result)
+}
+
+#[tauri::command]
+pub fn delete_dictation_template(
+    app: AppHandle,
+    template_manager: State<'_, Arc<DictationTemplateManager>>,
+    id: String,
+) -> Result<(), String> {
+    if template_manager
+        .progress()
+        .is_some_and(|progress| progress.template_id == id)
+    {
+        template_manager.cancel();
+    }
+    settings::update_settings(&app, |s| s.dictation_templates.retain(|t| t.id != id));
+    Ok(())
+}
+
+/// Make the next dictations fill the template's slots.
+#[tauri::command]
+pub fn start_dictation_template(
+    template_manager: State<'_, Arc<DictationTemplateManager>>,
+    id: String,
+) -> Result<TemplateProgress, String> {
+    template_manager.start(&id).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn cancel_dictation_template(template_manager: State<'_, Arc<DictationTemplateManager>>) {
+    template_manager.cancel();
+}
+
+#[tauri::command]
+pub fn get_dictation_template_progress(
+    template_manager: State<'_, Arc<DictationTemplateManager>>,
+) -> Option<TemplateProgress> {
+    template_manager.progress()
+}
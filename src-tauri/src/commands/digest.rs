@@ -0,0 +1,47 @@
+//! Tauri command handlers for daily digests.
+
+use crate::managers::digest::{DailyDigest, DigestManager};
+use chrono::{Days, Local, NaiveDate};
+use std::sync::Arc;
+use tauri::State;
+
+/// Parse a `YYYY-MM-DD` date, defaulting to yesterday.
+fn parse_date(date: Option<String>) -> Result<NaiveDate, String> {
+    match date {
+        Some(date) => NaiveDate::parse_from_str(&date, "%Y-%m-%d")
+            .map_err(|e| format!("Invalid date '{}': {}", date, e)),
+        None => Local::now()
+            .date_naive()
+            .checked_sub_days(Days::new(1))
+            .ok_or_else(|| "Date out of range".to_string()),
+    }
+}
+
+/// Generate (or regenerate) the digest for `date`, defaulting to yesterday.
+#[tauri::command]
+pub async fn generate_daily_digest(
+    digest_manager: State<'_, Arc<DigestManager>>,
+    date: Option<String>,
+) -> Result<DailyDigest, String> {
+    let date = parse_date(date)?;
+    digest_manager
+        .generate(date)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn get_daily_digest(
+    digest_manager: State<'_, Arc<DigestManager>>,
+    date: Option<String>,
+) -> Result<Option<DailyDigest>, String> {
+    let date = parse_date(date)?;
+    digest_manager.get_digest(date).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn list_daily_digests(
+    digest_manager: State<'_, Arc<DigestManager>>,
+) -> Result<Vec<DailyDigest>, String> {
+    digest_manager.list_digests().map_err(|e| e.to_string())
+}
@@ -0,0 +1,40 @@
+//! Tauri command handlers for feature flags and the in-app changelog.
+
+use crate::changelog::{self, ChangelogRelease};
+use crate::managers::captions::CaptionsManager;
+use crate::managers::feature_flags::{FeatureFlag, FeatureFlagManager, FeatureFlagState};
+use std::sync::Arc;
+use tauri::State;
+
+#[tauri::command]
+pub fn get_feature_flags(
+    feature_flag_manager: State<'_, Arc<FeatureFlagManager>>,
+) -> Vec<FeatureFlagState> {
+    feature_flag_manager.list()
+}
+
+/// Override a flag, or pass no value to return it to its default.
+#[tauri::command]
+pub async fn set_feature_flag(
+    feature_flag_manager: State<'_, Arc<FeatureFlagManager>>,
+    captions_manager: State<'_, Arc<CaptionsManager>>,
+    flag: FeatureFlag,
+    enabled: Option<bool>,
+) -> Result<Vec<FeatureFlagState>, String> {
+    let now_enabled = feature_flag_manager.set(flag, enabled);
+
+    if flag == FeatureFlag::LiveCaptions && !now_enabled && captions_manager.is_running() {
+        let manager = captions_manager.inner().clone();
+        tauri::async_runtime::spawn_blocking(move || manager.stop())
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+
+    Ok(feature_flag_manager.list())
+}
+
+/// Release notes for versions newer than `version`, newest first.
+#[tauri::command]
+pub fn get_changelog_since(version: Option<String>) -> Vec<ChangelogRelease> {
+    changelog::releases_since(version.as_deref())
+}
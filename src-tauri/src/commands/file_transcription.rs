@@ -1,13 +1,15 @@
 use crate::audio_toolkit::audio::decode_audio_file;
-use crate::managers::history::HistoryManager;
-use crate::managers::transcription::TranscriptionManager;
-use log::{error, info};
-use rusqlite::params;
+use crate::managers::diarization::{DiarizationManager, SpeakerSegment};
+use crate::managers::history::{HistoryManager, RecordingMode, RecordingSource};
+use crate::managers::transcription::{TimedSegment, TranscriptionManager};
+use crate::managers::transcription_queue::{JobPriority, TranscriptionJob};
+use crate::settings::get_settings;
+use log::{error, info, warn};
 use serde::Serialize;
 use serde_json::json;
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 use tauri::{AppHandle, Emitter, Manager, State};
 
@@ -18,6 +20,9 @@ pub struct FileTranscriptionProgress {
     pub message: String,
     #[serde(rename = "fileName")]
     pub file_name: Option<String>,
+    /// Transcription job to cancel the import with, once transcribing.
+    #[serde(rename = "jobId", skip_serializing_if = "Option::is_none")]
+    pub job_id: Option<u64>,
 }
 
 /// Check if a file is a video format
@@ -72,6 +77,7 @@ pub async fn transcribe_audio_file(
                 "Loading audio file...".to_string()
             },
             file_name: file_name.clone(),
+            job_id: None,
         },
     );
 
@@ -109,6 +115,11 @@ pub async fn transcribe_audio_file(
         format!("{}s", duration_secs)
     };
 
+    // Files wait for dictations, which are short and being waited on
+    let label = format!("File: {}", file_name.as_deref().unwrap_or("audio"));
+    let job = transcription_manager.submit_job(JobPriority::Background, &label);
+    let job_id = job.id();
+
     // Emit progress: Decoding complete, starting transcription
     emit_progress(
         &app,
@@ -117,6 +128,7 @@ pub async fn transcribe_audio_file(
             progress: -1.0, // Indeterminate progress
             message: format!("Transcribing {} of audio...", duration_str),
             file_name: file_name.clone(),
+            job_id: Some(job_id),
         },
     );
 
@@ -129,6 +141,9 @@ pub async fn transcribe_audio_file(
     // Start elapsed time tracking thread
     let progress_complete = Arc::new(AtomicBool::new(false));
     let progress_complete_clone = progress_complete.clone();
+    // Share of the audio transcribed, once long audio reports it
+    let transcribed = Arc::new(Mutex::new(-1.0));
+    let transcribed_clone = transcribed.clone();
     let app_clone = app.clone();
     let file_name_clone = file_name.clone();
     let duration_str_clone = duration_str.clone();
@@ -153,12 +168,13 @@ pub async fn transcribe_audio_file(
                 &app_clone,
                 &FileTranscriptionProgress {
                     status: "transcribing".to_string(),
-                    progress: -1.0, // Indeterminate progress
+                    progress: *transcribed_clone.lock().unwrap(),
                     message: format!(
                         "Transcribing {} of audio... ({})",
                         duration_str_clone, elapsed_str
                     ),
                     file_name: file_name_clone.clone(),
+                    job_id: Some(job_id),
                 },
             );
 
@@ -166,15 +182,51 @@ pub async fn transcribe_audio_file(
         }
     });
 
-    // Transcribe the audio
-    let transcription_result = transcription_manager.transcribe(audio_samples.clone());
+    // Transcribe the audio, one speaker turn at a time when diarizing
+    let speaker_segments =
+        transcribe_by_speaker(&app, &transcription_manager, &job, &audio_samples);
+    let mut timestamps: Vec<TimedSegment> = Vec::new();
+    let mut language: Option<String> = None;
+    let transcription_result = match &speaker_segments {
+        _ if job.is_cancelled() => Err(job.cancelled_error()),
+        Some(segments) => Ok(speaker_transcript(segments)),
+        None => transcription_manager
+            .transcribe_long(&job, &audio_samples, |done| {
+                // Saving takes the last tenth of the bar
+                let progress = done * 0.9;
+                *transcribed.lock().unwrap() = progress;
+                emit_progress(
+                    &app,
+                    &FileTranscriptionProgress {
+                        status: "transcribing".to_string(),
+                        progress,
+                        message: format!("Transcribing {} of audio...", duration_str),
+                        file_name: file_name.clone(),
+                        job_id: Some(job_id),
+                    },
+                );
+            })
+            .map(|transcript| {
+                timestamps = transcript.segments;
+                language = transcript.language;
+                transcript.text
+            }),
+    };
 
     // Signal progress thread to stop
     progress_complete.store(true, Ordering::SeqCst);
     let _ = progress_handle.join();
 
+    let cancelled = job.is_cancelled();
+    drop(job);
+
     let transcription_text = match transcription_result {
         Ok(text) => text,
+        Err(_) if cancelled => {
+            let err = "Transcription cancelled".to_string();
+            emit_error(&app, &err, file_name.clone());
+            return Err(err);
+        }
         Err(e) => {
             let err = format!("Transcription failed: {}", e);
             emit_error(&app, &err, file_name.clone());
@@ -196,6 +248,7 @@ pub async fn transcribe_audio_file(
             progress: 0.9,
             message: "Saving to history...".to_string(),
             file_name: file_name.clone(),
+            job_id: None,
         },
     );
 
@@ -209,33 +262,54 @@ pub async fn transcribe_audio_file(
 
     // Save to history with a custom title
     // We need to use save_transcription and then update the title
-    if let Err(e) = history_manager
+    let history_id = match history_manager
         .save_transcription(
             audio_samples,
             transcription_text.clone(),
             None, // post_processed_text
             None, // post_process_prompt
+            RecordingSource {
+                device: file_name.clone(),
+                sample_rate: None,
+                mode: Some(RecordingMode::File),
+            },
         )
         .await
     {
-        let err = format!("Failed to save to history: {}", e);
-        error!("{}", err);
-        // Don't return error here, transcription still succeeded
+        Ok(id) => id,
+        Err(e) => {
+            let err = format!("Failed to save to history: {}", e);
+            error!("{}", err);
+            // Don't return error here, transcription still succeeded
+            0
+        }
+    };
+
+    if let Some(segments) = speaker_segments.filter(|_| history_id != 0) {
+        if let Err(e) = history_manager
+            .save_speaker_segments(history_id, segments)
+            .await
+        {
+            error!("Failed to save speaker segments: {}", e);
+        }
+    }
+    if !timestamps.is_empty() && history_id != 0 {
+        if let Err(e) = history_manager
+            .save_timestamps(history_id, &timestamps)
+            .await
+        {
+            error!("Failed to save timestamps: {}", e);
+        }
+    }
+    if let Some(language) = language.filter(|_| history_id != 0) {
+        if let Err(e) = history_manager.save_language(history_id, language).await {
+            error!("Failed to save language: {}", e);
+        }
     }
 
     // Update the title in the database to use the file name
-    // Get the most recent entry and update its title
-    let db_path = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| format!("Failed to get app data dir: {}", e))?
-        .join("history.db");
-
-    if let Ok(conn) = rusqlite::Connection::open(&db_path) {
-        let _ = conn.execute(
-            "UPDATE transcription_history SET title = ?1 WHERE id = (SELECT id FROM transcription_history ORDER BY id DESC LIMIT 1)",
-            params![title],
-        );
+    if let Err(e) = history_manager.save_title(history_id, title).await {
+        error!("Failed to update file transcription title: {}", e);
     }
 
     // Emit progress: Complete
@@ -246,6 +320,7 @@ pub async fn transcribe_audio_file(
             progress: 1.0,
             message: "Transcription complete!".to_string(),
             file_name: file_name.clone(),
+            job_id: None,
         },
     );
 
@@ -277,12 +352,55 @@ pub async fn transcribe_audio_file(
     Ok(transcription_text)
 }
 
-fn emit_progress(app: &AppHandle, progress: &FileTranscriptionProgress) {
-    if let Err(e) = app.emit("file-transcription-progress", progress) {
-        error!("Failed to emit progress event: {}", e);
+/// Transcribe each speaker turn separately when file diarization is on and its
+/// models are downloaded. `None` means the file is transcribed as a whole.
+fn transcribe_by_speaker(
+    app: &AppHandle,
+    transcription_manager: &TranscriptionManager,
+    job: &TranscriptionJob,
+    samples: &[f32],
+) -> Option<Vec<SpeakerSegment>> {
+    let settings = get_settings(app);
+    if !settings.file_diarization_enabled {
+        return None;
+    }
+    let diarization_manager = app.try_state::<Arc<DiarizationManager>>()?;
+    if !diarization_manager.is_available() {
+        warn!("Diarization models are not downloaded, transcribing without speakers");
+        return None;
+    }
+
+    match diarization_manager.transcribe_by_speaker(
+        transcription_manager,
+        job,
+        samples,
+        settings.meeting_diarization_threshold,
+    ) {
+        Ok(segments) if !segments.is_empty() => Some(segments),
+        Ok(_) => {
+            warn!("Diarization found no speaker turns, transcribing without speakers");
+            None
+        }
+        Err(e) => {
+            error!("Diarization failed, transcribing without speakers: {}", e);
+            None
+        }
     }
 }
 
+/// Plain-text transcript with one "Speaker N: text" line per turn.
+fn speaker_transcript(segments: &[SpeakerSegment]) -> String {
+    segments
+        .iter()
+        .map(|seg| format!("{}: {}", seg.speaker_label, seg.text))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn emit_progress(app: &AppHandle, progress: &FileTranscriptionProgress) {
+    crate::helpers::event_throttle::emit(app, "file-transcription-progress", progress);
+}
+
 fn emit_error(app: &AppHandle, error_message: &str, file_name: Option<String>) {
     // Clear file transcription active flag
     crate::set_file_transcription_active(false);
@@ -300,6 +418,7 @@ fn emit_error(app: &AppHandle, error_message: &str, file_name: Option<String>) {
             progress: 0.0,
             message: error_message.to_string(),
             file_name,
+            job_id: None,
         },
     );
 }
@@ -0,0 +1,59 @@
+//! Tauri command handlers for focus sessions.
+
+use crate::managers::focus_session::{FocusSession, FocusSessionManager, FocusSessionStats};
+use crate::managers::history::{HistoryEntry, HistoryManager};
+use std::sync::Arc;
+use tauri::State;
+
+#[tauri::command]
+pub fn start_focus_session(
+    focus_session_manager: State<'_, Arc<FocusSessionManager>>,
+    name: String,
+) -> Result<FocusSession, String> {
+    focus_session_manager
+        .start(&name)
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn stop_focus_session(
+    focus_session_manager: State<'_, Arc<FocusSessionManager>>,
+) -> Result<Option<FocusSession>, String> {
+    focus_session_manager.stop().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn get_active_focus_session(
+    focus_session_manager: State<'_, Arc<FocusSessionManager>>,
+) -> Option<FocusSession> {
+    focus_session_manager.active_session()
+}
+
+#[tauri::command]
+pub fn list_focus_sessions(
+    focus_session_manager: State<'_, Arc<FocusSessionManager>>,
+) -> Result<Vec<FocusSessionStats>, String> {
+    focus_session_manager
+        .list_sessions()
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn get_focus_session_stats(
+    focus_session_manager: State<'_, Arc<FocusSessionManager>>,
+    id: i64,
+) -> Result<FocusSessionStats, String> {
+    focus_session_manager
+        .get_session_stats(id)
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn get_focus_session_transcriptions(
+    history_manager: State<'_, Arc<HistoryManager>>,
+    id: i64,
+) -> Result<Vec<HistoryEntry>, String> {
+    history_manager
+        .get_entries_for_session(id)
+        .map_err(|e| e.to_string())
+}
@@ -1,7 +1,19 @@
-use crate::managers::history::{HistoryEntry, HistoryManager};
+use crate::audio_toolkit::load_wav_file;
+use crate::helpers::text_diff::{self, DiffSpan};
+use crate::managers::diarization::SpeakerSegment;
+use crate::managers::entry_edits::{AppEditStats, EntryEdit};
+use crate::managers::export;
+use crate::managers::history::{HistoryEntry, HistoryManager, RecordingMode, RecordingSource};
+use crate::managers::history_import::HistoryImportManager;
+use crate::managers::meeting::ExportFormat;
+use crate::managers::recording_journal::orphaned_journals;
 use crate::managers::transcription::TranscriptionManager;
+use crate::managers::transcription_queue::JobPriority;
 use crate::managers::tts::TtsManager;
 use crate::settings::get_settings;
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
 use std::sync::Arc;
 use tauri::{AppHandle, State};
 
@@ -52,13 +64,21 @@ pub async fn delete_history_entry(
         .map_err(|e| e.to_string())
 }
 
+/// A retranscribed entry's new text and how it differs from the old one.
+#[derive(Serialize)]
+pub struct Retranscription {
+    pub text: String,
+    pub previous_text: String,
+    pub diff: Vec<DiffSpan>,
+}
+
 #[tauri::command]
 pub async fn retranscribe_history_entry(
     _app: AppHandle,
     history_manager: State<'_, Arc<HistoryManager>>,
     transcription_manager: State<'_, Arc<TranscriptionManager>>,
     id: i64,
-) -> Result<String, String> {
+) -> Result<Retranscription, String> {
     // Get the history entry to find the audio file
     let entry = history_manager
         .get_entry_by_id(id)
@@ -74,18 +94,46 @@ pub async fn retranscribe_history_entry(
     // Ensure model is loaded
     transcription_manager.initiate_model_load();
 
-    // Transcribe the audio
-    let new_transcription = transcription_manager
-        .transcribe(audio_samples)
+    // Transcribe the audio, after any dictation waiting for the engine
+    let job = transcription_manager.submit_job(JobPriority::Background, "Retranscription");
+    let transcript = transcription_manager
+        .transcribe_job(&job, audio_samples)
         .map_err(|e| format!("Transcription failed: {}", e))?;
+    let new_transcription = transcript.text;
 
-    // Update the history entry with the new transcription
-    history_manager
-        .retranscribe_entry(id, new_transcription.clone())
+    // Update the history entry with the new transcription, keeping the old
+    let previous_text = history_manager
+        .retranscribe_entry(
+            id,
+            new_transcription.clone(),
+            &transcript.segments,
+            transcript.language,
+        )
         .await
         .map_err(|e| format!("Failed to update history entry: {}", e))?;
 
-    Ok(new_transcription)
+    Ok(Retranscription {
+        diff: text_diff::word_diff(&previous_text, &new_transcription),
+        text: new_transcription,
+        previous_text,
+    })
+}
+
+/// Replace an entry's text with the user's correction.
+#[tauri::command]
+pub async fn update_history_entry_text(
+    history_manager: State<'_, Arc<HistoryManager>>,
+    id: i64,
+    text: String,
+) -> Result<(), String> {
+    let text = text.trim().to_string();
+    if text.is_empty() {
+        return Err("The transcription can't be empty".to_string());
+    }
+    history_manager
+        .edit_entry_text(id, text)
+        .await
+        .map_err(|e| e.to_string())
 }
 
 #[tauri::command]
@@ -106,6 +154,36 @@ pub async fn update_history_limit(
     Ok(())
 }
 
+/// Change the folder whose new recordings are imported into history.
+#[tauri::command]
+pub fn change_import_watch_folder_setting(
+    app: AppHandle,
+    folder: Option<String>,
+) -> Result<(), String> {
+    let folder = folder.filter(|f| !f.trim().is_empty());
+    if let Some(folder) = &folder {
+        if !Path::new(folder).is_dir() {
+            return Err(format!("{} is not a folder", folder));
+        }
+    }
+    crate::settings::update_settings(&app, |s| {
+        s.import_watch_folder = folder;
+    });
+    Ok(())
+}
+
+/// Queue every recording in the folder `path` for import into history.
+/// Returns how many were queued.
+#[tauri::command]
+pub fn import_history_bundle(
+    history_import_manager: State<'_, Arc<HistoryImportManager>>,
+    path: String,
+) -> Result<usize, String> {
+    history_import_manager
+        .import_bundle(Path::new(&path))
+        .map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 pub async fn update_recording_retention_period(
     app: AppHandle,
@@ -135,6 +213,58 @@ pub async fn update_recording_retention_period(
     Ok(())
 }
 
+/// Give entries from `mode` a retention period of their own, or make them
+/// follow the global one again when `period` is `None`.
+#[tauri::command]
+pub async fn update_source_retention_period(
+    app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    mode: RecordingMode,
+    period: Option<crate::settings::RecordingRetentionPeriod>,
+) -> Result<(), String> {
+    crate::settings::update_settings(&app, |s| match period {
+        Some(period) => {
+            s.source_retention.insert(mode, period);
+        }
+        None => {
+            s.source_retention.remove(&mode);
+        }
+    });
+
+    // Side effect outside lock
+    history_manager
+        .cleanup_old_entries()
+        .map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+/// Give entries from focus sessions named `tag` a retention period of their
+/// own, or make them follow their source's again when `period` is `None`.
+#[tauri::command]
+pub async fn update_tag_retention_period(
+    app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    tag: String,
+    period: Option<crate::settings::RecordingRetentionPeriod>,
+) -> Result<(), String> {
+    crate::settings::update_settings(&app, |s| match period {
+        Some(period) => {
+            s.tag_retention.insert(tag, period);
+        }
+        None => {
+            s.tag_retention.remove(&tag);
+        }
+    });
+
+    // Side effect outside lock
+    history_manager
+        .cleanup_old_entries()
+        .map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn reprocess_history_entry(
     app: AppHandle,
@@ -160,7 +290,14 @@ pub async fn reprocess_history_entry(
     let mut post_process_prompt: Option<String> = None;
 
     // Try post-processing
-    match crate::actions::maybe_post_process_transcription(&app, &settings, transcription).await {
+    match crate::actions::maybe_post_process_transcription(
+        &app,
+        &settings,
+        transcription,
+        entry.language.as_deref(),
+    )
+    .await
+    {
         crate::tools::PostProcessOutcome::Text(processed_text) => {
             final_text = processed_text.clone();
             post_processed_text = Some(processed_text);
@@ -191,10 +328,11 @@ pub async fn reprocess_history_entry(
     // Trigger TTS if enabled and post-processing was successful
     if settings.tts_enabled && post_processed_text.is_some() {
         let tts_manager_clone = Arc::clone(&tts_manager);
+        let pronunciations = settings.tts_pronunciations.clone();
         let text_to_speak = final_text.clone();
         info!("Triggering TTS for reprocessed text: {}", text_to_speak);
         std::thread::spawn(move || {
-            if let Err(e) = tts_manager_clone.speak(&text_to_speak) {
+            if let Err(e) = tts_manager_clone.speak(&text_to_speak, &pronunciations) {
                 error!("TTS failed: {}", e);
             }
         });
@@ -202,3 +340,116 @@ pub async fn reprocess_history_entry(
 
     Ok(final_text)
 }
+
+/// Edits made to a dictation after it was pasted, as seen by input tracking.
+#[tauri::command]
+pub fn get_entry_edits(
+    history_manager: State<'_, Arc<HistoryManager>>,
+    history_id: i64,
+) -> Result<Vec<EntryEdit>, String> {
+    history_manager
+        .get_entry_edits(history_id)
+        .map_err(|e| e.to_string())
+}
+
+/// How much pasted dictations get corrected, per app.
+#[tauri::command]
+pub fn get_entry_edit_stats(
+    history_manager: State<'_, Arc<HistoryManager>>,
+) -> Result<Vec<AppEditStats>, String> {
+    history_manager
+        .get_edit_stats_by_app()
+        .map_err(|e| e.to_string())
+}
+
+/// Speaker-labeled segments of a diarized entry; empty for other entries.
+#[tauri::command]
+pub fn get_history_speaker_segments(
+    history_manager: State<'_, Arc<HistoryManager>>,
+    history_id: i64,
+) -> Result<Vec<SpeakerSegment>, String> {
+    history_manager
+        .get_speaker_segments(history_id)
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn export_history_entry(
+    history_manager: State<'_, Arc<HistoryManager>>,
+    id: i64,
+    format: ExportFormat,
+) -> Result<String, String> {
+    let entry = history_manager
+        .get_entry_by_id(id)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("History entry {} not found", id))?;
+    let mut segments = history_manager
+        .get_speaker_segments(id)
+        .map_err(|e| e.to_string())?;
+    if segments.is_empty() {
+        segments = export::cues_from_timestamps(&entry.timestamps.unwrap_or_default());
+    }
+    if segments.is_empty() {
+        return Err("This entry has no speaker segments or timestamps".to_string());
+    }
+    Ok(export::export_history(&entry.title, &segments, &format))
+}
+
+/// Recordings a crash interrupted, by file name, oldest first.
+#[tauri::command]
+pub fn get_crashed_recordings(history_manager: State<'_, Arc<HistoryManager>>) -> Vec<String> {
+    orphaned_journals(history_manager.recordings_dir())
+        .iter()
+        .filter_map(|path| path.file_name()?.to_str().map(String::from))
+        .collect()
+}
+
+/// Transcribe recordings a crash interrupted into history, returning the new
+/// entries' ids. Recordings without speech are deleted.
+#[tauri::command]
+pub async fn recover_crashed_recordings(
+    history_manager: State<'_, Arc<HistoryManager>>,
+    transcription_manager: State<'_, Arc<TranscriptionManager>>,
+) -> Result<Vec<i64>, String> {
+    let journals = orphaned_journals(history_manager.recordings_dir());
+    if journals.is_empty() {
+        return Ok(Vec::new());
+    }
+    transcription_manager.initiate_model_load();
+
+    let mut ids = Vec::new();
+    for path in journals {
+        let samples = load_wav_file(&path)
+            .map_err(|e| format!("Failed to read interrupted recording: {}", e))?;
+        let text = if samples.is_empty() {
+            String::new()
+        } else {
+            let job = transcription_manager.submit_job(JobPriority::Background, "Recovery");
+            transcription_manager
+                .transcribe_long(&job, &samples, |_| {})
+                .map_err(|e| format!("Transcription failed: {}", e))?
+                .text
+        };
+        if !text.trim().is_empty() {
+            let id = history_manager
+                .save_transcription(samples, text, None, None, RecordingSource::default())
+                .await
+                .map_err(|e| format!("Failed to save to history: {}", e))?;
+            ids.push(id);
+        }
+        fs::remove_file(&path).map_err(|e| e.to_string())?;
+    }
+    Ok(ids)
+}
+
+/// Delete recordings a crash interrupted without transcribing them.
+#[tauri::command]
+pub fn discard_crashed_recordings(
+    history_manager: State<'_, Arc<HistoryManager>>,
+) -> Result<(), String> {
+    for path in orphaned_journals(history_manager.recordings_dir()) {
+        fs::remove_file(&path).map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
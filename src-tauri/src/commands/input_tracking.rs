@@ -1,6 +1,8 @@
+use crate::managers::focus_session::input_entries_have_session_column;
+use crate::managers::input_tracker::anonymize;
 use rusqlite::Connection;
-use serde::Serialize;
-use tauri::{AppHandle, Manager};
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
 
 #[derive(Debug, Clone, Serialize)]
 pub struct InputEntry {
@@ -14,22 +16,93 @@ pub struct InputEntry {
     pub duration_ms: i64,
 }
 
+/// How `export_input_entries` writes the typed text.
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum InputExportMode {
+    Full,
+    /// Text and window titles replaced by character-class placeholders;
+    /// apps and timing kept.
+    Anonymized,
+}
+
+/// An input entry with its text replaced, for sharing typing-behavior data.
+#[derive(Debug, Clone, Serialize)]
+pub struct AnonymizedInputEntry {
+    pub app_name: String,
+    pub app_bundle_id: Option<String>,
+    pub window_title: Option<String>,
+    pub content: String,
+    pub counts: anonymize::CharacterCounts,
+    pub timestamp: i64,
+    pub duration_ms: i64,
+}
+
+/// Get tracked input entries, newest first, optionally only those tagged with
+/// a focus session.
 #[tauri::command]
-pub fn get_input_entries(app: AppHandle, limit: Option<usize>) -> Result<Vec<InputEntry>, String> {
-    let app_data_dir = app
-        .path()
-        .app_data_dir()
+pub fn get_input_entries(
+    app: AppHandle,
+    limit: Option<usize>,
+    session_id: Option<i64>,
+) -> Result<Vec<InputEntry>, String> {
+    load_input_entries(&app, limit, session_id)
+}
+
+/// All tracked input entries as JSON, oldest first.
+#[tauri::command]
+pub fn export_input_entries(app: AppHandle, mode: InputExportMode) -> Result<String, String> {
+    let mut entries = load_input_entries(&app, None, None)?;
+    entries.reverse();
+    let json = match mode {
+        InputExportMode::Full => serde_json::to_string_pretty(&entries),
+        InputExportMode::Anonymized => {
+            let entries: Vec<AnonymizedInputEntry> = entries
+                .into_iter()
+                .map(|entry| AnonymizedInputEntry {
+                    app_name: entry.app_name,
+                    app_bundle_id: entry.app_bundle_id,
+                    window_title: entry.window_title.as_deref().map(anonymize::mask),
+                    content: anonymize::mask(&entry.content),
+                    counts: anonymize::count(&entry.content),
+                    timestamp: entry.timestamp,
+                    duration_ms: entry.duration_ms,
+                })
+                .collect();
+            serde_json::to_string_pretty(&entries)
+        }
+    };
+    json.map_err(|e| format!("Failed to serialize entries: {}", e))
+}
+
+fn load_input_entries(
+    app: &AppHandle,
+    limit: Option<usize>,
+    session_id: Option<i64>,
+) -> Result<Vec<InputEntry>, String> {
+    let app_data_dir = crate::profile::data_dir(app)
         .map_err(|e| format!("Failed to get app data directory: {}", e))?;
 
     let db_path = app_data_dir.join("echo.db");
 
     let conn = Connection::open(&db_path).map_err(|e| format!("Failed to open database: {}", e))?;
 
+    let session_clause = match session_id {
+        Some(id) => {
+            let has_column = input_entries_have_session_column(&conn)
+                .map_err(|e| format!("Failed to read input entries schema: {}", e))?;
+            if !has_column {
+                return Ok(Vec::new());
+            }
+            format!(" WHERE session_id = {}", id)
+        }
+        None => String::new(),
+    };
     let limit_clause = limit.map(|l| format!(" LIMIT {}", l)).unwrap_or_default();
     let query = format!(
         "SELECT id, app_name, app_bundle_id, app_pid, window_title, content, timestamp, duration_ms 
-         FROM input_entries ORDER BY timestamp DESC{}",
-        limit_clause
+         FROM input_entries{} ORDER BY timestamp DESC{}",
+        session_clause, limit_clause
     );
 
     let mut stmt = conn
@@ -58,9 +131,7 @@ pub fn get_input_entries(app: AppHandle, limit: Option<usize>) -> Result<Vec<Inp
 
 #[tauri::command]
 pub fn delete_input_entry(app: AppHandle, id: i64) -> Result<(), String> {
-    let app_data_dir = app
-        .path()
-        .app_data_dir()
+    let app_data_dir = crate::profile::data_dir(&app)
         .map_err(|e| format!("Failed to get app data directory: {}", e))?;
 
     let db_path = app_data_dir.join("echo.db");
@@ -76,9 +147,7 @@ pub fn delete_input_entry(app: AppHandle, id: i64) -> Result<(), String> {
 
 #[tauri::command]
 pub fn clear_all_input_entries(app: AppHandle) -> Result<(), String> {
-    let app_data_dir = app
-        .path()
-        .app_data_dir()
+    let app_data_dir = crate::profile::data_dir(&app)
         .map_err(|e| format!("Failed to get app data directory: {}", e))?;
 
     let db_path = app_data_dir.join("echo.db");
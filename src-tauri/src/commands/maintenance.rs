@@ -0,0 +1,17 @@
+//! Tauri command handlers for database maintenance.
+
+use crate::managers::maintenance::{MaintenanceManager, MaintenanceReport};
+use std::sync::Arc;
+use tauri::State;
+
+/// Checkpoint, vacuum and analyze the history and input tracking databases.
+#[tauri::command]
+pub async fn optimize_database(
+    maintenance_manager: State<'_, Arc<MaintenanceManager>>,
+) -> Result<Vec<MaintenanceReport>, String> {
+    let manager = maintenance_manager.inner().clone();
+    tauri::async_runtime::spawn_blocking(move || manager.optimize())
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+}
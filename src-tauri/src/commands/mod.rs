@@ -1,11 +1,28 @@
+pub mod action_registry;
 pub mod audio;
+pub mod automation;
+pub mod captions;
+pub mod confidence_review;
+pub mod data_api;
+pub mod diagnostics;
+pub mod dictation_template;
+pub mod digest;
+pub mod feature_flags;
 pub mod file_transcription;
+pub mod focus_session;
 pub mod history;
 pub mod input_tracking;
+pub mod maintenance;
 pub mod meeting;
 pub mod models;
+pub mod noise_profile;
+pub mod power;
+pub mod scheduled_recording;
+pub mod scratchpad;
+pub mod text_plugins;
 pub mod transcription;
 pub mod tts;
+pub mod voice_profile;
 
 use crate::settings;
 use crate::utils::cancel_current_operation;
@@ -18,11 +35,16 @@ pub fn cancel_operation(app: AppHandle) {
     cancel_current_operation(&app);
 }
 
+/// Abort the post-processing in flight and paste the raw transcription.
+/// Returns false if nothing was being post-processed.
+#[tauri::command]
+pub fn skip_post_processing() -> bool {
+    crate::actions::skip_post_processing()
+}
+
 #[tauri::command]
 pub fn get_app_dir_path(app: AppHandle) -> Result<String, String> {
-    let app_data_dir = app
-        .path()
-        .app_data_dir()
+    let app_data_dir = crate::profile::data_dir(&app)
         .map_err(|e| format!("Failed to get app data directory: {}", e))?;
 
     Ok(app_data_dir.to_string_lossy().to_string())
@@ -96,9 +118,7 @@ pub fn set_log_level(app: AppHandle, level: u8) -> Result<(), String> {
 
 #[tauri::command]
 pub fn open_recordings_folder(app: AppHandle) -> Result<(), String> {
-    let app_data_dir = app
-        .path()
-        .app_data_dir()
+    let app_data_dir = crate::profile::data_dir(&app)
         .map_err(|e| format!("Failed to get app data directory: {}", e))?;
 
     let recordings_dir = app_data_dir.join("recordings");
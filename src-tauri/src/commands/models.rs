@@ -70,6 +70,34 @@ pub async fn set_active_model(
     Ok(())
 }
 
+/// Set the model live previews run on while dictating, or `None` to run
+/// them on the selected model.
+#[tauri::command]
+pub async fn set_preview_model(
+    app_handle: AppHandle,
+    model_manager: State<'_, Arc<ModelManager>>,
+    transcription_manager: State<'_, Arc<TranscriptionManager>>,
+    model_id: Option<String>,
+) -> Result<(), String> {
+    if let Some(model_id) = &model_id {
+        let model_info = model_manager
+            .get_transcription_models()
+            .into_iter()
+            .find(|model| &model.id == model_id)
+            .ok_or_else(|| format!("Model not found: {}", model_id))?;
+        if !model_info.is_downloaded {
+            return Err(format!("Model not downloaded: {}", model_id));
+        }
+    }
+
+    settings::update_settings(&app_handle, |s| {
+        s.preview_model_id = model_id.clone();
+    });
+    // The new model loads when the next dictation starts
+    transcription_manager.unload_preview_model();
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn get_current_model(app_handle: AppHandle) -> Result<String, String> {
     let s = settings::get_settings(&app_handle);
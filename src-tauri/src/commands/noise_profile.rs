@@ -0,0 +1,65 @@
+//! Tauri command handlers for noise profile calibration.
+
+use crate::managers::audio::AudioRecordingManager;
+use crate::managers::noise_profile::{
+    NoiseProfileManager, NoiseProfileSummary, CALIBRATION_BINDING_ID, CALIBRATION_SECS,
+};
+use std::sync::Arc;
+use std::time::Duration;
+use tauri::{AppHandle, State};
+
+#[tauri::command]
+pub fn get_noise_profiles(
+    noise_profile_manager: State<'_, Arc<NoiseProfileManager>>,
+) -> Vec<NoiseProfileSummary> {
+    noise_profile_manager.list()
+}
+
+/// Record a few seconds of the room and save them as the noise profile
+/// `name`. The new profile becomes the active one.
+#[tauri::command]
+pub async fn calibrate_noise_profile(
+    app: AppHandle,
+    recording_manager: State<'_, Arc<AudioRecordingManager>>,
+    noise_profile_manager: State<'_, Arc<NoiseProfileManager>>,
+    name: String,
+) -> Result<NoiseProfileSummary, String> {
+    if !recording_manager.try_start_recording(CALIBRATION_BINDING_ID) {
+        return Err("Couldn't start recording. Is another recording running?".into());
+    }
+    let rm = recording_manager.inner().clone();
+    let manager = noise_profile_manager.inner().clone();
+    let summary = tauri::async_runtime::spawn_blocking(move || {
+        std::thread::sleep(Duration::from_secs(CALIBRATION_SECS));
+        let samples = rm
+            .stop_recording(CALIBRATION_BINDING_ID)
+            .ok_or_else(|| anyhow::anyhow!("Calibration recording was interrupted"))?;
+        manager.calibrate(&name, &samples)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+    .map_err(|e| e.to_string())?;
+
+    let active = summary.name.clone();
+    crate::settings::update_settings(&app, |s| {
+        s.active_noise_profile = Some(active);
+    });
+    Ok(summary)
+}
+
+#[tauri::command]
+pub fn delete_noise_profile(
+    app: AppHandle,
+    noise_profile_manager: State<'_, Arc<NoiseProfileManager>>,
+    name: String,
+) -> Result<(), String> {
+    noise_profile_manager
+        .delete(&name)
+        .map_err(|e| e.to_string())?;
+    crate::settings::update_settings(&app, |s| {
+        if s.active_noise_profile.as_deref() == Some(name.as_str()) {
+            s.active_noise_profile = None;
+        }
+    });
+    Ok(())
+}
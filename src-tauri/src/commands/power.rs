@@ -0,0 +1,12 @@
+use crate::managers::power::{PowerManager, PowerSourceEvent};
+use std::sync::Arc;
+use tauri::State;
+
+/// Report the current power source and whether the battery profile applies.
+#[tauri::command]
+pub fn get_power_state(power_manager: State<'_, Arc<PowerManager>>) -> PowerSourceEvent {
+    PowerSourceEvent {
+        on_battery: power_manager.is_on_battery(),
+        battery_profile_active: power_manager.battery_profile_active(),
+    }
+}
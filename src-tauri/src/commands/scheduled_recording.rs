@@ -0,0 +1,50 @@
+//! Tauri command handlers for scheduled recordings.
+
+use crate::managers::meeting::AudioSource;
+use crate::managers::scheduled_recording::{ScheduledRecording, ScheduledRecordingManager};
+use std::sync::Arc;
+use tauri::State;
+
+/// Book a recording starting at `start_time` (Unix seconds).
+#[tauri::command]
+pub fn schedule_recording(
+    scheduled_recording_manager: State<'_, Arc<ScheduledRecordingManager>>,
+    title: Option<String>,
+    start_time: i64,
+    duration_secs: i64,
+    source: AudioSource,
+) -> Result<ScheduledRecording, String> {
+    scheduled_recording_manager
+        .schedule(title, start_time, duration_secs, source)
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn list_scheduled_recordings(
+    scheduled_recording_manager: State<'_, Arc<ScheduledRecordingManager>>,
+) -> Result<Vec<ScheduledRecording>, String> {
+    scheduled_recording_manager
+        .list()
+        .map_err(|e| e.to_string())
+}
+
+/// Cancel an upcoming recording, or end a running one early.
+#[tauri::command]
+pub fn cancel_scheduled_recording(
+    scheduled_recording_manager: State<'_, Arc<ScheduledRecordingManager>>,
+    id: i64,
+) -> Result<(), String> {
+    scheduled_recording_manager
+        .cancel(id)
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn delete_scheduled_recording(
+    scheduled_recording_manager: State<'_, Arc<ScheduledRecordingManager>>,
+    id: i64,
+) -> Result<(), String> {
+    scheduled_recording_manager
+        .delete(id)
+        .map_err(|e| e.to_string())
+}
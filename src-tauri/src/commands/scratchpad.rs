@@ -0,0 +1,44 @@
+//! Tauri command handlers for the scratchpad.
+
+use crate::managers::scratchpad::ScratchpadManager;
+use std::sync::Arc;
+use tauri::State;
+
+/// Open the scratchpad window. Dictations go there until it is closed.
+#[tauri::command]
+pub async fn open_scratchpad(
+    scratchpad_manager: State<'_, Arc<ScratchpadManager>>,
+) -> Result<(), String> {
+    scratchpad_manager.open().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn get_scratchpad(scratchpad_manager: State<'_, Arc<ScratchpadManager>>) -> String {
+    scratchpad_manager.content()
+}
+
+#[tauri::command]
+pub fn scratchpad_append(
+    scratchpad_manager: State<'_, Arc<ScratchpadManager>>,
+    text: String,
+) -> String {
+    scratchpad_manager.append(&text)
+}
+
+#[tauri::command]
+pub fn scratchpad_replace(
+    scratchpad_manager: State<'_, Arc<ScratchpadManager>>,
+    text: String,
+) -> String {
+    scratchpad_manager.replace(text)
+}
+
+#[tauri::command]
+pub fn copy_scratchpad(
+    scratchpad_manager: State<'_, Arc<ScratchpadManager>>,
+    clear: bool,
+) -> Result<(), String> {
+    scratchpad_manager
+        .copy_out(clear)
+        .map_err(|e| e.to_string())
+}
@@ -0,0 +1,74 @@
+//! Tauri command handlers for WebAssembly text plugins.
+
+use crate::managers::text_plugins::{PluginInfo, PluginManifest, TextPluginManager};
+use std::path::PathBuf;
+use std::sync::Arc;
+use tauri::{AppHandle, State};
+use tauri_plugin_opener::OpenerExt;
+
+#[tauri::command]
+pub fn list_text_plugins(plugin_manager: State<'_, Arc<TextPluginManager>>) -> Vec<PluginInfo> {
+    plugin_manager.list()
+}
+
+/// Load the plugins folder again, picking up plugins dropped into it.
+#[tauri::command]
+pub fn reload_text_plugins(plugin_manager: State<'_, Arc<TextPluginManager>>) -> Vec<PluginInfo> {
+    plugin_manager.reload();
+    plugin_manager.list()
+}
+
+/// Install a plugin from its folder or its `manifest.json`.
+#[tauri::command]
+pub fn install_text_plugin(
+    plugin_manager: State<'_, Arc<TextPluginManager>>,
+    path: String,
+) -> Result<PluginManifest, String> {
+    plugin_manager
+        .install(&PathBuf::from(path))
+        .map_err(|e| format!("{:#}", e))
+}
+
+#[tauri::command]
+pub fn remove_text_plugin(
+    plugin_manager: State<'_, Arc<TextPluginManager>>,
+    id: String,
+) -> Result<(), String> {
+    plugin_manager.remove(&id).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn set_text_plugin_enabled(
+    plugin_manager: State<'_, Arc<TextPluginManager>>,
+    id: String,
+    enabled: bool,
+) -> Result<(), String> {
+    plugin_manager
+        .set_enabled(&id, enabled)
+        .map_err(|e| e.to_string())
+}
+
+/// Run a plugin on sample text, to try it before enabling it.
+#[tauri::command]
+pub async fn run_text_plugin(
+    plugin_manager: State<'_, Arc<TextPluginManager>>,
+    id: String,
+    text: String,
+) -> Result<String, String> {
+    let plugin_manager = plugin_manager.inner().clone();
+    tauri::async_runtime::spawn_blocking(move || plugin_manager.run(&id, &text))
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| format!("{:#}", e))
+}
+
+#[tauri::command]
+pub fn open_text_plugins_folder(
+    app: AppHandle,
+    plugin_manager: State<'_, Arc<TextPluginManager>>,
+) -> Result<(), String> {
+    let path = plugin_manager.dir().to_string_lossy().to_string();
+    app.opener()
+        .open_path(path, None::<String>)
+        .map_err(|e| format!("Failed to open plugins folder: {}", e))
+}
@@ -1,6 +1,18 @@
-use crate::managers::transcription::TranscriptionManager;
-use crate::settings::{self, ModelUnloadTimeout};
-use tauri::{AppHandle, State};
+use crate::audio_toolkit::backend::{self, BackendInfo};
+use crate::managers::audio::AudioRecordingManager;
+use crate::managers::history::HistoryManager;
+use crate::managers::transcription::{EngineStatus, ModelBenchmark, TranscriptionManager};
+use crate::managers::transcription_queue::JobInfo;
+use crate::settings::{self, InferenceBackend, ModelUnloadTimeout, WarmHours};
+use std::sync::Arc;
+use tauri::{AppHandle, Manager, State};
+
+/// Shortest dictation worth benchmarking on, in samples.
+const BENCH_MIN_SAMPLES: usize = 16000 * 10;
+/// Longer dictations are cut to this many samples.
+const BENCH_MAX_SAMPLES: usize = 16000 * 30;
+/// Recent history entries searched for a benchmark clip.
+const BENCH_SEARCH_ENTRIES: usize = 20;
 
 #[tauri::command]
 pub fn set_model_unload_timeout(app: AppHandle, timeout: ModelUnloadTimeout) {
@@ -9,6 +21,23 @@ pub fn set_model_unload_timeout(app: AppHandle, timeout: ModelUnloadTimeout) {
     });
 }
 
+/// Set the hours during which the model is kept loaded, or clear them.
+#[tauri::command]
+pub fn set_model_warm_hours(app: AppHandle, hours: Option<WarmHours>) -> Result<(), String> {
+    if let Some(hours) = hours {
+        if hours.start_hour > 23 || hours.end_hour > 23 {
+            return Err("Hours must be between 0 and 23".to_string());
+        }
+        if hours.start_hour == hours.end_hour {
+            return Err("Warm hours must start and end at different hours".to_string());
+        }
+    }
+    settings::update_settings(&app, |s| {
+        s.model_warm_hours = hours;
+    });
+    Ok(())
+}
+
 #[tauri::command]
 pub fn get_model_load_status(
     transcription_manager: State<TranscriptionManager>,
@@ -30,3 +59,96 @@ pub fn unload_model_manually(
         .unload_model()
         .map_err(|e| format!("Failed to unload model: {}", e))
 }
+
+/// Inference backends, with whether this machine can use them.
+#[tauri::command]
+pub fn get_inference_backends() -> Vec<BackendInfo> {
+    backend::available_backends()
+}
+
+/// Change the inference backend and CPU thread cap. A loaded model is
+/// unloaded so the next transcription loads it on the new backend.
+#[tauri::command]
+pub fn set_inference_backend(
+    app: AppHandle,
+    transcription_manager: State<'_, Arc<TranscriptionManager>>,
+    backend: InferenceBackend,
+    threads: Option<u32>,
+) -> Result<(), String> {
+    if !backend::is_available(backend) {
+        return Err(format!("{:?} is not available on this machine", backend));
+    }
+    settings::update_settings(&app, |s| {
+        s.inference_backend = backend;
+        s.inference_threads = threads.filter(|&n| n > 0);
+    });
+    if transcription_manager.is_model_loaded() {
+        transcription_manager
+            .unload_model()
+            .map_err(|e| format!("Failed to unload model: {}", e))?;
+    }
+    Ok(())
+}
+
+/// Whether a model is loaded and which, and what the engine is working on.
+#[tauri::command]
+pub fn get_engine_status(
+    transcription_manager: State<'_, Arc<TranscriptionManager>>,
+    recording_manager: State<'_, Arc<AudioRecordingManager>>,
+) -> EngineStatus {
+    transcription_manager.status(recording_manager.is_recording())
+}
+
+/// Load time, real-time factor and memory of the downloaded models, or just
+/// `model_id`. The clip is the most recent dictation in history of at least
+/// ten seconds, cut to thirty: real speech of dictation length, in the
+/// user's own voice and microphone. `memory_mb` is only measured on Linux
+/// and is `None` elsewhere.
+#[tauri::command]
+pub async fn bench_model(
+    app: AppHandle,
+    model_id: Option<String>,
+) -> Result<Vec<ModelBenchmark>, String> {
+    let history_manager = app.state::<Arc<HistoryManager>>().inner().clone();
+    let entries = history_manager
+        .get_history_entries()
+        .await
+        .map_err(|e| e.to_string())?;
+    let mut clip = entries
+        .iter()
+        .take(BENCH_SEARCH_ENTRIES)
+        .filter_map(|entry| history_manager.load_audio_for_entry(&entry.file_name).ok())
+        .find(|samples| samples.len() >= BENCH_MIN_SAMPLES)
+        .ok_or("Record a dictation of at least ten seconds to benchmark models with")?;
+    clip.truncate(BENCH_MAX_SAMPLES);
+
+    tauri::async_runtime::spawn_blocking(move || {
+        app.state::<Arc<TranscriptionManager>>()
+            .benchmark(model_id.as_deref(), &clip)
+            .map_err(|e| e.to_string())
+    })
+    .await
+    .map_err(|e| format!("Benchmark failed to run: {}", e))?
+}
+
+/// Transcription jobs that are running or waiting for the engine.
+#[tauri::command]
+pub fn get_transcription_jobs(
+    transcription_manager: State<'_, Arc<TranscriptionManager>>,
+) -> Vec<JobInfo> {
+    transcription_manager.jobs()
+}
+
+/// Cancel a transcription job. A pass already in the engine finishes, but
+/// its result is discarded.
+#[tauri::command]
+pub fn cancel_transcription_job(
+    transcription_manager: State<'_, Arc<TranscriptionManager>>,
+    id: u64,
+) -> Result<(), String> {
+    if transcription_manager.cancel_job(id) {
+        Ok(())
+    } else {
+        Err(format!("No transcription job {}", id))
+    }
+}
@@ -1,8 +1,16 @@
 use crate::managers::tts::TtsManager;
+use crate::settings;
 use std::sync::Arc;
-use tauri::State;
+use tauri::{AppHandle, State};
 
 #[tauri::command]
-pub fn preview_tts(state: State<'_, Arc<TtsManager>>, text: String) -> Result<(), String> {
-    state.speak(&text).map_err(|e| e.to_string())
+pub fn preview_tts(
+    app: AppHandle,
+    state: State<'_, Arc<TtsManager>>,
+    text: String,
+) -> Result<(), String> {
+    let pronunciations = settings::get_settings(&app).tts_pronunciations;
+    state
+        .speak(&text, &pronunciations)
+        .map_err(|e| e.to_string())
 }
@@ -0,0 +1,57 @@
+//! Tauri command handlers for voice profile enrollment.
+
+use crate::managers::audio::AudioRecordingManager;
+use crate::managers::voice_profile::{
+    VoiceProfileManager, VoiceProfileStatus, ENROLLMENT_BINDING_ID,
+};
+use std::sync::Arc;
+use tauri::State;
+
+#[tauri::command]
+pub fn get_voice_profile_status(
+    voice_profile_manager: State<'_, Arc<VoiceProfileManager>>,
+) -> VoiceProfileStatus {
+    voice_profile_manager.status()
+}
+
+/// Start recording the enrollment passage.
+#[tauri::command]
+pub fn start_voice_enrollment(
+    recording_manager: State<'_, Arc<AudioRecordingManager>>,
+) -> Result<(), String> {
+    if recording_manager.try_start_recording(ENROLLMENT_BINDING_ID) {
+        Ok(())
+    } else {
+        Err("Couldn't start recording. Is another recording running?".into())
+    }
+}
+
+/// Stop the enrollment recording and build the voice profile from it.
+#[tauri::command]
+pub async fn finish_voice_enrollment(
+    recording_manager: State<'_, Arc<AudioRecordingManager>>,
+    voice_profile_manager: State<'_, Arc<VoiceProfileManager>>,
+) -> Result<VoiceProfileStatus, String> {
+    let samples = recording_manager
+        .stop_recording(ENROLLMENT_BINDING_ID)
+        .ok_or("No enrollment recording in progress")?;
+    let manager = voice_profile_manager.inner().clone();
+    tauri::async_runtime::spawn_blocking(move || manager.enroll(&samples))
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn cancel_voice_enrollment(recording_manager: State<'_, Arc<AudioRecordingManager>>) {
+    if recording_manager.is_recording_binding(ENROLLMENT_BINDING_ID) {
+        recording_manager.cancel_recording();
+    }
+}
+
+#[tauri::command]
+pub fn delete_voice_profile(
+    voice_profile_manager: State<'_, Arc<VoiceProfileManager>>,
+) -> Result<(), String> {
+    voice_profile_manager.delete().map_err(|e| e.to_string())
+}
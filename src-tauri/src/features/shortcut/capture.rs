@@ -0,0 +1,311 @@
+//! Shortcut capture mode for the settings UI.
+//!
+//! The frontend can't reliably name keys (layouts, dead keys, Option-modified
+//! characters on macOS), so `capture_next_shortcut` watches the app's input
+//! hook for the next key combination and returns it in the format
+//! `register_shortcut` understands.
+
+use log::{debug, info};
+use rdev::{Event, EventType, Key};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc;
+use std::time::Duration;
+
+use crate::input_hook;
+
+use super::init::validate_shortcut_string;
+
+/// How long to wait for a key combination when the caller doesn't specify.
+const DEFAULT_CAPTURE_TIMEOUT_SECS: u64 = 10;
+
+/// Modifiers in the order they are written in a shortcut string.
+#[derive(Default)]
+struct HeldModifiers {
+    ctrl: bool,
+    alt: bool,
+    shift: bool,
+    meta: bool,
+}
+
+impl HeldModifiers {
+    /// Update the held state for `key`. Returns false if `key` isn't a modifier.
+    fn update(&mut self, key: Key, pressed: bool) -> bool {
+        let slot = match key {
+            Key::ControlLeft | Key::ControlRight => &mut self.ctrl,
+            Key::Alt | Key::AltGr => &mut self.alt,
+            Key::ShiftLeft | Key::ShiftRight => &mut self.shift,
+            Key::MetaLeft | Key::MetaRight => &mut self.meta,
+            _ => return false,
+        };
+        *slot = pressed;
+        true
+    }
+
+    fn is_empty(&self) -> bool {
+        !(self.ctrl || self.alt || self.shift || self.meta)
+    }
+
+    fn parts(&self) -> Vec<&'static str> {
+        let mut parts = Vec::new();
+        if self.ctrl {
+            parts.push("ctrl");
+        }
+        if self.alt {
+            parts.push(if cfg!(target_os = "macos") {
+                "option"
+            } else {
+                "alt"
+            });
+        }
+        if self.shift {
+            parts.push("shift");
+        }
+        if self.meta {
+            parts.push(if cfg!(target_os = "macos") {
+                "command"
+            } else {
+                "super"
+            });
+        }
+        parts
+    }
+}
+
+/// Map a non-modifier rdev key to its shortcut-string name.
+fn key_name(key: Key) -> Option<&'static str> {
+    let name = match key {
+        Key::KeyA => "a",
+        Key::KeyB => "b",
+        Key::KeyC => "c",
+        Key::KeyD => "d",
+        Key::KeyE => "e",
+        Key::KeyF => "f",
+        Key::KeyG => "g",
+        Key::KeyH => "h",
+        Key::KeyI => "i",
+        Key::KeyJ => "j",
+        Key::KeyK => "k",
+        Key::KeyL => "l",
+        Key::KeyM => "m",
+        Key::KeyN => "n",
+        Key::KeyO => "o",
+        Key::KeyP => "p",
+        Key::KeyQ => "q",
+        Key::KeyR => "r",
+        Key::KeyS => "s",
+        Key::KeyT => "t",
+        Key::KeyU => "u",
+        Key::KeyV => "v",
+        Key::KeyW => "w",
+        Key::KeyX => "x",
+        Key::KeyY => "y",
+        Key::KeyZ => "z",
+        Key::Num0 => "0",
+        Key::Num1 => "1",
+        Key::Num2 => "2",
+        Key::Num3 => "3",
+        Key::Num4 => "4",
+        Key::Num5 => "5",
+        Key::Num6 => "6",
+        Key::Num7 => "7",
+        Key::Num8 => "8",
+        Key::Num9 => "9",
+        Key::F1 => "f1",
+        Key::F2 => "f2",
+        Key::F3 => "f3",
+        Key::F4 => "f4",
+        Key::F5 => "f5",
+        Key::F6 => "f6",
+        Key::F7 => "f7",
+        Key::F8 => "f8",
+        Key::F9 => "f9",
+        Key::F10 => "f10",
+        Key::F11 => "f11",
+        Key::F12 => "f12",
+        Key::Space => "space",
+        Key::Return => "enter",
+        Key::Tab => "tab",
+        Key::Backspace => "backspace",
+        Key::Escape => "escape",
+        Key::Delete => "delete",
+        Key::Insert => "insert",
+        Key::Home => "home",
+        Key::End => "end",
+        Key::PageUp => "pageup",
+        Key::PageDown => "pagedown",
+        Key::UpArrow => "up",
+        Key::DownArrow => "down",
+        Key::LeftArrow => "left",
+        Key::RightArrow => "right",
+        Key::Comma => "comma",
+        Key::Dot => "period",
+        Key::Slash => "slash",
+        Key::SemiColon => "semicolon",
+        Key::Quote => "quote",
+        Key::Minus => "minus",
+        Key::Equal => "equal",
+        Key::LeftBracket => "bracketleft",
+        Key::RightBracket => "bracketright",
+        Key::BackSlash => "backslash",
+        Key::BackQuote => "backquote",
+        _ => return None,
+    };
+    Some(name)
+}
+
+/// Build the shortcut string for `key` pressed while `modifiers` are held.
+fn build_shortcut(modifiers: &HeldModifiers, key: Key) -> Option<String> {
+    let key = key_name(key)?;
+    let mut parts = modifiers.parts();
+    parts.push(key);
+    Some(parts.join("+"))
+}
+
+/// What the capture session resolved to.
+enum CaptureOutcome {
+    Shortcut(String),
+    Cancelled,
+}
+
+/// Set while a capture waits on a key combination.
+static CAPTURING: AtomicBool = AtomicBool::new(false);
+
+/// Clears [`CAPTURING`] when the capture that set it ends.
+struct CaptureGuard;
+
+impl CaptureGuard {
+    /// Claim the capture, or None if another one is in progress.
+    fn claim() -> Option<Self> {
+        (!CAPTURING.swap(true, Ordering::SeqCst)).then_some(CaptureGuard)
+    }
+}
+
+impl Drop for CaptureGuard {
+    fn drop(&mut self) {
+        CAPTURING.store(false, Ordering::SeqCst);
+    }
+}
+
+/// Watch the shared input hook for the next key combination and send it on
+/// `tx`, once.
+fn watch_keys(tx: mpsc::Sender<CaptureOutcome>) -> input_hook::Subscription {
+    let mut modifiers = HeldModifiers::default();
+    let mut resolved = false;
+    input_hook::subscribe(move |event: &Event| {
+        let (key, pressed) = match event.event_type {
+            EventType::KeyPress(key) => (key, true),
+            EventType::KeyRelease(key) => (key, false),
+            _ => return,
+        };
+
+        if modifiers.update(key, pressed) || !pressed || resolved {
+            return;
+        }
+
+        let outcome = if matches!(key, Key::Escape) && modifiers.is_empty() {
+            CaptureOutcome::Cancelled
+        } else {
+            match build_shortcut(&modifiers, key) {
+                Some(shortcut) => CaptureOutcome::Shortcut(shortcut),
+                None => {
+                    debug!(
+                        "[Shortcuts] Ignoring unsupported key {:?} during capture",
+                        key
+                    );
+                    return;
+                }
+            }
+        };
+
+        let _ = tx.send(outcome);
+        resolved = true;
+    })
+}
+
+/// Capture the next key combination pressed anywhere on the system.
+/// Escape on its own cancels the capture. Only one capture runs at a time,
+/// and Wayland doesn't let apps see keys pressed in other windows.
+#[tauri::command]
+pub async fn capture_next_shortcut(timeout_secs: Option<u64>) -> Result<String, String> {
+    #[cfg(target_os = "linux")]
+    if super::wayland::is_wayland_session() {
+        return Err("Shortcut capture isn't supported on Wayland".to_string());
+    }
+    let guard = CaptureGuard::claim()
+        .ok_or_else(|| "A shortcut capture is already in progress".to_string())?;
+
+    let (tx, rx) = mpsc::channel();
+    let subscription = watch_keys(tx);
+
+    let timeout = Duration::from_secs(timeout_secs.unwrap_or(DEFAULT_CAPTURE_TIMEOUT_SECS));
+    let outcome = tauri::async_runtime::spawn_blocking(move || rx.recv_timeout(timeout))
+        .await
+        .map_err(|e| format!("Shortcut capture failed: {}", e))?;
+
+    // Stop watching whether it resolved or timed out
+    drop(subscription);
+    drop(guard);
+
+    match outcome {
+        Ok(CaptureOutcome::Shortcut(shortcut)) => {
+            validate_shortcut_string(&shortcut)?;
+            info!("[Shortcuts] Captured shortcut '{}'", shortcut);
+            Ok(shortcut)
+        }
+        Ok(CaptureOutcome::Cancelled) => Err("Shortcut capture cancelled".to_string()),
+        Err(_) => Err("No shortcut pressed before the capture timed out".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_plain_key() {
+        let modifiers = HeldModifiers::default();
+        assert_eq!(build_shortcut(&modifiers, Key::F5).as_deref(), Some("f5"));
+    }
+
+    #[test]
+    fn builds_modifier_combo_in_canonical_order() {
+        let mut modifiers = HeldModifiers::default();
+        modifiers.update(Key::ShiftLeft, true);
+        modifiers.update(Key::ControlLeft, true);
+        assert_eq!(
+            build_shortcut(&modifiers, Key::Space).as_deref(),
+            Some("ctrl+shift+space")
+        );
+    }
+
+    #[test]
+    fn releasing_modifier_removes_it() {
+        let mut modifiers = HeldModifiers::default();
+        modifiers.update(Key::ControlLeft, true);
+        modifiers.update(Key::ControlLeft, false);
+        assert!(modifiers.is_empty());
+    }
+
+    #[test]
+    fn modifier_keys_are_not_shortcut_keys() {
+        let mut modifiers = HeldModifiers::default();
+        assert!(modifiers.update(Key::MetaLeft, true));
+        assert!(key_name(Key::MetaLeft).is_none());
+    }
+
+    #[test]
+    fn only_one_capture_at_a_time() {
+        let first = CaptureGuard::claim().unwrap();
+        assert!(CaptureGuard::claim().is_none());
+        drop(first);
+        assert!(CaptureGuard::claim().is_some());
+    }
+
+    #[test]
+    fn captured_shortcuts_pass_validation() {
+        let mut modifiers = HeldModifiers::default();
+        modifiers.update(Key::Alt, true);
+        let shortcut = build_shortcut(&modifiers, Key::KeyK).unwrap();
+        assert!(validate_shortcut_string(&shortcut).is_ok());
+    }
+}
@@ -0,0 +1,130 @@
+//! Push-to-talk release debounce.
+//!
+//! Key bounce or Bluetooth keyboard lag can deliver a spurious release/press
+//! pair in the middle of a push-to-talk dictation. Releases are held back for a
+//! configurable window; a press for the same binding inside that window cancels
+//! the pending stop so the recording simply continues.
+
+use log::debug;
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::AppHandle;
+
+use crate::actions::ACTION_MAP;
+use crate::settings::get_settings;
+
+/// Pending releases keyed by binding id, each tagged with a unique token so a
+/// stale timer can't stop a newer recording.
+#[derive(Default)]
+struct ReleaseDebouncer {
+    pending: HashMap<String, u64>,
+    next_token: u64,
+}
+
+impl ReleaseDebouncer {
+    /// Record a release for `binding_id` and return its token.
+    fn schedule(&mut self, binding_id: &str) -> u64 {
+        self.next_token += 1;
+        self.pending.insert(binding_id.to_string(), self.next_token);
+        self.next_token
+    }
+
+    /// Drop the pending release for `binding_id`. Returns true if there was one.
+    fn cancel(&mut self, binding_id: &str) -> bool {
+        self.pending.remove(binding_id).is_some()
+    }
+
+    /// Claim the release once its window has elapsed. Returns false if it was
+    /// cancelled or superseded in the meantime.
+    fn take(&mut self, binding_id: &str, token: u64) -> bool {
+        if self.pending.get(binding_id) == Some(&token) {
+            self.pending.remove(binding_id);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+static RELEASE_DEBOUNCER: Lazy<Mutex<ReleaseDebouncer>> =
+    Lazy::new(|| Mutex::new(ReleaseDebouncer::default()));
+
+/// Handle a push-to-talk key press.
+pub fn handle_ptt_pressed(app: &AppHandle, binding_id: &str, shortcut_str: &str) {
+    let Some(action) = ACTION_MAP.get(binding_id) else {
+        return;
+    };
+
+    if RELEASE_DEBOUNCER.lock().unwrap().cancel(binding_id) {
+        debug!(
+            "[Shortcuts] Press within release debounce for '{}', continuing recording",
+            binding_id
+        );
+        return;
+    }
+
+    action.start(app, binding_id, shortcut_str);
+}
+
+/// Handle a push-to-talk key release, delaying the stop by the configured debounce.
+pub fn handle_ptt_released(app: &AppHandle, binding_id: &str, shortcut_str: &str) {
+    let Some(action) = ACTION_MAP.get(binding_id) else {
+        return;
+    };
+
+    let debounce_ms = get_settings(app).ptt_release_debounce_ms;
+    if debounce_ms == 0 {
+        action.stop(app, binding_id, shortcut_str);
+        return;
+    }
+
+    let token = RELEASE_DEBOUNCER.lock().unwrap().schedule(binding_id);
+    let app = app.clone();
+    let binding_id = binding_id.to_string();
+    let shortcut_str = shortcut_str.to_string();
+    std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(debounce_ms));
+        if RELEASE_DEBOUNCER.lock().unwrap().take(&binding_id, token) {
+            action.stop(&app, &binding_id, &shortcut_str);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn release_fires_when_not_cancelled() {
+        let mut debouncer = ReleaseDebouncer::default();
+        let token = debouncer.schedule("transcribe");
+        assert!(debouncer.take("transcribe", token));
+        assert!(!debouncer.take("transcribe", token));
+    }
+
+    #[test]
+    fn press_within_window_cancels_release() {
+        let mut debouncer = ReleaseDebouncer::default();
+        let token = debouncer.schedule("transcribe");
+        assert!(debouncer.cancel("transcribe"));
+        assert!(!debouncer.take("transcribe", token));
+    }
+
+    #[test]
+    fn stale_token_does_not_fire_newer_release() {
+        let mut debouncer = ReleaseDebouncer::default();
+        let stale = debouncer.schedule("transcribe");
+        debouncer.cancel("transcribe");
+        let fresh = debouncer.schedule("transcribe");
+        assert!(!debouncer.take("transcribe", stale));
+        assert!(debouncer.take("transcribe", fresh));
+    }
+
+    #[test]
+    fn cancel_without_pending_release_is_noop() {
+        let mut debouncer = ReleaseDebouncer::default();
+        assert!(!debouncer.cancel("transcribe"));
+    }
+}
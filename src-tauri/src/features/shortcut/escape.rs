@@ -76,3 +76,36 @@ pub fn unregister_escape_shortcut(app: AppHandle) -> Result<(), String> {
 
     Ok(())
 }
+
+/// Shortcut that skips post-processing, held only while an LLM call is in flight.
+const SKIP_POST_PROCESSING_SHORTCUT: &str = "shift+escape";
+
+/// Register the shortcut that skips the post-processing in flight and pastes
+/// the raw transcription.
+pub fn register_skip_post_processing_shortcut(app: &AppHandle) -> Result<(), String> {
+    let shortcut = SKIP_POST_PROCESSING_SHORTCUT
+        .parse::<Shortcut>()
+        .map_err(|e| format!("Failed to parse skip shortcut: {}", e))?;
+    if app.global_shortcut().is_registered(shortcut) {
+        return Ok(());
+    }
+    app.global_shortcut()
+        .on_shortcut(shortcut, move |_ah, scut, event| {
+            if scut == &shortcut && event.state == ShortcutState::Pressed {
+                crate::actions::skip_post_processing();
+            }
+        })
+        .map_err(|e| format!("Failed to register skip shortcut: {}", e))
+}
+
+/// Unregister the skip shortcut once post-processing is over.
+pub fn unregister_skip_post_processing_shortcut(app: &AppHandle) {
+    let Ok(shortcut) = SKIP_POST_PROCESSING_SHORTCUT.parse::<Shortcut>() else {
+        return;
+    };
+    if app.global_shortcut().is_registered(shortcut) {
+        if let Err(e) = app.global_shortcut().unregister(shortcut) {
+            warn!("Failed to unregister skip shortcut: {}", e);
+        }
+    }
+}
@@ -6,12 +6,12 @@
 //! - **Windows/macOS**: Uses tauri-plugin-global-shortcut
 
 use log::{error, info, warn};
-use tauri::{AppHandle, Manager};
+use tauri::AppHandle;
 use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
 
-use crate::actions::ACTION_MAP;
+use super::debounce::{handle_ptt_pressed, handle_ptt_released};
+use crate::actions::{toggle_binding, ACTION_MAP};
 use crate::settings::{self, get_settings, ShortcutBinding};
-use crate::ManagedToggleState;
 
 /// Initialize all shortcuts from settings.
 /// Only registers shortcuts that have corresponding actions in ACTION_MAP.
@@ -158,32 +158,15 @@ pub fn register_shortcut(app: &AppHandle, binding: ShortcutBinding) -> Result<()
                 let shortcut_string = scut.into_string();
                 let settings = get_settings(ah);
 
-                if let Some(action) = ACTION_MAP.get(&binding_id_for_closure) {
+                if ACTION_MAP.contains_key(&binding_id_for_closure) {
                     if settings.push_to_talk {
                         if event.state == ShortcutState::Pressed {
-                            action.start(ah, &binding_id_for_closure, &shortcut_string);
+                            handle_ptt_pressed(ah, &binding_id_for_closure, &shortcut_string);
                         } else if event.state == ShortcutState::Released {
-                            action.stop(ah, &binding_id_for_closure, &shortcut_string);
+                            handle_ptt_released(ah, &binding_id_for_closure, &shortcut_string);
                         }
                     } else if event.state == ShortcutState::Pressed {
-                        let toggle_state_manager = ah.state::<ManagedToggleState>();
-
-                        let mut states = toggle_state_manager
-                            .lock()
-                            .expect("Failed to lock toggle state manager");
-
-                        let is_currently_active = states
-                            .active_toggles
-                            .entry(binding_id_for_closure.clone())
-                            .or_insert(false);
-
-                        if *is_currently_active {
-                            action.stop(ah, &binding_id_for_closure, &shortcut_string);
-                            *is_currently_active = false;
-                        } else {
-                            action.start(ah, &binding_id_for_closure, &shortcut_string);
-                            *is_currently_active = true;
-                        }
+                        toggle_binding(ah, &binding_id_for_closure, &shortcut_string);
                     }
                 } else {
                     warn!(
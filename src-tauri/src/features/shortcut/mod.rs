@@ -4,10 +4,14 @@
 //! - Shortcut initialization and registration (`init`)
 //! - Escape key handling for canceling operations (`escape`)
 //! - Binding management commands (`bindings`)
+//! - Capturing the next key combination for the settings UI (`capture`)
+//! - Push-to-talk release debounce (`debounce`)
 //! - Settings commands organized by feature area (`settings`)
 //! - Wayland-specific global shortcuts via XDG Portal (`wayland`)
 
 pub mod bindings;
+pub mod capture;
+pub mod debounce;
 pub mod escape;
 pub mod init;
 pub mod settings;
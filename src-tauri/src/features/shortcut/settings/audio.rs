@@ -1,9 +1,12 @@
 //! Audio-related settings commands.
 
 use log::warn;
-use tauri::AppHandle;
+use std::sync::Arc;
+use tauri::{AppHandle, State};
 
-use crate::settings::{self, SoundTheme};
+use crate::audio_toolkit::audio::{MAX_INPUT_GAIN_DB, MIN_INPUT_GAIN_DB};
+use crate::managers::model::ModelManager;
+use crate::settings::{self, RecordingFormat, SoundTheme};
 
 /// Change push-to-talk setting.
 #[tauri::command]
@@ -17,6 +20,15 @@ pub fn change_ptt_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
     Ok(())
 }
 
+/// Change push-to-talk release debounce setting.
+#[tauri::command]
+pub fn change_ptt_release_debounce_setting(app: AppHandle, debounce_ms: u64) -> Result<(), String> {
+    settings::update_settings(&app, |s| {
+        s.ptt_release_debounce_ms = debounce_ms;
+    });
+    Ok(())
+}
+
 /// Change audio feedback enabled setting.
 #[tauri::command]
 pub fn change_audio_feedback_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
@@ -26,6 +38,125 @@ pub fn change_audio_feedback_setting(app: AppHandle, enabled: bool) -> Result<()
     Ok(())
 }
 
+/// Change whether a muted microphone cancels the recording with a warning.
+#[tauri::command]
+pub fn change_mute_warning_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    settings::update_settings(&app, |s| {
+        s.mute_warning_enabled = enabled;
+    });
+    Ok(())
+}
+
+/// Change whether dictations are filtered to the enrolled voice. Downloads the
+/// speaker embedding model the filter needs when it is missing.
+#[tauri::command]
+pub fn change_voice_filter_setting(
+    app: AppHandle,
+    model_manager: State<'_, Arc<ModelManager>>,
+    enabled: bool,
+) -> Result<(), String> {
+    settings::update_settings(&app, |s| {
+        s.voice_filter_enabled = enabled;
+    });
+
+    let needs_download = model_manager
+        .get_model_info("diarization-embedding")
+        .map(|m| !m.is_downloaded && !m.is_downloading)
+        .unwrap_or(false);
+    if enabled && needs_download {
+        let mm = model_manager.inner().clone();
+        tauri::async_runtime::spawn(async move {
+            if let Err(e) = mm.download_model("diarization-embedding").await {
+                log::error!("Failed to download embedding model: {}", e);
+            }
+        });
+    }
+    Ok(())
+}
+
+/// Change the noise profile subtracted from dictations. An empty name turns
+/// suppression off.
+#[tauri::command]
+pub fn change_active_noise_profile_setting(
+    app: AppHandle,
+    name: Option<String>,
+) -> Result<(), String> {
+    settings::update_settings(&app, |s| {
+        s.active_noise_profile = name.clone().filter(|name| !name.is_empty());
+    });
+    Ok(())
+}
+
+/// Change whether the microphone is denoised live while recording. Applies
+/// from the next recording.
+#[tauri::command]
+pub fn change_noise_suppression_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    settings::update_settings(&app, |s| {
+        s.noise_suppression_enabled = enabled;
+    });
+    Ok(())
+}
+
+/// Change the fixed gain applied to the microphone, in dB. Applies from the
+/// next recording.
+#[tauri::command]
+pub fn change_input_gain_setting(app: AppHandle, gain_db: f32) -> Result<(), String> {
+    settings::update_settings(&app, |s| {
+        s.input_gain_db = gain_db.clamp(MIN_INPUT_GAIN_DB, MAX_INPUT_GAIN_DB);
+    });
+    Ok(())
+}
+
+/// Change whether the microphone is levelled automatically. Applies from
+/// the next recording.
+#[tauri::command]
+pub fn change_auto_gain_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    settings::update_settings(&app, |s| {
+        s.auto_gain_enabled = enabled;
+    });
+    Ok(())
+}
+
+/// Change whether always-on recordings start with the audio from just
+/// before the shortcut.
+#[tauri::command]
+pub fn change_pre_roll_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    settings::update_settings(&app, |s| {
+        s.pre_roll_enabled = enabled;
+    });
+    Ok(())
+}
+
+/// Change whether the overlay flashes when recording starts and stops.
+#[tauri::command]
+pub fn change_visual_feedback_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    settings::update_settings(&app, |s| {
+        s.visual_feedback = enabled;
+    });
+    Ok(())
+}
+
+/// Change whether the trackpad taps when recording starts and stops.
+#[tauri::command]
+pub fn change_haptic_feedback_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    settings::update_settings(&app, |s| {
+        s.haptic_feedback = enabled;
+    });
+    Ok(())
+}
+
+/// Change whether recording and pasting are announced to the screen reader.
+#[tauri::command]
+pub fn change_accessibility_announcements_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    settings::update_settings(&app, |s| {
+        s.accessibility_announcements = enabled;
+    });
+    Ok(())
+}
+
 /// Change audio feedback volume setting.
 #[tauri::command]
 pub fn change_audio_feedback_volume_setting(app: AppHandle, volume: f32) -> Result<(), String> {
@@ -62,3 +193,87 @@ pub fn change_mute_while_recording_setting(app: AppHandle, enabled: bool) -> Res
 
     Ok(())
 }
+
+/// Change auto-stop on trailing silence setting.
+#[tauri::command]
+pub fn change_auto_stop_silence_setting(
+    app: AppHandle,
+    seconds: Option<u64>,
+) -> Result<(), String> {
+    settings::update_settings(&app, |s| {
+        s.auto_stop_silence_secs = seconds.filter(|&secs| secs > 0);
+    });
+    Ok(())
+}
+
+/// Change the maximum recording duration setting.
+#[tauri::command]
+pub fn change_max_recording_setting(app: AppHandle, minutes: Option<u64>) -> Result<(), String> {
+    settings::update_settings(&app, |s| {
+        s.max_recording_minutes = minutes.filter(|&mins| mins > 0);
+    });
+    Ok(())
+}
+
+/// Change how long the always-on microphone stays open without a recording.
+#[tauri::command]
+pub fn change_always_on_idle_release_setting(
+    app: AppHandle,
+    minutes: Option<u64>,
+) -> Result<(), String> {
+    settings::update_settings(&app, |s| {
+        s.always_on_idle_release_minutes = minutes.filter(|&mins| mins > 0);
+    });
+    Ok(())
+}
+
+/// Change the file format new history recordings are saved in.
+#[tauri::command]
+pub fn change_recording_format_setting(
+    app: AppHandle,
+    format: RecordingFormat,
+) -> Result<(), String> {
+    settings::update_settings(&app, |s| {
+        s.recording_format = format;
+    });
+    Ok(())
+}
+
+/// Change minimum recording duration setting.
+#[tauri::command]
+pub fn change_min_recording_duration_setting(
+    app: AppHandle,
+    duration_ms: u64,
+) -> Result<(), String> {
+    settings::update_settings(&app, |s| {
+        s.min_recording_duration_ms = duration_ms;
+    });
+    Ok(())
+}
+
+/// Change recording countdown setting.
+#[tauri::command]
+pub fn change_recording_countdown_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    settings::update_settings(&app, |s| {
+        s.recording_countdown_enabled = enabled;
+    });
+    Ok(())
+}
+
+/// Change whether audio and background threads get adjusted priorities.
+#[tauri::command]
+pub fn change_thread_priorities_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    settings::update_settings(&app, |s| {
+        s.thread_priorities_enabled = enabled;
+    });
+    Ok(())
+}
+
+/// Change whether silence is trimmed with the VAD before transcription.
+#[tauri::command]
+pub fn change_vad_trim_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    settings::update_settings(&app, |s| {
+        s.vad_trim_enabled = enabled;
+    });
+    Ok(())
+}
@@ -0,0 +1,88 @@
+//! Cloud transcription settings commands.
+
+use std::sync::Arc;
+
+use log::warn;
+use tauri::{AppHandle, Manager};
+
+use crate::managers::transcription::TranscriptionManager;
+use crate::settings;
+
+fn validate_provider_exists(
+    settings: &settings::AppSettings,
+    provider_id: &str,
+) -> Result<(), String> {
+    if !settings
+        .transcription_providers
+        .iter()
+        .any(|provider| provider.id == provider_id)
+    {
+        return Err(format!("Provider '{}' not found", provider_id));
+    }
+    Ok(())
+}
+
+/// Drop the loaded engine so the next recording picks cloud or local
+/// transcription from the new settings.
+fn reload_engine(app: &AppHandle) {
+    let tm = app.state::<Arc<TranscriptionManager>>();
+    if tm.is_model_loaded() {
+        if let Err(e) = tm.unload_model() {
+            warn!(
+                "Failed to unload model after transcription settings change: {}",
+                e
+            );
+        }
+    }
+}
+
+#[tauri::command]
+pub fn change_cloud_transcription_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    settings::update_settings(&app, |s| {
+        s.cloud_transcription_enabled = enabled;
+    });
+    reload_engine(&app);
+    Ok(())
+}
+
+/// Set the active cloud transcription provider.
+#[tauri::command]
+pub fn set_transcription_provider(app: AppHandle, provider_id: String) -> Result<(), String> {
+    settings::try_update_settings(&app, |s| {
+        validate_provider_exists(s, &provider_id)?;
+        s.transcription_provider_id = provider_id.clone();
+        Ok(())
+    })?;
+    reload_engine(&app);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn change_transcription_api_key_setting(
+    app: AppHandle,
+    provider_id: String,
+    api_key: String,
+) -> Result<(), String> {
+    settings::try_update_settings(&app, |s| {
+        validate_provider_exists(s, &provider_id)?;
+        s.transcription_api_keys
+            .insert(provider_id.clone(), api_key.clone());
+        Ok(())
+    })?;
+    reload_engine(&app);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn change_transcription_model_setting(
+    app: AppHandle,
+    provider_id: String,
+    model: String,
+) -> Result<(), String> {
+    settings::try_update_settings(&app, |s| {
+        validate_provider_exists(s, &provider_id)?;
+        s.transcription_models
+            .insert(provider_id.clone(), model.clone());
+        Ok(())
+    })
+}
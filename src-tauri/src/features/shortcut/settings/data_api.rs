@@ -0,0 +1,51 @@
+//! Data API settings commands.
+
+use std::sync::Arc;
+use tauri::{AppHandle, Manager};
+
+use crate::managers::data_api::DataApiManager;
+use crate::settings::{self, DataApiField};
+
+#[tauri::command]
+pub fn change_data_api_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let manager = app.state::<Arc<DataApiManager>>();
+    if enabled {
+        // Leave the setting off if the port is taken
+        manager.start().map_err(|e| e.to_string())?;
+    } else {
+        manager.stop();
+    }
+
+    settings::update_settings(&app, |s| {
+        s.data_api_enabled = enabled;
+    });
+    Ok(())
+}
+
+#[tauri::command]
+pub fn change_data_api_port_setting(app: AppHandle, port: u16) -> Result<(), String> {
+    if port < 1024 {
+        return Err("Port must be 1024 or higher".to_string());
+    }
+    settings::update_settings(&app, |s| {
+        s.data_api_port = port;
+    });
+
+    if settings::get_settings(&app).data_api_enabled {
+        app.state::<Arc<DataApiManager>>()
+            .start()
+            .map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub fn change_data_api_redacted_fields_setting(
+    app: AppHandle,
+    fields: Vec<DataApiField>,
+) -> Result<(), String> {
+    settings::update_settings(&app, |s| {
+        s.data_api_redacted_fields = fields;
+    });
+    Ok(())
+}
@@ -0,0 +1,36 @@
+//! Daily digest settings commands.
+
+use tauri::AppHandle;
+
+use crate::settings;
+
+#[tauri::command]
+pub fn change_daily_digest_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    settings::update_settings(&app, |s| {
+        s.daily_digest_enabled = enabled;
+    });
+    Ok(())
+}
+
+#[tauri::command]
+pub fn change_daily_digest_folder_setting(
+    app: AppHandle,
+    folder: Option<String>,
+) -> Result<(), String> {
+    let folder = folder.filter(|f| !f.trim().is_empty());
+    settings::update_settings(&app, |s| {
+        s.daily_digest_folder = folder;
+    });
+    Ok(())
+}
+
+#[tauri::command]
+pub fn change_daily_digest_llm_summary_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    settings::update_settings(&app, |s| {
+        s.daily_digest_llm_summary = enabled;
+    });
+    Ok(())
+}
@@ -4,7 +4,14 @@ use log::warn;
 use tauri::{AppHandle, Emitter};
 use tauri_plugin_autostart::ManagerExt;
 
-use crate::settings::{self, ClipboardHandling, OverlayPosition, PasteMethod};
+use crate::action_registry;
+use crate::helpers::locale_format::{self, LanguageRules};
+use crate::helpers::text_normalization;
+use crate::managers::input_tracker::validate_window_patterns;
+use crate::settings::{
+    self, ClipboardHandling, DictationCommand, OverlayPosition, PasteMethod, ProfanityFilter,
+    VoiceCommandRule,
+};
 
 /// Change translate to English setting.
 #[tauri::command]
@@ -15,6 +22,16 @@ pub fn change_translate_to_english_setting(app: AppHandle, enabled: bool) -> Res
     Ok(())
 }
 
+/// Turn privacy mode on or off. While it's on, dictations aren't kept in
+/// history and input tracking is paused.
+#[tauri::command]
+pub fn change_privacy_mode_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    settings::update_settings(&app, |s| {
+        s.privacy_mode = enabled;
+    });
+    Ok(())
+}
+
 /// Change selected language setting.
 #[tauri::command]
 pub fn change_selected_language_setting(app: AppHandle, language: String) -> Result<(), String> {
@@ -24,6 +41,247 @@ pub fn change_selected_language_setting(app: AppHandle, language: String) -> Res
     Ok(())
 }
 
+/// Change focused-field context prompt setting.
+#[tauri::command]
+pub fn change_context_prompt_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    settings::update_settings(&app, |s| {
+        s.context_prompt_enabled = enabled;
+    });
+    Ok(())
+}
+
+/// Change the Whisper initial prompt text.
+#[tauri::command]
+pub fn change_initial_prompt_setting(app: AppHandle, prompt: String) -> Result<(), String> {
+    settings::update_settings(&app, |s| {
+        s.initial_prompt = prompt.trim().to_string();
+    });
+    Ok(())
+}
+
+/// Change whether custom words are part of the initial prompt.
+#[tauri::command]
+pub fn change_initial_prompt_custom_words_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    settings::update_settings(&app, |s| {
+        s.initial_prompt_custom_words = enabled;
+    });
+    Ok(())
+}
+
+/// Change whether the frontmost app's name is part of the initial prompt.
+#[tauri::command]
+pub fn change_initial_prompt_active_app_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    settings::update_settings(&app, |s| {
+        s.initial_prompt_active_app = enabled;
+    });
+    Ok(())
+}
+
+/// Languages with punctuation and capitalization rules.
+#[tauri::command]
+pub fn get_locale_formatting_rules() -> &'static [LanguageRules] {
+    locale_format::SUPPORTED
+}
+
+/// Turn the punctuation and capitalization rules of `language` on or off.
+#[tauri::command]
+pub fn change_locale_formatting_setting(
+    app: AppHandle,
+    language: String,
+    enabled: bool,
+) -> Result<(), String> {
+    if !locale_format::is_supported(&language) {
+        return Err(format!("No formatting rules for language '{}'", language));
+    }
+    settings::update_settings(&app, |s| {
+        s.locale_formatting.retain(|code| code != &language);
+        if enabled {
+            s.locale_formatting.push(language.clone());
+        }
+    });
+    Ok(())
+}
+
+/// Languages whose spoken numbers can be written with digits.
+#[tauri::command]
+pub fn get_text_normalization_rules() -> &'static [LanguageRules] {
+    text_normalization::SUPPORTED
+}
+
+/// Turn writing spoken numbers, prices and dates with digits on or off for
+/// `language`.
+#[tauri::command]
+pub fn change_text_normalization_setting(
+    app: AppHandle,
+    language: String,
+    enabled: bool,
+) -> Result<(), String> {
+    if !text_normalization::is_supported(&language) {
+        return Err(format!("No number rules for language '{}'", language));
+    }
+    settings::update_settings(&app, |s| {
+        s.text_normalization.retain(|code| code != &language);
+        if enabled {
+            s.text_normalization.push(language.clone());
+        }
+    });
+    Ok(())
+}
+
+/// Change profanity filter setting.
+#[tauri::command]
+pub fn change_profanity_filter_setting(
+    app: AppHandle,
+    mode: ProfanityFilter,
+) -> Result<(), String> {
+    settings::update_settings(&app, |s| {
+        s.profanity_filter = mode;
+    });
+    Ok(())
+}
+
+/// Replace the words the profanity filter catches.
+#[tauri::command]
+pub fn set_profanity_words(app: AppHandle, words: Vec<String>) -> Result<(), String> {
+    let words: Vec<String> = words
+        .iter()
+        .map(|word| word.trim().to_lowercase())
+        .filter(|word| !word.is_empty() && word != "*")
+        .collect();
+    settings::update_settings(&app, |s| {
+        s.profanity_words = words.clone();
+    });
+    Ok(())
+}
+
+/// Change learn from corrections setting.
+#[tauri::command]
+pub fn change_learn_from_corrections_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    settings::update_settings(&app, |s| {
+        s.learn_from_corrections = enabled;
+    });
+    Ok(())
+}
+
+/// Change punctuation restoration setting.
+#[tauri::command]
+pub fn change_punctuation_restoration_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    settings::update_settings(&app, |s| {
+        s.punctuation_restoration = enabled;
+    });
+    Ok(())
+}
+
+/// Change dictation commands setting.
+#[tauri::command]
+pub fn change_dictation_commands_enabled_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    settings::update_settings(&app, |s| {
+        s.dictation_commands_enabled = enabled;
+    });
+    Ok(())
+}
+
+/// Replace the spoken commands of `language`. An empty list removes them.
+#[tauri::command]
+pub fn set_dictation_commands(
+    app: AppHandle,
+    language: String,
+    commands: Vec<DictationCommand>,
+) -> Result<(), String> {
+    let mut phrases = std::collections::HashSet::new();
+    for command in &commands {
+        let phrase = command.phrase.trim().to_lowercase();
+        if phrase.is_empty() {
+            return Err("Command phrase cannot be empty".to_string());
+        }
+        if !phrases.insert(phrase) {
+            return Err(format!(
+                "'{}' is used by two commands",
+                command.phrase.trim()
+            ));
+        }
+    }
+    settings::update_settings(&app, |s| {
+        if commands.is_empty() {
+            s.dictation_commands.remove(&language);
+        } else {
+            s.dictation_commands.insert(language, commands);
+        }
+    });
+    Ok(())
+}
+
+/// Change voice command rules setting.
+#[tauri::command]
+pub fn change_voice_command_rules_enabled_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    settings::update_settings(&app, |s| {
+        s.voice_command_rules_enabled = enabled;
+    });
+    Ok(())
+}
+
+/// Replace the voice command rules.
+#[tauri::command]
+pub fn set_voice_command_rules(app: AppHandle, rules: Vec<VoiceCommandRule>) -> Result<(), String> {
+    for rule in &rules {
+        if rule.phrase.trim().is_empty() {
+            return Err("Command phrase cannot be empty".to_string());
+        }
+        if !action_registry::ACTIONS
+            .iter()
+            .any(|action| action.id == rule.action)
+        {
+            return Err(format!("Unknown action '{}'", rule.action));
+        }
+    }
+    settings::update_settings(&app, |s| {
+        s.voice_command_rules = rules;
+    });
+    Ok(())
+}
+
+/// Change respect focus mode setting.
+#[tauri::command]
+pub fn change_respect_focus_mode_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    settings::update_settings(&app, |s| {
+        s.respect_focus_mode = enabled;
+    });
+    Ok(())
+}
+
+/// Change battery saver setting.
+#[tauri::command]
+pub fn change_battery_saver_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    settings::update_settings(&app, |s| {
+        s.battery_saver_enabled = enabled;
+    });
+    Ok(())
+}
+
+/// Change the model used while on battery. An empty id clears it.
+#[tauri::command]
+pub fn change_battery_model_setting(
+    app: AppHandle,
+    model_id: Option<String>,
+) -> Result<(), String> {
+    settings::update_settings(&app, |s| {
+        s.battery_model = model_id.clone().filter(|id| !id.is_empty());
+    });
+    Ok(())
+}
+
 /// Change overlay position setting.
 #[tauri::command]
 pub fn change_overlay_position_setting(app: AppHandle, position: String) -> Result<(), String> {
@@ -46,6 +304,15 @@ pub fn change_overlay_position_setting(app: AppHandle, position: String) -> Resu
     Ok(())
 }
 
+/// Change overlay shortcut hint setting.
+#[tauri::command]
+pub fn change_overlay_shortcut_hint_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    settings::update_settings(&app, |s| {
+        s.overlay_shortcut_hint = enabled;
+    });
+    Ok(())
+}
+
 /// Change debug mode setting.
 #[tauri::command]
 pub fn change_debug_mode_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
@@ -119,6 +386,33 @@ pub fn update_custom_words(app: AppHandle, words: Vec<String>) -> Result<(), Str
     Ok(())
 }
 
+/// Set the custom words used when `app` is frontmost. An empty list removes
+/// the app's list.
+#[tauri::command]
+pub fn set_app_custom_words(
+    app: AppHandle,
+    app_id: String,
+    words: Vec<String>,
+) -> Result<(), String> {
+    let app_id = app_id.trim().to_string();
+    if app_id.is_empty() {
+        return Err("App name or bundle id is required".to_string());
+    }
+    let words: Vec<String> = words
+        .iter()
+        .map(|word| word.trim().to_string())
+        .filter(|word| !word.is_empty())
+        .collect();
+    settings::update_settings(&app, |s| {
+        if words.is_empty() {
+            s.app_custom_words.remove(&app_id);
+        } else {
+            s.app_custom_words.insert(app_id.clone(), words.clone());
+        }
+    });
+    Ok(())
+}
+
 /// Change word correction threshold setting.
 #[tauri::command]
 pub fn change_word_correction_threshold_setting(
@@ -131,6 +425,39 @@ pub fn change_word_correction_threshold_setting(
     Ok(())
 }
 
+/// Change low-confidence review setting.
+#[tauri::command]
+pub fn change_low_confidence_review_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    settings::update_settings(&app, |s| {
+        s.low_confidence_review_enabled = enabled;
+    });
+    Ok(())
+}
+
+/// Change the confidence below which dictations are held for review.
+#[tauri::command]
+pub fn change_low_confidence_threshold_setting(
+    app: AppHandle,
+    threshold: f64,
+) -> Result<(), String> {
+    settings::update_settings(&app, |s| {
+        s.low_confidence_threshold = threshold.clamp(0.0, 1.0);
+    });
+    Ok(())
+}
+
+/// Change the interval live previews aim for between partial transcriptions.
+#[tauri::command]
+pub fn change_streaming_target_latency_setting(
+    app: AppHandle,
+    latency_ms: u64,
+) -> Result<(), String> {
+    settings::update_settings(&app, |s| {
+        s.streaming_target_latency_ms = latency_ms.clamp(300, 2000);
+    });
+    Ok(())
+}
+
 /// Change paste method setting.
 #[tauri::command]
 pub fn change_paste_method_setting(app: AppHandle, method: String) -> Result<(), String> {
@@ -172,6 +499,37 @@ pub fn change_clipboard_handling_setting(app: AppHandle, handling: String) -> Re
     Ok(())
 }
 
+/// Change paste target verification setting.
+#[tauri::command]
+pub fn change_verify_paste_target_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    settings::update_settings(&app, |s| {
+        s.verify_paste_target = enabled;
+    });
+    Ok(())
+}
+
+/// Change the apps dictations are never pasted into.
+#[tauri::command]
+pub fn change_no_paste_apps_setting(app: AppHandle, apps: Vec<String>) -> Result<(), String> {
+    settings::update_settings(&app, |s| {
+        s.no_paste_apps = apps;
+    });
+    Ok(())
+}
+
+/// Change the window title/URL patterns dictations are never pasted into.
+#[tauri::command]
+pub fn change_no_paste_windows_setting(
+    app: AppHandle,
+    patterns: Vec<String>,
+) -> Result<(), String> {
+    validate_window_patterns(&patterns)?;
+    settings::update_settings(&app, |s| {
+        s.no_paste_window_patterns = patterns;
+    });
+    Ok(())
+}
+
 /// Change debug logging setting.
 #[tauri::command]
 pub fn change_debug_logging_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
@@ -1,9 +1,11 @@
 //! Input tracking settings commands.
+//!
+//! The commands only write settings: the input tracker follows them live
+//! through its settings subscription.
 
-use std::sync::Arc;
-use tauri::{AppHandle, Manager};
+use tauri::AppHandle;
 
-use crate::managers::input_tracker::InputTrackerManager;
+use crate::managers::input_tracker::validate_window_patterns;
 use crate::settings;
 
 /// Change input tracking setting.
@@ -18,18 +20,6 @@ pub fn change_input_tracking_setting(app: AppHandle, enabled: bool) -> Result<()
         s.input_tracking_enabled = enabled;
     });
 
-    // Side effect outside lock: update the input tracker manager state
-    if let Some(manager) = app.try_state::<Arc<std::sync::Mutex<InputTrackerManager>>>() {
-        log::info!("[InputTracker] Found manager state, updating...");
-        if let Ok(mut tracker) = manager.lock() {
-            tracker.set_enabled(enabled, &app);
-        } else {
-            log::error!("[InputTracker] Failed to lock manager");
-        }
-    } else {
-        log::error!("[InputTracker] Manager not found in app state!");
-    }
-
     Ok(())
 }
 
@@ -44,17 +34,39 @@ pub fn change_input_tracking_excluded_apps(
         apps.len()
     );
 
-    let apps_for_manager = apps.clone();
     settings::update_settings(&app, |s| {
         s.input_tracking_excluded_apps = apps;
     });
 
-    // Side effect outside lock: update the input tracker manager
-    if let Some(manager) = app.try_state::<Arc<std::sync::Mutex<InputTrackerManager>>>() {
-        if let Ok(tracker) = manager.lock() {
-            tracker.set_excluded_apps(apps_for_manager);
-        }
-    }
+    Ok(())
+}
+
+/// Change input tracking window title/URL exclusion patterns.
+#[tauri::command]
+pub fn change_input_tracking_excluded_windows(
+    app: AppHandle,
+    patterns: Vec<String>,
+) -> Result<(), String> {
+    log::info!(
+        "[InputTracker] change_input_tracking_excluded_windows called with {} patterns",
+        patterns.len()
+    );
+
+    validate_window_patterns(&patterns)?;
+
+    settings::update_settings(&app, |s| {
+        s.input_tracking_excluded_window_patterns = patterns;
+    });
+
+    Ok(())
+}
+
+/// Change whether pasted clipboard text is merged into tracked entries.
+#[tauri::command]
+pub fn change_input_tracking_capture_paste(app: AppHandle, enabled: bool) -> Result<(), String> {
+    settings::update_settings(&app, |s| {
+        s.input_tracking_capture_paste = enabled;
+    });
 
     Ok(())
 }
@@ -74,13 +86,5 @@ pub fn change_input_tracking_idle_timeout(
         s.input_tracking_idle_timeout = timeout_secs;
     });
 
-    // Side effect outside lock: update the input tracker manager
-    if let Some(manager) = app.try_state::<Arc<std::sync::Mutex<InputTrackerManager>>>() {
-        if let Ok(tracker) = manager.lock() {
-            // 0 means disabled, None also means disabled
-            tracker.set_idle_timeout(timeout_secs.unwrap_or(0));
-        }
-    }
-
     Ok(())
 }
@@ -33,6 +33,14 @@ pub fn change_meeting_auto_summary_setting(app: AppHandle, enabled: bool) -> Res
     Ok(())
 }
 
+#[tauri::command]
+pub fn change_interview_mode_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    settings::update_settings(&app, |s| {
+        s.interview_mode_enabled = enabled;
+    });
+    Ok(())
+}
+
 #[tauri::command]
 pub fn change_meeting_chunk_duration_setting(
     app: AppHandle,
@@ -56,35 +64,57 @@ pub fn change_meeting_diarization_setting(
 
     // Auto-download diarization models when enabling
     if enabled {
-        let segmentation_needs_download = model_manager
-            .get_model_info("diarization-segmentation")
-            .map(|m| !m.is_downloaded && !m.is_downloading)
-            .unwrap_or(false);
-        let embedding_needs_download = model_manager
-            .get_model_info("diarization-embedding")
-            .map(|m| !m.is_downloaded && !m.is_downloading)
-            .unwrap_or(false);
-
-        if segmentation_needs_download || embedding_needs_download {
-            let mm = model_manager.inner().clone();
-            tauri::async_runtime::spawn(async move {
-                if segmentation_needs_download {
-                    if let Err(e) = mm.download_model("diarization-segmentation").await {
-                        log::error!("Failed to download segmentation model: {}", e);
-                    }
-                }
-                if embedding_needs_download {
-                    if let Err(e) = mm.download_model("diarization-embedding").await {
-                        log::error!("Failed to download embedding model: {}", e);
-                    }
-                }
-            });
-        }
+        download_diarization_models(&model_manager);
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub fn change_file_diarization_setting(
+    app: AppHandle,
+    model_manager: State<'_, Arc<ModelManager>>,
+    enabled: bool,
+) -> Result<(), String> {
+    settings::update_settings(&app, |s| {
+        s.file_diarization_enabled = enabled;
+    });
+
+    if enabled {
+        download_diarization_models(&model_manager);
     }
 
     Ok(())
 }
 
+/// Start downloading whichever diarization models are missing.
+fn download_diarization_models(model_manager: &Arc<ModelManager>) {
+    let segmentation_needs_download = model_manager
+        .get_model_info("diarization-segmentation")
+        .map(|m| !m.is_downloaded && !m.is_downloading)
+        .unwrap_or(false);
+    let embedding_needs_download = model_manager
+        .get_model_info("diarization-embedding")
+        .map(|m| !m.is_downloaded && !m.is_downloading)
+        .unwrap_or(false);
+
+    if segmentation_needs_download || embedding_needs_download {
+        let mm = Arc::clone(model_manager);
+        tauri::async_runtime::spawn(async move {
+            if segmentation_needs_download {
+                if let Err(e) = mm.download_model("diarization-segmentation").await {
+                    log::error!("Failed to download segmentation model: {}", e);
+                }
+            }
+            if embedding_needs_download {
+                if let Err(e) = mm.download_model("diarization-embedding").await {
+                    log::error!("Failed to download embedding model: {}", e);
+                }
+            }
+        });
+    }
+}
+
 #[tauri::command]
 pub fn get_diarization_status(
     app: AppHandle,
@@ -2,11 +2,17 @@
 //!
 //! This module is organized by feature area:
 //! - `audio` - Audio-related settings (feedback, theme, mute)
+//! - `cloud_transcription` - Cloud transcription providers and API keys
 //! - `general` - General application settings (language, overlay, clipboard, etc.)
 //! - `post_process` - LLM/post-processing settings (providers, prompts, models)
 //! - `input_tracking` - Input tracking settings
+//! - `digest` - Daily digest settings
+//! - `data_api` - Read-only data API settings
 
 pub mod audio;
+pub mod cloud_transcription;
+pub mod data_api;
+pub mod digest;
 pub mod general;
 pub mod input_tracking;
 pub mod meeting;
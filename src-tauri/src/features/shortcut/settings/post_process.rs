@@ -405,6 +405,15 @@ pub fn change_voice_commands_enabled_setting(app: AppHandle, enabled: bool) -> R
     Ok(())
 }
 
+/// Change spoken prompt switching ("echo, formal mode") setting.
+#[tauri::command]
+pub fn change_voice_prompt_switching_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    settings::update_settings(&app, |s| {
+        s.voice_prompt_switching_enabled = enabled;
+    });
+    Ok(())
+}
+
 /// Change post-process enabled setting.
 #[tauri::command]
 pub fn change_post_process_enabled_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
@@ -1,6 +1,7 @@
 //! TTS-related settings commands.
 
 use crate::settings;
+use std::collections::HashMap;
 use tauri::AppHandle;
 
 #[tauri::command]
@@ -10,3 +11,21 @@ pub fn change_tts_enabled_setting(app: AppHandle, enabled: bool) -> Result<(), S
     });
     Ok(())
 }
+
+/// Replace the pronunciation dictionary text-to-speech applies before
+/// reading back. Entries without a word or a respelling are dropped.
+#[tauri::command]
+pub fn set_tts_pronunciations(
+    app: AppHandle,
+    pronunciations: HashMap<String, String>,
+) -> Result<(), String> {
+    let pronunciations: HashMap<String, String> = pronunciations
+        .into_iter()
+        .map(|(word, respelling)| (word.trim().to_string(), respelling.trim().to_string()))
+        .filter(|(word, respelling)| !word.is_empty() && !respelling.is_empty())
+        .collect();
+    settings::update_settings(&app, |s| {
+        s.tts_pronunciations = pronunciations.clone();
+    });
+    Ok(())
+}
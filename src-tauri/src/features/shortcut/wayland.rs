@@ -32,9 +32,9 @@ use std::sync::{Arc, Mutex};
 use tauri::{AppHandle, Emitter, Manager};
 use tokio::sync::{mpsc, oneshot};
 
-use crate::actions::ACTION_MAP;
+use super::debounce::{handle_ptt_pressed, handle_ptt_released};
+use crate::actions::{toggle_binding, ACTION_MAP};
 use crate::settings::{self, ShortcutBinding};
-use crate::ManagedToggleState;
 
 // ---------------------------------------------------------------------------
 // Types
@@ -881,10 +881,10 @@ pub fn check_wayland_shortcut_conflict(binding: String) -> Option<String> {
 
 /// Handle shortcut activation (key pressed).
 fn handle_shortcut_activated(app: &AppHandle, shortcut_id: &str) {
-    let Some(action) = ACTION_MAP.get(shortcut_id) else {
+    if !ACTION_MAP.contains_key(shortcut_id) {
         warn!("[Wayland] No action found for shortcut ID: {}", shortcut_id);
         return;
-    };
+    }
 
     // Apply backspace workaround if needed (before the action to clean up leaked character)
     if needs_backspace_workaround(app, shortcut_id) {
@@ -901,50 +901,29 @@ fn handle_shortcut_activated(app: &AppHandle, shortcut_id: &str) {
     if settings.push_to_talk {
         // Push-to-talk mode: start on press
         info!("[Wayland] PTT mode: starting action for '{}'", shortcut_id);
-        action.start(app, shortcut_id, shortcut_id);
+        handle_ptt_pressed(app, shortcut_id, shortcut_id);
     } else {
-        // Toggle mode: toggle state on press
-        let toggle_state = app.state::<ManagedToggleState>();
-
-        if let Ok(mut states) = toggle_state.lock() {
-            let is_active = states
-                .active_toggles
-                .entry(shortcut_id.to_string())
-                .or_insert(false);
-
-            if *is_active {
-                info!(
-                    "[Wayland] Toggle mode: stopping action for '{}'",
-                    shortcut_id
-                );
-                action.stop(app, shortcut_id, shortcut_id);
-                *is_active = false;
-            } else {
-                info!(
-                    "[Wayland] Toggle mode: starting action for '{}'",
-                    shortcut_id
-                );
-                action.start(app, shortcut_id, shortcut_id);
-                *is_active = true;
-            }
-        } else {
-            error!("[Wayland] Failed to lock toggle state");
-        };
+        // Toggle mode: the recording manager decides between start and stop
+        info!(
+            "[Wayland] Toggle mode: toggling action for '{}'",
+            shortcut_id
+        );
+        toggle_binding(app, shortcut_id, shortcut_id);
     }
 }
 
 /// Handle shortcut deactivation (key released).
 fn handle_shortcut_deactivated(app: &AppHandle, shortcut_id: &str) {
-    let Some(action) = ACTION_MAP.get(shortcut_id) else {
+    if !ACTION_MAP.contains_key(shortcut_id) {
         return;
-    };
+    }
 
     let settings = settings::get_settings(app);
 
     if settings.push_to_talk {
         // Push-to-talk mode: stop on release
         info!("[Wayland] PTT mode: stopping action for '{}'", shortcut_id);
-        action.stop(app, shortcut_id, shortcut_id);
+        handle_ptt_released(app, shortcut_id, shortcut_id);
     }
     // Toggle mode: do nothing on release (toggle happens on press)
 }
@@ -0,0 +1,71 @@
+//! Screen reader announcements.
+//!
+//! Recording starting and stopping and the transcription being pasted are
+//! otherwise only shown by the overlay and the sounds, so they're also
+//! announced to the screen reader. On macOS VoiceOver speaks them through an
+//! NSAccessibility announcement request. Elsewhere they're sent to the main
+//! window with an `accessibility-announcement` event and read from an ARIA
+//! live region by Narrator or Orca.
+
+use tauri::AppHandle;
+
+use crate::settings;
+
+/// Announce `message` to the screen reader, if announcements are on.
+pub fn announce(app: &AppHandle, message: &str) {
+    if !settings::get_settings(app).accessibility_announcements {
+        return;
+    }
+    log::debug!("Announcing to the screen reader: {}", message);
+    post(app, message);
+}
+
+#[cfg(target_os = "macos")]
+fn post(app: &AppHandle, message: &str) {
+    let message = message.to_string();
+    let _ = app.run_on_main_thread(move || post_announcement(&message));
+}
+
+#[cfg(not(target_os = "macos"))]
+fn post(app: &AppHandle, message: &str) {
+    use tauri::Emitter;
+
+    let _ = app.emit("accessibility-announcement", message);
+}
+
+#[cfg(target_os = "macos")]
+fn post_announcement(message: &str) {
+    use cocoa::base::{id, nil};
+    use cocoa::foundation::NSString;
+    use objc::{class, msg_send, sel, sel_impl};
+
+    #[link(name = "AppKit", kind = "framework")]
+    extern "C" {
+        static NSAccessibilityAnnouncementRequestedNotification: id;
+        static NSAccessibilityAnnouncementKey: id;
+        static NSAccessibilityPriorityKey: id;
+        fn NSAccessibilityPostNotificationWithUserInfo(element: id, notification: id, info: id);
+    }
+    // NSAccessibilityPriorityHigh, so it interrupts whatever is being read
+    const PRIORITY_HIGH: isize = 90;
+
+    unsafe {
+        let ns_app: id = msg_send![class!(NSApplication), sharedApplication];
+        let text = NSString::alloc(nil).init_str(message);
+        let priority: id = msg_send![class!(NSNumber), numberWithInteger: PRIORITY_HIGH];
+        let keys = [NSAccessibilityAnnouncementKey, NSAccessibilityPriorityKey];
+        let values = [text, priority];
+        let info: id = msg_send![
+            class!(NSDictionary),
+            dictionaryWithObjects: values.as_ptr()
+            forKeys: keys.as_ptr()
+            count: keys.len()
+        ];
+        NSAccessibilityPostNotificationWithUserInfo(
+            ns_app,
+            NSAccessibilityAnnouncementRequestedNotification,
+            info,
+        );
+        let _: () = msg_send![text, release];
+    }
+}
@@ -0,0 +1,58 @@
+//! Per-app custom words.
+//!
+//! Besides the global custom word list, each app can have its own list so
+//! coding terms are corrected in editors and medical terms elsewhere. Lists
+//! are keyed by bundle id (the executable path on Windows, the window class
+//! on Linux) or by app name, compared without regard to case.
+
+use std::collections::HashMap;
+
+/// The word list for the app with `bundle_id` and `name`, empty when it has
+/// none. A bundle id key wins over a name key.
+pub fn for_app<'a>(
+    lists: &'a HashMap<String, Vec<String>>,
+    bundle_id: Option<&str>,
+    name: &str,
+) -> &'a [String] {
+    let find = |wanted: &str| {
+        lists
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(wanted))
+            .map(|(_, words)| words.as_slice())
+    };
+    bundle_id
+        .and_then(find)
+        .or_else(|| find(name))
+        .unwrap_or(&[])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lists() -> HashMap<String, Vec<String>> {
+        HashMap::from([
+            (
+                "com.microsoft.VSCode".to_string(),
+                vec!["Kubernetes".to_string()],
+            ),
+            ("Visual Studio Code".to_string(), vec!["Tauri".to_string()]),
+            ("Notes".to_string(), vec!["metoprolol".to_string()]),
+        ])
+    }
+
+    #[test]
+    fn bundle_id_beats_name_and_case_is_ignored() {
+        let lists = lists();
+        assert_eq!(
+            for_app(&lists, Some("com.microsoft.vscode"), "Visual Studio Code"),
+            ["Kubernetes"]
+        );
+        assert_eq!(
+            for_app(&lists, Some("com.apple.Notes"), "notes"),
+            ["metoprolol"]
+        );
+        assert!(for_app(&lists, None, "Terminal").is_empty());
+        assert!(for_app(&lists, None, "").is_empty());
+    }
+}
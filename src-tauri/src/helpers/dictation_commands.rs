@@ -0,0 +1,250 @@
+//! Spoken dictation commands.
+//!
+//! Phrases like "comma", "new line" or "delete that" in a dictation are
+//! replaced by the punctuation, line breaks or edits they stand for before
+//! the text is pasted. The phrases are set per language in the settings.
+//! Matching ignores case, hyphens and the punctuation the engine put around
+//! a phrase, so "New line." and "new-line" both break the line.
+
+use crate::settings::{DictationCommand, DictationCommandAction};
+
+/// Punctuation the engine may have put before a spoken punctuation mark.
+const TRAILING_PUNCTUATION: &[char] = &[',', '.', ';', ':', '!', '?'];
+const SENTENCE_ENDS: &[char] = &['.', '!', '?'];
+
+/// Apply the spoken `commands` in `text`.
+pub fn apply(text: &str, commands: &[DictationCommand]) -> String {
+    let phrases: Vec<(Vec<String>, &DictationCommandAction)> = commands
+        .iter()
+        .map(|command| (words(&command.phrase), &command.action))
+        .filter(|(phrase, _)| !phrase.is_empty())
+        .collect();
+    let tokens: Vec<&str> = text.split_whitespace().collect();
+    let token_words: Vec<Vec<String>> = tokens.iter().map(|token| words(token)).collect();
+
+    let mut out = String::with_capacity(text.len());
+    // Start the next word with a capital / without a space before it
+    let mut capitalize = false;
+    let mut glue = false;
+    let mut i = 0;
+    while i < tokens.len() {
+        if let Some((count, action)) = longest_match(&token_words[i..], &phrases) {
+            match action {
+                DictationCommandAction::Insert { text } => {
+                    insert(&mut out, text);
+                    glue = text.ends_with('\n');
+                }
+                DictationCommandAction::DeleteWord => {
+                    trim_punctuation(&mut out);
+                    let end = out.rfind(char::is_whitespace).map_or(0, |idx| idx + 1);
+                    out.truncate(end);
+                    trim_spaces(&mut out);
+                    glue = out.ends_with('\n');
+                }
+                DictationCommandAction::DeleteSentence => {
+                    trim_punctuation(&mut out);
+                    let end = out
+                        .rfind(|c: char| c == '\n' || SENTENCE_ENDS.contains(&c))
+                        .map_or(0, |idx| idx + 1);
+                    out.truncate(end);
+                    glue = out.ends_with('\n');
+                }
+            }
+            capitalize = sentence_ended(&out);
+            i += count;
+            continue;
+        }
+
+        if !out.is_empty() && !glue {
+            out.push(' ');
+        }
+        if capitalize {
+            out.push_str(&capitalize_first(tokens[i]));
+        } else {
+            out.push_str(tokens[i]);
+        }
+        capitalize = false;
+        glue = false;
+        i += 1;
+    }
+    trim_spaces(&mut out);
+    out
+}
+
+/// The longest phrase spoken at the start of `tokens`, with the number of
+/// tokens it covers.
+fn longest_match<'a>(
+    tokens: &[Vec<String>],
+    phrases: &[(Vec<String>, &'a DictationCommandAction)],
+) -> Option<(usize, &'a DictationCommandAction)> {
+    // Only a token with words can start a phrase
+    if tokens.first()?.is_empty() {
+        return None;
+    }
+    phrases
+        .iter()
+        .filter_map(|(phrase, action)| {
+            let mut spoken = Vec::new();
+            for (count, token) in tokens.iter().enumerate() {
+                spoken.extend(token.iter().map(String::as_str));
+                if spoken.len() >= phrase.len() {
+                    return (spoken == *phrase).then_some((count + 1, phrase.len(), *action));
+                }
+            }
+            None
+        })
+        .max_by_key(|(_, length, _)| *length)
+        .map(|(count, _, action)| (count, action))
+}
+
+fn insert(out: &mut String, text: &str) {
+    if text.starts_with(|c: char| c.is_alphanumeric()) {
+        if !out.is_empty() && !out.ends_with('\n') {
+            out.push(' ');
+        }
+    } else if text.starts_with(char::is_whitespace) {
+        trim_spaces(out);
+    } else {
+        // "Hello, comma" comes out as "Hello," not "Hello,,"
+        trim_punctuation(out);
+    }
+    out.push_str(text);
+}
+
+/// Drop trailing spaces and the punctuation before them.
+fn trim_punctuation(out: &mut String) {
+    let len = out
+        .trim_end_matches(|c: char| c == ' ' || TRAILING_PUNCTUATION.contains(&c))
+        .len();
+    out.truncate(len);
+}
+
+/// Whether the next word starts a sentence.
+fn sentence_ended(out: &str) -> bool {
+    let out = out.trim_end();
+    out.is_empty() || out.ends_with(SENTENCE_ENDS)
+}
+
+fn trim_spaces(out: &mut String) {
+    let len = out.trim_end_matches(' ').len();
+    out.truncate(len);
+}
+
+fn capitalize_first(token: &str) -> String {
+    match token.char_indices().find(|(_, c)| c.is_alphabetic()) {
+        Some((idx, c)) => {
+            let mut capitalized = token[..idx].to_string();
+            capitalized.extend(c.to_uppercase());
+            capitalized.push_str(&token[idx + c.len_utf8()..]);
+            capitalized
+        }
+        None => token.to_string(),
+    }
+}
+
+/// Lowercased words of `text`, split on spaces and hyphens, without the
+/// punctuation around them.
+fn words(text: &str) -> Vec<String> {
+    text.to_lowercase()
+        .replace('’', "'")
+        .split(|c: char| c.is_whitespace() || c == '-')
+        .map(|word| word.trim_matches(|c: char| !c.is_alphanumeric()))
+        .filter(|word| !word.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn commands(entries: &[(&str, DictationCommandAction)]) -> Vec<DictationCommand> {
+        entries
+            .iter()
+            .map(|(phrase, action)| DictationCommand {
+                phrase: phrase.to_string(),
+                action: action.clone(),
+            })
+            .collect()
+    }
+
+    fn insert_text(text: &str) -> DictationCommandAction {
+        DictationCommandAction::Insert {
+            text: text.to_string(),
+        }
+    }
+
+    fn english() -> Vec<DictationCommand> {
+        commands(&[
+            ("period", insert_text(".")),
+            ("comma", insert_text(",")),
+            ("new line", insert_text("\n")),
+            ("new paragraph", insert_text("\n\n")),
+            ("delete last word", DictationCommandAction::DeleteWord),
+            ("delete that", DictationCommandAction::DeleteSentence),
+        ])
+    }
+
+    #[test]
+    fn punctuation_replaces_what_the_engine_wrote() {
+        assert_eq!(
+            apply("Hello comma how are you period thanks", &english()),
+            "Hello, how are you. Thanks"
+        );
+        assert_eq!(
+            apply("Hello, comma, how are you? Period.", &english()),
+            "Hello, how are you."
+        );
+    }
+
+    #[test]
+    fn line_breaks_drop_the_spaces_around_them() {
+        assert_eq!(
+            apply(
+                "Dear Sam, New line. Thanks for coming. New paragraph. best,",
+                &english()
+            ),
+            "Dear Sam,\nThanks for coming.\n\nBest,"
+        );
+    }
+
+    #[test]
+    fn deletes_the_word_or_sentence_before() {
+        assert_eq!(
+            apply(
+                "Ship it today. No tomorrow, delete that. Thanks.",
+                &english()
+            ),
+            "Ship it today. Thanks."
+        );
+        assert_eq!(
+            apply("Call me maybe delete last word.", &english()),
+            "Call me"
+        );
+        assert_eq!(apply("Delete that.", &english()), "");
+    }
+
+    #[test]
+    fn longest_phrase_wins_and_hyphens_match_spaces() {
+        let spanish = commands(&[
+            ("punto", insert_text(".")),
+            ("punto y coma", insert_text(";")),
+        ]);
+        assert_eq!(apply("uno punto y coma dos", &spanish), "uno; dos");
+
+        let french = commands(&[("point d'interrogation", insert_text("?"))]);
+        assert_eq!(
+            apply("Tu viens point d’interrogation", &french),
+            "Tu viens?"
+        );
+        assert_eq!(apply("the new-line char", &english()), "the\nchar");
+    }
+
+    #[test]
+    fn text_without_commands_is_unchanged() {
+        assert_eq!(
+            apply("A comma-free sentence, really.", &english()),
+            "A comma-free sentence, really."
+        );
+    }
+}
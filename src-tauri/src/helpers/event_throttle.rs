@@ -0,0 +1,288 @@
+//! Rate-limited event emission to the webview.
+//!
+//! High-frequency events (mic levels, streaming transcription text, input entry
+//! updates) can flood the IPC bridge. Events with a configured minimum
+//! interval are coalesced per event name and target window: the first event
+//! goes out immediately, later ones within the interval replace each other, and
+//! the latest payload is flushed once the interval has passed, so listeners
+//! always end up with the most recent value.
+
+use log::warn;
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::{Condvar, Mutex, Once, RwLock};
+use std::thread;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager};
+
+/// Default minimum intervals between emissions of the same event.
+const DEFAULT_RATES: &[(&str, Duration)] = &[
+    ("mic-level", Duration::from_millis(33)),
+    ("transcription-progress", Duration::from_millis(100)),
+    ("file-transcription-progress", Duration::from_millis(100)),
+    ("input-entries-updated", Duration::from_millis(1000)),
+];
+
+static THROTTLER: Lazy<EventThrottler> = Lazy::new(EventThrottler::with_defaults);
+
+/// Emit `event` to all webviews, coalescing it if a rate is configured.
+pub fn emit<S: Serialize>(app_handle: &AppHandle, event: &str, payload: S) {
+    THROTTLER.emit(app_handle, None, event, payload);
+}
+
+/// Emit `event` to one webview window, coalescing it if a rate is configured.
+pub fn emit_to_window<S: Serialize>(app_handle: &AppHandle, label: &str, event: &str, payload: S) {
+    THROTTLER.emit(app_handle, Some(label), event, payload);
+}
+
+/// Set the minimum interval between emissions of `event`. `None` disables
+/// throttling for it.
+#[tauri::command]
+pub fn set_event_rate_limit(event: String, interval_ms: Option<u64>) {
+    let mut rates = THROTTLER.rates.write().unwrap();
+    match interval_ms {
+        Some(ms) => rates.insert(event, Duration::from_millis(ms)),
+        None => rates.remove(&event),
+    };
+}
+
+/// Coalescing state for one (target, event) pair.
+#[derive(Debug)]
+struct Slot<P> {
+    last_emit: Option<Instant>,
+    pending: Option<P>,
+}
+
+impl<P> Default for Slot<P> {
+    fn default() -> Self {
+        Self {
+            last_emit: None,
+            pending: None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Offer<P> {
+    /// Send this payload now.
+    EmitNow(P),
+    /// The payload was stored; flush the slot at this instant.
+    Deferred(Instant),
+}
+
+impl<P> Slot<P> {
+    fn offer(&mut self, payload: P, interval: Duration, now: Instant) -> Offer<P> {
+        match self.last_emit {
+            Some(last) if self.pending.is_some() || now.duration_since(last) < interval => {
+                self.pending = Some(payload);
+                Offer::Deferred(last + interval)
+            }
+            _ => {
+                self.last_emit = Some(now);
+                Offer::EmitNow(payload)
+            }
+        }
+    }
+
+    /// Take the pending payload if its interval has elapsed.
+    fn take_due(&mut self, interval: Duration, now: Instant) -> Option<P> {
+        let due = self.last_emit.map_or(true, |last| now >= last + interval);
+        if due && self.pending.is_some() {
+            self.last_emit = Some(now);
+            self.pending.take()
+        } else {
+            None
+        }
+    }
+
+    /// When the pending payload should be flushed, if there is one.
+    fn due_at(&self, interval: Duration) -> Option<Instant> {
+        self.pending.as_ref()?;
+        Some(
+            self.last_emit
+                .map_or_else(Instant::now, |last| last + interval),
+        )
+    }
+}
+
+type SlotKey = (Option<String>, String);
+
+struct Pending {
+    app_handle: AppHandle,
+    payload: serde_json::Value,
+}
+
+struct EventThrottler {
+    rates: RwLock<HashMap<String, Duration>>,
+    slots: Mutex<HashMap<SlotKey, Slot<Pending>>>,
+    wakeup: Condvar,
+    flusher: Once,
+}
+
+impl EventThrottler {
+    fn with_defaults() -> Self {
+        let rates = DEFAULT_RATES
+            .iter()
+            .map(|(event, interval)| (event.to_string(), *interval))
+            .collect();
+        Self {
+            rates: RwLock::new(rates),
+            slots: Mutex::new(HashMap::new()),
+            wakeup: Condvar::new(),
+            flusher: Once::new(),
+        }
+    }
+
+    fn emit<S: Serialize>(
+        &'static self,
+        app_handle: &AppHandle,
+        target: Option<&str>,
+        event: &str,
+        payload: S,
+    ) {
+        let interval = self.rates.read().unwrap().get(event).copied();
+        let Some(interval) = interval else {
+            send(app_handle, target, event, payload);
+            return;
+        };
+
+        let payload = match serde_json::to_value(payload) {
+            Ok(value) => value,
+            Err(e) => {
+                warn!("Failed to serialize '{}' event payload: {}", event, e);
+                return;
+            }
+        };
+        let pending = Pending {
+            app_handle: app_handle.clone(),
+            payload,
+        };
+
+        let offer = {
+            let mut slots = self.slots.lock().unwrap();
+            let key = (target.map(str::to_string), event.to_string());
+            slots
+                .entry(key)
+                .or_default()
+                .offer(pending, interval, Instant::now())
+        };
+
+        match offer {
+            Offer::EmitNow(pending) => send(&pending.app_handle, target, event, pending.payload),
+            Offer::Deferred(_) => {
+                self.flusher.call_once(|| {
+                    thread::spawn(move || self.run_flusher());
+                });
+                self.wakeup.notify_one();
+            }
+        }
+    }
+
+    /// Background loop that sends coalesced payloads once they are due.
+    fn run_flusher(&self) {
+        let mut slots = self.slots.lock().unwrap();
+        loop {
+            let now = Instant::now();
+            let rates = self.rates.read().unwrap().clone();
+            let mut due = Vec::new();
+            let mut next_wake: Option<Instant> = None;
+
+            for ((target, event), slot) in slots.iter_mut() {
+                let interval = rates.get(event).copied().unwrap_or_default();
+                if let Some(pending) = slot.take_due(interval, now) {
+                    due.push((target.clone(), event.clone(), pending));
+                } else if let Some(at) = slot.due_at(interval) {
+                    next_wake = Some(next_wake.map_or(at, |w| w.min(at)));
+                }
+            }
+
+            if !due.is_empty() {
+                drop(slots);
+                for (target, event, pending) in due {
+                    send(
+                        &pending.app_handle,
+                        target.as_deref(),
+                        &event,
+                        pending.payload,
+                    );
+                }
+                slots = self.slots.lock().unwrap();
+                continue;
+            }
+
+            slots = match next_wake {
+                Some(at) => {
+                    let timeout = at.saturating_duration_since(Instant::now());
+                    self.wakeup.wait_timeout(slots, timeout).unwrap().0
+                }
+                None => self.wakeup.wait(slots).unwrap(),
+            };
+        }
+    }
+}
+
+fn send<S: Serialize>(app_handle: &AppHandle, target: Option<&str>, event: &str, payload: S) {
+    match target {
+        None => {
+            let _ = app_handle.emit(event, payload);
+        }
+        Some(label) => {
+            if let Some(window) = app_handle.get_webview_window(label) {
+                let _ = window.emit(event, payload);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INTERVAL: Duration = Duration::from_millis(100);
+
+    #[test]
+    fn first_event_is_sent_immediately() {
+        let mut slot = Slot::default();
+        assert_eq!(slot.offer(1, INTERVAL, Instant::now()), Offer::EmitNow(1));
+    }
+
+    #[test]
+    fn burst_is_coalesced_to_latest_payload() {
+        let start = Instant::now();
+        let mut slot = Slot::default();
+        slot.offer(1, INTERVAL, start);
+
+        assert_eq!(
+            slot.offer(2, INTERVAL, start + Duration::from_millis(10)),
+            Offer::Deferred(start + INTERVAL)
+        );
+        slot.offer(3, INTERVAL, start + Duration::from_millis(20));
+
+        assert_eq!(
+            slot.take_due(INTERVAL, start + Duration::from_millis(50)),
+            None
+        );
+        assert_eq!(slot.take_due(INTERVAL, start + INTERVAL), Some(3));
+        assert_eq!(slot.due_at(INTERVAL), None);
+    }
+
+    #[test]
+    fn pending_payload_keeps_order_after_interval() {
+        let start = Instant::now();
+        let mut slot = Slot::default();
+        slot.offer(1, INTERVAL, start);
+        slot.offer(2, INTERVAL, start + Duration::from_millis(10));
+
+        // A newer event arriving after the interval must not overtake the
+        // pending one; it replaces it and waits for the flusher.
+        assert!(matches!(
+            slot.offer(3, INTERVAL, start + Duration::from_millis(150)),
+            Offer::Deferred(_)
+        ));
+        assert_eq!(
+            slot.take_due(INTERVAL, start + Duration::from_millis(150)),
+            Some(3)
+        );
+    }
+}
@@ -0,0 +1,183 @@
+//! Detection of OS do-not-disturb / focus modes.
+//!
+//! macOS Focus is read from the DoNotDisturb assertion store, Windows Focus
+//! Assist from the shell's user notification state, and GNOME's do-not-disturb
+//! from its notification banner setting. When `respect_focus_mode` is enabled,
+//! the overlay and feedback sounds stay silent while a focus mode is active.
+
+use log::debug;
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::AppHandle;
+
+use crate::settings::{get_settings, AppSettings};
+
+/// Detection shells out or reads files, so results are reused for a short while.
+const CACHE_TTL: Duration = Duration::from_secs(5);
+
+static CACHED_STATE: Lazy<Mutex<Option<(Instant, Option<bool>)>>> = Lazy::new(|| Mutex::new(None));
+
+/// Focus mode state reported to the UI.
+#[derive(Debug, Clone, Serialize)]
+pub struct FocusModeState {
+    /// Whether focus mode detection is available on this platform.
+    pub supported: bool,
+    /// Whether a focus / do-not-disturb mode is currently active.
+    pub active: bool,
+    /// Whether overlay, sounds and notifications should currently be suppressed.
+    pub suppress_feedback: bool,
+}
+
+/// Returns `Some(active)` if the platform state could be read, `None` otherwise.
+fn detect_focus_mode() -> Option<bool> {
+    #[cfg(target_os = "macos")]
+    {
+        macos_focus_active()
+    }
+    #[cfg(target_os = "windows")]
+    {
+        windows_focus_assist_active()
+    }
+    #[cfg(target_os = "linux")]
+    {
+        gnome_dnd_active()
+    }
+    #[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
+    {
+        None
+    }
+}
+
+fn cached_focus_mode() -> Option<bool> {
+    let mut cache = CACHED_STATE.lock().unwrap();
+    if let Some((checked_at, state)) = *cache {
+        if checked_at.elapsed() < CACHE_TTL {
+            return state;
+        }
+    }
+
+    let state = detect_focus_mode();
+    debug!("Focus mode detected: {:?}", state);
+    *cache = Some((Instant::now(), state));
+    state
+}
+
+/// Whether a focus mode is active. Undetectable states count as inactive.
+pub fn is_focus_mode_active() -> bool {
+    cached_focus_mode().unwrap_or(false)
+}
+
+/// Whether overlay and sounds should be suppressed under the given settings.
+pub fn should_suppress_feedback(settings: &AppSettings) -> bool {
+    settings.respect_focus_mode && is_focus_mode_active()
+}
+
+/// Get the current OS focus / do-not-disturb state.
+#[tauri::command]
+pub fn get_focus_mode_state(app: AppHandle) -> FocusModeState {
+    let detected = cached_focus_mode();
+    let active = detected.unwrap_or(false);
+    FocusModeState {
+        supported: detected.is_some(),
+        active,
+        suppress_feedback: active && get_settings(&app).respect_focus_mode,
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn macos_focus_active() -> Option<bool> {
+    let home = std::env::var("HOME").ok()?;
+    let path = std::path::Path::new(&home).join("Library/DoNotDisturb/DB/Assertions.json");
+    let contents = std::fs::read_to_string(path).ok()?;
+    parse_macos_assertions(&contents)
+}
+
+/// A Focus is active when the assertion store holds at least one record.
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+fn parse_macos_assertions(contents: &str) -> Option<bool> {
+    let json: serde_json::Value = serde_json::from_str(contents).ok()?;
+    let entries = json.get("data")?.as_array()?;
+    Some(entries.iter().any(|entry| {
+        entry
+            .get("storeAssertionRecords")
+            .and_then(|records| records.as_array())
+            .is_some_and(|records| !records.is_empty())
+    }))
+}
+
+#[cfg(target_os = "windows")]
+fn windows_focus_assist_active() -> Option<bool> {
+    #[link(name = "shell32")]
+    extern "system" {
+        fn SHQueryUserNotificationState(state: *mut i32) -> i32;
+    }
+
+    let mut state = 0;
+    let hr = unsafe { SHQueryUserNotificationState(&mut state) };
+    if hr < 0 {
+        return None;
+    }
+    Some(is_quiet_notification_state(state))
+}
+
+/// QUNS_BUSY (2), QUNS_RUNNING_D3D_FULL_SCREEN (3), QUNS_PRESENTATION_MODE (4)
+/// and QUNS_QUIET_TIME (6) all mean notifications are being held back.
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+fn is_quiet_notification_state(state: i32) -> bool {
+    matches!(state, 2 | 3 | 4 | 6)
+}
+
+#[cfg(target_os = "linux")]
+fn gnome_dnd_active() -> Option<bool> {
+    let output = std::process::Command::new("gsettings")
+        .args(["get", "org.gnome.desktop.notifications", "show-banners"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    parse_gnome_show_banners(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// GNOME's do-not-disturb toggle sets `show-banners` to false.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_gnome_show_banners(output: &str) -> Option<bool> {
+    match output.trim() {
+        "false" => Some(true),
+        "true" => Some(false),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn macos_assertions_with_records_is_active() {
+        let json = r#"{"data":[{"storeAssertionRecords":[{"assertionDetails":{}}]}]}"#;
+        assert_eq!(parse_macos_assertions(json), Some(true));
+    }
+
+    #[test]
+    fn macos_assertions_without_records_is_inactive() {
+        assert_eq!(parse_macos_assertions(r#"{"data":[{}]}"#), Some(false));
+        assert_eq!(parse_macos_assertions("not json"), None);
+    }
+
+    #[test]
+    fn windows_quiet_states() {
+        assert!(is_quiet_notification_state(6));
+        assert!(is_quiet_notification_state(4));
+        assert!(!is_quiet_notification_state(5));
+    }
+
+    #[test]
+    fn gnome_show_banners_output() {
+        assert_eq!(parse_gnome_show_banners("false\n"), Some(true));
+        assert_eq!(parse_gnome_show_banners("true\n"), Some(false));
+        assert_eq!(parse_gnome_show_banners(""), None);
+    }
+}
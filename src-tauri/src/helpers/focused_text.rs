@@ -0,0 +1,284 @@
+//! Read the text of the currently focused input field.
+//!
+//! Used to bias transcription towards the style and terminology already present
+//! in the document, and to check that a paste has somewhere to go. Only macOS
+//! is supported (via the Accessibility API, and only when the app is trusted);
+//! other platforms return `None`.
+
+/// Maximum number of characters of existing text used as context.
+const MAX_CONTEXT_CHARS: usize = 400;
+
+/// Returns the trailing text of the focused field, if it can be read.
+pub fn read_focused_text() -> Option<String> {
+    #[cfg(target_os = "macos")]
+    {
+        macos::read_focused_value()
+    }
+    #[cfg(not(target_os = "macos"))]
+    {
+        log::debug!("Reading focused field text is not supported on this platform");
+        None
+    }
+}
+
+/// Whether the focused element takes typed text. `None` when that can't be
+/// told, like when nothing reports focus (Electron apps often don't) or the
+/// platform has no accessibility API to ask.
+pub fn focused_element_takes_text() -> Option<bool> {
+    #[cfg(target_os = "macos")]
+    {
+        macos::focused_element_takes_text()
+    }
+    #[cfg(not(target_os = "macos"))]
+    {
+        None
+    }
+}
+
+/// Read the focused field and turn it into a whisper initial prompt.
+pub fn read_context_prompt() -> Option<String> {
+    read_focused_text().and_then(|text| build_context_prompt(&text, MAX_CONTEXT_CHARS))
+}
+
+/// Keep the last `max_chars` characters of `text`, starting on a word boundary
+/// so the prompt doesn't begin mid-word.
+fn build_context_prompt(text: &str, max_chars: usize) -> Option<String> {
+    let trimmed = text.trim();
+    if trimmed.is_empty() {
+        return None;
+    }
+
+    let char_count = trimmed.chars().count();
+    if char_count <= max_chars {
+        return Some(trimmed.to_string());
+    }
+
+    let tail: String = trimmed.chars().skip(char_count - max_chars).collect();
+    let tail = match tail.find(char::is_whitespace) {
+        Some(idx) => tail[idx..].trim_start().to_string(),
+        None => tail,
+    };
+
+    if tail.is_empty() {
+        None
+    } else {
+        Some(tail)
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod macos {
+    use std::ffi::c_void;
+    use std::ptr;
+
+    type CFTypeRef = *const c_void;
+    type CFStringRef = *const c_void;
+
+    #[allow(non_upper_case_globals)]
+    const kAXErrorSuccess: i32 = 0;
+    #[allow(non_upper_case_globals)]
+    const kCFStringEncodingUTF8: u32 = 0x08000100;
+
+    #[link(name = "ApplicationServices", kind = "framework")]
+    extern "C" {
+        fn AXIsProcessTrusted() -> bool;
+        fn AXUIElementCreateSystemWide() -> CFTypeRef;
+        fn AXUIElementCopyAttributeValue(
+            element: CFTypeRef,
+            attribute: CFStringRef,
+            value: *mut CFTypeRef,
+        ) -> i32;
+        fn AXUIElementIsAttributeSettable(
+            element: CFTypeRef,
+            attribute: CFStringRef,
+            settable: *mut bool,
+        ) -> i32;
+    }
+
+    /// Roles of elements that never take typed text. Anything else, like web
+    /// areas and groups that may be editable, gets the benefit of the doubt.
+    const NON_TEXT_ROLES: &[&str] = &[
+        "AXApplication",
+        "AXButton",
+        "AXCell",
+        "AXCheckBox",
+        "AXImage",
+        "AXList",
+        "AXMenuButton",
+        "AXMenuItem",
+        "AXOutline",
+        "AXPopUpButton",
+        "AXRadioButton",
+        "AXRow",
+        "AXScrollArea",
+        "AXSlider",
+        "AXTabGroup",
+        "AXTable",
+        "AXToolbar",
+        "AXWindow",
+    ];
+
+    #[link(name = "CoreFoundation", kind = "framework")]
+    extern "C" {
+        fn CFRelease(cf: CFTypeRef);
+        fn CFGetTypeID(cf: CFTypeRef) -> usize;
+        fn CFStringGetTypeID() -> usize;
+        fn CFStringGetLength(string: CFStringRef) -> isize;
+        fn CFStringGetMaximumSizeForEncoding(length: isize, encoding: u32) -> isize;
+        fn CFStringGetCString(
+            string: CFStringRef,
+            buffer: *mut i8,
+            buffer_size: isize,
+            encoding: u32,
+        ) -> bool;
+        fn CFStringCreateWithCString(
+            alloc: CFTypeRef,
+            c_str: *const i8,
+            encoding: u32,
+        ) -> CFStringRef;
+    }
+
+    /// Copy an attribute of `element`. The caller owns the returned reference.
+    unsafe fn copy_attribute(element: CFTypeRef, name: &[u8]) -> Option<CFTypeRef> {
+        let attr = CFStringCreateWithCString(
+            ptr::null(),
+            name.as_ptr() as *const i8,
+            kCFStringEncodingUTF8,
+        );
+        if attr.is_null() {
+            return None;
+        }
+
+        let mut value: CFTypeRef = ptr::null();
+        let result = AXUIElementCopyAttributeValue(element, attr, &mut value);
+        CFRelease(attr);
+
+        if result != kAXErrorSuccess || value.is_null() {
+            None
+        } else {
+            Some(value)
+        }
+    }
+
+    unsafe fn is_settable(element: CFTypeRef, name: &[u8]) -> bool {
+        let attr = CFStringCreateWithCString(
+            ptr::null(),
+            name.as_ptr() as *const i8,
+            kCFStringEncodingUTF8,
+        );
+        if attr.is_null() {
+            return false;
+        }
+        let mut settable = false;
+        let result = AXUIElementIsAttributeSettable(element, attr, &mut settable);
+        CFRelease(attr);
+        result == kAXErrorSuccess && settable
+    }
+
+    unsafe fn cf_string_to_string(string: CFStringRef) -> Option<String> {
+        let length = CFStringGetLength(string);
+        let capacity = CFStringGetMaximumSizeForEncoding(length, kCFStringEncodingUTF8) + 1;
+        let mut buffer = vec![0i8; capacity as usize];
+        if !CFStringGetCString(string, buffer.as_mut_ptr(), capacity, kCFStringEncodingUTF8) {
+            return None;
+        }
+        Some(
+            std::ffi::CStr::from_ptr(buffer.as_ptr())
+                .to_string_lossy()
+                .into_owned(),
+        )
+    }
+
+    pub fn read_focused_value() -> Option<String> {
+        unsafe {
+            if !AXIsProcessTrusted() {
+                log::debug!("Accessibility permission not granted, skipping focused text read");
+                return None;
+            }
+
+            let system_wide = AXUIElementCreateSystemWide();
+            if system_wide.is_null() {
+                return None;
+            }
+
+            let focused = copy_attribute(system_wide, b"AXFocusedUIElement\0");
+            CFRelease(system_wide);
+            let focused = focused?;
+
+            let value = copy_attribute(focused, b"AXValue\0");
+            CFRelease(focused);
+            let value = value?;
+
+            // Only text fields expose a string value; sliders etc. return numbers
+            let text = if CFGetTypeID(value) == CFStringGetTypeID() {
+                cf_string_to_string(value)
+            } else {
+                None
+            };
+            CFRelease(value);
+            text
+        }
+    }
+
+    pub fn focused_element_takes_text() -> Option<bool> {
+        unsafe {
+            if !AXIsProcessTrusted() {
+                return None;
+            }
+
+            let system_wide = AXUIElementCreateSystemWide();
+            if system_wide.is_null() {
+                return None;
+            }
+            let focused = copy_attribute(system_wide, b"AXFocusedUIElement\0");
+            CFRelease(system_wide);
+            let focused = focused?;
+
+            let role = copy_attribute(focused, b"AXRole\0").and_then(|role| {
+                let name = if CFGetTypeID(role) == CFStringGetTypeID() {
+                    cf_string_to_string(role)
+                } else {
+                    None
+                };
+                CFRelease(role);
+                name
+            });
+            let editable = is_settable(focused, b"AXValue\0");
+            CFRelease(focused);
+
+            let role = role?;
+            log::debug!("Focused element: role={}, editable={}", role, editable);
+            Some(editable || !NON_TEXT_ROLES.contains(&role.as_str()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_text_gives_no_prompt() {
+        assert_eq!(build_context_prompt("   \n", 100), None);
+    }
+
+    #[test]
+    fn short_text_is_kept_whole() {
+        assert_eq!(
+            build_context_prompt("  Dear team,  ", 100).as_deref(),
+            Some("Dear team,")
+        );
+    }
+
+    #[test]
+    fn long_text_keeps_tail_from_word_boundary() {
+        let prompt = build_context_prompt("alpha beta gamma delta", 12).unwrap();
+        assert_eq!(prompt, "gamma delta");
+    }
+
+    #[test]
+    fn tail_without_whitespace_is_kept() {
+        let prompt = build_context_prompt("abcdefghij", 4).unwrap();
+        assert_eq!(prompt, "ghij");
+    }
+}
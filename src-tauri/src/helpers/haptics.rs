@@ -0,0 +1,32 @@
+//! Trackpad haptic feedback.
+//!
+//! On macOS, Force Touch trackpads tap back through `NSHapticFeedbackManager`.
+//! The tap is only felt while a finger rests on the trackpad. Other platforms
+//! have no equivalent, so this is a no-op there.
+
+/// Play a short haptic tap.
+#[cfg(target_os = "macos")]
+pub fn tap() {
+    use cocoa::base::{id, nil};
+    use objc::{class, msg_send, sel, sel_impl};
+
+    // NSHapticFeedbackPatternGeneric, NSHapticFeedbackPerformanceTimeNow
+    const PATTERN_GENERIC: isize = 0;
+    const PERFORMANCE_TIME_NOW: usize = 1;
+
+    unsafe {
+        let performer: id = msg_send![class!(NSHapticFeedbackManager), defaultPerformer];
+        if performer != nil {
+            let _: () = msg_send![
+                performer,
+                performFeedbackPattern: PATTERN_GENERIC
+                performanceTime: PERFORMANCE_TIME_NOW
+            ];
+        }
+    }
+}
+
+#[cfg(not(target_os = "macos"))]
+pub fn tap() {
+    log::debug!("Haptic feedback is not supported on this platform");
+}
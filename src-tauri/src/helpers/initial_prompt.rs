@@ -0,0 +1,100 @@
+//! Whisper initial prompt for a recording.
+//!
+//! Whisper treats the initial prompt as text that came right before the
+//! audio, so names and jargon in it are recognized more reliably and the
+//! style of the text is carried on. The prompt is put together from the
+//! vocabulary in the settings, the app being dictated into and the text
+//! already in the focused field, with that text last since Whisper weighs
+//! the end of the prompt most.
+
+use crate::settings::AppSettings;
+
+/// Longest vocabulary part of the prompt. Whisper keeps only the last ~220
+/// tokens of a prompt, and the focused-field text needs room after it.
+const MAX_VOCABULARY_CHARS: usize = 400;
+
+/// The part of the prompt that comes from the settings, if any.
+pub fn vocabulary(settings: &AppSettings) -> Option<String> {
+    let mut parts = Vec::new();
+    let text = settings.initial_prompt.trim();
+    if !text.is_empty() {
+        parts.push(text.to_string());
+    }
+    if settings.initial_prompt_custom_words && !settings.custom_words.is_empty() {
+        parts.push(format!("{}.", settings.custom_words.join(", ")));
+    }
+    if parts.is_empty() {
+        return None;
+    }
+    Some(truncate_on_word(&parts.join(" "), MAX_VOCABULARY_CHARS))
+}
+
+/// Put the prompt together from its parts; `None` if they are all missing.
+pub fn build(
+    vocabulary: Option<&str>,
+    app_name: Option<&str>,
+    context: Option<&str>,
+) -> Option<String> {
+    let app = app_name
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(|name| format!("Writing in {}.", name));
+    let parts: Vec<&str> = [vocabulary, app.as_deref(), context]
+        .into_iter()
+        .flatten()
+        .filter(|part| !part.trim().is_empty())
+        .collect();
+    (!parts.is_empty()).then(|| parts.join(" "))
+}
+
+/// The first `max_chars` characters of `text`, ending on a word boundary.
+fn truncate_on_word(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+    let head: String = text.chars().take(max_chars).collect();
+    match head.rfind(char::is_whitespace) {
+        Some(idx) => head[..idx].trim_end().to_string(),
+        None => head,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::get_default_settings;
+
+    #[test]
+    fn vocabulary_combines_text_and_custom_words() {
+        let mut settings = get_default_settings();
+        assert_eq!(vocabulary(&settings), None);
+
+        settings.initial_prompt = "  Meeting notes for Acme.  ".to_string();
+        settings.custom_words = vec!["Kubernetes".to_string(), "Tauri".to_string()];
+        assert_eq!(
+            vocabulary(&settings).as_deref(),
+            Some("Meeting notes for Acme.")
+        );
+
+        settings.initial_prompt_custom_words = true;
+        assert_eq!(
+            vocabulary(&settings).as_deref(),
+            Some("Meeting notes for Acme. Kubernetes, Tauri.")
+        );
+    }
+
+    #[test]
+    fn context_comes_last() {
+        assert_eq!(
+            build(Some("Acme, Tauri."), Some("Slack"), Some("Hi team,")).as_deref(),
+            Some("Acme, Tauri. Writing in Slack. Hi team,")
+        );
+        assert_eq!(build(None, Some(" "), None), None);
+    }
+
+    #[test]
+    fn long_vocabulary_is_cut_between_words() {
+        assert_eq!(truncate_on_word("alpha beta gamma", 12), "alpha beta");
+        assert_eq!(truncate_on_word("alpha", 12), "alpha");
+    }
+}
@@ -0,0 +1,126 @@
+//! Detection of a hardware- or OS-muted microphone.
+//!
+//! A muted input still delivers a stream, just an empty one, so without a
+//! check the user only finds out from an empty transcription. Two signals are
+//! used: the mute state the OS reports for the default input, and a signal
+//! that is exactly silent, which is what most headset mute buttons produce.
+
+/// Peak amplitude at or below which the input is digital silence. Even a
+/// quiet room puts a live microphone's noise floor well above this.
+pub const DIGITAL_SILENCE_PEAK: f32 = 1e-5;
+
+pub fn is_digital_silence(peak: f32) -> bool {
+    peak <= DIGITAL_SILENCE_PEAK
+}
+
+/// Whether the OS reports the default input device as muted (or at zero
+/// volume). `None` when the state can't be read.
+#[cfg(target_os = "macos")]
+pub fn is_default_input_muted() -> Option<bool> {
+    let output = std::process::Command::new("osascript")
+        .args(["-e", "input volume of (get volume settings)"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    // "missing value" when the device has no volume control
+    let volume: u32 = String::from_utf8_lossy(&output.stdout)
+        .trim()
+        .parse()
+        .ok()?;
+    Some(volume == 0)
+}
+
+#[cfg(target_os = "windows")]
+pub fn is_default_input_muted() -> Option<bool> {
+    use windows::Win32::{
+        Media::Audio::{
+            eCapture, eConsole, Endpoints::IAudioEndpointVolume, IMMDeviceEnumerator,
+            MMDeviceEnumerator,
+        },
+        System::Com::{CoCreateInstance, CoInitializeEx, CLSCTX_ALL, COINIT_MULTITHREADED},
+    };
+
+    unsafe {
+        let _ = CoInitializeEx(None, COINIT_MULTITHREADED);
+        let enumerator: IMMDeviceEnumerator =
+            CoCreateInstance(&MMDeviceEnumerator, None, CLSCTX_ALL).ok()?;
+        let device = enumerator
+            .GetDefaultAudioEndpoint(eCapture, eConsole)
+            .ok()?;
+        let volume = device
+            .Activate::<IAudioEndpointVolume>(CLSCTX_ALL, None)
+            .ok()?;
+        let muted = volume.GetMute().ok()?.as_bool();
+        let level = volume.GetMasterVolumeLevelScalar().ok()?;
+        Some(muted || level <= 0.0)
+    }
+}
+
+#[cfg(target_os = "linux")]
+pub fn is_default_input_muted() -> Option<bool> {
+    use std::process::Command;
+
+    let run = |program: &str, args: &[&str]| {
+        Command::new(program)
+            .args(args)
+            .output()
+            .ok()
+            .filter(|o| o.status.success())
+            .map(|o| String::from_utf8_lossy(&o.stdout).into_owned())
+    };
+
+    if let Some(out) = run("wpctl", &["get-volume", "@DEFAULT_AUDIO_SOURCE@"]) {
+        return parse_wpctl_muted(&out);
+    }
+    run("pactl", &["get-source-mute", "@DEFAULT_SOURCE@"]).and_then(|out| parse_pactl_muted(&out))
+}
+
+/// Parse `wpctl get-volume` output, e.g. "Volume: 0.40 [MUTED]".
+#[cfg(any(target_os = "linux", test))]
+fn parse_wpctl_muted(output: &str) -> Option<bool> {
+    let rest = output.trim().strip_prefix("Volume:")?;
+    if rest.contains("[MUTED]") {
+        return Some(true);
+    }
+    let volume: f32 = rest.split_whitespace().next()?.parse().ok()?;
+    Some(volume <= 0.0)
+}
+
+/// Parse `pactl get-source-mute` output, e.g. "Mute: yes".
+#[cfg(any(target_os = "linux", test))]
+fn parse_pactl_muted(output: &str) -> Option<bool> {
+    match output.trim().strip_prefix("Mute:")?.trim() {
+        "yes" => Some(true),
+        "no" => Some(false),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_wpctl_volume() {
+        assert_eq!(parse_wpctl_muted("Volume: 0.40 [MUTED]\n"), Some(true));
+        assert_eq!(parse_wpctl_muted("Volume: 0.00\n"), Some(true));
+        assert_eq!(parse_wpctl_muted("Volume: 1.00\n"), Some(false));
+        assert_eq!(parse_wpctl_muted("Object not found\n"), None);
+    }
+
+    #[test]
+    fn parses_pactl_mute() {
+        assert_eq!(parse_pactl_muted("Mute: yes\n"), Some(true));
+        assert_eq!(parse_pactl_muted("Mute: no\n"), Some(false));
+        assert_eq!(parse_pactl_muted(""), None);
+    }
+
+    #[test]
+    fn noise_floor_is_not_silence() {
+        assert!(is_digital_silence(0.0));
+        // Roughly -70 dBFS, a very quiet but live microphone
+        assert!(!is_digital_silence(3e-4));
+    }
+}
@@ -0,0 +1,93 @@
+//! Spoken-language detection for transcripts made with the language on "auto".
+//!
+//! The engines don't report the language they detected, so it is guessed
+//! from the text with the same classifier TTS uses to pick a voice.
+
+use whichlang::{detect_language, Lang};
+
+/// Shortest text, in letters, worth guessing a language for. "Okay" could be
+/// anything.
+const MIN_LETTERS: usize = 6;
+
+/// Guess the language of `text` as an ISO 639-1 code.
+pub fn detect(text: &str) -> Option<&'static str> {
+    if text.chars().filter(|c| c.is_alphabetic()).count() < MIN_LETTERS {
+        return None;
+    }
+    Some(lang_code(detect_language(text)))
+}
+
+/// ISO 639-1 code of a detected language.
+pub fn lang_code(lang: Lang) -> &'static str {
+    match lang {
+        Lang::Ara => "ar",
+        Lang::Cmn => "zh",
+        Lang::Deu => "de",
+        Lang::Eng => "en",
+        Lang::Fra => "fr",
+        Lang::Hin => "hi",
+        Lang::Ita => "it",
+        Lang::Jpn => "ja",
+        Lang::Kor => "ko",
+        Lang::Nld => "nl",
+        Lang::Por => "pt",
+        Lang::Rus => "ru",
+        Lang::Spa => "es",
+        Lang::Swe => "sv",
+        Lang::Tur => "tr",
+        Lang::Vie => "vi",
+    }
+}
+
+/// English name of a language code, as shown to the user and in prompts.
+pub fn language_name(code: &str) -> Option<&'static str> {
+    Some(match code {
+        "en" => "English",
+        "fr" => "French",
+        "de" => "German",
+        "es" => "Spanish",
+        "it" => "Italian",
+        "pt" => "Portuguese",
+        "nl" => "Dutch",
+        "pl" => "Polish",
+        "sv" => "Swedish",
+        "tr" => "Turkish",
+        "ru" => "Russian",
+        "uk" => "Ukrainian",
+        "zh" => "Chinese",
+        "ja" => "Japanese",
+        "ko" => "Korean",
+        "vi" => "Vietnamese",
+        "ar" => "Arabic",
+        "he" => "Hebrew",
+        "hi" => "Hindi",
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_the_language_of_a_sentence() {
+        assert_eq!(
+            detect("I think the meeting is on Tuesday and we have to prepare the slides"),
+            Some("en")
+        );
+        assert_eq!(
+            detect("Je pense que la réunion est mardi et nous devons préparer les diapositives"),
+            Some("fr")
+        );
+        assert_eq!(
+            detect("Ich glaube, das Treffen ist am Dienstag und wir müssen die Folien vorbereiten"),
+            Some("de")
+        );
+    }
+
+    #[test]
+    fn short_text_is_not_guessed() {
+        assert_eq!(detect("Okay."), None);
+        assert_eq!(detect(""), None);
+    }
+}
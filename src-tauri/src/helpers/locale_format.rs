@@ -0,0 +1,242 @@
+//! Typographic rules of the language a transcript is in.
+//!
+//! The engines punctuate every language the English way. These rules fix up
+//! the conventions that differ, for the languages the user turned them on
+//! for in the settings:
+//!
+//! - French: no-break space before `? ! ; :` and inside `« »`.
+//! - German: capitalization of words whose ending marks them as nouns
+//!   ("die bedeutung" → "die Bedeutung"), which models often miss.
+//! - Chinese and Japanese: full-width punctuation after CJK text, no spaces
+//!   between CJK characters, and half-width letters and digits.
+
+use serde::Serialize;
+
+/// A language with rules, as offered in the settings.
+#[derive(Serialize, Debug)]
+pub struct LanguageRules {
+    /// ISO 639-1 code.
+    pub code: &'static str,
+    pub name: &'static str,
+    /// What the rules do.
+    pub description: &'static str,
+}
+
+pub const SUPPORTED: &[LanguageRules] = &[
+    LanguageRules {
+        code: "fr",
+        name: "French",
+        description: "No-break spaces before ? ! ; : and inside « »",
+    },
+    LanguageRules {
+        code: "de",
+        name: "German",
+        description: "Capitalize words with noun endings (-ung, -keit)",
+    },
+    LanguageRules {
+        code: "zh",
+        name: "Chinese",
+        description: "Full-width punctuation, no spaces between characters",
+    },
+    LanguageRules {
+        code: "ja",
+        name: "Japanese",
+        description: "Full-width punctuation, no spaces between characters",
+    },
+];
+
+const NBSP: char = '\u{00A0}';
+/// Narrow no-break space, used before `? ! ;` in French.
+const NNBSP: char = '\u{202F}';
+
+/// Endings that make a German word a noun.
+const GERMAN_NOUN_SUFFIXES: &[&str] = &[
+    "ung", "heit", "keit", "schaft", "tion", "ität", "tum", "ismus", "nis", "ment",
+];
+
+pub fn is_supported(language: &str) -> bool {
+    SUPPORTED.iter().any(|rules| rules.code == language)
+}
+
+/// Apply the rules of `language` to `text`. Text in languages without rules
+/// is returned as it is.
+pub fn apply(text: &str, language: &str) -> String {
+    match language {
+        "fr" => french(text),
+        "de" => german(text),
+        "zh" | "ja" => cjk(text),
+        _ => text.to_string(),
+    }
+}
+
+fn french(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::with_capacity(text.len() + 8);
+    for (i, &c) in chars.iter().enumerate() {
+        let next = chars.get(i + 1).copied();
+        let space = match c {
+            '?' | '!' | ';' => Some(NNBSP),
+            // Leave times (10:30) and URLs (https://) alone
+            ':' if !next.is_some_and(|n| n.is_ascii_digit() || n == '/') => Some(NBSP),
+            '»' => Some(NBSP),
+            _ => None,
+        };
+        if let Some(space) = space {
+            // Replace a plain space before the mark, or add one after a word
+            if out.ends_with(' ') {
+                out.pop();
+                out.push(space);
+            } else if out.chars().last().is_some_and(|p| p.is_alphanumeric()) {
+                out.push(space);
+            }
+        }
+        out.push(c);
+        if c == '«' && next.is_some_and(|n| n == ' ' || n.is_alphanumeric()) {
+            out.push(NBSP);
+        }
+    }
+    // A space after « was replaced above
+    out.replace("«\u{00A0} ", "«\u{00A0}")
+}
+
+fn german(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut word = String::new();
+    for c in text.chars() {
+        if c.is_alphabetic() {
+            word.push(c);
+        } else {
+            out.push_str(&capitalize_german_noun(&word));
+            word.clear();
+            out.push(c);
+        }
+    }
+    out.push_str(&capitalize_german_noun(&word));
+    out
+}
+
+fn capitalize_german_noun(word: &str) -> String {
+    // "jung" ends in -ung without being a noun; the stem must be longer
+    let is_noun = GERMAN_NOUN_SUFFIXES
+        .iter()
+        .any(|suffix| word.ends_with(suffix) && word.chars().count() > suffix.chars().count() + 2);
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) if is_noun && first.is_lowercase() => {
+            first.to_uppercase().chain(chars).collect()
+        }
+        _ => word.to_string(),
+    }
+}
+
+fn is_cjk(c: char) -> bool {
+    matches!(c,
+        '\u{3040}'..='\u{30FF}'   // Hiragana, Katakana
+        | '\u{3400}'..='\u{4DBF}' // CJK Extension A
+        | '\u{4E00}'..='\u{9FFF}' // CJK Unified Ideographs
+        | '\u{F900}'..='\u{FAFF}' // CJK Compatibility Ideographs
+        | '\u{3000}'..='\u{303F}' // CJK punctuation
+        | '\u{FF01}'..='\u{FF0F}' // Full-width punctuation
+        | '\u{FF1A}'..='\u{FF20}')
+}
+
+fn full_width_punctuation(c: char) -> Option<char> {
+    Some(match c {
+        ',' => '，',
+        '.' => '。',
+        '?' => '？',
+        '!' => '！',
+        ':' => '：',
+        ';' => '；',
+        _ => return None,
+    })
+}
+
+fn cjk(text: &str) -> String {
+    // Full-width letters and digits to half-width
+    let chars: Vec<char> = text
+        .chars()
+        .map(|c| match c {
+            '０'..='９' | 'Ａ'..='Ｚ' | 'ａ'..='ｚ' => {
+                char::from_u32(c as u32 - 0xFEE0).unwrap_or(c)
+            }
+            _ => c,
+        })
+        .collect();
+
+    let mut out = String::with_capacity(text.len());
+    for (i, &c) in chars.iter().enumerate() {
+        let prev = out.chars().last();
+        let after_cjk = prev.is_some_and(is_cjk);
+        if c == ' ' && after_cjk && chars.get(i + 1).copied().is_some_and(is_cjk) {
+            continue;
+        }
+        match full_width_punctuation(c) {
+            // A period between digits is a decimal point
+            Some(_) if c == '.' && chars.get(i + 1).is_some_and(|n| n.is_ascii_digit()) => {
+                out.push(c)
+            }
+            Some(wide) if after_cjk => out.push(wide),
+            _ => out.push(c),
+        }
+    }
+    // Full-width punctuation carries its own spacing
+    let mut result = String::with_capacity(out.len());
+    let mut chars = out.chars().peekable();
+    while let Some(c) = chars.next() {
+        result.push(c);
+        if matches!(c, '，' | '。' | '？' | '！' | '：' | '；') {
+            while chars.peek() == Some(&' ') {
+                chars.next();
+            }
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn french_spaces_before_high_punctuation() {
+        assert_eq!(
+            apply("Tu viens ? Oui! Voici la liste: pain", "fr"),
+            "Tu viens\u{202F}? Oui\u{202F}! Voici la liste\u{00A0}: pain"
+        );
+        assert_eq!(
+            apply("Il a dit «bonjour» à 10:30", "fr"),
+            "Il a dit «\u{00A0}bonjour\u{00A0}» à 10:30"
+        );
+        assert_eq!(
+            apply("Voir https://example.com", "fr"),
+            "Voir https://example.com"
+        );
+    }
+
+    #[test]
+    fn german_nouns_are_capitalized_by_ending() {
+        assert_eq!(
+            apply("die bedeutung der freiheit und gemeinschaft", "de"),
+            "die Bedeutung der Freiheit und Gemeinschaft"
+        );
+        assert_eq!(apply("jung", "de"), "jung");
+    }
+
+    #[test]
+    fn cjk_width_is_normalized() {
+        assert_eq!(
+            apply("你好, 世界. 今天 天气 很好!", "zh"),
+            "你好，世界。今天天气很好！"
+        );
+        assert_eq!(apply("版本１２ 是 3.5", "zh"), "版本12 是 3.5");
+        assert_eq!(apply("Hello, world.", "ja"), "Hello, world.");
+    }
+
+    #[test]
+    fn other_languages_are_untouched() {
+        assert_eq!(apply("Really? Yes: sure!", "en"), "Really? Yes: sure!");
+        assert!(!is_supported("en"));
+        assert!(is_supported("fr"));
+    }
+}
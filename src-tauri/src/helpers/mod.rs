@@ -1 +1,23 @@
+pub mod accessibility;
+pub mod app_words;
 pub mod clamshell;
+pub mod dictation_commands;
+pub mod event_throttle;
+pub mod focus_mode;
+pub mod focused_text;
+pub mod haptics;
+pub mod initial_prompt;
+pub mod input_mute;
+pub mod language_detect;
+pub mod locale_format;
+pub mod native_indicator;
+pub mod pipeline_trace;
+pub mod profanity;
+pub mod prompt_command;
+pub mod pronunciation;
+pub mod punctuation;
+pub mod question_detection;
+pub mod text_diff;
+pub mod text_normalization;
+pub mod transcription_confidence;
+pub mod voice_commands;
@@ -0,0 +1,117 @@
+//! Recording feedback for when the overlay webview can't be created.
+//!
+//! Some Linux setups fail to create the transparent overlay window. Instead of
+//! losing all feedback, the tray icon blinks while recording or transcribing
+//! and warnings and tool results are shown as system notifications. The
+//! failure is reported to the frontend with an `overlay-unavailable` event.
+
+use crate::tray::{self, TrayIconState};
+use log::{info, warn};
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+use tauri::image::Image;
+use tauri::tray::TrayIcon;
+use tauri::{AppHandle, Emitter, Manager};
+use tauri_plugin_notification::NotificationExt;
+
+/// Alternate tray icon frame shown while blinking.
+const BLINK_ICON: &str = "resources/echo-icon-light.png";
+const BLINK_INTERVAL: Duration = Duration::from_millis(600);
+
+/// Error from creating the overlay window; set once the fallback is in use.
+static OVERLAY_ERROR: Lazy<Mutex<Option<String>>> = Lazy::new(|| Mutex::new(None));
+
+/// Bumped to stop the running blink animation. Held while a frame is drawn so
+/// no frame lands after [`hide`] returns.
+static ANIMATION: Lazy<Mutex<u64>> = Lazy::new(|| Mutex::new(0));
+
+#[derive(Clone, Debug, Serialize)]
+pub struct OverlayUnavailable {
+    pub error: String,
+}
+
+/// Switch recording feedback to the native indicator.
+pub fn enable(app_handle: &AppHandle, error: String) {
+    warn!(
+        "[Overlay] Falling back to tray and notification feedback: {}",
+        error
+    );
+    *OVERLAY_ERROR.lock().unwrap() = Some(error.clone());
+    let _ = app_handle.emit("overlay-unavailable", OverlayUnavailable { error });
+}
+
+pub fn is_active() -> bool {
+    OVERLAY_ERROR.lock().unwrap().is_some()
+}
+
+/// Why the overlay is unavailable, for frontends that missed the event.
+#[tauri::command]
+pub fn get_overlay_error() -> Option<String> {
+    OVERLAY_ERROR.lock().unwrap().clone()
+}
+
+/// Blink the tray icon until [`hide`]. A notification is shown when
+/// recording starts, since the tray may not be visible.
+pub fn show(app_handle: &AppHandle, state: TrayIconState) {
+    if state == TrayIconState::Recording {
+        notify(app_handle, "Recording…");
+    }
+
+    let generation = {
+        let mut animation = ANIMATION.lock().unwrap();
+        *animation += 1;
+        *animation
+    };
+    let app_handle = app_handle.clone();
+    thread::spawn(move || {
+        let theme = tray::get_current_theme(&app_handle);
+        let frames = [BLINK_ICON, tray::get_icon_path(theme, state)];
+        for frame in frames.iter().cycle() {
+            thread::sleep(BLINK_INTERVAL);
+            let animation = ANIMATION.lock().unwrap();
+            if *animation != generation {
+                return;
+            }
+            set_tray_frame(&app_handle, frame);
+            drop(animation);
+        }
+    });
+}
+
+/// Stop the tray animation. The caller restores the tray icon for the new state.
+pub fn hide() {
+    *ANIMATION.lock().unwrap() += 1;
+}
+
+/// Show a one-off message, e.g. a warning or tool result.
+pub fn notify(app_handle: &AppHandle, message: &str) {
+    if let Err(e) = app_handle
+        .notification()
+        .builder()
+        .title("Echo")
+        .body(message)
+        .show()
+    {
+        info!("[Overlay] Failed to show notification: {}", e);
+    }
+}
+
+fn set_tray_frame(app_handle: &AppHandle, icon_path: &str) {
+    let Some(tray) = app_handle.try_state::<TrayIcon>() else {
+        return;
+    };
+    let image = app_handle
+        .path()
+        .resolve(icon_path, tauri::path::BaseDirectory::Resource)
+        .map_err(|e| e.to_string())
+        .and_then(|path| Image::from_path(path).map_err(|e| e.to_string()));
+    match image {
+        Ok(image) => {
+            let _ = tray.set_icon(Some(image));
+        }
+        Err(e) => warn!("[Overlay] Failed to load tray frame {}: {}", icon_path, e),
+    }
+}
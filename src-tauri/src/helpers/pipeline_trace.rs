@@ -0,0 +1,347 @@
+//! End-to-end tracing of the dictation pipeline.
+//!
+//! Each shortcut press opens a `pipeline` span; recording, transcription,
+//! post-processing, history saving and paste run in child spans of it, across
+//! whichever threads and tasks they happen on. A small `tracing` subscriber
+//! collects the spans of this crate, and once a pipeline's root span closes the
+//! whole trace (span timings and fields) is kept in a ring buffer for the
+//! debug panel.
+
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Current, Id, Record};
+use tracing::{Event, Metadata, Span, Subscriber};
+
+/// Name of the span that starts a trace.
+const ROOT_SPAN: &str = "pipeline";
+/// Number of finished traces kept for the debug panel.
+const MAX_TRACES: usize = 50;
+
+static COLLECTOR: Lazy<TraceCollector> = Lazy::new(TraceCollector::default);
+
+/// Root span of the pipeline currently being recorded, with its `recording`
+/// child. Taken when the shortcut is released.
+static ACTIVE: Lazy<Mutex<Option<(Span, Span)>>> = Lazy::new(|| Mutex::new(None));
+
+#[derive(Clone, Debug, Serialize)]
+pub struct SpanRecord {
+    pub name: String,
+    /// Offset from the start of the trace.
+    pub start_ms: f64,
+    pub duration_ms: f64,
+    pub fields: BTreeMap<String, String>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct PipelineTrace {
+    pub trace_id: u64,
+    /// Unix timestamp in milliseconds.
+    pub started_at: i64,
+    pub duration_ms: f64,
+    /// Fields of the root span (binding, outcome).
+    pub fields: BTreeMap<String, String>,
+    /// Child spans ordered by start time.
+    pub spans: Vec<SpanRecord>,
+}
+
+/// Install the trace collector as the global `tracing` subscriber.
+pub fn install() {
+    if tracing::subscriber::set_global_default(COLLECTOR.clone()).is_err() {
+        log::warn!("A tracing subscriber is already installed; pipeline traces are disabled");
+    }
+}
+
+/// Open a pipeline trace for a shortcut press. Any pipeline still open is
+/// closed first.
+pub fn start_pipeline(binding_id: &str) {
+    let root = tracing::info_span!(
+        "pipeline",
+        binding = binding_id,
+        outcome = tracing::field::Empty
+    );
+    let recording = tracing::info_span!(parent: &root, "recording");
+    *ACTIVE.lock().unwrap() = Some((root, recording));
+}
+
+/// End the recording phase and hand over the pipeline's root span. Returns a
+/// disabled span if no pipeline is open.
+pub fn take_pipeline() -> Span {
+    ACTIVE
+        .lock()
+        .unwrap()
+        .take()
+        .map_or_else(Span::none, |(root, _recording)| root)
+}
+
+/// The most recent finished traces, newest first.
+#[tauri::command]
+pub fn get_pipeline_traces(limit: Option<usize>) -> Vec<PipelineTrace> {
+    COLLECTOR.traces(limit.unwrap_or(MAX_TRACES))
+}
+
+thread_local! {
+    /// Spans entered on this thread, innermost last.
+    static ENTERED: RefCell<Vec<Id>> = const { RefCell::new(Vec::new()) };
+}
+
+struct SpanData {
+    metadata: &'static Metadata<'static>,
+    parent: Option<Id>,
+    /// Id of the root span of the trace this span belongs to, if any.
+    trace: Option<Id>,
+    start: Instant,
+    fields: BTreeMap<String, String>,
+    refs: usize,
+}
+
+struct OpenTrace {
+    started_at: i64,
+    start: Instant,
+    spans: Vec<SpanRecord>,
+}
+
+#[derive(Default)]
+struct State {
+    spans: HashMap<Id, SpanData>,
+    traces: HashMap<Id, OpenTrace>,
+    finished: VecDeque<PipelineTrace>,
+}
+
+#[derive(Clone, Default)]
+struct TraceCollector {
+    next_id: Arc<AtomicU64>,
+    state: Arc<Mutex<State>>,
+}
+
+impl TraceCollector {
+    fn traces(&self, limit: usize) -> Vec<PipelineTrace> {
+        let state = self.state.lock().unwrap();
+        state.finished.iter().rev().take(limit).cloned().collect()
+    }
+
+    /// Finish a span whose last handle was dropped, then release the reference
+    /// it held on its parent.
+    fn close(state: &mut State, id: &Id) {
+        let Some(span) = state.spans.remove(id) else {
+            return;
+        };
+        let duration = span.start.elapsed();
+
+        if let Some(trace_id) = &span.trace {
+            if trace_id == id {
+                if let Some(trace) = state.traces.remove(trace_id) {
+                    let mut spans = trace.spans;
+                    spans.sort_by(|a, b| a.start_ms.total_cmp(&b.start_ms));
+                    state.finished.push_back(PipelineTrace {
+                        trace_id: id.into_u64(),
+                        started_at: trace.started_at,
+                        duration_ms: millis(duration),
+                        fields: span.fields,
+                        spans,
+                    });
+                    if state.finished.len() > MAX_TRACES {
+                        state.finished.pop_front();
+                    }
+                }
+            } else if let Some(trace) = state.traces.get_mut(trace_id) {
+                trace.spans.push(SpanRecord {
+                    name: span.metadata.name().to_string(),
+                    start_ms: millis(span.start.saturating_duration_since(trace.start)),
+                    duration_ms: millis(duration),
+                    fields: span.fields,
+                });
+            }
+        }
+
+        if let Some(parent) = span.parent {
+            let remaining = state.spans.get_mut(&parent).map(|p| {
+                p.refs -= 1;
+                p.refs
+            });
+            if remaining == Some(0) {
+                Self::close(state, &parent);
+            }
+        }
+    }
+}
+
+impl Subscriber for TraceCollector {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.is_span() && metadata.target().starts_with(env!("CARGO_CRATE_NAME"))
+    }
+
+    fn new_span(&self, attrs: &Attributes<'_>) -> Id {
+        let id = Id::from_u64(self.next_id.fetch_add(1, Ordering::Relaxed) + 1);
+        let parent = if attrs.is_contextual() {
+            ENTERED.with(|entered| entered.borrow().last().cloned())
+        } else {
+            attrs.parent().cloned()
+        };
+
+        let mut fields = BTreeMap::new();
+        attrs.record(&mut FieldVisitor(&mut fields));
+
+        let mut state = self.state.lock().unwrap();
+        let parent = parent.filter(|p| state.spans.contains_key(p));
+        let trace = match &parent {
+            Some(p) => {
+                let parent_span = state.spans.get_mut(p).expect("parent span is open");
+                parent_span.refs += 1;
+                parent_span.trace.clone()
+            }
+            None if attrs.metadata().name() == ROOT_SPAN => {
+                state.traces.insert(
+                    id.clone(),
+                    OpenTrace {
+                        started_at: chrono::Utc::now().timestamp_millis(),
+                        start: Instant::now(),
+                        spans: Vec::new(),
+                    },
+                );
+                Some(id.clone())
+            }
+            None => None,
+        };
+
+        state.spans.insert(
+            id.clone(),
+            SpanData {
+                metadata: attrs.metadata(),
+                parent,
+                trace,
+                start: Instant::now(),
+                fields,
+                refs: 1,
+            },
+        );
+        id
+    }
+
+    fn record(&self, id: &Id, values: &Record<'_>) {
+        if let Some(span) = self.state.lock().unwrap().spans.get_mut(id) {
+            values.record(&mut FieldVisitor(&mut span.fields));
+        }
+    }
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, _event: &Event<'_>) {}
+
+    fn enter(&self, id: &Id) {
+        ENTERED.with(|entered| entered.borrow_mut().push(id.clone()));
+    }
+
+    fn exit(&self, id: &Id) {
+        ENTERED.with(|entered| {
+            let mut entered = entered.borrow_mut();
+            if let Some(pos) = entered.iter().rposition(|e| e == id) {
+                entered.remove(pos);
+            }
+        });
+    }
+
+    fn clone_span(&self, id: &Id) -> Id {
+        if let Some(span) = self.state.lock().unwrap().spans.get_mut(id) {
+            span.refs += 1;
+        }
+        id.clone()
+    }
+
+    fn try_close(&self, id: Id) -> bool {
+        let mut state = self.state.lock().unwrap();
+        let Some(span) = state.spans.get_mut(&id) else {
+            return false;
+        };
+        span.refs -= 1;
+        if span.refs > 0 {
+            return false;
+        }
+        Self::close(&mut state, &id);
+        true
+    }
+
+    fn current_span(&self) -> Current {
+        let Some(id) = ENTERED.with(|entered| entered.borrow().last().cloned()) else {
+            return Current::none();
+        };
+        match self.state.lock().unwrap().spans.get(&id) {
+            Some(span) => Current::new(id, span.metadata),
+            None => Current::none(),
+        }
+    }
+}
+
+struct FieldVisitor<'a>(&'a mut BTreeMap<String, String>);
+
+impl Visit for FieldVisitor<'_> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().to_string(), value.to_string());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0
+            .insert(field.name().to_string(), format!("{:?}", value));
+    }
+}
+
+fn millis(duration: std::time::Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn child_spans_are_collected_into_the_trace() {
+        let collector = TraceCollector::default();
+        tracing::subscriber::with_default(collector.clone(), || {
+            let root = tracing::info_span!(
+                "pipeline",
+                binding = "transcribe",
+                outcome = tracing::field::Empty
+            );
+            let recording = tracing::info_span!(parent: &root, "recording");
+            drop(recording);
+
+            let transcription =
+                root.in_scope(|| tracing::info_span!("transcription", samples = 16000));
+            // Spans outside any pipeline are not collected
+            drop(tracing::info_span!("unrelated"));
+            drop(transcription);
+
+            root.record("outcome", "pasted");
+            // The trace only finishes once every child has closed
+            let paste = tracing::info_span!(parent: &root, "paste");
+            drop(root);
+            assert!(collector.traces(10).is_empty());
+            drop(paste);
+        });
+
+        let traces = collector.traces(10);
+        assert_eq!(traces.len(), 1);
+        let trace = &traces[0];
+        assert_eq!(
+            trace.fields.get("binding").map(String::as_str),
+            Some("transcribe")
+        );
+        assert_eq!(
+            trace.fields.get("outcome").map(String::as_str),
+            Some("pasted")
+        );
+
+        let names: Vec<&str> = trace.spans.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["recording", "transcription", "paste"]);
+        assert_eq!(
+            trace.spans[1].fields.get("samples").map(String::as_str),
+            Some("16000")
+        );
+    }
+}
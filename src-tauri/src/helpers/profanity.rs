@@ -0,0 +1,142 @@
+//! Profanity filter.
+//!
+//! Blocklisted words in a transcript are either masked ("f***") or removed.
+//! Removal also drops the separator the word leaves behind, so "Shit, that
+//! hurt." becomes "That hurt." rather than ", that hurt.".
+
+use crate::settings::ProfanityFilter;
+
+/// `text` with the words on `blocklist` masked or removed as `mode` says.
+/// Entries match whole words regardless of case; a trailing `*` matches any
+/// word starting with the rest of the entry.
+pub fn apply(text: &str, blocklist: &[String], mode: ProfanityFilter) -> String {
+    if mode == ProfanityFilter::Keep || blocklist.is_empty() {
+        return text.to_string();
+    }
+    let pieces = split(text);
+    if !pieces
+        .iter()
+        .any(|(is_word, piece)| *is_word && blocked(piece, blocklist))
+    {
+        return text.to_string();
+    }
+
+    let mut out = String::with_capacity(text.len());
+    let mut removed = false;
+    let mut capitalize = false;
+    for (is_word, piece) in pieces {
+        if is_word && blocked(piece, blocklist) {
+            if mode == ProfanityFilter::Mask {
+                out.extend(mask(piece));
+                continue;
+            }
+            // The word opened a sentence, so the next one does now
+            capitalize |= starts_sentence(&out) && piece.starts_with(char::is_uppercase);
+            removed = true;
+        } else if is_word {
+            if std::mem::take(&mut capitalize) {
+                let mut chars = piece.chars();
+                out.extend(chars.next().into_iter().flat_map(char::to_uppercase));
+                out.push_str(chars.as_str());
+            } else {
+                out.push_str(piece);
+            }
+            removed = false;
+        } else if std::mem::take(&mut removed) {
+            // Drop the space or comma that followed the removed word, and
+            // the space before punctuation that ends up next to the text
+            let rest = piece.trim_start_matches([' ', ',']);
+            if rest.starts_with(|c: char| !c.is_whitespace()) {
+                out.truncate(out.trim_end_matches(' ').len());
+            }
+            out.push_str(rest);
+        } else {
+            out.push_str(piece);
+        }
+    }
+    if removed {
+        out.truncate(out.trim_end_matches(' ').len());
+    }
+    out
+}
+
+/// Runs of word characters and of everything else, in order.
+fn split(text: &str) -> Vec<(bool, &str)> {
+    let is_word_char = |c: char| c.is_alphanumeric() || c == '\'';
+    let mut pieces = Vec::new();
+    let mut start = 0;
+    let mut in_word = false;
+    for (i, c) in text.char_indices() {
+        if is_word_char(c) != in_word {
+            if i > start {
+                pieces.push((in_word, &text[start..i]));
+            }
+            start = i;
+            in_word = !in_word;
+        }
+    }
+    if start < text.len() {
+        pieces.push((in_word, &text[start..]));
+    }
+    pieces
+}
+
+fn blocked(word: &str, blocklist: &[String]) -> bool {
+    let word = word.to_lowercase();
+    blocklist.iter().any(|entry| {
+        let entry = entry.trim().to_lowercase();
+        match entry.strip_suffix('*') {
+            Some(prefix) => !prefix.is_empty() && word.starts_with(prefix),
+            None => word == entry,
+        }
+    })
+}
+
+fn mask(word: &str) -> impl Iterator<Item = char> + '_ {
+    word.chars()
+        .enumerate()
+        .map(|(i, c)| if i == 0 { c } else { '*' })
+}
+
+fn starts_sentence(text: &str) -> bool {
+    let text = text.trim_end();
+    text.is_empty() || text.ends_with(['.', '!', '?', '\n'])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn blocklist() -> Vec<String> {
+        vec!["fuck*".to_string(), "shit".to_string()]
+    }
+
+    #[test]
+    fn masking_keeps_the_first_letter() {
+        assert_eq!(
+            apply(
+                "What the fucking hell, Shit!",
+                &blocklist(),
+                ProfanityFilter::Mask
+            ),
+            "What the f****** hell, S***!"
+        );
+        // Whole words only
+        assert_eq!(
+            apply("Shitake mushrooms", &blocklist(), ProfanityFilter::Mask),
+            "Shitake mushrooms"
+        );
+    }
+
+    #[test]
+    fn removal_tidies_what_is_left() {
+        let remove = |text| apply(text, &blocklist(), ProfanityFilter::Remove);
+        assert_eq!(remove("what the fuck is this"), "what the is this");
+        assert_eq!(remove("Shit, that hurt. It is shit."), "That hurt. It is.");
+        assert_eq!(remove("Oh shit"), "Oh");
+        assert_eq!(
+            apply("Oh shit", &blocklist(), ProfanityFilter::Keep),
+            "Oh shit"
+        );
+    }
+}
@@ -0,0 +1,130 @@
+//! Spoken prompt switching.
+//!
+//! A dictation that opens with the wake word and a prompt name ("Echo,
+//! formal mode. Dear team…") selects that post-processing prompt; the rest of
+//! the dictation is processed with it. Matching ignores case, punctuation and
+//! a trailing "mode" or "prompt" on either side, so "formal mode" finds a
+//! prompt named "Formal".
+
+use crate::settings::LLMPrompt;
+
+pub const WAKE_WORD: &str = "echo";
+
+/// Longest prompt name, in words, looked for after the wake word.
+const MAX_NAME_WORDS: usize = 6;
+
+#[derive(Debug, PartialEq)]
+pub struct PromptCommand<'a> {
+    pub prompt_id: String,
+    pub prompt_name: String,
+    /// The dictation after the command phrase, possibly empty.
+    pub rest: &'a str,
+}
+
+/// Parse a leading prompt command out of `text`.
+pub fn parse<'a>(text: &'a str, prompts: &[LLMPrompt]) -> Option<PromptCommand<'a>> {
+    let spoken = words(text);
+    let (first, _) = spoken.first()?;
+    if *first != WAKE_WORD {
+        return None;
+    }
+
+    // Longest match first, so "formal email" wins over "formal"
+    let max = MAX_NAME_WORDS.min(spoken.len() - 1);
+    for count in (1..=max).rev() {
+        let phrase: Vec<&str> = spoken[1..=count].iter().map(|(w, _)| w.as_str()).collect();
+        let phrase = strip_suffix(&phrase);
+        if phrase.is_empty() {
+            continue;
+        }
+        let Some(prompt) = prompts.iter().find(|prompt| {
+            let name = words(&prompt.name);
+            let name: Vec<&str> = name.iter().map(|(w, _)| w.as_str()).collect();
+            strip_suffix(&name) == phrase
+        }) else {
+            continue;
+        };
+
+        let end = spoken[count].1;
+        let rest = text[end..].trim_start_matches(|c: char| !c.is_alphanumeric());
+        return Some(PromptCommand {
+            prompt_id: prompt.id.clone(),
+            prompt_name: prompt.name.clone(),
+            rest,
+        });
+    }
+    None
+}
+
+/// Drop a trailing "mode" or "prompt".
+fn strip_suffix<'a, 'b>(words: &'b [&'a str]) -> &'b [&'a str] {
+    match words.split_last() {
+        Some((last, rest)) if matches!(*last, "mode" | "prompt") => rest,
+        _ => words,
+    }
+}
+
+/// Lowercased words with the byte offset where each ends in `text`.
+fn words(text: &str) -> Vec<(String, usize)> {
+    let mut words = Vec::new();
+    let mut start = None;
+    for (i, c) in text
+        .char_indices()
+        .chain(std::iter::once((text.len(), ' ')))
+    {
+        let in_word = c.is_alphanumeric() || c == '\'';
+        match (start, in_word) {
+            (None, true) => start = Some(i),
+            (Some(s), false) => {
+                words.push((text[s..i].to_lowercase(), i));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn prompts() -> Vec<LLMPrompt> {
+        [
+            ("formal", "Formal"),
+            ("formal_email", "Formal Email"),
+            ("notes", "Meeting Notes Prompt"),
+        ]
+        .into_iter()
+        .map(|(id, name)| LLMPrompt {
+            id: id.to_string(),
+            name: name.to_string(),
+            prompt: String::new(),
+        })
+        .collect()
+    }
+
+    #[test]
+    fn switches_and_keeps_the_rest() {
+        let command = parse("Echo, formal mode. Dear team, thanks.", &prompts()).unwrap();
+        assert_eq!(command.prompt_id, "formal");
+        assert_eq!(command.rest, "Dear team, thanks.");
+
+        let command = parse("echo formal email", &prompts()).unwrap();
+        assert_eq!(command.prompt_id, "formal_email");
+        assert_eq!(command.rest, "");
+
+        let command = parse("Echo meeting notes. Agenda first", &prompts()).unwrap();
+        assert_eq!(command.prompt_id, "notes");
+        assert_eq!(command.rest, "Agenda first");
+    }
+
+    #[test]
+    fn ordinary_dictation_is_not_a_command() {
+        assert_eq!(parse("Echo chambers are everywhere.", &prompts()), None);
+        assert_eq!(parse("Formal mode please", &prompts()), None);
+        assert_eq!(parse("echo", &prompts()), None);
+        assert_eq!(parse("Echo, mode.", &prompts()), None);
+        assert_eq!(parse("", &prompts()), None);
+    }
+}
@@ -0,0 +1,95 @@
+//! Pronunciation dictionary for text-to-speech.
+//!
+//! System voices mangle product names and uncommon names, so before text is
+//! read back the user's entries swap such words for a spelling the voice
+//! says right ("Nguyen" → "win"). Entries match whole words or phrases
+//! regardless of case, longest first so "Echo Pro" wins over "Echo".
+
+use regex::{Regex, RegexBuilder};
+use std::collections::HashMap;
+
+/// `text` with every entry of `pronunciations` replaced by its respelling.
+pub fn apply(text: &str, pronunciations: &HashMap<String, String>) -> String {
+    let Some(pattern) = build_pattern(pronunciations) else {
+        return text.to_string();
+    };
+    let respellings: HashMap<String, &str> = pronunciations
+        .iter()
+        .map(|(word, respelling)| (word.trim().to_lowercase(), respelling.as_str()))
+        .collect();
+    pattern
+        .replace_all(text, |caps: &regex::Captures| {
+            let found = &caps[0];
+            respellings
+                .get(&found.to_lowercase())
+                .map_or_else(|| found.to_string(), |respelling| respelling.to_string())
+        })
+        .into_owned()
+}
+
+/// One alternation of all the entries, longest first. Word boundaries are
+/// only required at ends that are word characters, so "C++" still matches.
+fn build_pattern(pronunciations: &HashMap<String, String>) -> Option<Regex> {
+    let mut words: Vec<&str> = pronunciations
+        .keys()
+        .map(|word| word.trim())
+        .filter(|word| !word.is_empty())
+        .collect();
+    if words.is_empty() {
+        return None;
+    }
+    words.sort_by_key(|word| std::cmp::Reverse(word.chars().count()));
+    let alternatives: Vec<String> = words
+        .iter()
+        .map(|word| {
+            let start = if word.starts_with(char::is_alphanumeric) {
+                r"\b"
+            } else {
+                ""
+            };
+            let end = if word.ends_with(char::is_alphanumeric) {
+                r"\b"
+            } else {
+                ""
+            };
+            format!("{}{}{}", start, regex::escape(word), end)
+        })
+        .collect();
+    RegexBuilder::new(&alternatives.join("|"))
+        .case_insensitive(true)
+        .build()
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dictionary(entries: &[(&str, &str)]) -> HashMap<String, String> {
+        entries
+            .iter()
+            .map(|(word, respelling)| (word.to_string(), respelling.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn replaces_whole_words_and_phrases() {
+        let pronunciations = dictionary(&[
+            ("Nguyen", "win"),
+            ("Echo Pro", "echo pro edition"),
+            ("Echo", "ekko"),
+            ("C++", "see plus plus"),
+        ]);
+        assert_eq!(
+            apply(
+                "nguyen uses Echo Pro and echo, not Echoes, in C++.",
+                &pronunciations
+            ),
+            "win uses echo pro edition and ekko, not Echoes, in see plus plus."
+        );
+        assert_eq!(
+            apply("Nothing to change", &HashMap::new()),
+            "Nothing to change"
+        );
+    }
+}
@@ -0,0 +1,119 @@
+//! Punctuation restoration for engines that don't punctuate.
+//!
+//! Parakeet returns lowercase text without punctuation. Sentences are split
+//! where the speaker paused between words, then each one gets a capital and
+//! a closing `.`, or `?` when it opens like an English question. Text that
+//! already holds sentence punctuation is left alone.
+
+use super::question_detection;
+use crate::audio_toolkit::transcriber::TimedSegment;
+
+/// Silence between two words, in milliseconds, that ends a sentence.
+const SENTENCE_PAUSE_MS: i64 = 700;
+
+/// `text` split into punctuated, capitalized sentences. `words` are the
+/// engine's word timestamps; pauses are only used when they line up with the
+/// words of `text`.
+pub fn restore(text: &str, words: &[TimedSegment], language: Option<&str>) -> String {
+    if text.contains(['.', '?', '!']) {
+        return text.to_string();
+    }
+    let text_words: Vec<&str> = text.split_whitespace().collect();
+    if text_words.is_empty() {
+        return text.to_string();
+    }
+    let english = language.is_none_or(|language| language == "en");
+    let timed = words.len() == text_words.len();
+
+    let mut sentences = Vec::new();
+    let mut sentence: Vec<String> = Vec::new();
+    for (i, word) in text_words.iter().enumerate() {
+        let word = if english {
+            capitalize_i(word)
+        } else {
+            word.to_string()
+        };
+        sentence.push(word);
+        let pause = timed
+            && words
+                .get(i + 1)
+                .is_some_and(|next| next.start_ms - words[i].end_ms >= SENTENCE_PAUSE_MS);
+        if pause || i + 1 == text_words.len() {
+            sentences.push(finish(&sentence.join(" "), english));
+            sentence.clear();
+        }
+    }
+    sentences.join(" ")
+}
+
+fn finish(sentence: &str, english: bool) -> String {
+    let mut chars = sentence.chars();
+    let mut out: String = chars
+        .next()
+        .into_iter()
+        .flat_map(char::to_uppercase)
+        .collect();
+    out.push_str(chars.as_str());
+    // Drop a trailing comma or colon the engine left before the break
+    out.truncate(out.trim_end_matches([',', ';', ':']).len());
+    out.push(if english && question_detection::is_question(&out) {
+        '?'
+    } else {
+        '.'
+    });
+    out
+}
+
+/// The English pronoun "I" and its contractions, which are always capitalized.
+fn capitalize_i(word: &str) -> String {
+    match word {
+        "i" | "i'm" | "i've" | "i'll" | "i'd" => format!("I{}", &word[1..]),
+        _ => word.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word(start_ms: i64, end_ms: i64, text: &str) -> TimedSegment {
+        TimedSegment {
+            start_ms,
+            end_ms,
+            text: text.to_string(),
+            confidence: None,
+        }
+    }
+
+    #[test]
+    fn splits_sentences_at_pauses() {
+        let words = [
+            word(0, 200, "i"),
+            word(250, 500, "missed"),
+            word(550, 800, "it"),
+            word(1800, 2000, "can"),
+            word(2050, 2200, "you"),
+            word(2250, 2500, "resend"),
+            word(2550, 2800, "it"),
+        ];
+        assert_eq!(
+            restore("i missed it can you resend it", &words, Some("en")),
+            "I missed it. Can you resend it?"
+        );
+        // Without matching timestamps the text is one sentence
+        assert_eq!(
+            restore("i missed it can you resend it", &[], None),
+            "I missed it can you resend it."
+        );
+    }
+
+    #[test]
+    fn leaves_punctuated_text_alone() {
+        assert_eq!(
+            restore("Hello there. i said", &[], None),
+            "Hello there. i said"
+        );
+        assert_eq!(restore("", &[], None), "");
+        assert_eq!(restore("je suis là", &[], Some("fr")), "Je suis là.");
+    }
+}
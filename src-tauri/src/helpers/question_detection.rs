@@ -0,0 +1,167 @@
+//! Question detection for interview mode.
+//!
+//! Whisper usually punctuates questions, so a sentence ending in `?` counts
+//! in any language. Unpunctuated English sentences also count when they open
+//! like a question ("how did you…", "can you…") or an interview prompt
+//! ("tell me about…", "walk me through…").
+
+/// Sentences shorter than this are ignored ("Right?", "You know?").
+const MIN_WORDS: usize = 3;
+
+/// Filler words skipped before looking for an opener ("so, how did you…").
+const FILLERS: &[&str] = &[
+    "so", "and", "but", "okay", "ok", "well", "now", "then", "alright", "um", "uh",
+];
+
+/// Words that open a question when they start a sentence.
+const QUESTION_OPENERS: &[&str] = &[
+    "who",
+    "whom",
+    "whose",
+    "what",
+    "when",
+    "where",
+    "why",
+    "how",
+    "which",
+    "is",
+    "are",
+    "was",
+    "were",
+    "am",
+    "do",
+    "does",
+    "did",
+    "can",
+    "could",
+    "would",
+    "will",
+    "should",
+    "shall",
+    "may",
+    "might",
+    "have",
+    "has",
+    "had",
+    "isn't",
+    "aren't",
+    "don't",
+    "doesn't",
+    "didn't",
+    "can't",
+    "couldn't",
+    "wouldn't",
+    "won't",
+    "shouldn't",
+];
+
+/// Interview prompts phrased as requests rather than questions.
+const PROMPT_OPENERS: &[&str] = &[
+    "tell me about",
+    "tell us about",
+    "walk me through",
+    "walk us through",
+    "describe",
+    "explain",
+    "give me an example",
+    "give us an example",
+];
+
+/// The questions in `text`, trimmed, in order.
+pub fn questions(text: &str) -> Vec<String> {
+    sentences(text)
+        .into_iter()
+        .filter(|sentence| is_question(sentence))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Whether `sentence` reads as a question or an interview prompt.
+pub fn is_question(sentence: &str) -> bool {
+    let words: Vec<String> = sentence
+        .split_whitespace()
+        .map(|word| {
+            word.trim_matches(|c: char| !c.is_alphanumeric() && c != '\'')
+                .to_lowercase()
+        })
+        .filter(|word| !word.is_empty())
+        .collect();
+    if words.len() < MIN_WORDS {
+        return false;
+    }
+    if sentence.ends_with('?') {
+        return true;
+    }
+    // Exclamations ("What a great answer!") aren't asking anything
+    if sentence.ends_with('!') {
+        return false;
+    }
+    let Some(first) = words
+        .iter()
+        .position(|word| !FILLERS.contains(&word.as_str()))
+    else {
+        return false;
+    };
+    let start = words[first..].join(" ");
+    QUESTION_OPENERS.contains(&words[first].as_str())
+        || PROMPT_OPENERS
+            .iter()
+            .any(|prompt| start == *prompt || start.starts_with(&format!("{} ", prompt)))
+}
+
+/// Split on sentence-ending punctuation, keeping it with its sentence.
+fn sentences(text: &str) -> Vec<&str> {
+    let mut sentences = Vec::new();
+    let mut start = 0;
+    for (i, c) in text.char_indices() {
+        if matches!(c, '.' | '?' | '!' | '？' | '。') {
+            let end = i + c.len_utf8();
+            sentences.push(text[start..end].trim());
+            start = end;
+        }
+    }
+    sentences.push(text[start..].trim());
+    sentences.retain(|sentence| !sentence.is_empty());
+    sentences
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_punctuated_questions() {
+        assert_eq!(
+            questions("Thanks for joining. What drew you to this role? Great."),
+            ["What drew you to this role?"]
+        );
+        assert_eq!(
+            questions("Vous avez travaillé à Paris? Oui."),
+            ["Vous avez travaillé à Paris?"]
+        );
+    }
+
+    #[test]
+    fn finds_unpunctuated_questions_and_prompts() {
+        assert_eq!(
+            questions("So, how did you handle that"),
+            ["So, how did you handle that"]
+        );
+        assert_eq!(
+            questions("Tell me about a time you disagreed with a manager."),
+            ["Tell me about a time you disagreed with a manager."]
+        );
+        assert_eq!(
+            questions("Walk me through your last project"),
+            ["Walk me through your last project"]
+        );
+    }
+
+    #[test]
+    fn ignores_statements_and_short_tags() {
+        assert!(questions("I worked there for three years.").is_empty());
+        assert!(questions("Right? Okay.").is_empty());
+        assert!(questions("What a great answer!").is_empty());
+        assert!(questions("Describer is a word I made up.").is_empty());
+    }
+}
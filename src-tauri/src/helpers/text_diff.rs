@@ -0,0 +1,100 @@
+//! Word diff between two transcriptions of the same audio.
+//!
+//! Retranscribing with another model replaces an entry's text; the diff
+//! shows which words it heard differently. Words are compared exactly, so a
+//! change of case or punctuation shows as a swapped word.
+
+use serde::Serialize;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiffOp {
+    Equal,
+    Insert,
+    Delete,
+}
+
+/// A run of words that are in both texts, only the new one, or only the old.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct DiffSpan {
+    pub op: DiffOp,
+    pub text: String,
+}
+
+/// Spans taking `old` to `new`, deletions before insertions where words
+/// were swapped.
+pub fn word_diff(old: &str, new: &str) -> Vec<DiffSpan> {
+    let old: Vec<&str> = old.split_whitespace().collect();
+    let new: Vec<&str> = new.split_whitespace().collect();
+
+    // lcs[i][j]: longest common subsequence of old[i..] and new[j..]
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut spans: Vec<DiffSpan> = Vec::new();
+    let mut push = |op: DiffOp, word: &str| match spans.last_mut() {
+        Some(last) if last.op == op => {
+            last.text.push(' ');
+            last.text.push_str(word);
+        }
+        _ => spans.push(DiffSpan {
+            op,
+            text: word.to_string(),
+        }),
+    };
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            push(DiffOp::Equal, old[i]);
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            push(DiffOp::Delete, old[i]);
+            i += 1;
+        } else {
+            push(DiffOp::Insert, new[j]);
+            j += 1;
+        }
+    }
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn span(op: DiffOp, text: &str) -> DiffSpan {
+        DiffSpan {
+            op,
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn marks_swapped_added_and_dropped_words() {
+        assert_eq!(
+            word_diff("send the report to Ann today", "send the reports to Anne"),
+            vec![
+                span(DiffOp::Equal, "send the"),
+                span(DiffOp::Delete, "report"),
+                span(DiffOp::Insert, "reports"),
+                span(DiffOp::Equal, "to"),
+                span(DiffOp::Delete, "Ann today"),
+                span(DiffOp::Insert, "Anne"),
+            ]
+        );
+        assert_eq!(
+            word_diff("same  words", "same words"),
+            vec![span(DiffOp::Equal, "same words")]
+        );
+        assert_eq!(word_diff("", "hello"), vec![span(DiffOp::Insert, "hello")]);
+    }
+}
@@ -0,0 +1,275 @@
+//! Inverse text normalization: spoken numbers to written ones.
+//!
+//! Engines often spell out what people write with digits. For the languages
+//! the user turned it on for, this rewrites them without an LLM round trip:
+//!
+//! - Numbers: "twenty five" → "25", "three point five" → "3.5". Numbers
+//!   below ten stay words ("one of them") unless a unit follows.
+//! - Currencies and percentages: "twenty five dollars" → "$25",
+//!   "ten dollars and fifty cents" → "$10.50", "five percent" → "5%".
+//! - Dates: "march third" → "March 3rd".
+
+mod numbers;
+
+use crate::helpers::locale_format::LanguageRules;
+use numbers::{parse_cardinal, parse_day, parse_decimals, parse_year};
+
+pub const SUPPORTED: &[LanguageRules] = &[LanguageRules {
+    code: "en",
+    name: "English",
+    description: "Write numbers, prices, percentages and dates with digits",
+}];
+
+const MONTHS: &[&str] = &[
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+];
+
+pub fn is_supported(language: &str) -> bool {
+    SUPPORTED.iter().any(|rules| rules.code == language)
+}
+
+/// Write the spoken numbers in `text` with digits. Text in languages without
+/// rules is returned as it is.
+pub fn apply(text: &str, language: &str) -> String {
+    match language {
+        "en" => english(text),
+        _ => text.to_string(),
+    }
+}
+
+/// A word with the whitespace before it and the punctuation around it.
+struct Token<'a> {
+    space: &'a str,
+    lead: &'a str,
+    core: &'a str,
+    trail: &'a str,
+    word: String,
+}
+
+fn tokenize(text: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = text;
+    while !rest.is_empty() {
+        let start = rest.len() - rest.trim_start().len();
+        let (space, after) = rest.split_at(start);
+        if after.is_empty() {
+            break;
+        }
+        let end = after.find(char::is_whitespace).unwrap_or(after.len());
+        let (raw, remaining) = after.split_at(end);
+        let core_start = raw.find(|c: char| c.is_alphanumeric()).unwrap_or(raw.len());
+        let core_end = raw
+            .rfind(|c: char| c.is_alphanumeric())
+            .map_or(core_start, |idx| {
+                idx + raw[idx..].chars().next().map_or(0, char::len_utf8)
+            });
+        let core = &raw[core_start..core_end];
+        tokens.push(Token {
+            space,
+            lead: &raw[..core_start],
+            core,
+            trail: &raw[core_end..],
+            word: core.to_lowercase(),
+        });
+        rest = remaining;
+    }
+    tokens
+}
+
+/// whitespace in between.
+fn continues(tokens: &[Token], idx: usize) -> bool {
+    idx > 0 && idx < tokens.len() && tokens[idx - 1].trail.is_empty() && tokens[idx].lead.is_empty()
+}
+
+fn ordinal_suffix(n: u64) -> &'static str {
+    match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    }
+}
+
+/// Digits with thousands separators from 10,000 up; shorter numbers are
+/// often years or codes.
+fn group(n: u64) -> String {
+    let digits = n.to_string();
+    if n < 10_000 {
+        return digits;
+    }
+    let mut out = String::with_capacity(digits.len() + digits.len() / 3);
+    for (idx, digit) in digits.chars().enumerate() {
+        if idx > 0 && (digits.len() - idx) % 3 == 0 {
+            out.push(',');
+        }
+        out.push(digit);
+    }
+    out
+}
+
+/// What is spoken at `tokens[start..]` written with digits, with the index
+/// after it. `None` when nothing there is rewritten.
+fn rewrite(tokens: &[Token], start: usize) -> Option<(String, usize)> {
+    let token = &tokens[start];
+    if let Some(month) = MONTHS.iter().find(|month| **month == token.word) {
+        let (day, ordinal, end) = parse_day(tokens, start + 1)?;
+        // "you may first check" and "march ten miles" are no dates
+        let capitalized = token.core.starts_with(char::is_uppercase);
+        let is_date = capitalized
+            || match *month {
+                "may" => false,
+                "march" => ordinal,
+                _ => true,
+            };
+        if !is_date {
+            return None;
+        }
+        let suffix = if ordinal { ordinal_suffix(day) } else { "" };
+        let mut name = month.to_string();
+        name[..1].make_ascii_uppercase();
+        return Some((format!("{} {}{}", name, day, suffix), end));
+    }
+
+    let (number, end) = match parse_year(tokens, start) {
+        Some((year, end)) => (year.to_string(), end),
+        None => {
+            let (value, end) = parse_cardinal(tokens, start)?;
+            match parse_decimals(tokens, end) {
+                Some((decimals, end)) => (format!("{}.{}", group(value), decimals), end),
+                None => (group(value), end),
+            }
+        }
+    };
+    let word_at = |idx: usize| continues(tokens, idx).then(|| tokens[idx].word.as_str());
+
+    let symbol = match word_at(end) {
+        Some("dollar" | "dollars") => Some("$"),
+        Some("euro" | "euros") => Some("€"),
+        _ => None,
+    };
+    if let Some(symbol) = symbol {
+        // "ten dollars and fifty cents"
+        if word_at(end + 1) == Some("and") && continues(tokens, end + 2) {
+            if let Some((cents, cents_end)) = parse_cardinal(tokens, end + 2) {
+                if cents < 100
+                    && !number.contains('.')
+                    && matches!(word_at(cents_end), Some("cent" | "cents"))
+                {
+                    return Some((format!("{}{}.{:02}", symbol, number, cents), cents_end + 1));
+                }
+            }
+        }
+        return Some((format!("{}{}", symbol, number), end + 1));
+    }
+    match (word_at(end), word_at(end + 1)) {
+        (Some("percent"), _) => return Some((format!("{}%", number), end + 1)),
+        (Some("per"), Some("cent")) => return Some((format!("{}%", number), end + 2)),
+        _ => {}
+    }
+
+    // "one of them" stays as it is
+    let single_digit = end == start + 1 && number.len() == 1;
+    (!single_digit).then_some((number, end))
+}
+
+fn english(text: &str) -> String {
+    let tokens = tokenize(text);
+    let mut out = String::with_capacity(text.len());
+    let mut i = 0;
+    while i < tokens.len() {
+        let token = &tokens[i];
+        match rewrite(&tokens, i) {
+            Some((written, end)) => {
+                out.push_str(token.space);
+                out.push_str(token.lead);
+                out.push_str(&written);
+                out.push_str(tokens[end - 1].trail);
+                i = end;
+            }
+            None => {
+                out.push_str(token.space);
+                out.push_str(token.lead);
+                out.push_str(token.core);
+                out.push_str(token.trail);
+                i += 1;
+            }
+        }
+    }
+    // Whitespace after the last word
+    out.push_str(&text[text.trim_end().len()..]);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers_are_written_with_digits() {
+        assert_eq!(
+            apply("I counted twenty five birds.", "en"),
+            "I counted 25 birds."
+        );
+        assert_eq!(
+            apply(
+                "One hundred and twenty-three people, then two thousand",
+                "en"
+            ),
+            "123 people, then 2000"
+        );
+        assert_eq!(
+            apply("about three point five million", "en"),
+            "about 3.5 million"
+        );
+        assert_eq!(apply("forty five thousand users", "en"), "45,000 users");
+        assert_eq!(apply("born in nineteen ninety nine", "en"), "born in 1999");
+    }
+
+    #[test]
+    fn small_numbers_stay_words() {
+        assert_eq!(
+            apply("One of the two options", "en"),
+            "One of the two options"
+        );
+        assert_eq!(apply("five, six", "en"), "five, six");
+    }
+
+    #[test]
+    fn prices_and_percentages() {
+        assert_eq!(
+            apply("It costs twenty five dollars.", "en"),
+            "It costs $25."
+        );
+        assert_eq!(apply("ten dollars and fifty cents", "en"), "$10.50");
+        assert_eq!(apply("five euros or five percent", "en"), "€5 or 5%");
+        assert_eq!(apply("one dollar", "en"), "$1");
+    }
+
+    #[test]
+    fn dates() {
+        assert_eq!(apply("Due march third.", "en"), "Due March 3rd.");
+        assert_eq!(apply("On March twenty first", "en"), "On March 21st");
+        assert_eq!(apply("july four", "en"), "July 4");
+        assert_eq!(apply("They march ten miles", "en"), "They march 10 miles");
+        assert_eq!(apply("you may first check", "en"), "you may first check");
+    }
+
+    #[test]
+    fn other_languages_are_untouched() {
+        assert_eq!(apply("twenty five", "fr"), "twenty five");
+        assert!(is_supported("en"));
+        assert!(!is_supported("fr"));
+    }
+}
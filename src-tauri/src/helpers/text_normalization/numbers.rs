@@ -0,0 +1,208 @@
+//! Spoken English numbers, read from tokens.
+
+use super::{continues, Token};
+
+const UNITS: &[&str] = &[
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+const TEENS: &[&str] = &[
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+const TENS: &[&str] = &[
+    "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+const ORDINAL_UNITS: &[&str] = &[
+    "first", "second", "third", "fourth", "fifth", "sixth", "seventh", "eighth", "ninth",
+];
+const ORDINAL_TEENS: &[&str] = &[
+    "tenth",
+    "eleventh",
+    "twelfth",
+    "thirteenth",
+    "fourteenth",
+    "fifteenth",
+    "sixteenth",
+    "seventeenth",
+    "eighteenth",
+    "nineteenth",
+];
+
+#[derive(Clone, Copy)]
+enum Kind {
+    Unit,
+    Teen,
+    Tens,
+    TensUnit,
+    Hundred,
+    Scale,
+}
+
+fn cardinal_word(word: &str) -> Option<(u64, Kind)> {
+    if let Some((tens, unit)) = word.split_once('-') {
+        let tens = position(TENS, tens)?;
+        let unit = position(UNITS, unit).filter(|unit| *unit > 0)?;
+        return Some((20 + tens * 10 + unit, Kind::TensUnit));
+    }
+    if let Some(unit) = position(UNITS, word) {
+        return Some((unit, Kind::Unit));
+    }
+    if let Some(teen) = position(TEENS, word) {
+        return Some((10 + teen, Kind::Teen));
+    }
+    if let Some(tens) = position(TENS, word) {
+        return Some((20 + tens * 10, Kind::Tens));
+    }
+    match word {
+        "hundred" => Some((100, Kind::Hundred)),
+        "thousand" => Some((1_000, Kind::Scale)),
+        "million" => Some((1_000_000, Kind::Scale)),
+        "billion" => Some((1_000_000_000, Kind::Scale)),
+        _ => None,
+    }
+}
+
+fn ordinal_word(word: &str) -> Option<u64> {
+    if let Some((tens, unit)) = word.split_once('-') {
+        let tens = position(TENS, tens)?;
+        return Some(20 + tens * 10 + position(ORDINAL_UNITS, unit)? + 1);
+    }
+    if let Some(unit) = position(ORDINAL_UNITS, word) {
+        return Some(unit + 1);
+    }
+    if let Some(teen) = position(ORDINAL_TEENS, word) {
+        return Some(10 + teen);
+    }
+    match word {
+        "twentieth" => Some(20),
+        "thirtieth" => Some(30),
+        _ => None,
+    }
+}
+
+fn position(words: &[&str], word: &str) -> Option<u64> {
+    words
+        .iter()
+        .position(|candidate| *candidate == word)
+        .map(|idx| idx as u64)
+}
+
+/// Whether `tokens[idx]` continues the phrase before it: nothing but
+/// The number spoken at `tokens[start..]`, with the index after it.
+pub(super) fn parse_cardinal(tokens: &[Token], start: usize) -> Option<(u64, usize)> {
+    let mut total = 0;
+    let mut current = 0;
+    let mut last: Option<Kind> = None;
+    let mut last_scale = u64::MAX;
+    let mut end = start;
+    let mut i = start;
+    while i == start || continues(tokens, i) {
+        let Some(token) = tokens.get(i) else {
+            break;
+        };
+        // "one hundred and five"
+        if token.word == "and" && matches!(last, Some(Kind::Hundred | Kind::Scale)) {
+            i += 1;
+            continue;
+        }
+        let Some((value, kind)) = cardinal_word(&token.word) else {
+            break;
+        };
+        let fits = match kind {
+            Kind::Unit => !matches!(last, Some(Kind::Unit | Kind::Teen | Kind::TensUnit)),
+            Kind::Teen | Kind::Tens | Kind::TensUnit => {
+                current % 100 == 0
+                    && !matches!(
+                        last,
+                        Some(Kind::Unit | Kind::Teen | Kind::Tens | Kind::TensUnit)
+                    )
+            }
+            Kind::Hundred => {
+                matches!(
+                    last,
+                    Some(Kind::Unit | Kind::Teen | Kind::Tens | Kind::TensUnit)
+                ) && current < 100
+            }
+            Kind::Scale => current > 0 && value < last_scale,
+        };
+        if !fits {
+            break;
+        }
+        match kind {
+            Kind::Hundred => current *= 100,
+            Kind::Scale => {
+                total += current * value;
+                current = 0;
+                last_scale = value;
+            }
+            _ => current += value,
+        }
+        last = Some(kind);
+        i += 1;
+        end = i;
+    }
+    (end > start).then_some((total + current, end))
+}
+
+/// Digits spoken after "point", with the index after them.
+pub(super) fn parse_decimals(tokens: &[Token], start: usize) -> Option<(String, usize)> {
+    if !continues(tokens, start) || tokens[start].word != "point" {
+        return None;
+    }
+    let mut digits = String::new();
+    let mut i = start + 1;
+    while continues(tokens, i) {
+        match tokens[i].word.as_str() {
+            "oh" => digits.push('0'),
+            word => match position(UNITS, word) {
+                Some(digit) => digits.push_str(&digit.to_string()),
+                None => break,
+            },
+        }
+        i += 1;
+    }
+    (!digits.is_empty()).then_some((digits, i))
+}
+
+/// "nineteen ninety nine" and "twenty twenty four" are years.
+pub(super) fn parse_year(tokens: &[Token], start: usize) -> Option<(u64, usize)> {
+    let century = match tokens[start].word.as_str() {
+        "nineteen" => 19,
+        "twenty" => 20,
+        _ => return None,
+    };
+    if !continues(tokens, start + 1) {
+        return None;
+    }
+    let (rest, end) = parse_cardinal(tokens, start + 1)?;
+    ((10..100).contains(&rest) && end - start <= 3).then_some((century * 100 + rest, end))
+}
+
+/// A day of the month, with whether it was spoken as an ordinal.
+pub(super) fn parse_day(tokens: &[Token], start: usize) -> Option<(u64, bool, usize)> {
+    if !continues(tokens, start) {
+        return None;
+    }
+    if let Some(day) = ordinal_word(&tokens[start].word) {
+        return (day <= 31).then_some((day, true, start + 1));
+    }
+    // "twenty first"
+    if let Some(tens @ (20 | 30)) = cardinal_word(&tokens[start].word).map(|(value, _)| value) {
+        if continues(tokens, start + 1) {
+            if let Some(unit) = position(ORDINAL_UNITS, &tokens[start + 1].word) {
+                let day = tens + unit + 1;
+                return (day <= 31).then_some((day, true, start + 2));
+            }
+        }
+    }
+    let (day, end) = parse_cardinal(tokens, start)?;
+    (1..=31).contains(&day).then_some((day, false, end))
+}
@@ -0,0 +1,221 @@
+//! Transcription confidence estimate.
+//!
+//! Neither engine reports token probabilities through transcribe-rs, so the
+//! score is built from the usual signs of a bad transcription: a speaking rate
+//! far outside normal speech, repeated phrases (Whisper's decoding loops),
+//! known hallucinations on silence ("Thanks for watching!") and very quiet
+//! audio. 1.0 means nothing looked wrong. The same signs score individual
+//! words or segments so the UI can point at probable errors.
+
+use crate::audio_toolkit::transcriber::TimedSegment;
+
+const SAMPLE_RATE: f32 = 16000.0;
+
+/// Segments scoring below this are flagged as probable errors.
+pub const LOW_SEGMENT_CONFIDENCE: f32 = 0.5;
+
+/// Samples per window of a [`level_envelope`]: 100 ms.
+const ENVELOPE_WINDOW: usize = 1600;
+
+/// Normal dictation stays well inside this words-per-second range.
+const MIN_WORDS_PER_SEC: f32 = 0.5;
+const MAX_WORDS_PER_SEC: f32 = 6.0;
+
+/// RMS level below which the recording is likely mostly silence.
+const QUIET_RMS: f32 = 0.01;
+
+/// What Whisper tends to produce for silence or noise, as the whole text.
+const HALLUCINATIONS: &[&str] = &[
+    "thank you for watching",
+    "thanks for watching",
+    "thank you for watching and see you next time",
+    "please subscribe",
+    "subtitles by the amara org community",
+    "you",
+    "bye",
+];
+
+/// Non-speech markers some models emit.
+const NOISE_TAGS: &[&str] = &[
+    "[music]",
+    "[blank_audio]",
+    "(inaudible)",
+    "[inaudible]",
+    "(music)",
+];
+
+/// Estimated confidence in `text` as a transcription of `samples`, 0.0 to 1.0.
+pub fn estimate(text: &str, samples: &[f32]) -> f32 {
+    let seconds = samples.len() as f32 / SAMPLE_RATE;
+    score(text, seconds, rms(samples), true)
+}
+
+/// RMS level of every 100 ms of `samples`, kept so segments can be scored
+/// without holding on to the audio.
+pub fn level_envelope(samples: &[f32]) -> Vec<f32> {
+    samples.chunks(ENVELOPE_WINDOW).filter_map(rms).collect()
+}
+
+/// Fill in the confidence of each segment. `envelope` is the
+/// [`level_envelope`] of the audio the segment timestamps refer to.
+pub fn score_segments(segments: &mut [TimedSegment], envelope: &[f32]) {
+    let window_ms = (ENVELOPE_WINDOW as f32 / SAMPLE_RATE * 1000.0) as i64;
+    for segment in segments {
+        let end = ((segment.end_ms + window_ms - 1) / window_ms).max(0) as usize;
+        let end = end.min(envelope.len());
+        let start = ((segment.start_ms / window_ms).max(0) as usize).min(end);
+        let windows = &envelope[start..end];
+        let level = (!windows.is_empty()).then(|| {
+            (windows.iter().map(|rms| rms * rms).sum::<f32>() / windows.len() as f32).sqrt()
+        });
+        let seconds = (segment.end_ms - segment.start_ms).max(0) as f32 / 1000.0;
+        // A single short word like "you" is only suspicious as a whole dictation
+        segment.confidence = Some(score(&segment.text, seconds, level, seconds >= 1.0));
+    }
+}
+
+/// Text of the segments flagged as probable errors, in order.
+pub fn low_confidence_words(segments: &[TimedSegment]) -> Vec<String> {
+    segments
+        .iter()
+        .filter(|segment| {
+            segment
+                .confidence
+                .is_some_and(|confidence| confidence < LOW_SEGMENT_CONFIDENCE)
+        })
+        .map(|segment| segment.text.clone())
+        .collect()
+}
+
+fn score(text: &str, seconds: f32, level: Option<f32>, check_hallucinations: bool) -> f32 {
+    let words = words(text);
+    if words.is_empty() {
+        return 0.0;
+    }
+
+    let mut score = rate_factor(words.len(), seconds) * repetition_factor(&words);
+    if check_hallucinations && HALLUCINATIONS.contains(&words.join(" ").as_str()) {
+        score *= 0.3;
+    }
+    let lower = text.to_lowercase();
+    if NOISE_TAGS.iter().any(|tag| lower.contains(tag)) {
+        score *= 0.3;
+    }
+    score *= level.map_or(1.0, level_factor);
+    score.clamp(0.0, 1.0)
+}
+
+/// Penalize speech that is implausibly sparse or dense for its duration.
+fn rate_factor(word_count: usize, seconds: f32) -> f32 {
+    // Too short to judge a rate
+    if seconds < 1.0 {
+        return 1.0;
+    }
+    let rate = word_count as f32 / seconds;
+    if rate < MIN_WORDS_PER_SEC {
+        (rate / MIN_WORDS_PER_SEC).max(0.4)
+    } else if rate > MAX_WORDS_PER_SEC {
+        (MAX_WORDS_PER_SEC / rate).max(0.3)
+    } else {
+        1.0
+    }
+}
+
+/// Penalize repeated three-word runs, as produced by decoding loops.
+fn repetition_factor(words: &[String]) -> f32 {
+    if words.len() < 6 {
+        return 1.0;
+    }
+    let trigrams: Vec<&[String]> = words.windows(3).collect();
+    let mut distinct = trigrams.clone();
+    distinct.sort();
+    distinct.dedup();
+    let ratio = distinct.len() as f32 / trigrams.len() as f32;
+    ((ratio - 0.3) / 0.4).clamp(0.2, 1.0)
+}
+
+fn level_factor(rms: f32) -> f32 {
+    (rms / QUIET_RMS).clamp(0.3, 1.0)
+}
+
+fn rms(samples: &[f32]) -> Option<f32> {
+    if samples.is_empty() {
+        return None;
+    }
+    Some((samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32).sqrt())
+}
+
+fn words(text: &str) -> Vec<String> {
+    text.split_whitespace()
+        .map(|word| {
+            word.trim_matches(|c: char| !c.is_alphanumeric() && c != '\'')
+                .to_lowercase()
+        })
+        .filter(|word| !word.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `seconds` of a tone at speech-like level.
+    fn speech(seconds: f32) -> Vec<f32> {
+        (0..(seconds * SAMPLE_RATE) as usize)
+            .map(|i| 0.1 * (i as f32 * 0.05).sin())
+            .collect()
+    }
+
+    #[test]
+    fn clean_dictation_scores_high() {
+        let text = "Please send the quarterly report to the finance team by Friday.";
+        assert!(estimate(text, &speech(4.0)) > 0.9);
+    }
+
+    #[test]
+    fn loops_and_hallucinations_score_low() {
+        let looped = "I will be there. I will be there. I will be there. I will be there.";
+        assert!(estimate(looped, &speech(5.0)) < 0.5);
+
+        let quiet = vec![0.001; 16000 * 3];
+        assert!(estimate("Thanks for watching!", &quiet) < 0.2);
+        assert!(estimate("[BLANK_AUDIO]", &speech(2.0)) < 0.5);
+    }
+
+    #[test]
+    fn implausible_rate_scores_low() {
+        let dense = "one two three four five six seven eight nine ten ".repeat(3);
+        assert!(estimate(&dense, &speech(2.0)) < 0.5);
+        assert!(estimate("Okay", &speech(12.0)) < 0.5);
+    }
+
+    #[test]
+    fn flags_mumbled_words() {
+        let mut audio = speech(1.0);
+        audio.resize(16000 + 8000, 0.001);
+        let mut segments = vec![
+            TimedSegment {
+                start_ms: 0,
+                end_ms: 400,
+                text: "Call".to_string(),
+                confidence: None,
+            },
+            TimedSegment {
+                start_ms: 400,
+                end_ms: 900,
+                text: "you".to_string(),
+                confidence: None,
+            },
+            TimedSegment {
+                start_ms: 1_000,
+                end_ms: 1_500,
+                text: "Bob".to_string(),
+                confidence: None,
+            },
+        ];
+        score_segments(&mut segments, &level_envelope(&audio));
+        // "you" is a hallucination on its own, not as one word of many
+        assert!(segments[1].confidence.unwrap() > 0.9);
+        assert_eq!(low_confidence_words(&segments), ["Bob"]);
+    }
+}
@@ -0,0 +1,198 @@
+//! Voice commands.
+//!
+//! With voice commands on, a dictation that is nothing but a command phrase
+//! ("open terminal", "switch prompt to email") runs an action from the
+//! action registry instead of being pasted. Phrases may hold `{name}`
+//! placeholders, each standing for one or more words that are passed on to
+//! the action's parameters. The whole dictation must match, ignoring case
+//! and punctuation, so ordinary sentences that merely contain a phrase are
+//! still pasted.
+
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+use crate::settings::VoiceCommandRule;
+
+enum Part {
+    Word(String),
+    Placeholder(String),
+}
+
+/// The rule `text` matches, with the action parameters filled in. When
+/// several rules match, the one with the most literal words wins, so "open
+/// scratchpad" beats "open {app}".
+pub fn find<'a>(
+    text: &str,
+    rules: &'a [VoiceCommandRule],
+) -> Option<(&'a VoiceCommandRule, Map<String, Value>)> {
+    let spoken = words(text);
+    if spoken.is_empty() {
+        return None;
+    }
+    rules
+        .iter()
+        .filter_map(|rule| {
+            let parts = parse(&rule.phrase);
+            let literal = parts
+                .iter()
+                .filter(|part| matches!(part, Part::Word(_)))
+                .count();
+            let mut captures = HashMap::new();
+            match_parts(&parts, &spoken, &mut captures).then(|| (rule, literal, captures))
+        })
+        // First rule wins among equals
+        .rev()
+        .max_by_key(|(_, literal, _)| *literal)
+        .map(|(rule, _, captures)| (rule, params(rule, &captures)))
+}
+
+fn parse(phrase: &str) -> Vec<Part> {
+    phrase
+        .split_whitespace()
+        .filter_map(|token| {
+            match token
+                .strip_prefix('{')
+                .and_then(|rest| rest.strip_suffix('}'))
+            {
+                Some(name) => Some(Part::Placeholder(name.to_string())),
+                None => normalize(token).map(Part::Word),
+            }
+        })
+        .collect()
+}
+
+/// Match `parts` against all of `spoken`, recording what each placeholder
+/// stood for.
+fn match_parts<'t>(
+    parts: &[Part],
+    spoken: &[(String, &'t str)],
+    captures: &mut HashMap<String, Vec<&'t str>>,
+) -> bool {
+    let Some((part, rest)) = parts.split_first() else {
+        return spoken.is_empty();
+    };
+    match part {
+        Part::Word(word) => match spoken.split_first() {
+            Some(((first, _), remaining)) if first == word => {
+                match_parts(rest, remaining, captures)
+            }
+            _ => false,
+        },
+        Part::Placeholder(name) => {
+            // Shortest capture first, so a later literal word can still match
+            for count in 1..=spoken.len() {
+                if match_parts(rest, &spoken[count..], captures) {
+                    let words = spoken[..count].iter().map(|(_, original)| *original);
+                    captures.insert(name.clone(), words.collect());
+                    return true;
+                }
+            }
+            false
+        }
+    }
+}
+
+/// The rule's parameters with placeholders filled in. "true" and "false"
+/// become booleans.
+fn params(rule: &VoiceCommandRule, captures: &HashMap<String, Vec<&str>>) -> Map<String, Value> {
+    rule.params
+        .iter()
+        .map(|(name, template)| {
+            let value = captures
+                .iter()
+                .fold(template.clone(), |value, (placeholder, words)| {
+                    value.replace(&format!("{{{}}}", placeholder), &words.join(" "))
+                });
+            let value = match value.as_str() {
+                "true" => Value::Bool(true),
+                "false" => Value::Bool(false),
+                _ => Value::String(value),
+            };
+            (name.clone(), value)
+        })
+        .collect()
+}
+
+/// Lowercased words of `text`, each with its original spelling minus the
+/// punctuation around it.
+fn words(text: &str) -> Vec<(String, &str)> {
+    text.split_whitespace()
+        .filter_map(|token| {
+            let original = token.trim_matches(|c: char| !c.is_alphanumeric());
+            normalize(original).map(|word| (word, original))
+        })
+        .collect()
+}
+
+fn normalize(word: &str) -> Option<String> {
+    let word = word.trim_matches(|c: char| !c.is_alphanumeric());
+    (!word.is_empty()).then(|| word.to_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn rule(phrase: &str, action: &str, params: &[(&str, &str)]) -> VoiceCommandRule {
+        VoiceCommandRule {
+            phrase: phrase.to_string(),
+            action: action.to_string(),
+            params: params
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+        }
+    }
+
+    fn rules() -> Vec<VoiceCommandRule> {
+        vec![
+            rule("open {app}", "app.launch", &[("name", "{app}")]),
+            rule("open scratchpad", "scratchpad.open", &[]),
+            rule(
+                "switch prompt to {prompt}",
+                "post_processing.select_prompt",
+                &[("id", "{prompt}")],
+            ),
+            rule(
+                "copy scratchpad and clear",
+                "scratchpad.copy",
+                &[("clear", "true")],
+            ),
+        ]
+    }
+
+    #[test]
+    fn placeholders_capture_the_original_words() {
+        let rules = rules();
+        let (rule, params) = find("Open Visual Studio Code.", &rules).unwrap();
+        assert_eq!(rule.action, "app.launch");
+        assert_eq!(
+            Value::Object(params),
+            json!({ "name": "Visual Studio Code" })
+        );
+
+        let (rule, params) = find("Switch prompt to email", &rules).unwrap();
+        assert_eq!(rule.action, "post_processing.select_prompt");
+        assert_eq!(Value::Object(params), json!({ "id": "email" }));
+    }
+
+    #[test]
+    fn literal_phrases_beat_placeholders() {
+        let rules = rules();
+        let (rule, _) = find("Open scratchpad!", &rules).unwrap();
+        assert_eq!(rule.action, "scratchpad.open");
+
+        let (_, params) = find("copy scratchpad and clear", &rules).unwrap();
+        assert_eq!(Value::Object(params), json!({ "clear": true }));
+    }
+
+    #[test]
+    fn only_whole_dictations_match() {
+        let rules = rules();
+        assert!(find("Please open the door for me", &rules).is_none());
+        assert!(find("I need to switch prompt to email later", &rules).is_none());
+        assert!(find("open", &rules).is_none());
+        assert!(find("", &rules).is_none());
+    }
+}
@@ -0,0 +1,132 @@
+//! The app's one system-wide rdev hook.
+//!
+//! rdev keeps a single global callback on Windows and X11, so a second
+//! `rdev::listen` silently replaces the first one's. Everything that needs
+//! raw keyboard and mouse events (the input tracker, shortcut capture)
+//! subscribes here instead. The hook is started by the first subscriber and
+//! stays installed, since rdev can't unhook; with no subscribers it does
+//! nothing.
+
+use log::{error, info};
+use once_cell::sync::Lazy;
+use rdev::{listen, Event};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+type Callback<E> = Box<dyn FnMut(&E) + Send>;
+
+/// Subscribers to events of type `E`, in the order they subscribed.
+struct Subscribers<E> {
+    next_id: u64,
+    callbacks: Vec<(u64, Callback<E>)>,
+}
+
+impl<E> Subscribers<E> {
+    const fn new() -> Self {
+        Self {
+            next_id: 0,
+            callbacks: Vec::new(),
+        }
+    }
+
+    fn add(&mut self, callback: Callback<E>) -> u64 {
+        self.next_id += 1;
+        self.callbacks.push((self.next_id, callback));
+        self.next_id
+    }
+
+    fn remove(&mut self, id: u64) {
+        self.callbacks.retain(|(callback_id, _)| *callback_id != id);
+    }
+
+    fn dispatch(&mut self, event: &E) {
+        for (_, callback) in &mut self.callbacks {
+            callback(event);
+        }
+    }
+}
+
+static SUBSCRIBERS: Lazy<Mutex<Subscribers<Event>>> = Lazy::new(|| Mutex::new(Subscribers::new()));
+
+/// Whether the hook thread is running. Cleared if `rdev::listen` fails, so
+/// the next subscriber tries again.
+static HOOK_RUNNING: AtomicBool = AtomicBool::new(false);
+
+/// Keeps a callback subscribed until dropped.
+pub struct Subscription {
+    id: u64,
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        SUBSCRIBERS.lock().unwrap().remove(self.id);
+    }
+}
+
+/// Call `callback` with every keyboard and mouse event until the returned
+/// subscription is dropped. Callbacks run on the hook thread, one after the
+/// other, and must not subscribe or unsubscribe themselves.
+pub fn subscribe<F>(callback: F) -> Subscription
+where
+    F: FnMut(&Event) + Send + 'static,
+{
+    let id = SUBSCRIBERS.lock().unwrap().add(Box::new(callback));
+    ensure_hook();
+    Subscription { id }
+}
+
+fn ensure_hook() {
+    if HOOK_RUNNING.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    let spawned = thread::Builder::new()
+        .name("input-hook".to_string())
+        .spawn(|| {
+            info!("[InputHook] Starting rdev::listen");
+            let result = listen(|event| SUBSCRIBERS.lock().unwrap().dispatch(&event));
+            if let Err(e) = result {
+                error!("[InputHook] rdev::listen failed: {:?}", e);
+            }
+            HOOK_RUNNING.store(false, Ordering::SeqCst);
+        });
+    if let Err(e) = spawned {
+        error!("[InputHook] Failed to spawn the hook thread: {}", e);
+        HOOK_RUNNING.store(false, Ordering::SeqCst);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[test]
+    fn every_subscriber_sees_each_event_until_removed() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let mut subscribers = Subscribers::new();
+        let tracker = {
+            let seen = seen.clone();
+            subscribers.add(Box::new(move |event: &u32| {
+                seen.lock().unwrap().push(("tracker", *event))
+            }))
+        };
+        let capture = {
+            let seen = seen.clone();
+            subscribers.add(Box::new(move |event: &u32| {
+                seen.lock().unwrap().push(("capture", *event))
+            }))
+        };
+
+        subscribers.dispatch(&1);
+        subscribers.remove(capture);
+        subscribers.dispatch(&2);
+        subscribers.remove(tracker);
+        subscribers.dispatch(&3);
+
+        assert_eq!(
+            *seen.lock().unwrap(),
+            [("tracker", 1), ("capture", 1), ("tracker", 2)]
+        );
+    }
+}
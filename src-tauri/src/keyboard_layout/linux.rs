@@ -0,0 +1,103 @@
+//! X11 layout lookup via XKB. libX11 and libxkbcommon are loaded at runtime,
+//! so the app still starts where they're missing. Under Wayland without
+//! XWayland there is no display to ask, and lookups return `None`.
+
+use super::Level;
+use log::debug;
+use once_cell::sync::Lazy;
+use std::ffi::{c_int, c_uint};
+use std::sync::Mutex;
+use x11_dl::xlib::{Display, XkbStateRec, Xlib};
+use xkbcommon_dl::{xkbcommon_option, XkbCommon};
+
+/// X keycodes. Narrowed to the display's actual range when translating.
+pub const KEY_CODES: std::ops::RangeInclusive<u32> = 8..=255;
+
+const XKB_USE_CORE_KBD: c_uint = 0x0100;
+
+static XLIB: Lazy<Option<Xlib>> = Lazy::new(|| Xlib::open().ok());
+
+struct Connection {
+    xlib: &'static Xlib,
+    xkbcommon: &'static XkbCommon,
+    display: *mut Display,
+    min_keycode: u32,
+    max_keycode: u32,
+}
+
+// The display is only used while holding the mutex
+unsafe impl Send for Connection {}
+
+enum DisplayState {
+    Untried,
+    Open(Connection),
+    /// No libraries or no display; not retried, since lookups run on every
+    /// keystroke.
+    Unavailable,
+}
+
+static DISPLAY: Mutex<DisplayState> = Mutex::new(DisplayState::Untried);
+
+fn open_display() -> Option<Connection> {
+    let xlib = XLIB.as_ref()?;
+    let xkbcommon = xkbcommon_option()?;
+    let display = unsafe { (xlib.XOpenDisplay)(std::ptr::null()) };
+    if display.is_null() {
+        return None;
+    }
+    let (mut min, mut max) = (0, 0);
+    unsafe { (xlib.XDisplayKeycodes)(display, &mut min, &mut max) };
+    Some(Connection {
+        xlib,
+        xkbcommon,
+        display,
+        min_keycode: min as u32,
+        max_keycode: max as u32,
+    })
+}
+
+fn with_display<T>(f: impl FnOnce(&Connection) -> Option<T>) -> Option<T> {
+    let mut state = DISPLAY.lock().unwrap();
+    if let DisplayState::Untried = *state {
+        *state = match open_display() {
+            Some(connection) => DisplayState::Open(connection),
+            None => {
+                debug!("No X display for keyboard layout lookups");
+                DisplayState::Unavailable
+            }
+        };
+    }
+    match &*state {
+        DisplayState::Open(connection) => f(connection),
+        _ => None,
+    }
+}
+
+fn active_group(connection: &Connection) -> Option<c_int> {
+    let mut state: XkbStateRec = unsafe { std::mem::zeroed() };
+    let status =
+        unsafe { (connection.xlib.XkbGetState)(connection.display, XKB_USE_CORE_KBD, &mut state) };
+    (status == 0).then_some(state.group as c_int)
+}
+
+pub fn translate(code: u32, level: Level) -> Option<char> {
+    with_display(|connection| {
+        if code < connection.min_keycode || code > connection.max_keycode {
+            return None;
+        }
+        let group = active_group(connection)?;
+        let shift_level = level.shift as c_int + if level.alt_gr { 2 } else { 0 };
+        let keysym = unsafe {
+            (connection.xlib.XkbKeycodeToKeysym)(connection.display, code as u8, group, shift_level)
+        };
+        if keysym == 0 {
+            return None;
+        }
+        let codepoint = unsafe { (connection.xkbcommon.xkb_keysym_to_utf32)(keysym as u32) };
+        char::from_u32(codepoint).filter(|&c| c != '\0')
+    })
+}
+
+pub fn layout_id() -> Option<u64> {
+    with_display(|connection| active_group(connection).map(|group| group as u64))
+}
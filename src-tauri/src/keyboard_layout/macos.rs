@@ -0,0 +1,133 @@
+//! macOS layout lookup via Text Input Sources and `UCKeyTranslate`.
+//!
+//! TIS calls must happen on the main thread (recent macOS versions abort
+//! otherwise), so [`refresh_layout`] copies the active layout's `uchr` data
+//! there and translation works on the copy from any thread.
+
+use super::Level;
+use std::ffi::c_void;
+use std::hash::{Hash, Hasher};
+use std::sync::Mutex;
+
+/// macOS virtual key codes.
+pub const KEY_CODES: std::ops::RangeInclusive<u32> = 0..=127;
+
+const K_UC_KEY_ACTION_DOWN: u16 = 0;
+const K_UC_KEY_TRANSLATE_NO_DEAD_KEYS_MASK: u32 = 1;
+/// Carbon modifier bits (`shiftKey`, `optionKey`) shifted right by 8, as
+/// `UCKeyTranslate` expects.
+const SHIFT_STATE: u32 = 0x02;
+const OPTION_STATE: u32 = 0x08;
+
+type CFTypeRef = *const c_void;
+
+#[link(name = "Carbon", kind = "framework")]
+extern "C" {
+    static kTISPropertyUnicodeKeyLayoutData: CFTypeRef;
+
+    fn TISCopyCurrentKeyboardLayoutInputSource() -> CFTypeRef;
+    fn TISGetInputSourceProperty(source: CFTypeRef, key: CFTypeRef) -> CFTypeRef;
+    fn LMGetKbdType() -> u8;
+    fn UCKeyTranslate(
+        layout: *const u8,
+        virtual_key_code: u16,
+        key_action: u16,
+        modifier_key_state: u32,
+        keyboard_type: u32,
+        key_translate_options: u32,
+        dead_key_state: *mut u32,
+        max_string_length: usize,
+        actual_string_length: *mut usize,
+        unicode_string: *mut u16,
+    ) -> i32;
+}
+
+#[link(name = "CoreFoundation", kind = "framework")]
+extern "C" {
+    fn CFDataGetBytePtr(data: CFTypeRef) -> *const u8;
+    fn CFDataGetLength(data: CFTypeRef) -> isize;
+    fn CFRelease(cf: CFTypeRef);
+}
+
+struct Layout {
+    data: Vec<u8>,
+    keyboard_type: u32,
+    id: u64,
+}
+
+static LAYOUT: Mutex<Option<Layout>> = Mutex::new(None);
+
+/// Copy the current layout. Must run on the main thread.
+pub fn refresh_layout() {
+    let layout = unsafe {
+        let source = TISCopyCurrentKeyboardLayoutInputSource();
+        if source.is_null() {
+            return;
+        }
+        let data = TISGetInputSourceProperty(source, kTISPropertyUnicodeKeyLayoutData);
+        let bytes = if data.is_null() {
+            None
+        } else {
+            let len = CFDataGetLength(data).max(0) as usize;
+            Some(std::slice::from_raw_parts(CFDataGetBytePtr(data), len).to_vec())
+        };
+        CFRelease(source);
+        bytes.map(|data| Layout {
+            id: {
+                let mut hasher = std::collections::hash_map::DefaultHasher::new();
+                data.hash(&mut hasher);
+                hasher.finish()
+            },
+            data,
+            keyboard_type: LMGetKbdType() as u32,
+        })
+    };
+    // Input methods without key layout data (some CJK ones) keep the last
+    // known layout
+    if let Some(layout) = layout {
+        *LAYOUT.lock().unwrap() = Some(layout);
+    }
+}
+
+pub fn translate(code: u32, level: Level) -> Option<char> {
+    let guard = LAYOUT.lock().unwrap();
+    let layout = guard.as_ref()?;
+
+    let mut modifiers = 0;
+    if level.shift {
+        modifiers |= SHIFT_STATE;
+    }
+    if level.alt_gr {
+        modifiers |= OPTION_STATE;
+    }
+
+    let mut dead_key_state = 0u32;
+    let mut length = 0usize;
+    let mut buffer = [0u16; 4];
+    let status = unsafe {
+        UCKeyTranslate(
+            layout.data.as_ptr(),
+            code as u16,
+            K_UC_KEY_ACTION_DOWN,
+            modifiers,
+            layout.keyboard_type,
+            K_UC_KEY_TRANSLATE_NO_DEAD_KEYS_MASK,
+            &mut dead_key_state,
+            buffer.len(),
+            &mut length,
+            buffer.as_mut_ptr(),
+        )
+    };
+    if status != 0 || length == 0 {
+        return None;
+    }
+    let mut chars = char::decode_utf16(buffer[..length].iter().copied());
+    match (chars.next(), chars.next()) {
+        (Some(Ok(c)), None) => Some(c),
+        _ => None,
+    }
+}
+
+pub fn layout_id() -> Option<u64> {
+    LAYOUT.lock().unwrap().as_ref().map(|layout| layout.id)
+}
@@ -0,0 +1,155 @@
+//! Keyboard-layout-aware character mapping.
+//!
+//! rdev reports physical keys, and the character it attaches to a key event is
+//! missing on some platforms and layouts. This module asks the active OS
+//! keyboard layout (UCKeyTranslate on macOS, ToUnicodeEx on Windows, XKB on
+//! Linux) which character a key produces, and the reverse: which key types a
+//! given character. The input tracker uses it to record what was actually
+//! typed on AZERTY, Dvorak or non-Latin layouts and to recognise shortcuts by
+//! their letter; direct-input paste uses it to type with the keys of the
+//! active layout.
+//!
+//! Key codes are the platform codes rdev reports: virtual key codes on macOS
+//! and Windows, X keycodes on Linux.
+
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use tauri::AppHandle;
+
+#[cfg(target_os = "macos")]
+mod macos;
+#[cfg(target_os = "macos")]
+use macos as platform;
+
+#[cfg(target_os = "windows")]
+mod windows;
+#[cfg(target_os = "windows")]
+use windows as platform;
+
+#[cfg(target_os = "linux")]
+mod linux;
+#[cfg(target_os = "linux")]
+use linux as platform;
+
+/// Fallback for unsupported platforms: no layout information.
+#[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
+mod platform {
+    use super::Level;
+
+    pub const KEY_CODES: std::ops::RangeInclusive<u32> = 0..=0;
+
+    pub fn translate(_code: u32, _level: Level) -> Option<char> {
+        None
+    }
+
+    pub fn layout_id() -> Option<u64> {
+        None
+    }
+}
+
+/// Shift state a character is typed with.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Level {
+    pub shift: bool,
+    /// AltGr on Windows and Linux, Option on macOS.
+    pub alt_gr: bool,
+}
+
+impl Level {
+    /// Levels in the order a character is looked up: the simplest key wins.
+    const ALL: [Level; 4] = [
+        Level {
+            shift: false,
+            alt_gr: false,
+        },
+        Level {
+            shift: true,
+            alt_gr: false,
+        },
+        Level {
+            shift: false,
+            alt_gr: true,
+        },
+        Level {
+            shift: true,
+            alt_gr: true,
+        },
+    ];
+}
+
+/// Reverse lookup table for the layout it was built from.
+struct CharMap {
+    layout_id: u64,
+    keys: HashMap<char, (u32, Level)>,
+}
+
+static CHAR_MAP: Lazy<Mutex<Option<CharMap>>> = Lazy::new(|| Mutex::new(None));
+
+/// Character the key with platform code `code` produces at `level` in the
+/// active layout. Dead keys and keys that produce no text return `None`.
+pub fn char_for_key(code: u32, level: Level) -> Option<char> {
+    platform::translate(code, level).filter(|&c| is_text_char(c))
+}
+
+/// Key and level that type `c` in the active layout, if any.
+pub fn key_for_char(c: char) -> Option<(u32, Level)> {
+    let layout_id = platform::layout_id()?;
+    let mut map = CHAR_MAP.lock().unwrap();
+    if map.as_ref().map(|m| m.layout_id) != Some(layout_id) {
+        *map = Some(CharMap {
+            layout_id,
+            keys: build_char_map(),
+        });
+    }
+    map.as_ref().and_then(|m| m.keys.get(&c).copied())
+}
+
+fn build_char_map() -> HashMap<char, (u32, Level)> {
+    let mut keys = HashMap::new();
+    for level in Level::ALL {
+        for code in platform::KEY_CODES {
+            if let Some(c) = char_for_key(code, level) {
+                keys.entry(c).or_insert((code, level));
+            }
+        }
+    }
+    log::debug!("Keyboard layout maps {} characters", keys.len());
+    keys
+}
+
+/// Re-read the active layout where it is cached (macOS, where it can only be
+/// read on the main thread). Call when the layout may have changed, e.g. when
+/// another app comes to the front.
+pub fn refresh(app: &AppHandle) {
+    #[cfg(target_os = "macos")]
+    {
+        if let Err(e) = app.run_on_main_thread(macos::refresh_layout) {
+            log::warn!("Failed to refresh keyboard layout: {}", e);
+        }
+    }
+    #[cfg(not(target_os = "macos"))]
+    let _ = app;
+}
+
+/// Whether `c` is typed text worth recording. Excludes control characters
+/// (Return and Tab are handled as keys) and the private-use characters macOS
+/// reports for function and arrow keys.
+pub fn is_text_char(c: char) -> bool {
+    !c.is_control() && !matches!(c, '\u{E000}'..='\u{F8FF}')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_chars_include_non_ascii() {
+        for c in ['a', 'Z', ' ', 'é', 'ß', 'ж', '€', '日'] {
+            assert!(is_text_char(c), "{:?} should count as text", c);
+        }
+        for c in ['\r', '\n', '\t', '\u{8}', '\u{1b}', '\u{F702}'] {
+            assert!(!is_text_char(c), "{:?} should not count as text", c);
+        }
+    }
+}
@@ -0,0 +1,62 @@
+//! Windows layout lookup via `ToUnicodeEx`, using the layout of the
+//! foreground window's thread (layouts are per thread on Windows).
+
+use super::Level;
+use windows::Win32::UI::Input::KeyboardAndMouse::{
+    GetKeyboardLayout, MapVirtualKeyExW, ToUnicodeEx, HKL, MAPVK_VK_TO_VSC_EX, VK_CONTROL, VK_MENU,
+    VK_SHIFT,
+};
+use windows::Win32::UI::WindowsAndMessaging::{GetForegroundWindow, GetWindowThreadProcessId};
+
+/// Windows virtual key codes.
+pub const KEY_CODES: std::ops::RangeInclusive<u32> = 0x01..=0xFE;
+
+/// Don't change the keyboard state: without it, translating a dead key
+/// would swallow the next real keystroke.
+const NO_STATE_CHANGE: u32 = 0x4;
+const KEY_DOWN: u8 = 0x80;
+
+fn foreground_layout() -> HKL {
+    unsafe {
+        let thread_id = GetWindowThreadProcessId(GetForegroundWindow(), None);
+        GetKeyboardLayout(thread_id)
+    }
+}
+
+pub fn translate(code: u32, level: Level) -> Option<char> {
+    let layout = foreground_layout();
+    let mut key_state = [0u8; 256];
+    if level.shift {
+        key_state[VK_SHIFT.0 as usize] = KEY_DOWN;
+    }
+    if level.alt_gr {
+        key_state[VK_CONTROL.0 as usize] = KEY_DOWN;
+        key_state[VK_MENU.0 as usize] = KEY_DOWN;
+    }
+
+    let mut buffer = [0u16; 4];
+    let written = unsafe {
+        let scan_code = MapVirtualKeyExW(code, MAPVK_VK_TO_VSC_EX, Some(layout));
+        ToUnicodeEx(
+            code,
+            scan_code,
+            &key_state,
+            &mut buffer,
+            NO_STATE_CHANGE,
+            Some(layout),
+        )
+    };
+    // Negative for dead keys, 0 for keys without a character
+    if written <= 0 {
+        return None;
+    }
+    let mut chars = char::decode_utf16(buffer[..written as usize].iter().copied());
+    match (chars.next(), chars.next()) {
+        (Some(Ok(c)), None) => Some(c),
+        _ => None,
+    }
+}
+
+pub fn layout_id() -> Option<u64> {
+    Some(foreground_layout().0 as usize as u64)
+}
@@ -1,18 +1,26 @@
 #![allow(unexpected_cfgs)] // objc 0.2.x macros emit spurious cfg(feature = "cargo-clippy") checks
 
+mod action_registry;
 mod actions;
 mod audio_feedback;
 pub mod audio_toolkit;
+mod changelog;
 mod clipboard;
+mod cloud_transcription;
 mod commands;
 mod features;
 mod helpers;
+mod input_hook;
+mod keyboard_layout;
 mod llm_client;
 mod logging;
 mod managers;
 mod tools;
 mod overlay;
+mod policy;
+mod profile;
 mod settings;
+mod shutdown;
 #[cfg(unix)]
 mod signal_handle;
 mod startup;
@@ -25,15 +33,31 @@ use features::shortcut;
 
 use env_filter::Builder as EnvFilterBuilder;
 use managers::audio::AudioRecordingManager;
+use managers::automation::AutomationManager;
+use managers::captions::CaptionsManager;
+use managers::confidence_review::ConfidenceReviewManager;
+use managers::data_api::DataApiManager;
+use managers::db_writer::DbWriter;
 use managers::diarization::DiarizationManager;
+use managers::dictation_template::DictationTemplateManager;
+use managers::digest::DigestManager;
+use managers::feature_flags::FeatureFlagManager;
+use managers::focus_session::FocusSessionManager;
 use managers::history::HistoryManager;
+use managers::history_import::HistoryImportManager;
 use managers::input_tracker::InputTrackerManager;
+use managers::maintenance::MaintenanceManager;
 use managers::meeting::MeetingManager;
 use managers::model::ModelManager;
+use managers::noise_profile::NoiseProfileManager;
+use managers::power::PowerManager;
+use managers::scheduled_recording::ScheduledRecordingManager;
+use managers::scratchpad::ScratchpadManager;
+use managers::text_plugins::TextPluginManager;
 use managers::transcription::TranscriptionManager;
 use managers::tts::TtsManager;
+use managers::voice_profile::VoiceProfileManager;
 use startup::show_main_window;
-use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 use tauri::image::Image;
 
@@ -49,14 +73,6 @@ use tauri::{AppHandle, Manager};
 use tauri_plugin_autostart::{MacosLauncher, ManagerExt};
 use tauri_plugin_log::{Builder as LogBuilder, LogLevel, RotationStrategy, Target, TargetKind};
 
-#[derive(Default)]
-struct ShortcutToggleStates {
-    // Map: shortcut_binding_id -> is_active
-    active_toggles: HashMap<String, bool>,
-}
-
-type ManagedToggleState = Mutex<ShortcutToggleStates>;
-
 /// Global flag to track if a file transcription is currently in progress
 static FILE_TRANSCRIPTION_ACTIVE: AtomicBool = AtomicBool::new(false);
 
@@ -81,8 +97,14 @@ fn initialize_core_logic(app_handle: &AppHandle) {
         TranscriptionManager::new(app_handle, model_manager.clone())
             .expect("Failed to initialize transcription manager"),
     );
-    let history_manager =
-        Arc::new(HistoryManager::new(app_handle).expect("Failed to initialize history manager"));
+    // All history and input tracking writes go through a single writer thread
+    let db_writer = Arc::new(DbWriter::new().expect("Failed to start database writer"));
+    let history_manager = Arc::new(
+        HistoryManager::new(app_handle, db_writer.clone())
+            .expect("Failed to initialize history manager"),
+    );
+    let power_manager =
+        Arc::new(PowerManager::new(app_handle).expect("Failed to initialize power manager"));
 
     // Initialize input tracker manager
     let input_tracker_manager = Arc::new(Mutex::new(
@@ -100,26 +122,85 @@ fn initialize_core_logic(app_handle: &AppHandle) {
     let meeting_manager =
         Arc::new(MeetingManager::new(app_handle).expect("Failed to initialize meeting manager"));
 
+    let focus_session_manager = Arc::new(
+        FocusSessionManager::new(app_handle).expect("Failed to initialize focus session manager"),
+    );
+    let digest_manager =
+        Arc::new(DigestManager::new(app_handle).expect("Failed to initialize digest manager"));
+    let maintenance_manager = Arc::new(
+        MaintenanceManager::new(app_handle).expect("Failed to initialize maintenance manager"),
+    );
+
+    let captions_manager = Arc::new(CaptionsManager::new(app_handle));
+    let scratchpad_manager = Arc::new(
+        ScratchpadManager::new(app_handle).expect("Failed to initialize scratchpad manager"),
+    );
+    let dictation_template_manager = Arc::new(DictationTemplateManager::new(app_handle));
+    let confidence_review_manager = Arc::new(ConfidenceReviewManager::new(app_handle));
+    let data_api_manager = Arc::new(DataApiManager::new(app_handle));
+    let feature_flag_manager = Arc::new(FeatureFlagManager::new(app_handle));
+    let scheduled_recording_manager = Arc::new(
+        ScheduledRecordingManager::new(app_handle)
+            .expect("Failed to initialize scheduled recording manager"),
+    );
+    let automation_manager = Arc::new(
+        AutomationManager::new(app_handle).expect("Failed to initialize automation manager"),
+    );
+
     // Initialize diarization manager
     let diarization_manager = Arc::new(
         DiarizationManager::new(app_handle, model_manager.clone())
             .expect("Failed to initialize diarization manager"),
     );
+    let voice_profile_manager = Arc::new(
+        VoiceProfileManager::new(app_handle, model_manager.clone())
+            .expect("Failed to initialize voice profile manager"),
+    );
+    let noise_profile_manager = Arc::new(
+        NoiseProfileManager::new(app_handle).expect("Failed to initialize noise profile manager"),
+    );
+    let history_import_manager = Arc::new(HistoryImportManager::new(app_handle));
+    let text_plugin_manager = Arc::new(
+        TextPluginManager::new(app_handle).expect("Failed to initialize text plugin manager"),
+    );
 
     // Add managers to Tauri's managed state
     app_handle.manage(recording_manager.clone());
     app_handle.manage(model_manager.clone());
     app_handle.manage(transcription_manager.clone());
+    app_handle.manage(db_writer.clone());
     app_handle.manage(history_manager.clone());
+    app_handle.manage(power_manager.clone());
     app_handle.manage(input_tracker_manager.clone());
     app_handle.manage(tts_manager.clone());
     app_handle.manage(meeting_manager.clone());
     app_handle.manage(diarization_manager.clone());
+    app_handle.manage(digest_manager.clone());
+    app_handle.manage(focus_session_manager.clone());
+    app_handle.manage(maintenance_manager.clone());
+    app_handle.manage(captions_manager.clone());
+    app_handle.manage(scratchpad_manager.clone());
+    app_handle.manage(dictation_template_manager.clone());
+    app_handle.manage(confidence_review_manager.clone());
+    app_handle.manage(data_api_manager.clone());
+    app_handle.manage(feature_flag_manager.clone());
+    app_handle.manage(scheduled_recording_manager.clone());
+    app_handle.manage(automation_manager.clone());
+    app_handle.manage(voice_profile_manager.clone());
+    app_handle.manage(noise_profile_manager.clone());
+    app_handle.manage(text_plugin_manager.clone());
+    app_handle.manage(history_import_manager.clone());
 
     // Start input tracker if enabled in settings
     {
         let settings = settings::get_settings(app_handle);
-        if settings.input_tracking_enabled {
+        if settings.privacy_mode {
+            // Recordings a crash interrupted aren't offered for recovery
+            managers::recording_journal::discard_orphaned_journals(
+                history_manager.recordings_dir(),
+            );
+        }
+        if settings.input_tracking_enabled && !settings.privacy_mode {
             if let Ok(mut tracker) = input_tracker_manager.lock() {
                 if let Err(e) = tracker.start(app_handle.clone()) {
                     log::error!("Failed to start input tracker: {}", e);
@@ -181,6 +262,11 @@ fn initialize_core_logic(app_handle: &AppHandle) {
                 show_main_window(app);
                 let _ = app.emit("check-for-updates", ());
             }
+            "scratchpad" => {
+                if let Err(e) = app.state::<Arc<ScratchpadManager>>().open() {
+                    log::error!("Failed to open scratchpad: {}", e);
+                }
+            }
             "cancel" => {
                 use crate::utils::cancel_current_operation;
 
@@ -275,7 +361,8 @@ async fn validate_and_transcribe_file(app: AppHandle, file_path: PathBuf) -> Res
 
     // Validate file extension
     let valid_extensions = [
-        "wav", "wave", "mp3", "m4a", "aac", "ogg", "oga", "mp4", "mov", "avi", "mkv", "webm", "flv",
+        "wav", "wave", "flac", "mp3", "m4a", "aac", "ogg", "oga", "mp4", "mov", "avi", "mkv",
+        "webm", "flv",
     ];
     let extension = file_path
         .extension()
@@ -287,7 +374,7 @@ async fn validate_and_transcribe_file(app: AppHandle, file_path: PathBuf) -> Res
         let error_payload = serde_json::json!({
             "title": "Unsupported File Format",
             "message": format!("The file format '.{}' is not supported.", extension),
-            "details": "Supported formats: Audio (wav, mp3, m4a, ogg) and Video (mp4, mov, mkv, webm)"
+            "details": "Supported formats: Audio (wav, flac, mp3, m4a, ogg) and Video (mp4, mov, mkv, webm)"
         });
         let _ = app.emit("show-error-dialog", error_payload);
         return Err(format!("Unsupported file format: .{}", extension));
@@ -332,7 +419,8 @@ async fn validate_and_transcribe_file_icon_drop(
 
     // Validate file extension
     let valid_extensions = [
-        "wav", "wave", "mp3", "m4a", "aac", "ogg", "oga", "mp4", "mov", "avi", "mkv", "webm", "flv",
+        "wav", "wave", "flac", "mp3", "m4a", "aac", "ogg", "oga", "mp4", "mov", "avi", "mkv",
+        "webm", "flv",
     ];
     let extension = file_path
         .extension()
@@ -345,7 +433,7 @@ async fn validate_and_transcribe_file_icon_drop(
         let error_payload = serde_json::json!({
             "title": "Unsupported File Format",
             "message": format!("The file format '.{}' is not supported.", extension),
-            "details": "Supported formats: Audio (wav, mp3, m4a, ogg) and Video (mp4, mov, mkv, webm)"
+            "details": "Supported formats: Audio (wav, flac, mp3, m4a, ogg) and Video (mp4, mov, mkv, webm)"
         });
         let _ = app.emit("show-error-dialog", error_payload);
         startup::show_main_window(&app);
@@ -375,6 +463,9 @@ async fn validate_and_transcribe_file_icon_drop(
 }
 
 pub fn run() {
+    helpers::pipeline_trace::install();
+    managers::input_tracker::install_panic_hook();
+
     tauri::Builder::default()
         .plugin(tauri_plugin_single_instance::init(|app, args, _cwd| {
             // Check if any arguments look like file paths (icon drops)
@@ -452,9 +543,12 @@ pub fn run() {
                 ])
                 .build(),
         )
-        .manage(Mutex::new(ShortcutToggleStates::default()))
         .manage(Mutex::new(startup::StartupState::default()))
         .setup(move |app| {
+            // Everything below reads the active profile's settings and data
+            profile::init(app.handle());
+            policy::init(app.handle());
+            keyboard_layout::refresh(app.handle());
             let settings = settings::get_settings(&app.handle());
             logging::set_debug_logging(settings.debug_logging_enabled);
             // Set initial file log level from settings
@@ -611,6 +705,7 @@ pub fn run() {
             shortcut::bindings::reset_binding,
             shortcut::bindings::suspend_binding,
             shortcut::bindings::resume_binding,
+            shortcut::capture::capture_next_shortcut,
             shortcut::check_wayland_shortcut_conflict,
             shortcut::is_wayland_session,
             shortcut::get_wayland_shortcuts,
@@ -620,22 +715,63 @@ pub fn run() {
             shortcut::escape::unregister_escape_shortcut,
             // Audio settings commands
             shortcut::settings::audio::change_ptt_setting,
+            shortcut::settings::audio::change_ptt_release_debounce_setting,
             shortcut::settings::audio::change_audio_feedback_setting,
             shortcut::settings::audio::change_audio_feedback_volume_setting,
             shortcut::settings::audio::change_sound_theme_setting,
             shortcut::settings::audio::change_mute_while_recording_setting,
+            shortcut::settings::audio::change_auto_stop_silence_setting,
+            shortcut::settings::audio::change_max_recording_setting,
+            shortcut::settings::audio::change_recording_format_setting,
+            shortcut::settings::audio::change_always_on_idle_release_setting,
+            shortcut::settings::audio::change_min_recording_duration_setting,
+            shortcut::settings::audio::change_recording_countdown_setting,
+            shortcut::settings::audio::change_thread_priorities_setting,
+            shortcut::settings::audio::change_vad_trim_setting,
+            shortcut::settings::audio::change_visual_feedback_setting,
+            shortcut::settings::audio::change_haptic_feedback_setting,
+            shortcut::settings::audio::change_accessibility_announcements_setting,
+            shortcut::settings::audio::change_mute_warning_setting,
             // General settings commands
             shortcut::settings::general::change_start_hidden_setting,
             shortcut::settings::general::change_autostart_setting,
             shortcut::settings::general::change_translate_to_english_setting,
+            shortcut::settings::general::change_privacy_mode_setting,
             shortcut::settings::general::change_selected_language_setting,
+            shortcut::settings::general::change_context_prompt_setting,
+            shortcut::settings::general::change_initial_prompt_setting,
+            shortcut::settings::general::change_initial_prompt_custom_words_setting,
+            shortcut::settings::general::change_initial_prompt_active_app_setting,
+            shortcut::settings::general::get_locale_formatting_rules,
+            shortcut::settings::general::change_locale_formatting_setting,
+            shortcut::settings::general::get_text_normalization_rules,
+            shortcut::settings::general::change_text_normalization_setting,
+            shortcut::settings::general::change_profanity_filter_setting,
+            shortcut::settings::general::set_profanity_words,
+            shortcut::settings::general::change_punctuation_restoration_setting,
+            shortcut::settings::general::change_dictation_commands_enabled_setting,
+            shortcut::settings::general::set_dictation_commands,
+            shortcut::settings::general::change_voice_command_rules_enabled_setting,
+            shortcut::settings::general::set_voice_command_rules,
             shortcut::settings::general::change_overlay_position_setting,
+            shortcut::settings::general::change_overlay_shortcut_hint_setting,
+            shortcut::settings::general::change_respect_focus_mode_setting,
+            shortcut::settings::general::change_battery_saver_setting,
+            shortcut::settings::general::change_battery_model_setting,
             shortcut::settings::general::change_debug_mode_setting,
             shortcut::settings::general::change_debug_logging_setting,
             shortcut::settings::general::change_word_correction_threshold_setting,
+            shortcut::settings::general::change_low_confidence_review_setting,
+            shortcut::settings::general::change_low_confidence_threshold_setting,
+            shortcut::settings::general::change_streaming_target_latency_setting,
             shortcut::settings::general::change_paste_method_setting,
             shortcut::settings::general::change_clipboard_handling_setting,
+            shortcut::settings::general::change_verify_paste_target_setting,
+            shortcut::settings::general::change_no_paste_apps_setting,
+            shortcut::settings::general::change_no_paste_windows_setting,
             shortcut::settings::general::update_custom_words,
+            shortcut::settings::general::set_app_custom_words,
+            shortcut::settings::general::change_learn_from_corrections_setting,
             // Post-process settings commands
             shortcut::settings::post_process::change_post_process_base_url_setting,
             shortcut::settings::post_process::change_post_process_enabled_setting,
@@ -649,21 +785,35 @@ pub fn run() {
             shortcut::settings::post_process::set_post_process_selected_prompt,
             shortcut::settings::post_process::check_model_tool_support,
             shortcut::settings::post_process::change_voice_commands_enabled_setting,
+            shortcut::settings::post_process::change_voice_prompt_switching_setting,
+            // Cloud transcription settings commands
+            shortcut::settings::cloud_transcription::change_cloud_transcription_setting,
+            shortcut::settings::cloud_transcription::set_transcription_provider,
+            shortcut::settings::cloud_transcription::change_transcription_api_key_setting,
+            shortcut::settings::cloud_transcription::change_transcription_model_setting,
             // Input tracking settings commands
             shortcut::settings::input_tracking::change_input_tracking_setting,
             shortcut::settings::input_tracking::change_input_tracking_excluded_apps,
+            shortcut::settings::input_tracking::change_input_tracking_excluded_windows,
+            shortcut::settings::input_tracking::change_input_tracking_capture_paste,
             shortcut::settings::input_tracking::change_input_tracking_idle_timeout,
             trigger_update_check,
             startup::mark_frontend_ready,
             commands::cancel_operation,
+            commands::skip_post_processing,
             commands::get_app_dir_path,
             commands::open_recordings_folder,
+            commands::power::get_power_state,
+            commands::diagnostics::run_self_test,
+            commands::diagnostics::get_app_runtime_info,
+            commands::diagnostics::run_pipeline_fixture,
             commands::models::get_available_models,
             commands::models::get_model_info,
             commands::models::download_model,
             commands::models::delete_model,
             commands::models::cancel_download,
             commands::models::set_active_model,
+            commands::models::set_preview_model,
             commands::models::get_current_model,
             commands::models::get_transcription_model_status,
             commands::models::is_model_loading,
@@ -672,7 +822,9 @@ pub fn run() {
             commands::models::get_recommended_first_model,
             commands::audio::update_microphone_mode,
             commands::audio::get_microphone_mode,
+            commands::audio::get_active_recordings,
             commands::audio::get_available_microphones,
+            commands::audio::score_input_devices,
             commands::audio::set_selected_microphone,
             commands::audio::get_selected_microphone,
             commands::audio::set_clamshell_microphone,
@@ -680,23 +832,48 @@ pub fn run() {
             commands::audio::get_available_output_devices,
             commands::audio::set_selected_output_device,
             commands::audio::get_selected_output_device,
+            commands::audio::set_feedback_sound_device,
             commands::audio::play_test_sound,
             commands::audio::check_custom_sounds,
             helpers::clamshell::is_clamshell,
             helpers::clamshell::is_laptop,
+            helpers::focus_mode::get_focus_mode_state,
+            helpers::event_throttle::set_event_rate_limit,
+            helpers::pipeline_trace::get_pipeline_traces,
+            helpers::native_indicator::get_overlay_error,
             commands::transcription::set_model_unload_timeout,
+            commands::transcription::set_model_warm_hours,
+            commands::transcription::get_inference_backends,
+            commands::transcription::set_inference_backend,
             commands::transcription::get_model_load_status,
+            commands::transcription::get_engine_status,
+            commands::transcription::bench_model,
             commands::transcription::unload_model_manually,
+            commands::transcription::get_transcription_jobs,
+            commands::transcription::cancel_transcription_job,
             commands::history::get_history_entries,
             commands::history::toggle_history_entry_saved,
             commands::history::get_audio_file_path,
             commands::history::delete_history_entry,
             commands::history::retranscribe_history_entry,
+            commands::history::get_crashed_recordings,
+            commands::history::recover_crashed_recordings,
+            commands::history::discard_crashed_recordings,
             commands::history::reprocess_history_entry,
+            commands::history::update_history_entry_text,
             commands::history::update_history_limit,
             commands::history::update_recording_retention_period,
+            commands::history::change_import_watch_folder_setting,
+            commands::history::import_history_bundle,
+            commands::history::update_source_retention_period,
+            commands::history::update_tag_retention_period,
+            commands::history::get_entry_edits,
+            commands::history::get_entry_edit_stats,
+            commands::history::get_history_speaker_segments,
+            commands::history::export_history_entry,
             commands::file_transcription::transcribe_audio_file,
             commands::input_tracking::get_input_entries,
+            commands::input_tracking::export_input_entries,
             commands::input_tracking::delete_input_entry,
             commands::input_tracking::clear_all_input_entries,
             commands::input_tracking::get_installed_apps,
@@ -704,12 +881,15 @@ pub fn run() {
             commands::open_log_dir,
             commands::set_log_level,
             features::shortcut::settings::tts::change_tts_enabled_setting,
+            features::shortcut::settings::tts::set_tts_pronunciations,
             // Meeting settings commands
             shortcut::settings::meeting::change_meeting_system_audio_setting,
             shortcut::settings::meeting::change_meeting_system_audio_device_setting,
             shortcut::settings::meeting::change_meeting_auto_summary_setting,
             shortcut::settings::meeting::change_meeting_chunk_duration_setting,
+            shortcut::settings::meeting::change_interview_mode_setting,
             shortcut::settings::meeting::change_meeting_diarization_setting,
+            shortcut::settings::meeting::change_file_diarization_setting,
             shortcut::settings::meeting::get_diarization_status,
             // Meeting commands
             commands::meeting::start_meeting,
@@ -725,8 +905,101 @@ pub fn run() {
             commands::meeting::is_system_audio_available,
             commands::meeting::get_meeting_audio_path,
             commands::meeting::retranscribe_meeting,
+            // Low-confidence review commands
+            commands::confidence_review::resolve_low_confidence,
+            // Dictation template commands
+            commands::dictation_template::save_dictation_template,
+            commands::dictation_template::delete_dictation_template,
+            commands::dictation_template::start_dictation_template,
+            commands::dictation_template::cancel_dictation_template,
+            commands::dictation_template::get_dictation_template_progress,
+            // Scheduled recording commands
+            commands::scheduled_recording::schedule_recording,
+            commands::scheduled_recording::list_scheduled_recordings,
+            commands::scheduled_recording::cancel_scheduled_recording,
+            commands::scheduled_recording::delete_scheduled_recording,
+            commands::automation::list_automation_rules,
+            commands::automation::create_automation_rule,
+            commands::automation::update_automation_rule,
+            commands::automation::delete_automation_rule,
+            commands::text_plugins::list_text_plugins,
+            commands::text_plugins::reload_text_plugins,
+            commands::text_plugins::install_text_plugin,
+            commands::text_plugins::remove_text_plugin,
+            commands::text_plugins::set_text_plugin_enabled,
+            commands::text_plugins::run_text_plugin,
+            commands::text_plugins::open_text_plugins_folder,
+            // Live captions commands
+            commands::captions::start_live_captions,
+            commands::captions::stop_live_captions,
+            commands::captions::get_live_captions_status,
+            commands::captions::get_caption_history,
+            commands::captions::get_interview_questions,
+            commands::captions::clear_caption_history,
+            // Scratchpad commands
+            commands::scratchpad::open_scratchpad,
+            commands::scratchpad::get_scratchpad,
+            commands::scratchpad::scratchpad_append,
+            commands::scratchpad::scratchpad_replace,
+            commands::scratchpad::copy_scratchpad,
+            // Data API commands
+            shortcut::settings::data_api::change_data_api_setting,
+            shortcut::settings::data_api::change_data_api_port_setting,
+            shortcut::settings::data_api::change_data_api_redacted_fields_setting,
+            commands::data_api::get_data_api_status,
+            commands::data_api::regenerate_data_api_token,
+            // Feature flag commands
+            commands::feature_flags::get_feature_flags,
+            commands::feature_flags::set_feature_flag,
+            commands::feature_flags::get_changelog_since,
+            // Profile commands
+            profile::list_profiles,
+            profile::get_active_profile,
+            profile::get_settings_store_path,
+            profile::switch_profile,
+            policy::get_locked_settings,
             commands::tts::preview_tts,
+            // Daily digest commands
+            shortcut::settings::digest::change_daily_digest_setting,
+            shortcut::settings::digest::change_daily_digest_folder_setting,
+            shortcut::settings::digest::change_daily_digest_llm_summary_setting,
+            commands::digest::generate_daily_digest,
+            commands::digest::get_daily_digest,
+            commands::digest::list_daily_digests,
+            // Database maintenance commands
+            commands::maintenance::optimize_database,
+            // Focus session commands
+            commands::focus_session::start_focus_session,
+            commands::focus_session::stop_focus_session,
+            commands::focus_session::get_active_focus_session,
+            commands::focus_session::list_focus_sessions,
+            commands::focus_session::get_focus_session_stats,
+            commands::focus_session::get_focus_session_transcriptions,
+            // Voice profile commands
+            shortcut::settings::audio::change_voice_filter_setting,
+            commands::voice_profile::get_voice_profile_status,
+            commands::voice_profile::start_voice_enrollment,
+            commands::voice_profile::finish_voice_enrollment,
+            commands::voice_profile::cancel_voice_enrollment,
+            commands::voice_profile::delete_voice_profile,
+            // Noise profile commands
+            shortcut::settings::audio::change_active_noise_profile_setting,
+            shortcut::settings::audio::change_noise_suppression_setting,
+            shortcut::settings::audio::change_input_gain_setting,
+            shortcut::settings::audio::change_auto_gain_setting,
+            shortcut::settings::audio::change_pre_roll_setting,
+            commands::noise_profile::get_noise_profiles,
+            commands::noise_profile::calibrate_noise_profile,
+            commands::noise_profile::delete_noise_profile,
+            // Action registry commands
+            commands::action_registry::list_actions,
+            commands::action_registry::invoke_action,
         ])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|app, event| {
+            if let tauri::RunEvent::Exit = event {
+                shutdown::run(app);
+            }
+        });
 }
@@ -1,4 +1,4 @@
-use crate::settings::PostProcessProvider;
+use crate::settings::{AppSettings, PostProcessProvider};
 use async_openai::{config::OpenAIConfig, Client};
 
 /// Create an OpenAI-compatible client configured for the given provider
@@ -40,3 +40,64 @@ pub fn create_client(
 
     Ok(client)
 }
+
+/// Send a system + user prompt to the active post-processing provider and
+/// return the reply text.
+pub async fn complete_with_active_provider(
+    settings: &AppSettings,
+    system_prompt: &str,
+    user_prompt: String,
+) -> Result<String, String> {
+    use async_openai::types::{
+        ChatCompletionRequestSystemMessageArgs, ChatCompletionRequestUserMessageArgs,
+        CreateChatCompletionRequestArgs,
+    };
+
+    let provider = settings
+        .active_post_process_provider()
+        .ok_or_else(|| "No post-processing provider configured".to_string())?
+        .clone();
+    let api_key = settings
+        .post_process_api_keys
+        .get(&provider.id)
+        .cloned()
+        .unwrap_or_default();
+    let model = settings
+        .post_process_models
+        .get(&provider.id)
+        .cloned()
+        .unwrap_or_default();
+    if model.is_empty() {
+        return Err("No post-processing model configured".to_string());
+    }
+
+    let client = create_client(&provider, api_key)?;
+    let request = CreateChatCompletionRequestArgs::default()
+        .model(&model)
+        .messages(vec![
+            ChatCompletionRequestSystemMessageArgs::default()
+                .content(system_prompt)
+                .build()
+                .map_err(|e| e.to_string())?
+                .into(),
+            ChatCompletionRequestUserMessageArgs::default()
+                .content(user_prompt)
+                .build()
+                .map_err(|e| e.to_string())?
+                .into(),
+        ])
+        .build()
+        .map_err(|e| e.to_string())?;
+
+    let response = client
+        .chat()
+        .create(request)
+        .await
+        .map_err(|e| format!("LLM request failed: {}", e))?;
+
+    Ok(response
+        .choices
+        .first()
+        .and_then(|c| c.message.content.clone())
+        .unwrap_or_default())
+}
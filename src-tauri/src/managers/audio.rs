@@ -1,14 +1,19 @@
 use crate::actions::OPERATION_GENERATION;
+use crate::audio_toolkit::audio::{spawn_hotplug_watcher, DeviceChanges, HotplugWatcher};
 use crate::audio_toolkit::{list_input_devices, vad::SmoothedVad, AudioRecorder, SileroVad};
-use crate::helpers::clamshell;
+use crate::helpers::{clamshell, input_mute};
+use crate::managers::history::{HistoryManager, RecordingMode, RecordingSource};
+use crate::managers::recording_journal::RecordingJournal;
 use crate::managers::transcription::TranscriptionManager;
+use crate::managers::voice_profile::ENROLLMENT_BINDING_ID;
 use crate::settings::{get_settings, AppSettings};
 use crate::utils;
-use log::{debug, info};
+use log::{debug, info, warn};
+use serde::Serialize;
 use std::sync::atomic::Ordering;
 use std::sync::{Arc, Mutex};
-use std::time::Instant;
-use tauri::Manager;
+use std::time::{Duration, Instant};
+use tauri::{Emitter, Manager};
 
 fn set_mute(mute: bool) {
     #[cfg(target_os = "windows")]
@@ -88,6 +93,121 @@ fn set_mute(mute: bool) {
 
 const WHISPER_SAMPLE_RATE: usize = 16000;
 
+/// How often the auto-stop watcher checks for trailing silence.
+const SILENCE_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// How often the duration guard checks the length of the recording.
+const DURATION_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// How long before the maximum duration the overlay warns that it's coming.
+const DURATION_WARNING: Duration = Duration::from_secs(30);
+
+/// How much input the mute monitor waits for before judging the signal.
+const MUTE_CHECK_DELAY: Duration = Duration::from_millis(700);
+
+/// How often the always-on stream is checked for having sat idle too long.
+const IDLE_RELEASE_POLL_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Audio kept from before the shortcut while the always-on stream is open.
+const PRE_ROLL: Duration = Duration::from_millis(2500);
+
+/// Returns true once speech has been heard and the silence since the last
+/// speech frame reaches `threshold`. Silence before any speech never triggers.
+fn trailing_silence_exceeded(
+    last_speech: Option<Instant>,
+    now: Instant,
+    threshold: Duration,
+) -> bool {
+    match last_speech {
+        Some(at) => now.saturating_duration_since(at) >= threshold,
+        None => false,
+    }
+}
+
+/// Recordings from the always-on stream are marked as such whatever the
+/// shortcut mode.
+fn recording_mode(always_on: bool, push_to_talk: bool) -> RecordingMode {
+    if always_on {
+        RecordingMode::AlwaysOn
+    } else if push_to_talk {
+        RecordingMode::PushToTalk
+    } else {
+        RecordingMode::Toggle
+    }
+}
+
+/// The pending binding, then the recording one if it's another.
+fn active_binding_ids(pending: Option<String>, state: &RecordingState) -> Vec<String> {
+    let mut active: Vec<String> = pending.into_iter().collect();
+    if let RecordingState::Recording { binding_id } = state {
+        if !active.contains(binding_id) {
+            active.push(binding_id.clone());
+        }
+    }
+    active
+}
+
+/// What the duration guard does after a recording has run for a while.
+#[derive(Debug, PartialEq)]
+enum DurationCheck {
+    Continue,
+    /// Warn that the recording stops in `remaining_secs`.
+    Warn {
+        remaining_secs: u64,
+    },
+    Stop,
+}
+
+/// Check a recording that has run `elapsed` against its `limit`. The warning
+/// is given once, `DURATION_WARNING` before the limit.
+fn check_duration(elapsed: Duration, limit: Duration, warned: bool) -> DurationCheck {
+    if elapsed >= limit {
+        DurationCheck::Stop
+    } else if !warned && elapsed + DURATION_WARNING >= limit {
+        DurationCheck::Warn {
+            remaining_secs: (limit - elapsed).as_secs(),
+        }
+    } else {
+        DurationCheck::Continue
+    }
+}
+
+/// Time without a recording after which the always-on stream is released,
+/// or None to keep it open.
+fn idle_release_limit(minutes: Option<u64>) -> Option<Duration> {
+    minutes
+        .filter(|&m| m > 0)
+        .map(|m| Duration::from_secs(m * 60))
+}
+
+/// Whether the always-on stream has gone `limit` without a recording and can
+/// be released: nothing is recording or about to, and it's still open.
+fn idle_release_due(
+    mode: &MicrophoneMode,
+    state: &RecordingState,
+    pending_binding: bool,
+    open: bool,
+    idle_for: Duration,
+    limit: Duration,
+) -> bool {
+    matches!(mode, MicrophoneMode::AlwaysOn)
+        && matches!(state, RecordingState::Idle)
+        && !pending_binding
+        && open
+        && idle_for >= limit
+}
+
+/// Sent with `device-changed` when the open input device was unplugged and
+/// the stream moved to the default one.
+#[derive(Clone, Debug, Serialize)]
+struct DeviceChanged {
+    previous: String,
+    current: Option<String>,
+    /// Whether a recording carried on across the switch.
+    recording: bool,
+    message: String,
+}
+
 /* ──────────────────────────────────────────────────────────────── */
 
 #[derive(Clone, Debug)]
@@ -107,6 +227,8 @@ pub enum MicrophoneMode {
 fn create_audio_recorder(
     vad_path: &str,
     app_handle: &tauri::AppHandle,
+    last_speech: Arc<Mutex<Option<Instant>>>,
+    input_peak: Arc<Mutex<f32>>,
 ) -> Result<AudioRecorder, anyhow::Error> {
     let silero = SileroVad::new(vad_path, 0.3)
         .map_err(|e| anyhow::anyhow!("Failed to create SileroVad: {}", e))?;
@@ -122,7 +244,29 @@ fn create_audio_recorder(
             move |levels| {
                 utils::emit_levels(&app_handle, &levels);
             }
-        });
+        })
+        .with_speech_callback(move |is_speech| {
+            if is_speech {
+                *last_speech.lock().unwrap() = Some(Instant::now());
+            }
+        })
+        .with_peak_callback(move |peak| {
+            let mut loudest = input_peak.lock().unwrap();
+            *loudest = loudest.max(peak);
+        })
+        .with_noise_suppression({
+            let app_handle = app_handle.clone();
+            move || get_settings(&app_handle).noise_suppression_enabled
+        })
+        .with_input_gain({
+            let app_handle = app_handle.clone();
+            move || get_settings(&app_handle).input_gain()
+        })
+        .with_pre_roll(PRE_ROLL, {
+            let app_handle = app_handle.clone();
+            move || get_settings(&app_handle).pre_roll_enabled
+        })
+        .with_realtime_priority(get_settings(app_handle).thread_priorities_enabled);
 
     Ok(recorder)
 }
@@ -139,6 +283,24 @@ pub struct AudioRecordingManager {
     is_open: Arc<Mutex<bool>>,
     is_recording: Arc<Mutex<bool>>,
     did_mute: Arc<Mutex<bool>>,
+    /// Time of the last VAD speech frame in the current recording.
+    last_speech: Arc<Mutex<Option<Instant>>>,
+    /// Loudest raw input sample in the current recording.
+    input_peak: Arc<Mutex<f32>>,
+    recording_started_at: Arc<Mutex<Option<Instant>>>,
+    /// Binding whose recording is about to start (e.g. during the countdown).
+    pending_binding: Arc<Mutex<Option<String>>>,
+    /// Device, rate and mode of the current or last recording.
+    recording_source: Arc<Mutex<RecordingSource>>,
+    /// Audio of the current recording from an input device that was
+    /// unplugged, to go before what the new device records.
+    carried_samples: Arc<Mutex<Vec<f32>>>,
+    /// Copy of the current recording on disk, in case the app crashes.
+    journal: Arc<Mutex<Option<RecordingJournal>>>,
+    /// When the stream was last opened or a recording last started or ended.
+    last_activity: Arc<Mutex<Instant>>,
+    /// Watches for the stream's device being unplugged while it's open.
+    hotplug_watcher: Arc<Mutex<Option<HotplugWatcher>>>,
 }
 
 impl AudioRecordingManager {
@@ -161,8 +323,19 @@ impl AudioRecordingManager {
             is_open: Arc::new(Mutex::new(false)),
             is_recording: Arc::new(Mutex::new(false)),
             did_mute: Arc::new(Mutex::new(false)),
+            last_speech: Arc::new(Mutex::new(None)),
+            input_peak: Arc::new(Mutex::new(0.0)),
+            recording_started_at: Arc::new(Mutex::new(None)),
+            pending_binding: Arc::new(Mutex::new(None)),
+            recording_source: Arc::new(Mutex::new(RecordingSource::default())),
+            carried_samples: Arc::new(Mutex::new(Vec::new())),
+            journal: Arc::new(Mutex::new(None)),
+            last_activity: Arc::new(Mutex::new(Instant::now())),
+            hotplug_watcher: Arc::new(Mutex::new(None)),
         };
 
+        manager.spawn_idle_release_watcher();
+
         // Always-on?  Open immediately.
         if matches!(mode, MicrophoneMode::AlwaysOn) {
             manager.start_microphone_stream()?;
@@ -197,7 +370,12 @@ impl AudioRecordingManager {
         debug!("Preloading VAD model from {:?}", vad_path);
 
         // Create recorder (this loads the heavy VAD model)
-        let recorder = create_audio_recorder(vad_path.to_str().unwrap(), &self.app_handle)?;
+        let recorder = create_audio_recorder(
+            vad_path.to_str().unwrap(),
+            &self.app_handle,
+            self.last_speech.clone(),
+            self.input_peak.clone(),
+        )?;
 
         // Store it if not already set by start_microphone_stream
         let mut recorder_guard = self.recorder.lock().unwrap();
@@ -288,6 +466,8 @@ impl AudioRecordingManager {
             *recorder_opt = Some(create_audio_recorder(
                 vad_path.to_str().unwrap(),
                 &self.app_handle,
+                self.last_speech.clone(),
+                self.input_peak.clone(),
             )?);
         }
 
@@ -301,6 +481,15 @@ impl AudioRecordingManager {
         }
 
         *open_flag = true;
+        self.touch_activity();
+        // Still running when the stream is reopened on another device
+        let mut hotplug_watcher = self.hotplug_watcher.lock().unwrap();
+        if hotplug_watcher.is_none() {
+            let manager = self.clone();
+            *hotplug_watcher = Some(spawn_hotplug_watcher(move |changes| {
+                manager.handle_device_changes(&changes)
+            }));
+        }
         info!(
             "Microphone stream initialized in {:?}",
             start_time.elapsed()
@@ -310,6 +499,7 @@ impl AudioRecordingManager {
 
     pub fn stop_microphone_stream(&self) {
         self.remove_mute();
+        self.hotplug_watcher.lock().unwrap().take();
 
         let mut open_flag = self.is_open.lock().unwrap();
         if !*open_flag {
@@ -329,6 +519,48 @@ impl AudioRecordingManager {
         debug!("Microphone stream stopped");
     }
 
+    pub fn is_microphone_open(&self) -> bool {
+        *self.is_open.lock().unwrap()
+    }
+
+    fn touch_activity(&self) {
+        *self.last_activity.lock().unwrap() = Instant::now();
+    }
+
+    /// Close the always-on stream once it has gone the configured time
+    /// without a recording, so the system's microphone indicator goes off.
+    /// The next recording opens it again.
+    fn spawn_idle_release_watcher(&self) {
+        let manager = self.clone();
+        std::thread::spawn(move || loop {
+            std::thread::sleep(IDLE_RELEASE_POLL_INTERVAL);
+            let settings = get_settings(&manager.app_handle);
+            if let Some(limit) = idle_release_limit(settings.always_on_idle_release_minutes) {
+                manager.release_if_idle(limit);
+            }
+        });
+    }
+
+    fn release_if_idle(&self, limit: Duration) {
+        let mode = self.mode.lock().unwrap().clone();
+        // Holding the state keeps a recording from starting meanwhile
+        let state = self.state.lock().unwrap();
+        let due = idle_release_due(
+            &mode,
+            &state,
+            self.pending_binding.lock().unwrap().is_some(),
+            self.is_microphone_open(),
+            self.last_activity.lock().unwrap().elapsed(),
+            limit,
+        );
+        if !due {
+            return;
+        }
+        info!("Releasing the always-on microphone after {limit:?} without a recording");
+        self.stop_microphone_stream();
+        drop(state);
+    }
+
     /* ---------- mode switching --------------------------------------------- */
 
     pub fn update_mode(&self, new_mode: MicrophoneMode) -> Result<(), anyhow::Error> {
@@ -357,22 +589,35 @@ impl AudioRecordingManager {
         let mut state = self.state.lock().unwrap();
 
         if let RecordingState::Idle = *state {
-            // Ensure microphone is open in on-demand mode
-            if matches!(*self.mode.lock().unwrap(), MicrophoneMode::OnDemand) {
-                if let Err(e) = self.start_microphone_stream() {
-                    log::error!("Failed to open microphone stream: {e}");
-                    return false;
-                }
+            // Ensure microphone is open in on-demand mode, or after the
+            // always-on stream was released for being idle
+            if let Err(e) = self.start_microphone_stream() {
+                log::error!("Failed to open microphone stream: {e}");
+                return false;
             }
 
             if let Some(rec) = self.recorder.lock().unwrap().as_ref() {
                 let (chunk_tx, chunk_rx) = std::sync::mpsc::channel();
+                *self.last_speech.lock().unwrap() = None;
+                *self.input_peak.lock().unwrap() = 0.0;
+                self.carried_samples.lock().unwrap().clear();
 
                 if rec.start(Some(chunk_tx)).is_ok() {
+                    self.touch_activity();
                     *self.is_recording.lock().unwrap() = true;
+                    *self.recording_started_at.lock().unwrap() = Some(Instant::now());
                     *state = RecordingState::Recording {
                         binding_id: binding_id.to_string(),
                     };
+                    let always_on = matches!(*self.mode.lock().unwrap(), MicrophoneMode::AlwaysOn);
+                    *self.recording_source.lock().unwrap() = RecordingSource {
+                        device: rec.device_name(),
+                        sample_rate: rec.sample_rate(),
+                        mode: Some(recording_mode(
+                            always_on,
+                            get_settings(&self.app_handle).push_to_talk,
+                        )),
+                    };
                     debug!("Recording started for binding {binding_id}");
 
                     // Start streaming thread
@@ -387,14 +632,30 @@ impl AudioRecordingManager {
                     let generation = OPERATION_GENERATION.load(Ordering::SeqCst);
                     tm.start_streaming(generation);
 
-                    let binding_id_clone = binding_id.to_string();
-                    std::thread::spawn(move || {
-                        debug!("Streaming thread started for binding {}", binding_id_clone);
-                        while let Ok(chunk) = chunk_rx.recv() {
-                            tm.handle_streaming_chunk(chunk, generation);
+                    self.open_journal(binding_id);
+                    self.spawn_chunk_consumer(chunk_rx, generation);
+
+                    let settings = get_settings(&self.app_handle);
+                    if let Some(secs) = settings.auto_stop_silence_secs.filter(|&s| s > 0) {
+                        // Push-to-talk already stops on key release
+                        if !settings.push_to_talk {
+                            self.spawn_silence_watcher(
+                                binding_id.to_string(),
+                                Duration::from_secs(secs),
+                                generation,
+                            );
                         }
-                        debug!("Streaming thread finished");
-                    });
+                    }
+                    if settings.mute_warning_enabled {
+                        self.spawn_mute_monitor(binding_id.to_string(), generation);
+                    }
+                    if let Some(mins) = settings.max_recording_minutes.filter(|&m| m > 0) {
+                        self.spawn_duration_guard(
+                            binding_id.to_string(),
+                            Duration::from_secs(mins * 60),
+                            generation,
+                        );
+                    }
 
                     return true;
                 }
@@ -406,6 +667,312 @@ impl AudioRecordingManager {
         }
     }
 
+    /// Feed the recorded audio to live transcription and the crash journal.
+    fn spawn_chunk_consumer(&self, chunk_rx: std::sync::mpsc::Receiver<Vec<f32>>, generation: u64) {
+        let tm = self
+            .app_handle
+            .state::<Arc<TranscriptionManager>>()
+            .inner()
+            .clone();
+        let journal = self.journal.clone();
+        std::thread::spawn(move || {
+            debug!("Streaming thread started");
+            while let Ok(chunk) = chunk_rx.recv() {
+                if let Some(journal) = journal.lock().unwrap().as_mut() {
+                    if let Err(e) = journal.append(&chunk) {
+                        warn!("Failed to journal recording: {e}");
+                    }
+                }
+                tm.handle_streaming_chunk(chunk, generation);
+            }
+            debug!("Streaming thread finished");
+        });
+    }
+
+    /// Start journaling the new recording. Voice enrollment and recordings
+    /// made in privacy mode never reach history, so they aren't journaled.
+    /// Recording goes on without a journal if it can't be created.
+    fn open_journal(&self, binding_id: &str) {
+        let journaled =
+            binding_id != ENROLLMENT_BINDING_ID && !get_settings(&self.app_handle).privacy_mode;
+        let journal = match self.app_handle.try_state::<Arc<HistoryManager>>() {
+            Some(history) if journaled => {
+                match RecordingJournal::create(history.recordings_dir()) {
+                    Ok(journal) => Some(journal),
+                    Err(e) => {
+                        warn!("Recording without a crash journal: {e:#}");
+                        None
+                    }
+                }
+            }
+            _ => None,
+        };
+        if let Some(previous) = std::mem::replace(&mut *self.journal.lock().unwrap(), journal) {
+            previous.discard();
+        }
+    }
+
+    fn discard_journal(&self) {
+        if let Some(journal) = self.journal.lock().unwrap().take() {
+            journal.discard();
+        }
+    }
+
+    /// Where the current (or, once stopped, the last) recording came from.
+    pub fn recording_source(&self) -> RecordingSource {
+        self.recording_source.lock().unwrap().clone()
+    }
+
+    /// How long the current recording has been running, if any.
+    pub fn recording_duration(&self) -> Option<Duration> {
+        self.recording_started_at
+            .lock()
+            .unwrap()
+            .map(|started| started.elapsed())
+    }
+
+    pub fn is_recording_binding(&self, binding_id: &str) -> bool {
+        matches!(
+            &*self.state.lock().unwrap(),
+            RecordingState::Recording { binding_id: active } if active == binding_id
+        )
+    }
+
+    pub fn is_recording(&self) -> bool {
+        matches!(
+            *self.state.lock().unwrap(),
+            RecordingState::Recording { .. }
+        )
+    }
+
+    /* ---------- binding activity (source of truth for toggles) ------------- */
+
+    /// Mark `binding_id` as about to record, so toggles treat it as active
+    /// before the microphone actually starts.
+    pub fn set_pending_binding(&self, binding_id: &str) {
+        *self.pending_binding.lock().unwrap() = Some(binding_id.to_string());
+    }
+
+    /// Clear the pending marker for `binding_id`. Returns false if it was
+    /// already cleared (e.g. by a stop or cancel in the meantime).
+    pub fn take_pending_binding(&self, binding_id: &str) -> bool {
+        let mut pending = self.pending_binding.lock().unwrap();
+        if pending.as_deref() == Some(binding_id) {
+            *pending = None;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Whether `binding_id` is pending or recording. Toggle shortcuts use this
+    /// to decide between start and stop, so state can never get out of sync
+    /// with the actual recording.
+    pub fn is_binding_active(&self, binding_id: &str) -> bool {
+        self.pending_binding.lock().unwrap().as_deref() == Some(binding_id)
+            || self.is_recording_binding(binding_id)
+    }
+
+    /// All bindings that are currently pending or recording.
+    pub fn active_bindings(&self) -> Vec<String> {
+        let pending = self.pending_binding.lock().unwrap().clone();
+        active_binding_ids(pending, &self.state.lock().unwrap())
+    }
+
+    /// Watch the VAD activity of the current recording and stop it once the
+    /// speaker has been silent for `silence`.
+    fn spawn_silence_watcher(&self, binding_id: String, silence: Duration, generation: u64) {
+        let manager = self.clone();
+        std::thread::spawn(move || loop {
+            std::thread::sleep(SILENCE_POLL_INTERVAL);
+
+            if OPERATION_GENERATION.load(Ordering::SeqCst) != generation
+                || !manager.is_recording_binding(&binding_id)
+            {
+                break;
+            }
+
+            let last_speech = *manager.last_speech.lock().unwrap();
+            if trailing_silence_exceeded(last_speech, Instant::now(), silence) {
+                utils::stop_binding_recording(&manager.app_handle, &binding_id, "trailing silence");
+                break;
+            }
+        });
+    }
+
+    /// Stop the current recording once it has run for `limit`, warning in the
+    /// overlay shortly before. It is transcribed like any other recording.
+    fn spawn_duration_guard(&self, binding_id: String, limit: Duration, generation: u64) {
+        let manager = self.clone();
+        std::thread::spawn(move || {
+            let mut warned = false;
+            loop {
+                std::thread::sleep(DURATION_POLL_INTERVAL);
+
+                if OPERATION_GENERATION.load(Ordering::SeqCst) != generation
+                    || !manager.is_recording_binding(&binding_id)
+                {
+                    break;
+                }
+
+                let elapsed = manager.recording_duration().unwrap_or_default();
+                match check_duration(elapsed, limit, warned) {
+                    DurationCheck::Continue => {}
+                    DurationCheck::Warn { remaining_secs } => {
+                        warned = true;
+                        utils::show_recording_hint(
+                            &manager.app_handle,
+                            &format!("Recording limit: stopping in {remaining_secs} s"),
+                        );
+                    }
+                    DurationCheck::Stop => {
+                        warn!("Recording reached the maximum duration of {:?}", limit);
+                        utils::stop_binding_recording(
+                            &manager.app_handle,
+                            &binding_id,
+                            "maximum duration reached",
+                        );
+                        break;
+                    }
+                }
+            }
+        });
+    }
+
+    /// Check right after the recording starts whether the microphone is
+    /// muted, and if so cancel it with a warning rather than record silence.
+    fn spawn_mute_monitor(&self, binding_id: String, generation: u64) {
+        let manager = self.clone();
+        std::thread::spawn(move || {
+            let settings = get_settings(&manager.app_handle);
+            // The OS only reports the state of the default input device
+            let uses_default_device =
+                settings.selected_microphone.is_none() && settings.clamshell_microphone.is_none();
+            let os_muted =
+                uses_default_device && input_mute::is_default_input_muted() == Some(true);
+
+            let muted = os_muted || {
+                std::thread::sleep(MUTE_CHECK_DELAY);
+                input_mute::is_digital_silence(*manager.input_peak.lock().unwrap())
+            };
+            if !muted
+                || OPERATION_GENERATION.load(Ordering::SeqCst) != generation
+                || !manager.is_recording_binding(&binding_id)
+            {
+                return;
+            }
+
+            warn!(
+                "Microphone appears muted ({}), cancelling recording",
+                if os_muted {
+                    "muted by the OS"
+                } else {
+                    "no signal"
+                }
+            );
+            utils::cancel_current_operation(&manager.app_handle);
+            utils::show_warning_overlay(&manager.app_handle, "Microphone is muted");
+        });
+    }
+
+    /// Move the open stream to the default input device when its device is
+    /// unplugged, carrying on with a recording in progress.
+    fn handle_device_changes(&self, changes: &DeviceChanges) {
+        debug!("Input devices changed: {:?}", changes);
+        if !*self.is_open.lock().unwrap() {
+            return;
+        }
+        let lost = self
+            .recorder
+            .lock()
+            .unwrap()
+            .as_ref()
+            .and_then(|rec| rec.device_name())
+            .filter(|name| changes.removed.contains(name));
+        let Some(lost) = lost else {
+            return;
+        };
+
+        let recording = *self.is_recording.lock().unwrap();
+        warn!("Input device {lost} disconnected, switching to the default device");
+        if let Err(e) = self.switch_to_default_device(recording) {
+            log::error!("Failed to switch to the default input device: {e}");
+            utils::show_warning_overlay(&self.app_handle, "Microphone disconnected");
+            // Transcribe what was recorded before the device went away
+            let state = self.state.lock().unwrap().clone();
+            if let RecordingState::Recording { binding_id } = state {
+                utils::stop_binding_recording(&self.app_handle, &binding_id, "device lost");
+            }
+            return;
+        }
+
+        let current = self
+            .recorder
+            .lock()
+            .unwrap()
+            .as_ref()
+            .and_then(|rec| rec.device_name());
+        let message = format!(
+            "{lost} disconnected, switched to {}",
+            current.as_deref().unwrap_or("the default microphone")
+        );
+        if recording {
+            utils::show_recording_hint(&self.app_handle, &message);
+        }
+        let _ = self.app_handle.emit(
+            "device-changed",
+            DeviceChanged {
+                previous: lost,
+                current,
+                recording,
+                message,
+            },
+        );
+    }
+
+    /// Reopen the stream, which falls back to the default device while the
+    /// selected one is missing. A recording keeps what it had and goes on.
+    fn switch_to_default_device(&self, recording: bool) -> Result<(), anyhow::Error> {
+        if let Some(rec) = self.recorder.lock().unwrap().as_mut() {
+            if recording {
+                match rec.stop() {
+                    Ok(samples) => self.carried_samples.lock().unwrap().extend(samples),
+                    Err(e) => log::error!("Lost the audio recorded on the old device: {e}"),
+                }
+            }
+            let _ = rec.close();
+        }
+        *self.is_open.lock().unwrap() = false;
+        if recording {
+            // Previews go on from what the recording kept, not the chunks
+            // streamed before the switch, so the final pass can reuse them
+            let carried = self.carried_samples.lock().unwrap().clone();
+            self.app_handle
+                .state::<Arc<TranscriptionManager>>()
+                .restart_streaming(&carried);
+        }
+
+        // Reopening forgets the mute applied for this recording
+        let did_mute = *self.did_mute.lock().unwrap();
+        self.start_microphone_stream()?;
+        *self.did_mute.lock().unwrap() = did_mute;
+        if !recording {
+            return Ok(());
+        }
+
+        let (chunk_tx, chunk_rx) = std::sync::mpsc::channel();
+        self.spawn_chunk_consumer(chunk_rx, OPERATION_GENERATION.load(Ordering::SeqCst));
+
+        if let Some(rec) = self.recorder.lock().unwrap().as_ref() {
+            rec.start(Some(chunk_tx))
+                .map_err(|e| anyhow::anyhow!("Failed to restart recording: {e}"))?;
+            let mut source = self.recording_source.lock().unwrap();
+            source.device = rec.device_name();
+            source.sample_rate = rec.sample_rate();
+        }
+        Ok(())
+    }
+
     pub fn update_selected_device(&self) -> Result<(), anyhow::Error> {
         // If currently open, restart the microphone stream to use the new device
         if *self.is_open.lock().unwrap() {
@@ -438,8 +1005,21 @@ impl AudioRecordingManager {
                     Vec::new()
                 };
 
+                // The samples are in memory now, on their way to history
+                self.discard_journal();
+
+                // Audio from a device unplugged during the recording goes first
+                let carried = std::mem::take(&mut *self.carried_samples.lock().unwrap());
+                let samples = if carried.is_empty() {
+                    samples
+                } else {
+                    [carried, samples].concat()
+                };
+
                 *self.is_recording.lock().unwrap() = false;
+                *self.recording_started_at.lock().unwrap() = None;
                 self.remove_mute();
+                self.touch_activity();
 
                 // In on-demand mode turn the mic off again
                 if matches!(*self.mode.lock().unwrap(), MicrophoneMode::OnDemand) {
@@ -461,8 +1041,20 @@ impl AudioRecordingManager {
         }
     }
 
+    /// Check that the microphone can be opened. An already-open stream counts as
+    /// success; otherwise the stream is opened and closed again.
+    pub fn probe_microphone(&self) -> Result<(), anyhow::Error> {
+        if *self.is_open.lock().unwrap() {
+            return Ok(());
+        }
+        self.start_microphone_stream()?;
+        self.stop_microphone_stream();
+        Ok(())
+    }
+
     /// Cancel any ongoing recording without returning audio samples
     pub fn cancel_recording(&self) {
+        *self.pending_binding.lock().unwrap() = None;
         let mut state = self.state.lock().unwrap();
 
         if let RecordingState::Recording { .. } = *state {
@@ -472,9 +1064,12 @@ impl AudioRecordingManager {
             if let Some(rec) = self.recorder.lock().unwrap().as_ref() {
                 let _ = rec.stop(); // Discard the result
             }
+            self.discard_journal();
 
             *self.is_recording.lock().unwrap() = false;
+            *self.recording_started_at.lock().unwrap() = None;
             self.remove_mute();
+            self.touch_activity();
 
             // In on-demand mode turn the mic off again
             if matches!(*self.mode.lock().unwrap(), MicrophoneMode::OnDemand) {
@@ -483,3 +1078,111 @@ impl AudioRecordingManager {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn silence_before_speech_never_triggers() {
+        let now = Instant::now();
+        assert!(!trailing_silence_exceeded(
+            None,
+            now,
+            Duration::from_secs(1)
+        ));
+    }
+
+    #[test]
+    fn triggers_once_threshold_reached() {
+        let spoke_at = Instant::now();
+        let threshold = Duration::from_secs(2);
+        assert!(!trailing_silence_exceeded(
+            Some(spoke_at),
+            spoke_at + Duration::from_millis(1500),
+            threshold
+        ));
+        assert!(trailing_silence_exceeded(
+            Some(spoke_at),
+            spoke_at + threshold,
+            threshold
+        ));
+    }
+
+    #[test]
+    fn active_bindings_list_each_binding_once() {
+        let recording = |id: &str| RecordingState::Recording {
+            binding_id: id.to_string(),
+        };
+        let pending = |id: &str| Some(id.to_string());
+
+        assert!(active_binding_ids(None, &RecordingState::Idle).is_empty());
+        assert_eq!(
+            active_binding_ids(pending("transcribe"), &RecordingState::Idle),
+            ["transcribe"]
+        );
+        assert_eq!(active_binding_ids(None, &recording("meeting")), ["meeting"]);
+        assert_eq!(
+            active_binding_ids(pending("transcribe"), &recording("transcribe")),
+            ["transcribe"]
+        );
+        // A second shortcut pressed while another one records
+        assert_eq!(
+            active_binding_ids(pending("continue_dictation"), &recording("transcribe")),
+            ["continue_dictation", "transcribe"]
+        );
+    }
+
+    #[test]
+    fn duration_guard_warns_once_then_stops() {
+        let limit = Duration::from_secs(600);
+        let secs = Duration::from_secs;
+        assert_eq!(
+            check_duration(secs(60), limit, false),
+            DurationCheck::Continue
+        );
+        assert_eq!(
+            check_duration(secs(575), limit, false),
+            DurationCheck::Warn { remaining_secs: 25 }
+        );
+        assert_eq!(
+            check_duration(secs(580), limit, true),
+            DurationCheck::Continue
+        );
+        assert_eq!(check_duration(limit, limit, true), DurationCheck::Stop);
+        // A limit shorter than the warning warns right away
+        assert_eq!(
+            check_duration(secs(1), secs(10), false),
+            DurationCheck::Warn { remaining_secs: 9 }
+        );
+    }
+
+    #[test]
+    fn idle_release_is_off_without_a_limit() {
+        assert_eq!(idle_release_limit(None), None);
+        assert_eq!(idle_release_limit(Some(0)), None);
+        assert_eq!(idle_release_limit(Some(5)), Some(Duration::from_secs(300)));
+    }
+
+    #[test]
+    fn only_an_unused_always_on_stream_is_released() {
+        use MicrophoneMode::{AlwaysOn, OnDemand};
+        use RecordingState::Idle;
+
+        let limit = Duration::from_secs(300);
+        let due = |mode, state, pending, open, idle_for| {
+            idle_release_due(&mode, &state, pending, open, idle_for, limit)
+        };
+        let recording = || RecordingState::Recording {
+            binding_id: "transcribe".to_string(),
+        };
+
+        assert!(due(AlwaysOn, Idle, false, true, limit));
+        assert!(!due(AlwaysOn, Idle, false, true, limit / 2));
+        assert!(!due(OnDemand, Idle, false, true, limit));
+        assert!(!due(AlwaysOn, recording(), false, true, limit));
+        // A press waiting to start a recording keeps the stream open
+        assert!(!due(AlwaysOn, Idle, true, true, limit));
+        assert!(!due(AlwaysOn, Idle, false, false, limit));
+    }
+}
@@ -0,0 +1,373 @@
+//! Time-of-day automation rules.
+//!
+//! A rule covers a window of the week, like 9:00–18:00 on weekdays, and says
+//! what to change when the window opens ("turn input tracking on and switch
+//! to the work profile") and when it closes ("turn privacy mode on"). A
+//! background scheduler checks the rules every half minute and applies them
+//! only when a window opens or closes, so a setting changed by hand stays
+//! changed until the next boundary.
+//!
+//! Rules live in the app data directory rather than in a profile, since they
+//! can switch profiles. The last state applied for each rule is saved with
+//! them, so the restart of a profile switch doesn't apply it all over again.
+
+use anyhow::{anyhow, Context, Result};
+use chrono::{Datelike, Local, Timelike};
+use log::{error, info};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+
+use super::scheduler;
+use crate::settings;
+
+/// How often the scheduler checks the rules.
+const EVALUATE_INTERVAL: Duration = Duration::from_secs(30);
+const RULES_FILE: &str = "automation_rules.json";
+const MINUTES_PER_DAY: u16 = 24 * 60;
+
+/// What a rule changes. Fields left out are left alone.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct RuleActions {
+    #[serde(default)]
+    pub input_tracking: Option<bool>,
+    #[serde(default)]
+    pub privacy_mode: Option<bool>,
+    /// Profile to switch to. Switching restarts the app.
+    #[serde(default)]
+    pub profile: Option<String>,
+}
+
+impl RuleActions {
+    fn is_empty(&self) -> bool {
+        self.input_tracking.is_none() && self.privacy_mode.is_none() && self.profile.is_none()
+    }
+}
+
+/// A rule as created or edited in the settings.
+#[derive(Clone, Debug, Deserialize)]
+pub struct AutomationRuleInput {
+    pub name: String,
+    pub enabled: bool,
+    /// Days the window starts on, 0 for Monday to 6 for Sunday.
+    pub days: Vec<u8>,
+    /// Minutes after local midnight. A window ending before it starts runs
+    /// past midnight.
+    pub start_minute: u16,
+    pub end_minute: u16,
+    /// Applied when the window opens.
+    pub actions: RuleActions,
+    /// Applied when the window closes.
+    #[serde(default)]
+    pub otherwise: RuleActions,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AutomationRule {
+    pub id: u64,
+    pub name: String,
+    pub enabled: bool,
+    pub days: Vec<u8>,
+    pub start_minute: u16,
+    pub end_minute: u16,
+    pub actions: RuleActions,
+    #[serde(default)]
+    pub otherwise: RuleActions,
+}
+
+impl AutomationRule {
+    fn from_input(id: u64, input: AutomationRuleInput) -> Self {
+        let mut days = input.days;
+        days.sort_unstable();
+        days.dedup();
+        Self {
+            id,
+            name: input.name.trim().to_string(),
+            enabled: input.enabled,
+            days,
+            start_minute: input.start_minute,
+            end_minute: input.end_minute,
+            actions: input.actions,
+            otherwise: input.otherwise,
+        }
+    }
+
+    /// Whether the window is open on `weekday` (0 for Monday) at `minute`
+    /// after midnight.
+    fn is_active(&self, weekday: u8, minute: u16) -> bool {
+        let starts_on = |day: u8| self.days.contains(&day);
+        if self.start_minute < self.end_minute {
+            starts_on(weekday) && (self.start_minute..self.end_minute).contains(&minute)
+        } else {
+            // Overnight: the part after midnight belongs to the day before
+            let yesterday = (weekday + 6) % 7;
+            (starts_on(weekday) && minute >= self.start_minute)
+                || (starts_on(yesterday) && minute < self.end_minute)
+        }
+    }
+}
+
+/// The actions `rule` calls for at `now`, or `None` if its window hasn't
+/// opened or closed since `applied`, the state last acted on.
+fn transition(rule: &AutomationRule, applied: Option<bool>, now: bool) -> Option<&RuleActions> {
+    if !rule.enabled || applied == Some(now) {
+        return None;
+    }
+    Some(if now { &rule.actions } else { &rule.otherwise })
+}
+
+fn validate(input: &AutomationRuleInput) -> Result<()> {
+    if input.name.trim().is_empty() {
+        return Err(anyhow!("Name the rule"));
+    }
+    if input.days.is_empty() || input.days.iter().any(|&day| day > 6) {
+        return Err(anyhow!("Pick at least one day"));
+    }
+    if input.start_minute >= MINUTES_PER_DAY || input.end_minute >= MINUTES_PER_DAY {
+        return Err(anyhow!("Times must be within the day"));
+    }
+    if input.start_minute == input.end_minute {
+        return Err(anyhow!("The rule needs a start and an end time"));
+    }
+    if input.actions.is_empty() && input.otherwise.is_empty() {
+        return Err(anyhow!("The rule doesn't change anything"));
+    }
+    for profile in [&input.actions.profile, &input.otherwise.profile]
+        .into_iter()
+        .flatten()
+    {
+        if !crate::profile::is_valid_name(profile) {
+            return Err(anyhow!("Invalid profile name '{}'", profile));
+        }
+    }
+    Ok(())
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct RulesFile {
+    #[serde(default)]
+    rules: Vec<AutomationRule>,
+    /// Whether each rule's window was open when it was last applied.
+    #[serde(default)]
+    applied: HashMap<u64, bool>,
+}
+
+pub struct AutomationManager {
+    app_handle: AppHandle,
+    path: PathBuf,
+    state: Mutex<RulesFile>,
+}
+
+impl AutomationManager {
+    pub fn new(app_handle: &AppHandle) -> Result<Self> {
+        let path = app_handle.path().app_data_dir()?.join(RULES_FILE);
+        let state = if path.exists() {
+            let json = fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read automation rules at {:?}", path))?;
+            serde_json::from_str(&json).context("Failed to parse automation rules")?
+        } else {
+            RulesFile::default()
+        };
+
+        let scheduler_handle = app_handle.clone();
+        thread::spawn(move || run_scheduler(scheduler_handle));
+
+        Ok(Self {
+            app_handle: app_handle.clone(),
+            path,
+            state: Mutex::new(state),
+        })
+    }
+
+    pub fn list(&self) -> Vec<AutomationRule> {
+        self.state.lock().unwrap().rules.clone()
+    }
+
+    pub fn create(&self, input: AutomationRuleInput) -> Result<AutomationRule> {
+        validate(&input)?;
+        let mut state = self.state.lock().unwrap();
+        let id = state.rules.iter().map(|rule| rule.id).max().unwrap_or(0) + 1;
+        let rule = AutomationRule::from_input(id, input);
+        state.rules.push(rule.clone());
+        self.save(&state)?;
+        drop(state);
+
+        info!("[Automation] Created rule '{}' ({})", rule.name, rule.id);
+        self.emit_changed();
+        Ok(rule)
+    }
+
+    /// Replace rule `id`. It's applied afresh on the next check.
+    pub fn update(&self, id: u64, input: AutomationRuleInput) -> Result<AutomationRule> {
+        validate(&input)?;
+        let mut state = self.state.lock().unwrap();
+        let slot = state
+            .rules
+            .iter_mut()
+            .find(|rule| rule.id == id)
+            .ok_or_else(|| anyhow!("Automation rule not found"))?;
+        *slot = AutomationRule::from_input(id, input);
+        let rule = slot.clone();
+        state.applied.remove(&id);
+        self.save(&state)?;
+        drop(state);
+
+        self.emit_changed();
+        Ok(rule)
+    }
+
+    pub fn delete(&self, id: u64) -> Result<()> {
+        let mut state = self.state.lock().unwrap();
+        state.rules.retain(|rule| rule.id != id);
+        state.applied.remove(&id);
+        self.save(&state)?;
+        drop(state);
+
+        self.emit_changed();
+        Ok(())
+    }
+
+    /// Apply the rules whose window opened or closed since they were last
+    /// applied. Later rules win where rules disagree.
+    fn evaluate(&self) -> Result<()> {
+        let now = Local::now();
+        let weekday = now.weekday().num_days_from_monday() as u8;
+        let minute = (now.hour() * 60 + now.minute()) as u16;
+
+        let mut state = self.state.lock().unwrap();
+        let mut due = RuleActions::default();
+        let mut changed = Vec::new();
+        for rule in &state.rules {
+            let active = rule.is_active(weekday, minute);
+            let Some(actions) = transition(rule, state.applied.get(&rule.id).copied(), active)
+            else {
+                continue;
+            };
+            info!(
+                "[Automation] Rule '{}' ({}) {}",
+                rule.name,
+                rule.id,
+                if active { "started" } else { "ended" }
+            );
+            due.input_tracking = actions.input_tracking.or(due.input_tracking);
+            due.privacy_mode = actions.privacy_mode.or(due.privacy_mode);
+            due.profile = actions.profile.clone().or(due.profile);
+            changed.push((rule.id, active));
+        }
+        if changed.is_empty() {
+            return Ok(());
+        }
+        // Saved before acting, as a profile switch restarts the app
+        state.applied.extend(changed);
+        self.save(&state)?;
+        drop(state);
+
+        self.apply(due)
+    }
+
+    fn apply(&self, actions: RuleActions) -> Result<()> {
+        if actions.input_tracking.is_some() || actions.privacy_mode.is_some() {
+            settings::update_settings(&self.app_handle, |s| {
+                if let Some(enabled) = actions.input_tracking {
+                    s.input_tracking_enabled = enabled;
+                }
+                if let Some(enabled) = actions.privacy_mode {
+                    s.privacy_mode = enabled;
+                }
+            });
+        }
+        for (setting, value) in [
+            ("input_tracking_enabled", actions.input_tracking),
+            ("privacy_mode", actions.privacy_mode),
+        ] {
+            if let Some(value) = value {
+                let _ = self.app_handle.emit(
+                    "settings-changed",
+                    serde_json::json!({ "setting": setting, "value": value }),
+                );
+            }
+        }
+        if let Some(profile) = actions.profile {
+            crate::profile::switch(&self.app_handle, &profile).map_err(|e| anyhow!(e))?;
+        }
+        Ok(())
+    }
+
+    fn save(&self, state: &RulesFile) -> Result<()> {
+        fs::write(&self.path, serde_json::to_string_pretty(state)?)
+            .with_context(|| format!("Failed to write automation rules to {:?}", self.path))
+    }
+
+    fn emit_changed(&self) {
+        let _ = self.app_handle.emit("automation-rules-changed", ());
+    }
+}
+
+fn run_scheduler(app_handle: AppHandle) {
+    scheduler::run_every(
+        &app_handle,
+        EVALUATE_INTERVAL,
+        |manager: &AutomationManager| {
+            if let Err(e) = manager.evaluate() {
+                error!("[Automation] Failed to apply rules: {}", e);
+            }
+        },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(days: &[u8], start_minute: u16, end_minute: u16) -> AutomationRule {
+        AutomationRule {
+            id: 1,
+            name: "Work".to_string(),
+            enabled: true,
+            days: days.to_vec(),
+            start_minute,
+            end_minute,
+            actions: RuleActions {
+                input_tracking: Some(true),
+                privacy_mode: Some(false),
+                profile: Some("work".to_string()),
+            },
+            otherwise: RuleActions {
+                privacy_mode: Some(true),
+                ..Default::default()
+            },
+        }
+    }
+
+    #[test]
+    fn windows_cover_their_days_and_hours() {
+        let weekdays = rule(&[0, 1, 2, 3, 4], 9 * 60, 18 * 60);
+        assert!(weekdays.is_active(0, 9 * 60));
+        assert!(weekdays.is_active(4, 18 * 60 - 1));
+        assert!(!weekdays.is_active(4, 18 * 60));
+        assert!(!weekdays.is_active(5, 12 * 60));
+
+        // Friday 22:00 to 02:00 runs into Saturday, not into Friday morning
+        let late = rule(&[4], 22 * 60, 2 * 60);
+        assert!(late.is_active(4, 23 * 60));
+        assert!(late.is_active(5, 60));
+        assert!(!late.is_active(4, 60));
+        assert!(!late.is_active(5, 23 * 60));
+    }
+
+    #[test]
+    fn rules_apply_only_when_a_window_opens_or_closes() {
+        let mut work = rule(&[0], 9 * 60, 18 * 60);
+        assert_eq!(transition(&work, None, true), Some(&work.actions));
+        assert_eq!(transition(&work, Some(true), true), None);
+        assert_eq!(transition(&work, Some(true), false), Some(&work.otherwise));
+
+        work.enabled = false;
+        assert_eq!(transition(&work, Some(true), false), None);
+    }
+}
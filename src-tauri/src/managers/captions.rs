@@ -0,0 +1,349 @@
+//! Live captions.
+//!
+//! Transcribes system audio continuously and shows rolling captions in an
+//! always-on-top window. Audio is read from the configured system audio device
+//! (`meeting_system_audio_device`, usually a loopback or monitor input) when
+//! set, otherwise from the platform system audio capture. A caption line grows
+//! with every step until a pause or the maximum line length finalizes it into
+//! the caption history.
+//!
+//! In interview mode, questions in finalized lines are also emitted as
+//! `interview-question` events, so they can be shown while the call goes on.
+
+use crate::audio_toolkit::audio::system_capture::{self, SystemAudioCapture};
+use crate::audio_toolkit::{list_input_devices, AudioRecorder};
+use crate::helpers::question_detection;
+use crate::managers::transcription::TranscriptionManager;
+use crate::settings;
+use anyhow::{anyhow, Context, Result};
+use log::{debug, info, warn};
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager, WebviewWindowBuilder};
+
+pub const CAPTIONS_WINDOW_LABEL: &str = "live_captions";
+
+/// How often the current line is re-transcribed.
+const STEP: Duration = Duration::from_secs(2);
+/// Lines are finalized after this much audio even without a pause.
+const MAX_LINE_SAMPLES: usize = 10 * 16_000;
+/// RMS below which a step counts as a pause.
+const SILENCE_RMS: f32 = 0.005;
+/// Finalized lines kept for the history view.
+const MAX_HISTORY: usize = 500;
+/// Detected interview questions kept for the questions view.
+const MAX_QUESTIONS: usize = 200;
+
+#[derive(Clone, Debug, Serialize)]
+pub struct CaptionLine {
+    pub text: String,
+    /// Unix timestamp in milliseconds of when the line was last updated.
+    pub timestamp: i64,
+    /// False while the line is still being spoken.
+    pub is_final: bool,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct InterviewQuestion {
+    pub text: String,
+    /// Unix timestamp in milliseconds of the caption line it came from.
+    pub timestamp: i64,
+}
+
+pub struct CaptionsManager {
+    app_handle: AppHandle,
+    running: Arc<AtomicBool>,
+    worker: Mutex<Option<JoinHandle<()>>>,
+    history: Arc<Mutex<VecDeque<CaptionLine>>>,
+    questions: Arc<Mutex<VecDeque<InterviewQuestion>>>,
+}
+
+impl CaptionsManager {
+    pub fn new(app_handle: &AppHandle) -> Self {
+        Self {
+            app_handle: app_handle.clone(),
+            running: Arc::new(AtomicBool::new(false)),
+            worker: Mutex::new(None),
+            history: Arc::new(Mutex::new(VecDeque::new())),
+            questions: Arc::new(Mutex::new(VecDeque::new())),
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.running.load(Ordering::SeqCst)
+    }
+
+    /// Start capturing and open the captions window.
+    pub fn start(&self) -> Result<()> {
+        let mut worker = self.worker.lock().unwrap();
+        if worker.is_some() {
+            return Ok(());
+        }
+
+        let source = CaptionSource::open(&self.app_handle)?;
+        self.app_handle
+            .state::<Arc<TranscriptionManager>>()
+            .initiate_model_load();
+
+        self.running.store(true, Ordering::SeqCst);
+        let app_handle = self.app_handle.clone();
+        let running = self.running.clone();
+        let history = self.history.clone();
+        let questions = self.questions.clone();
+        *worker = Some(
+            thread::Builder::new()
+                .name("live-captions".into())
+                .spawn(move || run_captions(app_handle, running, history, questions, source))
+                .context("Failed to spawn live captions thread")?,
+        );
+        drop(worker);
+
+        if let Err(e) = show_captions_window(&self.app_handle) {
+            self.stop();
+            return Err(e);
+        }
+        info!("Live captions started");
+        self.emit_state();
+        Ok(())
+    }
+
+    /// Stop capturing and close the captions window. Caption history is kept.
+    pub fn stop(&self) {
+        self.running.store(false, Ordering::SeqCst);
+        let Some(handle) = self.worker.lock().unwrap().take() else {
+            return;
+        };
+        // The worker finishes its current step before it notices
+        let _ = handle.join();
+
+        if let Some(window) = self.app_handle.get_webview_window(CAPTIONS_WINDOW_LABEL) {
+            let _ = window.close();
+        }
+        info!("Live captions stopped");
+        self.emit_state();
+    }
+
+    /// Finalized caption lines, oldest first.
+    pub fn history(&self) -> Vec<CaptionLine> {
+        self.history.lock().unwrap().iter().cloned().collect()
+    }
+
+    /// Questions detected in interview mode, oldest first.
+    pub fn questions(&self) -> Vec<InterviewQuestion> {
+        self.questions.lock().unwrap().iter().cloned().collect()
+    }
+
+    pub fn clear_history(&self) {
+        self.history.lock().unwrap().clear();
+        self.questions.lock().unwrap().clear();
+    }
+
+    fn emit_state(&self) {
+        let _ = self
+            .app_handle
+            .emit("live-captions-state-changed", self.is_running());
+    }
+}
+
+/// Where caption audio comes from. Both variants yield 16kHz mono samples.
+enum CaptionSource {
+    Device(AudioRecorder),
+    System(Box<dyn SystemAudioCapture>, mpsc::Receiver<Vec<f32>>),
+}
+
+impl CaptionSource {
+    fn open(app_handle: &AppHandle) -> Result<Self> {
+        let app_settings = settings::get_settings(app_handle);
+        if let Some(device_name) = app_settings.meeting_system_audio_device.as_deref() {
+            let device = list_input_devices()
+                .map_err(|e| anyhow!("Failed to list audio devices: {}", e))?
+                .into_iter()
+                .find(|d| d.name == device_name)
+                .ok_or_else(|| anyhow!("System audio device '{}' not found", device_name))?
+                .device;
+            // No VAD: captions should cover everything that plays
+            let mut recorder = AudioRecorder::new()
+                .map_err(|e| anyhow!("Failed to create captions recorder: {}", e))?
+                .with_realtime_priority(app_settings.thread_priorities_enabled);
+            recorder
+                .open(Some(device))
+                .map_err(|e| anyhow!("Failed to open system audio device: {}", e))?;
+            recorder
+                .start(None)
+                .map_err(|e| anyhow!("Failed to start system audio capture: {}", e))?;
+            return Ok(Self::Device(recorder));
+        }
+
+        if !system_capture::is_system_audio_available() {
+            return Err(anyhow!(
+                "System audio capture is not available; select a system audio device"
+            ));
+        }
+        let mut capture = system_capture::create_system_capture()?;
+        let samples = capture.start()?;
+        Ok(Self::System(capture, samples))
+    }
+
+    /// Audio captured since the previous call.
+    fn take_samples(&mut self) -> Vec<f32> {
+        match self {
+            Self::Device(recorder) => {
+                // Restarting keeps the recorder's buffer bounded to one step
+                let samples = recorder.stop().unwrap_or_else(|e| {
+                    warn!("Failed to read captions audio: {}", e);
+                    Vec::new()
+                });
+                if let Err(e) = recorder.start(None) {
+                    warn!("Failed to restart captions audio: {}", e);
+                }
+                samples
+            }
+            Self::System(_, samples) => samples.try_iter().flatten().collect(),
+        }
+    }
+
+    fn close(self) {
+        match self {
+            Self::Device(mut recorder) => {
+                let _ = recorder.stop();
+                let _ = recorder.close();
+            }
+            Self::System(mut capture, _) => {
+                if let Err(e) = capture.stop() {
+                    warn!("Failed to stop system audio capture: {}", e);
+                }
+            }
+        }
+    }
+}
+
+fn run_captions(
+    app_handle: AppHandle,
+    running: Arc<AtomicBool>,
+    history: Arc<Mutex<VecDeque<CaptionLine>>>,
+    questions: Arc<Mutex<VecDeque<InterviewQuestion>>>,
+    mut source: CaptionSource,
+) {
+    let tm = app_handle.state::<Arc<TranscriptionManager>>();
+    let mut line: Vec<f32> = Vec::new();
+
+    while running.load(Ordering::SeqCst) {
+        thread::sleep(STEP);
+        let samples = source.take_samples();
+        let pause = rms(&samples) < SILENCE_RMS;
+        if pause && line.is_empty() {
+            continue;
+        }
+        line.extend(samples);
+        let is_final = pause || line.len() >= MAX_LINE_SAMPLES;
+
+        let text = match tm
+            .ensure_model_loaded()
+            .and_then(|_| tm.transcribe(line.clone()))
+        {
+            Ok(text) => text.trim().to_string(),
+            Err(e) => {
+                warn!("Live caption transcription failed: {}", e);
+                String::new()
+            }
+        };
+        if is_final {
+            line.clear();
+        }
+        if text.is_empty() {
+            continue;
+        }
+
+        let caption = CaptionLine {
+            text,
+            timestamp: chrono::Utc::now().timestamp_millis(),
+            is_final,
+        };
+        debug!("Live caption ({}): {}", is_final, caption.text);
+        if is_final {
+            let mut history = history.lock().unwrap();
+            history.push_back(caption.clone());
+            if history.len() > MAX_HISTORY {
+                history.pop_front();
+            }
+        }
+        let _ = app_handle.emit("live-caption", &caption);
+
+        if is_final && settings::get_settings(&app_handle).interview_mode_enabled {
+            for text in question_detection::questions(&caption.text) {
+                let question = InterviewQuestion {
+                    text,
+                    timestamp: caption.timestamp,
+                };
+                debug!("Interview question: {}", question.text);
+                let _ = app_handle.emit("interview-question", &question);
+                let mut questions = questions.lock().unwrap();
+                questions.push_back(question);
+                if questions.len() > MAX_QUESTIONS {
+                    questions.pop_front();
+                }
+            }
+        }
+    }
+
+    source.close();
+}
+
+fn rms(samples: &[f32]) -> f32 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+    (samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32).sqrt()
+}
+
+/// Create the captions window, or bring it back if it already exists.
+fn show_captions_window(app_handle: &AppHandle) -> Result<()> {
+    if let Some(window) = app_handle.get_webview_window(CAPTIONS_WINDOW_LABEL) {
+        let _ = window.show();
+        return Ok(());
+    }
+    let window = WebviewWindowBuilder::new(
+        app_handle,
+        CAPTIONS_WINDOW_LABEL,
+        tauri::WebviewUrl::App("src/captions/index.html".into()),
+    )
+    .title("Live Captions")
+    .inner_size(720.0, 160.0)
+    .min_inner_size(320.0, 96.0)
+    .always_on_top(true)
+    .visible_on_all_workspaces(true)
+    .skip_taskbar(true)
+    .focused(false)
+    .build()
+    .context("Failed to create live captions window")?;
+
+    // Closing the window from its title bar ends the session
+    let app_handle = app_handle.clone();
+    window.on_window_event(move |event| {
+        if let tauri::WindowEvent::Destroyed = event {
+            let app_handle = app_handle.clone();
+            thread::spawn(move || {
+                if let Some(captions) = app_handle.try_state::<Arc<CaptionsManager>>() {
+                    captions.stop();
+                }
+            });
+        }
+    });
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rms_detects_silence() {
+        assert_eq!(rms(&[]), 0.0);
+        assert!(rms(&[0.001; 1600]) < SILENCE_RMS);
+        assert!(rms(&[0.1, -0.1, 0.1, -0.1]) > SILENCE_RMS);
+    }
+}
@@ -0,0 +1,237 @@
+//! Review of low-confidence dictations.
+//!
+//! A dictation whose estimated confidence falls below the review threshold is
+//! held instead of pasted. The overlay then offers to paste it anyway, record
+//! it again, or re-run it with a more accurate downloaded model.
+
+use crate::actions::{
+    finish_transcription, prepare_for_transcription, ACTION_MAP, OPERATION_GENERATION,
+};
+use crate::helpers::transcription_confidence;
+use crate::managers::history::RecordingSource;
+use crate::managers::model::{ModelInfo, ModelManager};
+use crate::managers::transcription::{Transcript, TranscriptionManager};
+use crate::overlay::{
+    hide_recording_overlay, show_low_confidence_overlay, show_tool_overlay,
+    show_transcribing_overlay,
+};
+use crate::settings::get_settings;
+use crate::tray::{change_tray_icon, TrayIconState};
+use anyhow::{anyhow, Result};
+use log::{error, info};
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::Ordering;
+use std::sync::{Arc, Mutex};
+use tauri::{AppHandle, Emitter, Manager};
+
+/// A dictation waiting for the user's decision.
+pub struct PendingReview {
+    pub binding_id: String,
+    /// Text with scored timestamps and language.
+    pub transcript: Transcript,
+    /// The dictation as recorded, before noise suppression and voice
+    /// filtering.
+    pub samples: Vec<f32>,
+    pub source: RecordingSource,
+    pub confidence: f32,
+    /// Operation generation the dictation belongs to; cancelling it makes the
+    /// review stale.
+    pub generation: u64,
+}
+
+/// Sent with `low-confidence-transcription` when a dictation is held.
+#[derive(Serialize, Debug, Clone)]
+pub struct LowConfidenceEvent {
+    pub text: String,
+    pub confidence: f32,
+    /// Words that most likely were misheard, for highlighting.
+    pub low_confidence_words: Vec<String>,
+    /// Name of the model "re-run" would use, if one is downloaded.
+    pub larger_model: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ReviewChoice {
+    PasteAnyway,
+    ReRecord,
+    LargerModel,
+}
+
+pub struct ConfidenceReviewManager {
+    app_handle: AppHandle,
+    pending: Mutex<Option<PendingReview>>,
+}
+
+impl ConfidenceReviewManager {
+    pub fn new(app_handle: &AppHandle) -> Self {
+        Self {
+            app_handle: app_handle.clone(),
+            pending: Mutex::new(None),
+        }
+    }
+
+    /// Hold a dictation and ask what to do with it, replacing any earlier one.
+    pub fn hold(&self, review: PendingReview) {
+        info!(
+            "[Confidence] Holding dictation with confidence {:.2}",
+            review.confidence
+        );
+        let event = LowConfidenceEvent {
+            text: review.transcript.text.clone(),
+            confidence: review.confidence,
+            low_confidence_words: transcription_confidence::low_confidence_words(
+                &review.transcript.segments,
+            ),
+            larger_model: self.larger_model().map(|model| model.name),
+        };
+        *self.pending.lock().unwrap() = Some(review);
+
+        change_tray_icon(&self.app_handle, TrayIconState::Idle);
+        show_low_confidence_overlay(&self.app_handle, &event);
+        let _ = self.app_handle.emit("low-confidence-transcription", &event);
+    }
+
+    /// Act on the user's choice for the held dictation.
+    pub fn resolve(&self, choice: ReviewChoice) -> Result<()> {
+        let review = self
+            .pending
+            .lock()
+            .unwrap()
+            .take()
+            .ok_or_else(|| anyhow!("No dictation is waiting for review"))?;
+        if OPERATION_GENERATION.load(Ordering::SeqCst) != review.generation {
+            return Err(anyhow!("The held dictation was cancelled"));
+        }
+        info!("[Confidence] Resolving held dictation: {:?}", choice);
+
+        let app = self.app_handle.clone();
+        match choice {
+            ReviewChoice::PasteAnyway => {
+                tauri::async_runtime::spawn(finish_transcription(
+                    app,
+                    review.transcript,
+                    review.samples,
+                    review.source,
+                    review.generation,
+                    tracing::Span::none(),
+                ));
+            }
+            ReviewChoice::ReRecord => {
+                hide_recording_overlay(&app);
+                // A push-to-talk recording can only be started by holding the key
+                if get_settings(&app).push_to_talk {
+                    show_tool_overlay(&app, "Hold the shortcut to record again");
+                } else if let Some(action) = ACTION_MAP.get(&review.binding_id) {
+                    action.start(&app, &review.binding_id, "");
+                }
+            }
+            ReviewChoice::LargerModel => {
+                let model = self
+                    .larger_model()
+                    .ok_or_else(|| anyhow!("No more accurate model is downloaded"))?;
+                change_tray_icon(&app, TrayIconState::Transcribing);
+                show_transcribing_overlay(&app);
+                tauri::async_runtime::spawn(async move {
+                    let tm = Arc::clone(&app.state::<Arc<TranscriptionManager>>());
+                    let samples = prepare_for_transcription(&app, review.samples.clone());
+                    let rerun = tauri::async_runtime::spawn_blocking(move || {
+                        tm.load_model(&model.id)?;
+                        let transcript = tm.transcribe_timed(samples);
+                        // The next dictation loads the usual model again
+                        if let Err(e) = tm.unload_model() {
+                            error!("[Confidence] Failed to unload {}: {}", model.id, e);
+                        }
+                        transcript
+                    })
+                    .await;
+
+                    let transcript = match rerun {
+                        Ok(Ok(transcript)) if !transcript.text.is_empty() => transcript,
+                        Ok(Ok(_)) => review.transcript,
+                        Ok(Err(e)) => {
+                            error!("[Confidence] Re-run failed: {}", e);
+                            review.transcript
+                        }
+                        Err(e) => {
+                            error!("[Confidence] Re-run task failed: {}", e);
+                            review.transcript
+                        }
+                    };
+                    finish_transcription(
+                        app,
+                        transcript,
+                        review.samples,
+                        review.source,
+                        review.generation,
+                        tracing::Span::none(),
+                    )
+                    .await;
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// The most accurate downloaded model that beats the one in use.
+    fn larger_model(&self) -> Option<ModelInfo> {
+        let settings = get_settings(&self.app_handle);
+        let current = self
+            .app_handle
+            .try_state::<Arc<TranscriptionManager>>()
+            .and_then(|tm| tm.get_current_model())
+            .unwrap_or(settings.selected_model);
+        let models = self
+            .app_handle
+            .try_state::<Arc<ModelManager>>()?
+            .get_transcription_models();
+        pick_larger_model(&models, &current).cloned()
+    }
+}
+
+fn pick_larger_model<'a>(models: &'a [ModelInfo], current_id: &str) -> Option<&'a ModelInfo> {
+    let current_score = models
+        .iter()
+        .find(|model| model.id == current_id)
+        .map_or(0.0, |model| model.accuracy_score);
+    models
+        .iter()
+        .filter(|model| model.is_downloaded && model.accuracy_score > current_score)
+        .max_by(|a, b| a.accuracy_score.total_cmp(&b.accuracy_score))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::managers::model::EngineType;
+
+    fn model(id: &str, accuracy_score: f32, is_downloaded: bool) -> ModelInfo {
+        ModelInfo {
+            id: id.to_string(),
+            name: id.to_string(),
+            description: String::new(),
+            filename: String::new(),
+            url: None,
+            size_mb: 0,
+            is_downloaded,
+            is_downloading: false,
+            partial_size: 0,
+            is_directory: false,
+            engine_type: EngineType::Whisper,
+            accuracy_score,
+            speed_score: 0.5,
+        }
+    }
+
+    #[test]
+    fn picks_most_accurate_downloaded_model() {
+        let models = [
+            model("small", 0.6, true),
+            model("medium", 0.75, true),
+            model("large", 0.9, false),
+            model("turbo", 0.8, true),
+        ];
+        assert_eq!(pick_larger_model(&models, "small").unwrap().id, "turbo");
+        assert!(pick_larger_model(&models, "turbo").is_none());
+    }
+}
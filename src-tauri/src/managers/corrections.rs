@@ -0,0 +1,217 @@
+//! Learning custom words from corrections.
+//!
+//! When a dictation is edited, in history or right after it was pasted, the
+//! words that were swapped for others are recorded. A word that dictations
+//! keep getting corrected to is one the model keeps mishearing, so once it
+//! recurs it is added to the custom words, where word correction snaps near
+//! misses to it.
+
+use anyhow::Result;
+use log::info;
+use rusqlite::{params, Connection};
+use tauri::{AppHandle, Emitter};
+
+use super::entry_edits::normalize;
+use crate::settings;
+
+/// Dictations a word must be corrected to in before it is learned.
+const LEARN_MIN_DICTATIONS: i64 = 3;
+/// Shorter words are rarely misheard for lack of knowing them.
+const MIN_WORD_CHARS: usize = 3;
+/// Edits that change more than this fraction of the words are rewrites, not
+/// corrections.
+const MAX_CHANGED_RATIO: f64 = 0.5;
+
+/// Where a dictation was corrected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CorrectionSource {
+    /// Edited in history.
+    History,
+    /// Edited in the app it was pasted into, as seen by input tracking.
+    Input,
+}
+
+impl CorrectionSource {
+    fn as_str(self) -> &'static str {
+        match self {
+            CorrectionSource::History => "history",
+            CorrectionSource::Input => "input",
+        }
+    }
+}
+
+/// A word of a dictation replaced by another.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Correction {
+    pub original: String,
+    pub corrected: String,
+}
+
+/// The words of `original` that `edited` replaced one for one. Added and
+/// removed words aren't corrections of what was heard, so they're skipped.
+pub fn substitutions(original: &str, edited: &str) -> Vec<Correction> {
+    let a: Vec<&str> = original.split_whitespace().collect();
+    let b: Vec<&str> = edited.split_whitespace().collect();
+    let a_norm: Vec<String> = a.iter().copied().map(normalize).collect();
+    let b_norm: Vec<String> = b.iter().copied().map(normalize).collect();
+
+    // Edit distances between every pair of prefixes, for the backtrace
+    let mut table = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for (i, row) in table.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in table[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let substitution = table[i - 1][j - 1] + usize::from(a_norm[i - 1] != b_norm[j - 1]);
+            table[i][j] = substitution
+                .min(table[i - 1][j] + 1)
+                .min(table[i][j - 1] + 1);
+        }
+    }
+    if table[a.len()][b.len()] as f64 > a.len() as f64 * MAX_CHANGED_RATIO {
+        return Vec::new();
+    }
+
+    let mut corrections = Vec::new();
+    let (mut i, mut j) = (a.len(), b.len());
+    while i > 0 && j > 0 {
+        let same = a_norm[i - 1] == b_norm[j - 1];
+        if table[i][j] == table[i - 1][j - 1] + usize::from(!same) {
+            if !same {
+                let corrected = b[j - 1].trim_matches(|c: char| !c.is_alphanumeric());
+                if corrected.chars().count() >= MIN_WORD_CHARS
+                    && corrected.chars().any(char::is_alphabetic)
+                {
+                    corrections.push(Correction {
+                        original: a_norm[i - 1].clone(),
+                        corrected: corrected.to_string(),
+                    });
+                }
+            }
+            i -= 1;
+            j -= 1;
+        } else if table[i][j] == table[i - 1][j] + 1 {
+            i -= 1;
+        } else {
+            j -= 1;
+        }
+    }
+    corrections.reverse();
+    corrections
+}
+
+/// Record the words `edited` corrected in history entry `history_id`.
+pub fn record(
+    conn: &Connection,
+    history_id: i64,
+    original: &str,
+    edited: &str,
+    source: CorrectionSource,
+    timestamp: i64,
+) -> Result<usize> {
+    let corrections = substitutions(original, edited);
+    for correction in &corrections {
+        conn.execute(
+            "INSERT INTO corrections (history_id, original_word, corrected_word, source, timestamp)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                history_id,
+                correction.original,
+                correction.corrected,
+                source.as_str(),
+                timestamp
+            ],
+        )?;
+    }
+    Ok(corrections.len())
+}
+
+/// Words corrected to in enough dictations that haven't been learned yet.
+/// They're marked learned, so a word the user later removes from the custom
+/// words has to recur again before it comes back.
+pub fn take_recurring(conn: &Connection) -> Result<Vec<String>> {
+    let mut stmt = conn.prepare(
+        "SELECT corrected_word FROM corrections WHERE learned = 0
+         GROUP BY lower(corrected_word) HAVING COUNT(DISTINCT history_id) >= ?1",
+    )?;
+    let words = stmt
+        .query_map([LEARN_MIN_DICTATIONS], |row| row.get::<_, String>(0))?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    for word in &words {
+        conn.execute(
+            "UPDATE corrections SET learned = 1 WHERE lower(corrected_word) = lower(?1)",
+            [word],
+        )?;
+    }
+    Ok(words)
+}
+
+/// Add `words` to the custom words, skipping ones already there, and tell
+/// the UI which were added.
+pub fn learn(app: &AppHandle, words: Vec<String>) {
+    if words.is_empty() {
+        return;
+    }
+    let mut added = Vec::new();
+    settings::update_settings(app, |s| {
+        for word in words {
+            if !s
+                .custom_words
+                .iter()
+                .any(|known| known.to_lowercase() == word.to_lowercase())
+            {
+                s.custom_words.push(word.clone());
+                added.push(word);
+            }
+        }
+    });
+    if !added.is_empty() {
+        info!(
+            "Learned custom words from corrections: {}",
+            added.join(", ")
+        );
+        let _ = app.emit("custom-words-learned", &added);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn correction(original: &str, corrected: &str) -> Correction {
+        Correction {
+            original: original.to_string(),
+            corrected: corrected.to_string(),
+        }
+    }
+
+    #[test]
+    fn finds_words_replaced_one_for_one() {
+        assert_eq!(
+            substitutions(
+                "deploy it with cooper netties and tarry",
+                "Deploy it with cooper Kubernetes and Tauri."
+            ),
+            [
+                correction("netties", "Kubernetes"),
+                correction("tarry", "Tauri")
+            ]
+        );
+        // Added words, short words and case changes aren't corrections
+        assert_eq!(
+            substitutions("send it to me now", "Please send it to us now"),
+            []
+        );
+    }
+
+    #[test]
+    fn rewrites_are_not_corrections() {
+        assert_eq!(
+            substitutions("see you at the gym", "meeting moved to friday"),
+            []
+        );
+    }
+}
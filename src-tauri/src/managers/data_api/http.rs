@@ -0,0 +1,210 @@
+//! Just enough HTTP/1.1 for the data API: one request per connection, no
+//! request bodies, JSON responses.
+
+use serde::Serialize;
+use std::collections::HashMap;
+use std::io::{self, Read, Write};
+use std::time::{Duration, Instant};
+
+/// Requests larger than this are rejected; the API only takes query strings.
+const MAX_REQUEST_BYTES: usize = 16 * 1024;
+/// Time a client has to send its whole request, however slowly it trickles.
+const MAX_REQUEST_TIME: Duration = Duration::from_secs(10);
+
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub query: HashMap<String, String>,
+    /// Header names are lowercased.
+    pub headers: HashMap<String, String>,
+}
+
+impl Request {
+    /// Token from `Authorization: Bearer <token>`. A token in the query
+    /// string isn't accepted, as URLs end up in logs and browser history.
+    pub fn token(&self) -> Option<&str> {
+        self.headers
+            .get("authorization")
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .map(str::trim)
+    }
+}
+
+pub fn read_request(stream: &mut impl Read) -> io::Result<Request> {
+    let mut buffer = Vec::new();
+    let mut chunk = [0u8; 1024];
+    let started = Instant::now();
+    while !buffer.windows(4).any(|w| w == b"\r\n\r\n") {
+        if started.elapsed() > MAX_REQUEST_TIME {
+            return Err(invalid("request took too long"));
+        }
+        let read = stream.read(&mut chunk)?;
+        if read == 0 {
+            break;
+        }
+        buffer.extend_from_slice(&chunk[..read]);
+        if buffer.len() > MAX_REQUEST_BYTES {
+            return Err(invalid("request too large"));
+        }
+    }
+    parse_request(&String::from_utf8_lossy(&buffer))
+}
+
+fn parse_request(raw: &str) -> io::Result<Request> {
+    let mut lines = raw.split("\r\n");
+    let mut request_line = lines
+        .next()
+        .ok_or_else(|| invalid("empty request"))?
+        .split_whitespace();
+    let method = request_line
+        .next()
+        .ok_or_else(|| invalid("missing method"))?
+        .to_string();
+    let target = request_line.next().ok_or_else(|| invalid("missing path"))?;
+
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let query = query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (percent_decode(key), percent_decode(value))
+        })
+        .collect();
+    let headers = lines
+        .take_while(|line| !line.is_empty())
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, value)| (name.trim().to_lowercase(), value.trim().to_string()))
+        .collect();
+
+    Ok(Request {
+        method,
+        path: path.to_string(),
+        query,
+        headers,
+    })
+}
+
+fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => decoded.push(b' '),
+            b'%' if i + 2 < bytes.len() => {
+                let byte = std::str::from_utf8(&bytes[i + 1..i + 3])
+                    .ok()
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+                match byte {
+                    Some(byte) => {
+                        decoded.push(byte);
+                        i += 2;
+                    }
+                    None => decoded.push(b'%'),
+                }
+            }
+            byte => decoded.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+pub struct Response {
+    status: u16,
+    body: String,
+}
+
+impl Response {
+    pub fn json(body: &impl Serialize) -> Self {
+        match serde_json::to_string(body) {
+            Ok(body) => Self { status: 200, body },
+            Err(e) => Self::error(500, &format!("Failed to serialize response: {}", e)),
+        }
+    }
+
+    pub fn error(status: u16, message: &str) -> Self {
+        Self {
+            status,
+            body: serde_json::json!({ "error": message }).to_string(),
+        }
+    }
+
+    /// Answer to CORS preflight requests.
+    pub fn no_content() -> Self {
+        Self {
+            status: 204,
+            body: String::new(),
+        }
+    }
+
+    pub fn write_to(&self, stream: &mut impl Write) -> io::Result<()> {
+        let reason = match self.status {
+            200 => "OK",
+            204 => "No Content",
+            400 => "Bad Request",
+            401 => "Unauthorized",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            _ => "Internal Server Error",
+        };
+        // Any origin may call the API; the token is what grants access
+        write!(
+            stream,
+            "HTTP/1.1 {} {}\r\n\
+             Content-Type: application/json; charset=utf-8\r\n\
+             Content-Length: {}\r\n\
+             Cache-Control: no-store\r\n\
+             Access-Control-Allow-Origin: *\r\n\
+             Access-Control-Allow-Headers: Authorization\r\n\
+             Access-Control-Allow-Methods: GET, OPTIONS\r\n\
+             Connection: close\r\n\r\n{}",
+            self.status,
+            reason,
+            self.body.len(),
+            self.body
+        )?;
+        stream.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_path_query_and_headers() {
+        let request = parse_request(
+            "GET /v1/history?since=100&redact=text%2Ctitle&q=a+b HTTP/1.1\r\n\
+             Host: localhost\r\n\
+             Authorization: Bearer secret\r\n\r\n",
+        )
+        .unwrap();
+        assert_eq!(request.method, "GET");
+        assert_eq!(request.path, "/v1/history");
+        assert_eq!(request.query["since"], "100");
+        assert_eq!(request.query["redact"], "text,title");
+        assert_eq!(request.query["q"], "a b");
+        assert_eq!(request.token(), Some("secret"));
+    }
+
+    #[test]
+    fn token_is_only_read_from_the_header() {
+        let request = parse_request("GET /v1/stats?token=abc HTTP/1.1\r\n\r\n").unwrap();
+        assert_eq!(request.token(), None);
+        let request = parse_request("GET /v1/stats HTTP/1.1\r\n\r\n").unwrap();
+        assert_eq!(request.token(), None);
+    }
+
+    #[test]
+    fn keeps_malformed_percent_escapes() {
+        assert_eq!(percent_decode("100%"), "100%");
+        assert_eq!(percent_decode("%zz"), "%zz");
+        assert_eq!(percent_decode("caf%C3%A9"), "café");
+    }
+}
@@ -0,0 +1,337 @@
+//! Read-only data API.
+//!
+//! Serves transcription history and dictation stats over HTTP on
+//! `127.0.0.1`, for personal dashboards (Obsidian, Grafana, scripts) that
+//! can't reach into the app. It is off by default. Every request must carry
+//! the token from settings as `Authorization: Bearer <token>`. The history
+//! database is opened read-only, so the API can't change anything.
+//! Connections are served on their own threads, a few at a time.
+//!
+//! Endpoints (all `GET`, JSON):
+//!
+//! - `/v1` - API version and the list of endpoints.
+//! - `/v1/history` - entries newest first. Parameters: `since` and `until`
+//!   (Unix seconds, `until` exclusive), `limit` (default 100, max 1000),
+//!   `offset`.
+//! - `/v1/stats` - dictation and word counts in total and per local day, and
+//!   per-app edit rates. Parameters: `since`, `until`.
+//!
+//! Both data endpoints take `redact`, a comma-separated list of `text`,
+//! `title`, `prompt` and `app_name`, to leave those fields out (`null`).
+//! Fields redacted in settings are never returned.
+
+mod http;
+mod queries;
+
+use crate::settings::{self, DataApiField};
+use anyhow::{Context, Result};
+use http::{Request, Response};
+use log::{debug, info, warn};
+use queries::{Redaction, TimeRange};
+use rusqlite::{Connection, OpenFlags};
+use serde::Serialize;
+use std::io::ErrorKind;
+use std::net::{Ipv4Addr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+use tauri::AppHandle;
+
+const DEFAULT_LIMIT: usize = 100;
+const MAX_LIMIT: usize = 1000;
+/// How often the accept loop checks whether it should stop.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+/// Slow or idle clients are dropped after this.
+const CLIENT_TIMEOUT: Duration = Duration::from_secs(5);
+/// Connections served at once; more are closed right away.
+const MAX_CONNECTIONS: usize = 8;
+
+#[derive(Clone, Debug, Serialize)]
+pub struct DataApiStatus {
+    pub running: bool,
+    pub url: Option<String>,
+    pub token: String,
+}
+
+struct Server {
+    port: u16,
+    running: Arc<AtomicBool>,
+    handle: JoinHandle<()>,
+}
+
+pub struct DataApiManager {
+    app_handle: AppHandle,
+    server: Mutex<Option<Server>>,
+}
+
+impl DataApiManager {
+    pub fn new(app_handle: &AppHandle) -> Self {
+        let manager = Self {
+            app_handle: app_handle.clone(),
+            server: Mutex::new(None),
+        };
+        if settings::get_settings(app_handle).data_api_enabled {
+            if let Err(e) = manager.start() {
+                warn!("[DataApi] Failed to start: {}", e);
+            }
+        }
+        manager
+    }
+
+    /// Start serving on the configured port. Restarts a running server so a
+    /// port change takes effect.
+    pub fn start(&self) -> Result<()> {
+        self.stop();
+        ensure_token(&self.app_handle);
+
+        let port = settings::get_settings(&self.app_handle).data_api_port;
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port))
+            .with_context(|| format!("Failed to listen on port {}", port))?;
+        listener
+            .set_nonblocking(true)
+            .context("Failed to configure data API listener")?;
+
+        let running = Arc::new(AtomicBool::new(true));
+        let app_handle = self.app_handle.clone();
+        let server_running = running.clone();
+        let handle = thread::Builder::new()
+            .name("data-api".into())
+            .spawn(move || serve(app_handle, listener, server_running))
+            .context("Failed to spawn data API thread")?;
+
+        info!("[DataApi] Listening on http://127.0.0.1:{}", port);
+        *self.server.lock().unwrap() = Some(Server {
+            port,
+            running,
+            handle,
+        });
+        Ok(())
+    }
+
+    pub fn stop(&self) {
+        let Some(server) = self.server.lock().unwrap().take() else {
+            return;
+        };
+        server.running.store(false, Ordering::SeqCst);
+        let _ = server.handle.join();
+        info!("[DataApi] Stopped");
+    }
+
+    pub fn status(&self) -> DataApiStatus {
+        let url = self
+            .server
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|server| format!("http://127.0.0.1:{}/v1", server.port));
+        DataApiStatus {
+            running: url.is_some(),
+            url,
+            token: settings::get_settings(&self.app_handle).data_api_token,
+        }
+    }
+
+    /// Replace the token, locking out clients that have the old one.
+    pub fn regenerate_token(&self) -> String {
+        let token = new_token();
+        let stored = token.clone();
+        settings::update_settings(&self.app_handle, |s| s.data_api_token = stored);
+        info!("[DataApi] Token regenerated");
+        token
+    }
+}
+
+fn ensure_token(app_handle: &AppHandle) {
+    if settings::get_settings(app_handle).data_api_token.is_empty() {
+        let token = new_token();
+        settings::update_settings(app_handle, |s| s.data_api_token = token);
+    }
+}
+
+fn new_token() -> String {
+    uuid::Uuid::new_v4().simple().to_string()
+}
+
+fn serve(app_handle: AppHandle, listener: TcpListener, running: Arc<AtomicBool>) {
+    let open = Arc::new(AtomicUsize::new(0));
+    while running.load(Ordering::SeqCst) {
+        match listener.accept() {
+            Ok((stream, _)) => spawn_connection(&app_handle, stream, &open),
+            Err(e) if e.kind() == ErrorKind::WouldBlock => thread::sleep(POLL_INTERVAL),
+            Err(e) => {
+                warn!("[DataApi] Failed to accept connection: {}", e);
+                thread::sleep(POLL_INTERVAL);
+            }
+        }
+    }
+}
+
+/// Serve `stream` on its own thread, so a slow client doesn't hold up the
+/// others, unless `MAX_CONNECTIONS` are already open.
+fn spawn_connection(app_handle: &AppHandle, stream: TcpStream, open: &Arc<AtomicUsize>) {
+    if open.fetch_add(1, Ordering::SeqCst) >= MAX_CONNECTIONS {
+        open.fetch_sub(1, Ordering::SeqCst);
+        debug!("[DataApi] Too many connections, dropping one");
+        return;
+    }
+    let app_handle = app_handle.clone();
+    let open = Arc::clone(open);
+    let spawned = thread::Builder::new()
+        .name("data-api-conn".into())
+        .spawn(move || {
+            handle_connection(&app_handle, stream);
+            open.fetch_sub(1, Ordering::SeqCst);
+        });
+    if let Err(e) = spawned {
+        warn!("[DataApi] Failed to spawn connection thread: {}", e);
+        open.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+fn handle_connection(app_handle: &AppHandle, mut stream: TcpStream) {
+    let _ = stream.set_nonblocking(false);
+    let _ = stream.set_read_timeout(Some(CLIENT_TIMEOUT));
+    let _ = stream.set_write_timeout(Some(CLIENT_TIMEOUT));
+
+    let response = match http::read_request(&mut stream) {
+        Ok(request) => respond(app_handle, &request),
+        Err(e) => Response::error(400, &e.to_string()),
+    };
+    if let Err(e) = response.write_to(&mut stream) {
+        debug!("[DataApi] Failed to write response: {}", e);
+    }
+}
+
+fn respond(app_handle: &AppHandle, request: &Request) -> Response {
+    if request.method == "OPTIONS" {
+        return Response::no_content();
+    }
+    if request.method != "GET" {
+        return Response::error(405, "Only GET requests are supported");
+    }
+
+    let app_settings = settings::get_settings(app_handle);
+    let authorized = request.token().is_some_and(|token| {
+        !app_settings.data_api_token.is_empty()
+            && constant_time_eq(token.as_bytes(), app_settings.data_api_token.as_bytes())
+    });
+    if !authorized {
+        return Response::error(401, "Missing or invalid token");
+    }
+
+    let result = match request.path.trim_end_matches('/') {
+        "/v1" => Ok(describe()),
+        "/v1/history" => history(app_handle, request, &app_settings.data_api_redacted_fields),
+        "/v1/stats" => stats(app_handle, request, &app_settings.data_api_redacted_fields),
+        _ => return Response::error(404, "Unknown endpoint"),
+    };
+    result.unwrap_or_else(|e| e)
+}
+
+fn describe() -> Response {
+    Response::json(&serde_json::json!({
+        "version": 1,
+        "endpoints": {
+            "/v1/history": ["since", "until", "limit", "offset", "redact"],
+            "/v1/stats": ["since", "until", "redact"],
+        },
+        "redactable_fields": ["text", "title", "prompt", "app_name"],
+    }))
+}
+
+fn history(
+    app_handle: &AppHandle,
+    request: &Request,
+    redacted: &[DataApiField],
+) -> Result<Response, Response> {
+    let range = time_range(request)?;
+    let limit = number_param(request, "limit")?
+        .unwrap_or(DEFAULT_LIMIT)
+        .min(MAX_LIMIT);
+    let offset = number_param(request, "offset")?.unwrap_or(0);
+    let redaction = Redaction::new(redacted, &redact_param(request)?);
+
+    let conn = open_history_db(app_handle)?;
+    queries::history(&conn, range, limit, offset, &redaction)
+        .map(|entries| Response::json(&entries))
+        .map_err(|e| Response::error(500, &format!("Failed to query history: {}", e)))
+}
+
+fn stats(
+    app_handle: &AppHandle,
+    request: &Request,
+    redacted: &[DataApiField],
+) -> Result<Response, Response> {
+    let range = time_range(request)?;
+    let redaction = Redaction::new(redacted, &redact_param(request)?);
+
+    let conn = open_history_db(app_handle)?;
+    queries::stats(&conn, range, &redaction)
+        .map(|stats| Response::json(&stats))
+        .map_err(|e| Response::error(500, &format!("Failed to query stats: {}", e)))
+}
+
+/// Opened per request and read-only, so the API never writes and follows the
+/// active profile.
+fn open_history_db(app_handle: &AppHandle) -> Result<Connection, Response> {
+    let path = crate::profile::data_dir(app_handle)
+        .map_err(|e| Response::error(500, &format!("Failed to get data directory: {}", e)))?
+        .join("history.db");
+    Connection::open_with_flags(&path, OpenFlags::SQLITE_OPEN_READ_ONLY)
+        .map_err(|e| Response::error(500, &format!("Failed to open database: {}", e)))
+}
+
+fn time_range(request: &Request) -> Result<TimeRange, Response> {
+    Ok(TimeRange {
+        since: number_param(request, "since")?,
+        until: number_param(request, "until")?,
+    })
+}
+
+fn number_param<T: std::str::FromStr>(
+    request: &Request,
+    name: &str,
+) -> Result<Option<T>, Response> {
+    request
+        .query
+        .get(name)
+        .map(|value| {
+            value
+                .parse()
+                .map_err(|_| Response::error(400, &format!("Invalid value for '{}'", name)))
+        })
+        .transpose()
+}
+
+fn redact_param(request: &Request) -> Result<Vec<DataApiField>, Response> {
+    let Some(value) = request.query.get("redact") else {
+        return Ok(Vec::new());
+    };
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|field| !field.is_empty())
+        .map(|field| {
+            serde_json::from_value(serde_json::Value::String(field.to_string()))
+                .map_err(|_| Response::error(400, &format!("Unknown field '{}'", field)))
+        })
+        .collect()
+}
+
+/// Compare tokens without leaking how much of a guess was right.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compares_tokens() {
+        assert!(constant_time_eq(b"abc123", b"abc123"));
+        assert!(!constant_time_eq(b"abc123", b"abc124"));
+        assert!(!constant_time_eq(b"abc", b"abc123"));
+    }
+}
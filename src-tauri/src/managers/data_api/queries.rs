@@ -0,0 +1,260 @@
+//! Read-only queries behind the data API endpoints, with field redaction.
+
+use crate::managers::entry_edits::AppEditStats;
+use crate::settings::DataApiField;
+use chrono::{Local, TimeZone};
+use rusqlite::{params, Connection};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashSet};
+
+/// Unix timestamps in seconds; `since` is inclusive, `until` exclusive.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TimeRange {
+    pub since: Option<i64>,
+    pub until: Option<i64>,
+}
+
+impl TimeRange {
+    fn bounds(&self) -> (i64, i64) {
+        (self.since.unwrap_or(0), self.until.unwrap_or(i64::MAX))
+    }
+}
+
+/// Fields withheld from a response: those redacted in settings plus any the
+/// client asked to leave out. Clients can't un-redact a field.
+pub struct Redaction(HashSet<DataApiField>);
+
+impl Redaction {
+    pub fn new(configured: &[DataApiField], requested: &[DataApiField]) -> Self {
+        Self(configured.iter().chain(requested).copied().collect())
+    }
+
+    pub fn hides(&self, field: DataApiField) -> bool {
+        self.0.contains(&field)
+    }
+
+    fn keep<T>(&self, field: DataApiField, value: T) -> Option<T> {
+        (!self.hides(field)).then_some(value)
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ApiHistoryEntry {
+    pub id: i64,
+    pub timestamp: i64,
+    pub title: Option<String>,
+    /// Final text: post-processed when available, else the transcription.
+    pub text: Option<String>,
+    pub transcription_text: Option<String>,
+    pub post_process_prompt: Option<String>,
+    /// Counted before redaction, so stats dashboards work without text.
+    pub word_count: usize,
+    pub saved: bool,
+    pub session_id: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DayStats {
+    /// Local date, `YYYY-MM-DD`.
+    pub date: String,
+    pub dictations: usize,
+    pub words: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ApiStats {
+    pub dictations: usize,
+    pub words: usize,
+    pub days: Vec<DayStats>,
+    /// Omitted when app names are redacted.
+    pub edits_by_app: Option<Vec<AppEditStats>>,
+}
+
+/// History entries in the range, newest first.
+pub fn history(
+    conn: &Connection,
+    range: TimeRange,
+    limit: usize,
+    offset: usize,
+    redaction: &Redaction,
+) -> rusqlite::Result<Vec<ApiHistoryEntry>> {
+    let (since, until) = range.bounds();
+    let mut stmt = conn.prepare(
+        "SELECT id, timestamp, saved, title, transcription_text, post_processed_text,
+                post_process_prompt, session_id
+         FROM transcription_history
+         WHERE timestamp >= ?1 AND timestamp < ?2
+         ORDER BY timestamp DESC LIMIT ?3 OFFSET ?4",
+    )?;
+    let rows = stmt.query_map(params![since, until, limit as i64, offset as i64], |row| {
+        let transcription_text: String = row.get("transcription_text")?;
+        let post_processed_text: Option<String> = row.get("post_processed_text")?;
+        let text = post_processed_text.unwrap_or_else(|| transcription_text.clone());
+        let prompt: Option<String> = row.get("post_process_prompt")?;
+        Ok(ApiHistoryEntry {
+            id: row.get("id")?,
+            timestamp: row.get("timestamp")?,
+            title: redaction.keep(DataApiField::Title, row.get("title")?),
+            word_count: text.split_whitespace().count(),
+            text: redaction.keep(DataApiField::Text, text),
+            transcription_text: redaction.keep(DataApiField::Text, transcription_text),
+            post_process_prompt: prompt
+                .and_then(|prompt| redaction.keep(DataApiField::Prompt, prompt)),
+            saved: row.get("saved")?,
+            session_id: row.get("session_id")?,
+        })
+    })?;
+    rows.collect()
+}
+
+/// Dictation counts per local day, and how dictations got edited per app.
+pub fn stats(
+    conn: &Connection,
+    range: TimeRange,
+    redaction: &Redaction,
+) -> rusqlite::Result<ApiStats> {
+    let (since, until) = range.bounds();
+    let mut stmt = conn.prepare(
+        "SELECT timestamp, COALESCE(post_processed_text, transcription_text) AS text
+         FROM transcription_history WHERE timestamp >= ?1 AND timestamp < ?2",
+    )?;
+    let rows = stmt.query_map(params![since, until], |row| {
+        Ok((
+            row.get::<_, i64>("timestamp")?,
+            row.get::<_, String>("text")?,
+        ))
+    })?;
+
+    let mut days: BTreeMap<String, (usize, usize)> = BTreeMap::new();
+    for row in rows {
+        let (timestamp, text) = row?;
+        let Some(date) = Local.timestamp_opt(timestamp, 0).single() else {
+            continue;
+        };
+        let day = days.entry(date.format("%Y-%m-%d").to_string()).or_default();
+        day.0 += 1;
+        day.1 += text.split_whitespace().count();
+    }
+
+    let edits_by_app = if redaction.hides(DataApiField::AppName) {
+        None
+    } else {
+        Some(edit_stats_by_app(conn, since, until)?)
+    };
+
+    Ok(ApiStats {
+        dictations: days.values().map(|(dictations, _)| dictations).sum(),
+        words: days.values().map(|(_, words)| words).sum(),
+        days: days
+            .into_iter()
+            .map(|(date, (dictations, words))| DayStats {
+                date,
+                dictations,
+                words,
+            })
+            .collect(),
+        edits_by_app,
+    })
+}
+
+fn edit_stats_by_app(
+    conn: &Connection,
+    since: i64,
+    until: i64,
+) -> rusqlite::Result<Vec<AppEditStats>> {
+    let mut stmt = conn.prepare(
+        "SELECT app_name, COUNT(*) AS linked_count,
+                SUM(CASE WHEN word_edit_distance > 0 THEN 1 ELSE 0 END) AS edited_count,
+                SUM(word_edit_distance) AS total_edits,
+                SUM(original_word_count) AS total_words
+         FROM entry_edits WHERE timestamp >= ?1 AND timestamp < ?2
+         GROUP BY app_name ORDER BY linked_count DESC",
+    )?;
+    let rows = stmt.query_map(params![since, until], |row| {
+        let total_edits: i64 = row.get("total_edits")?;
+        let total_words: i64 = row.get("total_words")?;
+        Ok(AppEditStats {
+            app_name: row.get("app_name")?,
+            linked_count: row.get("linked_count")?,
+            edited_count: row.get("edited_count")?,
+            word_edit_rate: if total_words > 0 {
+                total_edits as f64 / total_words as f64
+            } else {
+                0.0
+            },
+        })
+    })?;
+    rows.collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::managers::database;
+
+    fn test_db() -> (tempfile::TempDir, Connection) {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("history.db");
+        database::initialize_database(&path).unwrap();
+        let conn = Connection::open(&path).unwrap();
+        for (timestamp, text, post_processed) in [
+            (1_000, "hello there", None),
+            (2_000, "one two three", Some("One, two, three.")),
+            (90_000, "next day", None),
+        ] {
+            conn.execute(
+                "INSERT INTO transcription_history
+                 (file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt)
+                 VALUES ('a.wav', ?1, 0, 'Title', ?2, ?3, 'Clean this')",
+                params![timestamp, text, post_processed],
+            )
+            .unwrap();
+        }
+        (dir, conn)
+    }
+
+    #[test]
+    fn history_filters_and_redacts() {
+        let (_dir, conn) = test_db();
+        let range = TimeRange {
+            since: Some(1_500),
+            until: None,
+        };
+
+        let entries = history(&conn, range, 10, 0, &Redaction::new(&[], &[])).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].timestamp, 90_000);
+        assert_eq!(entries[1].text.as_deref(), Some("One, two, three."));
+        assert_eq!(
+            entries[1].post_process_prompt.as_deref(),
+            Some("Clean this")
+        );
+
+        let redaction = Redaction::new(&[DataApiField::Text], &[DataApiField::Prompt]);
+        let entries = history(&conn, range, 1, 1, &redaction).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].text, None);
+        assert_eq!(entries[0].transcription_text, None);
+        assert_eq!(entries[0].post_process_prompt, None);
+        assert_eq!(entries[0].title.as_deref(), Some("Title"));
+        assert_eq!(entries[0].word_count, 3);
+    }
+
+    #[test]
+    fn stats_count_words_and_respect_app_name_redaction() {
+        let (_dir, conn) = test_db();
+        let all = stats(&conn, TimeRange::default(), &Redaction::new(&[], &[])).unwrap();
+        assert_eq!(all.dictations, 3);
+        assert_eq!(all.words, 7);
+        assert_eq!(all.days.iter().map(|d| d.dictations).sum::<usize>(), 3);
+        assert!(all.edits_by_app.is_some());
+
+        let redacted = stats(
+            &conn,
+            TimeRange::default(),
+            &Redaction::new(&[], &[DataApiField::AppName]),
+        )
+        .unwrap();
+        assert!(redacted.edits_by_app.is_none());
+    }
+}
@@ -13,11 +13,11 @@
 
 use anyhow::{Context, Result};
 use log::{debug, info};
-use rusqlite::Connection;
+use rusqlite::{Connection, OpenFlags};
 use std::path::Path;
 
 /// Current schema version. Increment this when adding new migrations.
-const CURRENT_SCHEMA_VERSION: u32 = 6;
+pub const CURRENT_SCHEMA_VERSION: u32 = 16;
 
 /// A database migration with version and SQL statement.
 struct Migration {
@@ -99,6 +99,109 @@ const MIGRATIONS: &[Migration] = &[
         CREATE INDEX idx_segments_meeting ON meeting_segments(meeting_id);
         CREATE INDEX idx_segments_time ON meeting_segments(start_ms)",
     },
+    Migration {
+        version: 7,
+        description: "create_daily_digests_table",
+        sql: "CREATE TABLE daily_digests (
+            date TEXT PRIMARY KEY,
+            markdown TEXT NOT NULL,
+            created_at INTEGER NOT NULL,
+            file_path TEXT
+        )",
+    },
+    Migration {
+        version: 8,
+        description: "create_focus_sessions_table",
+        sql: "CREATE TABLE focus_sessions (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            name TEXT NOT NULL,
+            start_time INTEGER NOT NULL,
+            end_time INTEGER
+        );
+        ALTER TABLE transcription_history ADD COLUMN session_id INTEGER;
+        CREATE INDEX idx_history_session ON transcription_history(session_id)",
+    },
+    Migration {
+        version: 9,
+        description: "create_entry_edits_table",
+        sql: "CREATE TABLE entry_edits (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            history_id INTEGER NOT NULL REFERENCES transcription_history(id) ON DELETE CASCADE,
+            app_name TEXT NOT NULL,
+            original_text TEXT NOT NULL,
+            edited_text TEXT NOT NULL,
+            word_edit_distance INTEGER NOT NULL,
+            original_word_count INTEGER NOT NULL,
+            timestamp INTEGER NOT NULL
+        );
+        CREATE INDEX idx_entry_edits_history ON entry_edits(history_id)",
+    },
+    Migration {
+        version: 10,
+        description: "create_scheduled_recordings_table",
+        sql: "CREATE TABLE scheduled_recordings (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            title TEXT NOT NULL,
+            start_time INTEGER NOT NULL,
+            duration_secs INTEGER NOT NULL,
+            source TEXT NOT NULL,
+            status TEXT NOT NULL DEFAULT 'pending',
+            meeting_id INTEGER REFERENCES meetings(id) ON DELETE SET NULL,
+            error TEXT,
+            created_at INTEGER NOT NULL
+        );
+        CREATE INDEX idx_scheduled_recordings_start ON scheduled_recordings(start_time)",
+    },
+    Migration {
+        version: 11,
+        description: "create_history_segments_table",
+        sql: "CREATE TABLE history_segments (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            history_id INTEGER NOT NULL REFERENCES transcription_history(id) ON DELETE CASCADE,
+            speaker_label TEXT NOT NULL,
+            start_ms INTEGER NOT NULL,
+            end_ms INTEGER NOT NULL,
+            text TEXT NOT NULL
+        );
+        CREATE INDEX idx_history_segments_history ON history_segments(history_id)",
+    },
+    Migration {
+        version: 12,
+        description: "add_timestamps_column",
+        sql: "ALTER TABLE transcription_history ADD COLUMN timestamps TEXT",
+    },
+    Migration {
+        version: 13,
+        description: "add_recording_source_columns",
+        sql: "ALTER TABLE transcription_history ADD COLUMN source_device TEXT;
+        ALTER TABLE transcription_history ADD COLUMN sample_rate INTEGER;
+        ALTER TABLE transcription_history ADD COLUMN recording_mode TEXT;
+        CREATE INDEX idx_transcription_history_recording_mode ON transcription_history(recording_mode)",
+    },
+    Migration {
+        version: 14,
+        description: "add_language_column",
+        sql: "ALTER TABLE transcription_history ADD COLUMN language TEXT",
+    },
+    Migration {
+        version: 15,
+        description: "create_corrections_table",
+        sql: "CREATE TABLE corrections (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            history_id INTEGER NOT NULL REFERENCES transcription_history(id) ON DELETE CASCADE,
+            original_word TEXT NOT NULL,
+            corrected_word TEXT NOT NULL,
+            source TEXT NOT NULL,
+            learned INTEGER NOT NULL DEFAULT 0,
+            timestamp INTEGER NOT NULL
+        );
+        CREATE INDEX idx_corrections_history ON corrections(history_id)",
+    },
+    Migration {
+        version: 16,
+        description: "add_previous_transcription_column",
+        sql: "ALTER TABLE transcription_history ADD COLUMN previous_transcription_text TEXT",
+    },
 ];
 
 /// Initialize the database at the given path, creating schema and running migrations.
@@ -163,6 +266,15 @@ pub fn initialize_database(db_path: &Path) -> Result<()> {
     Ok(())
 }
 
+/// An in-memory database with every migration applied, for tests.
+#[cfg(test)]
+pub fn open_in_memory() -> Result<Connection> {
+    let conn = Connection::open_in_memory()?;
+    create_schema_version_table(&conn)?;
+    run_migrations(&conn)?;
+    Ok(conn)
+}
+
 /// Check if a table exists in the database.
 fn check_table_exists(conn: &Connection, table_name: &str) -> Result<bool> {
     let count: i32 = conn
@@ -213,6 +325,14 @@ fn create_schema_version_table(conn: &Connection) -> Result<()> {
     Ok(())
 }
 
+/// Schema version of the existing database at `db_path`, without creating
+/// or migrating it.
+pub fn read_schema_version(db_path: &Path) -> Result<u32> {
+    let conn = Connection::open_with_flags(db_path, OpenFlags::SQLITE_OPEN_READ_ONLY)
+        .with_context(|| format!("Failed to open database at {:?}", db_path))?;
+    get_schema_version(&conn)
+}
+
 /// Get the current schema version.
 fn get_schema_version(conn: &Connection) -> Result<u32> {
     let version: u32 = conn
@@ -253,8 +373,39 @@ fn detect_schema_version(conn: &Connection) -> Result<u32> {
         check_column_exists(conn, "transcription_history", "post_processed_text")?;
 
     let has_meetings = check_table_exists(conn, "meetings")?;
-
-    if has_meetings {
+    let has_daily_digests = check_table_exists(conn, "daily_digests")?;
+    let has_focus_sessions = check_table_exists(conn, "focus_sessions")?;
+    let has_entry_edits = check_table_exists(conn, "entry_edits")?;
+    let has_scheduled_recordings = check_table_exists(conn, "scheduled_recordings")?;
+    let has_history_segments = check_table_exists(conn, "history_segments")?;
+    let has_timestamps = check_column_exists(conn, "transcription_history", "timestamps")?;
+    let has_recording_mode = check_column_exists(conn, "transcription_history", "recording_mode")?;
+    let has_language = check_column_exists(conn, "transcription_history", "language")?;
+    let has_corrections = check_table_exists(conn, "corrections")?;
+    let has_previous_transcription =
+        check_column_exists(conn, "transcription_history", "previous_transcription_text")?;
+
+    if has_previous_transcription {
+        Ok(16)
+    } else if has_corrections {
+        Ok(15)
+    } else if has_language {
+        Ok(14)
+    } else if has_recording_mode {
+        Ok(13)
+    } else if has_timestamps {
+        Ok(12)
+    } else if has_history_segments {
+        Ok(11)
+    } else if has_scheduled_recordings {
+        Ok(10)
+    } else if has_entry_edits {
+        Ok(9)
+    } else if has_focus_sessions {
+        Ok(8)
+    } else if has_daily_digests {
+        Ok(7)
+    } else if has_meetings {
         Ok(6)
     } else if has_input_entries && has_app_pid {
         Ok(5)
@@ -0,0 +1,330 @@
+//! Serialized database writes.
+//!
+//! History, input tracking and file transcription hand their writes to a single
+//! writer actor instead of opening a connection per save. The actor runs on its
+//! own thread, keeps one connection per database file, and commits whatever
+//! writes are queued together in one transaction (each write in its own
+//! savepoint, so a failing write doesn't roll back its neighbours). Producers
+//! never block on SQLite: `submit` only enqueues, and `write` resolves once
+//! the write has been committed. On exit, `shutdown` commits whatever is still
+//! queued and closes the connections.
+
+use anyhow::{Context, Result};
+use log::{debug, error};
+use rusqlite::Connection;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc as std_mpsc;
+use std::thread;
+use std::time::Duration;
+use tokio::sync::{mpsc, oneshot};
+
+/// Upper bound on the number of queued writes committed in one transaction.
+const MAX_BATCH: usize = 64;
+/// How long a write waits for another connection (e.g. maintenance) to
+/// release the database.
+const BUSY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Called with the outcome of the transaction a write was committed in.
+type Completion = Box<dyn FnOnce(Result<(), String>) + Send>;
+/// Runs a write against the connection; returns whether it succeeded and what
+/// to do once the batch has been committed.
+type Job = Box<dyn FnOnce(&Connection) -> (bool, Option<Completion>) + Send>;
+
+struct Write {
+    db_path: PathBuf,
+    job: Job,
+}
+
+enum Request {
+    Write(Write),
+    /// Commit everything queued before it, close all connections and stop.
+    Shutdown(std_mpsc::Sender<()>),
+}
+
+pub struct DbWriter {
+    sender: mpsc::UnboundedSender<Request>,
+}
+
+impl DbWriter {
+    pub fn new() -> Result<Self> {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        thread::Builder::new()
+            .name("db-writer".to_string())
+            .spawn(move || run(receiver))
+            .context("Failed to spawn database writer thread")?;
+        Ok(Self { sender })
+    }
+
+    /// Queue a write without waiting for it. Failures are logged.
+    pub fn submit<F>(&self, db_path: &Path, op: F)
+    where
+        F: FnOnce(&Connection) -> Result<()> + Send + 'static,
+    {
+        self.enqueue(
+            db_path,
+            Box::new(move |conn| match op(conn) {
+                Ok(()) => (true, None),
+                Err(e) => {
+                    error!("Database write failed: {:#}", e);
+                    (false, None)
+                }
+            }),
+        );
+    }
+
+    /// Queue a write and call `then` on the writer thread with its result once
+    /// it has been committed. Enqueueing never blocks.
+    pub fn submit_then<T, F, C>(&self, db_path: &Path, op: F, then: C)
+    where
+        T: Send + 'static,
+        F: FnOnce(&Connection) -> Result<T> + Send + 'static,
+        C: FnOnce(Result<T>) + Send + 'static,
+    {
+        self.enqueue(
+            db_path,
+            Box::new(move |conn| {
+                let result = op(conn);
+                let ok = result.is_ok();
+                let completion: Completion = Box::new(move |committed| {
+                    then(match committed {
+                        Ok(()) => result,
+                        Err(e) => result.and(Err(anyhow::anyhow!(e))),
+                    });
+                });
+                (ok, Some(completion))
+            }),
+        );
+    }
+
+    /// Queue a write and wait until it has been committed.
+    pub async fn write<T, F>(&self, db_path: &Path, op: F) -> Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&Connection) -> Result<T> + Send + 'static,
+    {
+        let (reply, response) = oneshot::channel();
+        self.submit_then(db_path, op, move |result| {
+            let _ = reply.send(result);
+        });
+        response
+            .await
+            .context("Database writer stopped before completing the write")?
+    }
+
+    /// Commit all queued writes and close the connections, waiting at most
+    /// `timeout`. Writes submitted afterwards are dropped. Returns whether the
+    /// writer finished in time.
+    pub fn shutdown(&self, timeout: Duration) -> bool {
+        let (ack, done) = std_mpsc::channel();
+        if self.sender.send(Request::Shutdown(ack)).is_err() {
+            return true;
+        }
+        done.recv_timeout(timeout).is_ok()
+    }
+
+    fn enqueue(&self, db_path: &Path, job: Job) {
+        let request = Request::Write(Write {
+            db_path: db_path.to_path_buf(),
+            job,
+        });
+        if self.sender.send(request).is_err() {
+            error!("Database writer is not running; dropping write");
+        }
+    }
+}
+
+fn run(mut receiver: mpsc::UnboundedReceiver<Request>) {
+    let mut connections: HashMap<PathBuf, Connection> = HashMap::new();
+
+    while let Some(first) = receiver.blocking_recv() {
+        let mut batch = Vec::new();
+        let mut shutdown = None;
+        let mut next = Some(first);
+        while let Some(request) = next.take() {
+            match request {
+                Request::Write(write) => batch.push(write),
+                Request::Shutdown(ack) => {
+                    shutdown = Some(ack);
+                    break;
+                }
+            }
+            if batch.len() < MAX_BATCH {
+                next = receiver.try_recv().ok();
+            }
+        }
+
+        for (db_path, jobs) in group_by_path(batch) {
+            if !connections.contains_key(&db_path) {
+                match Connection::open(&db_path).and_then(|conn| {
+                    conn.busy_timeout(BUSY_TIMEOUT)?;
+                    Ok(conn)
+                }) {
+                    Ok(conn) => {
+                        connections.insert(db_path.clone(), conn);
+                    }
+                    Err(e) => {
+                        error!("Failed to open database at {:?}: {}", db_path, e);
+                        continue;
+                    }
+                }
+            }
+            let conn = connections
+                .get_mut(&db_path)
+                .expect("connection was just opened");
+            if let Err(e) = commit_batch(conn, jobs) {
+                error!("Failed to commit writes to {:?}: {:#}", db_path, e);
+                // Reopen on the next batch in case the connection is broken
+                connections.remove(&db_path);
+            }
+        }
+
+        if let Some(ack) = shutdown {
+            // Closing the last connection also checkpoints the WAL
+            connections.clear();
+            let _ = ack.send(());
+            break;
+        }
+    }
+
+    debug!("Database writer stopped");
+}
+
+/// Split a batch by database file, keeping submission order within each file.
+fn group_by_path(batch: Vec<Write>) -> Vec<(PathBuf, Vec<Job>)> {
+    let mut groups: Vec<(PathBuf, Vec<Job>)> = Vec::new();
+    for write in batch {
+        match groups.iter_mut().find(|(path, _)| *path == write.db_path) {
+            Some((_, jobs)) => jobs.push(write.job),
+            None => groups.push((write.db_path, vec![write.job])),
+        }
+    }
+    groups
+}
+
+fn commit_batch(conn: &mut Connection, jobs: Vec<Job>) -> Result<()> {
+    let mut completions = Vec::new();
+    let result = (|| -> Result<()> {
+        let mut tx = conn.transaction()?;
+        for job in jobs {
+            let savepoint = tx.savepoint()?;
+            let (ok, completion) = job(&savepoint);
+            if ok {
+                savepoint.commit()?;
+            }
+            // A dropped savepoint rolls back the failed write only
+            completions.extend(completion);
+        }
+        tx.commit()?;
+        Ok(())
+    })();
+
+    let outcome = result.as_ref().map(|_| ()).map_err(|e| format!("{:#}", e));
+    for completion in completions {
+        completion(outcome.clone());
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn count_rows(db_path: &Path) -> i64 {
+        Connection::open(db_path)
+            .unwrap()
+            .query_row("SELECT COUNT(*) FROM items", [], |row| row.get(0))
+            .unwrap()
+    }
+
+    #[test]
+    fn failed_write_does_not_roll_back_batch() {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+        let mut conn = Connection::open(&db_path).unwrap();
+        conn.execute_batch("CREATE TABLE items (id INTEGER PRIMARY KEY, name TEXT NOT NULL)")
+            .unwrap();
+
+        let jobs: Vec<Job> = vec![
+            Box::new(|c: &Connection| {
+                (
+                    c.execute("INSERT INTO items (name) VALUES ('a')", [])
+                        .is_ok(),
+                    None,
+                )
+            }),
+            Box::new(|c: &Connection| {
+                (
+                    c.execute("INSERT INTO items (name) VALUES (NULL)", [])
+                        .is_ok(),
+                    None,
+                )
+            }),
+            Box::new(|c: &Connection| {
+                (
+                    c.execute("INSERT INTO items (name) VALUES ('b')", [])
+                        .is_ok(),
+                    None,
+                )
+            }),
+        ];
+        commit_batch(&mut conn, jobs).unwrap();
+
+        assert_eq!(count_rows(&db_path), 2);
+    }
+
+    #[test]
+    fn writes_are_applied_in_order() {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+        Connection::open(&db_path)
+            .unwrap()
+            .execute_batch("CREATE TABLE items (id INTEGER PRIMARY KEY, name TEXT NOT NULL)")
+            .unwrap();
+
+        let writer = DbWriter::new().unwrap();
+        for name in ["a", "b", "c"] {
+            writer.submit(&db_path, move |conn| {
+                conn.execute("INSERT INTO items (name) VALUES (?1)", [name])?;
+                Ok(())
+            });
+        }
+        let last: String = tauri::async_runtime::block_on(writer.write(&db_path, |conn| {
+            Ok(conn.query_row(
+                "SELECT name FROM items ORDER BY id DESC LIMIT 1",
+                [],
+                |row| row.get(0),
+            )?)
+        }))
+        .unwrap();
+
+        assert_eq!(last, "c");
+        assert_eq!(count_rows(&db_path), 3);
+    }
+
+    #[test]
+    fn shutdown_commits_queued_writes() {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+        Connection::open(&db_path)
+            .unwrap()
+            .execute_batch("CREATE TABLE items (id INTEGER PRIMARY KEY, name TEXT NOT NULL)")
+            .unwrap();
+
+        let writer = DbWriter::new().unwrap();
+        writer.submit(&db_path, |conn| {
+            conn.execute("INSERT INTO items (name) VALUES ('a')", [])?;
+            Ok(())
+        });
+        assert!(writer.shutdown(Duration::from_secs(5)));
+        assert_eq!(count_rows(&db_path), 1);
+
+        // The writer is gone; later writes are dropped
+        writer.submit(&db_path, |conn| {
+            conn.execute("INSERT INTO items (name) VALUES ('b')", [])?;
+            Ok(())
+        });
+        assert_eq!(count_rows(&db_path), 1);
+    }
+}
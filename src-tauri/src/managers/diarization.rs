@@ -4,12 +4,16 @@
 //! using sherpa-rs (sherpa-onnx) with pyannote segmentation and 3D-Speaker embedding models.
 
 use anyhow::{Context, Result};
-use log::info;
+use log::{debug, error, info};
+use serde::{Deserialize, Serialize};
 use sherpa_rs::diarize::{Diarize, DiarizeConfig};
+use std::collections::HashMap;
 use std::sync::Arc;
 use tauri::AppHandle;
 
 use super::model::ModelManager;
+use super::transcription::TranscriptionManager;
+use super::transcription_queue::TranscriptionJob;
 
 const SEGMENTATION_MODEL_ID: &str = "diarization-segmentation";
 const EMBEDDING_MODEL_ID: &str = "diarization-embedding";
@@ -22,6 +26,15 @@ pub struct DiarizationSegment {
     pub speaker_id: i32,
 }
 
+/// Transcribed text attributed to one speaker.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SpeakerSegment {
+    pub speaker_label: String,
+    pub start_ms: i64,
+    pub end_ms: i64,
+    pub text: String,
+}
+
 pub struct DiarizationManager {
     model_manager: Arc<ModelManager>,
 }
@@ -99,6 +112,76 @@ impl DiarizationManager {
         Ok(result)
     }
 
+    /// Diarize `samples`, then transcribe each speaker turn on its own as a
+    /// pass of `job`. Turns too short to transcribe or that come back empty
+    /// are skipped.
+    pub fn transcribe_by_speaker(
+        &self,
+        transcription_manager: &TranscriptionManager,
+        job: &TranscriptionJob,
+        samples: &[f32],
+        threshold: f32,
+    ) -> Result<Vec<SpeakerSegment>> {
+        let raw_segments = self.diarize(samples, threshold)?;
+        // Merge consecutive same-speaker segments (max 30s for transcription context)
+        let merged = Self::merge_consecutive(&raw_segments, 30_000);
+        let labels = Self::speaker_labels(&merged);
+
+        let sample_rate: i64 = 16_000;
+        let min_samples: usize = 1600; // 100ms minimum
+
+        let mut result = Vec::new();
+        for seg in &merged {
+            let start_sample = (seg.start_ms * sample_rate / 1000) as usize;
+            let end_sample = ((seg.end_ms * sample_rate / 1000) as usize).min(samples.len());
+
+            if end_sample <= start_sample || (end_sample - start_sample) < min_samples {
+                continue;
+            }
+
+            if job.is_cancelled() {
+                return Err(job.cancelled_error());
+            }
+
+            match transcription_manager
+                .transcribe_job(job, samples[start_sample..end_sample].to_vec())
+                .map(|transcript| transcript.text)
+            {
+                Ok(text) if !text.trim().is_empty() => result.push(SpeakerSegment {
+                    speaker_label: labels[&seg.speaker_id].clone(),
+                    start_ms: seg.start_ms,
+                    end_ms: seg.end_ms,
+                    text: text.trim().to_string(),
+                }),
+                Ok(_) => debug!("Empty transcription for speaker turn at {}ms", seg.start_ms),
+                Err(e) => error!(
+                    "Failed to transcribe speaker turn at {}ms: {}",
+                    seg.start_ms, e
+                ),
+            }
+        }
+
+        info!(
+            "Transcribed {} speaker turns from {} diarization segments",
+            result.len(),
+            raw_segments.len()
+        );
+        Ok(result)
+    }
+
+    /// "Speaker 1", "Speaker 2", ... in the order speakers first talk. The
+    /// raw cluster ids are arbitrary and start at 0.
+    pub fn speaker_labels(segments: &[DiarizationSegment]) -> HashMap<i32, String> {
+        let mut labels = HashMap::new();
+        for seg in segments {
+            let next = labels.len() + 1;
+            labels
+                .entry(seg.speaker_id)
+                .or_insert_with(|| format!("Speaker {}", next));
+        }
+        labels
+    }
+
     /// Merge consecutive same-speaker segments up to a maximum duration.
     /// This produces longer segments for better transcription context.
     pub fn merge_consecutive(
@@ -252,6 +335,17 @@ mod tests {
         assert_eq!(result[2].speaker_id, 0);
     }
 
+    // ── speaker_labels ─────────────────────────────────────────────────
+
+    #[test]
+    fn speaker_labels_number_by_first_appearance() {
+        let input = [seg(0, 1000, 3), seg(1000, 2000, 0), seg(2000, 3000, 3)];
+        let labels = DiarizationManager::speaker_labels(&input);
+        assert_eq!(labels.len(), 2);
+        assert_eq!(labels[&3], "Speaker 1");
+        assert_eq!(labels[&0], "Speaker 2");
+    }
+
     // ── Edge cases ─────────────────────────────────────────────────────
 
     #[test]
@@ -0,0 +1,236 @@
+//! Dictation templates with fill-in slots.
+//!
+//! Starting a template turns the next dictations into slot fills: each one
+//! fills the next `{{slot}}` in the template while the overlay prompts for the
+//! slot after it. Once every slot has text, the assembled result is pasted in
+//! place of the last dictation.
+
+use crate::settings::{self, DictationTemplate};
+use anyhow::{anyhow, Result};
+use log::info;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter};
+
+/// Where a template session is, sent with `dictation-template-changed`.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct TemplateProgress {
+    pub template_id: String,
+    pub name: String,
+    /// The slot the next dictation fills.
+    pub slot: String,
+    pub index: usize,
+    pub total: usize,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum SlotFill {
+    /// More slots to go; the next dictation fills `slot`.
+    Next(TemplateProgress),
+    /// Every slot is filled. The session has ended.
+    Complete(String),
+}
+
+struct TemplateSession {
+    template: DictationTemplate,
+    slots: Vec<String>,
+    values: HashMap<String, String>,
+}
+
+impl TemplateSession {
+    fn progress(&self) -> TemplateProgress {
+        let index = self.values.len();
+        TemplateProgress {
+            template_id: self.template.id.clone(),
+            name: self.template.name.clone(),
+            slot: self.slots[index].clone(),
+            index,
+            total: self.slots.len(),
+        }
+    }
+}
+
+pub struct DictationTemplateManager {
+    app_handle: AppHandle,
+    session: Mutex<Option<TemplateSession>>,
+}
+
+impl DictationTemplateManager {
+    pub fn new(app_handle: &AppHandle) -> Self {
+        Self {
+            app_handle: app_handle.clone(),
+            session: Mutex::new(None),
+        }
+    }
+
+    /// Start filling the template with `id`, replacing any session in progress.
+    pub fn start(&self, id: &str) -> Result<TemplateProgress> {
+        let template = settings::get_settings(&self.app_handle)
+            .dictation_templates
+            .into_iter()
+            .find(|template| template.id == id)
+            .ok_or_else(|| anyhow!("Template '{}' not found", id))?;
+        let slots = slots(&template.template);
+        if slots.is_empty() {
+            return Err(anyhow!("Template '{}' has no {{{{slots}}}}", template.name));
+        }
+
+        info!(
+            "[Template] Starting '{}' ({} slots)",
+            template.name,
+            slots.len()
+        );
+        let session = TemplateSession {
+            template,
+            slots,
+            values: HashMap::new(),
+        };
+        let progress = session.progress();
+        *self.session.lock().unwrap() = Some(session);
+        self.emit(Some(&progress));
+        Ok(progress)
+    }
+
+    pub fn cancel(&self) {
+        if self.session.lock().unwrap().take().is_some() {
+            info!("[Template] Cancelled");
+            self.emit(None);
+        }
+    }
+
+    pub fn progress(&self) -> Option<TemplateProgress> {
+        self.session
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(TemplateSession::progress)
+    }
+
+    /// Fill the current slot with a dictation. Returns `None` when no template
+    /// is in progress, so the dictation should be handled as usual.
+    pub fn fill(&self, text: &str) -> Option<SlotFill> {
+        let mut guard = self.session.lock().unwrap();
+        let session = guard.as_mut()?;
+        let slot = session.slots[session.values.len()].clone();
+        session.values.insert(slot, text.trim().to_string());
+
+        if session.values.len() < session.slots.len() {
+            let progress = session.progress();
+            drop(guard);
+            self.emit(Some(&progress));
+            return Some(SlotFill::Next(progress));
+        }
+
+        let session = guard.take()?;
+        drop(guard);
+        info!("[Template] Completed '{}'", session.template.name);
+        self.emit(None);
+        Some(SlotFill::Complete(assemble(
+            &session.template.template,
+            &session.values,
+        )))
+    }
+
+    fn emit(&self, progress: Option<&TemplateProgress>) {
+        let _ = self.app_handle.emit("dictation-template-changed", progress);
+    }
+}
+
+/// Slot names in the order they first appear in `template`.
+pub fn slots(template: &str) -> Vec<String> {
+    let mut slots: Vec<String> = Vec::new();
+    for (_, name, _) in placeholders(template) {
+        if !slots.iter().any(|slot| slot == name) {
+            slots.push(name.to_string());
+        }
+    }
+    slots
+}
+
+/// Replace each `{{slot}}` with its value. A value's closing punctuation is
+/// dropped when the template punctuates right after the slot, so "Hi Sam."
+/// in "{{greeting}}," gives "Hi Sam,". Slots without a value are left as-is.
+pub fn assemble(template: &str, values: &HashMap<String, String>) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut last = 0;
+    for (start, name, end) in placeholders(template) {
+        let Some(value) = values.get(name) else {
+            continue;
+        };
+        out.push_str(&template[last..start]);
+        let punctuated = template[end..].starts_with(|c: char| ",.;:!?".contains(c));
+        if punctuated {
+            out.push_str(value.trim_end_matches(|c: char| ",.;:!?".contains(c)));
+        } else {
+            out.push_str(value);
+        }
+        last = end;
+    }
+    out.push_str(&template[last..]);
+    out
+}
+
+/// `(start, trimmed name, end)` byte spans of the `{{name}}` placeholders.
+fn placeholders(template: &str) -> Vec<(usize, &str, usize)> {
+    let mut found = Vec::new();
+    let mut from = 0;
+    while let Some(open) = template[from..].find("{{").map(|i| from + i) {
+        let Some(close) = template[open + 2..].find("}}").map(|i| open + 2 + i) else {
+            break;
+        };
+        let name = template[open + 2..close].trim();
+        if !name.is_empty() && !name.contains('{') {
+            found.push((open, name, close + 2));
+            from = close + 2;
+        } else {
+            from = open + 2;
+        }
+    }
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EMAIL: &str = "{{greeting}},\n\n{{ body }}\n\n{{signature}}";
+
+    fn values(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn finds_slots_in_order_once() {
+        assert_eq!(slots(EMAIL), ["greeting", "body", "signature"]);
+        assert_eq!(
+            slots("{{name}} and {{name}} again, {{other}}"),
+            ["name", "other"]
+        );
+        assert!(slots("no slots, {{}} or {{ unclosed").is_empty());
+    }
+
+    #[test]
+    fn assembles_with_punctuation_fixed() {
+        let text = assemble(
+            EMAIL,
+            &values(&[
+                ("greeting", "Hi Sam."),
+                ("body", "The report is attached."),
+                ("signature", "Best, Alex."),
+            ]),
+        );
+        assert_eq!(text, "Hi Sam,\n\nThe report is attached.\n\nBest, Alex.");
+    }
+
+    #[test]
+    fn leaves_unfilled_slots() {
+        assert_eq!(
+            assemble("{{a}} then {{b}}", &values(&[("a", "first")])),
+            "first then {{b}}"
+        );
+    }
+}
@@ -0,0 +1,334 @@
+//! Daily digest generation.
+//!
+//! A background scheduler compiles the previous day's input tracking and
+//! dictation activity into a Markdown report: top apps, word counts, dictation
+//! minutes and notable transcriptions. Digests are stored in the history
+//! database, optionally written to a folder, and can be prefaced with a short
+//! summary from the configured post-processing LLM.
+
+mod report;
+
+use anyhow::{Context, Result};
+use chrono::{Days, Local, NaiveDate, TimeZone};
+use log::{debug, error, info, warn};
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::thread;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+
+use super::database;
+use super::scheduler;
+use crate::audio_toolkit::recording_duration_secs;
+use crate::audio_toolkit::thread_priority::{apply_current_thread_priority, ThreadPriority};
+use crate::settings;
+use report::{count_words, notable_transcriptions, render_markdown, top_apps};
+
+/// How often the scheduler checks whether yesterday's digest is due.
+const SCHEDULE_INTERVAL: Duration = Duration::from_secs(10 * 60);
+/// Number of apps listed in the "Top apps" section.
+const TOP_APPS: usize = 5;
+/// Number of transcriptions quoted in the "Notable transcriptions" section.
+const NOTABLE_TRANSCRIPTIONS: usize = 3;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DailyDigest {
+    /// Local calendar date, `YYYY-MM-DD`.
+    pub date: String,
+    pub markdown: String,
+    pub created_at: i64,
+    /// Where the report was written, when a digest folder is configured.
+    pub file_path: Option<String>,
+}
+
+/// Raw numbers for one day, rendered by [`render_markdown`].
+#[derive(Debug, Default, PartialEq)]
+pub struct DigestStats {
+    /// `(app name, words typed)`, most active first.
+    pub top_apps: Vec<(String, usize)>,
+    pub typed_words: usize,
+    pub dictated_words: usize,
+    pub dictation_seconds: f64,
+    pub transcription_count: usize,
+    pub notable: Vec<String>,
+}
+
+impl DigestStats {
+    fn is_empty(&self) -> bool {
+        self.typed_words == 0 && self.transcription_count == 0
+    }
+}
+
+pub struct DigestManager {
+    app_handle: AppHandle,
+    recordings_dir: PathBuf,
+    db_path: PathBuf,
+    /// Input tracking keeps its entries in a separate database.
+    input_db_path: PathBuf,
+}
+
+impl DigestManager {
+    pub fn new(app_handle: &AppHandle) -> Result<Self> {
+        let app_data_dir = crate::profile::data_dir(app_handle)?;
+        let db_path = app_data_dir.join("history.db");
+
+        database::initialize_database(&db_path)
+            .context("Failed to initialize database for digests")?;
+
+        let scheduler_handle = app_handle.clone();
+        thread::spawn(move || run_scheduler(scheduler_handle));
+
+        Ok(Self {
+            app_handle: app_handle.clone(),
+            recordings_dir: app_data_dir.join("recordings"),
+            db_path,
+            input_db_path: app_data_dir.join("echo.db"),
+        })
+    }
+
+    fn get_connection(&self) -> Result<Connection> {
+        Connection::open(&self.db_path)
+            .with_context(|| format!("Failed to open database at {:?}", self.db_path))
+    }
+
+    /// Build (or rebuild) the digest for `date`, store it and write it to the
+    /// digest folder if one is configured.
+    pub async fn generate(&self, date: NaiveDate) -> Result<DailyDigest> {
+        let app_settings = settings::get_settings(&self.app_handle);
+        let stats = self.collect_stats(date)?;
+
+        let mut summary = None;
+        if app_settings.daily_digest_llm_summary && !stats.is_empty() {
+            let report = render_markdown(date, &stats, None);
+            match crate::llm_client::complete_with_active_provider(
+                &app_settings,
+                "You summarize a person's daily computer activity report in two or three friendly sentences.",
+                report,
+            )
+            .await
+            {
+                Ok(text) if !text.trim().is_empty() => summary = Some(text.trim().to_string()),
+                Ok(_) => {}
+                Err(e) => warn!("[Digest] LLM summary failed, continuing without it: {}", e),
+            }
+        }
+
+        let markdown = render_markdown(date, &stats, summary.as_deref());
+        let file_path = match &app_settings.daily_digest_folder {
+            Some(folder) if !folder.trim().is_empty() => {
+                let path = PathBuf::from(folder).join(format!("echo-digest-{}.md", date));
+                fs::create_dir_all(folder)
+                    .and_then(|_| fs::write(&path, &markdown))
+                    .with_context(|| format!("Failed to write digest to {:?}", path))?;
+                Some(path.to_string_lossy().into_owned())
+            }
+            _ => None,
+        };
+
+        let digest = DailyDigest {
+            date: date.to_string(),
+            markdown,
+            created_at: chrono::Utc::now().timestamp(),
+            file_path,
+        };
+
+        let conn = self.get_connection()?;
+        conn.execute(
+            "INSERT OR REPLACE INTO daily_digests (date, markdown, created_at, file_path) VALUES (?1, ?2, ?3, ?4)",
+            params![digest.date, digest.markdown, digest.created_at, digest.file_path],
+        )?;
+
+        info!("[Digest] Generated digest for {}", digest.date);
+        if let Err(e) = self.app_handle.emit("daily-digest-generated", &digest.date) {
+            error!("Failed to emit daily-digest-generated event: {}", e);
+        }
+
+        Ok(digest)
+    }
+
+    pub fn get_digest(&self, date: NaiveDate) -> Result<Option<DailyDigest>> {
+        let conn = self.get_connection()?;
+        let digest = conn
+            .query_row(
+                "SELECT date, markdown, created_at, file_path FROM daily_digests WHERE date = ?1",
+                params![date.to_string()],
+                |row| {
+                    Ok(DailyDigest {
+                        date: row.get("date")?,
+                        markdown: row.get("markdown")?,
+                        created_at: row.get("created_at")?,
+                        file_path: row.get("file_path")?,
+                    })
+                },
+            )
+            .optional()?;
+        Ok(digest)
+    }
+
+    pub fn list_digests(&self) -> Result<Vec<DailyDigest>> {
+        let conn = self.get_connection()?;
+        let mut stmt = conn.prepare(
+            "SELECT date, markdown, created_at, file_path FROM daily_digests ORDER BY date DESC",
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok(DailyDigest {
+                date: row.get("date")?,
+                markdown: row.get("markdown")?,
+                created_at: row.get("created_at")?,
+                file_path: row.get("file_path")?,
+            })
+        })?;
+
+        let mut digests = Vec::new();
+        for row in rows {
+            digests.push(row?);
+        }
+        Ok(digests)
+    }
+
+    fn collect_stats(&self, date: NaiveDate) -> Result<DigestStats> {
+        let (start, end) = local_day_bounds(date)?;
+        let mut stats = DigestStats::default();
+
+        let mut app_words: HashMap<String, usize> = HashMap::new();
+        for (app_name, content) in self.input_entries_between(start, end)? {
+            let words = count_words(&content);
+            stats.typed_words += words;
+            *app_words.entry(app_name).or_default() += words;
+        }
+        stats.top_apps = top_apps(app_words, TOP_APPS);
+
+        let conn = self.get_connection()?;
+
+        let mut stmt = conn.prepare(
+            "SELECT file_name, saved, transcription_text, post_processed_text FROM transcription_history
+             WHERE timestamp >= ?1 AND timestamp < ?2",
+        )?;
+        let rows = stmt.query_map(params![start, end], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, bool>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, Option<String>>(3)?,
+            ))
+        })?;
+
+        let mut candidates = Vec::new();
+        for row in rows {
+            let (file_name, saved, text, post_processed) = row?;
+            let text = post_processed.unwrap_or(text);
+            let words = count_words(&text);
+            stats.transcription_count += 1;
+            stats.dictated_words += words;
+            stats.dictation_seconds += self.recording_seconds(&file_name);
+            candidates.push((saved, words, text));
+        }
+        stats.notable = notable_transcriptions(candidates, NOTABLE_TRANSCRIPTIONS);
+
+        Ok(stats)
+    }
+
+    /// `(app name, content)` of input entries in `[start, end)`. Empty if input
+    /// tracking has never saved anything.
+    fn input_entries_between(&self, start: i64, end: i64) -> Result<Vec<(String, String)>> {
+        if !self.input_db_path.exists() {
+            return Ok(Vec::new());
+        }
+        let conn = Connection::open(&self.input_db_path)
+            .with_context(|| format!("Failed to open database at {:?}", self.input_db_path))?;
+        let has_table: bool = conn.query_row(
+            "SELECT COUNT(*) > 0 FROM sqlite_master WHERE type='table' AND name='input_entries'",
+            [],
+            |row| row.get(0),
+        )?;
+        if !has_table {
+            return Ok(Vec::new());
+        }
+
+        let mut stmt = conn.prepare(
+            "SELECT app_name, content FROM input_entries WHERE timestamp >= ?1 AND timestamp < ?2",
+        )?;
+        let rows = stmt.query_map(params![start, end], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+        })?;
+        let mut entries = Vec::new();
+        for row in rows {
+            entries.push(row?);
+        }
+        Ok(entries)
+    }
+
+    /// Duration of a stored recording, or 0 if it has been cleaned up.
+    fn recording_seconds(&self, file_name: &str) -> f64 {
+        recording_duration_secs(self.recordings_dir.join(file_name)).unwrap_or(0.0)
+    }
+}
+
+/// Generate yesterday's digest once per day while the feature is enabled.
+fn run_scheduler(app_handle: AppHandle) {
+    if settings::get_settings(&app_handle).thread_priorities_enabled {
+        apply_current_thread_priority(ThreadPriority::Background);
+    }
+    let mut last_checked: Option<NaiveDate> = None;
+    scheduler::run_every(&app_handle, SCHEDULE_INTERVAL, |manager: &DigestManager| {
+        if settings::get_settings(&app_handle).daily_digest_enabled {
+            generate_if_due(manager, &mut last_checked);
+        }
+    });
+}
+
+fn generate_if_due(manager: &DigestManager, last_checked: &mut Option<NaiveDate>) {
+    let Some(yesterday) = Local::now().date_naive().checked_sub_days(Days::new(1)) else {
+        return;
+    };
+    if *last_checked == Some(yesterday) {
+        return;
+    }
+    *last_checked = Some(yesterday);
+
+    match manager.get_digest(yesterday) {
+        Ok(Some(_)) => debug!("[Digest] Digest for {} already exists", yesterday),
+        Ok(None) => match manager.collect_stats(yesterday) {
+            Ok(stats) if stats.is_empty() => {
+                debug!("[Digest] No activity on {}, skipping", yesterday)
+            }
+            Ok(_) => {
+                if let Err(e) = tauri::async_runtime::block_on(manager.generate(yesterday)) {
+                    error!("[Digest] Failed to generate digest: {}", e);
+                }
+            }
+            Err(e) => error!("[Digest] Failed to collect stats: {}", e),
+        },
+        Err(e) => error!("[Digest] Failed to look up digest: {}", e),
+    }
+}
+
+/// Start and end (exclusive) of a local calendar day as Unix seconds.
+fn local_day_bounds(date: NaiveDate) -> Result<(i64, i64)> {
+    let start_of = |day: NaiveDate| {
+        day.and_hms_opt(0, 0, 0)
+            .and_then(|midnight| Local.from_local_datetime(&midnight).earliest())
+            .map(|dt| dt.timestamp())
+            .with_context(|| format!("Invalid local date {}", day))
+    };
+    let next = date
+        .checked_add_days(Days::new(1))
+        .context("Date out of range")?;
+    Ok((start_of(date)?, start_of(next)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn day_bounds_span_one_day() {
+        let date = NaiveDate::from_ymd_opt(2024, 6, 1).unwrap();
+        let (start, end) = local_day_bounds(date).unwrap();
+        // 23-25h depending on DST transitions in the local zone.
+        assert!((23 * 3600..=25 * 3600).contains(&(end - start)));
+    }
+}
@@ -0,0 +1,156 @@
+//! Picking what goes into a digest and rendering it as Markdown.
+
+use chrono::NaiveDate;
+use std::collections::HashMap;
+
+use super::DigestStats;
+
+/// Notable transcriptions are truncated to this many characters.
+const NOTABLE_MAX_CHARS: usize = 280;
+
+pub(super) fn count_words(text: &str) -> usize {
+    text.split_whitespace().count()
+}
+
+pub(super) fn top_apps(app_words: HashMap<String, usize>, limit: usize) -> Vec<(String, usize)> {
+    let mut apps: Vec<(String, usize)> = app_words.into_iter().filter(|(_, w)| *w > 0).collect();
+    apps.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    apps.truncate(limit);
+    apps
+}
+
+/// Pick starred transcriptions first, then the longest ones.
+pub(super) fn notable_transcriptions(
+    mut candidates: Vec<(bool, usize, String)>,
+    limit: usize,
+) -> Vec<String> {
+    candidates.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| b.1.cmp(&a.1)));
+    candidates
+        .into_iter()
+        .filter(|(_, words, _)| *words > 0)
+        .take(limit)
+        .map(|(_, _, text)| truncate_chars(text.trim(), NOTABLE_MAX_CHARS))
+        .collect()
+}
+
+fn truncate_chars(text: &str, max_chars: usize) -> String {
+    match text.char_indices().nth(max_chars) {
+        Some((idx, _)) => format!("{}…", text[..idx].trim_end()),
+        None => text.to_string(),
+    }
+}
+
+/// Render a day's stats as Markdown, with an optional LLM summary up top.
+pub(super) fn render_markdown(
+    date: NaiveDate,
+    stats: &DigestStats,
+    summary: Option<&str>,
+) -> String {
+    let mut out = format!("# Daily digest — {}\n\n", date.format("%A, %B %-d, %Y"));
+
+    if let Some(summary) = summary {
+        out.push_str(&format!("{}\n\n", summary));
+    }
+
+    out.push_str("## At a glance\n\n");
+    out.push_str(&format!("- Words typed: {}\n", stats.typed_words));
+    out.push_str(&format!(
+        "- Words dictated: {} across {} transcription{}\n",
+        stats.dictated_words,
+        stats.transcription_count,
+        if stats.transcription_count == 1 {
+            ""
+        } else {
+            "s"
+        }
+    ));
+    out.push_str(&format!(
+        "- Dictation time: {:.1} min\n\n",
+        stats.dictation_seconds / 60.0
+    ));
+
+    if !stats.top_apps.is_empty() {
+        out.push_str("## Top apps\n\n");
+        for (i, (app, words)) in stats.top_apps.iter().enumerate() {
+            out.push_str(&format!("{}. {} — {} words\n", i + 1, app, words));
+        }
+        out.push('\n');
+    }
+
+    if !stats.notable.is_empty() {
+        out.push_str("## Notable transcriptions\n\n");
+        for text in &stats.notable {
+            out.push_str(&format!("> {}\n\n", text.replace('\n', "\n> ")));
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn top_apps_sorted_by_words_and_truncated() {
+        let words = HashMap::from([
+            ("Slack".to_string(), 120),
+            ("Mail".to_string(), 300),
+            ("Notes".to_string(), 0),
+            ("Code".to_string(), 120),
+        ]);
+        assert_eq!(
+            top_apps(words, 2),
+            vec![("Mail".to_string(), 300), ("Code".to_string(), 120)]
+        );
+    }
+
+    #[test]
+    fn notable_prefers_saved_then_longest() {
+        let picked = notable_transcriptions(
+            vec![
+                (false, 10, "long one".to_string()),
+                (true, 2, "starred".to_string()),
+                (false, 3, "short".to_string()),
+                (false, 0, "".to_string()),
+            ],
+            2,
+        );
+        assert_eq!(picked, vec!["starred".to_string(), "long one".to_string()]);
+    }
+
+    #[test]
+    fn truncate_chars_respects_char_boundaries() {
+        assert_eq!(truncate_chars("héllo wörld", 5), "héllo…");
+        assert_eq!(truncate_chars("short", 10), "short");
+    }
+
+    #[test]
+    fn markdown_includes_sections_and_summary() {
+        let stats = DigestStats {
+            top_apps: vec![("Slack".to_string(), 42)],
+            typed_words: 42,
+            dictated_words: 7,
+            dictation_seconds: 90.0,
+            transcription_count: 1,
+            notable: vec!["Remember to ship the release".to_string()],
+        };
+        let date = NaiveDate::from_ymd_opt(2024, 3, 5).unwrap();
+        let md = render_markdown(date, &stats, Some("A productive day."));
+
+        assert!(md.starts_with("# Daily digest — Tuesday, March 5, 2024"));
+        assert!(md.contains("A productive day."));
+        assert!(md.contains("- Words dictated: 7 across 1 transcription\n"));
+        assert!(md.contains("- Dictation time: 1.5 min"));
+        assert!(md.contains("1. Slack — 42 words"));
+        assert!(md.contains("> Remember to ship the release"));
+    }
+
+    #[test]
+    fn markdown_omits_empty_sections() {
+        let date = NaiveDate::from_ymd_opt(2024, 3, 5).unwrap();
+        let md = render_markdown(date, &DigestStats::default(), None);
+        assert!(!md.contains("## Top apps"));
+        assert!(!md.contains("## Notable transcriptions"));
+    }
+}
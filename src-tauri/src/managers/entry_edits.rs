@@ -0,0 +1,145 @@
+//! Matching tracked input against pasted dictations.
+//!
+//! When a dictation is pasted and then edited, the tracked input entry contains
+//! the edited text, possibly surrounded by text typed before or after it. These
+//! helpers find the span of the entry that corresponds to the dictation (via a
+//! word-level longest common subsequence) and measure how much it was changed.
+
+use serde::Serialize;
+
+/// Fraction of the dictation's words that must reappear in the entry for the
+/// two to be linked.
+const MIN_MATCH_RATIO: f64 = 0.5;
+/// Longer texts are not compared, to keep the quadratic alignment cheap.
+const MAX_WORDS: usize = 2000;
+
+/// A tracked edit of a pasted dictation.
+#[derive(Clone, Debug, Serialize)]
+pub struct EntryEdit {
+    pub id: i64,
+    pub history_id: i64,
+    pub app_name: String,
+    pub original_text: String,
+    pub edited_text: String,
+    /// Word-level Levenshtein distance between the two texts.
+    pub word_edit_distance: i64,
+    pub original_word_count: i64,
+    pub timestamp: i64,
+}
+
+/// How much dictations get corrected in one app.
+#[derive(Clone, Debug, Serialize)]
+pub struct AppEditStats {
+    pub app_name: String,
+    pub linked_count: i64,
+    /// Linked dictations that were changed at all.
+    pub edited_count: i64,
+    /// Total word edits divided by total dictated words.
+    pub word_edit_rate: f64,
+}
+
+/// The part of `content` that corresponds to `dictation`, if enough of the
+/// dictation's words survive in it.
+pub fn match_dictation(dictation: &str, content: &str) -> Option<String> {
+    let original: Vec<&str> = dictation.split_whitespace().collect();
+    let edited: Vec<&str> = content.split_whitespace().collect();
+    if original.is_empty() || edited.is_empty() || original.len().max(edited.len()) > MAX_WORDS {
+        return None;
+    }
+
+    let original_norm: Vec<String> = original.iter().copied().map(normalize).collect();
+    let edited_norm: Vec<String> = edited.iter().copied().map(normalize).collect();
+    let (matched, span) = lcs_span(&original_norm, &edited_norm);
+
+    if (matched as f64) < original.len() as f64 * MIN_MATCH_RATIO {
+        return None;
+    }
+    let (start, end) = span?;
+    Some(edited[start..=end].join(" "))
+}
+
+/// Word-level Levenshtein distance.
+pub fn word_edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<String> = a.split_whitespace().map(normalize).collect();
+    let b: Vec<String> = b.split_whitespace().map(normalize).collect();
+
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, word_a) in a.iter().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, word_b) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(word_a != word_b);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// Lowercase and strip surrounding punctuation so "Hello," matches "hello".
+pub(crate) fn normalize(word: &str) -> String {
+    word.trim_matches(|c: char| !c.is_alphanumeric())
+        .to_lowercase()
+}
+
+/// Length of the longest common subsequence and the inclusive span of `b`
+/// it covers.
+fn lcs_span(a: &[String], b: &[String]) -> (usize, Option<(usize, usize)>) {
+    let mut table = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            table[i][j] = if a[i] == b[j] {
+                table[i + 1][j + 1] + 1
+            } else {
+                table[i + 1][j].max(table[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let mut span: Option<(usize, usize)> = None;
+    while i < a.len() && j < b.len() {
+        if a[i] == b[j] {
+            span = Some((span.map_or(j, |(start, _)| start), j));
+            i += 1;
+            j += 1;
+        } else if table[i + 1][j] >= table[i][j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    (table[0][0], span)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_edited_dictation_inside_typed_text() {
+        let matched = match_dictation(
+            "Let's meet on Tuesday at three",
+            "Hi Sam, let's meet on Wednesday at three. Thanks",
+        );
+        assert_eq!(
+            matched.as_deref(),
+            Some("let's meet on Wednesday at three.")
+        );
+    }
+
+    #[test]
+    fn unrelated_text_is_not_matched() {
+        assert_eq!(
+            match_dictation("Let's meet on Tuesday at three", "buy milk and eggs"),
+            None
+        );
+    }
+
+    #[test]
+    fn edit_distance_counts_word_changes() {
+        assert_eq!(word_edit_distance("the quick fox", "The quick fox."), 0);
+        assert_eq!(word_edit_distance("the quick fox", "the slow fox"), 1);
+        assert_eq!(word_edit_distance("the quick fox", "the fox jumps high"), 3);
+        assert_eq!(word_edit_distance("", "one two"), 2);
+    }
+}
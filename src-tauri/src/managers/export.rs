@@ -1,9 +1,16 @@
 //! Meeting export engine — SRT, VTT, TXT, and Markdown serializers.
+//! History entries with speaker segments or timestamps are exported through
+//! the same serializers.
 
+use crate::managers::diarization::SpeakerSegment;
 use crate::managers::meeting::{
-    format_ms_to_hms, format_ms_to_srt_time, format_ms_to_vtt_time, ExportFormat, Meeting,
-    MeetingSegment,
+    format_ms_to_hms, format_ms_to_srt_time, format_ms_to_vtt_time, AudioSource, ExportFormat,
+    Meeting, MeetingSegment,
 };
+use crate::managers::transcription::TimedSegment;
+
+/// Longest subtitle cue built from timestamps.
+const MAX_CUE_MS: i64 = 5_000;
 
 /// Export meeting transcript in the requested format.
 pub fn export(
@@ -19,16 +26,68 @@ pub fn export(
     }
 }
 
+/// Group word or segment timestamps into subtitle-sized cues without a
+/// speaker. A cue ends at a sentence boundary or once it reaches
+/// `MAX_CUE_MS`.
+pub fn cues_from_timestamps(timestamps: &[TimedSegment]) -> Vec<SpeakerSegment> {
+    let mut cues: Vec<SpeakerSegment> = Vec::new();
+    let mut open = false;
+    for item in timestamps {
+        match cues.last_mut() {
+            Some(cue) if open && item.end_ms - cue.start_ms <= MAX_CUE_MS => {
+                cue.end_ms = item.end_ms;
+                cue.text.push(' ');
+                cue.text.push_str(&item.text);
+            }
+            _ => cues.push(SpeakerSegment {
+                speaker_label: String::new(),
+                start_ms: item.start_ms,
+                end_ms: item.end_ms,
+                text: item.text.clone(),
+            }),
+        }
+        open = !item.text.ends_with(['.', '?', '!']);
+    }
+    cues
+}
+
+/// Export a history entry's speaker segments, or cues without speakers.
+pub fn export_history(title: &str, segments: &[SpeakerSegment], format: &ExportFormat) -> String {
+    let segments: Vec<MeetingSegment> = segments
+        .iter()
+        .map(|seg| MeetingSegment {
+            id: 0,
+            meeting_id: 0,
+            speaker_label: seg.speaker_label.clone(),
+            start_ms: seg.start_ms,
+            end_ms: seg.end_ms,
+            text: seg.text.clone(),
+            confidence: None,
+            audio_source: AudioSource::Mic.as_str().to_string(),
+        })
+        .collect();
+
+    match format {
+        ExportFormat::Srt => export_srt(&segments),
+        ExportFormat::Vtt => export_vtt(&segments),
+        ExportFormat::Txt => export_txt(&segments),
+        ExportFormat::Markdown => {
+            let mut out = format!("# {}\n\n", title);
+            push_markdown_transcript(&mut out, &segments);
+            out
+        }
+    }
+}
+
 fn export_srt(segments: &[MeetingSegment]) -> String {
     let mut out = String::new();
     for (i, seg) in segments.iter().enumerate() {
         out.push_str(&format!(
-            "{}\n{} --> {}\n{}: {}\n\n",
+            "{}\n{} --> {}\n{}\n\n",
             i + 1,
             format_ms_to_srt_time(seg.start_ms),
             format_ms_to_srt_time(seg.end_ms),
-            seg.speaker_label,
-            seg.text,
+            with_speaker(seg),
         ));
     }
     out
@@ -37,11 +96,16 @@ fn export_srt(segments: &[MeetingSegment]) -> String {
 fn export_vtt(segments: &[MeetingSegment]) -> String {
     let mut out = String::from("WEBVTT\n\n");
     for seg in segments {
+        let voice = if seg.speaker_label.is_empty() {
+            String::new()
+        } else {
+            format!("<v {}>", seg.speaker_label)
+        };
         out.push_str(&format!(
-            "{} --> {}\n<v {}>{}\n\n",
+            "{} --> {}\n{}{}\n\n",
             format_ms_to_vtt_time(seg.start_ms),
             format_ms_to_vtt_time(seg.end_ms),
-            seg.speaker_label,
+            voice,
             seg.text,
         ));
     }
@@ -52,10 +116,9 @@ fn export_txt(segments: &[MeetingSegment]) -> String {
     let mut out = String::new();
     for seg in segments {
         out.push_str(&format!(
-            "[{}] {}: {}\n",
+            "[{}] {}\n",
             format_ms_to_hms(seg.start_ms),
-            seg.speaker_label,
-            seg.text,
+            with_speaker(seg),
         ));
     }
     out
@@ -70,16 +133,37 @@ fn export_markdown(meeting: &Meeting, segments: &[MeetingSegment]) -> String {
         out.push_str("\n\n");
     }
 
+    push_markdown_transcript(&mut out, segments);
+    out
+}
+
+fn push_markdown_transcript(out: &mut String, segments: &[MeetingSegment]) {
     out.push_str("## Transcript\n\n");
     for seg in segments {
-        out.push_str(&format!(
-            "**[{}] {}:**\n{}\n\n",
-            format_ms_to_hms(seg.start_ms),
-            seg.speaker_label,
-            seg.text,
-        ));
+        if seg.speaker_label.is_empty() {
+            out.push_str(&format!(
+                "**[{}]**\n{}\n\n",
+                format_ms_to_hms(seg.start_ms),
+                seg.text,
+            ));
+        } else {
+            out.push_str(&format!(
+                "**[{}] {}:**\n{}\n\n",
+                format_ms_to_hms(seg.start_ms),
+                seg.speaker_label,
+                seg.text,
+            ));
+        }
+    }
+}
+
+/// "Speaker: text", or just the text for segments without a speaker.
+fn with_speaker(seg: &MeetingSegment) -> String {
+    if seg.speaker_label.is_empty() {
+        seg.text.clone()
+    } else {
+        format!("{}: {}", seg.speaker_label, seg.text)
     }
-    out
 }
 
 #[cfg(test)]
@@ -266,4 +350,80 @@ mod tests {
         let md = export(&meeting, &segments, &ExportFormat::Markdown);
         assert!(md.starts_with("# Weekly"), "Markdown should start with title heading");
     }
+
+    // ── History entries ────────────────────────────────────────────────
+
+    #[test]
+    fn history_export_uses_speaker_labels() {
+        let segments = [
+            SpeakerSegment {
+                speaker_label: "Speaker 1".to_string(),
+                start_ms: 0,
+                end_ms: 4_000,
+                text: "Shall we start?".to_string(),
+            },
+            SpeakerSegment {
+                speaker_label: "Speaker 2".to_string(),
+                start_ms: 4_000,
+                end_ms: 9_000,
+                text: "Yes, go ahead.".to_string(),
+            },
+        ];
+
+        let txt = export_history("Interview", &segments, &ExportFormat::Txt);
+        assert_eq!(
+            txt,
+            "[00:00:00] Speaker 1: Shall we start?\n[00:00:04] Speaker 2: Yes, go ahead.\n"
+        );
+
+        let md = export_history("Interview", &segments, &ExportFormat::Markdown);
+        assert!(md.starts_with("# Interview\n\n## Transcript\n\n"));
+        assert!(md.contains("**[00:00:04] Speaker 2:**\nYes, go ahead."));
+    }
+
+    #[test]
+    fn timestamps_group_into_sentence_cues() {
+        let words: Vec<TimedSegment> = [
+            (0, 400, "Hello"),
+            (400, 900, "there."),
+            (1_200, 1_500, "This"),
+            (1_500, 1_800, "is"),
+            (1_800, 2_400, "subtitled"),
+        ]
+        .iter()
+        .map(|&(start_ms, end_ms, text)| TimedSegment {
+            start_ms,
+            end_ms,
+            text: text.to_string(),
+            confidence: None,
+        })
+        .collect();
+
+        let cues = cues_from_timestamps(&words);
+        assert_eq!(cues.len(), 2);
+        assert_eq!((cues[0].start_ms, cues[0].end_ms), (0, 900));
+        assert_eq!(cues[1].text, "This is subtitled");
+
+        let srt = export_history("Clip", &cues, &ExportFormat::Srt);
+        assert!(srt.contains("00:00:01,200 --> 00:00:02,400\nThis is subtitled\n"));
+        let vtt = export_history("Clip", &cues, &ExportFormat::Vtt);
+        assert!(!vtt.contains("<v"));
+    }
+
+    #[test]
+    fn long_runs_split_at_max_cue_length() {
+        let words: Vec<TimedSegment> = (0..20)
+            .map(|i| TimedSegment {
+                start_ms: i * 500,
+                end_ms: i * 500 + 400,
+                text: "word".to_string(),
+                confidence: None,
+            })
+            .collect();
+        let cues = cues_from_timestamps(&words);
+        assert!(cues
+            .iter()
+            .all(|cue| cue.end_ms - cue.start_ms <= MAX_CUE_MS));
+        assert_eq!(cues.len(), 2);
+    }
 }
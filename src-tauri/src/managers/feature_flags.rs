@@ -0,0 +1,147 @@
+//! Feature flags for beta subsystems.
+//!
+//! Every flag has a built-in default. Overrides are persisted in settings
+//! (`feature_flags`, keyed by flag id) and changed with the
+//! `set_feature_flag` command; clearing an override returns the flag to its
+//! default, so a default can change in a later release for everyone who
+//! never touched it.
+
+use crate::settings;
+use log::info;
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FeatureFlag {
+    /// Let the post-processing LLM call tools (notes, sound theme) when voice
+    /// commands are on
+    AiTools,
+    /// Live captions of system audio
+    LiveCaptions,
+}
+
+impl FeatureFlag {
+    pub const ALL: [FeatureFlag; 2] = [FeatureFlag::AiTools, FeatureFlag::LiveCaptions];
+
+    pub fn id(self) -> &'static str {
+        match self {
+            FeatureFlag::AiTools => "ai_tools",
+            FeatureFlag::LiveCaptions => "live_captions",
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            FeatureFlag::AiTools => "AI Tools",
+            FeatureFlag::LiveCaptions => "Live Captions",
+        }
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            FeatureFlag::AiTools => {
+                "Let voice commands run actions such as creating notes or changing the sound theme."
+            }
+            FeatureFlag::LiveCaptions => {
+                "Caption system audio in an always-on-top window from the Meetings page."
+            }
+        }
+    }
+
+    fn default_enabled(self) -> bool {
+        match self {
+            FeatureFlag::AiTools => true,
+            FeatureFlag::LiveCaptions => false,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct FeatureFlagState {
+    pub id: FeatureFlag,
+    pub name: &'static str,
+    pub description: &'static str,
+    pub enabled: bool,
+    pub default_enabled: bool,
+    /// Whether the user has overridden the default
+    pub overridden: bool,
+}
+
+pub struct FeatureFlagManager {
+    app_handle: AppHandle,
+}
+
+impl FeatureFlagManager {
+    pub fn new(app_handle: &AppHandle) -> Self {
+        Self {
+            app_handle: app_handle.clone(),
+        }
+    }
+
+    pub fn is_enabled(&self, flag: FeatureFlag) -> bool {
+        settings::get_settings(&self.app_handle)
+            .feature_flags
+            .get(flag.id())
+            .copied()
+            .unwrap_or(flag.default_enabled())
+    }
+
+    pub fn list(&self) -> Vec<FeatureFlagState> {
+        let overrides = settings::get_settings(&self.app_handle).feature_flags;
+        FeatureFlag::ALL
+            .iter()
+            .map(|&flag| {
+                let value = overrides.get(flag.id()).copied();
+                FeatureFlagState {
+                    id: flag,
+                    name: flag.name(),
+                    description: flag.description(),
+                    enabled: value.unwrap_or(flag.default_enabled()),
+                    default_enabled: flag.default_enabled(),
+                    overridden: value.is_some(),
+                }
+            })
+            .collect()
+    }
+
+    /// Override a flag, or with `None` return it to its default. Returns
+    /// whether the flag is now enabled.
+    pub fn set(&self, flag: FeatureFlag, enabled: Option<bool>) -> bool {
+        settings::update_settings(&self.app_handle, |s| match enabled {
+            Some(enabled) => {
+                s.feature_flags.insert(flag.id().to_string(), enabled);
+            }
+            None => {
+                s.feature_flags.remove(flag.id());
+            }
+        });
+        let now_enabled = self.is_enabled(flag);
+        info!(
+            "Feature flag '{}' {} ({})",
+            flag.id(),
+            if now_enabled { "enabled" } else { "disabled" },
+            if enabled.is_some() {
+                "override"
+            } else {
+                "default"
+            }
+        );
+        now_enabled
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids_match_serialized_names() {
+        for flag in FeatureFlag::ALL {
+            assert_eq!(
+                serde_json::to_value(flag).unwrap(),
+                serde_json::Value::String(flag.id().to_string())
+            );
+        }
+    }
+}
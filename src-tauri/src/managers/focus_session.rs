@@ -0,0 +1,321 @@
+//! Named focus sessions (e.g. a Pomodoro block).
+//!
+//! While a session is active, new transcriptions and input tracking entries are
+//! tagged with its id so they can be reviewed and counted per session. The
+//! active session survives restarts: an unfinished session is resumed on
+//! startup.
+
+use anyhow::{Context, Result};
+use log::{error, info};
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use tauri::{AppHandle, Emitter, Manager};
+
+use super::database;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FocusSession {
+    pub id: i64,
+    pub name: String,
+    pub start_time: i64,
+    pub end_time: Option<i64>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct FocusSessionStats {
+    pub session: FocusSession,
+    pub duration_secs: i64,
+    pub transcription_count: usize,
+    pub dictated_words: usize,
+    pub input_entry_count: usize,
+    pub typed_words: usize,
+}
+
+pub struct FocusSessionManager {
+    app_handle: AppHandle,
+    db_path: PathBuf,
+    /// Input tracking keeps its entries in a separate database.
+    input_db_path: PathBuf,
+    active: Mutex<Option<FocusSession>>,
+}
+
+impl FocusSessionManager {
+    pub fn new(app_handle: &AppHandle) -> Result<Self> {
+        let app_data_dir = crate::profile::data_dir(app_handle)?;
+        let db_path = app_data_dir.join("history.db");
+
+        database::initialize_database(&db_path)
+            .context("Failed to initialize database for focus sessions")?;
+
+        let manager = Self {
+            app_handle: app_handle.clone(),
+            db_path,
+            input_db_path: app_data_dir.join("echo.db"),
+            active: Mutex::new(None),
+        };
+
+        let resumed = manager.load_unfinished_session()?;
+        if let Some(session) = &resumed {
+            info!("Resuming focus session '{}' ({})", session.name, session.id);
+        }
+        *manager.active.lock().unwrap() = resumed;
+
+        Ok(manager)
+    }
+
+    fn get_connection(&self) -> Result<Connection> {
+        Connection::open(&self.db_path)
+            .with_context(|| format!("Failed to open database at {:?}", self.db_path))
+    }
+
+    fn load_unfinished_session(&self) -> Result<Option<FocusSession>> {
+        let conn = self.get_connection()?;
+        let session = conn
+            .query_row(
+                "SELECT id, name, start_time, end_time FROM focus_sessions
+                 WHERE end_time IS NULL ORDER BY start_time DESC LIMIT 1",
+                [],
+                row_to_session,
+            )
+            .optional()?;
+        Ok(session)
+    }
+
+    pub fn active_session(&self) -> Option<FocusSession> {
+        self.active.lock().unwrap().clone()
+    }
+
+    /// Start a named session, ending the current one first.
+    pub fn start(&self, name: &str) -> Result<FocusSession> {
+        let name = name.trim();
+        if name.is_empty() {
+            anyhow::bail!("Session name cannot be empty");
+        }
+
+        let mut active = self.active.lock().unwrap();
+        let conn = self.get_connection()?;
+        let now = chrono::Utc::now().timestamp();
+
+        if let Some(previous) = active.take() {
+            conn.execute(
+                "UPDATE focus_sessions SET end_time = ?1 WHERE id = ?2",
+                params![now, previous.id],
+            )?;
+        }
+
+        conn.execute(
+            "INSERT INTO focus_sessions (name, start_time) VALUES (?1, ?2)",
+            params![name, now],
+        )?;
+        let session = FocusSession {
+            id: conn.last_insert_rowid(),
+            name: name.to_string(),
+            start_time: now,
+            end_time: None,
+        };
+        *active = Some(session.clone());
+        drop(active);
+
+        info!("Started focus session '{}' ({})", session.name, session.id);
+        self.emit_changed();
+        Ok(session)
+    }
+
+    /// End the active session, returning it if there was one.
+    pub fn stop(&self) -> Result<Option<FocusSession>> {
+        let Some(mut session) = self.active.lock().unwrap().take() else {
+            return Ok(None);
+        };
+
+        let now = chrono::Utc::now().timestamp();
+        let conn = self.get_connection()?;
+        conn.execute(
+            "UPDATE focus_sessions SET end_time = ?1 WHERE id = ?2",
+            params![now, session.id],
+        )?;
+        session.end_time = Some(now);
+
+        info!("Stopped focus session '{}' ({})", session.name, session.id);
+        self.emit_changed();
+        Ok(Some(session))
+    }
+
+    /// All sessions with their stats, newest first.
+    pub fn list_sessions(&self) -> Result<Vec<FocusSessionStats>> {
+        let conn = self.get_connection()?;
+        let mut stmt = conn.prepare(
+            "SELECT id, name, start_time, end_time FROM focus_sessions ORDER BY start_time DESC",
+        )?;
+        let rows = stmt.query_map([], row_to_session)?;
+
+        let mut sessions = Vec::new();
+        for row in rows {
+            sessions.push(self.stats_for(row?)?);
+        }
+        Ok(sessions)
+    }
+
+    pub fn get_session_stats(&self, id: i64) -> Result<FocusSessionStats> {
+        let conn = self.get_connection()?;
+        let session = conn
+            .query_row(
+                "SELECT id, name, start_time, end_time FROM focus_sessions WHERE id = ?1",
+                params![id],
+                row_to_session,
+            )
+            .optional()?
+            .with_context(|| format!("Focus session {} not found", id))?;
+        self.stats_for(session)
+    }
+
+    fn stats_for(&self, session: FocusSession) -> Result<FocusSessionStats> {
+        let conn = self.get_connection()?;
+        let mut stmt = conn.prepare(
+            "SELECT COALESCE(post_processed_text, transcription_text) FROM transcription_history
+             WHERE session_id = ?1",
+        )?;
+        let transcriptions = stmt
+            .query_map(params![session.id], |row| row.get::<_, String>(0))?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+
+        let inputs = self.input_contents_for(session.id)?;
+
+        let end = session
+            .end_time
+            .unwrap_or_else(|| chrono::Utc::now().timestamp());
+        Ok(FocusSessionStats {
+            duration_secs: (end - session.start_time).max(0),
+            transcription_count: transcriptions.len(),
+            dictated_words: count_words(&transcriptions),
+            input_entry_count: inputs.len(),
+            typed_words: count_words(&inputs),
+            session,
+        })
+    }
+
+    /// Contents of input entries tagged with the session. Empty if input
+    /// tracking hasn't saved anything since tagging was introduced.
+    fn input_contents_for(&self, session_id: i64) -> Result<Vec<String>> {
+        if !self.input_db_path.exists() {
+            return Ok(Vec::new());
+        }
+        let conn = Connection::open(&self.input_db_path)
+            .with_context(|| format!("Failed to open database at {:?}", self.input_db_path))?;
+        if !input_entries_have_session_column(&conn)? {
+            return Ok(Vec::new());
+        }
+
+        let mut stmt = conn.prepare("SELECT content FROM input_entries WHERE session_id = ?1")?;
+        let contents = stmt
+            .query_map(params![session_id], |row| row.get::<_, String>(0))?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        Ok(contents)
+    }
+
+    fn emit_changed(&self) {
+        if let Err(e) = self
+            .app_handle
+            .emit("focus-session-changed", self.active_session())
+        {
+            error!("Failed to emit focus-session-changed event: {}", e);
+        }
+    }
+}
+
+/// Id of the active focus session, used to tag new entries.
+pub fn active_session_id(app_handle: &AppHandle) -> Option<i64> {
+    app_handle
+        .try_state::<Arc<FocusSessionManager>>()
+        .and_then(|manager| manager.active_session())
+        .map(|session| session.id)
+}
+
+/// Whether `input_entries` has the `session_id` column. Input tracking adds it
+/// lazily on its next save.
+pub fn input_entries_have_session_column(conn: &Connection) -> Result<bool> {
+    let mut stmt = conn.prepare("PRAGMA table_info(input_entries)")?;
+    let columns = stmt
+        .query_map([], |row| row.get::<_, String>(1))?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(columns.iter().any(|c| c == "session_id"))
+}
+
+fn row_to_session(row: &rusqlite::Row) -> rusqlite::Result<FocusSession> {
+    Ok(FocusSession {
+        id: row.get("id")?,
+        name: row.get("name")?,
+        start_time: row.get("start_time")?,
+        end_time: row.get("end_time")?,
+    })
+}
+
+fn count_words(texts: &[String]) -> usize {
+    texts.iter().map(|t| t.split_whitespace().count()).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn count_words_sums_across_texts() {
+        let texts = vec!["one two".to_string(), "  three ".to_string(), String::new()];
+        assert_eq!(count_words(&texts), 3);
+    }
+
+    #[test]
+    fn session_column_detection() {
+        let temp_dir = TempDir::new().unwrap();
+        let conn = Connection::open(temp_dir.path().join("echo.db")).unwrap();
+        conn.execute_batch("CREATE TABLE input_entries (id INTEGER PRIMARY KEY, content TEXT)")
+            .unwrap();
+        assert!(!input_entries_have_session_column(&conn).unwrap());
+
+        conn.execute_batch("ALTER TABLE input_entries ADD COLUMN session_id INTEGER")
+            .unwrap();
+        assert!(input_entries_have_session_column(&conn).unwrap());
+    }
+
+    #[test]
+    fn history_rows_can_be_tagged_with_sessions() {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("history.db");
+        database::initialize_database(&db_path).unwrap();
+        let conn = Connection::open(&db_path).unwrap();
+
+        conn.execute(
+            "INSERT INTO focus_sessions (name, start_time) VALUES ('Deep work', 100)",
+            [],
+        )
+        .unwrap();
+        let session_id = conn.last_insert_rowid();
+        conn.execute(
+            "INSERT INTO transcription_history (file_name, timestamp, title, transcription_text, session_id)
+             VALUES ('a.wav', 120, 't', 'hello there', ?1)",
+            params![session_id],
+        )
+        .unwrap();
+
+        let session = conn
+            .query_row(
+                "SELECT id, name, start_time, end_time FROM focus_sessions WHERE id = ?1",
+                params![session_id],
+                row_to_session,
+            )
+            .unwrap();
+        assert_eq!(session.name, "Deep work");
+        assert_eq!(session.end_time, None);
+
+        let tagged: i64 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM transcription_history WHERE session_id = ?1",
+                params![session_id],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(tagged, 1);
+    }
+}
@@ -3,13 +3,25 @@ use chrono::{DateTime, Local, Utc};
 use log::{debug, error};
 use rusqlite::{params, Connection, OptionalExtension};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
-use tauri::{AppHandle, Emitter, Manager};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tauri::{AppHandle, Emitter};
 
+use super::corrections::{self, CorrectionSource};
 use super::database;
-use crate::audio_toolkit::{load_wav_file, save_wav_file};
-use crate::settings::RecordingRetentionPeriod;
+use super::db_writer::DbWriter;
+use super::diarization::SpeakerSegment;
+use super::entry_edits::{self, AppEditStats, EntryEdit};
+use super::transcription::TimedSegment;
+use crate::audio_toolkit::{append_recording, load_recording, save_recording};
+use crate::settings::{get_settings, RecordingRetentionPeriod};
+
+/// Dictations pasted longer ago than this are not linked to input entries.
+const EDIT_LINK_WINDOW_SECS: i64 = 10 * 60;
+/// Entries started longer ago than this aren't continued by a dictation.
+const CONTINUE_WINDOW_SECS: i64 = 10 * 60;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct HistoryEntry {
@@ -21,18 +33,74 @@ pub struct HistoryEntry {
     pub transcription_text: String,
     pub post_processed_text: Option<String>,
     pub post_process_prompt: Option<String>,
+    /// Word or segment timestamps, for entries transcribed with them.
+    pub timestamps: Option<Vec<TimedSegment>>,
+    /// Input device name, or the file name for imported files.
+    pub source_device: Option<String>,
+    /// Native sample rate of the input device.
+    pub sample_rate: Option<u32>,
+    pub recording_mode: Option<RecordingMode>,
+    /// Spoken language as an ISO 639-1 code, set or detected at transcription.
+    pub language: Option<String>,
+    /// Text before the last retranscription, to show what changed.
+    pub previous_transcription_text: Option<String>,
+}
+
+/// How the audio of a history entry was captured.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordingMode {
+    PushToTalk,
+    Toggle,
+    /// Recorded from the always-on microphone stream.
+    AlwaysOn,
+    File,
+    SystemAudio,
+}
+
+impl RecordingMode {
+    fn as_str(self) -> &'static str {
+        match self {
+            RecordingMode::PushToTalk => "push_to_talk",
+            RecordingMode::Toggle => "toggle",
+            RecordingMode::AlwaysOn => "always_on",
+            RecordingMode::File => "file",
+            RecordingMode::SystemAudio => "system_audio",
+        }
+    }
+
+    fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "push_to_talk" => Some(RecordingMode::PushToTalk),
+            "toggle" => Some(RecordingMode::Toggle),
+            "always_on" => Some(RecordingMode::AlwaysOn),
+            "file" => Some(RecordingMode::File),
+            "system_audio" => Some(RecordingMode::SystemAudio),
+            _ => None,
+        }
+    }
+}
+
+/// Where a history entry's audio came from, kept for filtering and for
+/// tracking down quality issues.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RecordingSource {
+    pub device: Option<String>,
+    pub sample_rate: Option<u32>,
+    pub mode: Option<RecordingMode>,
 }
 
 pub struct HistoryManager {
     app_handle: AppHandle,
     recordings_dir: PathBuf,
     db_path: PathBuf,
+    db_writer: Arc<DbWriter>,
 }
 
 impl HistoryManager {
-    pub fn new(app_handle: &AppHandle) -> Result<Self> {
+    pub fn new(app_handle: &AppHandle, db_writer: Arc<DbWriter>) -> Result<Self> {
         // Create recordings directory in app data dir
-        let app_data_dir = app_handle.path().app_data_dir()?;
+        let app_data_dir = crate::profile::data_dir(app_handle)?;
         let recordings_dir = app_data_dir.join("recordings");
         let db_path = app_data_dir.join("history.db");
 
@@ -49,6 +117,7 @@ impl HistoryManager {
             app_handle: app_handle.clone(),
             recordings_dir,
             db_path,
+            db_writer,
         };
 
         Ok(manager)
@@ -59,31 +128,36 @@ impl HistoryManager {
             .with_context(|| format!("Failed to open database at {:?}", self.db_path))
     }
 
-    /// Save a transcription to history (both database and WAV file)
+    /// Save a transcription to history (both database and recording file)
+    /// and return the new entry's id
     pub async fn save_transcription(
         &self,
         audio_samples: Vec<f32>,
         transcription_text: String,
         post_processed_text: Option<String>,
         post_process_prompt: Option<String>,
-    ) -> Result<()> {
+        source: RecordingSource,
+    ) -> Result<i64> {
         let timestamp = Utc::now().timestamp();
-        let file_name = format!("echo-{}.wav", timestamp);
+        let format = get_settings(&self.app_handle).recording_format;
+        let file_name = format!("echo-{}.{}", timestamp, format.extension());
         let title = self.format_timestamp_title(timestamp);
 
-        // Save WAV file
         let file_path = self.recordings_dir.join(&file_name);
-        save_wav_file(file_path, &audio_samples).await?;
+        save_recording(file_path, &audio_samples, format).await?;
 
         // Save to database
-        self.save_to_database(
-            file_name,
-            timestamp,
-            title,
-            transcription_text,
-            post_processed_text,
-            post_process_prompt,
-        )?;
+        let id = self
+            .save_to_database(
+                file_name,
+                timestamp,
+                title,
+                transcription_text,
+                post_processed_text,
+                post_process_prompt,
+                source,
+            )
+            .await?;
 
         // Clean up old entries
         self.cleanup_old_entries()?;
@@ -93,10 +167,10 @@ impl HistoryManager {
             error!("Failed to emit history-updated event: {}", e);
         }
 
-        Ok(())
+        Ok(id)
     }
 
-    fn save_to_database(
+    async fn save_to_database(
         &self,
         file_name: String,
         timestamp: i64,
@@ -104,30 +178,222 @@ impl HistoryManager {
         transcription_text: String,
         post_processed_text: Option<String>,
         post_process_prompt: Option<String>,
+        source: RecordingSource,
+    ) -> Result<i64> {
+        let session_id = super::focus_session::active_session_id(&self.app_handle);
+        let id = self
+            .db_writer
+            .write(&self.db_path, move |conn| {
+                conn.execute(
+                    "INSERT INTO transcription_history (file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, session_id, source_device, sample_rate, recording_mode) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+                    params![file_name, timestamp, false, title, transcription_text, post_processed_text, post_process_prompt, session_id, source.device, source.sample_rate, source.mode.map(RecordingMode::as_str)],
+                )?;
+                Ok(conn.last_insert_rowid())
+            })
+            .await?;
+
+        debug!("Saved transcription to database");
+        Ok(id)
+    }
+
+    /// Store the speaker-labeled segments of an entry, replacing any it had.
+    pub async fn save_speaker_segments(
+        &self,
+        history_id: i64,
+        segments: Vec<SpeakerSegment>,
+    ) -> Result<()> {
+        let count = segments.len();
+        self.db_writer
+            .write(&self.db_path, move |conn| {
+                conn.execute(
+                    "DELETE FROM history_segments WHERE history_id = ?1",
+                    params![history_id],
+                )?;
+                for segment in &segments {
+                    conn.execute(
+                        "INSERT INTO history_segments (history_id, speaker_label, start_ms, end_ms, text) VALUES (?1, ?2, ?3, ?4, ?5)",
+                        params![history_id, segment.speaker_label, segment.start_ms, segment.end_ms, segment.text],
+                    )?;
+                }
+                Ok(())
+            })
+            .await?;
+
+        debug!(
+            "Saved {} speaker segments for history entry {}",
+            count, history_id
+        );
+        Ok(())
+    }
+
+    /// Store the word or segment timestamps of an entry.
+    pub async fn save_timestamps(
+        &self,
+        history_id: i64,
+        timestamps: &[TimedSegment],
     ) -> Result<()> {
+        let json = serde_json::to_string(timestamps)?;
+        self.db_writer
+            .write(&self.db_path, move |conn| {
+                conn.execute(
+                    "UPDATE transcription_history SET timestamps = ?1 WHERE id = ?2",
+                    params![json, history_id],
+                )?;
+                Ok(())
+            })
+            .await
+    }
+
+    /// Replace the title of an entry.
+    pub async fn save_title(&self, history_id: i64, title: String) -> Result<()> {
+        self.db_writer
+            .write(&self.db_path, move |conn| {
+                conn.execute(
+                    "UPDATE transcription_history SET title = ?1 WHERE id = ?2",
+                    params![title, history_id],
+                )?;
+                Ok(())
+            })
+            .await
+    }
+
+    /// Store the spoken language of an entry.
+    pub async fn save_language(&self, history_id: i64, language: String) -> Result<()> {
+        self.db_writer
+            .write(&self.db_path, move |conn| {
+                conn.execute(
+                    "UPDATE transcription_history SET language = ?1 WHERE id = ?2",
+                    params![language, history_id],
+                )?;
+                Ok(())
+            })
+            .await
+    }
+
+    /// Add a dictation to the end of the newest entry: its audio after the
+    /// entry's audio and its text after the entry's text. Returns the entry's
+    /// id, or `None` when the newest entry isn't a recent dictation to
+    /// continue.
+    pub async fn append_to_latest(
+        &self,
+        audio_samples: &[f32],
+        transcription_text: &str,
+        post_processed_text: Option<&str>,
+        timestamps: &[TimedSegment],
+    ) -> Result<Option<i64>> {
+        let Some(entry) = self.get_latest_entry()? else {
+            return Ok(None);
+        };
+        if !is_continuable(&entry, Utc::now().timestamp()) {
+            return Ok(None);
+        }
+
+        let file_path = self.get_audio_file_path(&entry.file_name);
+        let previous_samples = append_recording(&file_path, audio_samples)?;
+        let offset_ms = previous_samples as i64 * 1000 / 16000;
+
+        let (text, post_processed, timestamps) = merge_continuation(
+            &entry,
+            offset_ms,
+            transcription_text,
+            post_processed_text,
+            timestamps,
+        );
+        let timestamps = timestamps.map(|t| serde_json::to_string(&t)).transpose()?;
+
+        let id = entry.id;
+        self.db_writer
+            .write(&self.db_path, move |conn| {
+                conn.execute(
+                    "UPDATE transcription_history SET transcription_text = ?1, post_processed_text = ?2, timestamps = ?3 WHERE id = ?4",
+                    params![text, post_processed, timestamps, id],
+                )?;
+                // Speaker segments only covered the first part
+                conn.execute(
+                    "DELETE FROM history_segments WHERE history_id = ?1",
+                    params![id],
+                )?;
+                Ok(())
+            })
+            .await?;
+
+        debug!("Appended dictation to history entry {}", id);
+
+        // Emit history updated event
+        if let Err(e) = self.app_handle.emit("history-updated", ()) {
+            error!("Failed to emit history-updated event: {}", e);
+        }
+
+        Ok(Some(id))
+    }
+
+    /// The most recent entry, if any.
+    fn get_latest_entry(&self) -> Result<Option<HistoryEntry>> {
         let conn = self.get_connection()?;
-        conn.execute(
-            "INSERT INTO transcription_history (file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
-            params![file_name, timestamp, false, title, transcription_text, post_processed_text, post_process_prompt],
+        let mut stmt = conn.prepare(
+            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, timestamps, source_device, sample_rate, recording_mode, language, previous_transcription_text
+             FROM transcription_history ORDER BY timestamp DESC, id DESC LIMIT 1",
         )?;
 
-        debug!("Saved transcription to database");
+        Ok(stmt.query_row([], row_to_entry).optional()?)
+    }
+
+    /// Speaker-labeled segments of an entry, empty unless it was diarized.
+    pub fn get_speaker_segments(&self, history_id: i64) -> Result<Vec<SpeakerSegment>> {
+        let conn = self.get_connection()?;
+        let mut stmt = conn.prepare(
+            "SELECT speaker_label, start_ms, end_ms, text FROM history_segments WHERE history_id = ?1 ORDER BY start_ms",
+        )?;
+
+        let rows = stmt.query_map([history_id], |row| {
+            Ok(SpeakerSegment {
+                speaker_label: row.get("speaker_label")?,
+                start_ms: row.get("start_ms")?,
+                end_ms: row.get("end_ms")?,
+                text: row.get("text")?,
+            })
+        })?;
+
+        let mut segments = Vec::new();
+        for row in rows {
+            segments.push(row?);
+        }
+
+        Ok(segments)
+    }
+
+    /// Verify the history database accepts writes. The probe table is created
+    /// inside a transaction that is rolled back, so nothing is persisted.
+    pub fn check_writable(&self) -> Result<()> {
+        let mut conn = self.get_connection()?;
+        let tx = conn.transaction()?;
+        tx.execute_batch(
+            "CREATE TABLE self_test_probe (id INTEGER); INSERT INTO self_test_probe VALUES (1);",
+        )
+        .context("History database is not writable")?;
+        tx.rollback()?;
         Ok(())
     }
 
+    /// Delete entries past their retention period. Focus-session tags and
+    /// sources with a period of their own follow it; everything else follows
+    /// the global one.
     pub fn cleanup_old_entries(&self) -> Result<()> {
-        let retention_period = crate::settings::get_recording_retention_period(&self.app_handle);
+        let settings = get_settings(&self.app_handle);
+        let policy = RetentionPolicy {
+            global: crate::settings::get_recording_retention_period(&self.app_handle),
+            limit: crate::settings::get_history_limit(&self.app_handle),
+            sources: settings.source_retention,
+            tags: settings.tag_retention,
+        };
 
-        match retention_period {
-            RecordingRetentionPeriod::Never => Ok(()),
-            RecordingRetentionPeriod::PreserveLimit => {
-                let limit = crate::settings::get_history_limit(&self.app_handle);
-                self.cleanup_by_count(limit)
-            }
-            RecordingRetentionPeriod::Days3
-            | RecordingRetentionPeriod::Weeks2
-            | RecordingRetentionPeriod::Months3 => self.cleanup_by_time(retention_period),
+        let conn = self.get_connection()?;
+        let expired = expired_entries(&conn, &policy, Utc::now().timestamp())?;
+        let deleted_count = self.delete_entries_and_files(&expired)?;
+        if deleted_count > 0 {
+            debug!("Cleaned up {} old history entries", deleted_count);
         }
+        Ok(())
     }
 
     fn delete_entries_and_files(&self, entries: &[(i64, String)]) -> Result<usize> {
@@ -158,86 +424,31 @@ impl HistoryManager {
         Ok(deleted_count)
     }
 
-    fn cleanup_by_count(&self, limit: usize) -> Result<()> {
+    pub async fn get_history_entries(&self) -> Result<Vec<HistoryEntry>> {
         let conn = self.get_connection()?;
-
         let mut stmt = conn.prepare(
-            "SELECT id, file_name FROM transcription_history WHERE saved = 0 ORDER BY timestamp DESC",
+            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, timestamps, source_device, sample_rate, recording_mode, language, previous_transcription_text FROM transcription_history ORDER BY timestamp DESC"
         )?;
 
-        let rows = stmt.query_map([], |row| {
-            Ok((row.get::<_, i64>("id")?, row.get::<_, String>("file_name")?))
-        })?;
+        let rows = stmt.query_map([], row_to_entry)?;
 
-        let mut entries: Vec<(i64, String)> = Vec::new();
+        let mut entries = Vec::new();
         for row in rows {
             entries.push(row?);
         }
 
-        if entries.len() > limit {
-            let entries_to_delete = &entries[limit..];
-            let deleted_count = self.delete_entries_and_files(entries_to_delete)?;
-            if deleted_count > 0 {
-                debug!("Cleaned up {} old history entries by count", deleted_count);
-            }
-        }
-
-        Ok(())
-    }
-
-    fn cleanup_by_time(&self, retention_period: RecordingRetentionPeriod) -> Result<()> {
-        let conn = self.get_connection()?;
-
-        let now = Utc::now().timestamp();
-        let cutoff_timestamp = match retention_period {
-            RecordingRetentionPeriod::Days3 => now - (3 * 24 * 60 * 60),
-            RecordingRetentionPeriod::Weeks2 => now - (2 * 7 * 24 * 60 * 60),
-            RecordingRetentionPeriod::Months3 => now - (3 * 30 * 24 * 60 * 60),
-            _ => unreachable!("cleanup_by_time called with unsupported retention period"),
-        };
-
-        let mut stmt = conn.prepare(
-            "SELECT id, file_name FROM transcription_history WHERE saved = 0 AND timestamp < ?1",
-        )?;
-
-        let rows = stmt.query_map(params![cutoff_timestamp], |row| {
-            Ok((row.get::<_, i64>("id")?, row.get::<_, String>("file_name")?))
-        })?;
-
-        let mut entries_to_delete: Vec<(i64, String)> = Vec::new();
-        for row in rows {
-            entries_to_delete.push(row?);
-        }
-
-        let deleted_count = self.delete_entries_and_files(&entries_to_delete)?;
-        if deleted_count > 0 {
-            debug!(
-                "Cleaned up {} old history entries based on retention period",
-                deleted_count
-            );
-        }
-
-        Ok(())
+        Ok(entries)
     }
 
-    pub async fn get_history_entries(&self) -> Result<Vec<HistoryEntry>> {
+    /// Transcriptions tagged with a focus session, newest first.
+    pub fn get_entries_for_session(&self, session_id: i64) -> Result<Vec<HistoryEntry>> {
         let conn = self.get_connection()?;
         let mut stmt = conn.prepare(
-            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt FROM transcription_history ORDER BY timestamp DESC"
+            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, timestamps, source_device, sample_rate, recording_mode, language, previous_transcription_text
+             FROM transcription_history WHERE session_id = ?1 ORDER BY timestamp DESC",
         )?;
 
-        let rows = stmt.query_map([], |row| {
-            Ok(HistoryEntry {
-                id: row.get("id")?,
-                file_name: row.get("file_name")?,
-                timestamp: row.get("timestamp")?,
-                saved: row.get("saved")?,
-                title: row.get("title")?,
-                transcription_text: row.get("transcription_text")?,
-                post_processed_text: row.get("post_processed_text")?,
-                post_process_prompt: row.get("post_process_prompt")?,
-            })
-        })?;
+        let rows = stmt.query_map([session_id], row_to_entry)?;
 
         let mut entries = Vec::new();
         for row in rows {
@@ -274,6 +485,94 @@ impl HistoryManager {
         Ok(())
     }
 
+    /// Link a saved input entry to the recent dictation it was pasted from,
+    /// recording how the dictation was edited. The write is queued.
+    pub fn link_input_entry(&self, app_name: &str, content: &str, timestamp: i64) {
+        let app_name = app_name.to_string();
+        let content = content.to_string();
+        let app = self.app_handle.clone();
+        let learn = get_settings(&app).learn_from_corrections;
+        self.db_writer.submit_then(
+            &self.db_path,
+            move |conn| {
+                let linked = link_input_entry(conn, &app_name, &content, timestamp)?;
+                if linked && learn {
+                    corrections::take_recurring(conn)
+                } else {
+                    Ok(Vec::new())
+                }
+            },
+            move |learned| {
+                if let Ok(words) = learned {
+                    corrections::learn(&app, words);
+                }
+            },
+        );
+    }
+
+    /// Edits made to a dictation after it was pasted.
+    pub fn get_entry_edits(&self, history_id: i64) -> Result<Vec<EntryEdit>> {
+        let conn = self.get_connection()?;
+        let mut stmt = conn.prepare(
+            "SELECT id, history_id, app_name, original_text, edited_text, word_edit_distance, original_word_count, timestamp
+             FROM entry_edits WHERE history_id = ?1 ORDER BY timestamp",
+        )?;
+        let rows = stmt.query_map([history_id], |row| {
+            Ok(EntryEdit {
+                id: row.get("id")?,
+                history_id: row.get("history_id")?,
+                app_name: row.get("app_name")?,
+                original_text: row.get("original_text")?,
+                edited_text: row.get("edited_text")?,
+                word_edit_distance: row.get("word_edit_distance")?,
+                original_word_count: row.get("original_word_count")?,
+                timestamp: row.get("timestamp")?,
+            })
+        })?;
+
+        let mut edits = Vec::new();
+        for row in rows {
+            edits.push(row?);
+        }
+        Ok(edits)
+    }
+
+    /// Per-app correction stats across all linked dictations.
+    pub fn get_edit_stats_by_app(&self) -> Result<Vec<AppEditStats>> {
+        let conn = self.get_connection()?;
+        let mut stmt = conn.prepare(
+            "SELECT app_name, COUNT(*) AS linked_count,
+                    SUM(CASE WHEN word_edit_distance > 0 THEN 1 ELSE 0 END) AS edited_count,
+                    SUM(word_edit_distance) AS total_edits,
+                    SUM(original_word_count) AS total_words
+             FROM entry_edits GROUP BY app_name ORDER BY linked_count DESC",
+        )?;
+        let rows = stmt.query_map([], |row| {
+            let total_edits: i64 = row.get("total_edits")?;
+            let total_words: i64 = row.get("total_words")?;
+            Ok(AppEditStats {
+                app_name: row.get("app_name")?,
+                linked_count: row.get("linked_count")?,
+                edited_count: row.get("edited_count")?,
+                word_edit_rate: if total_words > 0 {
+                    total_edits as f64 / total_words as f64
+                } else {
+                    0.0
+                },
+            })
+        })?;
+
+        let mut stats = Vec::new();
+        for row in rows {
+            stats.push(row?);
+        }
+        Ok(stats)
+    }
+
+    pub fn recordings_dir(&self) -> &Path {
+        &self.recordings_dir
+    }
+
     pub fn get_audio_file_path(&self, file_name: &str) -> PathBuf {
         self.recordings_dir.join(file_name)
     }
@@ -281,24 +580,11 @@ impl HistoryManager {
     pub async fn get_entry_by_id(&self, id: i64) -> Result<Option<HistoryEntry>> {
         let conn = self.get_connection()?;
         let mut stmt = conn.prepare(
-            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt
+            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, timestamps, source_device, sample_rate, recording_mode, language, previous_transcription_text
              FROM transcription_history WHERE id = ?1",
         )?;
 
-        let entry = stmt
-            .query_row([id], |row| {
-                Ok(HistoryEntry {
-                    id: row.get("id")?,
-                    file_name: row.get("file_name")?,
-                    timestamp: row.get("timestamp")?,
-                    saved: row.get("saved")?,
-                    title: row.get("title")?,
-                    transcription_text: row.get("transcription_text")?,
-                    post_processed_text: row.get("post_processed_text")?,
-                    post_process_prompt: row.get("post_process_prompt")?,
-                })
-            })
-            .optional()?;
+        let entry = stmt.query_row([id], row_to_entry).optional()?;
 
         Ok(entry)
     }
@@ -344,14 +630,32 @@ impl HistoryManager {
         }
     }
 
-    /// Retranscribe a history entry using its stored audio file
-    pub async fn retranscribe_entry(&self, id: i64, new_transcription: String) -> Result<()> {
+    /// Retranscribe a history entry using its stored audio file, keeping the
+    /// text it replaces. Returns that previous text.
+    pub async fn retranscribe_entry(
+        &self,
+        id: i64,
+        new_transcription: String,
+        timestamps: &[TimedSegment],
+        language: Option<String>,
+    ) -> Result<String> {
         let conn = self.get_connection()?;
+        let timestamps = serde_json::to_string(timestamps)?;
+        let previous: String = conn.query_row(
+            "SELECT transcription_text FROM transcription_history WHERE id = ?1",
+            params![id],
+            |row| row.get(0),
+        )?;
 
         // Update the transcription text in the database
         conn.execute(
-            "UPDATE transcription_history SET transcription_text = ?1, post_processed_text = NULL, post_process_prompt = NULL WHERE id = ?2",
-            params![new_transcription, id],
+            "UPDATE transcription_history SET transcription_text = ?1, previous_transcription_text = ?2, post_processed_text = NULL, post_process_prompt = NULL, timestamps = ?3, language = ?4 WHERE id = ?5",
+            params![new_transcription, previous, timestamps, language, id],
+        )?;
+        // Speaker segments belong to the old transcription
+        conn.execute(
+            "DELETE FROM history_segments WHERE history_id = ?1",
+            params![id],
         )?;
 
         debug!("Retranscribed history entry with id: {}", id);
@@ -361,13 +665,48 @@ impl HistoryManager {
             error!("Failed to emit history-updated event: {}", e);
         }
 
+        Ok(previous)
+    }
+
+    /// Replace an entry's text with the user's edit, recording the words
+    /// they corrected. Word timings no longer match the text, so they go.
+    pub async fn edit_entry_text(&self, id: i64, text: String) -> Result<()> {
+        let conn = self.get_connection()?;
+        let original: String = conn.query_row(
+            "SELECT transcription_text FROM transcription_history WHERE id = ?1",
+            params![id],
+            |row| row.get(0),
+        )?;
+        conn.execute(
+            "UPDATE transcription_history SET transcription_text = ?1, timestamps = NULL WHERE id = ?2",
+            params![text, id],
+        )?;
+        corrections::record(
+            &conn,
+            id,
+            &original,
+            &text,
+            CorrectionSource::History,
+            Utc::now().timestamp(),
+        )?;
+        if get_settings(&self.app_handle).learn_from_corrections {
+            corrections::learn(&self.app_handle, corrections::take_recurring(&conn)?);
+        }
+
+        debug!("Edited the text of history entry with id: {}", id);
+
+        // Emit history updated event
+        if let Err(e) = self.app_handle.emit("history-updated", ()) {
+            error!("Failed to emit history-updated event: {}", e);
+        }
+
         Ok(())
     }
 
-    /// Load audio samples from a history entry's WAV file
+    /// Load audio samples from a history entry's recording, WAV or FLAC
     pub fn load_audio_for_entry(&self, file_name: &str) -> Result<Vec<f32>> {
         let file_path = self.get_audio_file_path(file_name);
-        load_wav_file(&file_path)
+        load_recording(&file_path)
     }
 
     /// Update post-processed text for a history entry
@@ -397,3 +736,433 @@ impl HistoryManager {
         Ok(())
     }
 }
+
+/// Whether a dictation may continue `entry`: a dictation itself, started
+/// within [`CONTINUE_WINDOW_SECS`] of `now`, rather than an imported file, a
+/// meeting or a dictation from earlier in the day.
+fn is_continuable(entry: &HistoryEntry, now: i64) -> bool {
+    let dictated = matches!(
+        entry.recording_mode,
+        Some(RecordingMode::PushToTalk | RecordingMode::Toggle | RecordingMode::AlwaysOn)
+    );
+    dictated && now - entry.timestamp <= CONTINUE_WINDOW_SECS
+}
+
+/// Text, post-processed text and timestamps of `entry` with a dictation
+/// added to its end. The dictation's timestamps are moved `offset_ms` on,
+/// past the entry's audio.
+fn merge_continuation(
+    entry: &HistoryEntry,
+    offset_ms: i64,
+    transcription_text: &str,
+    post_processed_text: Option<&str>,
+    timestamps: &[TimedSegment],
+) -> (String, Option<String>, Option<Vec<TimedSegment>>) {
+    let text = format!("{} {}", entry.transcription_text, transcription_text);
+    // Keep what was pasted for both parts once either was post-processed
+    let post_processed = match (&entry.post_processed_text, post_processed_text) {
+        (None, None) => None,
+        (previous, new) => Some(format!(
+            "{} {}",
+            previous.as_deref().unwrap_or(&entry.transcription_text),
+            new.unwrap_or(transcription_text)
+        )),
+    };
+    let timestamps = entry.timestamps.clone().map(|mut previous| {
+        previous.extend(timestamps.iter().map(|segment| TimedSegment {
+            start_ms: segment.start_ms + offset_ms,
+            end_ms: segment.end_ms + offset_ms,
+            ..segment.clone()
+        }));
+        previous
+    });
+    (text, post_processed, timestamps)
+}
+
+/// Which entries are deleted when: focus-session tags with a period of
+/// their own come first, then sources with one, then the global period.
+struct RetentionPolicy {
+    global: RecordingRetentionPeriod,
+    /// Entries kept per scope with [`RecordingRetentionPeriod::PreserveLimit`].
+    limit: usize,
+    sources: HashMap<RecordingMode, RecordingRetentionPeriod>,
+    tags: HashMap<String, RecordingRetentionPeriod>,
+}
+
+/// Entries that share a retention period, and a limit with
+/// [`RecordingRetentionPeriod::PreserveLimit`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum RetentionScope {
+    Tag(String),
+    Source(RecordingMode),
+    Global,
+}
+
+impl RetentionPolicy {
+    /// The scope an entry from `mode`, recorded during a focus session
+    /// named `tag`, falls in, and that scope's period.
+    fn scope(
+        &self,
+        mode: Option<RecordingMode>,
+        tag: Option<String>,
+    ) -> (RetentionScope, RecordingRetentionPeriod) {
+        if let Some(tag) = tag {
+            if let Some(&period) = self.tags.get(&tag) {
+                return (RetentionScope::Tag(tag), period);
+            }
+        }
+        if let Some(mode) = mode {
+            if let Some(&period) = self.sources.get(&mode) {
+                return (RetentionScope::Source(mode), period);
+            }
+        }
+        (RetentionScope::Global, self.global)
+    }
+}
+
+/// How long `period` keeps entries, in seconds, or `None` for periods that
+/// don't go by age.
+fn retention_secs(period: RecordingRetentionPeriod) -> Option<i64> {
+    match period {
+        RecordingRetentionPeriod::Days3 => Some(3 * 24 * 60 * 60),
+        RecordingRetentionPeriod::Weeks2 => Some(2 * 7 * 24 * 60 * 60),
+        RecordingRetentionPeriod::Months3 => Some(3 * 30 * 24 * 60 * 60),
+        RecordingRetentionPeriod::Never | RecordingRetentionPeriod::PreserveLimit => None,
+    }
+}
+
+/// Id and file name of the unsaved entries `policy` deletes at `now`.
+fn expired_entries(
+    conn: &Connection,
+    policy: &RetentionPolicy,
+    now: i64,
+) -> Result<Vec<(i64, String)>> {
+    let mut stmt = conn.prepare(
+        "SELECT h.id, h.file_name, h.timestamp, h.recording_mode, s.name
+         FROM transcription_history h LEFT JOIN focus_sessions s ON s.id = h.session_id
+         WHERE h.saved = 0 ORDER BY h.timestamp DESC, h.id DESC",
+    )?;
+    let rows = stmt.query_map([], |row| {
+        Ok((
+            row.get::<_, i64>(0)?,
+            row.get::<_, String>(1)?,
+            row.get::<_, i64>(2)?,
+            row.get::<_, Option<String>>(3)?,
+            row.get::<_, Option<String>>(4)?,
+        ))
+    })?;
+
+    // Newest first, so the first `limit` of each scope are the ones kept
+    let mut kept: HashMap<RetentionScope, usize> = HashMap::new();
+    let mut expired = Vec::new();
+    for row in rows {
+        let (id, file_name, timestamp, mode, tag) = row?;
+        let mode = mode.as_deref().and_then(RecordingMode::from_str);
+        let (scope, period) = policy.scope(mode, tag);
+        let is_expired = match period {
+            RecordingRetentionPeriod::PreserveLimit => {
+                let count = kept.entry(scope).or_default();
+                *count += 1;
+                *count > policy.limit
+            }
+            period => retention_secs(period).is_some_and(|secs| timestamp < now - secs),
+        };
+        if is_expired {
+            expired.push((id, file_name));
+        }
+    }
+    Ok(expired)
+}
+
+/// Link an input entry to the recent dictation it was pasted from, recording
+/// how the dictation was edited. Returns whether a link was made.
+fn link_input_entry(
+    conn: &Connection,
+    app_name: &str,
+    content: &str,
+    timestamp: i64,
+) -> Result<bool> {
+    let mut stmt = conn.prepare(
+        "SELECT id, COALESCE(post_processed_text, transcription_text) FROM transcription_history
+         WHERE timestamp >= ?1 AND timestamp <= ?2
+         AND id NOT IN (SELECT history_id FROM entry_edits)
+         ORDER BY timestamp DESC",
+    )?;
+    let candidates = stmt
+        .query_map(
+            params![
+                timestamp - EDIT_LINK_WINDOW_SECS,
+                timestamp + EDIT_LINK_WINDOW_SECS
+            ],
+            |row| Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?)),
+        )?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    let Some((history_id, original, edited)) = candidates.into_iter().find_map(|(id, text)| {
+        entry_edits::match_dictation(&text, content).map(|edited| (id, text, edited))
+    }) else {
+        return Ok(false);
+    };
+
+    let distance = entry_edits::word_edit_distance(&original, &edited);
+    conn.execute(
+        "INSERT INTO entry_edits (history_id, app_name, original_text, edited_text, word_edit_distance, original_word_count, timestamp)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+        params![
+            history_id,
+            app_name,
+            original,
+            edited,
+            distance as i64,
+            original.split_whitespace().count() as i64,
+            timestamp
+        ],
+    )?;
+    corrections::record(
+        conn,
+        history_id,
+        &original,
+        &edited,
+        CorrectionSource::Input,
+        timestamp,
+    )?;
+
+    debug!(
+        "Linked input entry in {} to history entry {} ({} word edits)",
+        app_name, history_id, distance
+    );
+    Ok(true)
+}
+
+fn row_to_entry(row: &rusqlite::Row) -> rusqlite::Result<HistoryEntry> {
+    Ok(HistoryEntry {
+        id: row.get("id")?,
+        file_name: row.get("file_name")?,
+        timestamp: row.get("timestamp")?,
+        saved: row.get("saved")?,
+        title: row.get("title")?,
+        transcription_text: row.get("transcription_text")?,
+        post_processed_text: row.get("post_processed_text")?,
+        post_process_prompt: row.get("post_process_prompt")?,
+        timestamps: parse_timestamps(row.get("timestamps")?),
+        source_device: row.get("source_device")?,
+        sample_rate: row.get("sample_rate")?,
+        recording_mode: row
+            .get::<_, Option<String>>("recording_mode")?
+            .as_deref()
+            .and_then(RecordingMode::from_str),
+        language: row.get("language")?,
+        previous_transcription_text: row.get("previous_transcription_text")?,
+    })
+}
+
+/// Timestamps stored as JSON; unreadable values are treated as missing.
+fn parse_timestamps(json: Option<String>) -> Option<Vec<TimedSegment>> {
+    json.and_then(|json| serde_json::from_str(&json).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(mode: Option<RecordingMode>, timestamp: i64) -> HistoryEntry {
+        HistoryEntry {
+            id: 1,
+            file_name: "echo-1.wav".to_string(),
+            timestamp,
+            saved: false,
+            title: String::new(),
+            transcription_text: "first part".to_string(),
+            post_processed_text: None,
+            post_process_prompt: None,
+            timestamps: Some(vec![segment("first", 0, 400)]),
+            source_device: None,
+            sample_rate: None,
+            recording_mode: mode,
+            language: None,
+            previous_transcription_text: None,
+        }
+    }
+
+    fn segment(text: &str, start_ms: i64, end_ms: i64) -> TimedSegment {
+        TimedSegment {
+            start_ms,
+            end_ms,
+            text: text.to_string(),
+            confidence: None,
+        }
+    }
+
+    #[test]
+    fn only_recent_dictations_are_continued() {
+        let now = 100_000;
+        assert!(is_continuable(
+            &entry(Some(RecordingMode::PushToTalk), now - 60),
+            now
+        ));
+        assert!(is_continuable(
+            &entry(Some(RecordingMode::AlwaysOn), now),
+            now
+        ));
+        assert!(!is_continuable(
+            &entry(Some(RecordingMode::Toggle), now - 3600),
+            now
+        ));
+        assert!(!is_continuable(&entry(Some(RecordingMode::File), now), now));
+        assert!(!is_continuable(
+            &entry(Some(RecordingMode::SystemAudio), now),
+            now
+        ));
+        assert!(!is_continuable(&entry(None, now), now));
+    }
+
+    #[test]
+    fn continuation_follows_the_entry() {
+        let previous = entry(Some(RecordingMode::PushToTalk), 0);
+        let (text, post_processed, timestamps) = merge_continuation(
+            &previous,
+            2000,
+            "second part",
+            None,
+            &[segment("second", 100, 500)],
+        );
+
+        assert_eq!(text, "first part second part");
+        assert_eq!(post_processed, None);
+        let timestamps = timestamps.unwrap();
+        assert_eq!(timestamps.len(), 2);
+        assert_eq!((timestamps[1].start_ms, timestamps[1].end_ms), (2100, 2500));
+    }
+
+    #[test]
+    fn post_processing_of_either_part_is_kept() {
+        let mut previous = entry(Some(RecordingMode::Toggle), 0);
+        previous.post_processed_text = Some("First part.".to_string());
+
+        let (_, post_processed, _) = merge_continuation(&previous, 0, "second part", None, &[]);
+        assert_eq!(post_processed.as_deref(), Some("First part. second part"));
+
+        previous.post_processed_text = None;
+        let (_, post_processed, _) =
+            merge_continuation(&previous, 0, "second part", Some("Second part."), &[]);
+        assert_eq!(post_processed.as_deref(), Some("first part Second part."));
+    }
+
+    const DAY: i64 = 24 * 60 * 60;
+    const NOW: i64 = 100 * DAY;
+
+    fn policy(global: RecordingRetentionPeriod) -> RetentionPolicy {
+        RetentionPolicy {
+            global,
+            limit: 2,
+            sources: HashMap::new(),
+            tags: HashMap::new(),
+        }
+    }
+
+    /// Insert an unsaved entry `days_old` days old and return its id.
+    fn insert(
+        conn: &Connection,
+        days_old: i64,
+        mode: Option<RecordingMode>,
+        session_id: Option<i64>,
+    ) -> i64 {
+        conn.execute(
+            "INSERT INTO transcription_history (file_name, timestamp, saved, title, transcription_text, recording_mode, session_id) VALUES ('a.wav', ?1, 0, 'Title', 'text', ?2, ?3)",
+            params![NOW - days_old * DAY, mode.map(RecordingMode::as_str), session_id],
+        )
+        .unwrap();
+        conn.last_insert_rowid()
+    }
+
+    fn expired_ids(conn: &Connection, policy: &RetentionPolicy) -> Vec<i64> {
+        let mut ids: Vec<i64> = expired_entries(conn, policy, NOW)
+            .unwrap()
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect();
+        ids.sort();
+        ids
+    }
+
+    #[test]
+    fn overridden_sources_keep_their_own_period() {
+        let conn = database::open_in_memory().unwrap();
+        let old_file = insert(&conn, 30, Some(RecordingMode::File), None);
+        let old_dictation = insert(&conn, 30, Some(RecordingMode::PushToTalk), None);
+        let old_unknown = insert(&conn, 30, None, None);
+        insert(&conn, 1, Some(RecordingMode::PushToTalk), None);
+
+        let mut policy = policy(RecordingRetentionPeriod::Days3);
+        policy
+            .sources
+            .insert(RecordingMode::File, RecordingRetentionPeriod::Never);
+        // Entries from before sources were recorded follow the global period
+        assert_eq!(expired_ids(&conn, &policy), [old_dictation, old_unknown]);
+
+        policy
+            .sources
+            .insert(RecordingMode::File, RecordingRetentionPeriod::Weeks2);
+        assert_eq!(
+            expired_ids(&conn, &policy),
+            [old_file, old_dictation, old_unknown]
+        );
+    }
+
+    #[test]
+    fn preserve_limit_counts_each_scope_apart() {
+        let conn = database::open_in_memory().unwrap();
+        let dictations: Vec<i64> = (0..4)
+            .map(|days_old| insert(&conn, days_old, Some(RecordingMode::Toggle), None))
+            .collect();
+        let files: Vec<i64> = (0..3)
+            .map(|days_old| insert(&conn, days_old, Some(RecordingMode::File), None))
+            .collect();
+
+        let mut policy = policy(RecordingRetentionPeriod::PreserveLimit);
+        policy
+            .sources
+            .insert(RecordingMode::File, RecordingRetentionPeriod::PreserveLimit);
+        // The two newest of each are kept
+        assert_eq!(
+            expired_ids(&conn, &policy),
+            [dictations[2], dictations[3], files[2]]
+        );
+    }
+
+    #[test]
+    fn focus_session_tags_come_before_sources() {
+        let conn = database::open_in_memory().unwrap();
+        conn.execute(
+            "INSERT INTO focus_sessions (name, start_time) VALUES ('Meetings', 0)",
+            [],
+        )
+        .unwrap();
+        let session = conn.last_insert_rowid();
+        let tagged = insert(&conn, 30, Some(RecordingMode::File), Some(session));
+        let untagged = insert(&conn, 30, Some(RecordingMode::File), None);
+
+        let mut policy = policy(RecordingRetentionPeriod::Never);
+        policy
+            .sources
+            .insert(RecordingMode::File, RecordingRetentionPeriod::Days3);
+        assert_eq!(expired_ids(&conn, &policy), [tagged, untagged]);
+
+        policy
+            .tags
+            .insert("Meetings".to_string(), RecordingRetentionPeriod::Never);
+        assert_eq!(expired_ids(&conn, &policy), [untagged]);
+    }
+
+    #[test]
+    fn saved_entries_are_never_expired() {
+        let conn = database::open_in_memory().unwrap();
+        let id = insert(&conn, 30, Some(RecordingMode::PushToTalk), None);
+        conn.execute(
+            "UPDATE transcription_history SET saved = 1 WHERE id = ?1",
+            params![id],
+        )
+        .unwrap();
+        assert!(expired_ids(&conn, &policy(RecordingRetentionPeriod::Days3)).is_empty());
+    }
+}
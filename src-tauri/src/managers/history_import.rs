@@ -0,0 +1,312 @@
+//! Background import of recordings into history.
+//!
+//! Recordings arrive either as a bundle, a folder of them imported at once,
+//! or one by one in a watch folder. Each file is registered as a background
+//! job in the transcription queue as soon as it arrives, so it shows up in
+//! (and can be cancelled from) the job list and dictations made meanwhile go
+//! first. A single worker then transcribes the files in arrival order and
+//! saves each to history, titled after the file.
+//!
+//! Watch-folder files are moved into an `Imported` subfolder once saved, or a
+//! `Failed` one when they couldn't be (or were cancelled), so they are only
+//! picked up once. The folder isn't scanned while privacy mode is on.
+
+use anyhow::{Context, Result};
+use log::{debug, error, info, warn};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+
+use super::history::{HistoryManager, RecordingMode, RecordingSource};
+use super::scheduler;
+use super::transcription::TranscriptionManager;
+use super::transcription_queue::{JobPriority, TranscriptionJob};
+use crate::audio_toolkit::audio::{decode_audio_file, AudioFormat};
+use crate::settings;
+
+/// How often the watch folder is scanned for new files.
+const WATCH_INTERVAL: Duration = Duration::from_secs(10);
+/// Watch-folder subfolder that imported files are moved to.
+const IMPORTED_DIR: &str = "Imported";
+/// Watch-folder subfolder that files which couldn't be imported are moved to.
+const FAILED_DIR: &str = "Failed";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ImportSource {
+    Bundle,
+    WatchFolder,
+}
+
+struct ImportItem {
+    path: PathBuf,
+    source: ImportSource,
+    job: TranscriptionJob,
+}
+
+pub struct HistoryImportManager {
+    app_handle: AppHandle,
+    sender: Sender<ImportItem>,
+    /// Files queued or being imported, so none is queued twice.
+    pending: Arc<Mutex<HashSet<PathBuf>>>,
+}
+
+impl HistoryImportManager {
+    pub fn new(app_handle: &AppHandle) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        let pending = Arc::new(Mutex::new(HashSet::new()));
+
+        let worker_handle = app_handle.clone();
+        let worker_pending = pending.clone();
+        thread::spawn(move || run_worker(worker_handle, receiver, worker_pending));
+
+        let watcher_handle = app_handle.clone();
+        thread::spawn(move || {
+            // File sizes seen on the previous scan, to skip files still being written
+            let mut sizes = HashMap::new();
+            scheduler::run_every::<HistoryImportManager, _>(
+                &watcher_handle,
+                WATCH_INTERVAL,
+                |manager| manager.scan_watch_folder(&mut sizes),
+            );
+        });
+
+        Self {
+            app_handle: app_handle.clone(),
+            sender,
+            pending,
+        }
+    }
+
+    /// Queue every audio file in the folder `dir` and its subfolders. Returns
+    /// how many were queued.
+    pub fn import_bundle(&self, dir: &Path) -> Result<usize> {
+        if !dir.is_dir() {
+            anyhow::bail!("{} is not a folder", dir.display());
+        }
+        let mut files = Vec::new();
+        collect_audio_files(dir, &mut files)
+            .with_context(|| format!("Failed to read {}", dir.display()))?;
+        if files.is_empty() {
+            anyhow::bail!("No audio files found in {}", dir.display());
+        }
+        files.sort();
+
+        let queued = files
+            .into_iter()
+            .filter(|path| self.enqueue(path.clone(), ImportSource::Bundle))
+            .count();
+        info!("Queued {} files from {} for import", queued, dir.display());
+        Ok(queued)
+    }
+
+    /// Queue the files that arrived in the watch folder and have stopped
+    /// growing since the last scan.
+    fn scan_watch_folder(&self, sizes: &mut HashMap<PathBuf, u64>) {
+        let app_settings = settings::get_settings(&self.app_handle);
+        let Some(folder) = app_settings.import_watch_folder else {
+            sizes.clear();
+            return;
+        };
+        if app_settings.privacy_mode {
+            return;
+        }
+        let entries = match fs::read_dir(&folder) {
+            Ok(entries) => entries,
+            Err(e) => {
+                warn!("Failed to read import watch folder {}: {}", folder, e);
+                return;
+            }
+        };
+
+        let mut seen = HashMap::new();
+        for path in entries.flatten().map(|entry| entry.path()) {
+            if !path.is_file() || !is_audio_file(&path) {
+                continue;
+            }
+            let Ok(size) = fs::metadata(&path).map(|m| m.len()) else {
+                continue;
+            };
+            if size > 0 && sizes.get(&path) == Some(&size) {
+                self.enqueue(path.clone(), ImportSource::WatchFolder);
+            }
+            seen.insert(path, size);
+        }
+        *sizes = seen;
+    }
+
+    /// Register `path` with the transcription queue and hand it to the
+    /// worker. Returns false if it is already queued.
+    fn enqueue(&self, path: PathBuf, source: ImportSource) -> bool {
+        if !self.pending.lock().unwrap().insert(path.clone()) {
+            return false;
+        }
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let transcription_manager = self.app_handle.state::<Arc<TranscriptionManager>>();
+        let job =
+            transcription_manager.submit_job(JobPriority::Background, &format!("Import: {}", name));
+        debug!("Queued {} for import as job {}", path.display(), job.id());
+
+        if self.sender.send(ImportItem { path, source, job }).is_err() {
+            error!("History import worker has stopped");
+            return false;
+        }
+        true
+    }
+}
+
+fn run_worker(
+    app_handle: AppHandle,
+    receiver: Receiver<ImportItem>,
+    pending: Arc<Mutex<HashSet<PathBuf>>>,
+) {
+    for item in receiver {
+        let result = import_file(&app_handle, &item.path, &item.job);
+        match &result {
+            Ok(id) => info!("Imported {} as history entry {}", item.path.display(), id),
+            Err(_) if item.job.is_cancelled() => {
+                info!("Import of {} cancelled", item.path.display())
+            }
+            Err(e) => error!("Failed to import {}: {:#}", item.path.display(), e),
+        }
+        drop(item.job);
+
+        if item.source == ImportSource::WatchFolder {
+            let subfolder = if result.is_ok() {
+                IMPORTED_DIR
+            } else {
+                FAILED_DIR
+            };
+            if let Err(e) = move_into(&item.path, subfolder) {
+                error!(
+                    "Failed to move {} out of the watch folder: {}",
+                    item.path.display(),
+                    e
+                );
+            }
+        }
+        pending.lock().unwrap().remove(&item.path);
+    }
+}
+
+/// Transcribe the file at `path` and save it to history. Returns the id of
+/// the new entry.
+fn import_file(app: &AppHandle, path: &Path, job: &TranscriptionJob) -> Result<i64> {
+    if job.is_cancelled() {
+        return Err(job.cancelled_error());
+    }
+    let samples = decode_audio_file(path)?;
+
+    let transcription_manager = app.state::<Arc<TranscriptionManager>>();
+    transcription_manager.initiate_model_load();
+    let transcript = transcription_manager.transcribe_long(job, &samples, |_| {})?;
+    if transcript.text.trim().is_empty() {
+        anyhow::bail!("No speech detected in the audio");
+    }
+
+    let file_name = path.file_name().map(|n| n.to_string_lossy().to_string());
+    let title = format!(
+        "File: {}",
+        path.file_stem()
+            .map(|s| s.to_string_lossy())
+            .unwrap_or_default()
+    );
+    let history_manager = app.state::<Arc<HistoryManager>>();
+    tauri::async_runtime::block_on(async {
+        let id = history_manager
+            .save_transcription(
+                samples,
+                transcript.text,
+                None,
+                None,
+                RecordingSource {
+                    device: file_name,
+                    sample_rate: None,
+                    mode: Some(RecordingMode::File),
+                },
+            )
+            .await?;
+        history_manager.save_title(id, title).await?;
+        if !transcript.segments.is_empty() {
+            history_manager
+                .save_timestamps(id, &transcript.segments)
+                .await?;
+        }
+        if let Some(language) = transcript.language {
+            history_manager.save_language(id, language).await?;
+        }
+        anyhow::Ok(id)
+    })
+}
+
+fn is_audio_file(path: &Path) -> bool {
+    !matches!(AudioFormat::from_path(path), AudioFormat::Unsupported)
+}
+
+fn collect_audio_files(dir: &Path, files: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_audio_files(&path, files)?;
+        } else if is_audio_file(&path) {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Move `path` into `subfolder` of its own folder, keeping its name.
+fn move_into(path: &Path, subfolder: &str) -> Result<()> {
+    let (Some(parent), Some(name)) = (path.parent(), path.file_name()) else {
+        anyhow::bail!("{} has no parent folder", path.display());
+    };
+    let dir = parent.join(subfolder);
+    fs::create_dir_all(&dir)?;
+    fs::rename(path, dir.join(name))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bundles_are_searched_recursively_for_audio() {
+        let dir = tempfile::tempdir().unwrap();
+        let nested = dir.path().join("day 2");
+        fs::create_dir(&nested).unwrap();
+        for path in [
+            dir.path().join("a.wav"),
+            dir.path().join("notes.txt"),
+            nested.join("b.MP3"),
+        ] {
+            fs::write(path, b"").unwrap();
+        }
+
+        let mut files = Vec::new();
+        collect_audio_files(dir.path(), &mut files).unwrap();
+        files.sort();
+        assert_eq!(files, vec![dir.path().join("a.wav"), nested.join("b.MP3")]);
+    }
+
+    #[test]
+    fn handled_files_move_into_a_subfolder() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("call.m4a");
+        fs::write(&path, b"audio").unwrap();
+
+        move_into(&path, IMPORTED_DIR).unwrap();
+        assert!(!path.exists());
+        assert_eq!(
+            fs::read(dir.path().join(IMPORTED_DIR).join("call.m4a")).unwrap(),
+            b"audio"
+        );
+    }
+}
@@ -0,0 +1,100 @@
+//! Anonymized copies of typed text for sharing typing-behavior data.
+//!
+//! Every character is replaced by a placeholder for its class, so lengths,
+//! word shapes and punctuation rhythm survive but the text doesn't:
+//! "Hi Bob, it's 9!" becomes "Aa Aaa. aa.a 0.".
+
+use serde::Serialize;
+
+/// How many characters of each class a text has.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct CharacterCounts {
+    pub length: usize,
+    pub letters: usize,
+    pub uppercase: usize,
+    pub digits: usize,
+    pub whitespace: usize,
+    pub punctuation: usize,
+    /// Emoji, symbols and anything else.
+    pub other: usize,
+}
+
+/// Replace each character of `text` with the placeholder for its class:
+/// `A`/`a` for upper and lower case letters, `0` for digits, `.` for
+/// punctuation and `*` for anything else. Whitespace is kept.
+pub fn mask(text: &str) -> String {
+    text.chars()
+        .map(|c| {
+            if c.is_uppercase() {
+                'A'
+            } else if c.is_alphabetic() {
+                'a'
+            } else if c.is_numeric() {
+                '0'
+            } else if c == '\n' || c == '\t' {
+                c
+            } else if c.is_whitespace() {
+                ' '
+            } else if c.is_ascii_punctuation() || is_unicode_punctuation(c) {
+                '.'
+            } else {
+                '*'
+            }
+        })
+        .collect()
+}
+
+pub fn count(text: &str) -> CharacterCounts {
+    let mut counts = CharacterCounts::default();
+    for c in text.chars() {
+        counts.length += 1;
+        if c.is_alphabetic() {
+            counts.letters += 1;
+            if c.is_uppercase() {
+                counts.uppercase += 1;
+            }
+        } else if c.is_numeric() {
+            counts.digits += 1;
+        } else if c.is_whitespace() {
+            counts.whitespace += 1;
+        } else if c.is_ascii_punctuation() || is_unicode_punctuation(c) {
+            counts.punctuation += 1;
+        } else {
+            counts.other += 1;
+        }
+    }
+    counts
+}
+
+/// Common non-ASCII punctuation: typographic quotes, dashes, ellipses and
+/// the CJK full stop and comma.
+fn is_unicode_punctuation(c: char) -> bool {
+    matches!(c, '\u{2010}'..='\u{2027}') || "¡¿«»、。".contains(c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn masks_characters_by_class() {
+        assert_eq!(mask("Hi Bob, it's 9!"), "Aa Aaa. aa.a 0.");
+        assert_eq!(mask("Ça va ? 👍\nOui…"), "Aa aa . *\nAaa.");
+    }
+
+    #[test]
+    fn counts_characters_by_class() {
+        assert_eq!(
+            count("Hi Bob, 42 👍"),
+            CharacterCounts {
+                length: 12,
+                letters: 5,
+                uppercase: 2,
+                digits: 2,
+                whitespace: 3,
+                punctuation: 1,
+                other: 1,
+            }
+        );
+    }
+}
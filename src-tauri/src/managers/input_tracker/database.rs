@@ -1,97 +1,124 @@
 //! Database operations for persisting input tracking entries.
 
 use super::types::InputEntry;
+use crate::helpers::event_throttle;
+use crate::managers::db_writer::DbWriter;
+use crate::managers::history::HistoryManager;
+use anyhow::Result;
 use rusqlite::Connection;
 use std::path::PathBuf;
-use tauri::{AppHandle, Emitter};
+use std::sync::Arc;
+use tauri::{AppHandle, Manager};
 
-/// Save an input entry to the database and emit event to frontend
+/// Queue an input entry for saving and emit event to frontend once it is
+/// committed. Never blocks the calling (event-processing) thread.
 pub fn save_entry_to_db(db_path: &PathBuf, entry: &InputEntry, app_handle: &AppHandle) {
-    match Connection::open(db_path) {
-        Ok(conn) => {
-            // Ensure table exists (handles migration edge cases)
-            let table_exists: bool = conn
-                .query_row(
-                    "SELECT COUNT(*) > 0 FROM sqlite_master WHERE type='table' AND name='input_entries'",
-                    [],
-                    |row| row.get(0),
-                )
-                .unwrap_or(false);
+    let Some(writer) = app_handle.try_state::<Arc<DbWriter>>() else {
+        log::error!("[InputTracker] Database writer not available, dropping entry");
+        return;
+    };
+    let session_id = crate::managers::focus_session::active_session_id(app_handle);
+    let saved = entry.clone();
+    let entry = entry.clone();
+    let app_handle = app_handle.clone();
 
-            if !table_exists {
-                log::warn!(
-                    "[InputTracker] Creating input_entries table (migration may have been skipped)"
+    writer.submit_then(
+        db_path,
+        move |conn| insert_entry(conn, &saved, session_id),
+        move |result| match result {
+            Ok(()) => {
+                log::info!(
+                    "[InputTracker] Saved to DB: app={} (pid={:?}), len={}",
+                    entry.app_name,
+                    entry.app_pid,
+                    entry.content.len()
                 );
-                if let Err(e) = conn.execute_batch(
-                    "CREATE TABLE IF NOT EXISTS input_entries (
-                        id INTEGER PRIMARY KEY AUTOINCREMENT,
-                        app_name TEXT NOT NULL,
-                        app_bundle_id TEXT,
-                        window_title TEXT,
-                        content TEXT NOT NULL,
-                        timestamp INTEGER NOT NULL,
-                        duration_ms INTEGER DEFAULT 0,
-                        app_pid INTEGER
-                    );
-                    CREATE INDEX IF NOT EXISTS idx_input_entries_timestamp ON input_entries(timestamp);
-                    CREATE INDEX IF NOT EXISTS idx_input_entries_app ON input_entries(app_bundle_id)",
-                ) {
-                    log::error!("[InputTracker] Failed to create table: {}", e);
-                    return;
-                }
-            } else {
-                // Check if app_pid column exists, add it if missing (handles upgrade from older versions)
-                let has_app_pid: bool = conn
-                    .prepare("PRAGMA table_info(input_entries)")
-                    .and_then(|mut stmt| {
-                        let columns: Vec<String> = stmt
-                            .query_map([], |row| row.get::<_, String>(1))
-                            .map(|iter| iter.filter_map(|r| r.ok()).collect())
-                            .unwrap_or_default();
-                        Ok(columns.iter().any(|c| c == "app_pid"))
-                    })
-                    .unwrap_or(false);
-
-                if !has_app_pid {
-                    log::info!("[InputTracker] Adding app_pid column to input_entries table");
-                    if let Err(e) =
-                        conn.execute("ALTER TABLE input_entries ADD COLUMN app_pid INTEGER", [])
-                    {
-                        log::error!("[InputTracker] Failed to add app_pid column: {}", e);
-                        // Continue anyway, we'll just not save the pid
-                    }
-                }
+                link_to_dictation(&entry, &app_handle);
+                // Emit event to notify frontend
+                event_throttle::emit(&app_handle, "input-entries-updated", ());
             }
+            Err(e) => log::error!("[InputTracker] DB save failed: {:#}", e),
+        },
+    );
+}
 
-            let result = conn.execute(
-                "INSERT INTO input_entries (app_name, app_bundle_id, window_title, content, timestamp, duration_ms, app_pid) 
-                 VALUES (?1, ?2, NULL, ?3, ?4, ?5, ?6)",
-                (
-                    &entry.app_name,
-                    &entry.app_bundle_id,
-                    &entry.content,
-                    entry.timestamp,
-                    entry.duration_ms,
-                    entry.app_pid,
-                ),
+fn insert_entry(conn: &Connection, entry: &InputEntry, session_id: Option<i64>) -> Result<()> {
+    // Ensure table exists (handles migration edge cases)
+    let table_exists: bool = conn
+        .query_row(
+            "SELECT COUNT(*) > 0 FROM sqlite_master WHERE type='table' AND name='input_entries'",
+            [],
+            |row| row.get(0),
+        )
+        .unwrap_or(false);
+
+    if !table_exists {
+        log::warn!("[InputTracker] Creating input_entries table (migration may have been skipped)");
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS input_entries (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                app_name TEXT NOT NULL,
+                app_bundle_id TEXT,
+                window_title TEXT,
+                content TEXT NOT NULL,
+                timestamp INTEGER NOT NULL,
+                duration_ms INTEGER DEFAULT 0,
+                app_pid INTEGER,
+                session_id INTEGER
             );
+            CREATE INDEX IF NOT EXISTS idx_input_entries_timestamp ON input_entries(timestamp);
+            CREATE INDEX IF NOT EXISTS idx_input_entries_app ON input_entries(app_bundle_id)",
+        )?;
+    } else {
+        // Add columns missing from older versions (handles upgrades)
+        let columns: Vec<String> = conn
+            .prepare("PRAGMA table_info(input_entries)")
+            .and_then(|mut stmt| {
+                Ok(stmt
+                    .query_map([], |row| row.get::<_, String>(1))
+                    .map(|iter| iter.filter_map(|r| r.ok()).collect())
+                    .unwrap_or_default())
+            })
+            .unwrap_or_default();
 
-            match result {
-                Ok(_) => {
-                    log::info!(
-                        "[InputTracker] Saved to DB: app={} (pid={:?}), len={}",
-                        entry.app_name,
-                        entry.app_pid,
-                        entry.content.len()
-                    );
-                    // Emit event to notify frontend
-                    if let Err(e) = app_handle.emit("input-entries-updated", ()) {
-                        log::warn!("[InputTracker] Failed to emit update event: {}", e);
-                    }
-                }
-                Err(e) => log::error!("[InputTracker] DB save failed: {}", e),
+        for column in ["app_pid", "session_id"] {
+            if columns.iter().any(|c| c == column) {
+                continue;
+            }
+            log::info!(
+                "[InputTracker] Adding {} column to input_entries table",
+                column
+            );
+            if let Err(e) = conn.execute(
+                &format!("ALTER TABLE input_entries ADD COLUMN {} INTEGER", column),
+                [],
+            ) {
+                log::error!("[InputTracker] Failed to add {} column: {}", column, e);
+                // Continue anyway, we'll just not save the value
             }
         }
-        Err(e) => log::error!("[InputTracker] DB open failed: {}", e),
     }
+
+    conn.execute(
+        "INSERT INTO input_entries (app_name, app_bundle_id, window_title, content, timestamp, duration_ms, app_pid, session_id)
+         VALUES (?1, ?2, NULL, ?3, ?4, ?5, ?6, ?7)",
+        (
+            &entry.app_name,
+            &entry.app_bundle_id,
+            &entry.content,
+            entry.timestamp,
+            entry.duration_ms,
+            entry.app_pid,
+            session_id,
+        ),
+    )?;
+    Ok(())
+}
+
+/// Record how a pasted dictation was edited, if this entry contains one
+fn link_to_dictation(entry: &InputEntry, app_handle: &AppHandle) {
+    let Some(history) = app_handle.try_state::<Arc<HistoryManager>>() else {
+        return;
+    };
+    history.link_input_entry(&entry.app_name, &entry.content, entry.timestamp);
 }
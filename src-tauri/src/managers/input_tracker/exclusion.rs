@@ -0,0 +1,102 @@
+//! Window-scoped exclusion rules.
+//!
+//! Complements app-based exclusions with case-insensitive regexes matched
+//! against the focused window's title and, where the platform exposes it, its
+//! document URL (e.g. exclude any window containing "Incognito" or "1Password").
+
+use regex::{Regex, RegexBuilder};
+
+use super::types::WindowContext;
+
+/// Compiled window exclusion patterns.
+#[derive(Debug, Default)]
+pub struct WindowExclusionRules {
+    patterns: Vec<Regex>,
+}
+
+impl WindowExclusionRules {
+    /// Compile `patterns`, skipping (and logging) any that aren't valid regexes.
+    pub fn compile(patterns: &[String]) -> Self {
+        let patterns = patterns
+            .iter()
+            .filter(|p| !p.trim().is_empty())
+            .filter_map(|p| match build_regex(p) {
+                Ok(regex) => Some(regex),
+                Err(e) => {
+                    log::warn!(
+                        "[InputTracker] Ignoring invalid window pattern '{}': {}",
+                        p,
+                        e
+                    );
+                    None
+                }
+            })
+            .collect();
+        Self { patterns }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty()
+    }
+
+    /// Whether the window's title or URL matches any rule.
+    pub fn matches(&self, window: &WindowContext) -> bool {
+        [window.title.as_deref(), window.url.as_deref()]
+            .into_iter()
+            .flatten()
+            .any(|text| self.patterns.iter().any(|p| p.is_match(text)))
+    }
+}
+
+fn build_regex(pattern: &str) -> Result<Regex, regex::Error> {
+    RegexBuilder::new(pattern).case_insensitive(true).build()
+}
+
+/// Check that every pattern compiles, returning a message naming the first bad one.
+pub fn validate_patterns(patterns: &[String]) -> Result<(), String> {
+    for pattern in patterns.iter().filter(|p| !p.trim().is_empty()) {
+        build_regex(pattern).map_err(|e| format!("Invalid pattern '{}': {}", pattern, e))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn window(title: &str, url: Option<&str>) -> WindowContext {
+        WindowContext {
+            title: Some(title.to_string()),
+            url: url.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn matches_title_case_insensitively() {
+        let rules = WindowExclusionRules::compile(&["incognito".to_string()]);
+        assert!(rules.matches(&window("New Tab - Google Chrome (Incognito)", None)));
+        assert!(!rules.matches(&window("New Tab - Google Chrome", None)));
+    }
+
+    #[test]
+    fn matches_url() {
+        let rules = WindowExclusionRules::compile(&[r"^https://bank\.example\.com".to_string()]);
+        assert!(rules.matches(&window(
+            "Accounts",
+            Some("https://bank.example.com/accounts")
+        )));
+    }
+
+    #[test]
+    fn invalid_and_blank_patterns_are_skipped() {
+        let rules = WindowExclusionRules::compile(&["(".to_string(), "  ".to_string()]);
+        assert!(rules.is_empty());
+        assert!(!rules.matches(&window("anything", None)));
+    }
+
+    #[test]
+    fn validation_reports_bad_pattern() {
+        assert!(validate_patterns(&["1Password".to_string()]).is_ok());
+        assert!(validate_patterns(&["[".to_string()]).is_err());
+    }
+}
@@ -9,58 +9,122 @@
 //! The module is organized into several sub-modules:
 //!
 //! - `types` - Core data types (ActiveAppInfo, InputEntry, events)
+//! - `anonymize` - Character-class placeholders for sharing entries without their text
+//! - `exclusion` - Window title/URL exclusion rules
+//! - `status` - Live tracking status events for the UI and tray tooltip
 //! - `state` - Input state management (buffer, cursor, modifiers)
+//! - `processor` - Event processing: buffer edits and when entries are saved
+//! - `simulation` - Scripted input for end-to-end tests (test builds only)
 //! - `platform` - OS-specific application detection
 //! - `database` - Database persistence operations
+//! - `supervisor` - Panic catching and restart for the worker threads
 //!
 //! ## Event-based Architecture
 //!
 //! The tracker uses an event-based architecture with separate threads for:
-//! - Keyboard/mouse event capture (rdev)
+//! - Keyboard/mouse event capture (the app's shared rdev hook, see
+//!   [`crate::input_hook`])
 //! - App change detection (polling with native APIs)
 //! - Idle timeout checking
 //! - Event processing and database persistence
+//!
+//! The idle checker and event processor run supervised: a panic is logged,
+//! reported to the UI and the thread restarted. A panic in the keyboard hook
+//! callback is logged and reported, and the next event goes through again.
 
+pub mod anonymize;
 mod database;
+mod exclusion;
 mod platform;
+mod processor;
+#[cfg(test)]
+mod simulation;
 mod state;
+mod status;
+mod supervisor;
 mod types;
 
+use crate::input_hook;
+use crate::keyboard_layout;
+use crate::settings::AppSettings;
 use anyhow::Result;
-use rdev::{listen, Event, EventType, Key};
+use rdev::{Event, EventType, UnicodeInfo};
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
-use std::sync::{mpsc, Arc, RwLock};
+use std::sync::{mpsc, Arc, Mutex, RwLock};
 use std::thread;
 use std::time::Duration;
 use tauri::{AppHandle, Manager};
 
 use database::save_entry_to_db;
-use platform::get_active_app_info_fast;
-use state::{InputState, ModifierState};
-use types::{ActiveAppInfo, InputTrackerEvent, KeystrokeEvent};
+pub use exclusion::WindowExclusionRules;
+pub use platform::get_active_app_info_fast;
+pub use platform::get_active_window_context;
+use processor::{Environment, Processor};
+use status::{StatusReporter, TrackingStatus};
+pub use supervisor::install_panic_hook;
+use supervisor::spawn_supervised;
+pub use types::{ActiveAppInfo, WindowContext};
+use types::{InputEntry, InputTrackerEvent, KeystrokeEvent};
+
+pub use exclusion::validate_patterns as validate_window_patterns;
 
 // Re-export the manager for external use (currently unused but may be needed by other modules)
 
-/// Default timeout duration after which we consider the user has left an input field
-/// Value of 0 means disabled (only count on app switch/click)
-const DEFAULT_INPUT_IDLE_TIMEOUT_SECS: u64 = 2;
-
 /// How often to check for idle timeout (milliseconds)
 /// Only used for idle timeout checking, not app switching
 const IDLE_CHECK_INTERVAL: Duration = Duration::from_millis(500);
 
+/// How often the focused window title/URL is polled while window rules exist
+const WINDOW_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Pasted text longer than this is not merged into the entry (the buffer is
+/// saved and cleared instead, as for other clipboard operations)
+const MAX_CAPTURED_PASTE_CHARS: usize = 10_000;
+
+/// The settings the tracker follows, kept to apply only what changed.
+#[derive(Clone, Debug, PartialEq)]
+struct TrackerSettings {
+    /// Tracking is on and privacy mode doesn't pause it
+    enabled: bool,
+    excluded_apps: Vec<String>,
+    window_patterns: Vec<String>,
+    /// 0 means disabled (only count on app switch/click)
+    idle_timeout_secs: u64,
+    capture_paste: bool,
+}
+
+impl TrackerSettings {
+    fn from_settings(settings: &AppSettings) -> Self {
+        Self {
+            enabled: settings.input_tracking_enabled && !settings.privacy_mode,
+            excluded_apps: settings.input_tracking_excluded_apps.clone(),
+            window_patterns: settings.input_tracking_excluded_window_patterns.clone(),
+            idle_timeout_secs: settings.input_tracking_idle_timeout.unwrap_or(0),
+            capture_paste: settings.input_tracking_capture_paste,
+        }
+    }
+}
+
 /// Manager for tracking system-wide input and storing entries
 pub struct InputTrackerManager {
     enabled: Arc<AtomicBool>,
     db_path: PathBuf,
     excluded_apps: Arc<RwLock<Vec<String>>>,
+    /// Regex rules matched against the focused window's title and URL
+    window_rules: Arc<RwLock<WindowExclusionRules>>,
     /// Idle timeout in seconds. 0 means disabled (only count on app switch/click)
     idle_timeout_secs: Arc<AtomicU64>,
+    /// Merge clipboard text into the buffer on paste
+    capture_paste: Arc<AtomicBool>,
     /// Channel sender for events
     event_sender: Option<mpsc::Sender<InputTrackerEvent>>,
+    /// Keyboard/mouse events, while tracking
+    hook: Option<input_hook::Subscription>,
     /// App handle for emitting Tauri events
     app_handle: Option<AppHandle>,
+    /// Settings last applied, see [`Self::apply_settings`]
+    applied: TrackerSettings,
 }
 
 impl InputTrackerManager {
@@ -68,36 +132,75 @@ impl InputTrackerManager {
     pub fn new(app_handle: &AppHandle) -> Result<Self> {
         log::info!("[InputTracker] Creating new InputTrackerManager");
 
-        let app_data_dir = app_handle
-            .path()
-            .app_data_dir()
-            .expect("Failed to get app data directory");
+        let app_data_dir =
+            crate::profile::data_dir(app_handle).expect("Failed to get app data directory");
         let db_path = app_data_dir.join("echo.db");
 
-        let settings = crate::settings::get_settings(app_handle);
-        let excluded_apps = settings.input_tracking_excluded_apps.clone();
-        let idle_timeout = settings
-            .input_tracking_idle_timeout
-            .unwrap_or(DEFAULT_INPUT_IDLE_TIMEOUT_SECS);
+        let applied = TrackerSettings::from_settings(&crate::settings::get_settings(app_handle));
+        let window_rules = WindowExclusionRules::compile(&applied.window_patterns);
 
         let manager = Self {
             enabled: Arc::new(AtomicBool::new(false)),
             db_path,
-            excluded_apps: Arc::new(RwLock::new(excluded_apps)),
-            idle_timeout_secs: Arc::new(AtomicU64::new(idle_timeout)),
+            excluded_apps: Arc::new(RwLock::new(applied.excluded_apps.clone())),
+            window_rules: Arc::new(RwLock::new(window_rules)),
+            idle_timeout_secs: Arc::new(AtomicU64::new(applied.idle_timeout_secs)),
+            capture_paste: Arc::new(AtomicBool::new(applied.capture_paste)),
             event_sender: None,
+            hook: None,
             app_handle: Some(app_handle.clone()),
+            applied,
         };
 
+        // Follow settings changes live, however they're made. The manager is
+        // only found once it's managed, which happens before tracking starts.
+        crate::settings::subscribe(|app, settings| {
+            if let Some(manager) = app.try_state::<Arc<Mutex<InputTrackerManager>>>() {
+                match manager.lock() {
+                    Ok(mut tracker) => tracker.apply_settings(settings, app),
+                    Err(_) => log::error!("[InputTracker] Failed to lock manager"),
+                }
+            }
+        });
+
         Ok(manager)
     }
 
+    /// Apply the input tracking settings that changed since the last call,
+    /// starting or stopping the tracker as needed.
+    fn apply_settings(&mut self, settings: &AppSettings, app_handle: &AppHandle) {
+        let next = TrackerSettings::from_settings(settings);
+        if next == self.applied {
+            return;
+        }
+        if next.excluded_apps != self.applied.excluded_apps {
+            self.set_excluded_apps(next.excluded_apps.clone());
+        }
+        if next.window_patterns != self.applied.window_patterns {
+            self.set_excluded_window_patterns(&next.window_patterns);
+        }
+        if next.idle_timeout_secs != self.applied.idle_timeout_secs {
+            self.set_idle_timeout(next.idle_timeout_secs);
+        }
+        if next.capture_paste != self.applied.capture_paste {
+            self.set_capture_paste(next.capture_paste);
+        }
+        if next.enabled != self.applied.enabled {
+            self.set_enabled(next.enabled, app_handle);
+        }
+        self.applied = next;
+    }
+
     /// Update the list of excluded apps
     pub fn set_excluded_apps(&self, apps: Vec<String>) {
-        if let Ok(mut excluded) = self.excluded_apps.write() {
-            *excluded = apps;
-            log::info!("[InputTracker] Updated excluded apps list");
-        }
+        *supervisor::write(&self.excluded_apps) = apps;
+        log::info!("[InputTracker] Updated excluded apps list");
+    }
+
+    /// Update the window title/URL exclusion patterns
+    pub fn set_excluded_window_patterns(&self, patterns: &[String]) {
+        *supervisor::write(&self.window_rules) = WindowExclusionRules::compile(patterns);
+        log::info!("[InputTracker] Updated excluded window patterns");
     }
 
     /// Update the idle timeout in seconds. 0 means disabled.
@@ -109,8 +212,14 @@ impl InputTrackerManager {
         );
     }
 
+    /// Enable or disable merging pasted clipboard text into tracked entries
+    pub fn set_capture_paste(&self, enabled: bool) {
+        self.capture_paste.store(enabled, Ordering::SeqCst);
+        log::info!("[InputTracker] Updated paste capture to {}", enabled);
+    }
+
     /// Check if an app is excluded
-    fn is_app_excluded(excluded: &[String], app_info: &ActiveAppInfo) -> bool {
+    pub(crate) fn is_app_excluded(excluded: &[String], app_info: &ActiveAppInfo) -> bool {
         let by_bundle_id = app_info.bundle_id.as_ref().map_or(false, |id| {
             excluded.iter().any(|e| e.eq_ignore_ascii_case(id))
         });
@@ -138,339 +247,62 @@ impl InputTrackerManager {
 
         let db_path = self.db_path.clone();
         let excluded_apps = self.excluded_apps.clone();
+        let window_rules = self.window_rules.clone();
         let idle_timeout_secs = self.idle_timeout_secs.clone();
+        let capture_paste = self.capture_paste.clone();
 
         // Spawn the main event processor thread
         let processor_app_handle = app_handle.clone();
-        thread::spawn(move || {
+        let processor = move || {
             log::info!("[InputTracker] Event processor thread started");
-            let mut state = InputState::default();
-
-            // Get initial app info
-            let mut current_app = get_active_app_info_fast();
-            state.set_current_app(current_app.clone());
-            log::info!(
-                "[InputTracker] Initial app: '{}' ({:?})",
-                current_app.name,
-                current_app.bundle_id
-            );
-
-            for event in rx {
-                match event {
-                    InputTrackerEvent::AppChanged(new_app) => {
-                        if new_app != current_app && !current_app.name.is_empty() {
-                            log::info!(
-                                "[InputTracker] App changed: {} -> {}",
-                                current_app.name,
-                                new_app.name
-                            );
-
-                            // Check if previous app was excluded
-                            let prev_excluded = {
-                                let excluded = excluded_apps.read().unwrap();
-                                Self::is_app_excluded(&excluded, &current_app)
-                            };
-
-                            if !prev_excluded {
-                                if let Some(entry) = state.take_entry() {
-                                    save_entry_to_db(&db_path, &entry, &processor_app_handle);
-                                }
-                            } else {
-                                state.clear();
-                            }
-
-                            state.set_current_app(new_app.clone());
-                            current_app = new_app;
-                        } else if current_app.name.is_empty() {
-                            current_app = new_app.clone();
-                            state.set_current_app(new_app);
-                        }
-                    }
-                    InputTrackerEvent::Keystroke(keystroke) => {
-                        // Check if current app is excluded
-                        let is_excluded = {
-                            let excluded = excluded_apps.read().unwrap();
-                            Self::is_app_excluded(&excluded, &current_app)
-                        };
-
-                        if is_excluded {
-                            continue;
-                        }
-
-                        if keystroke.is_press {
-                            state.modifiers.update(keystroke.key, true);
-
-                            if ModifierState::is_modifier_key(keystroke.key) {
-                                continue;
-                            }
-
-                            // --- Buffer-invalidating operations ---
-                            // These operations make our cursor tracking unreliable, so save and clear
-
-                            // Selection (Shift + Arrow keys) invalidates cursor tracking
-                            let is_selection = state.modifiers.shift
-                                && matches!(
-                                    keystroke.key,
-                                    Key::LeftArrow
-                                        | Key::RightArrow
-                                        | Key::UpArrow
-                                        | Key::DownArrow
-                                        | Key::Home
-                                        | Key::End
-                                );
-
-                            // Clipboard operations (Cmd/Ctrl + C/X/V/A)
-                            let is_clipboard_op = (state.modifiers.meta || state.modifiers.ctrl)
-                                && matches!(
-                                    keystroke.key,
-                                    Key::KeyC | Key::KeyX | Key::KeyV | Key::KeyA
-                                );
-
-                            // Undo/Redo (Cmd/Ctrl + Z, Cmd/Ctrl + Shift + Z, Cmd/Ctrl + Y)
-                            let is_undo_redo = (state.modifiers.meta || state.modifiers.ctrl)
-                                && matches!(keystroke.key, Key::KeyZ | Key::KeyY);
-
-                            // Vertical navigation (Up/Down arrows without word modifier)
-                            // These move between lines which we can't track
-                            let is_vertical_nav = !state.modifiers.shift
-                                && matches!(keystroke.key, Key::UpArrow | Key::DownArrow);
-
-                            // Large jumps (PageUp/PageDown, Cmd+Up/Down for document start/end)
-                            let is_large_jump =
-                                matches!(keystroke.key, Key::PageUp | Key::PageDown)
-                                    || (state.modifiers.is_line_modifier()
-                                        && matches!(keystroke.key, Key::UpArrow | Key::DownArrow));
-
-                            // Submit keys (Return, Tab, Escape)
-                            let is_submit_key =
-                                matches!(keystroke.key, Key::Return | Key::Tab | Key::Escape);
-
-                            // Save shortcut (Cmd/Ctrl + S)
-                            let is_save_shortcut = (state.modifiers.meta || state.modifiers.ctrl)
-                                && matches!(keystroke.key, Key::KeyS);
-
-                            if is_selection || is_clipboard_op || is_undo_redo || is_large_jump {
-                                // Save buffer and clear - these operations invalidate our tracking
-                                if let Some(entry) = state.take_entry() {
-                                    log::info!(
-                                        "[InputTracker] Buffer-invalidating op {:?}, saving: '{}'",
-                                        keystroke.key,
-                                        entry.content
-                                    );
-                                    save_entry_to_db(&db_path, &entry, &processor_app_handle);
-                                }
-                            } else if is_vertical_nav {
-                                // Vertical navigation without modifiers - save and clear
-                                if let Some(entry) = state.take_entry() {
-                                    log::info!(
-                                        "[InputTracker] Vertical nav {:?}, saving: '{}'",
-                                        keystroke.key,
-                                        entry.content
-                                    );
-                                    save_entry_to_db(&db_path, &entry, &processor_app_handle);
-                                }
-                            } else if is_submit_key || is_save_shortcut {
-                                if let Some(entry) = state.take_entry() {
-                                    let reason = if is_save_shortcut {
-                                        "save shortcut"
-                                    } else {
-                                        "submit key"
-                                    };
-                                    log::info!(
-                                        "[InputTracker] {} {:?}, saving: '{}'",
-                                        reason,
-                                        keystroke.key,
-                                        entry.content
-                                    );
-                                    save_entry_to_db(&db_path, &entry, &processor_app_handle);
-                                }
-                            }
-                            // --- Cursor movement operations ---
-                            else if matches!(keystroke.key, Key::LeftArrow) {
-                                if state.modifiers.is_line_modifier() {
-                                    // Cmd+Left on macOS: move to start of line
-                                    state.move_cursor_to_start();
-                                } else if state.modifiers.is_word_modifier() {
-                                    // Option+Left on macOS, Ctrl+Left on Win/Linux: word jump
-                                    state.move_cursor_word_left();
-                                } else {
-                                    // Plain left arrow: single character
-                                    state.move_cursor_left();
-                                }
-                            } else if matches!(keystroke.key, Key::RightArrow) {
-                                if state.modifiers.is_line_modifier() {
-                                    // Cmd+Right on macOS: move to end of line
-                                    state.move_cursor_to_end();
-                                } else if state.modifiers.is_word_modifier() {
-                                    // Option+Right on macOS, Ctrl+Right on Win/Linux: word jump
-                                    state.move_cursor_word_right();
-                                } else {
-                                    // Plain right arrow: single character
-                                    state.move_cursor_right();
-                                }
-                            } else if matches!(keystroke.key, Key::Home) {
-                                state.move_cursor_to_start();
-                            } else if matches!(keystroke.key, Key::End) {
-                                state.move_cursor_to_end();
-                            }
-                            // --- Deletion operations ---
-                            else if matches!(keystroke.key, Key::Backspace) {
-                                if state.modifiers.meta || state.modifiers.ctrl {
-                                    // Cmd/Ctrl+Backspace: delete word or line - clear buffer
-                                    state.clear();
-                                } else if state.modifiers.is_word_modifier() {
-                                    // Option+Backspace on macOS: delete word
-                                    // We can't accurately track this, so clear
-                                    state.clear();
-                                } else {
-                                    state.handle_backspace();
-                                }
-                            } else if matches!(keystroke.key, Key::Delete) {
-                                if state.modifiers.meta
-                                    || state.modifiers.ctrl
-                                    || state.modifiers.is_word_modifier()
-                                {
-                                    // Modified delete: clear buffer
-                                    state.clear();
-                                } else {
-                                    state.handle_delete();
-                                }
-                            }
-                            // --- Character input ---
-                            else if !state.modifiers.any_modifier() {
-                                if let Some(ref unicode) = keystroke.unicode {
-                                    for c in unicode.chars() {
-                                        if c.is_ascii_graphic() || c == ' ' {
-                                            state.append_char(c);
-                                        }
-                                    }
-                                }
-                            } else {
-                                // Some other modifier combination - just update last keystroke
-                                state.last_keystroke = Some(std::time::Instant::now());
-                            }
-                        } else {
-                            state.modifiers.update(keystroke.key, false);
-                        }
-                    }
-                    InputTrackerEvent::Click => {
-                        let is_excluded = {
-                            let excluded = excluded_apps.read().unwrap();
-                            Self::is_app_excluded(&excluded, &current_app)
-                        };
-
-                        if !is_excluded {
-                            if let Some(entry) = state.take_entry() {
-                                log::info!("[InputTracker] Click, saving: '{}'", entry.content);
-                                save_entry_to_db(&db_path, &entry, &processor_app_handle);
-                            }
-                        }
-
-                        // On click, also check if app changed (handles Dock clicks, etc.)
-                        let new_app = get_active_app_info_fast();
-                        if new_app != current_app {
-                            log::info!(
-                                "[InputTracker] App changed on click: {} -> {}",
-                                current_app.name,
-                                new_app.name
-                            );
-                            state.set_current_app(new_app.clone());
-                            current_app = new_app;
-                        }
-                    }
-                    InputTrackerEvent::IdleCheck => {
-                        let timeout_secs = idle_timeout_secs.load(Ordering::SeqCst);
-                        if timeout_secs > 0 {
-                            let is_excluded = {
-                                let excluded = excluded_apps.read().unwrap();
-                                Self::is_app_excluded(&excluded, &current_app)
-                            };
-
-                            if !is_excluded
-                                && state.is_idle(Duration::from_secs(timeout_secs))
-                                && state.has_content()
-                            {
-                                if let Some(entry) = state.take_entry() {
-                                    log::info!(
-                                        "[InputTracker] Idle timeout, saving: '{}'",
-                                        entry.content
-                                    );
-                                    save_entry_to_db(&db_path, &entry, &processor_app_handle);
-                                }
-                            }
-                        }
-                    }
-                    InputTrackerEvent::Shutdown => {
-                        log::info!("[InputTracker] Received shutdown signal");
-                        // Save any remaining content
-                        if let Some(entry) = state.take_entry() {
-                            log::info!(
-                                "[InputTracker] Saving remaining on shutdown: '{}'",
-                                entry.content
-                            );
-                            save_entry_to_db(&db_path, &entry, &processor_app_handle);
-                        }
-                        break;
-                    }
+            let mut processor = Processor::new(LiveEnvironment {
+                app_handle: processor_app_handle.clone(),
+                db_path: db_path.clone(),
+                excluded_apps: excluded_apps.clone(),
+                window_rules: window_rules.clone(),
+                idle_timeout_secs: idle_timeout_secs.clone(),
+                capture_paste: capture_paste.clone(),
+                status_reporter: StatusReporter::default(),
+            });
+            for event in rx.iter() {
+                if !processor.handle(event) {
+                    break;
                 }
             }
             log::info!("[InputTracker] Event processor thread stopped");
-        });
+        };
+        spawn_supervised(
+            "processor",
+            self.enabled.clone(),
+            app_handle.clone(),
+            processor,
+        );
 
-        // Spawn the keyboard/mouse listener thread
+        // Subscribe to keyboard/mouse events from the app's shared hook
         let keyboard_tx = tx.clone();
         let keyboard_enabled = self.enabled.clone();
-        thread::spawn(move || {
-            log::info!("[InputTracker] Keyboard listener thread starting...");
-
-            let callback = move |event: Event| {
-                if !keyboard_enabled.load(Ordering::SeqCst) {
-                    return;
-                }
-
-                match event.event_type {
-                    EventType::KeyPress(key) => {
-                        let unicode = event.unicode.and_then(|u| {
-                            if let Some(name) = u.name {
-                                Some(name)
-                            } else if !u.unicode.is_empty() {
-                                String::from_utf16(&u.unicode).ok()
-                            } else {
-                                None
-                            }
-                        });
-
-                        let _ = keyboard_tx.send(InputTrackerEvent::Keystroke(KeystrokeEvent {
-                            key,
-                            unicode,
-                            is_press: true,
-                        }));
-                    }
-                    EventType::KeyRelease(key) => {
-                        let _ = keyboard_tx.send(InputTrackerEvent::Keystroke(KeystrokeEvent {
-                            key,
-                            unicode: None,
-                            is_press: false,
-                        }));
-                    }
-                    EventType::ButtonPress(_) => {
-                        let _ = keyboard_tx.send(InputTrackerEvent::Click);
-                    }
-                    _ => {}
-                }
-            };
+        let keyboard_app_handle = app_handle.clone();
+        let handle_event = move |event: &Event| {
+            if !keyboard_enabled.load(Ordering::SeqCst) {
+                return;
+            }
 
-            log::info!("[InputTracker] Starting rdev::listen...");
-            if let Err(e) = listen(callback) {
-                log::error!("[InputTracker] rdev::listen failed: {:?}", e);
+            let code = platform_key_code(event);
+            if let Some(event) = to_tracker_event(event.event_type, event.unicode.clone(), code) {
+                let _ = keyboard_tx.send(event);
             }
-            log::info!("[InputTracker] rdev::listen exited");
-        });
+        };
+        // The hook is called from OS code, which a panic must not unwind into
+        self.hook = Some(input_hook::subscribe(move |event| {
+            if let Err(payload) = supervisor::catch_panic(|| handle_event(event)) {
+                supervisor::report_panic(&keyboard_app_handle, "listener", payload);
+            }
+        }));
 
         // Spawn the idle timeout checker thread
         let idle_tx = tx.clone();
         let idle_enabled = self.enabled.clone();
-        thread::spawn(move || {
+        let idle_checker = move || {
             log::info!("[InputTracker] Idle checker thread started");
             while idle_enabled.load(Ordering::SeqCst) {
                 thread::sleep(IDLE_CHECK_INTERVAL);
@@ -479,6 +311,37 @@ impl InputTrackerManager {
                 }
             }
             log::info!("[InputTracker] Idle checker thread stopped");
+        };
+        spawn_supervised(
+            "idle-checker",
+            self.enabled.clone(),
+            app_handle.clone(),
+            idle_checker,
+        );
+
+        // Spawn the window title/URL watcher thread (idle unless window rules exist)
+        let window_tx = tx.clone();
+        let window_enabled = self.enabled.clone();
+        let watcher_rules = self.window_rules.clone();
+        thread::spawn(move || {
+            let mut last_window = WindowContext::default();
+            while window_enabled.load(Ordering::SeqCst) {
+                thread::sleep(WINDOW_POLL_INTERVAL);
+                if supervisor::read(&watcher_rules).is_empty() {
+                    continue;
+                }
+
+                let window = get_active_window_context();
+                if window != last_window {
+                    if window_tx
+                        .send(InputTrackerEvent::WindowChanged(window.clone()))
+                        .is_err()
+                    {
+                        break;
+                    }
+                    last_window = window;
+                }
+            }
         });
 
         // Spawn the app change watcher thread (fallback polling with longer interval)
@@ -498,16 +361,34 @@ impl InputTrackerManager {
     pub fn stop(&mut self) {
         log::info!("[InputTracker] Stopping input tracker...");
         self.enabled.store(false, Ordering::SeqCst);
+        self.hook = None;
 
         // Send shutdown event
         if let Some(ref sender) = self.event_sender {
-            let _ = sender.send(InputTrackerEvent::Shutdown);
+            let _ = sender.send(InputTrackerEvent::Shutdown(None));
         }
         self.event_sender = None;
 
         log::info!("[InputTracker] Input tracker stopped");
     }
 
+    /// Stop tracking and wait (up to `timeout`) until the event processor has
+    /// queued the unsaved buffer for writing. Returns whether it did in time.
+    pub fn flush_and_stop(&mut self, timeout: Duration) -> bool {
+        let Some(sender) = self.event_sender.take() else {
+            return true;
+        };
+        log::info!("[InputTracker] Flushing buffer before exit...");
+        self.enabled.store(false, Ordering::SeqCst);
+        self.hook = None;
+
+        let (ack, done) = mpsc::channel();
+        if sender.send(InputTrackerEvent::Shutdown(Some(ack))).is_err() {
+            return false;
+        }
+        done.recv_timeout(timeout).is_ok()
+    }
+
     /// Check if tracking is currently enabled
     pub fn is_enabled(&self) -> bool {
         self.enabled.load(Ordering::SeqCst)
@@ -526,6 +407,135 @@ impl InputTrackerManager {
     }
 }
 
+/// Read the focused window context, skipping the platform query when no window
+/// rules are configured.
+fn current_window_context(rules: &RwLock<WindowExclusionRules>) -> WindowContext {
+    if supervisor::read(rules).is_empty() {
+        WindowContext::default()
+    } else {
+        get_active_window_context()
+    }
+}
+
+/// The processor's environment while tracking for real.
+struct LiveEnvironment {
+    app_handle: AppHandle,
+    db_path: PathBuf,
+    excluded_apps: Arc<RwLock<Vec<String>>>,
+    window_rules: Arc<RwLock<WindowExclusionRules>>,
+    idle_timeout_secs: Arc<AtomicU64>,
+    capture_paste: Arc<AtomicBool>,
+    status_reporter: StatusReporter,
+}
+
+impl Environment for LiveEnvironment {
+    fn active_app(&self) -> ActiveAppInfo {
+        get_active_app_info_fast()
+    }
+
+    fn window_context(&self) -> WindowContext {
+        current_window_context(&self.window_rules)
+    }
+
+    fn is_app_excluded(&self, app: &ActiveAppInfo) -> bool {
+        InputTrackerManager::is_app_excluded(&supervisor::read(&self.excluded_apps), app)
+    }
+
+    fn is_window_excluded(&self, window: &WindowContext) -> bool {
+        supervisor::read(&self.window_rules).matches(window)
+    }
+
+    fn idle_timeout_secs(&self) -> u64 {
+        self.idle_timeout_secs.load(Ordering::SeqCst)
+    }
+
+    fn pasted_text(&self) -> Option<String> {
+        if self.capture_paste.load(Ordering::SeqCst) {
+            read_pasted_text(&self.app_handle)
+        } else {
+            None
+        }
+    }
+
+    fn refresh_layout(&self) {
+        keyboard_layout::refresh(&self.app_handle);
+    }
+
+    fn save(&mut self, entry: &InputEntry) {
+        save_entry_to_db(&self.db_path, entry, &self.app_handle);
+    }
+
+    fn report_status(&mut self, status: TrackingStatus) {
+        self.status_reporter.report(&self.app_handle, status);
+    }
+}
+
+/// Convert a raw rdev event into a tracker event. None for events the
+/// tracker ignores (mouse moves, wheel, button releases).
+fn to_tracker_event(
+    event_type: EventType,
+    unicode: Option<UnicodeInfo>,
+    code: u32,
+) -> Option<InputTrackerEvent> {
+    match event_type {
+        EventType::KeyPress(key) => {
+            // Dead keys type nothing themselves; the composed character
+            // arrives with the next key
+            let unicode = unicode.filter(|u| !u.is_dead).and_then(|u| {
+                if let Some(name) = u.name {
+                    Some(name)
+                } else if !u.unicode.is_empty() {
+                    String::from_utf16(&u.unicode).ok()
+                } else {
+                    None
+                }
+            });
+            Some(InputTrackerEvent::Keystroke(KeystrokeEvent {
+                key,
+                code,
+                unicode,
+                is_press: true,
+            }))
+        }
+        EventType::KeyRelease(key) => Some(InputTrackerEvent::Keystroke(KeystrokeEvent {
+            key,
+            code,
+            unicode: None,
+            is_press: false,
+        })),
+        EventType::ButtonPress(_) => Some(InputTrackerEvent::Click),
+        _ => None,
+    }
+}
+
+/// Key code to look the key up with in the active layout: the X keycode on
+/// Linux (where rdev's platform code is the keysym), the virtual key code
+/// elsewhere.
+fn platform_key_code(event: &Event) -> u32 {
+    if cfg!(target_os = "linux") {
+        event.position_code
+    } else {
+        event.platform_code
+    }
+}
+
+/// Read the text about to be pasted. None when the clipboard can't be read
+/// (e.g. no permission on this platform), holds no text, or is too large.
+fn read_pasted_text(app_handle: &AppHandle) -> Option<String> {
+    use tauri_plugin_clipboard_manager::ClipboardExt;
+
+    match app_handle.clipboard().read_text() {
+        Ok(text) if !text.is_empty() && text.chars().count() <= MAX_CAPTURED_PASTE_CHARS => {
+            Some(text)
+        }
+        Ok(_) => None,
+        Err(e) => {
+            log::debug!("[InputTracker] Clipboard not readable on paste: {}", e);
+            None
+        }
+    }
+}
+
 /// Start watching for app changes using polling with reduced interval
 /// On macOS, we use a shorter interval since native APIs are fast
 /// On other platforms, we use polling as the primary mechanism
@@ -627,3 +637,24 @@ impl Drop for InputTrackerManager {
         self.stop();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::get_default_settings;
+
+    #[test]
+    fn tracker_settings_follow_privacy_mode_and_disabled_timeout() {
+        let mut settings = get_default_settings();
+        settings.input_tracking_enabled = true;
+        settings.input_tracking_idle_timeout = None;
+        let tracker = TrackerSettings::from_settings(&settings);
+        assert!(tracker.enabled);
+        assert_eq!(tracker.idle_timeout_secs, 0);
+
+        settings.privacy_mode = true;
+        let paused = TrackerSettings::from_settings(&settings);
+        assert!(!paused.enabled);
+        assert_ne!(paused, tracker);
+    }
+}
@@ -1,6 +1,6 @@
 //! Linux-specific application detection using xdotool and GNOME Shell DBus.
 
-use super::ActiveAppInfo;
+use super::{ActiveAppInfo, WindowContext};
 use std::process::Command;
 
 /// Get information about the currently active window on Linux
@@ -181,3 +181,49 @@ fn get_active_app_info_x11() -> ActiveAppInfo {
         }
     }
 }
+
+/// Get the title of the focused window via GNOME Shell on Wayland or xdotool on X11.
+pub fn get_active_window_context() -> WindowContext {
+    let is_wayland = std::env::var("XDG_SESSION_TYPE")
+        .map(|s| s.to_lowercase() == "wayland")
+        .unwrap_or(false);
+
+    let title = if is_wayland {
+        Command::new("gdbus")
+            .args([
+                "call",
+                "--session",
+                "--dest",
+                "org.gnome.Shell",
+                "--object-path",
+                "/org/gnome/Shell",
+                "--method",
+                "org.gnome.Shell.Eval",
+                r#"
+                const fw = global.display.get_focus_window();
+                JSON.stringify({ title: fw ? fw.get_title() : '' });
+                "#,
+            ])
+            .output()
+            .ok()
+            .filter(|o| o.status.success())
+            .and_then(|o| {
+                let result = String::from_utf8_lossy(&o.stdout).to_string();
+                let start = result.find('{')?;
+                let end = result.rfind('}')?;
+                extract_json_string(&result[start..=end], "title")
+            })
+    } else {
+        Command::new("xdotool")
+            .args(["getactivewindow", "getwindowname"])
+            .output()
+            .ok()
+            .filter(|o| o.status.success())
+            .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string())
+    };
+
+    WindowContext {
+        title: title.filter(|t| !t.is_empty()),
+        url: None,
+    }
+}
@@ -1,6 +1,6 @@
 //! macOS-specific application detection using native Cocoa and Accessibility APIs.
 
-use super::ActiveAppInfo;
+use super::{ActiveAppInfo, WindowContext};
 
 /// Get information about the currently active (frontmost) application
 /// Uses native Cocoa NSWorkspace APIs and Accessibility APIs for reliable detection
@@ -183,3 +183,114 @@ fn get_focused_app_via_accessibility() -> Option<ActiveAppInfo> {
         None
     }
 }
+
+/// Get the focused window's title and document URL via the Accessibility API.
+/// Browsers such as Safari expose the page URL as the window's `AXDocument`.
+pub fn get_active_window_context() -> WindowContext {
+    use std::ffi::c_void;
+    use std::ptr;
+
+    type CFTypeRef = *const c_void;
+    type CFStringRef = *const c_void;
+
+    #[allow(non_upper_case_globals)]
+    const kAXErrorSuccess: i32 = 0;
+    #[allow(non_upper_case_globals)]
+    const kCFStringEncodingUTF8: u32 = 0x08000100;
+
+    #[link(name = "ApplicationServices", kind = "framework")]
+    extern "C" {
+        fn AXUIElementCreateSystemWide() -> CFTypeRef;
+        fn AXUIElementCopyAttributeValue(
+            element: CFTypeRef,
+            attribute: CFStringRef,
+            value: *mut CFTypeRef,
+        ) -> i32;
+    }
+
+    #[link(name = "CoreFoundation", kind = "framework")]
+    extern "C" {
+        fn CFRelease(cf: CFTypeRef);
+        fn CFGetTypeID(cf: CFTypeRef) -> usize;
+        fn CFStringGetTypeID() -> usize;
+        fn CFStringGetLength(string: CFStringRef) -> isize;
+        fn CFStringGetMaximumSizeForEncoding(length: isize, encoding: u32) -> isize;
+        fn CFStringGetCString(
+            string: CFStringRef,
+            buffer: *mut i8,
+            buffer_size: isize,
+            encoding: u32,
+        ) -> bool;
+        fn CFStringCreateWithCString(
+            alloc: CFTypeRef,
+            c_str: *const i8,
+            encoding: u32,
+        ) -> CFStringRef;
+    }
+
+    unsafe fn copy_attribute(element: CFTypeRef, name: &[u8]) -> Option<CFTypeRef> {
+        let attr = CFStringCreateWithCString(
+            ptr::null(),
+            name.as_ptr() as *const i8,
+            kCFStringEncodingUTF8,
+        );
+        if attr.is_null() {
+            return None;
+        }
+        let mut value: CFTypeRef = ptr::null();
+        let result = AXUIElementCopyAttributeValue(element, attr, &mut value);
+        CFRelease(attr);
+        if result != kAXErrorSuccess || value.is_null() {
+            None
+        } else {
+            Some(value)
+        }
+    }
+
+    unsafe fn copy_string_attribute(element: CFTypeRef, name: &[u8]) -> Option<String> {
+        let value = copy_attribute(element, name)?;
+        let text = if CFGetTypeID(value) == CFStringGetTypeID() {
+            let length = CFStringGetLength(value);
+            let capacity = CFStringGetMaximumSizeForEncoding(length, kCFStringEncodingUTF8) + 1;
+            let mut buffer = vec![0i8; capacity as usize];
+            if CFStringGetCString(value, buffer.as_mut_ptr(), capacity, kCFStringEncodingUTF8) {
+                Some(
+                    std::ffi::CStr::from_ptr(buffer.as_ptr())
+                        .to_string_lossy()
+                        .into_owned(),
+                )
+            } else {
+                None
+            }
+        } else {
+            None
+        };
+        CFRelease(value);
+        text.filter(|t| !t.is_empty())
+    }
+
+    unsafe {
+        let system_wide = AXUIElementCreateSystemWide();
+        if system_wide.is_null() {
+            return WindowContext::default();
+        }
+        let app = copy_attribute(system_wide, b"AXFocusedApplication\0");
+        CFRelease(system_wide);
+        let Some(app) = app else {
+            return WindowContext::default();
+        };
+
+        let window = copy_attribute(app, b"AXFocusedWindow\0");
+        CFRelease(app);
+        let Some(window) = window else {
+            return WindowContext::default();
+        };
+
+        let context = WindowContext {
+            title: copy_string_attribute(window, b"AXTitle\0"),
+            url: copy_string_attribute(window, b"AXDocument\0"),
+        };
+        CFRelease(window);
+        context
+    }
+}
@@ -3,7 +3,7 @@
 //! This module provides a unified interface for detecting the currently
 //! active application across macOS, Windows, and Linux.
 
-use super::types::ActiveAppInfo;
+use super::types::{ActiveAppInfo, WindowContext};
 
 #[cfg(target_os = "macos")]
 mod macos;
@@ -37,3 +37,24 @@ pub fn get_active_app_info_fast() -> ActiveAppInfo {
     log::debug!("[InputTracker] Active app detection not supported on this platform");
     ActiveAppInfo::default()
 }
+
+/// Get the title (and URL, where available) of the focused window.
+#[cfg(target_os = "macos")]
+pub fn get_active_window_context() -> WindowContext {
+    macos::get_active_window_context()
+}
+
+#[cfg(target_os = "windows")]
+pub fn get_active_window_context() -> WindowContext {
+    windows::get_active_window_context()
+}
+
+#[cfg(target_os = "linux")]
+pub fn get_active_window_context() -> WindowContext {
+    linux::get_active_window_context()
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
+pub fn get_active_window_context() -> WindowContext {
+    WindowContext::default()
+}
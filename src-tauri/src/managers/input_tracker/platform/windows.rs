@@ -1,6 +1,6 @@
 //! Windows-specific application detection using native Win32 APIs.
 
-use super::ActiveAppInfo;
+use super::{ActiveAppInfo, WindowContext};
 
 /// Get information about the currently active window on Windows
 /// Uses native Windows APIs for fast and reliable detection
@@ -87,3 +87,25 @@ pub fn get_active_app_info() -> ActiveAppInfo {
         }
     }
 }
+
+/// Get the title of the foreground window. Windows exposes no URL here.
+pub fn get_active_window_context() -> WindowContext {
+    use windows::Win32::UI::WindowsAndMessaging::{GetForegroundWindow, GetWindowTextW};
+
+    unsafe {
+        let hwnd = GetForegroundWindow();
+        if hwnd.0.is_null() {
+            return WindowContext::default();
+        }
+
+        let mut buffer = [0u16; 512];
+        let len = GetWindowTextW(hwnd, &mut buffer);
+        let title = if len > 0 {
+            Some(String::from_utf16_lossy(&buffer[..len as usize]))
+        } else {
+            None
+        };
+
+        WindowContext { title, url: None }
+    }
+}
@@ -0,0 +1,378 @@
+//! Event processing: turns the tracker's event stream into buffer edits and
+//! saved entries.
+//!
+//! Everything the processor needs from the outside world (app detection, the
+//! clipboard, exclusion rules, persistence) goes through [`Environment`], so
+//! the same code runs live and under the simulated input in tests.
+
+use super::state::{InputState, ModifierState};
+use super::status::TrackingStatus;
+use super::types::{ActiveAppInfo, InputEntry, InputTrackerEvent, WindowContext};
+use crate::keyboard_layout;
+use rdev::Key;
+use std::time::Duration;
+
+/// The processor's view of the system.
+pub(super) trait Environment {
+    /// Currently focused application
+    fn active_app(&self) -> ActiveAppInfo;
+    /// Title and URL of the focused window
+    fn window_context(&self) -> WindowContext;
+    fn is_app_excluded(&self, app: &ActiveAppInfo) -> bool;
+    fn is_window_excluded(&self, window: &WindowContext) -> bool;
+    /// Idle timeout in seconds, 0 when disabled
+    fn idle_timeout_secs(&self) -> u64;
+    /// Text being pasted, or None when paste capture is off or the clipboard
+    /// can't be read
+    fn pasted_text(&self) -> Option<String>;
+    /// Re-read the keyboard layout after an app change
+    fn refresh_layout(&self);
+    fn save(&mut self, entry: &InputEntry);
+    fn report_status(&mut self, status: TrackingStatus);
+}
+
+pub(super) struct Processor<E: Environment> {
+    env: E,
+    state: InputState,
+    current_app: ActiveAppInfo,
+    current_window: WindowContext,
+}
+
+impl<E: Environment> Processor<E> {
+    pub fn new(env: E) -> Self {
+        let mut state = InputState::default();
+        let current_app = env.active_app();
+        let current_window = env.window_context();
+        state.set_current_app(current_app.clone());
+        log::info!(
+            "[InputTracker] Initial app: '{}' ({:?})",
+            current_app.name,
+            current_app.bundle_id
+        );
+        Self {
+            env,
+            state,
+            current_app,
+            current_window,
+        }
+    }
+
+    /// App and window exclusions are evaluated together
+    fn is_excluded(&self, app: &ActiveAppInfo, window: &WindowContext) -> bool {
+        self.env.is_app_excluded(app) || self.env.is_window_excluded(window)
+    }
+
+    /// Handle one event. Returns false once the processor should stop.
+    pub fn handle(&mut self, event: InputTrackerEvent) -> bool {
+        match event {
+            InputTrackerEvent::AppChanged(new_app) => {
+                if new_app != self.current_app && !self.current_app.name.is_empty() {
+                    log::info!(
+                        "[InputTracker] App changed: {} -> {}",
+                        self.current_app.name,
+                        new_app.name
+                    );
+
+                    // Check if previous app was excluded
+                    let prev_excluded = self.is_excluded(&self.current_app, &self.current_window);
+
+                    if !prev_excluded {
+                        if let Some(entry) = self.state.take_entry() {
+                            self.env.save(&entry);
+                        }
+                    } else {
+                        self.state.clear();
+                    }
+
+                    self.state.set_current_app(new_app.clone());
+                    self.current_app = new_app;
+                    // Each app can use its own input source
+                    self.env.refresh_layout();
+                    // Refresh right away so typing in an excluded window isn't
+                    // recorded before the window watcher catches up
+                    self.current_window = self.env.window_context();
+                } else if self.current_app.name.is_empty() {
+                    self.current_app = new_app.clone();
+                    self.state.set_current_app(new_app);
+                }
+            }
+            InputTrackerEvent::WindowChanged(new_window) => {
+                if new_window == self.current_window {
+                    return true;
+                }
+
+                let was_excluded = self.is_excluded(&self.current_app, &self.current_window);
+                let now_excluded = self.is_excluded(&self.current_app, &new_window);
+                if !was_excluded && now_excluded {
+                    if let Some(entry) = self.state.take_entry() {
+                        self.env.save(&entry);
+                    }
+                } else if was_excluded && !now_excluded {
+                    self.state.clear();
+                }
+                self.current_window = new_window;
+            }
+            InputTrackerEvent::Keystroke(keystroke) => {
+                // Check if current app is excluded
+                let excluded = self.is_excluded(&self.current_app, &self.current_window);
+
+                if excluded {
+                    return true;
+                }
+
+                if keystroke.is_press {
+                    self.state.modifiers.update(keystroke.key, true);
+
+                    if ModifierState::is_modifier_key(keystroke.key) {
+                        return true;
+                    }
+                    let shortcut_key = keystroke.shortcut_key();
+
+                    // Paste: merge the clipboard text where we can read it,
+                    // otherwise fall through and treat it like other clipboard ops
+                    let is_paste = (self.state.modifiers.meta || self.state.modifiers.ctrl)
+                        && matches!(shortcut_key, Key::KeyV);
+                    if is_paste {
+                        if let Some(text) = self.env.pasted_text() {
+                            log::debug!(
+                                "[InputTracker] Merging {} pasted chars",
+                                text.chars().count()
+                            );
+                            self.state.insert_text(&text);
+                            return true;
+                        }
+                    }
+
+                    // --- Buffer-invalidating operations ---
+                    // These operations make our cursor tracking unreliable, so save and clear
+
+                    // Selection (Shift + Arrow keys) invalidates cursor tracking
+                    let is_selection = self.state.modifiers.shift
+                        && matches!(
+                            keystroke.key,
+                            Key::LeftArrow
+                                | Key::RightArrow
+                                | Key::UpArrow
+                                | Key::DownArrow
+                                | Key::Home
+                                | Key::End
+                        );
+
+                    // Clipboard operations (Cmd/Ctrl + C/X/V/A)
+                    let is_clipboard_op = (self.state.modifiers.meta || self.state.modifiers.ctrl)
+                        && matches!(shortcut_key, Key::KeyC | Key::KeyX | Key::KeyV | Key::KeyA);
+
+                    // Undo/Redo (Cmd/Ctrl + Z, Cmd/Ctrl + Shift + Z, Cmd/Ctrl + Y)
+                    let is_undo_redo = (self.state.modifiers.meta || self.state.modifiers.ctrl)
+                        && matches!(shortcut_key, Key::KeyZ | Key::KeyY);
+
+                    // Vertical navigation (Up/Down arrows without word modifier)
+                    // These move between lines which we can't track
+                    let is_vertical_nav = !self.state.modifiers.shift
+                        && matches!(keystroke.key, Key::UpArrow | Key::DownArrow);
+
+                    // Large jumps (PageUp/PageDown, Cmd+Up/Down for document start/end)
+                    let is_large_jump = matches!(keystroke.key, Key::PageUp | Key::PageDown)
+                        || (self.state.modifiers.is_line_modifier()
+                            && matches!(keystroke.key, Key::UpArrow | Key::DownArrow));
+
+                    // Submit keys (Return, Tab, Escape)
+                    let is_submit_key =
+                        matches!(keystroke.key, Key::Return | Key::Tab | Key::Escape);
+
+                    // Save shortcut (Cmd/Ctrl + S)
+                    let is_save_shortcut = (self.state.modifiers.meta || self.state.modifiers.ctrl)
+                        && matches!(shortcut_key, Key::KeyS);
+
+                    if is_selection || is_clipboard_op || is_undo_redo || is_large_jump {
+                        // Save buffer and clear - these operations invalidate our tracking
+                        if let Some(entry) = self.state.take_entry() {
+                            log::info!(
+                                "[InputTracker] Buffer-invalidating op {:?}, saving: '{}'",
+                                keystroke.key,
+                                entry.content
+                            );
+                            self.env.save(&entry);
+                        }
+                    } else if is_vertical_nav {
+                        // Vertical navigation without modifiers - save and clear
+                        if let Some(entry) = self.state.take_entry() {
+                            log::info!(
+                                "[InputTracker] Vertical nav {:?}, saving: '{}'",
+                                keystroke.key,
+                                entry.content
+                            );
+                            self.env.save(&entry);
+                        }
+                    } else if is_submit_key || is_save_shortcut {
+                        if let Some(entry) = self.state.take_entry() {
+                            let reason = if is_save_shortcut {
+                                "save shortcut"
+                            } else {
+                                "submit key"
+                            };
+                            log::info!(
+                                "[InputTracker] {} {:?}, saving: '{}'",
+                                reason,
+                                keystroke.key,
+                                entry.content
+                            );
+                            self.env.save(&entry);
+                        }
+                    }
+                    // --- Cursor movement operations ---
+                    else if matches!(keystroke.key, Key::LeftArrow) {
+                        if self.state.modifiers.is_line_modifier() {
+                            // Cmd+Left on macOS: move to start of line
+                            self.state.move_cursor_to_start();
+                        } else if self.state.modifiers.is_word_modifier() {
+                            // Option+Left on macOS, Ctrl+Left on Win/Linux: word jump
+                            self.state.move_cursor_word_left();
+                        } else {
+                            // Plain left arrow: single character
+                            self.state.move_cursor_left();
+                        }
+                    } else if matches!(keystroke.key, Key::RightArrow) {
+                        if self.state.modifiers.is_line_modifier() {
+                            // Cmd+Right on macOS: move to end of line
+                            self.state.move_cursor_to_end();
+                        } else if self.state.modifiers.is_word_modifier() {
+                            // Option+Right on macOS, Ctrl+Right on Win/Linux: word jump
+                            self.state.move_cursor_word_right();
+                        } else {
+                            // Plain right arrow: single character
+                            self.state.move_cursor_right();
+                        }
+                    } else if matches!(keystroke.key, Key::Home) {
+                        self.state.move_cursor_to_start();
+                    } else if matches!(keystroke.key, Key::End) {
+                        self.state.move_cursor_to_end();
+                    }
+                    // --- Deletion operations ---
+                    else if matches!(keystroke.key, Key::Backspace) {
+                        if self.state.modifiers.meta || self.state.modifiers.ctrl {
+                            // Cmd/Ctrl+Backspace: delete word or line - clear buffer
+                            self.state.clear();
+                        } else if self.state.modifiers.is_word_modifier() {
+                            // Option+Backspace on macOS: delete word
+                            // We can't accurately track this, so clear
+                            self.state.clear();
+                        } else {
+                            self.state.handle_backspace();
+                        }
+                    } else if matches!(keystroke.key, Key::Delete) {
+                        if self.state.modifiers.meta
+                            || self.state.modifiers.ctrl
+                            || self.state.modifiers.is_word_modifier()
+                        {
+                            // Modified delete: clear buffer
+                            self.state.clear();
+                        } else {
+                            self.state.handle_delete();
+                        }
+                    }
+                    // --- Character input ---
+                    else if self.state.modifiers.is_text_input() {
+                        // rdev's text is missing for some keys and layouts;
+                        // ask the layout what this key types instead
+                        let text = keystroke.unicode.clone().or_else(|| {
+                            keyboard_layout::char_for_key(
+                                keystroke.code,
+                                keyboard_layout::Level {
+                                    shift: self.state.modifiers.shift,
+                                    alt_gr: self.state.modifiers.alt_gr
+                                        || (self.state.modifiers.ctrl && self.state.modifiers.alt),
+                                },
+                            )
+                            .map(String::from)
+                        });
+                        if let Some(text) = text {
+                            for c in text.chars() {
+                                if keyboard_layout::is_text_char(c) {
+                                    self.state.append_char(c);
+                                }
+                            }
+                        }
+                    } else {
+                        // Some other modifier combination - just update last keystroke
+                        self.state.last_keystroke = Some(std::time::Instant::now());
+                    }
+                } else {
+                    self.state.modifiers.update(keystroke.key, false);
+                }
+            }
+            InputTrackerEvent::Click => {
+                let excluded = self.is_excluded(&self.current_app, &self.current_window);
+
+                if !excluded {
+                    if let Some(entry) = self.state.take_entry() {
+                        log::info!("[InputTracker] Click, saving: '{}'", entry.content);
+                        self.env.save(&entry);
+                    }
+                }
+
+                // On click, also check if app changed (handles Dock clicks, etc.)
+                let new_app = self.env.active_app();
+                if new_app != self.current_app {
+                    log::info!(
+                        "[InputTracker] App changed on click: {} -> {}",
+                        self.current_app.name,
+                        new_app.name
+                    );
+                    self.state.set_current_app(new_app.clone());
+                    self.current_app = new_app;
+                    self.current_window = self.env.window_context();
+                }
+            }
+            InputTrackerEvent::IdleCheck => {
+                self.env.report_status(TrackingStatus::new(
+                    &self.current_app.name,
+                    self.env.is_app_excluded(&self.current_app),
+                    self.env.is_window_excluded(&self.current_window),
+                ));
+
+                let timeout_secs = self.env.idle_timeout_secs();
+                if timeout_secs > 0 {
+                    let excluded = self.is_excluded(&self.current_app, &self.current_window);
+
+                    if !excluded
+                        && self.state.is_idle(Duration::from_secs(timeout_secs))
+                        && self.state.has_content()
+                    {
+                        if let Some(entry) = self.state.take_entry() {
+                            log::info!("[InputTracker] Idle timeout, saving: '{}'", entry.content);
+                            self.env.save(&entry);
+                        }
+                    }
+                }
+            }
+            InputTrackerEvent::Shutdown(ack) => {
+                log::info!("[InputTracker] Received shutdown signal");
+                // Save any remaining content
+                if let Some(entry) = self.state.take_entry() {
+                    log::info!(
+                        "[InputTracker] Saving remaining on shutdown: '{}'",
+                        entry.content
+                    );
+                    self.env.save(&entry);
+                }
+                self.env.report_status(TrackingStatus::stopped());
+                if let Some(ack) = ack {
+                    let _ = ack.send(());
+                }
+                return false;
+            }
+        }
+        true
+    }
+
+    #[cfg(test)]
+    pub fn env_mut(&mut self) -> &mut E {
+        &mut self.env
+    }
+
+    #[cfg(test)]
+    pub fn state(&self) -> &InputState {
+        &self.state
+    }
+}
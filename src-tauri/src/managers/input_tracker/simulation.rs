@@ -0,0 +1,351 @@
+//! Simulated input for end-to-end tests of the tracker.
+//!
+//! Scripted rdev events go through the same conversion as the live listener
+//! and into the real [`Processor`]; app detection, the clipboard and the
+//! database are replaced by [`SimulatedEnvironment`], which records what
+//! would have been saved. Key codes are 0, which no layout maps, so results
+//! don't depend on the keyboard layout of the machine running the tests.
+
+use super::processor::{Environment, Processor};
+use super::status::{TrackingState, TrackingStatus};
+use super::to_tracker_event;
+use super::types::{ActiveAppInfo, InputEntry, InputTrackerEvent, WindowContext};
+use rdev::{Button, EventType, Key, UnicodeInfo};
+
+#[derive(Default)]
+pub struct SimulatedEnvironment {
+    pub app: ActiveAppInfo,
+    pub window: WindowContext,
+    pub excluded_apps: Vec<String>,
+    pub excluded_window_titles: Vec<String>,
+    /// Clipboard text returned on paste; None behaves like capture being off
+    pub clipboard: Option<String>,
+    pub saved: Vec<InputEntry>,
+    pub statuses: Vec<TrackingStatus>,
+}
+
+impl Environment for SimulatedEnvironment {
+    fn active_app(&self) -> ActiveAppInfo {
+        self.app.clone()
+    }
+
+    fn window_context(&self) -> WindowContext {
+        self.window.clone()
+    }
+
+    fn is_app_excluded(&self, app: &ActiveAppInfo) -> bool {
+        self.excluded_apps.contains(&app.name)
+    }
+
+    fn is_window_excluded(&self, window: &WindowContext) -> bool {
+        window
+            .title
+            .as_ref()
+            .is_some_and(|title| self.excluded_window_titles.contains(title))
+    }
+
+    fn idle_timeout_secs(&self) -> u64 {
+        0
+    }
+
+    fn pasted_text(&self) -> Option<String> {
+        self.clipboard.clone()
+    }
+
+    fn refresh_layout(&self) {}
+
+    fn save(&mut self, entry: &InputEntry) {
+        self.saved.push(entry.clone());
+    }
+
+    fn report_status(&mut self, status: TrackingStatus) {
+        self.statuses.push(status);
+    }
+}
+
+/// A processor driven by scripted input.
+pub struct Simulation {
+    processor: Processor<SimulatedEnvironment>,
+    stopped: bool,
+}
+
+impl Simulation {
+    /// Start tracking with `app_name` focused.
+    pub fn new(app_name: &str) -> Self {
+        Self {
+            processor: Processor::new(SimulatedEnvironment {
+                app: app(app_name),
+                ..Default::default()
+            }),
+            stopped: false,
+        }
+    }
+
+    pub fn env(&mut self) -> &mut SimulatedEnvironment {
+        self.processor.env_mut()
+    }
+
+    /// Inject a tracker event directly.
+    pub fn event(&mut self, event: InputTrackerEvent) -> &mut Self {
+        assert!(!self.stopped, "event after shutdown");
+        self.stopped = !self.processor.handle(event);
+        self
+    }
+
+    /// Inject a raw rdev event, with the text rdev would attach to it.
+    pub fn rdev(&mut self, event_type: EventType, text: Option<&str>) -> &mut Self {
+        let unicode = text.map(|text| UnicodeInfo {
+            name: Some(text.to_string()),
+            unicode: text.encode_utf16().collect(),
+            is_dead: false,
+        });
+        if let Some(event) = to_tracker_event(event_type, unicode, 0) {
+            self.event(event);
+        }
+        self
+    }
+
+    /// Press and release a key that types `text`.
+    pub fn key_with_text(&mut self, key: Key, text: &str) -> &mut Self {
+        self.rdev(EventType::KeyPress(key), Some(text))
+            .rdev(EventType::KeyRelease(key), None)
+    }
+
+    /// Type each character of `text` with its own key press.
+    pub fn type_text(&mut self, text: &str) -> &mut Self {
+        for c in text.chars() {
+            let key = if c == ' ' {
+                Key::Space
+            } else {
+                Key::Unknown(0)
+            };
+            self.key_with_text(key, &c.to_string());
+        }
+        self
+    }
+
+    /// Press and release a key that types nothing.
+    pub fn tap(&mut self, key: Key) -> &mut Self {
+        self.rdev(EventType::KeyPress(key), None)
+            .rdev(EventType::KeyRelease(key), None)
+    }
+
+    /// Tap `key` while holding `modifiers`.
+    pub fn chord(&mut self, modifiers: &[Key], key: Key) -> &mut Self {
+        for &modifier in modifiers {
+            self.rdev(EventType::KeyPress(modifier), None);
+        }
+        self.tap(key);
+        for &modifier in modifiers.iter().rev() {
+            self.rdev(EventType::KeyRelease(modifier), None);
+        }
+        self
+    }
+
+    pub fn click(&mut self) -> &mut Self {
+        self.rdev(EventType::ButtonPress(Button::Left), None)
+    }
+
+    pub fn switch_app(&mut self, app_name: &str) -> &mut Self {
+        self.env().app = app(app_name);
+        self.event(InputTrackerEvent::AppChanged(app(app_name)))
+    }
+
+    pub fn switch_window(&mut self, title: &str) -> &mut Self {
+        let window = WindowContext {
+            title: Some(title.to_string()),
+            url: None,
+        };
+        self.env().window = window.clone();
+        self.event(InputTrackerEvent::WindowChanged(window))
+    }
+
+    pub fn shutdown(&mut self) -> &mut Self {
+        self.event(InputTrackerEvent::Shutdown(None))
+    }
+
+    /// The buffer with `|` at the cursor.
+    pub fn buffer(&self) -> String {
+        self.processor.state().render()
+    }
+
+    /// Saved entries as `app: content`.
+    pub fn saved(&mut self) -> Vec<String> {
+        self.env()
+            .saved
+            .iter()
+            .map(|entry| format!("{}: {}", entry.app_name, entry.content))
+            .collect()
+    }
+}
+
+fn app(name: &str) -> ActiveAppInfo {
+    ActiveAppInfo {
+        name: name.to_string(),
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The modifier that edits by word on this platform.
+    #[cfg(target_os = "macos")]
+    const WORD: Key = Key::Alt;
+    #[cfg(not(target_os = "macos"))]
+    const WORD: Key = Key::ControlLeft;
+
+    /// The modifier for clipboard shortcuts on this platform.
+    #[cfg(target_os = "macos")]
+    const COMMAND: Key = Key::MetaLeft;
+    #[cfg(not(target_os = "macos"))]
+    const COMMAND: Key = Key::ControlLeft;
+
+    #[test]
+    fn typing_fills_the_buffer() {
+        let mut sim = Simulation::new("Notes");
+        sim.type_text("hello world");
+        assert_eq!(sim.buffer(), "hello world|");
+        assert!(sim.saved().is_empty());
+    }
+
+    #[test]
+    fn cursor_edits() {
+        let mut sim = Simulation::new("Notes");
+        sim.type_text("helo")
+            .tap(Key::LeftArrow)
+            .type_text("l")
+            .tap(Key::End)
+            .type_text("!");
+        assert_eq!(sim.buffer(), "hello!|");
+
+        sim.tap(Key::Home).tap(Key::Delete).type_text("H");
+        assert_eq!(sim.buffer(), "H|ello!");
+
+        sim.tap(Key::RightArrow).tap(Key::Backspace);
+        assert_eq!(sim.buffer(), "H|llo!");
+    }
+
+    #[test]
+    fn word_moves() {
+        let mut sim = Simulation::new("Notes");
+        sim.type_text("one two three")
+            .chord(&[WORD], Key::LeftArrow)
+            .chord(&[WORD], Key::LeftArrow);
+        assert_eq!(sim.buffer(), "one |two three");
+
+        sim.chord(&[WORD], Key::RightArrow);
+        assert_eq!(sim.buffer(), "one two |three");
+    }
+
+    #[test]
+    fn non_ascii_text() {
+        let mut sim = Simulation::new("Notes");
+        sim.type_text("Grüße, ça va")
+            .chord(&[WORD], Key::LeftArrow)
+            .tap(Key::Backspace)
+            .type_text("—");
+        assert_eq!(sim.buffer(), "Grüße, ça—|va");
+    }
+
+    #[test]
+    fn shifted_text_is_typed_but_shortcuts_are_not() {
+        let mut sim = Simulation::new("Notes");
+        sim.rdev(EventType::KeyPress(Key::ShiftLeft), None)
+            .key_with_text(Key::KeyA, "A")
+            .rdev(EventType::KeyRelease(Key::ShiftLeft), None)
+            .type_text("bc")
+            .rdev(EventType::KeyPress(Key::MetaLeft), None)
+            .key_with_text(Key::KeyD, "d")
+            .rdev(EventType::KeyRelease(Key::MetaLeft), None);
+        assert_eq!(sim.buffer(), "Abc|");
+    }
+
+    #[test]
+    fn submit_keys_save_the_entry() {
+        let mut sim = Simulation::new("Chat");
+        sim.type_text("hi").tap(Key::Return);
+        // Too short to keep
+        assert!(sim.saved().is_empty());
+
+        sim.type_text("see you soon").tap(Key::Return);
+        assert_eq!(sim.saved(), ["Chat: see you soon"]);
+        assert_eq!(sim.buffer(), "|");
+    }
+
+    #[test]
+    fn selection_and_clipboard_ops_save_and_reset() {
+        let mut sim = Simulation::new("Editor");
+        sim.type_text("first draft")
+            .chord(&[Key::ShiftLeft], Key::LeftArrow)
+            .type_text("second try")
+            .chord(&[COMMAND], Key::KeyZ)
+            .type_text("third");
+        assert_eq!(sim.saved(), ["Editor: first draft", "Editor: second try"]);
+        assert_eq!(sim.buffer(), "third|");
+    }
+
+    #[test]
+    fn paste_merges_clipboard_text() {
+        let mut sim = Simulation::new("Editor");
+        sim.env().clipboard = Some("pasted ".to_string());
+        sim.type_text("a b")
+            .tap(Key::LeftArrow)
+            .chord(&[COMMAND], Key::KeyV);
+        assert_eq!(sim.buffer(), "a pasted |b");
+
+        // Unreadable clipboard: treated like any clipboard operation
+        sim.env().clipboard = None;
+        sim.chord(&[COMMAND], Key::KeyV);
+        assert_eq!(sim.saved(), ["Editor: a pasted b"]);
+        assert_eq!(sim.buffer(), "|");
+    }
+
+    #[test]
+    fn app_switch_and_click_save_per_app() {
+        let mut sim = Simulation::new("Mail");
+        sim.type_text("dear team").switch_app("Chat");
+        sim.type_text("lunch?").click().shutdown();
+        assert_eq!(sim.saved(), ["Mail: dear team", "Chat: lunch?"]);
+    }
+
+    #[test]
+    fn excluded_apps_and_windows_are_not_recorded() {
+        let mut sim = Simulation::new("Notes");
+        sim.env().excluded_apps = vec!["Passwords".to_string()];
+        sim.env().excluded_window_titles = vec!["Private".to_string()];
+
+        sim.switch_app("Passwords")
+            .type_text("hunter2")
+            .switch_app("Notes");
+        sim.type_text("public notes")
+            .switch_window("Private")
+            .type_text("secret stuff")
+            .event(InputTrackerEvent::IdleCheck);
+        assert_eq!(
+            sim.env().statuses.last().map(|status| status.state),
+            Some(TrackingState::ExcludedWindow)
+        );
+
+        sim.switch_window("Public")
+            .type_text("more notes")
+            .shutdown();
+        assert_eq!(sim.saved(), ["Notes: public notes", "Notes: more notes"]);
+    }
+
+    #[test]
+    fn dead_keys_type_nothing_until_composed() {
+        let mut sim = Simulation::new("Notes");
+        let dead = UnicodeInfo {
+            name: Some("^".to_string()),
+            unicode: vec!['^' as u16],
+            is_dead: true,
+        };
+        if let Some(event) = to_tracker_event(EventType::KeyPress(Key::Unknown(0)), Some(dead), 0) {
+            sim.event(event);
+        }
+        sim.type_text("ê");
+        assert_eq!(sim.buffer(), "ê|");
+    }
+}
@@ -13,6 +13,7 @@ pub struct ModifierState {
     pub shift: bool,
     pub ctrl: bool,
     pub alt: bool,
+    pub alt_gr: bool,
     pub meta: bool, // Cmd on macOS, Win on Windows
 }
 
@@ -22,6 +23,19 @@ impl ModifierState {
         self.ctrl || self.alt || self.meta
     }
 
+    /// Check if a key pressed now types text. Option on macOS and AltGr
+    /// elsewhere select a layout's extra characters (e.g. `@` on AZERTY);
+    /// Windows reports AltGr as Ctrl+Alt.
+    #[cfg(target_os = "macos")]
+    pub fn is_text_input(&self) -> bool {
+        !self.ctrl && !self.meta
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    pub fn is_text_input(&self) -> bool {
+        !self.meta && (self.alt_gr || (self.ctrl == self.alt))
+    }
+
     /// Check if the word-jump modifier is pressed
     /// On macOS: Option (alt) key
     /// On Windows/Linux: Ctrl key
@@ -53,7 +67,8 @@ impl ModifierState {
         match key {
             Key::ShiftLeft | Key::ShiftRight => self.shift = pressed,
             Key::ControlLeft | Key::ControlRight => self.ctrl = pressed,
-            Key::Alt | Key::AltGr => self.alt = pressed,
+            Key::Alt => self.alt = pressed,
+            Key::AltGr => self.alt_gr = pressed,
             Key::MetaLeft | Key::MetaRight => self.meta = pressed,
             _ => {}
         }
@@ -78,7 +93,7 @@ impl ModifierState {
 /// Internal state for tracking typed input
 pub struct InputState {
     buffer: String,
-    /// Cursor position within the buffer (0 = start, buffer.len() = end)
+    /// Cursor position in chars within the buffer (0 = start, char count = end)
     cursor_position: usize,
     pub last_keystroke: Option<Instant>,
     session_start: Option<Instant>,
@@ -106,11 +121,8 @@ impl InputState {
             self.session_start = Some(Instant::now());
         }
         // Insert at cursor position instead of appending
-        if self.cursor_position >= self.buffer.len() {
-            self.buffer.push(c);
-        } else {
-            self.buffer.insert(self.cursor_position, c);
-        }
+        let index = self.byte_index(self.cursor_position);
+        self.buffer.insert(index, c);
         self.cursor_position += 1;
         self.last_keystroke = Some(Instant::now());
         log::debug!(
@@ -121,23 +133,32 @@ impl InputState {
         );
     }
 
+    /// Insert pasted text at the current cursor position
+    pub fn insert_text(&mut self, text: &str) {
+        if self.session_start.is_none() {
+            self.session_start = Some(Instant::now());
+        }
+        let index = self.byte_index(self.cursor_position);
+        self.buffer.insert_str(index, text);
+        self.cursor_position += text.chars().count();
+        self.last_keystroke = Some(Instant::now());
+    }
+
     /// Handle backspace key press
     pub fn handle_backspace(&mut self) {
         if self.cursor_position > 0 {
             self.cursor_position -= 1;
-            if self.cursor_position < self.buffer.len() {
-                self.buffer.remove(self.cursor_position);
-            } else {
-                self.buffer.pop();
-            }
+            let index = self.byte_index(self.cursor_position);
+            self.buffer.remove(index);
         }
         self.last_keystroke = Some(Instant::now());
     }
 
     /// Handle delete key press
     pub fn handle_delete(&mut self) {
-        if self.cursor_position < self.buffer.len() {
-            self.buffer.remove(self.cursor_position);
+        if self.cursor_position < self.char_count() {
+            let index = self.byte_index(self.cursor_position);
+            self.buffer.remove(index);
         }
         self.last_keystroke = Some(Instant::now());
     }
@@ -205,7 +226,7 @@ impl InputState {
 
     /// Move cursor one position right
     pub fn move_cursor_right(&mut self) {
-        if self.cursor_position < self.buffer.len() {
+        if self.cursor_position < self.char_count() {
             self.cursor_position += 1;
         }
         self.last_keystroke = Some(Instant::now());
@@ -219,7 +240,7 @@ impl InputState {
 
     /// Move cursor to the end of the buffer
     pub fn move_cursor_to_end(&mut self) {
-        self.cursor_position = self.buffer.len();
+        self.cursor_position = self.char_count();
         self.last_keystroke = Some(Instant::now());
     }
 
@@ -273,6 +294,26 @@ impl InputState {
         self.last_keystroke = Some(Instant::now());
     }
 
+    /// The buffer with `|` marking the cursor, for tests
+    #[cfg(test)]
+    pub fn render(&self) -> String {
+        let mut rendered = self.buffer.clone();
+        rendered.insert(self.byte_index(self.cursor_position), '|');
+        rendered
+    }
+
+    fn char_count(&self) -> usize {
+        self.buffer.chars().count()
+    }
+
+    /// Byte offset of the char at `position`, or the buffer length past the end
+    fn byte_index(&self, position: usize) -> usize {
+        self.buffer
+            .char_indices()
+            .nth(position)
+            .map_or(self.buffer.len(), |(index, _)| index)
+    }
+
     /// Check if a character is a word boundary (whitespace or punctuation)
     fn is_word_boundary(c: char) -> bool {
         c.is_whitespace()
@@ -312,3 +353,52 @@ impl InputState {
             )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_text_at_cursor() {
+        let mut state = InputState::default();
+        for c in "hello world".chars() {
+            state.append_char(c);
+        }
+        state.move_cursor_word_left();
+        state.insert_text("big ");
+        assert_eq!(state.buffer, "hello big world");
+
+        state.move_cursor_to_end();
+        state.insert_text("!");
+        state.append_char('?');
+        assert_eq!(state.buffer, "hello big world!?");
+    }
+
+    #[test]
+    fn edits_non_ascii_text_by_char() {
+        let mut state = InputState::default();
+        for c in "café ñu".chars() {
+            state.append_char(c);
+        }
+        state.move_cursor_word_left();
+        state.move_cursor_left();
+        state.handle_backspace();
+        state.append_char('ê');
+        assert_eq!(state.buffer, "cafê ñu");
+
+        state.move_cursor_right();
+        state.handle_delete();
+        state.insert_text("日本");
+        state.move_cursor_right();
+        state.append_char('é');
+        assert_eq!(state.buffer, "cafê 日本ué");
+    }
+
+    #[test]
+    fn insert_text_starts_session() {
+        let mut state = InputState::default();
+        state.insert_text("pasted text");
+        assert!(state.has_content());
+        assert_eq!(state.take_entry().unwrap().content, "pasted text");
+    }
+}
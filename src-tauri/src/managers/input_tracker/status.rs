@@ -0,0 +1,136 @@
+//! Live tracking status reported to the UI and tray tooltip.
+//!
+//! The event processor emits an `input-tracking-status` event whenever the
+//! tracked app or its exclusion state changes, and re-emits it periodically so
+//! late listeners pick up the current state.
+
+use serde::Serialize;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter};
+
+/// Re-emit an unchanged status at least this often.
+const STATUS_HEARTBEAT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TrackingState {
+    Tracking,
+    ExcludedApp,
+    ExcludedWindow,
+    Stopped,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TrackingStatus {
+    pub app_name: String,
+    pub state: TrackingState,
+}
+
+impl TrackingStatus {
+    pub fn new(app_name: &str, app_excluded: bool, window_excluded: bool) -> Self {
+        let state = if app_excluded {
+            TrackingState::ExcludedApp
+        } else if window_excluded {
+            TrackingState::ExcludedWindow
+        } else {
+            TrackingState::Tracking
+        };
+        Self {
+            app_name: app_name.to_string(),
+            state,
+        }
+    }
+
+    pub fn stopped() -> Self {
+        Self {
+            app_name: String::new(),
+            state: TrackingState::Stopped,
+        }
+    }
+
+    /// Short human-readable label, e.g. "Tracking: Slack". None once stopped.
+    pub fn label(&self) -> Option<String> {
+        match self.state {
+            TrackingState::Tracking if self.app_name.is_empty() => Some("Tracking".to_string()),
+            TrackingState::Tracking => Some(format!("Tracking: {}", self.app_name)),
+            TrackingState::ExcludedApp => Some("Paused: excluded app".to_string()),
+            TrackingState::ExcludedWindow => Some("Paused: excluded window".to_string()),
+            TrackingState::Stopped => None,
+        }
+    }
+}
+
+/// Emits status events, skipping unchanged statuses until the heartbeat is due.
+#[derive(Default)]
+pub struct StatusReporter {
+    last: Option<(TrackingStatus, Instant)>,
+}
+
+impl StatusReporter {
+    fn should_emit(&self, status: &TrackingStatus, now: Instant) -> bool {
+        match &self.last {
+            Some((last, at)) => last != status || now.duration_since(*at) >= STATUS_HEARTBEAT,
+            None => true,
+        }
+    }
+
+    pub fn report(&mut self, app_handle: &AppHandle, status: TrackingStatus) {
+        let now = Instant::now();
+        if !self.should_emit(&status, now) {
+            return;
+        }
+
+        let changed = self.last.as_ref().map_or(true, |(last, _)| *last != status);
+        if changed {
+            crate::tray::set_tray_tooltip(app_handle, status.label().as_deref());
+        }
+        if let Err(e) = app_handle.emit("input-tracking-status", &status) {
+            log::warn!("[InputTracker] Failed to emit status event: {}", e);
+        }
+        self.last = Some((status, now));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn labels_reflect_state() {
+        assert_eq!(
+            TrackingStatus::new("Slack", false, false)
+                .label()
+                .as_deref(),
+            Some("Tracking: Slack")
+        );
+        assert_eq!(
+            TrackingStatus::new("1Password", true, true)
+                .label()
+                .as_deref(),
+            Some("Paused: excluded app")
+        );
+        assert_eq!(
+            TrackingStatus::new("Chrome", false, true)
+                .label()
+                .as_deref(),
+            Some("Paused: excluded window")
+        );
+        assert_eq!(TrackingStatus::stopped().label(), None);
+    }
+
+    #[test]
+    fn unchanged_status_waits_for_heartbeat() {
+        let now = Instant::now();
+        let status = TrackingStatus::new("Slack", false, false);
+        let reporter = StatusReporter {
+            last: Some((status.clone(), now)),
+        };
+
+        assert!(!reporter.should_emit(&status, now + Duration::from_secs(1)));
+        assert!(reporter.should_emit(&status, now + STATUS_HEARTBEAT));
+        assert!(reporter.should_emit(
+            &TrackingStatus::new("Mail", false, false),
+            now + Duration::from_secs(1)
+        ));
+    }
+}
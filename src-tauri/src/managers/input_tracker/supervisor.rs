@@ -0,0 +1,292 @@
+//! Panic supervision for the tracker's worker threads.
+//!
+//! A panic in the keyboard hook, idle checker or event processor used to
+//! kill that thread silently, leaving tracking half-dead until restart. Workers
+//! started through [`spawn_supervised`] have their panics caught and logged,
+//! reported to the UI as an `input-tracker-health` event, and are restarted
+//! with exponential backoff for as long as tracking stays enabled.
+//!
+//! Catching a panic needs it to unwind, so release builds keep
+//! `panic = "unwind"`; a build that aborts on panic fails to compile here
+//! rather than losing the supervision silently. Only supervised workers and
+//! code run through [`catch_panic`] get to unwind, though: [`install_panic_hook`] aborts the app on a panic in any
+//! other thread, as `panic = "abort"` did, instead of leaving poisoned locks
+//! behind for other threads to trip over. The tracker's own locks hold plain
+//! values that are replaced wholesale, so [`read`] and [`write`] recover them
+//! from a poisoned state rather than panicking again.
+
+use serde::Serialize;
+use std::any::Any;
+use std::cell::Cell;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use std::thread;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter};
+
+#[cfg(not(panic = "unwind"))]
+compile_error!("the input tracker supervisor needs panic = \"unwind\" to restart workers");
+
+/// Delay before the first restart of a panicked worker.
+const INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+/// Upper bound for the restart delay.
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+/// A worker that ran this long before panicking restarts with the initial
+/// delay again.
+const STABLE_RUN: Duration = Duration::from_secs(60);
+
+thread_local! {
+    /// Whether this thread runs a supervised worker, whose panics are caught.
+    static SUPERVISED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Abort the app on a panic outside a supervised worker, after the default
+/// hook has reported it.
+pub fn install_panic_hook() {
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        default_hook(info);
+        if !SUPERVISED.with(Cell::get) {
+            std::process::abort();
+        }
+    }));
+}
+
+/// Lock `lock` for reading, even if a worker panicked while writing to it.
+pub fn read<T>(lock: &RwLock<T>) -> RwLockReadGuard<'_, T> {
+    lock.read().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Lock `lock` for writing, even if a worker panicked while writing to it.
+pub fn write<T>(lock: &RwLock<T>) -> RwLockWriteGuard<'_, T> {
+    lock.write().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Payload of the `input-tracker-health` event.
+#[derive(Clone, Debug, Serialize)]
+pub struct WorkerHealth {
+    pub worker: &'static str,
+    pub message: String,
+    /// Restarts of this worker since tracking was started.
+    pub restarts: u32,
+    /// Delay before the next restart, if the worker will be restarted.
+    pub restart_in_ms: Option<u64>,
+}
+
+/// Run `worker` on a named thread, restarting it whenever it panics while
+/// `enabled` is set. A worker that returns normally is not restarted.
+pub fn spawn_supervised<F>(
+    name: &'static str,
+    enabled: Arc<AtomicBool>,
+    app_handle: AppHandle,
+    worker: F,
+) where
+    F: FnMut() + Send + 'static,
+{
+    let spawned = thread::Builder::new()
+        .name(format!("input-tracker-{}", name))
+        .spawn(move || {
+            run_supervised(name, &enabled, INITIAL_BACKOFF, worker, |health| {
+                report_health(&app_handle, health)
+            })
+        });
+    if let Err(e) = spawned {
+        log::error!("[InputTracker] Failed to spawn {} thread: {}", name, e);
+    }
+}
+
+/// Run `f` and catch a panic in it, as a supervised worker's would be,
+/// instead of aborting the app.
+pub fn catch_panic<F: FnOnce()>(f: F) -> Result<(), Box<dyn Any + Send>> {
+    let was_supervised = SUPERVISED.with(|supervised| supervised.replace(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    SUPERVISED.with(|supervised| supervised.set(was_supervised));
+    result
+}
+
+/// Report a panic that was caught without stopping the worker (e.g. inside
+/// the keyboard hook callback, which must not unwind into the OS).
+pub fn report_panic(app_handle: &AppHandle, worker: &'static str, payload: Box<dyn Any + Send>) {
+    let health = WorkerHealth {
+        worker,
+        message: panic_message(payload.as_ref()),
+        restarts: 0,
+        restart_in_ms: None,
+    };
+    log::error!("[InputTracker] {} panicked: {}", worker, health.message);
+    report_health(app_handle, &health);
+}
+
+fn report_health(app_handle: &AppHandle, health: &WorkerHealth) {
+    if let Err(e) = app_handle.emit("input-tracker-health", health) {
+        log::error!("[InputTracker] Failed to emit health event: {}", e);
+    }
+}
+
+fn run_supervised<F, R>(
+    name: &'static str,
+    enabled: &AtomicBool,
+    initial_backoff: Duration,
+    mut worker: F,
+    report: R,
+) where
+    F: FnMut(),
+    R: Fn(&WorkerHealth),
+{
+    SUPERVISED.with(|supervised| supervised.set(true));
+    let mut restarts = 0;
+    let mut backoff = initial_backoff;
+
+    loop {
+        let started = Instant::now();
+        let payload = match panic::catch_unwind(AssertUnwindSafe(&mut worker)) {
+            Ok(()) => return,
+            Err(payload) => payload,
+        };
+
+        if started.elapsed() >= STABLE_RUN {
+            backoff = initial_backoff;
+        }
+        let will_restart = enabled.load(Ordering::SeqCst);
+        let health = WorkerHealth {
+            worker: name,
+            message: panic_message(payload.as_ref()),
+            restarts,
+            restart_in_ms: will_restart.then_some(backoff.as_millis() as u64),
+        };
+        log::error!(
+            "[InputTracker] {} thread panicked: {}{}",
+            name,
+            health.message,
+            if will_restart {
+                format!(", restarting in {:?}", backoff)
+            } else {
+                String::new()
+            }
+        );
+        report(&health);
+
+        if !will_restart {
+            return;
+        }
+        thread::sleep(backoff);
+        if !enabled.load(Ordering::SeqCst) {
+            return;
+        }
+        restarts += 1;
+        backoff = (backoff * 2).min(MAX_BACKOFF);
+        log::info!("[InputTracker] Restarting {} thread", name);
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[test]
+    fn panicking_worker_is_restarted_until_it_returns() {
+        let enabled = AtomicBool::new(true);
+        let reports = Mutex::new(Vec::new());
+        let mut runs = 0;
+
+        run_supervised(
+            "test",
+            &enabled,
+            Duration::from_millis(1),
+            || {
+                runs += 1;
+                if runs < 3 {
+                    panic!("run {} failed", runs);
+                }
+            },
+            |health| reports.lock().unwrap().push(health.clone()),
+        );
+
+        assert_eq!(runs, 3);
+        let reports = reports.into_inner().unwrap();
+        assert_eq!(reports.len(), 2);
+        assert_eq!(reports[0].message, "run 1 failed");
+        assert_eq!(reports[1].restarts, 1);
+        assert_eq!(reports[1].restart_in_ms, Some(2));
+    }
+
+    #[test]
+    fn worker_is_not_restarted_once_disabled() {
+        let enabled = AtomicBool::new(false);
+        let mut runs = 0;
+
+        run_supervised(
+            "test",
+            &enabled,
+            Duration::from_millis(1),
+            || {
+                runs += 1;
+                panic!("boom");
+            },
+            |health| assert_eq!(health.restart_in_ms, None),
+        );
+
+        assert_eq!(runs, 1);
+    }
+
+    #[test]
+    fn workers_run_supervised() {
+        let enabled = AtomicBool::new(true);
+        let mut supervised = false;
+
+        run_supervised(
+            "test",
+            &enabled,
+            Duration::from_millis(1),
+            || supervised = SUPERVISED.with(Cell::get),
+            |_| {},
+        );
+
+        assert!(supervised);
+    }
+
+    #[test]
+    fn poisoned_locks_stay_usable() {
+        let lock = Arc::new(RwLock::new(vec!["a".to_string()]));
+        let writer = lock.clone();
+        let _ = thread::spawn(move || {
+            let _guard = writer.write().unwrap();
+            panic!("worker died holding the lock");
+        })
+        .join();
+        assert!(lock.is_poisoned());
+
+        write(&lock).push("b".to_string());
+        assert_eq!(*read(&lock), ["a", "b"]);
+    }
+
+    /// Tests always unwind, so check that the release profile the app ships
+    /// with does too; with `panic = "abort"` nothing above would be caught.
+    #[test]
+    fn release_profile_unwinds_on_panic() {
+        let manifest = include_str!("../../../Cargo.toml");
+        let release = manifest
+            .split("[profile.release]")
+            .nth(1)
+            .expect("Cargo.toml has a release profile");
+        let release = release.split("\n[").next().unwrap();
+        let panic_strategy = release
+            .lines()
+            .map(str::trim)
+            .find_map(|line| line.strip_prefix("panic"))
+            .map(|value| value.trim_start_matches([' ', '=']).trim_matches('"'));
+        assert_eq!(panic_strategy, Some("unwind"));
+    }
+}
@@ -1,30 +1,59 @@
 //! Core types for the input tracker module.
 
 use rdev::Key;
+use std::sync::mpsc;
 
 /// Events that can be sent through the input tracker channel
 #[derive(Debug)]
 pub enum InputTrackerEvent {
     /// App has changed (new app info)
     AppChanged(ActiveAppInfo),
+    /// Focused window title/URL changed (only watched while window rules exist)
+    WindowChanged(WindowContext),
     /// A keystroke was received
     Keystroke(KeystrokeEvent),
     /// Mouse click happened
     Click,
     /// Check for idle timeout
     IdleCheck,
-    /// Shutdown the tracker
-    Shutdown,
+    /// Shutdown the tracker, acknowledging on the sender (if any) once the
+    /// remaining buffer has been queued for saving
+    Shutdown(Option<mpsc::Sender<()>>),
 }
 
 /// A keystroke event with key and optional unicode representation
 #[derive(Debug)]
 pub struct KeystrokeEvent {
     pub key: Key,
+    /// Platform key code, for looking the key up in the active layout
+    pub code: u32,
     pub unicode: Option<String>,
     pub is_press: bool,
 }
 
+impl KeystrokeEvent {
+    /// Key to match shortcuts against: the letter the key types in the active
+    /// layout, so Ctrl+Z is recognised on AZERTY (where rdev reports `KeyW`).
+    /// Keys that don't type a Latin letter (including every key on Cyrillic or
+    /// Greek layouts, where apps fall back to the physical key) keep their
+    /// physical key.
+    pub fn shortcut_key(&self) -> Key {
+        let letter = crate::keyboard_layout::char_for_key(self.code, Default::default())
+            .map(|c| c.to_ascii_lowercase());
+        match letter {
+            Some('a') => Key::KeyA,
+            Some('c') => Key::KeyC,
+            Some('s') => Key::KeyS,
+            Some('v') => Key::KeyV,
+            Some('x') => Key::KeyX,
+            Some('y') => Key::KeyY,
+            Some('z') => Key::KeyZ,
+            Some(c) if c.is_ascii_alphabetic() => Key::Unknown(0),
+            _ => self.key,
+        }
+    }
+}
+
 /// Information about the currently active application
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct ActiveAppInfo {
@@ -33,6 +62,13 @@ pub struct ActiveAppInfo {
     pub pid: Option<i32>,
 }
 
+/// Title and document URL of the focused window, used by window exclusion rules
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WindowContext {
+    pub title: Option<String>,
+    pub url: Option<String>,
+}
+
 /// An entry of tracked input to be saved to the database
 #[derive(Debug, Clone)]
 pub struct InputEntry {
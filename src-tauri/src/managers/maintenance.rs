@@ -0,0 +1,189 @@
+//! SQLite maintenance for the history and input tracking databases.
+//!
+//! Both databases only grow: deleted history and input entries leave free
+//! pages behind and the WAL file is never truncated. A background job runs
+//! once a day (and on demand via `optimize_database`) to checkpoint the WAL,
+//! reclaim free pages with an incremental vacuum and refresh the query planner
+//! statistics.
+
+use crate::audio_toolkit::thread_priority::{apply_current_thread_priority, ThreadPriority};
+use crate::settings::get_settings;
+use anyhow::{Context, Result};
+use log::{error, info};
+use rusqlite::Connection;
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager};
+
+/// Delay before the first scheduled run, so startup isn't slowed down.
+const STARTUP_DELAY: Duration = Duration::from_secs(5 * 60);
+/// Interval between scheduled runs.
+const MAINTENANCE_INTERVAL: Duration = Duration::from_secs(24 * 60 * 60);
+/// How long to wait for other connections to release the database.
+const BUSY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// `PRAGMA auto_vacuum` value for incremental mode.
+const AUTO_VACUUM_INCREMENTAL: i64 = 2;
+
+#[derive(Clone, Debug, Serialize)]
+pub struct MaintenanceReport {
+    pub database: String,
+    /// Database plus WAL file size, in bytes.
+    pub size_before: u64,
+    pub size_after: u64,
+    pub duration_ms: u64,
+}
+
+pub struct MaintenanceManager {
+    db_paths: Vec<PathBuf>,
+    /// Keeps scheduled and manual runs from overlapping.
+    running: Mutex<()>,
+}
+
+impl MaintenanceManager {
+    pub fn new(app_handle: &AppHandle) -> Result<Self> {
+        let app_data_dir = crate::profile::data_dir(app_handle)?;
+
+        let scheduler_handle = app_handle.clone();
+        thread::spawn(move || run_scheduler(scheduler_handle));
+
+        Ok(Self {
+            db_paths: vec![
+                app_data_dir.join("history.db"),
+                app_data_dir.join("echo.db"),
+            ],
+            running: Mutex::new(()),
+        })
+    }
+
+    /// Run maintenance on every database that exists.
+    pub fn optimize(&self) -> Result<Vec<MaintenanceReport>> {
+        let _guard = self.running.lock().unwrap();
+        let mut reports = Vec::new();
+        for db_path in self.db_paths.iter().filter(|p| p.exists()) {
+            let report = optimize_database(db_path)?;
+            info!(
+                "Optimized {} in {}ms: {} -> {} bytes",
+                report.database, report.duration_ms, report.size_before, report.size_after
+            );
+            reports.push(report);
+        }
+        Ok(reports)
+    }
+}
+
+fn run_scheduler(app_handle: AppHandle) {
+    if get_settings(&app_handle).thread_priorities_enabled {
+        apply_current_thread_priority(ThreadPriority::Background);
+    }
+    thread::sleep(STARTUP_DELAY);
+    loop {
+        match app_handle.try_state::<Arc<MaintenanceManager>>() {
+            Some(manager) => {
+                if let Err(e) = manager.optimize() {
+                    error!("Scheduled database maintenance failed: {}", e);
+                }
+            }
+            None => error!("Maintenance manager not registered, skipping scheduled run"),
+        }
+        thread::sleep(MAINTENANCE_INTERVAL);
+    }
+}
+
+/// Checkpoint the WAL, reclaim free pages and refresh statistics.
+///
+/// Databases created before incremental vacuum was enabled need a full
+/// `VACUUM` once to switch modes; after that only free pages are released.
+pub fn optimize_database(db_path: &Path) -> Result<MaintenanceReport> {
+    let start = Instant::now();
+    let size_before = database_size(db_path);
+
+    let conn = Connection::open(db_path)
+        .with_context(|| format!("Failed to open database at {:?}", db_path))?;
+    conn.busy_timeout(BUSY_TIMEOUT)?;
+
+    let auto_vacuum: i64 = conn.query_row("PRAGMA auto_vacuum", [], |row| row.get(0))?;
+    if auto_vacuum != AUTO_VACUUM_INCREMENTAL {
+        conn.execute_batch("PRAGMA auto_vacuum = INCREMENTAL; VACUUM;")
+            .context("Failed to enable incremental vacuum")?;
+    } else {
+        conn.execute_batch("PRAGMA incremental_vacuum;")
+            .context("Incremental vacuum failed")?;
+    }
+
+    conn.execute_batch("ANALYZE;")
+        .context("Failed to analyze database")?;
+    // Runs last so the WAL written by the steps above is folded in too
+    conn.query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |_| Ok(()))
+        .context("WAL checkpoint failed")?;
+    drop(conn);
+
+    Ok(MaintenanceReport {
+        database: db_path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default(),
+        size_before,
+        size_after: database_size(db_path),
+        duration_ms: start.elapsed().as_millis() as u64,
+    })
+}
+
+fn database_size(db_path: &Path) -> u64 {
+    let mut wal_path = db_path.as_os_str().to_owned();
+    wal_path.push("-wal");
+    [db_path.to_path_buf(), PathBuf::from(wal_path)]
+        .iter()
+        .filter_map(|path| fs::metadata(path).ok())
+        .map(|metadata| metadata.len())
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn optimize_reclaims_deleted_rows() {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("history.db");
+        let conn = Connection::open(&db_path).unwrap();
+        conn.execute_batch(
+            "PRAGMA journal_mode = WAL;
+             CREATE TABLE items (id INTEGER PRIMARY KEY, body TEXT NOT NULL);",
+        )
+        .unwrap();
+        let body = "x".repeat(4096);
+        for _ in 0..200 {
+            conn.execute("INSERT INTO items (body) VALUES (?1)", [&body])
+                .unwrap();
+        }
+        conn.execute("DELETE FROM items", []).unwrap();
+        drop(conn);
+
+        let first = optimize_database(&db_path).unwrap();
+        assert!(first.size_after < first.size_before);
+
+        let conn = Connection::open(&db_path).unwrap();
+        let auto_vacuum: i64 = conn
+            .query_row("PRAGMA auto_vacuum", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(auto_vacuum, AUTO_VACUUM_INCREMENTAL);
+
+        // Later runs only release the pages freed since
+        for _ in 0..50 {
+            conn.execute("INSERT INTO items (body) VALUES (?1)", [&body])
+                .unwrap();
+        }
+        conn.execute("DELETE FROM items", []).unwrap();
+        drop(conn);
+
+        let second = optimize_database(&db_path).unwrap();
+        assert!(second.size_after <= first.size_after + 4096);
+    }
+}
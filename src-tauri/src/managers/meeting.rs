@@ -17,11 +17,17 @@ use tokio::sync::Mutex;
 use super::database;
 use super::diarization::DiarizationManager;
 use super::transcription::TranscriptionManager;
+use super::transcription_queue::JobPriority;
 use crate::audio_toolkit::audio::save_wav_file;
 use crate::audio_toolkit::{list_input_devices, AudioRecorder};
 use crate::helpers::clamshell;
 use crate::settings;
 
+/// Speaker label of the mic channel of a mixed recording: the user.
+const MIC_SPEAKER: &str = "Me";
+/// Speaker label of the system audio channel when it isn't diarized.
+const SYSTEM_SPEAKER: &str = "Them";
+
 // ── Types ──────────────────────────────────────────────────────────────────
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -31,6 +37,7 @@ pub enum MeetingStatus {
     Processing,
     Complete,
     Error,
+    Cancelled,
 }
 
 impl MeetingStatus {
@@ -40,6 +47,7 @@ impl MeetingStatus {
             MeetingStatus::Processing => "processing",
             MeetingStatus::Complete => "complete",
             MeetingStatus::Error => "error",
+            MeetingStatus::Cancelled => "cancelled",
         }
     }
 
@@ -49,6 +57,7 @@ impl MeetingStatus {
             "processing" => MeetingStatus::Processing,
             "complete" => MeetingStatus::Complete,
             "error" => MeetingStatus::Error,
+            "cancelled" => MeetingStatus::Cancelled,
             _ => MeetingStatus::Error,
         }
     }
@@ -109,6 +118,9 @@ pub enum ExportFormat {
 struct RecordingState {
     meeting_id: i64,
     start_time: i64,
+    source: AudioSource,
+    /// System audio is recorded alongside the mic, as a second channel.
+    mixed: bool,
 }
 
 enum ManagerState {
@@ -126,11 +138,13 @@ pub struct MeetingManager {
     db_path: PathBuf,
     /// Dedicated mic recorder for meeting capture (no VAD — records everything).
     mic_recorder: Arc<std::sync::Mutex<Option<AudioRecorder>>>,
+    /// System audio recorder, when it is captured alongside the mic.
+    system_recorder: Arc<std::sync::Mutex<Option<AudioRecorder>>>,
 }
 
 impl MeetingManager {
     pub fn new(app_handle: &AppHandle) -> Result<Self> {
-        let app_data_dir = app_handle.path().app_data_dir()?;
+        let app_data_dir = crate::profile::data_dir(app_handle)?;
         let meetings_dir = app_data_dir.join("meetings");
         let db_path = app_data_dir.join("history.db");
 
@@ -150,6 +164,7 @@ impl MeetingManager {
             meetings_dir,
             db_path,
             mic_recorder: Arc::new(std::sync::Mutex::new(None)),
+            system_recorder: Arc::new(std::sync::Mutex::new(None)),
         })
     }
 
@@ -191,32 +206,54 @@ impl MeetingManager {
         }
     }
 
-    /// Start a new meeting recording.
+    /// Resolve the system audio device (a loopback or monitor input) from settings.
+    fn get_system_audio_device(&self) -> Result<cpal::Device> {
+        let app_settings = settings::get_settings(&self.app_handle);
+        let device_name = app_settings
+            .meeting_system_audio_device
+            .context("Select a system audio device in meeting settings to record system audio")?;
+        list_input_devices()
+            .map_err(|e| anyhow::anyhow!("Failed to list audio devices: {}", e))?
+            .into_iter()
+            .find(|d| d.name == device_name)
+            .map(|d| d.device)
+            .with_context(|| format!("System audio device '{}' not found", device_name))
+    }
+
+    /// Start a new meeting recording from the microphone.
     pub async fn start_meeting(&self, title: Option<String>) -> Result<i64> {
+        self.start_meeting_from(title, AudioSource::Mic).await
+    }
+
+    /// Start a new meeting recording from `source`.
+    pub async fn start_meeting_from(
+        &self,
+        title: Option<String>,
+        source: AudioSource,
+    ) -> Result<i64> {
         let mut state = self.state.lock().await;
         if !matches!(*state, ManagerState::Idle) {
             anyhow::bail!("A meeting is already in progress");
         }
 
-        // Open mic recorder (no VAD — meetings should capture all audio)
-        let mut recorder = AudioRecorder::new()
-            .map_err(|e| anyhow::anyhow!("Failed to create meeting audio recorder: {}", e))?;
-
-        let selected_device = self.get_effective_mic_device();
-        recorder
-            .open(selected_device)
-            .map_err(|e| anyhow::anyhow!("Failed to open microphone for meeting: {}", e))?;
-
-        recorder
-            .start(None)
-            .map_err(|e| anyhow::anyhow!("Failed to start microphone recording: {}", e))?;
+        // With system audio on, a mic meeting records both sides of the call
+        let mixed = source == AudioSource::Mic
+            && settings::get_settings(&self.app_handle).meeting_system_audio_enabled;
+        let system_recorder = if mixed {
+            let device = self.get_system_audio_device()?;
+            Some(self.start_recorder(Some(device), AudioSource::System)?)
+        } else {
+            None
+        };
 
-        info!("Meeting microphone stream started");
+        let selected_device = match source {
+            AudioSource::Mic => self.get_effective_mic_device(),
+            AudioSource::System => Some(self.get_system_audio_device()?),
+        };
+        let recorder = self.start_recorder(selected_device, source.clone())?;
 
-        {
-            let mut rec_guard = self.mic_recorder.lock().unwrap();
-            *rec_guard = Some(recorder);
-        }
+        *self.mic_recorder.lock().unwrap() = Some(recorder);
+        *self.system_recorder.lock().unwrap() = system_recorder;
 
         let now = Utc::now().timestamp();
         let meeting_title = title.unwrap_or_else(|| {
@@ -236,6 +273,8 @@ impl MeetingManager {
         *state = ManagerState::Recording(RecordingState {
             meeting_id,
             start_time: now,
+            source,
+            mixed,
         });
 
         self.emit_status_changed(MeetingStatus::Recording);
@@ -243,6 +282,71 @@ impl MeetingManager {
         Ok(meeting_id)
     }
 
+    /// Open and start a recorder on `device` (no VAD — meetings should
+    /// capture all audio).
+    fn start_recorder(
+        &self,
+        device: Option<cpal::Device>,
+        source: AudioSource,
+    ) -> Result<AudioRecorder> {
+        let realtime_priority = settings::get_settings(&self.app_handle).thread_priorities_enabled;
+        let mut recorder = AudioRecorder::new()
+            .map_err(|e| anyhow::anyhow!("Failed to create meeting audio recorder: {}", e))?
+            .with_realtime_priority(realtime_priority);
+        if source == AudioSource::Mic {
+            let app_handle = self.app_handle.clone();
+            recorder = recorder.with_noise_suppression(move || {
+                settings::get_settings(&app_handle).noise_suppression_enabled
+            });
+            let app_handle = self.app_handle.clone();
+            recorder =
+                recorder.with_input_gain(move || settings::get_settings(&app_handle).input_gain());
+        }
+
+        recorder.open(device).map_err(|e| {
+            anyhow::anyhow!(
+                "Failed to open {} audio for meeting: {}",
+                source.as_str(),
+                e
+            )
+        })?;
+
+        recorder
+            .start(None)
+            .map_err(|e| anyhow::anyhow!("Failed to start {} recording: {}", source.as_str(), e))?;
+
+        info!("Meeting {} stream started", source.as_str());
+        Ok(recorder)
+    }
+
+    /// Stop the recorder in `slot`, if any, close it and return everything it
+    /// captured.
+    fn stop_recorder(
+        slot: &std::sync::Mutex<Option<AudioRecorder>>,
+        source: AudioSource,
+    ) -> Vec<f32> {
+        let Some(mut recorder) = slot.lock().unwrap().take() else {
+            return Vec::new();
+        };
+        let samples = match recorder.stop() {
+            Ok(samples) => {
+                info!(
+                    "Meeting {} captured {} samples ({:.1}s)",
+                    source.as_str(),
+                    samples.len(),
+                    samples.len() as f32 / 16000.0
+                );
+                samples
+            }
+            Err(e) => {
+                error!("Failed to stop meeting {} recorder: {}", source.as_str(), e);
+                Vec::new()
+            }
+        };
+        let _ = recorder.close();
+        samples
+    }
+
     /// Stop the current meeting recording, save audio, and set status to Processing.
     pub async fn stop_meeting(&self) -> Result<()> {
         let recording = {
@@ -258,54 +362,39 @@ impl MeetingManager {
 
         self.emit_status_changed(MeetingStatus::Processing);
 
-        // Stop the mic recorder and collect all accumulated samples
-        let mic_samples = {
-            let rec_guard = self.mic_recorder.lock().unwrap();
-            if let Some(ref recorder) = *rec_guard {
-                match recorder.stop() {
-                    Ok(samples) => {
-                        info!(
-                            "Meeting mic captured {} samples ({:.1}s)",
-                            samples.len(),
-                            samples.len() as f32 / 16000.0
-                        );
-                        samples
-                    }
-                    Err(e) => {
-                        error!("Failed to stop meeting mic recorder: {}", e);
-                        Vec::new()
-                    }
-                }
-            } else {
-                warn!("No mic recorder was active for meeting");
-                Vec::new()
-            }
-        };
-
-        // Close and drop the recorder
-        {
-            let mut rec_guard = self.mic_recorder.lock().unwrap();
-            if let Some(mut recorder) = rec_guard.take() {
-                let _ = recorder.close();
-            }
+        // Stop the recorders and collect all accumulated samples
+        let mic_samples = Self::stop_recorder(&self.mic_recorder, recording.source.clone());
+        if mic_samples.is_empty() {
+            warn!("No audio was captured for the meeting");
         }
+        let system_samples = Self::stop_recorder(&self.system_recorder, AudioSource::System);
 
         let now = Utc::now().timestamp();
         let duration_ms = (now - recording.start_time) * 1000;
         let meeting_id = recording.meeting_id;
+        let source = recording.source;
 
-        // Save mic audio to WAV
-        let mic_file = if !mic_samples.is_empty() {
-            let name = format!("meeting-{}-mic.wav", meeting_id);
+        // Save audio to WAV; the recorder captured a single source
+        let audio_file = if !mic_samples.is_empty() {
+            let name = format!("meeting-{}-{}.wav", meeting_id, source.as_str());
             let path = self.meetings_dir.join(&name);
             save_wav_file(path, &mic_samples).await?;
             Some(name)
         } else {
             None
         };
-
-        // System audio not yet wired — placeholder for future implementation
-        let sys_file: Option<String> = None;
+        let (mic_file, sys_file) = match source {
+            AudioSource::Mic => (audio_file, None),
+            AudioSource::System => (None, audio_file),
+        };
+        // A mixed recording keeps the system audio as a channel of its own
+        let sys_file = if recording.mixed && !system_samples.is_empty() {
+            let name = format!("meeting-{}-system.wav", meeting_id);
+            save_wav_file(self.meetings_dir.join(&name), &system_samples).await?;
+            Some(name)
+        } else {
+            sys_file
+        };
 
         // Update meeting record
         let conn = self.get_connection()?;
@@ -322,33 +411,46 @@ impl MeetingManager {
         )?;
 
         // Transcribe mic audio — with or without speaker diarization
-        if !mic_samples.is_empty() {
-            let app_settings = settings::get_settings(&self.app_handle);
-            let diarization_available = self
-                .app_handle
-                .try_state::<Arc<DiarizationManager>>()
-                .map(|dm| dm.is_available())
-                .unwrap_or(false);
-
-            if app_settings.meeting_diarization_enabled && diarization_available {
-                self.diarize_and_transcribe(meeting_id, &mic_samples, AudioSource::Mic)
-                    .await;
-            } else {
-                self.transcribe_samples(meeting_id, &mic_samples, "Speaker", AudioSource::Mic)
-                    .await;
-            }
-        }
+        let app_settings = settings::get_settings(&self.app_handle);
+        let diarization_available = self
+            .app_handle
+            .try_state::<Arc<DiarizationManager>>()
+            .map(|dm| dm.is_available())
+            .unwrap_or(false);
+        let diarize = app_settings.meeting_diarization_enabled && diarization_available;
+        let finished = if recording.mixed {
+            self.transcribe_channels(meeting_id, &mic_samples, &system_samples, diarize)
+                .await
+        } else if mic_samples.is_empty() {
+            true
+        } else if diarize {
+            self.diarize_and_transcribe(meeting_id, &mic_samples, source)
+                .await
+        } else {
+            self.transcribe_samples(meeting_id, &mic_samples, "Speaker", source)
+                .await
+        };
 
-        // Mark complete
+        // A cancelled transcription leaves a partial transcript, so it isn't
+        // marked complete or summarized
+        let status = if finished {
+            MeetingStatus::Complete
+        } else {
+            MeetingStatus::Cancelled
+        };
         conn.execute(
             "UPDATE meetings SET status = ?1 WHERE id = ?2",
-            params![MeetingStatus::Complete.as_str(), meeting_id],
+            params![status.as_str(), meeting_id],
         )?;
 
         let mut state = self.state.lock().await;
         *state = ManagerState::Idle;
 
-        self.emit_status_changed(MeetingStatus::Complete);
+        self.emit_status_changed(status);
+        if !finished {
+            info!("Meeting {} transcription cancelled", meeting_id);
+            return Ok(());
+        }
         info!("Meeting {} completed", meeting_id);
 
         // Auto-generate summary if enabled
@@ -362,14 +464,50 @@ impl MeetingManager {
         Ok(())
     }
 
+    /// Transcribe a recording of the mic and system audio one channel at a
+    /// time, labelling the mic as the user. The channel already tells the
+    /// user apart, so only the system audio is diarized. Returns `false` if
+    /// the transcription was cancelled.
+    async fn transcribe_channels(
+        &self,
+        meeting_id: i64,
+        mic_samples: &[f32],
+        system_samples: &[f32],
+        diarize: bool,
+    ) -> bool {
+        if !mic_samples.is_empty()
+            && !self
+                .transcribe_samples(meeting_id, mic_samples, MIC_SPEAKER, AudioSource::Mic)
+                .await
+        {
+            return false;
+        }
+        if system_samples.is_empty() {
+            return true;
+        }
+        if diarize {
+            self.diarize_and_transcribe(meeting_id, system_samples, AudioSource::System)
+                .await
+        } else {
+            self.transcribe_samples(
+                meeting_id,
+                system_samples,
+                SYSTEM_SPEAKER,
+                AudioSource::System,
+            )
+            .await
+        }
+    }
+
     /// Transcribe audio samples in chunks and insert segments into the database.
+    /// Returns `false` if the job was cancelled before the last chunk.
     async fn transcribe_samples(
         &self,
         meeting_id: i64,
         samples: &[f32],
         speaker_label: &str,
         source: AudioSource,
-    ) {
+    ) -> bool {
         let chunk_secs = {
             let s = settings::get_settings(&self.app_handle);
             s.meeting_chunk_duration_secs.max(10) as usize
@@ -382,14 +520,23 @@ impl MeetingManager {
         // Ensure the transcription model is loaded before we start chunking
         transcription_manager.initiate_model_load();
 
+        // A background job, so dictations recorded meanwhile go first
+        let job =
+            transcription_manager.submit_job(JobPriority::Background, "Meeting transcription");
         let mut offset_ms: i64 = 0;
         let mut pos = 0;
 
         while pos < samples.len() {
+            if job.is_cancelled() {
+                return false;
+            }
             let end = (pos + chunk_size).min(samples.len());
             let chunk = &samples[pos..end];
 
-            match transcription_manager.transcribe(chunk.to_vec()) {
+            match transcription_manager
+                .transcribe_job(&job, chunk.to_vec())
+                .map(|transcript| transcript.text)
+            {
                 Ok(text) if !text.trim().is_empty() => {
                     let chunk_duration_ms = ((end - pos) as i64 * 1000) / 16_000;
                     let segment = MeetingSegment {
@@ -424,23 +571,25 @@ impl MeetingManager {
             offset_ms += (step as i64 * 1000) / 16_000;
             pos += step;
         }
+        true
     }
 
     /// Run speaker diarization on the full audio, then transcribe each speaker segment.
-    /// Falls back to chunked transcription on failure.
+    /// Falls back to chunked transcription on failure. Returns `false` if the
+    /// job was cancelled.
     async fn diarize_and_transcribe(
         &self,
         meeting_id: i64,
         samples: &[f32],
         source: AudioSource,
-    ) {
+    ) -> bool {
         let diarization_manager = match self.app_handle.try_state::<Arc<DiarizationManager>>() {
             Some(dm) => dm.inner().clone(),
             None => {
                 warn!("DiarizationManager not available, falling back to chunked transcription");
-                self.transcribe_samples(meeting_id, samples, "Speaker", source)
+                return self
+                    .transcribe_samples(meeting_id, samples, "Speaker", source)
                     .await;
-                return;
             }
         };
 
@@ -455,29 +604,35 @@ impl MeetingManager {
             Ok(segs) => segs,
             Err(e) => {
                 error!("Diarization failed, falling back to chunked transcription: {}", e);
-                self.transcribe_samples(meeting_id, samples, "Speaker", source)
+                return self
+                    .transcribe_samples(meeting_id, samples, "Speaker", source)
                     .await;
-                return;
             }
         };
 
         if raw_segments.is_empty() {
             warn!("Diarization returned no segments, falling back to chunked transcription");
-            self.transcribe_samples(meeting_id, samples, "Speaker", source)
+            return self
+                .transcribe_samples(meeting_id, samples, "Speaker", source)
                 .await;
-            return;
         }
 
         // Merge consecutive same-speaker segments (max 30s for transcription context)
         let merged = DiarizationManager::merge_consecutive(&raw_segments, 30_000);
+        let labels = DiarizationManager::speaker_labels(&merged);
 
         let transcription_manager = self.app_handle.state::<Arc<TranscriptionManager>>();
         transcription_manager.initiate_model_load();
 
+        let job =
+            transcription_manager.submit_job(JobPriority::Background, "Meeting transcription");
         let sample_rate: i64 = 16_000;
         let min_samples: usize = 1600; // 100ms minimum
 
         for seg in &merged {
+            if job.is_cancelled() {
+                return false;
+            }
             let start_sample = (seg.start_ms * sample_rate / 1000) as usize;
             let end_sample = ((seg.end_ms * sample_rate / 1000) as usize).min(samples.len());
 
@@ -487,12 +642,15 @@ impl MeetingManager {
 
             let chunk = &samples[start_sample..end_sample];
 
-            match transcription_manager.transcribe(chunk.to_vec()) {
+            match transcription_manager
+                .transcribe_job(&job, chunk.to_vec())
+                .map(|transcript| transcript.text)
+            {
                 Ok(text) if !text.trim().is_empty() => {
                     let segment = MeetingSegment {
                         id: 0,
                         meeting_id,
-                        speaker_label: format!("Speaker {}", seg.speaker_id),
+                        speaker_label: labels[&seg.speaker_id].clone(),
                         start_ms: seg.start_ms,
                         end_ms: seg.end_ms,
                         text: text.trim().to_string(),
@@ -525,6 +683,7 @@ impl MeetingManager {
             merged.len(),
             raw_segments.len()
         );
+        true
     }
 
     fn insert_segment(&self, segment: &MeetingSegment) -> Result<()> {
@@ -802,6 +961,12 @@ impl MeetingManager {
         if samples.is_empty() {
             anyhow::bail!("Audio file is empty for meeting {}", meeting_id);
         }
+        // Meetings recorded from the mic with a system audio file are mixed
+        let system_samples = match self.get_meeting(meeting_id)?.system_file_name {
+            Some(name) => load_wav_file(self.meetings_dir.join(&name))
+                .with_context(|| format!("Failed to load audio: {}", name))?,
+            None => Vec::new(),
+        };
 
         // Delete existing segments
         {
@@ -831,7 +996,11 @@ impl MeetingManager {
             anyhow::bail!("Speaker detection is enabled but diarization models are not downloaded yet. Please wait for the download to finish.");
         }
 
-        if app_settings.meeting_diarization_enabled && diarization_available {
+        let diarize = app_settings.meeting_diarization_enabled && diarization_available;
+        if !system_samples.is_empty() {
+            self.transcribe_channels(meeting_id, &samples, &system_samples, diarize)
+                .await;
+        } else if diarize {
             self.diarize_and_transcribe(meeting_id, &samples, AudioSource::Mic)
                 .await;
         } else {
@@ -1020,6 +1189,7 @@ mod tests {
             MeetingStatus::Processing,
             MeetingStatus::Complete,
             MeetingStatus::Error,
+            MeetingStatus::Cancelled,
         ];
         for status in &statuses {
             let s = status.as_str();
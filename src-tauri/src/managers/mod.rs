@@ -1,10 +1,32 @@
 pub mod audio;
+pub mod automation;
+pub mod captions;
+pub mod confidence_review;
+pub mod corrections;
+pub mod data_api;
 pub mod database;
+pub mod db_writer;
 pub mod diarization;
+pub mod dictation_template;
+pub mod digest;
+pub mod entry_edits;
 pub mod export;
+pub mod feature_flags;
+pub mod focus_session;
 pub mod history;
+pub mod history_import;
 pub mod input_tracker;
+pub mod maintenance;
 pub mod meeting;
 pub mod model;
+pub mod noise_profile;
+pub mod power;
+pub mod recording_journal;
+pub mod scheduled_recording;
+pub mod scheduler;
+pub mod scratchpad;
+pub mod text_plugins;
 pub mod transcription;
+pub mod transcription_queue;
 pub mod tts;
+pub mod voice_profile;
@@ -20,6 +20,10 @@ pub enum EngineType {
     Diarization,
 }
 
+/// Version of the built-in model catalog. Bump it when models are added,
+/// removed or point at new files.
+pub const MODEL_CATALOG_VERSION: u32 = 1;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ModelInfo {
     pub id: String,
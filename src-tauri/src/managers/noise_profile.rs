@@ -0,0 +1,187 @@
+//! Noise profiles for the places the user dictates in.
+//!
+//! Calibrating records a few seconds of the room with nobody talking and
+//! keeps its noise spectrum under a name like "office" or "café". While a
+//! profile is active, dictations have that noise subtracted before they are
+//! transcribed, with the suppression strength matched to how loud the room
+//! was: a quiet home office is barely touched, a busy café gets more.
+
+use anyhow::{anyhow, Context, Result};
+use chrono::Utc;
+use log::{debug, info};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use tauri::AppHandle;
+
+use crate::audio_toolkit::audio::{noise_spectrum, suppress_noise};
+
+/// Binding id the calibration recording runs under.
+pub const CALIBRATION_BINDING_ID: &str = "noise_calibration";
+/// How long calibration listens to the room.
+pub const CALIBRATION_SECS: u64 = 5;
+
+const SAMPLE_RATE: u32 = 16000;
+/// Shortest recording that gives a usable noise estimate.
+const MIN_CALIBRATION_SAMPLES: usize = 16000 * 2;
+/// Noise level at or below which the gentlest suppression is used.
+const QUIET_DB: f32 = -60.0;
+/// Noise level at or above which the strongest suppression is used.
+const LOUD_DB: f32 = -30.0;
+const MIN_STRENGTH: f32 = 1.0;
+const MAX_STRENGTH: f32 = 2.0;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NoiseProfile {
+    /// Average magnitude per frequency bin of the calibration recording.
+    pub spectrum: Vec<f32>,
+    /// Overall level of the calibration recording, in dBFS.
+    pub noise_level_db: f32,
+    /// How much of the noise estimate is subtracted.
+    pub strength: f32,
+    pub calibrated_at: i64,
+}
+
+/// A profile as listed in the settings.
+#[derive(Debug, Clone, Serialize)]
+pub struct NoiseProfileSummary {
+    pub name: String,
+    pub noise_level_db: f32,
+    pub strength: f32,
+    pub calibrated_at: i64,
+}
+
+pub struct NoiseProfileManager {
+    path: PathBuf,
+    profiles: Mutex<BTreeMap<String, NoiseProfile>>,
+}
+
+impl NoiseProfileManager {
+    pub fn new(app_handle: &AppHandle) -> Result<Self> {
+        let path = crate::profile::data_dir(app_handle)?.join("noise_profiles.json");
+        let profiles = if path.exists() {
+            let json = fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read noise profiles at {:?}", path))?;
+            serde_json::from_str(&json).context("Failed to parse noise profiles")?
+        } else {
+            BTreeMap::new()
+        };
+
+        Ok(Self {
+            path,
+            profiles: Mutex::new(profiles),
+        })
+    }
+
+    pub fn list(&self) -> Vec<NoiseProfileSummary> {
+        self.profiles
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(name, profile)| NoiseProfileSummary {
+                name: name.clone(),
+                noise_level_db: profile.noise_level_db,
+                strength: profile.strength,
+                calibrated_at: profile.calibrated_at,
+            })
+            .collect()
+    }
+
+    /// Build the profile `name` from a recording of the room, replacing any
+    /// earlier one of that name.
+    pub fn calibrate(&self, name: &str, samples: &[f32]) -> Result<NoiseProfileSummary> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(anyhow!("Name the environment to calibrate"));
+        }
+        if samples.len() < MIN_CALIBRATION_SAMPLES {
+            return Err(anyhow!(
+                "Calibration needs at least {} seconds of audio",
+                MIN_CALIBRATION_SAMPLES / SAMPLE_RATE as usize
+            ));
+        }
+
+        let noise_level_db = level_db(samples);
+        let profile = NoiseProfile {
+            spectrum: noise_spectrum(samples),
+            noise_level_db,
+            strength: strength_for_level(noise_level_db),
+            calibrated_at: Utc::now().timestamp(),
+        };
+        info!(
+            "Calibrated noise profile '{}' at {:.1} dBFS (strength {:.2})",
+            name, profile.noise_level_db, profile.strength
+        );
+
+        let mut profiles = self.profiles.lock().unwrap();
+        profiles.insert(name.to_string(), profile);
+        self.save(&profiles)?;
+        drop(profiles);
+
+        self.list()
+            .into_iter()
+            .find(|summary| summary.name == name)
+            .ok_or_else(|| anyhow!("Noise profile '{}' was not saved", name))
+    }
+
+    pub fn delete(&self, name: &str) -> Result<()> {
+        let mut profiles = self.profiles.lock().unwrap();
+        if profiles.remove(name).is_some() {
+            self.save(&profiles)?;
+        }
+        Ok(())
+    }
+
+    /// Subtract the noise of profile `name` from a dictation. Returns the
+    /// samples untouched when there is no such profile.
+    pub fn suppress(&self, name: &str, samples: &[f32]) -> Vec<f32> {
+        let Some(profile) = self.profiles.lock().unwrap().get(name).cloned() else {
+            debug!("Noise profile '{}' not found, skipping suppression", name);
+            return samples.to_vec();
+        };
+        suppress_noise(samples, &profile.spectrum, profile.strength)
+    }
+
+    fn save(&self, profiles: &BTreeMap<String, NoiseProfile>) -> Result<()> {
+        fs::write(&self.path, serde_json::to_string(profiles)?)
+            .with_context(|| format!("Failed to write noise profiles to {:?}", self.path))
+    }
+}
+
+/// RMS level of `samples` in dBFS.
+fn level_db(samples: &[f32]) -> f32 {
+    let rms = (samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32).sqrt();
+    20.0 * rms.max(1e-6).log10()
+}
+
+/// Louder rooms have noise that fluctuates more around its average, so more
+/// than the average is subtracted.
+fn strength_for_level(level_db: f32) -> f32 {
+    let t = ((level_db - QUIET_DB) / (LOUD_DB - QUIET_DB)).clamp(0.0, 1.0);
+    MIN_STRENGTH + t * (MAX_STRENGTH - MIN_STRENGTH)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn louder_rooms_get_stronger_suppression() {
+        assert_eq!(strength_for_level(-80.0), MIN_STRENGTH);
+        assert_eq!(strength_for_level(-20.0), MAX_STRENGTH);
+        let office = strength_for_level(-50.0);
+        let cafe = strength_for_level(-35.0);
+        assert!(MIN_STRENGTH < office && office < cafe && cafe < MAX_STRENGTH);
+    }
+
+    #[test]
+    fn level_of_full_scale_square_wave_is_zero_db() {
+        let samples: Vec<f32> = (0..1000)
+            .map(|i| if i % 2 == 0 { 1.0 } else { -1.0 })
+            .collect();
+        assert!(level_db(&samples).abs() < 1e-4);
+        assert!(level_db(&[0.0; 100]) <= -119.0);
+    }
+}
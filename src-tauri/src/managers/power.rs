@@ -0,0 +1,304 @@
+//! Power source watcher and battery-aware behavior profile.
+//!
+//! Polls whether the machine runs on battery. When `battery_saver_enabled` is
+//! set and the machine is unplugged, transcription switches to the configured
+//! lighter model, streaming partials are skipped and the idle-unload timeout is
+//! lengthened so the model isn't reloaded as often.
+
+use anyhow::Result;
+use log::{debug, info, warn};
+use serde::Serialize;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+
+use super::model::ModelManager;
+use super::transcription::TranscriptionManager;
+use crate::settings::{get_settings, AppSettings};
+
+/// How often the power source is checked.
+const POLL_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Idle-unload timeouts are multiplied by this while on battery.
+const BATTERY_UNLOAD_MULTIPLIER: u64 = 3;
+
+#[derive(Clone, Debug, Serialize)]
+pub struct PowerSourceEvent {
+    pub on_battery: bool,
+    pub battery_profile_active: bool,
+}
+
+pub struct PowerManager {
+    app_handle: AppHandle,
+    on_battery: Arc<AtomicBool>,
+}
+
+impl PowerManager {
+    pub fn new(app_handle: &AppHandle) -> Result<Self> {
+        let on_battery = Arc::new(AtomicBool::new(detect_on_battery().unwrap_or(false)));
+
+        {
+            let app_handle = app_handle.clone();
+            let on_battery = on_battery.clone();
+            thread::spawn(move || loop {
+                thread::sleep(POLL_INTERVAL);
+
+                let Some(now_on_battery) = detect_on_battery() else {
+                    continue;
+                };
+                if on_battery.swap(now_on_battery, Ordering::SeqCst) != now_on_battery {
+                    handle_power_source_change(&app_handle, now_on_battery);
+                }
+            });
+        }
+
+        Ok(Self {
+            app_handle: app_handle.clone(),
+            on_battery,
+        })
+    }
+
+    pub fn is_on_battery(&self) -> bool {
+        self.on_battery.load(Ordering::SeqCst)
+    }
+
+    /// Whether the battery profile currently applies.
+    pub fn battery_profile_active(&self) -> bool {
+        get_settings(&self.app_handle).battery_saver_enabled && self.is_on_battery()
+    }
+}
+
+/// Whether the battery profile is active, for callers that only hold an `AppHandle`.
+pub fn battery_profile_active(app: &AppHandle) -> bool {
+    app.try_state::<Arc<PowerManager>>()
+        .is_some_and(|pm| pm.battery_profile_active())
+}
+
+/// The model that should be loaded for transcription under the current power source.
+pub fn effective_model_id(app: &AppHandle, settings: &AppSettings) -> String {
+    let battery_model = settings
+        .battery_model
+        .as_deref()
+        .filter(|id| is_model_downloaded(app, id));
+    choose_model(
+        &settings.selected_model,
+        battery_model,
+        battery_profile_active(app),
+    )
+    .to_string()
+}
+
+/// Idle-unload timeout in seconds, lengthened while the battery profile is active.
+pub fn effective_unload_seconds(app: &AppHandle, timeout_seconds: Option<u64>) -> Option<u64> {
+    scale_unload_seconds(timeout_seconds, battery_profile_active(app))
+}
+
+fn is_model_downloaded(app: &AppHandle, model_id: &str) -> bool {
+    app.try_state::<Arc<ModelManager>>()
+        .and_then(|mm| mm.get_model_info(model_id))
+        .is_some_and(|info| info.is_downloaded)
+}
+
+fn handle_power_source_change(app: &AppHandle, on_battery: bool) {
+    let settings = get_settings(app);
+    let profile_active = settings.battery_saver_enabled && on_battery;
+    info!(
+        "Power source changed: {} (battery profile {})",
+        if on_battery { "battery" } else { "AC" },
+        if profile_active { "active" } else { "inactive" }
+    );
+
+    let _ = app.emit(
+        "power-source-changed",
+        PowerSourceEvent {
+            on_battery,
+            battery_profile_active: profile_active,
+        },
+    );
+
+    if !settings.battery_saver_enabled {
+        return;
+    }
+
+    // Unload a model that no longer matches the profile; the next recording
+    // loads the right one.
+    let tm = app.state::<Arc<TranscriptionManager>>();
+    let wanted = effective_model_id(app, &settings);
+    if let Some(current) = tm.get_current_model() {
+        if current != wanted {
+            debug!(
+                "Unloading model '{}' so '{}' is used on the new power source",
+                current, wanted
+            );
+            if let Err(e) = tm.unload_model() {
+                warn!("Failed to unload model after power source change: {}", e);
+            }
+        }
+    }
+}
+
+fn choose_model<'a>(selected: &'a str, battery_model: Option<&'a str>, active: bool) -> &'a str {
+    match battery_model {
+        Some(id) if active && !id.is_empty() => id,
+        _ => selected,
+    }
+}
+
+fn scale_unload_seconds(timeout_seconds: Option<u64>, active: bool) -> Option<u64> {
+    match timeout_seconds {
+        // Immediate unloading is an explicit choice; leave it alone
+        Some(0) => Some(0),
+        Some(secs) if active => Some(secs.saturating_mul(BATTERY_UNLOAD_MULTIPLIER)),
+        other => other,
+    }
+}
+
+/// Returns `Some(true)` on battery, `Some(false)` on AC, `None` if unknown.
+fn detect_on_battery() -> Option<bool> {
+    #[cfg(target_os = "macos")]
+    {
+        let output = std::process::Command::new("pmset")
+            .args(["-g", "batt"])
+            .output()
+            .ok()?;
+        parse_pmset_source(&String::from_utf8_lossy(&output.stdout))
+    }
+    #[cfg(target_os = "windows")]
+    {
+        #[repr(C)]
+        struct SystemPowerStatus {
+            ac_line_status: u8,
+            battery_flag: u8,
+            battery_life_percent: u8,
+            system_status_flag: u8,
+            battery_life_time: u32,
+            battery_full_life_time: u32,
+        }
+
+        #[link(name = "kernel32")]
+        extern "system" {
+            fn GetSystemPowerStatus(status: *mut SystemPowerStatus) -> i32;
+        }
+
+        let mut status = SystemPowerStatus {
+            ac_line_status: 255,
+            battery_flag: 0,
+            battery_life_percent: 0,
+            system_status_flag: 0,
+            battery_life_time: 0,
+            battery_full_life_time: 0,
+        };
+        if unsafe { GetSystemPowerStatus(&mut status) } == 0 {
+            return None;
+        }
+        match status.ac_line_status {
+            0 => Some(true),
+            1 => Some(false),
+            _ => None,
+        }
+    }
+    #[cfg(target_os = "linux")]
+    {
+        let entries = std::fs::read_dir("/sys/class/power_supply").ok()?;
+        let supplies: Vec<(String, String)> = entries
+            .flatten()
+            .filter_map(|entry| {
+                let path = entry.path();
+                let kind = std::fs::read_to_string(path.join("type")).ok()?;
+                let online = std::fs::read_to_string(path.join("online")).unwrap_or_default();
+                Some((kind.trim().to_string(), online.trim().to_string()))
+            })
+            .collect();
+        classify_linux_supplies(&supplies)
+    }
+    #[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
+    {
+        None
+    }
+}
+
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+fn parse_pmset_source(output: &str) -> Option<bool> {
+    let first_line = output.lines().next()?;
+    if first_line.contains("'Battery Power'") {
+        Some(true)
+    } else if first_line.contains("'AC Power'") {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+/// Classify `(type, online)` pairs from `/sys/class/power_supply`. Desktops
+/// without a battery report AC.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn classify_linux_supplies(supplies: &[(String, String)]) -> Option<bool> {
+    let mains_online = supplies
+        .iter()
+        .any(|(kind, online)| (kind == "Mains" || kind == "USB") && online == "1");
+    let has_battery = supplies.iter().any(|(kind, _)| kind == "Battery");
+
+    if mains_online {
+        Some(false)
+    } else if has_battery {
+        Some(true)
+    } else if supplies.is_empty() {
+        None
+    } else {
+        Some(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pmset_output_is_parsed() {
+        assert_eq!(
+            parse_pmset_source("Now drawing from 'Battery Power'\n -InternalBattery-0"),
+            Some(true)
+        );
+        assert_eq!(
+            parse_pmset_source("Now drawing from 'AC Power'\n"),
+            Some(false)
+        );
+        assert_eq!(parse_pmset_source(""), None);
+    }
+
+    #[test]
+    fn linux_supplies_are_classified() {
+        let supply = |kind: &str, online: &str| (kind.to_string(), online.to_string());
+        assert_eq!(
+            classify_linux_supplies(&[supply("Mains", "1"), supply("Battery", "")]),
+            Some(false)
+        );
+        assert_eq!(
+            classify_linux_supplies(&[supply("Mains", "0"), supply("Battery", "")]),
+            Some(true)
+        );
+        assert_eq!(
+            classify_linux_supplies(&[supply("Battery", "")]),
+            Some(true)
+        );
+        assert_eq!(classify_linux_supplies(&[]), None);
+    }
+
+    #[test]
+    fn battery_model_used_only_when_profile_active() {
+        assert_eq!(choose_model("large", Some("small"), true), "small");
+        assert_eq!(choose_model("large", Some("small"), false), "large");
+        assert_eq!(choose_model("large", None, true), "large");
+        assert_eq!(choose_model("large", Some(""), true), "large");
+    }
+
+    #[test]
+    fn unload_timeout_lengthened_on_battery() {
+        assert_eq!(scale_unload_seconds(Some(300), true), Some(900));
+        assert_eq!(scale_unload_seconds(Some(300), false), Some(300));
+        assert_eq!(scale_unload_seconds(Some(0), true), Some(0));
+        assert_eq!(scale_unload_seconds(None, true), None);
+    }
+}
@@ -0,0 +1,129 @@
+//! Crash-safe journal of the recording in progress.
+//!
+//! While recording, the samples that go into the recording are also written
+//! to a temporary WAV in the recordings folder, whose header is brought up to
+//! date every few seconds. A stopped or cancelled recording deletes its
+//! journal, so one that's still there on startup is what was left by a crash
+//! and can be transcribed into history. Journals are deleted unread on a
+//! startup in privacy mode.
+
+use anyhow::{Context, Result};
+use hound::{WavSpec, WavWriter};
+use std::fs::{self, File};
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// Journal files are named `<prefix><unix millis>.wav`.
+const JOURNAL_PREFIX: &str = "recording-in-progress-";
+/// How often the WAV header is updated, bounding what a crash loses.
+const FLUSH_INTERVAL: Duration = Duration::from_secs(5);
+
+const SPEC: WavSpec = WavSpec {
+    channels: 1,
+    sample_rate: 16000,
+    bits_per_sample: 16,
+    sample_format: hound::SampleFormat::Int,
+};
+
+pub struct RecordingJournal {
+    path: PathBuf,
+    writer: WavWriter<BufWriter<File>>,
+    last_flush: Instant,
+}
+
+impl RecordingJournal {
+    pub fn create(dir: &Path) -> Result<Self> {
+        fs::create_dir_all(dir)?;
+        let path = dir.join(format!(
+            "{}{}.wav",
+            JOURNAL_PREFIX,
+            chrono::Utc::now().timestamp_millis()
+        ));
+        let writer = WavWriter::create(&path, SPEC)
+            .with_context(|| format!("Failed to create recording journal {:?}", path))?;
+        Ok(Self {
+            path,
+            writer,
+            last_flush: Instant::now(),
+        })
+    }
+
+    pub fn append(&mut self, samples: &[f32]) -> Result<()> {
+        for sample in samples {
+            self.writer
+                .write_sample((sample * i16::MAX as f32) as i16)?;
+        }
+        if self.last_flush.elapsed() >= FLUSH_INTERVAL {
+            self.writer.flush()?;
+            self.last_flush = Instant::now();
+        }
+        Ok(())
+    }
+
+    /// Delete the journal once the recording no longer needs it.
+    pub fn discard(self) {
+        let path = self.path.clone();
+        drop(self.writer);
+        if let Err(e) = fs::remove_file(&path) {
+            log::warn!("Failed to delete recording journal {:?}: {}", path, e);
+        }
+    }
+}
+
+/// Journals left behind by recordings that never stopped, oldest first.
+pub fn orphaned_journals(dir: &Path) -> Vec<PathBuf> {
+    let mut journals: Vec<PathBuf> = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok().map(|e| e.path()))
+                .filter(|path| is_journal(path))
+                .collect()
+        })
+        .unwrap_or_default();
+    journals.sort();
+    journals
+}
+
+/// Delete the journals left behind by recordings that never stopped.
+pub fn discard_orphaned_journals(dir: &Path) {
+    for path in orphaned_journals(dir) {
+        if let Err(e) = fs::remove_file(&path) {
+            log::warn!("Failed to delete recording journal {:?}: {}", path, e);
+        }
+    }
+}
+
+fn is_journal(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name.starts_with(JOURNAL_PREFIX) && name.ends_with(".wav"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flushed_journal_survives_without_finalizing() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path();
+        fs::write(dir.join("echo-1.wav"), b"").unwrap();
+
+        let mut journal = RecordingJournal::create(dir).unwrap();
+        journal.append(&[0.5; 1600]).unwrap();
+        journal.writer.flush().unwrap();
+        // A crash: the writer is never finalized
+        std::mem::forget(journal);
+
+        let orphans = orphaned_journals(dir);
+        assert_eq!(orphans.len(), 1);
+        let reader = hound::WavReader::open(&orphans[0]).unwrap();
+        assert_eq!(reader.len(), 1600);
+
+        // Only journals are deleted
+        discard_orphaned_journals(dir);
+        assert!(orphaned_journals(dir).is_empty());
+        assert!(dir.join("echo-1.wav").exists());
+    }
+}
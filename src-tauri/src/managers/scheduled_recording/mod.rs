@@ -0,0 +1,342 @@
+//! Scheduled recordings.
+//!
+//! A recording can be booked ahead of time (a webinar, a call you can't
+//! attend) with a start time, a duration and an audio source. A background
+//! scheduler starts it as a meeting when it's due, stops it when the time is
+//! up, and the meeting pipeline transcribes it. A summary is always generated
+//! afterwards, whether or not automatic meeting summaries are on. Recordings
+//! whose whole window passed while Echo wasn't running are marked missed.
+
+mod runner;
+
+use anyhow::{Context, Result};
+use log::{error, info, warn};
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use tauri::{AppHandle, Emitter};
+
+use super::database;
+use super::meeting::AudioSource;
+
+/// Longest recording that can be scheduled.
+const MAX_DURATION_SECS: i64 = 8 * 60 * 60;
+/// Start times this far in the past are still accepted ("start now").
+const START_GRACE_SECS: i64 = 60;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScheduleStatus {
+    Pending,
+    Recording,
+    Complete,
+    Missed,
+    Cancelled,
+    Failed,
+}
+
+impl ScheduleStatus {
+    fn as_str(self) -> &'static str {
+        match self {
+            ScheduleStatus::Pending => "pending",
+            ScheduleStatus::Recording => "recording",
+            ScheduleStatus::Complete => "complete",
+            ScheduleStatus::Missed => "missed",
+            ScheduleStatus::Cancelled => "cancelled",
+            ScheduleStatus::Failed => "failed",
+        }
+    }
+
+    fn from_str(s: &str) -> Self {
+        match s {
+            "pending" => ScheduleStatus::Pending,
+            "recording" => ScheduleStatus::Recording,
+            "complete" => ScheduleStatus::Complete,
+            "missed" => ScheduleStatus::Missed,
+            "cancelled" => ScheduleStatus::Cancelled,
+            _ => ScheduleStatus::Failed,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ScheduledRecording {
+    pub id: i64,
+    pub title: String,
+    /// Unix timestamp in seconds.
+    pub start_time: i64,
+    pub duration_secs: i64,
+    pub source: AudioSource,
+    pub status: ScheduleStatus,
+    /// The meeting holding the recording, once it has started.
+    pub meeting_id: Option<i64>,
+    pub error: Option<String>,
+    pub created_at: i64,
+}
+
+impl ScheduledRecording {
+    fn end_time(&self) -> i64 {
+        self.start_time + self.duration_secs
+    }
+}
+
+fn overlaps(a_start: i64, a_end: i64, b_start: i64, b_end: i64) -> bool {
+    a_start < b_end && b_start < a_end
+}
+
+pub struct ScheduledRecordingManager {
+    app_handle: AppHandle,
+    db_path: PathBuf,
+    /// Id of the recording in progress.
+    active: Mutex<Option<i64>>,
+    /// Set to end the recording in progress early.
+    stop_requested: AtomicBool,
+}
+
+impl ScheduledRecordingManager {
+    pub fn new(app_handle: &AppHandle) -> Result<Self> {
+        let app_data_dir = crate::profile::data_dir(app_handle)?;
+        let db_path = app_data_dir.join("history.db");
+
+        database::initialize_database(&db_path)
+            .context("Failed to initialize database for scheduled recordings")?;
+
+        let manager = Self {
+            app_handle: app_handle.clone(),
+            db_path,
+            active: Mutex::new(None),
+            stop_requested: AtomicBool::new(false),
+        };
+
+        // A recording still marked as running was cut short by a quit or crash
+        let interrupted = manager.get_connection()?.execute(
+            "UPDATE scheduled_recordings SET status = ?1, error = ?2 WHERE status = ?3",
+            params![
+                ScheduleStatus::Failed.as_str(),
+                "Echo was closed during the recording",
+                ScheduleStatus::Recording.as_str(),
+            ],
+        )?;
+        if interrupted > 0 {
+            warn!(
+                "[Schedule] Marked {} interrupted recording(s) as failed",
+                interrupted
+            );
+        }
+
+        let scheduler_handle = app_handle.clone();
+        thread::spawn(move || runner::run_scheduler(scheduler_handle));
+
+        Ok(manager)
+    }
+
+    fn get_connection(&self) -> Result<Connection> {
+        let conn = Connection::open(&self.db_path)
+            .with_context(|| format!("Failed to open database at {:?}", self.db_path))?;
+        conn.execute_batch("PRAGMA foreign_keys = ON;")
+            .context("Failed to enable foreign keys")?;
+        Ok(conn)
+    }
+
+    /// Book a recording. Rejects windows that overlap another pending or
+    /// running recording, since only one meeting can record at a time.
+    pub fn schedule(
+        &self,
+        title: Option<String>,
+        start_time: i64,
+        duration_secs: i64,
+        source: AudioSource,
+    ) -> Result<ScheduledRecording> {
+        let now = chrono::Utc::now().timestamp();
+        if duration_secs <= 0 || duration_secs > MAX_DURATION_SECS {
+            anyhow::bail!(
+                "Duration must be between 1 second and {} hours",
+                MAX_DURATION_SECS / 3600
+            );
+        }
+        if start_time < now - START_GRACE_SECS {
+            anyhow::bail!("Start time is in the past");
+        }
+        let end_time = start_time + duration_secs;
+
+        for other in self.list()? {
+            let booked = matches!(
+                other.status,
+                ScheduleStatus::Pending | ScheduleStatus::Recording
+            );
+            if booked && overlaps(start_time, end_time, other.start_time, other.end_time()) {
+                anyhow::bail!("Overlaps with scheduled recording '{}'", other.title);
+            }
+        }
+
+        let title = title
+            .map(|t| t.trim().to_string())
+            .filter(|t| !t.is_empty())
+            .unwrap_or_else(|| {
+                let start = chrono::DateTime::from_timestamp(start_time, 0)
+                    .map(|dt| dt.with_timezone(&chrono::Local))
+                    .unwrap_or_else(chrono::Local::now);
+                format!("Scheduled recording {}", start.format("%b %d, %H:%M"))
+            });
+
+        let conn = self.get_connection()?;
+        conn.execute(
+            "INSERT INTO scheduled_recordings (title, start_time, duration_secs, source, status, created_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![
+                title,
+                start_time,
+                duration_secs,
+                source.as_str(),
+                ScheduleStatus::Pending.as_str(),
+                now,
+            ],
+        )?;
+        let recording = self.get(conn.last_insert_rowid())?;
+
+        info!(
+            "[Schedule] Scheduled '{}' ({}) at {} for {}s from {}",
+            recording.title,
+            recording.id,
+            recording.start_time,
+            recording.duration_secs,
+            source.as_str()
+        );
+        self.emit_changed();
+        Ok(recording)
+    }
+
+    pub fn get(&self, id: i64) -> Result<ScheduledRecording> {
+        let conn = self.get_connection()?;
+        conn.query_row(
+            "SELECT id, title, start_time, duration_secs, source, status, meeting_id, error, created_at FROM scheduled_recordings WHERE id = ?1",
+            params![id],
+            row_to_recording,
+        )
+        .optional()?
+        .context("Scheduled recording not found")
+    }
+
+    /// All scheduled recordings, soonest first.
+    pub fn list(&self) -> Result<Vec<ScheduledRecording>> {
+        let conn = self.get_connection()?;
+        let mut stmt = conn.prepare(
+            "SELECT id, title, start_time, duration_secs, source, status, meeting_id, error, created_at FROM scheduled_recordings ORDER BY start_time ASC",
+        )?;
+        let recordings = stmt
+            .query_map([], row_to_recording)?
+            .collect::<Result<Vec<_>, _>>()
+            .context("Failed to query scheduled recordings")?;
+        Ok(recordings)
+    }
+
+    /// Cancel a pending recording, or end a running one early. A recording
+    /// ended early is still transcribed.
+    pub fn cancel(&self, id: i64) -> Result<()> {
+        if *self.active.lock().unwrap() == Some(id) {
+            info!("[Schedule] Ending recording {} early", id);
+            self.stop_requested.store(true, Ordering::SeqCst);
+            return Ok(());
+        }
+
+        let recording = self.get(id)?;
+        if recording.status != ScheduleStatus::Pending {
+            anyhow::bail!("Only upcoming recordings can be cancelled");
+        }
+        self.set_status(id, ScheduleStatus::Cancelled, None)?;
+        info!("[Schedule] Cancelled scheduled recording {}", id);
+        Ok(())
+    }
+
+    /// Remove a recording from the schedule. The meeting it produced is kept.
+    pub fn delete(&self, id: i64) -> Result<()> {
+        if *self.active.lock().unwrap() == Some(id) {
+            anyhow::bail!("Stop the recording before deleting it");
+        }
+        let conn = self.get_connection()?;
+        conn.execute(
+            "DELETE FROM scheduled_recordings WHERE id = ?1",
+            params![id],
+        )?;
+        self.emit_changed();
+        Ok(())
+    }
+
+    fn set_status(&self, id: i64, status: ScheduleStatus, error: Option<&str>) -> Result<()> {
+        let conn = self.get_connection()?;
+        conn.execute(
+            "UPDATE scheduled_recordings SET status = ?1, error = ?2 WHERE id = ?3",
+            params![status.as_str(), error, id],
+        )?;
+        self.emit_changed();
+        Ok(())
+    }
+
+    fn set_meeting(&self, id: i64, meeting_id: i64) -> Result<()> {
+        let conn = self.get_connection()?;
+        conn.execute(
+            "UPDATE scheduled_recordings SET meeting_id = ?1 WHERE id = ?2",
+            params![meeting_id, id],
+        )?;
+        Ok(())
+    }
+
+    fn record_failure(&self, id: i64, message: &str) {
+        if let Err(e) = self.set_status(id, ScheduleStatus::Failed, Some(message)) {
+            error!("[Schedule] Failed to update recording {}: {}", id, e);
+        }
+    }
+
+    fn emit_changed(&self) {
+        let _ = self.app_handle.emit("scheduled-recordings-changed", ());
+    }
+}
+
+fn row_to_recording(row: &rusqlite::Row) -> rusqlite::Result<ScheduledRecording> {
+    let source: String = row.get(4)?;
+    let status: String = row.get(5)?;
+    Ok(ScheduledRecording {
+        id: row.get(0)?,
+        title: row.get(1)?,
+        start_time: row.get(2)?,
+        duration_secs: row.get(3)?,
+        source: if source == AudioSource::System.as_str() {
+            AudioSource::System
+        } else {
+            AudioSource::Mic
+        },
+        status: ScheduleStatus::from_str(&status),
+        meeting_id: row.get(6)?,
+        error: row.get(7)?,
+        created_at: row.get(8)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn back_to_back_windows_do_not_overlap() {
+        assert!(!overlaps(0, 60, 60, 120));
+        assert!(overlaps(0, 61, 60, 120));
+        assert!(overlaps(10, 20, 0, 100));
+    }
+
+    #[test]
+    fn status_strings_round_trip() {
+        for status in [
+            ScheduleStatus::Pending,
+            ScheduleStatus::Recording,
+            ScheduleStatus::Complete,
+            ScheduleStatus::Missed,
+            ScheduleStatus::Cancelled,
+            ScheduleStatus::Failed,
+        ] {
+            assert_eq!(ScheduleStatus::from_str(status.as_str()), status);
+        }
+        assert_eq!(ScheduleStatus::from_str("bogus"), ScheduleStatus::Failed);
+    }
+}
@@ -0,0 +1,203 @@
+//! Starting due recordings and running them to the end.
+
+use log::{debug, error, info, warn};
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+
+use super::{ScheduleStatus, ScheduledRecording, ScheduledRecordingManager};
+use crate::managers::meeting::MeetingManager;
+use crate::managers::scheduler;
+use crate::settings;
+
+/// How often the scheduler checks for due recordings.
+const SCHEDULE_INTERVAL: Duration = Duration::from_secs(5);
+/// How often a running recording checks for cancellation.
+const RECORDING_POLL: Duration = Duration::from_secs(1);
+
+/// What the scheduler should do with a pending recording at `now`.
+#[derive(Debug, PartialEq)]
+enum Due {
+    Wait,
+    Start,
+    Missed,
+}
+
+fn due(recording: &ScheduledRecording, now: i64) -> Due {
+    if now < recording.start_time {
+        Due::Wait
+    } else if now >= recording.end_time() {
+        Due::Missed
+    } else {
+        Due::Start
+    }
+}
+
+impl ScheduledRecordingManager {
+    /// Start, record and stop one recording. Blocks for its whole duration.
+    fn run(&self, recording: &ScheduledRecording) {
+        let Some(meeting_id) = self.start(recording) else {
+            return;
+        };
+        while chrono::Utc::now().timestamp() < recording.end_time()
+            && !self.stop_requested.load(Ordering::SeqCst)
+        {
+            thread::sleep(RECORDING_POLL);
+        }
+        self.finish(recording, meeting_id);
+    }
+
+    /// Start `recording` as a meeting, returning the meeting's id.
+    fn start(&self, recording: &ScheduledRecording) -> Option<i64> {
+        let meeting_manager = self.app_handle.state::<Arc<MeetingManager>>();
+        self.stop_requested.store(false, Ordering::SeqCst);
+
+        let started = tauri::async_runtime::block_on(
+            meeting_manager
+                .start_meeting_from(Some(recording.title.clone()), recording.source.clone()),
+        );
+        let meeting_id = match started {
+            Ok(meeting_id) => meeting_id,
+            Err(e) => {
+                error!(
+                    "[Schedule] Failed to start recording {}: {}",
+                    recording.id, e
+                );
+                self.record_failure(recording.id, &e.to_string());
+                return None;
+            }
+        };
+
+        *self.active.lock().unwrap() = Some(recording.id);
+        if let Err(e) = self
+            .set_meeting(recording.id, meeting_id)
+            .and_then(|_| self.set_status(recording.id, ScheduleStatus::Recording, None))
+        {
+            error!(
+                "[Schedule] Failed to update recording {}: {}",
+                recording.id, e
+            );
+        }
+        info!(
+            "[Schedule] Recording '{}' ({}) started as meeting {}",
+            recording.title, recording.id, meeting_id
+        );
+        Some(meeting_id)
+    }
+
+    /// Stop the meeting `recording` runs as, and summarize it.
+    fn finish(&self, recording: &ScheduledRecording, meeting_id: i64) {
+        let meeting_manager = self.app_handle.state::<Arc<MeetingManager>>();
+        let result = tauri::async_runtime::block_on(async {
+            meeting_manager.stop_meeting().await?;
+            // Auto summary already ran inside stop_meeting when it's on
+            if !settings::get_settings(&self.app_handle).meeting_auto_summary {
+                if let Err(e) = meeting_manager.generate_summary(meeting_id).await {
+                    warn!(
+                        "[Schedule] Failed to summarize meeting {}: {}",
+                        meeting_id, e
+                    );
+                }
+            }
+            anyhow::Ok(())
+        });
+        *self.active.lock().unwrap() = None;
+
+        match result {
+            Ok(()) => {
+                info!("[Schedule] Recording {} complete", recording.id);
+                if let Err(e) = self.set_status(recording.id, ScheduleStatus::Complete, None) {
+                    error!(
+                        "[Schedule] Failed to update recording {}: {}",
+                        recording.id, e
+                    );
+                }
+                let _ = self
+                    .app_handle
+                    .emit("scheduled-recording-complete", meeting_id);
+            }
+            Err(e) => {
+                error!(
+                    "[Schedule] Failed to finish recording {}: {}",
+                    recording.id, e
+                );
+                self.record_failure(recording.id, &e.to_string());
+            }
+        }
+    }
+}
+
+/// Start due recordings one at a time, in order of start time.
+pub(super) fn run_scheduler(app_handle: AppHandle) {
+    scheduler::run_every(
+        &app_handle,
+        SCHEDULE_INTERVAL,
+        |manager: &ScheduledRecordingManager| {
+            let pending = match manager.list() {
+                Ok(recordings) => recordings
+                    .into_iter()
+                    .filter(|r| r.status == ScheduleStatus::Pending),
+                Err(e) => {
+                    error!("[Schedule] Failed to list scheduled recordings: {}", e);
+                    return;
+                }
+            };
+
+            for recording in pending {
+                // Recordings run inline, so check against the current time
+                match due(&recording, chrono::Utc::now().timestamp()) {
+                    Due::Wait => break,
+                    Due::Missed => {
+                        warn!(
+                            "[Schedule] Missed recording '{}' ({})",
+                            recording.title, recording.id
+                        );
+                        if let Err(e) =
+                            manager.set_status(recording.id, ScheduleStatus::Missed, None)
+                        {
+                            error!(
+                                "[Schedule] Failed to update recording {}: {}",
+                                recording.id, e
+                            );
+                        }
+                    }
+                    Due::Start => {
+                        debug!("[Schedule] Recording {} is due", recording.id);
+                        manager.run(&recording);
+                    }
+                }
+            }
+        },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::managers::meeting::AudioSource;
+
+    fn recording(start_time: i64, duration_secs: i64) -> ScheduledRecording {
+        ScheduledRecording {
+            id: 1,
+            title: "Webinar".to_string(),
+            start_time,
+            duration_secs,
+            source: AudioSource::System,
+            status: ScheduleStatus::Pending,
+            meeting_id: None,
+            error: None,
+            created_at: 0,
+        }
+    }
+
+    #[test]
+    fn due_covers_the_recording_window() {
+        let r = recording(1_000, 600);
+        assert_eq!(due(&r, 999), Due::Wait);
+        assert_eq!(due(&r, 1_000), Due::Start);
+        assert_eq!(due(&r, 1_599), Due::Start);
+        assert_eq!(due(&r, 1_600), Due::Missed);
+    }
+}
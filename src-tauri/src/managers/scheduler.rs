@@ -0,0 +1,25 @@
+//! Background loops shared by the managers that act on a timer.
+
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+
+/// Call `tick` with the managed `T` every `interval`, on the current thread,
+/// for as long as the app runs.
+///
+/// Schedulers are spawned from their manager's constructor, so they start
+/// before `app.manage()` registers the manager. Ticks until then are skipped
+/// and the lookup is retried on the next one.
+pub fn run_every<T, F>(app_handle: &AppHandle, interval: Duration, mut tick: F)
+where
+    T: Send + Sync + 'static,
+    F: FnMut(&T),
+{
+    loop {
+        thread::sleep(interval);
+        if let Some(manager) = app_handle.try_state::<Arc<T>>() {
+            tick(&manager);
+        }
+    }
+}
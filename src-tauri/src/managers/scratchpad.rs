@@ -0,0 +1,143 @@
+//! Scratchpad dictation destination.
+//!
+//! While the scratchpad window is open, finished dictations are appended to a
+//! text buffer instead of being pasted into the focused app, so long texts
+//! can be composed safely and copied out in one go. The buffer is kept on disk
+//! and survives restarts.
+
+use anyhow::{Context, Result};
+use log::{debug, error};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter, Manager, WebviewWindowBuilder};
+use tauri_plugin_clipboard_manager::ClipboardExt;
+
+pub const SCRATCHPAD_WINDOW_LABEL: &str = "scratchpad";
+
+pub struct ScratchpadManager {
+    app_handle: AppHandle,
+    path: PathBuf,
+    content: Mutex<String>,
+}
+
+impl ScratchpadManager {
+    pub fn new(app_handle: &AppHandle) -> Result<Self> {
+        let path = crate::profile::data_dir(app_handle)?.join("scratchpad.txt");
+        let content = if path.exists() {
+            fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read scratchpad at {:?}", path))?
+        } else {
+            String::new()
+        };
+
+        Ok(Self {
+            app_handle: app_handle.clone(),
+            path,
+            content: Mutex::new(content),
+        })
+    }
+
+    /// Whether dictations currently go to the scratchpad.
+    pub fn is_active(&self) -> bool {
+        self.app_handle
+            .get_webview_window(SCRATCHPAD_WINDOW_LABEL)
+            .is_some()
+    }
+
+    /// Open the scratchpad window, which makes it the dictation destination.
+    pub fn open(&self) -> Result<()> {
+        if let Some(window) = self.app_handle.get_webview_window(SCRATCHPAD_WINDOW_LABEL) {
+            let _ = window.show();
+            let _ = window.set_focus();
+            return Ok(());
+        }
+        WebviewWindowBuilder::new(
+            &self.app_handle,
+            SCRATCHPAD_WINDOW_LABEL,
+            tauri::WebviewUrl::App("src/scratchpad/index.html".into()),
+        )
+        .title("Scratchpad")
+        .inner_size(520.0, 420.0)
+        .min_inner_size(320.0, 200.0)
+        .build()
+        .context("Failed to create scratchpad window")?;
+        Ok(())
+    }
+
+    pub fn content(&self) -> String {
+        self.content.lock().unwrap().clone()
+    }
+
+    /// Add a dictation to the end of the buffer.
+    pub fn append(&self, text: &str) -> String {
+        self.update(|content| append_text(content, text))
+    }
+
+    /// Replace the whole buffer, e.g. after edits in the scratchpad window.
+    pub fn replace(&self, text: String) -> String {
+        self.update(|content| *content = text)
+    }
+
+    /// Copy the buffer to the clipboard, optionally clearing it afterwards.
+    pub fn copy_out(&self, clear: bool) -> Result<()> {
+        let content = self.content();
+        self.app_handle
+            .clipboard()
+            .write_text(content)
+            .context("Failed to copy scratchpad to clipboard")?;
+        if clear {
+            self.replace(String::new());
+        }
+        Ok(())
+    }
+
+    fn update(&self, change: impl FnOnce(&mut String)) -> String {
+        let content = {
+            let mut content = self.content.lock().unwrap();
+            change(&mut content);
+            if let Err(e) = fs::write(&self.path, content.as_str()) {
+                error!("Failed to save scratchpad: {}", e);
+            }
+            content.clone()
+        };
+        debug!("Scratchpad updated ({} chars)", content.chars().count());
+        let _ = self.app_handle.emit("scratchpad-updated", &content);
+        content
+    }
+}
+
+/// Append `text` as a new sentence, separated from existing text by a space
+/// unless the buffer already ends with whitespace.
+fn append_text(content: &mut String, text: &str) {
+    let text = text.trim();
+    if text.is_empty() {
+        return;
+    }
+    if !content.is_empty() && !content.ends_with(char::is_whitespace) {
+        content.push(' ');
+    }
+    content.push_str(text);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn append_separates_dictations() {
+        let mut content = String::new();
+        append_text(&mut content, " Hello there. ");
+        assert_eq!(content, "Hello there.");
+
+        append_text(&mut content, "Second sentence.");
+        assert_eq!(content, "Hello there. Second sentence.");
+
+        content.push('\n');
+        append_text(&mut content, "New paragraph.");
+        assert_eq!(content, "Hello there. Second sentence.\nNew paragraph.");
+
+        append_text(&mut content, "   ");
+        assert_eq!(content, "Hello there. Second sentence.\nNew paragraph.");
+    }
+}
@@ -0,0 +1,359 @@
+//! Text post-processing plugins compiled to WebAssembly.
+//!
+//! A plugin is a folder under `plugins/` in the app data directory with a
+//! `manifest.json` and a WebAssembly module. Enabled plugins run in order on
+//! every dictation, after AI post-processing and before the text is pasted.
+//! Only the pasted text changes: history and read-back keep the
+//! post-processed text.
+//!
+//! Modules run sandboxed: they can't import anything, so they have no access
+//! to files, the network or the clock, and each call is limited in memory and
+//! in the instructions it may execute. A plugin that fails or runs out of
+//! either is skipped and the text passed on unchanged.
+//!
+//! A module exports its `memory` and two functions:
+//!
+//! - `alloc(len: i32) -> i32` returns a buffer of `len` bytes for the input;
+//! - `transform(ptr: i32, len: i32) -> i64` reads the input and returns the
+//!   output as its pointer in the upper 32 bits and its length in the lower.
+//!
+//! The input is a UTF-8 JSON object with the `text` and the extra inputs the
+//! manifest asks for, like `{"text": "...", "language": "en"}`. The output is
+//! the transformed text, as UTF-8.
+
+use anyhow::{anyhow, bail, Context, Result};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use tauri::{AppHandle, Emitter, Manager};
+use wasmi::{Engine, Module};
+
+use crate::managers::input_tracker::get_active_app_info_fast;
+use crate::profile;
+use crate::settings::{self, AppSettings};
+
+mod sandbox;
+
+use sandbox::{compile, run_sandboxed, sandbox_engine};
+
+const PLUGINS_DIR: &str = "plugins";
+const MANIFEST_FILE: &str = "manifest.json";
+/// Instructions a plugin may execute per dictation, roughly a few hundred
+/// milliseconds of work.
+const FUEL_PER_CALL: u64 = 500_000_000;
+
+/// What a plugin is given besides the text.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PluginInput {
+    /// Spoken language as an ISO 639-1 code, when known.
+    Language,
+    /// Name of the app the text will be pasted into.
+    App,
+}
+
+/// What a plugin returns.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PluginOutput {
+    /// Text that replaces the dictation.
+    #[default]
+    Text,
+}
+
+/// Contents of a plugin's `manifest.json`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PluginManifest {
+    /// Folder name of the installed plugin; letters, digits, `-` and `_`.
+    pub id: String,
+    pub name: String,
+    pub version: String,
+    #[serde(default)]
+    pub description: String,
+    /// Module file, relative to the manifest.
+    #[serde(default = "default_module")]
+    pub module: String,
+    #[serde(default)]
+    pub inputs: Vec<PluginInput>,
+    #[serde(default)]
+    pub output: PluginOutput,
+}
+
+fn default_module() -> String {
+    "plugin.wasm".to_string()
+}
+
+/// An installed plugin as shown in the settings.
+#[derive(Clone, Debug, Serialize)]
+pub struct PluginInfo {
+    #[serde(flatten)]
+    pub manifest: PluginManifest,
+    pub enabled: bool,
+}
+
+struct Plugin {
+    manifest: PluginManifest,
+    module: Module,
+}
+
+pub struct TextPluginManager {
+    app_handle: AppHandle,
+    engine: Engine,
+    dir: PathBuf,
+    plugins: Mutex<Vec<Arc<Plugin>>>,
+}
+
+impl TextPluginManager {
+    pub fn new(app_handle: &AppHandle) -> Result<Self> {
+        let dir = app_handle.path().app_data_dir()?.join(PLUGINS_DIR);
+        fs::create_dir_all(&dir)
+            .with_context(|| format!("Failed to create plugins directory at {:?}", dir))?;
+
+        let manager = Self {
+            app_handle: app_handle.clone(),
+            engine: sandbox_engine(),
+            dir,
+            plugins: Mutex::new(Vec::new()),
+        };
+        manager.reload();
+        Ok(manager)
+    }
+
+    /// Load every plugin in the plugins directory again, skipping broken ones.
+    pub fn reload(&self) {
+        let mut plugins = Vec::new();
+        if let Ok(entries) = fs::read_dir(&self.dir) {
+            for entry in entries.flatten() {
+                let path = entry.path();
+                if !path.is_dir() {
+                    continue;
+                }
+                match self.load(&path) {
+                    Ok(plugin) => plugins.push(Arc::new(plugin)),
+                    Err(e) => warn!("Skipping plugin in {:?}: {:#}", path, e),
+                }
+            }
+        }
+        plugins.sort_by(|a, b| a.manifest.name.cmp(&b.manifest.name));
+        info!("Loaded {} text plugins", plugins.len());
+        *self.plugins.lock().unwrap() = plugins;
+    }
+
+    /// Folder plugins are installed in.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn list(&self) -> Vec<PluginInfo> {
+        let enabled = settings::get_settings(&self.app_handle).text_plugins;
+        self.plugins
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|plugin| PluginInfo {
+                manifest: plugin.manifest.clone(),
+                enabled: enabled.contains(&plugin.manifest.id),
+            })
+            .collect()
+    }
+
+    /// Install the plugin whose manifest is `source`, or is in the folder
+    /// `source`, replacing an installed one with the same id.
+    pub fn install(&self, source: &Path) -> Result<PluginManifest> {
+        let source_dir = if source.is_dir() {
+            source
+        } else {
+            source
+                .parent()
+                .ok_or_else(|| anyhow!("No plugin folder for {:?}", source))?
+        };
+        let plugin = self.load(source_dir)?;
+        let manifest = plugin.manifest;
+
+        let target = self.dir.join(&manifest.id);
+        if target == source_dir {
+            self.reload();
+            return Ok(manifest);
+        }
+        if target.exists() {
+            fs::remove_dir_all(&target)?;
+        }
+        fs::create_dir_all(&target)?;
+        fs::copy(source_dir.join(MANIFEST_FILE), target.join(MANIFEST_FILE))?;
+        fs::copy(
+            source_dir.join(&manifest.module),
+            target.join(&manifest.module),
+        )?;
+
+        info!("Installed plugin {} {}", manifest.id, manifest.version);
+        self.reload();
+        self.emit_changed();
+        Ok(manifest)
+    }
+
+    pub fn remove(&self, id: &str) -> Result<()> {
+        if !profile::is_valid_name(id) {
+            bail!("Invalid plugin id: {}", id);
+        }
+        let target = self.dir.join(id);
+        if !target.exists() {
+            bail!("Plugin not found: {}", id);
+        }
+        fs::remove_dir_all(&target)
+            .with_context(|| format!("Failed to remove plugin at {:?}", target))?;
+        settings::update_settings(&self.app_handle, |s| {
+            s.text_plugins.retain(|enabled| enabled != id);
+        });
+
+        info!("Removed plugin {}", id);
+        self.reload();
+        self.emit_changed();
+        Ok(())
+    }
+
+    /// Enable or disable a plugin. Newly enabled plugins run last.
+    pub fn set_enabled(&self, id: &str, enabled: bool) -> Result<()> {
+        if !self
+            .plugins
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|plugin| plugin.manifest.id == id)
+        {
+            bail!("Plugin not found: {}", id);
+        }
+        settings::update_settings(&self.app_handle, |s| {
+            s.text_plugins.retain(|other| other != id);
+            if enabled {
+                s.text_plugins.push(id.to_string());
+            }
+        });
+        self.emit_changed();
+        Ok(())
+    }
+
+    /// `text` after every enabled plugin, or None when none changed it.
+    /// Blocks while the plugins run.
+    pub fn transform(
+        &self,
+        settings: &AppSettings,
+        text: &str,
+        language: Option<&str>,
+    ) -> Option<String> {
+        if settings.text_plugins.is_empty() {
+            return None;
+        }
+        // Taken out of the lock so a slow plugin doesn't hold up a reload
+        let enabled: Vec<Arc<Plugin>> = {
+            let plugins = self.plugins.lock().unwrap();
+            settings
+                .text_plugins
+                .iter()
+                .filter_map(|id| plugins.iter().find(|plugin| &plugin.manifest.id == id))
+                .cloned()
+                .collect()
+        };
+        let app = enabled
+            .iter()
+            .any(|plugin| plugin.manifest.inputs.contains(&PluginInput::App))
+            .then(|| get_active_app_info_fast().name);
+
+        let mut current = text.to_string();
+        for plugin in &enabled {
+            let input = plugin_input(&plugin.manifest, &current, language, app.as_deref());
+            match self.call(plugin, &input) {
+                Ok(output) => current = output,
+                Err(e) => warn!("Plugin {} failed, skipping it: {:#}", plugin.manifest.id, e),
+            }
+        }
+        (current != text).then_some(current)
+    }
+
+    /// Run one plugin on `text`, enabled or not, to try it out.
+    pub fn run(&self, id: &str, text: &str) -> Result<String> {
+        let plugin = self
+            .plugins
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|plugin| plugin.manifest.id == id)
+            .cloned()
+            .ok_or_else(|| anyhow!("Plugin not found: {}", id))?;
+        self.call(&plugin, &plugin_input(&plugin.manifest, text, None, None))
+    }
+
+    fn load(&self, dir: &Path) -> Result<Plugin> {
+        let manifest_path = dir.join(MANIFEST_FILE);
+        let json = fs::read_to_string(&manifest_path)
+            .with_context(|| format!("Failed to read {:?}", manifest_path))?;
+        let manifest: PluginManifest =
+            serde_json::from_str(&json).context("Invalid plugin manifest")?;
+        if !profile::is_valid_name(&manifest.id) {
+            bail!("Invalid plugin id: {}", manifest.id);
+        }
+        // The module must sit next to the manifest
+        if Path::new(&manifest.module).components().count() != 1 {
+            bail!(
+                "Plugin module must be in the plugin folder: {}",
+                manifest.module
+            );
+        }
+
+        let wasm = fs::read(dir.join(&manifest.module))
+            .with_context(|| format!("Failed to read plugin module {}", manifest.module))?;
+        let module = compile(&self.engine, &wasm)
+            .with_context(|| format!("Can't load plugin module {}", manifest.module))?;
+        Ok(Plugin { manifest, module })
+    }
+
+    fn call(&self, plugin: &Plugin, input: &str) -> Result<String> {
+        run_sandboxed(&self.engine, &plugin.module, input, FUEL_PER_CALL)
+    }
+
+    fn emit_changed(&self) {
+        let _ = self.app_handle.emit("text-plugins-changed", ());
+    }
+}
+
+/// The JSON a plugin is called with: the text and the inputs it declares.
+fn plugin_input(
+    manifest: &PluginManifest,
+    text: &str,
+    language: Option<&str>,
+    app: Option<&str>,
+) -> String {
+    let mut input = serde_json::Map::new();
+    input.insert("text".into(), text.into());
+    for kind in &manifest.inputs {
+        let (key, value) = match kind {
+            PluginInput::Language => ("language", language),
+            PluginInput::App => ("app", app),
+        };
+        input.insert(key.into(), value.into());
+    }
+    serde_json::Value::Object(input).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plugins_get_only_the_inputs_they_declare() {
+        let manifest: PluginManifest = serde_json::from_str(
+            r#"{"id": "emoji", "name": "Emoji", "version": "1.0", "inputs": ["language"]}"#,
+        )
+        .unwrap();
+        assert_eq!(manifest.module, "plugin.wasm");
+        assert_eq!(manifest.output, PluginOutput::Text);
+
+        let input = plugin_input(&manifest, "hi \"there\"", Some("en"), Some("Slack"));
+        let input: serde_json::Value = serde_json::from_str(&input).unwrap();
+        assert_eq!(
+            input,
+            serde_json::json!({"text": "hi \"there\"", "language": "en"})
+        );
+    }
+}
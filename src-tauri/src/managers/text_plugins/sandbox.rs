@@ -0,0 +1,162 @@
+//! Compiling and running plugin modules in the WebAssembly sandbox.
+
+use anyhow::{anyhow, bail, Context, Result};
+use wasmi::{Config, Engine, Linker, Module, Store, StoreLimits, StoreLimitsBuilder};
+
+/// Linear memory a plugin may grow to.
+const MAX_MEMORY_BYTES: usize = 64 * 1024 * 1024;
+/// Longest text a plugin may return.
+const MAX_OUTPUT_BYTES: usize = 1024 * 1024;
+
+/// Per-call state of a plugin's store.
+struct Sandbox {
+    limits: StoreLimits,
+}
+
+/// Engine plugins run on, which counts the instructions they execute.
+pub fn sandbox_engine() -> Engine {
+    let mut config = Config::default();
+    config.consume_fuel(true);
+    Engine::new(&config)
+}
+
+/// Compile a plugin module, refusing one that imports anything.
+pub fn compile(engine: &Engine, wasm: &[u8]) -> Result<Module> {
+    let module = Module::new(engine, wasm).context("Invalid WebAssembly")?;
+    if module.imports().next().is_some() {
+        bail!("The module imports host functions, which aren't available");
+    }
+    Ok(module)
+}
+
+/// Instantiate `module` in a fresh store and transform `input` with it,
+/// executing at most `fuel` instructions.
+pub fn run_sandboxed(engine: &Engine, module: &Module, input: &str, fuel: u64) -> Result<String> {
+    let limits = StoreLimitsBuilder::new()
+        .memory_size(MAX_MEMORY_BYTES)
+        .build();
+    let mut store = Store::new(engine, Sandbox { limits });
+    store.limiter(|sandbox| &mut sandbox.limits);
+    store.set_fuel(fuel)?;
+
+    let instance = Linker::<Sandbox>::new(engine)
+        .instantiate(&mut store, module)?
+        .start(&mut store)?;
+    let memory = instance
+        .get_memory(&store, "memory")
+        .ok_or_else(|| anyhow!("Plugin doesn't export its memory"))?;
+    let alloc = instance.get_typed_func::<i32, i32>(&store, "alloc")?;
+    let transform = instance.get_typed_func::<(i32, i32), i64>(&store, "transform")?;
+
+    let len = i32::try_from(input.len()).context("Text too long for a plugin")?;
+    let ptr = alloc.call(&mut store, len)?;
+    memory
+        .write(&mut store, ptr as u32 as usize, input.as_bytes())
+        .map_err(|e| anyhow!("Failed to pass the text to the plugin: {}", e))?;
+
+    let (out_ptr, out_len) = unpack_output(transform.call(&mut store, (ptr, len))?);
+    if out_len > MAX_OUTPUT_BYTES {
+        bail!("Plugin returned {} bytes, more than allowed", out_len);
+    }
+    let mut output = vec![0; out_len];
+    memory
+        .read(&store, out_ptr, &mut output)
+        .map_err(|e| anyhow!("Failed to read the plugin's output: {}", e))?;
+    String::from_utf8(output).context("Plugin returned invalid UTF-8")
+}
+
+/// Pointer and length of a plugin's output, packed into one `i64`.
+fn unpack_output(packed: i64) -> (usize, usize) {
+    let packed = packed as u64;
+    ((packed >> 32) as usize, (packed & 0xffff_ffff) as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Enough for the test modules (growing memory costs fuel too), and quick
+    /// to run out of.
+    const TEST_FUEL: u64 = 10_000_000;
+
+    /// A module whose `transform` runs `body` and then hands the input back.
+    fn echo_module(memory_pages: u32, body: &str) -> String {
+        format!(
+            r#"(module
+                (memory (export "memory") {memory_pages})
+                (func (export "alloc") (param i32) (result i32) (i32.const 1024))
+                (func (export "transform") (param $ptr i32) (param $len i32) (result i64)
+                    {body}
+                    (i64.or
+                        (i64.shl (i64.extend_i32_u (local.get $ptr)) (i64.const 32))
+                        (i64.extend_i32_u (local.get $len)))))"#
+        )
+    }
+
+    fn run_wat(wat: &str, input: &str) -> Result<String> {
+        let engine = sandbox_engine();
+        let module = compile(&engine, &wat::parse_str(wat).unwrap())?;
+        run_sandboxed(&engine, &module, input, TEST_FUEL)
+    }
+
+    #[test]
+    fn text_round_trips_through_a_plugin() {
+        let input = r#"{"text": "héllo wörld"}"#;
+        assert_eq!(run_wat(&echo_module(1, ""), input).unwrap(), input);
+    }
+
+    #[test]
+    fn running_out_of_fuel_stops_an_endless_loop() {
+        let wat = echo_module(1, "(loop $forever (br $forever))");
+        let err = run_wat(&wat, "{}").unwrap_err();
+        assert!(format!("{err:#}").contains("fuel"), "{err:#}");
+    }
+
+    #[test]
+    fn memory_cannot_grow_past_the_limit() {
+        // Traps if the growth is refused
+        let grow = |pages: usize| {
+            echo_module(
+                1,
+                &format!(
+                    "(if (i32.eq (memory.grow (i32.const {pages})) (i32.const -1)) (then unreachable))"
+                ),
+            )
+        };
+        let page = 64 * 1024;
+        assert!(run_wat(&grow(MAX_MEMORY_BYTES / page / 2), "{}").is_ok());
+        let err = run_wat(&grow(MAX_MEMORY_BYTES / page), "{}").unwrap_err();
+        assert!(format!("{err:#}").contains("unreachable"), "{err:#}");
+    }
+
+    #[test]
+    fn modules_with_imports_are_refused() {
+        let wat = r#"(module
+            (import "env" "now" (func (result i64)))
+            (memory (export "memory") 1))"#;
+        let engine = sandbox_engine();
+        let err = compile(&engine, &wat::parse_str(wat).unwrap()).unwrap_err();
+        assert!(err.to_string().contains("imports"), "{err}");
+    }
+
+    #[test]
+    fn oversized_output_is_rejected() {
+        // Claims one byte more than allowed, all of it inside its memory
+        let wat = format!(
+            r#"(module
+                (memory (export "memory") 32)
+                (func (export "alloc") (param i32) (result i32) (i32.const 0))
+                (func (export "transform") (param i32 i32) (result i64)
+                    (i64.const {})))"#,
+            MAX_OUTPUT_BYTES + 1
+        );
+        let err = run_wat(&wat, "{}").unwrap_err();
+        assert!(err.to_string().contains("more than allowed"), "{err}");
+    }
+
+    #[test]
+    fn output_pointer_and_length_are_unpacked() {
+        assert_eq!(unpack_output((4096 << 32) | 12), (4096, 12));
+        assert_eq!(unpack_output(-1), (0xffff_ffff, 0xffff_ffff));
+    }
+}
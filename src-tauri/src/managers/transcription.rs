@@ -1,23 +1,34 @@
-use crate::audio_toolkit::apply_custom_words;
-use crate::managers::model::{EngineType, ModelManager};
-use crate::settings::{get_settings, ModelUnloadTimeout};
+use crate::audio_toolkit::backend::{self, InferenceBackend};
+use crate::audio_toolkit::thread_priority::{apply_current_thread_priority, ThreadPriority};
+use crate::audio_toolkit::transcriber::{
+    build_transcript, EngineKind, InferenceOptions, LocalEngine, VAD_TRIM_PADDING_FRAMES,
+    VAD_TRIM_THRESHOLD,
+};
+use crate::audio_toolkit::vad::{trim_silence_mapped, SileroVad, Trimmed};
+use crate::cloud_transcription::{self, CloudError};
+use crate::helpers::{
+    initial_prompt, language_detect, locale_format, profanity, punctuation, text_normalization,
+    transcription_confidence,
+};
+use crate::managers::model::{EngineType, ModelInfo, ModelManager};
+use crate::managers::power;
+use crate::managers::transcription_queue::{
+    JobCancelled, JobInfo, JobPriority, JobState, TranscriptionJob, TranscriptionQueue,
+};
+use crate::settings::{get_settings, AppSettings, ModelUnloadTimeout, ProfanityFilter};
 use anyhow::Result;
+use chrono::{Local, Timelike};
 use log::{debug, error, info, warn};
 use serde::Serialize;
+use std::ops::Range;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, Condvar, Mutex};
 use std::thread;
 use std::time::{Duration, SystemTime};
-use tauri::{AppHandle, Emitter};
-use transcribe_rs::{
-    engines::{
-        parakeet::{
-            ParakeetEngine, ParakeetInferenceParams, ParakeetModelParams, TimestampGranularity,
-        },
-        whisper::{WhisperEngine, WhisperInferenceParams},
-    },
-    TranscriptionEngine,
-};
+use tauri::{AppHandle, Emitter, Manager};
+use transcribe_rs::TranscriptionResult;
+
+pub use crate::audio_toolkit::transcriber::{TimedSegment, Transcript};
 
 #[derive(Clone, Debug, Serialize)]
 pub struct ModelStateEvent {
@@ -28,8 +39,409 @@ pub struct ModelStateEvent {
 }
 
 enum LoadedEngine {
-    Whisper(WhisperEngine),
-    Parakeet(ParakeetEngine),
+    Local(LocalEngine),
+    Remote(RemoteEngine),
+}
+
+/// Cloud transcription. The local model is only loaded once the provider
+/// fails to answer.
+struct RemoteEngine {
+    fallback_model_id: String,
+    fallback: Option<LocalEngine>,
+}
+
+/// The smaller model live previews run on, when one is set.
+struct PreviewEngine {
+    model_id: String,
+    engine: LocalEngine,
+}
+
+impl LoadedEngine {
+    fn unload(&mut self) {
+        match self {
+            LoadedEngine::Local(local) => local.unload(),
+            LoadedEngine::Remote(remote) => {
+                if let Some(fallback) = remote.fallback.as_mut() {
+                    fallback.unload();
+                }
+            }
+        }
+    }
+}
+
+/// Inference options for the current settings.
+fn inference_options(settings: &AppSettings, initial_prompt: Option<String>) -> InferenceOptions {
+    InferenceOptions {
+        language: settings.transcription_language().map(str::to_string),
+        translate: settings.translate_to_english,
+        initial_prompt,
+        custom_words: settings.custom_words.clone(),
+        word_correction_threshold: settings.word_correction_threshold,
+    }
+}
+
+/// Whether `err` is the engine failing on the audio (a model that won't
+/// load, a failed inference, running out of memory), which another model may
+/// get past. A cancelled job or a cloud provider refusing the request isn't.
+pub fn is_engine_failure(err: &anyhow::Error) -> bool {
+    !err.is::<JobCancelled>() && !err.is::<CloudError>()
+}
+
+/// Segments must end this long before the end of the transcribed tail to be
+/// committed, so words still being spoken aren't frozen.
+const COMMIT_MARGIN_MS: i64 = 1500;
+/// A tail longer than this commits its settled segments even if consecutive
+/// passes disagree on them, keeping each pass short.
+const MAX_TAIL_SAMPLES: usize = 16000 * 20;
+/// Committed text handed to the engine as context for the tail, in
+/// characters. Whisper only keeps the last ~220 tokens of its prompt.
+const MAX_CONTEXT_CHARS: usize = 300;
+/// Most words at the start of a pass that can repeat the committed text.
+const MAX_OVERLAP_WORDS: usize = 4;
+
+/// Longest the interval between partial passes stretches on a slow machine.
+const MAX_PARTIAL_WINDOW_MS: u64 = 5000;
+
+/// Audio longer than this is transcribed in chunks.
+const LONG_AUDIO_SAMPLES: usize = 16000 * 120;
+/// Length a chunk of long audio is cut at when no pause falls near its end.
+const LONG_CHUNK_SAMPLES: usize = 16000 * 60;
+/// How far before that length a pause to cut at is looked for.
+const PAUSE_SEARCH_SAMPLES: usize = 16000 * 15;
+/// Audio a chunk cut mid-speech shares with the next, so the word at the
+/// cut is heard whole on one side.
+const CHUNK_OVERLAP_SAMPLES: usize = 16000 * 2;
+
+/// Sent with `streaming-window-changed` when the interval between partial
+/// passes adapts.
+#[derive(Clone, Debug, Serialize)]
+pub struct StreamingWindowEvent {
+    pub window_ms: u64,
+    pub target_ms: u64,
+}
+
+/// The interval before the next partial pass. Passes slower than the target
+/// stretch it to a quarter more than they took, so they don't queue up; it
+/// moves halfway there each pass so a single slow pass doesn't swing it.
+fn next_partial_window(window_ms: u64, target_ms: u64, pass_ms: u64) -> u64 {
+    let wanted = (pass_ms + pass_ms / 4).clamp(target_ms, MAX_PARTIAL_WINDOW_MS);
+    (window_ms + wanted) / 2
+}
+
+/// What the engine is doing, for `get_engine_status`.
+#[derive(Clone, Debug, Serialize)]
+pub struct EngineStatus {
+    pub model_loaded: bool,
+    pub model_id: Option<String>,
+    pub model_loading: bool,
+    /// The separate live-preview model, when one is loaded.
+    pub preview_model_id: Option<String>,
+    /// Whether a job has a pass in the engine.
+    pub transcribing: bool,
+    /// Whether the dictation being recorded gets live previews.
+    pub streaming: bool,
+    /// Jobs waiting for the engine.
+    pub queued: usize,
+    pub jobs: Vec<JobInfo>,
+}
+
+/// How a model did on the benchmark clip, for `bench_model`.
+#[derive(Clone, Debug, Serialize)]
+pub struct ModelBenchmark {
+    pub model_id: String,
+    pub model_name: String,
+    pub load_ms: u64,
+    /// Inference time over clip duration; below 1.0 is faster than real time.
+    pub real_time_factor: f64,
+    /// How much the app's resident memory grew with the model loaded and
+    /// transcribing. Only measured on Linux, `None` on other platforms.
+    pub memory_mb: Option<u64>,
+}
+
+/// The app's resident memory. Only Linux reports it cheaply (`/proc`), so
+/// it's `None` elsewhere.
+fn resident_memory_mb() -> Option<u64> {
+    #[cfg(target_os = "linux")]
+    {
+        let status = std::fs::read_to_string("/proc/self/status").ok()?;
+        let line = status.lines().find(|line| line.starts_with("VmRSS:"))?;
+        let kb: u64 = line.split_whitespace().nth(1)?.parse().ok()?;
+        Some(kb / 1024)
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        None
+    }
+}
+
+/// Sent with `transcription-progress` while streaming.
+#[derive(Clone, Debug, Default, Serialize, PartialEq)]
+pub struct StreamingProgress {
+    /// Text frozen so far; later passes don't change it.
+    pub committed: String,
+    /// Transcription of the audio after the committed text, which may still change.
+    pub tail: String,
+}
+
+/// Audio and text of the current streaming session.
+#[derive(Default)]
+struct StreamingState {
+    buffer: Vec<f32>,
+    /// Samples at the start of `buffer` covered by `committed`.
+    committed_samples: usize,
+    committed: String,
+    /// Segments of `committed`, timed from the start of `buffer`.
+    committed_segments: Vec<TimedSegment>,
+    /// Segments from the previous pass over the current tail.
+    previous_tail: Vec<TimedSegment>,
+}
+
+impl StreamingState {
+    /// Take in a pass over the `tail_samples` after the committed audio:
+    /// commit the segments that are settled and return the text to show.
+    fn advance(&mut self, mut transcript: Transcript, tail_samples: usize) -> StreamingProgress {
+        drop_overlap(&self.committed, &mut transcript);
+        let horizon_ms = (tail_samples as i64 * 1000 / 16000) - COMMIT_MARGIN_MS;
+        let segments = transcript.segments;
+        let settled = segments
+            .iter()
+            .take_while(|segment| segment.end_ms <= horizon_ms)
+            .count();
+        // A segment is stable once two consecutive passes agree on it
+        let stable = if tail_samples > MAX_TAIL_SAMPLES {
+            settled
+        } else {
+            segments
+                .iter()
+                .zip(&self.previous_tail)
+                .take(settled)
+                .take_while(|(current, previous)| same_words(&current.text, &previous.text))
+                .count()
+        };
+
+        if stable > 0 {
+            let end_ms = segments[stable - 1].end_ms;
+            let offset_ms = (self.committed_samples / 16) as i64;
+            for segment in &segments[..stable] {
+                if !self.committed.is_empty() {
+                    self.committed.push(' ');
+                }
+                self.committed.push_str(&segment.text);
+                self.committed_segments.push(TimedSegment {
+                    start_ms: segment.start_ms + offset_ms,
+                    end_ms: segment.end_ms + offset_ms,
+                    ..segment.clone()
+                });
+            }
+            self.committed_samples += (end_ms * 16) as usize;
+            // The next pass starts after the committed audio, so its
+            // timestamps aren't comparable with this one's
+            self.previous_tail.clear();
+        } else {
+            self.previous_tail = segments.clone();
+        }
+
+        let tail = segments[stable..]
+            .iter()
+            .map(|segment| segment.text.as_str())
+            .collect::<Vec<_>>()
+            .join(" ");
+        // Engines without timestamps leave everything in the tail
+        let tail = if segments.is_empty() {
+            transcript.text
+        } else {
+            tail
+        };
+        StreamingProgress {
+            committed: self.committed.clone(),
+            tail,
+        }
+    }
+
+    /// The end of the committed text, for the engine to carry on from when
+    /// it transcribes the tail.
+    fn context(&self) -> Option<String> {
+        end_context(&self.committed)
+    }
+}
+
+/// The end of `text`, at most [`MAX_CONTEXT_CHARS`] long, to hand to the
+/// engine as what was said right before the audio.
+fn end_context(text: &str) -> Option<String> {
+    let skip = text.chars().count().saturating_sub(MAX_CONTEXT_CHARS);
+    if skip == 0 {
+        return (!text.is_empty()).then(|| text.to_string());
+    }
+    let end: String = text.chars().skip(skip).collect();
+    // Don't start on half a word
+    end.split_once(char::is_whitespace)
+        .map(|(_, rest)| rest.trim_start().to_string())
+        .filter(|rest| !rest.is_empty())
+}
+
+/// How a pass through the engine runs.
+#[derive(Default)]
+struct Pass {
+    /// Use the preview model, if one is loaded.
+    preview: bool,
+    /// Initial prompt of the dictation the pass belongs to.
+    prompt: Option<String>,
+    /// Text spoken right before the audio, added to the initial prompt.
+    context: Option<String>,
+    /// An engine to use instead of the loaded model.
+    engine: Option<LocalEngine>,
+}
+
+/// Fill in the transcript's language and apply the text rules the user turned
+/// on: punctuation, number normalization, locale formatting and the
+/// profanity filter. `punctuated` is whether the engine wrote its own
+/// punctuation.
+fn apply_text_rules(
+    transcript: &mut Transcript,
+    settings: &AppSettings,
+    translate: bool,
+    punctuated: bool,
+) {
+    // With the language on "auto" it is guessed from the text, which is
+    // English regardless of what was spoken when translating
+    if transcript.language.is_none() && !translate {
+        transcript.language = language_detect::detect(&transcript.text).map(str::to_string);
+    }
+    // Only the text is punctuated; the segments are single words
+    if !punctuated && settings.punctuation_restoration {
+        transcript.text = punctuation::restore(
+            &transcript.text,
+            &transcript.segments,
+            transcript.language.as_deref(),
+        );
+    }
+    // Translations come out in English
+    let output_language = if translate {
+        Some("en".to_string())
+    } else {
+        transcript.language.clone()
+    };
+    if let Some(language) =
+        output_language.filter(|language| settings.text_normalization.contains(language))
+    {
+        rewrite_transcript(transcript, |text| {
+            text_normalization::apply(text, &language)
+        });
+    }
+    if let Some(language) = transcript
+        .language
+        .clone()
+        .filter(|language| !translate && settings.locale_formatting.contains(language))
+    {
+        rewrite_transcript(transcript, |text| locale_format::apply(text, &language));
+    }
+    if settings.profanity_filter != ProfanityFilter::Keep {
+        let (words, mode) = (&settings.profanity_words, settings.profanity_filter);
+        rewrite_transcript(transcript, |text| profanity::apply(text, words, mode));
+    }
+}
+
+/// Rewrite the transcript's text and each of its segments with `rewrite`.
+fn rewrite_transcript(transcript: &mut Transcript, rewrite: impl Fn(&str) -> String) {
+    transcript.text = rewrite(&transcript.text);
+    for segment in &mut transcript.segments {
+        segment.text = rewrite(&segment.text);
+    }
+}
+
+/// Whether two segment texts say the same words, ignoring case and punctuation.
+fn same_words(a: &str, b: &str) -> bool {
+    normalized_words(a) == normalized_words(b)
+}
+
+/// The words of `text`, lowercase and without punctuation.
+fn normalized_words(text: &str) -> Vec<String> {
+    text.split_whitespace()
+        .map(|word| {
+            word.trim_matches(|c: char| !c.is_alphanumeric())
+                .to_lowercase()
+        })
+        .filter(|word| !word.is_empty())
+        .collect()
+}
+
+/// Drop the words at the start of `transcript` that repeat the end of
+/// `committed`. A pass starts where the committed audio ends, so a word cut
+/// at that boundary can be heard on both sides of it.
+fn drop_overlap(committed: &str, transcript: &mut Transcript) {
+    let committed = normalized_words(committed);
+    let text = normalized_words(&transcript.text);
+    let overlap = (1..=MAX_OVERLAP_WORDS.min(committed.len()).min(text.len()))
+        .rev()
+        .find(|&n| committed[committed.len() - n..] == text[..n])
+        .unwrap_or(0);
+    if overlap == 0 {
+        return;
+    }
+    transcript.text = transcript
+        .text
+        .split_whitespace()
+        .skip(overlap)
+        .collect::<Vec<_>>()
+        .join(" ");
+    let mut count = overlap;
+    while count > 0 && !transcript.segments.is_empty() {
+        let words: Vec<&str> = transcript.segments[0].text.split_whitespace().collect();
+        if words.len() <= count {
+            count -= words.len();
+            transcript.segments.remove(0);
+        } else {
+            transcript.segments[0].text = words[count..].join(" ");
+            count = 0;
+        }
+    }
+}
+
+/// Split `len` samples into chunks of at most [`LONG_CHUNK_SAMPLES`]. A
+/// chunk ends in the middle of the last pause between the `speech` ranges
+/// that falls near its end; without one it is cut mid-speech and overlaps
+/// the next by [`CHUNK_OVERLAP_SAMPLES`].
+fn long_audio_chunks(len: usize, speech: &[Range<usize>]) -> Vec<Range<usize>> {
+    let mut pauses = Vec::new();
+    let mut silence_start = 0;
+    for range in speech {
+        if range.start > silence_start {
+            pauses.push((silence_start + range.start) / 2);
+        }
+        silence_start = range.end;
+    }
+    if silence_start < len {
+        pauses.push((silence_start + len) / 2);
+    }
+
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    while len - start > LONG_CHUNK_SAMPLES {
+        let end = start + LONG_CHUNK_SAMPLES;
+        let pause = pauses
+            .iter()
+            .rev()
+            .find(|&&pause| pause <= end && pause >= end - PAUSE_SEARCH_SAMPLES);
+        match pause {
+            Some(&pause) => {
+                chunks.push(start..pause);
+                start = pause;
+            }
+            None => {
+                chunks.push(start..end);
+                start = end - CHUNK_OVERLAP_SAMPLES;
+            }
+        }
+    }
+    chunks.push(start..len);
+    chunks
+}
+
+/// Whether the model should stay loaded right now.
+fn in_warm_hours(settings: &AppSettings) -> bool {
+    settings
+        .model_warm_hours
+        .is_some_and(|hours| hours.contains(Local::now().hour()))
 }
 
 #[derive(Clone)]
@@ -43,15 +455,27 @@ pub struct TranscriptionManager {
     watcher_handle: Arc<Mutex<Option<thread::JoinHandle<()>>>>,
     is_loading: Arc<Mutex<bool>>,
     loading_condvar: Arc<Condvar>,
-    streaming_buffer: Arc<Mutex<Vec<f32>>>,
+    streaming: Arc<Mutex<StreamingState>>,
     last_partial_update: Arc<Mutex<std::time::Instant>>,
+    /// Current interval between partial passes, adapted to how long they take.
+    partial_window_ms: Arc<AtomicU64>,
     streaming_in_progress: Arc<AtomicBool>,
-    /// Adaptive max samples for streaming - dynamically adjusted based on transcription performance
-    /// Starts at None (no limit), then caps when transcription exceeds 800ms
-    adaptive_max_samples: Arc<Mutex<Option<usize>>>,
     /// Generation counter for the current streaming session, used to discard
     /// stale streaming chunks that belong to a previous recording.
     active_generation: Arc<AtomicU64>,
+    /// Initial prompt of the dictation being streamed and its generation,
+    /// for live previews.
+    preview_prompt: Arc<Mutex<Option<(u64, String)>>>,
+    /// Whether partial transcriptions run for the current streaming session.
+    /// Turned off while the battery profile is active.
+    partials_enabled: Arc<AtomicBool>,
+    /// Silero VAD used to trim silence before inference, loaded on first use.
+    trim_vad: Arc<Mutex<Option<SileroVad>>>,
+    /// Decides which transcription uses the engine next.
+    queue: Arc<TranscriptionQueue>,
+    /// Model for live previews, loaded when a dictation starts.
+    preview_engine: Arc<Mutex<Option<PreviewEngine>>>,
+    preview_loading: Arc<AtomicBool>,
 }
 
 impl TranscriptionManager {
@@ -71,14 +495,20 @@ impl TranscriptionManager {
             watcher_handle: Arc::new(Mutex::new(None)),
             is_loading: Arc::new(Mutex::new(false)),
             loading_condvar: Arc::new(Condvar::new()),
-            streaming_buffer: Arc::new(Mutex::new(Vec::new())),
+            streaming: Arc::new(Mutex::new(StreamingState::default())),
             last_partial_update: Arc::new(Mutex::new(std::time::Instant::now())),
+            partial_window_ms: Arc::new(AtomicU64::new(0)),
             streaming_in_progress: Arc::new(AtomicBool::new(false)),
-            adaptive_max_samples: Arc::new(Mutex::new(None)),
             active_generation: Arc::new(AtomicU64::new(0)),
+            preview_prompt: Arc::new(Mutex::new(None)),
+            partials_enabled: Arc::new(AtomicBool::new(true)),
+            trim_vad: Arc::new(Mutex::new(None)),
+            queue: Arc::new(TranscriptionQueue::default()),
+            preview_engine: Arc::new(Mutex::new(None)),
+            preview_loading: Arc::new(AtomicBool::new(false)),
         };
 
-        // Start the idle watcher
+        // Start the idle watcher, which also loads the model when warm hours begin
         {
             let app_handle_cloned = app_handle.clone();
             let manager_cloned = manager.clone();
@@ -93,7 +523,18 @@ impl TranscriptionManager {
                     }
 
                     let settings = get_settings(&app_handle_cloned);
-                    let timeout_seconds = settings.model_unload_timeout.to_seconds();
+                    if in_warm_hours(&settings) {
+                        if !manager_cloned.is_model_loaded() {
+                            debug!("Loading model for warm hours");
+                            manager_cloned.initiate_model_load();
+                        }
+                        continue;
+                    }
+
+                    let timeout_seconds = power::effective_unload_seconds(
+                        &app_handle_cloned,
+                        settings.model_unload_timeout.to_seconds(),
+                    );
 
                     if let Some(limit_seconds) = timeout_seconds {
                         // Skip polling-based unloading for immediate timeout since it's handled directly in transcribe()
@@ -153,13 +594,11 @@ impl TranscriptionManager {
         {
             let mut engine = self.engine.lock().unwrap();
             if let Some(ref mut loaded_engine) = *engine {
-                match loaded_engine {
-                    LoadedEngine::Whisper(ref mut whisper) => whisper.unload_model(),
-                    LoadedEngine::Parakeet(ref mut parakeet) => parakeet.unload_model(),
-                }
+                loaded_engine.unload();
             }
             *engine = None; // Drop the engine to free memory
         }
+        self.unload_preview_model();
         {
             let mut current_model = self.current_model_id.lock().unwrap();
             *current_model = None;
@@ -218,58 +657,23 @@ impl TranscriptionManager {
             return Err(anyhow::anyhow!(error_msg));
         }
 
-        let model_path = self.model_manager.get_model_path(model_id)?;
-
-        // Create appropriate engine based on model type
-        let loaded_engine = match model_info.engine_type {
-            EngineType::Whisper => {
-                let mut engine = WhisperEngine::new();
-                engine.load_model(&model_path).map_err(|e| {
-                    let error_msg = format!("Failed to load whisper model {}: {}", model_id, e);
-                    let _ = self.app_handle.emit(
-                        "model-state-changed",
-                        ModelStateEvent {
-                            event_type: "loading_failed".to_string(),
-                            model_id: Some(model_id.to_string()),
-                            model_name: Some(model_info.name.clone()),
-                            error: Some(error_msg.clone()),
-                        },
-                    );
-                    anyhow::anyhow!(error_msg)
-                })?;
-                LoadedEngine::Whisper(engine)
-            }
-            EngineType::Parakeet => {
-                let mut engine = ParakeetEngine::new();
-                engine
-                    .load_model_with_params(&model_path, ParakeetModelParams::int8())
-                    .map_err(|e| {
-                        let error_msg =
-                            format!("Failed to load parakeet model {}: {}", model_id, e);
-                        let _ = self.app_handle.emit(
-                            "model-state-changed",
-                            ModelStateEvent {
-                                event_type: "loading_failed".to_string(),
-                                model_id: Some(model_id.to_string()),
-                                model_name: Some(model_info.name.clone()),
-                                error: Some(error_msg.clone()),
-                            },
-                        );
-                        anyhow::anyhow!(error_msg)
-                    })?;
-                LoadedEngine::Parakeet(engine)
-            }
-            EngineType::Diarization => {
-                return Err(anyhow::anyhow!(
-                    "Diarization models cannot be used for transcription"
-                ));
-            }
-        };
+        let local_engine = self.create_engine(&model_info).map_err(|e| {
+            let _ = self.app_handle.emit(
+                "model-state-changed",
+                ModelStateEvent {
+                    event_type: "loading_failed".to_string(),
+                    model_id: Some(model_id.to_string()),
+                    model_name: Some(model_info.name.clone()),
+                    error: Some(e.to_string()),
+                },
+            );
+            e
+        })?;
 
         // Update the current engine and model ID
         {
             let mut engine = self.engine.lock().unwrap();
-            *engine = Some(loaded_engine);
+            *engine = Some(LoadedEngine::Local(local_engine));
         }
         {
             let mut current_model = self.current_model_id.lock().unwrap();
@@ -296,6 +700,113 @@ impl TranscriptionManager {
         Ok(())
     }
 
+    /// Create a local engine for a downloaded model.
+    fn create_engine(&self, model_info: &ModelInfo) -> Result<LocalEngine> {
+        let model_path = self.model_manager.get_model_path(&model_info.id)?;
+        let kind = match model_info.engine_type {
+            EngineType::Whisper => EngineKind::Whisper,
+            EngineType::Parakeet => EngineKind::Parakeet,
+            EngineType::Diarization => {
+                return Err(anyhow::anyhow!(
+                    "Diarization models cannot be used for transcription"
+                ));
+            }
+        };
+        let settings = get_settings(&self.app_handle);
+        let mut inference_backend = settings.inference_backend;
+        if !backend::is_available(inference_backend) {
+            warn!(
+                "Inference backend {:?} is not available, using the CPU",
+                inference_backend
+            );
+            inference_backend = InferenceBackend::Cpu;
+        }
+        backend::apply(inference_backend, settings.inference_threads);
+        LocalEngine::load(kind, &model_path)
+    }
+
+    /// Load the preview model in the background, unless it is already loaded
+    /// or loading.
+    fn initiate_preview_load(&self, model_id: String) {
+        let loaded = self
+            .preview_engine
+            .lock()
+            .unwrap()
+            .as_ref()
+            .is_some_and(|preview| preview.model_id == model_id);
+        if loaded || self.preview_loading.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        let this = self.clone();
+        thread::spawn(move || {
+            let engine = this
+                .model_manager
+                .get_model_info(&model_id)
+                .filter(|model| model.is_downloaded)
+                .ok_or_else(|| anyhow::anyhow!("Preview model {} is not downloaded", model_id))
+                .and_then(|model_info| this.create_engine(&model_info));
+            match engine {
+                Ok(engine) => {
+                    info!("Loaded preview model {}", model_id);
+                    *this.preview_engine.lock().unwrap() = Some(PreviewEngine { model_id, engine });
+                }
+                Err(e) => warn!(
+                    "Failed to load preview model, previews use the main model: {}",
+                    e
+                ),
+            }
+            this.preview_loading.store(false, Ordering::SeqCst);
+        });
+    }
+
+    pub fn unload_preview_model(&self) {
+        if let Some(mut preview) = self.preview_engine.lock().unwrap().take() {
+            preview.engine.unload();
+            debug!("Unloaded preview model {}", preview.model_id);
+        }
+    }
+
+    /// Switch to cloud transcription, with `fallback_model_id` as the local
+    /// model to use when the provider can't be reached.
+    fn load_remote(&self, fallback_model_id: &str) -> Result<()> {
+        let settings = get_settings(&self.app_handle);
+        let provider = settings
+            .active_transcription_provider()
+            .ok_or_else(|| anyhow::anyhow!("No transcription provider configured"))?;
+        let has_key = settings
+            .transcription_api_keys
+            .get(&provider.id)
+            .is_some_and(|key| !key.is_empty());
+        if !has_key {
+            return Err(anyhow::anyhow!("No API key set for {}", provider.label));
+        }
+
+        {
+            let mut engine = self.engine.lock().unwrap();
+            *engine = Some(LoadedEngine::Remote(RemoteEngine {
+                fallback_model_id: fallback_model_id.to_string(),
+                fallback: None,
+            }));
+        }
+        {
+            let mut current_model = self.current_model_id.lock().unwrap();
+            *current_model = Some(fallback_model_id.to_string());
+        }
+
+        let _ = self.app_handle.emit(
+            "model-state-changed",
+            ModelStateEvent {
+                event_type: "loading_completed".to_string(),
+                model_id: Some(fallback_model_id.to_string()),
+                model_name: Some(provider.label.clone()),
+                error: None,
+            },
+        );
+        info!("Using {} for transcription", provider.label);
+        Ok(())
+    }
+
     /// Kicks off the model loading in a background thread if it's not already loaded
     pub fn initiate_model_load(&self) {
         let mut is_loading = self.is_loading.lock().unwrap();
@@ -307,7 +818,19 @@ impl TranscriptionManager {
         let self_clone = self.clone();
         thread::spawn(move || {
             let settings = get_settings(&self_clone.app_handle);
-            if let Err(e) = self_clone.load_model(&settings.selected_model) {
+            let model_id = power::effective_model_id(&self_clone.app_handle, &settings);
+            let loaded = if settings.cloud_transcription_enabled {
+                self_clone.load_remote(&model_id).or_else(|e| {
+                    warn!(
+                        "Cloud transcription unavailable, loading local model: {}",
+                        e
+                    );
+                    self_clone.load_model(&model_id)
+                })
+            } else {
+                self_clone.load_model(&model_id)
+            };
+            if let Err(e) = loaded {
                 error!("Failed to load model: {}", e);
             }
             let mut is_loading = self_clone.is_loading.lock().unwrap();
@@ -316,135 +839,568 @@ impl TranscriptionManager {
         });
     }
 
+    /// Load the model if needed and block until loading has finished.
+    pub fn ensure_model_loaded(&self) -> Result<()> {
+        self.initiate_model_load();
+        self.wait_for_model_load();
+
+        if self.is_model_loaded() {
+            Ok(())
+        } else {
+            Err(anyhow::anyhow!("Model failed to load"))
+        }
+    }
+
+    /// Block until a model load in progress has finished.
+    fn wait_for_model_load(&self) {
+        let mut is_loading = self.is_loading.lock().unwrap();
+        while *is_loading {
+            is_loading = self.loading_condvar.wait(is_loading).unwrap();
+        }
+    }
+
     pub fn get_current_model(&self) -> Option<String> {
         let current_model = self.current_model_id.lock().unwrap();
         current_model.clone()
     }
 
-    pub fn transcribe(&self, audio: Vec<f32>) -> Result<String> {
-        // Update last activity timestamp
-        self.last_activity.store(
-            SystemTime::now()
-                .duration_since(SystemTime::UNIX_EPOCH)
-                .unwrap()
-                .as_millis() as u64,
-            Ordering::Relaxed,
-        );
+    /// Set the Whisper initial prompt for the live previews of streaming
+    /// session `generation`. The final transcription is handed its prompt
+    /// by [`Self::transcribe_final`].
+    pub fn set_preview_prompt(&self, generation: u64, prompt: Option<String>) {
+        *self.preview_prompt.lock().unwrap() = prompt.map(|prompt| (generation, prompt));
+    }
 
-        let st = std::time::Instant::now();
+    /// Register a transcription job, to run passes through
+    /// [`Self::transcribe_job`] under. It can be cancelled until it's dropped.
+    pub fn submit_job(&self, priority: JobPriority, label: &str) -> TranscriptionJob {
+        self.queue.submit(priority, label)
+    }
 
-        debug!("Audio vector length: {}", audio.len());
+    /// Cancel job `id`. Returns false if there is no such job.
+    pub fn cancel_job(&self, id: u64) -> bool {
+        self.queue.cancel(id)
+    }
 
-        if audio.len() == 0 {
-            debug!("Empty audio vector");
-            return Ok(String::new());
-        }
+    pub fn jobs(&self) -> Vec<JobInfo> {
+        self.queue.jobs()
+    }
 
-        // Check if model is loaded, if not try to load it
-        {
-            // If the model is loading, wait for it to complete.
-            let mut is_loading = self.is_loading.lock().unwrap();
-            while *is_loading {
-                is_loading = self.loading_condvar.wait(is_loading).unwrap();
-            }
+    /// Transcribe `audio` with the smallest downloaded model other than the
+    /// loaded one, for when the loaded one failed. The model is only loaded
+    /// for this call, with the same initial prompt. Returns the transcript
+    /// and the model's name.
+    pub fn transcribe_with_fallback_model(
+        &self,
+        audio: Vec<f32>,
+        prompt: Option<String>,
+    ) -> Result<(Transcript, String)> {
+        let current = self.get_current_model();
+        let model = self
+            .model_manager
+            .get_transcription_models()
+            .into_iter()
+            .filter(|model| model.is_downloaded && Some(&model.id) != current.as_ref())
+            .min_by_key(|model| model.size_mb)
+            .ok_or_else(|| anyhow::anyhow!("No other downloaded model to retry with"))?;
+        info!("Retrying the transcription with {}", model.name);
+        let engine = self.create_engine(&model)?;
+        let job = self.submit_job(JobPriority::Interactive, "Transcription retry");
+        let transcript = self.run_job(
+            &job,
+            audio,
+            Pass {
+                prompt,
+                engine: Some(engine),
+                ..Default::default()
+            },
+        )?;
+        Ok((transcript, model.name))
+    }
 
-            let engine_guard = self.engine.lock().unwrap();
-            if engine_guard.is_none() {
-                return Err(anyhow::anyhow!("Model is not loaded for transcription."));
-            }
+    /// Benchmark the downloaded transcription models, or just `model_id`, on
+    /// 16 kHz mono `clip`. Each model is loaded on its own next to the
+    /// current one, timed, and unloaded again. Runs as background jobs so
+    /// dictations go first.
+    pub fn benchmark(&self, model_id: Option<&str>, clip: &[f32]) -> Result<Vec<ModelBenchmark>> {
+        let models: Vec<ModelInfo> = self
+            .model_manager
+            .get_transcription_models()
+            .into_iter()
+            .filter(|model| model.is_downloaded)
+            .filter(|model| model_id.is_none_or(|id| model.id == id))
+            .collect();
+        if models.is_empty() {
+            return Err(match model_id {
+                Some(id) => anyhow::anyhow!("Model {} is not downloaded", id),
+                None => anyhow::anyhow!("No downloaded models to benchmark"),
+            });
         }
 
-        // Get current settings for configuration
-        let settings = get_settings(&self.app_handle);
+        let options = inference_options(&get_settings(&self.app_handle), None);
+        let clip_secs = clip.len() as f64 / 16000.0;
+        let mut results = Vec::new();
+        for model in models {
+            let job = self.submit_job(JobPriority::Background, "Model benchmark");
+            let _turn = job.turn()?;
+            let memory_before = resident_memory_mb();
+
+            let load_start = std::time::Instant::now();
+            let mut engine = self.create_engine(&model)?;
+            let load_ms = load_start.elapsed().as_millis() as u64;
+
+            let infer_start = std::time::Instant::now();
+            let result = engine.infer(clip.to_vec(), &options);
+            let infer_secs = infer_start.elapsed().as_secs_f64();
+            let memory_mb = memory_before
+                .zip(resident_memory_mb())
+                .map(|(before, after)| after.saturating_sub(before));
+            engine.unload();
+            result?;
+
+            let benchmark = ModelBenchmark {
+                model_id: model.id,
+                model_name: model.name,
+                load_ms,
+                real_time_factor: infer_secs / clip_secs,
+                memory_mb,
+            };
+            info!(
+                "Benchmarked {}: loaded in {}ms, RTF {:.2}",
+                benchmark.model_name, benchmark.load_ms, benchmark.real_time_factor
+            );
+            results.push(benchmark);
+        }
+        Ok(results)
+    }
 
-        // Perform transcription with the appropriate engine
-        let result = {
-            let mut engine_guard = self.engine.lock().unwrap();
-            let engine = engine_guard.as_mut().ok_or_else(|| {
-                anyhow::anyhow!(
-                    "Model failed to load after auto-load attempt. Please check your model settings."
-                )
-            })?;
+    /// What the engine is doing. `recording` is whether a dictation is being
+    /// recorded, which only the audio manager knows.
+    pub fn status(&self, recording: bool) -> EngineStatus {
+        let jobs = self.jobs();
+        EngineStatus {
+            model_loaded: self.is_model_loaded(),
+            model_id: self.get_current_model(),
+            model_loading: *self.is_loading.lock().unwrap(),
+            preview_model_id: self
+                .preview_engine
+                .lock()
+                .unwrap()
+                .as_ref()
+                .map(|preview| preview.model_id.clone()),
+            transcribing: jobs.iter().any(|job| job.state == JobState::Running),
+            streaming: recording && self.partials_enabled.load(Ordering::SeqCst),
+            queued: jobs
+                .iter()
+                .filter(|job| job.state == JobState::Waiting)
+                .count(),
+            jobs,
+        }
+    }
 
-            match engine {
-                LoadedEngine::Whisper(whisper_engine) => {
-                    let whisper_language = if settings.selected_language == "auto" {
-                        None
-                    } else {
-                        let normalized =
-                            if matches!(settings.selected_language.as_str(), "zh-Hans" | "zh-Hant")
-                            {
-                                "zh".to_string()
-                            } else {
-                                settings.selected_language.clone()
-                            };
-                        Some(normalized)
-                    };
-
-                    let params = WhisperInferenceParams {
-                        language: whisper_language,
-                        translate: settings.translate_to_english,
-                        ..Default::default()
-                    };
+    pub fn transcribe(&self, audio: Vec<f32>) -> Result<String> {
+        Ok(self.transcribe_timed(audio)?.text)
+    }
 
-                    whisper_engine
-                        .transcribe_samples(audio, Some(params))
-                        .map_err(|e| anyhow::anyhow!("Whisper transcription failed: {}", e))?
-                }
-                LoadedEngine::Parakeet(parakeet_engine) => {
-                    let params = ParakeetInferenceParams {
-                        timestamp_granularity: TimestampGranularity::Segment,
-                        ..Default::default()
-                    };
+    /// Transcribe `audio` and keep the engine's timestamps, relative to the
+    /// start of `audio` even when silence was trimmed before inference. Runs
+    /// as an interactive job.
+    pub fn transcribe_timed(&self, audio: Vec<f32>) -> Result<Transcript> {
+        let job = self.submit_job(JobPriority::Interactive, "Transcription");
+        self.transcribe_job(&job, audio)
+    }
 
-                    parakeet_engine
-                        .transcribe_samples(audio, Some(params))
-                        .map_err(|e| anyhow::anyhow!("Parakeet transcription failed: {}", e))?
+    /// Transcribe `audio` as a pass of `job`, once the job's turn comes.
+    pub fn transcribe_job(&self, job: &TranscriptionJob, audio: Vec<f32>) -> Result<Transcript> {
+        self.run_job(job, audio, Pass::default())
+    }
+
+    /// Transcribe `audio` as passes of `job` like [`Self::transcribe_job`],
+    /// a chunk at a time when it is long, calling `progress` with the share
+    /// done after each chunk. Chunks are cut in pauses the VAD finds, each
+    /// carries on from the text before it, and words repeated where chunks
+    /// overlap are dropped when stitching them back together.
+    pub fn transcribe_long(
+        &self,
+        job: &TranscriptionJob,
+        audio: &[f32],
+        mut progress: impl FnMut(f64),
+    ) -> Result<Transcript> {
+        if audio.len() <= LONG_AUDIO_SAMPLES {
+            return self.transcribe_job(job, audio.to_vec());
+        }
+        let speech = self
+            .trim_silence(audio)
+            .map(|trimmed| trimmed.kept)
+            .unwrap_or_else(|| vec![0..audio.len()]);
+        let chunks = long_audio_chunks(audio.len(), &speech);
+        info!("Transcribing long audio in {} chunks", chunks.len());
+
+        let mut stitched = Transcript::default();
+        for (i, chunk) in chunks.iter().enumerate() {
+            if job.is_cancelled() {
+                return Err(job.cancelled_error());
+            }
+            let mut transcript = self.run_job(
+                job,
+                audio[chunk.clone()].to_vec(),
+                Pass {
+                    context: end_context(&stitched.text),
+                    ..Default::default()
+                },
+            )?;
+            drop_overlap(&stitched.text, &mut transcript);
+
+            let offset_ms = (chunk.start / 16) as i64;
+            stitched
+                .segments
+                .extend(transcript.segments.into_iter().map(|segment| TimedSegment {
+                    start_ms: segment.start_ms + offset_ms,
+                    end_ms: segment.end_ms + offset_ms,
+                    ..segment
+                }));
+            if !transcript.text.is_empty() {
+                if !stitched.text.is_empty() {
+                    stitched.text.push(' ');
                 }
+                stitched.text.push_str(&transcript.text);
             }
-        };
+            stitched.language = stitched.language.or(transcript.language);
+            progress((i + 1) as f64 / chunks.len() as f64);
+        }
+        Ok(stitched)
+    }
 
-        // Apply word correction if custom words are configured
-        let corrected_result = if !settings.custom_words.is_empty() {
-            apply_custom_words(
-                &result.text,
-                &settings.custom_words,
-                settings.word_correction_threshold,
-            )
-        } else {
-            result.text
+    /// Transcribe a dictation recorded during streaming session
+    /// `generation`. With `reuse_partials`, the text the live previews
+    /// already committed is kept and only the audio after it goes through
+    /// the engine, so stopping a long dictation doesn't transcribe it all
+    /// over again. `audio` must then be the recording exactly as streamed.
+    /// `prompt` is the dictation's Whisper initial prompt.
+    pub fn transcribe_final(
+        &self,
+        audio: Vec<f32>,
+        generation: u64,
+        reuse_partials: bool,
+        prompt: Option<String>,
+    ) -> Result<Transcript> {
+        // Text committed by a preview model isn't worth keeping
+        let reusable = reuse_partials
+            && self.active_generation.load(Ordering::SeqCst) == generation
+            && self.preview_engine.lock().unwrap().is_none()
+            && !self.preview_loading.load(Ordering::SeqCst);
+        let committed = reusable
+            .then(|| {
+                let streaming = self.streaming.lock().unwrap();
+                (streaming.committed_samples > 0 && streaming.committed_samples <= audio.len())
+                    .then(|| {
+                        (
+                            streaming.committed_samples,
+                            streaming.committed.clone(),
+                            streaming.committed_segments.clone(),
+                            streaming.context(),
+                        )
+                    })
+            })
+            .flatten();
+        let job = self.submit_job(JobPriority::Interactive, "Transcription");
+        let Some((committed_samples, committed, mut segments, context)) = committed else {
+            return self.run_job(
+                &job,
+                audio,
+                Pass {
+                    prompt,
+                    ..Default::default()
+                },
+            );
         };
 
-        let et = std::time::Instant::now();
-        let translation_note = if settings.translate_to_english {
+        debug!(
+            "Reusing {:.1}s of committed partials, transcribing the last {:.1}s",
+            committed_samples as f32 / 16000.0,
+            (audio.len() - committed_samples) as f32 / 16000.0
+        );
+        let mut tail = self.run_job(
+            &job,
+            audio[committed_samples..].to_vec(),
+            Pass {
+                prompt,
+                context,
+                ..Default::default()
+            },
+        )?;
+        drop_overlap(&committed, &mut tail);
+
+        let offset_ms = (committed_samples / 16) as i64;
+        segments.extend(tail.segments.into_iter().map(|segment| TimedSegment {
+            start_ms: segment.start_ms + offset_ms,
+            end_ms: segment.end_ms + offset_ms,
+            ..segment
+        }));
+        let text = [committed.as_str(), tail.text.trim()]
+            .into_iter()
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ");
+        Ok(Transcript {
+            text,
+            segments,
+            language: tail.language,
+        })
+    }
+
+    /// Run a pass of `job` through the engine.
+    fn run_job(
+        &self,
+        job: &TranscriptionJob,
+        audio: Vec<f32>,
+        mut pass: Pass,
+    ) -> Result<Transcript> {
+        self.record_activity();
+        let st = std::time::Instant::now();
+        debug!("Audio vector length: {}", audio.len());
+        if audio.len() == 0 {
+            debug!("Empty audio vector");
+            return Ok(Transcript::default());
+        }
+        let settings = get_settings(&self.app_handle);
+
+        // Levels of the untrimmed audio, which the timestamps end up referring to
+        let envelope = transcription_confidence::level_envelope(&audio);
+        let (audio, trimmed) = self.trim_if_enabled(&settings, audio);
+        if audio.is_empty() {
+            debug!("No speech detected, skipping inference");
+            return Ok(Transcript::default());
+        }
+        let turn = job.turn()?;
+        let prompt = initial_prompt::build(pass.prompt.as_deref(), None, pass.context.as_deref());
+        let options = inference_options(&settings, prompt);
+        let (result, punctuated) = self.infer(&mut pass, audio, &settings, &options)?;
+        drop(turn);
+        if job.is_cancelled() {
+            return Err(job.cancelled_error());
+        }
+        // Engine timestamps refer to the trimmed audio; map them back
+        let mut transcript = build_transcript(result, trimmed.as_ref(), &options);
+        transcription_confidence::score_segments(&mut transcript.segments, &envelope);
+        apply_text_rules(&mut transcript, &settings, options.translate, punctuated);
+
+        let translation_note = if options.translate {
             " (translated)"
         } else {
             ""
         };
         info!(
             "Transcription completed in {}ms{}",
-            (et - st).as_millis(),
+            st.elapsed().as_millis(),
             translation_note
         );
+        if !pass.preview {
+            self.unload_if_immediate(&settings);
+        }
+        Ok(transcript)
+    }
+
+    /// Update the last activity timestamp, which the idle unload watcher reads.
+    fn record_activity(&self) {
+        self.last_activity.store(
+            SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap()
+                .as_millis() as u64,
+            Ordering::Relaxed,
+        );
+    }
+
+    /// `audio` without its silence when VAD trimming is on, and how it was
+    /// trimmed.
+    fn trim_if_enabled(
+        &self,
+        settings: &AppSettings,
+        audio: Vec<f32>,
+    ) -> (Vec<f32>, Option<Trimmed>) {
+        let trimmed = if settings.vad_trim_enabled {
+            self.trim_silence(&audio)
+        } else {
+            None
+        };
+        match trimmed {
+            Some(mut trimmed) => (std::mem::take(&mut trimmed.samples), Some(trimmed)),
+            None => (audio, None),
+        }
+    }
 
-        // Check if we should immediately unload the model after transcription
-        if settings.model_unload_timeout == ModelUnloadTimeout::Immediately {
-            info!("Immediately unloading model after transcription");
-            if let Err(e) = self.unload_model() {
-                error!("Failed to immediately unload model: {}", e);
+    /// Run inference with the pass's own engine, the preview model or the
+    /// loaded model. Returns the engine's result and whether it punctuates.
+    fn infer(
+        &self,
+        pass: &mut Pass,
+        audio: Vec<f32>,
+        settings: &AppSettings,
+        options: &InferenceOptions,
+    ) -> Result<(TranscriptionResult, bool)> {
+        if let Some(engine) = pass.engine.as_mut() {
+            return Ok((engine.infer(audio, options)?, engine.punctuates()));
+        }
+        if pass.preview {
+            let mut preview_guard = self.preview_engine.lock().unwrap();
+            if let Some(preview) = preview_guard.as_mut() {
+                let result = preview.engine.infer(audio, options)?;
+                return Ok((result, preview.engine.punctuates()));
             }
         }
 
-        Ok(corrected_result.trim().to_string())
+        // If the model is loading, wait for it to complete
+        self.wait_for_model_load();
+        let mut engine_guard = self.engine.lock().unwrap();
+        let engine = engine_guard
+            .as_mut()
+            .ok_or_else(|| anyhow::anyhow!("Model is not loaded for transcription."))?;
+        match engine {
+            LoadedEngine::Remote(remote) => self.infer_remote(remote, audio, settings, options),
+            LoadedEngine::Local(local) => Ok((local.infer(audio, options)?, local.punctuates())),
+        }
     }
+
+    /// Transcribe with the cloud provider, or with the local fallback model
+    /// when the provider can't be reached.
+    fn infer_remote(
+        &self,
+        remote: &mut RemoteEngine,
+        audio: Vec<f32>,
+        settings: &AppSettings,
+        options: &InferenceOptions,
+    ) -> Result<(TranscriptionResult, bool)> {
+        let e = match cloud_transcription::transcribe(settings, &audio) {
+            Ok(result) => return Ok((result, true)),
+            Err(CloudError::Rejected(e)) => {
+                return Err(
+                    CloudError::Rejected(format!("Cloud transcription failed: {}", e)).into(),
+                );
+            }
+            Err(CloudError::Unreachable(e)) => e,
+        };
+        warn!("Cloud transcription failed, using the local model: {}", e);
+        if remote.fallback.is_none() {
+            let model_info = self
+                .model_manager
+                .get_model_info(&remote.fallback_model_id)
+                .filter(|model| model.is_downloaded)
+                .ok_or_else(|| anyhow::anyhow!("No local model to fall back to: {}", e))?;
+            remote.fallback = Some(self.create_engine(&model_info)?);
+        }
+        let fallback = remote
+            .fallback
+            .as_mut()
+            .expect("Fallback engine loaded above");
+        Ok((fallback.infer(audio, options)?, fallback.punctuates()))
+    }
+
+    /// Unload the model right after a transcription when the user chose to,
+    /// outside the warm hours.
+    fn unload_if_immediate(&self, settings: &AppSettings) {
+        if settings.model_unload_timeout != ModelUnloadTimeout::Immediately
+            || in_warm_hours(settings)
+        {
+            return;
+        }
+        info!("Immediately unloading model after transcription");
+        if let Err(e) = self.unload_model() {
+            error!("Failed to immediately unload model: {}", e);
+        }
+    }
+
+    /// Drop silence from `audio` with the VAD. Returns `None`, meaning the
+    /// audio is used untrimmed, if the VAD can't be loaded or fails.
+    fn trim_silence(&self, audio: &[f32]) -> Option<Trimmed> {
+        let mut guard = self.trim_vad.lock().unwrap();
+        if guard.is_none() {
+            match self.load_trim_vad() {
+                Ok(vad) => *guard = Some(vad),
+                Err(e) => {
+                    warn!("VAD trimming unavailable: {}", e);
+                    return None;
+                }
+            }
+        }
+        let vad = guard.as_mut()?;
+
+        match trim_silence_mapped(vad, audio, VAD_TRIM_PADDING_FRAMES) {
+            Ok(trimmed) => {
+                debug!(
+                    "VAD trimmed {:.1}s of audio to {:.1}s",
+                    audio.len() as f32 / 16000.0,
+                    trimmed.samples.len() as f32 / 16000.0
+                );
+                Some(trimmed)
+            }
+            Err(e) => {
+                warn!("VAD trimming failed, using untrimmed audio: {}", e);
+                None
+            }
+        }
+    }
+
+    fn load_trim_vad(&self) -> Result<SileroVad> {
+        let vad_path = self
+            .app_handle
+            .path()
+            .resolve(
+                "resources/models/silero_vad_v4.onnx",
+                tauri::path::BaseDirectory::Resource,
+            )
+            .map_err(|e| anyhow::anyhow!("Failed to resolve VAD path: {}", e))?;
+        SileroVad::new(vad_path, VAD_TRIM_THRESHOLD)
+    }
+
     pub fn start_streaming(&self, generation: u64) {
-        debug!("start_streaming called - clearing buffer and resetting adaptive limit");
+        debug!("start_streaming called - clearing buffer and committed text");
         self.active_generation.store(generation, Ordering::SeqCst);
-        let mut buf = self.streaming_buffer.lock().unwrap();
-        buf.clear();
+        *self.streaming.lock().unwrap() = StreamingState::default();
         *self.last_partial_update.lock().unwrap() = std::time::Instant::now();
-        // Reset adaptive limit for new recording session
-        *self.adaptive_max_samples.lock().unwrap() = None;
+        let settings = get_settings(&self.app_handle);
+        self.set_partial_window(
+            settings.streaming_target_latency_ms,
+            settings.streaming_target_latency_ms,
+        );
+        // Partials would upload the recording over and over to a cloud provider
+        let partials_enabled = !power::battery_profile_active(&self.app_handle)
+            && !settings.cloud_transcription_enabled;
+        self.partials_enabled
+            .store(partials_enabled, Ordering::SeqCst);
+
+        // A separate preview model only pays off if it isn't the main one
+        let preview_model = settings
+            .preview_model_id
+            .filter(|id| partials_enabled && self.get_current_model().as_ref() != Some(id));
+        match preview_model {
+            Some(model_id) => self.initiate_preview_load(model_id),
+            None => self.unload_preview_model(),
+        }
+    }
+
+    /// Set the interval between partial passes, telling the UI if it moved.
+    fn set_partial_window(&self, window_ms: u64, target_ms: u64) {
+        if self.partial_window_ms.swap(window_ms, Ordering::SeqCst) != window_ms {
+            let _ = self.app_handle.emit(
+                "streaming-window-changed",
+                StreamingWindowEvent {
+                    window_ms,
+                    target_ms,
+                },
+            );
+        }
+    }
+
+    /// Restart the live previews of the recording in progress from `audio`,
+    /// what it has kept so far, after its input device changed. The chunks
+    /// streamed before the switch needn't line up with it, so the committed
+    /// text and its offsets are dropped.
+    pub fn restart_streaming(&self, audio: &[f32]) {
+        *self.streaming.lock().unwrap() = StreamingState {
+            buffer: audio.to_vec(),
+            ..Default::default()
+        };
     }
 
     pub fn handle_streaming_chunk(&self, chunk: Vec<f32>, generation: u64) {
@@ -453,24 +1409,28 @@ impl TranscriptionManager {
             return;
         }
 
+        if !self.partials_enabled.load(Ordering::SeqCst) {
+            return;
+        }
+
         // Append chunk to buffer
-        let current_len = {
-            let mut buf = self.streaming_buffer.lock().unwrap();
-            buf.extend_from_slice(&chunk);
-            buf.len()
+        let tail_len = {
+            let mut streaming = self.streaming.lock().unwrap();
+            streaming.buffer.extend_from_slice(&chunk);
+            streaming.buffer.len() - streaming.committed_samples
         };
 
-        // Throttle updates to ~500ms
+        // Throttle updates to the adaptive window
         let now = std::time::Instant::now();
         let mut last = self.last_partial_update.lock().unwrap();
-        let elapsed_ms = now.duration_since(*last).as_millis();
+        let elapsed_ms = now.duration_since(*last).as_millis() as u64;
 
-        if elapsed_ms > 500 {
+        if elapsed_ms > self.partial_window_ms.load(Ordering::SeqCst) {
             *last = now;
             drop(last);
 
-            // Avoid transcribing extremely short buffers (need at least 1 second)
-            if current_len < 16000 {
+            // Avoid transcribing extremely short tails (need at least 1 second)
+            if tail_len < 16000 {
                 return;
             }
 
@@ -481,36 +1441,36 @@ impl TranscriptionManager {
                 return;
             }
 
-            // Adaptive streaming: adjust max samples based on transcription performance
-            // Target: keep transcription time under 800ms for responsive UI
-            // Minimum: 5 seconds of audio for context (16000 * 5 = 80000 samples)
-            const MIN_STREAMING_SAMPLES: usize = 16000 * 5;
-            const TARGET_TRANSCRIPTION_MS: u128 = 800;
-
-            let adaptive_limit = *self.adaptive_max_samples.lock().unwrap();
-
-            // Get buffer to transcribe (limited by adaptive window)
-            let buf_to_transcribe = {
-                let buf = self.streaming_buffer.lock().unwrap();
-                match adaptive_limit {
-                    Some(max_samples) if buf.len() > max_samples => {
-                        // Sliding window - only transcribe last X samples
-                        buf[buf.len() - max_samples..].to_vec()
-                    }
-                    _ => {
-                        // No limit yet, transcribe full buffer
-                        buf.clone()
-                    }
-                }
+            // Only the uncommitted tail is transcribed, so each pass stays short
+            // however long the dictation gets, with the committed text as
+            // context so it carries on where the last commit left off
+            let (tail_start, tail, context) = {
+                let streaming = self.streaming.lock().unwrap();
+                (
+                    streaming.committed_samples,
+                    streaming.buffer[streaming.committed_samples..].to_vec(),
+                    streaming.context(),
+                )
             };
-
-            // Calculate audio duration for logging
-            let audio_duration_secs = buf_to_transcribe.len() as f32 / 16000.0;
-            let samples_count = buf_to_transcribe.len();
+            let prompt = self
+                .preview_prompt
+                .lock()
+                .unwrap()
+                .as_ref()
+                .filter(|(prompt_generation, _)| *prompt_generation == generation)
+                .map(|(_, prompt)| prompt.clone());
+            let audio_duration_secs = tail.len() as f32 / 16000.0;
+            let tail_samples = tail.len();
 
             let this = self.clone();
 
             thread::spawn(move || {
+                let settings = get_settings(&this.app_handle);
+                // Partials must not starve audio capture or the final transcription
+                if settings.thread_priorities_enabled {
+                    apply_current_thread_priority(ThreadPriority::Background);
+                }
+
                 // Check generation before starting transcription work
                 if this.active_generation.load(Ordering::SeqCst) != generation {
                     this.streaming_in_progress.store(false, Ordering::SeqCst);
@@ -518,52 +1478,46 @@ impl TranscriptionManager {
                 }
 
                 let transcription_start = std::time::Instant::now();
-                if let Ok(text) = this.transcribe(buf_to_transcribe) {
-                    let transcription_ms = transcription_start.elapsed().as_millis();
-
+                let job = this.submit_job(JobPriority::Partial, "Live preview");
+                let result = this.run_job(
+                    &job,
+                    tail,
+                    Pass {
+                        preview: true,
+                        prompt,
+                        context,
+                        ..Default::default()
+                    },
+                );
+                let pass_ms = transcription_start.elapsed().as_millis() as u64;
+                if this.active_generation.load(Ordering::SeqCst) == generation {
+                    let target_ms = settings.streaming_target_latency_ms;
+                    let window_ms = this.partial_window_ms.load(Ordering::SeqCst);
+                    this.set_partial_window(
+                        next_partial_window(window_ms, target_ms, pass_ms),
+                        target_ms,
+                    );
+                }
+                if let Ok(transcript) = result {
                     info!(
-                        "Partial transcription ({:.1}s audio, {}ms): '{}'",
-                        audio_duration_secs, transcription_ms, text
+                        "Partial transcription ({:.1}s tail, {}ms): '{}'",
+                        audio_duration_secs, pass_ms, transcript.text
                     );
 
-                    // Adaptive algorithm: if transcription exceeded target time, reduce the limit
-                    if transcription_ms > TARGET_TRANSCRIPTION_MS {
-                        let mut adaptive = this.adaptive_max_samples.lock().unwrap();
-
-                        match *adaptive {
-                            None => {
-                                // First time exceeding: set limit to current sample count
-                                let new_limit = samples_count.max(MIN_STREAMING_SAMPLES);
-                                info!(
-                                    "Adaptive limit set: {:.1}s ({}ms exceeded {}ms target)",
-                                    new_limit as f32 / 16000.0,
-                                    transcription_ms,
-                                    TARGET_TRANSCRIPTION_MS
-                                );
-                                *adaptive = Some(new_limit);
-                            }
-                            Some(current) => {
-                                // Already have a limit but still exceeding: reduce by 10%
-                                let reduced = (current as f32 * 0.9) as usize;
-                                let new_limit = reduced.max(MIN_STREAMING_SAMPLES);
-
-                                if new_limit < current {
-                                    info!(
-                                        "Adaptive limit reduced: {:.1}s -> {:.1}s ({}ms still exceeded {}ms)",
-                                        current as f32 / 16000.0,
-                                        new_limit as f32 / 16000.0,
-                                        transcription_ms,
-                                        TARGET_TRANSCRIPTION_MS
-                                    );
-                                    *adaptive = Some(new_limit);
-                                }
-                            }
-                        }
-                    }
-
-                    // Only emit if this generation is still current
+                    // Only update and emit if this generation is still current
                     if this.active_generation.load(Ordering::SeqCst) == generation {
-                        crate::overlay::emit_transcription_progress(&this.app_handle, &text);
+                        let progress = {
+                            let mut streaming = this.streaming.lock().unwrap();
+                            // A pass over an older tail can't be lined up anymore
+                            (streaming.committed_samples == tail_start)
+                                .then(|| streaming.advance(transcript, tail_samples))
+                        };
+                        if let Some(progress) = progress {
+                            crate::overlay::emit_transcription_progress(
+                                &this.app_handle,
+                                &progress,
+                            );
+                        }
                     }
                 }
                 // Mark streaming transcription as complete
@@ -590,3 +1544,176 @@ impl Drop for TranscriptionManager {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_engine_failures_are_retried() {
+        let queue = Arc::new(TranscriptionQueue::default());
+        let job = queue.submit(JobPriority::Interactive, "Transcription");
+        queue.cancel(job.id());
+        let cancelled = job.turn().map(|_| ()).unwrap_err();
+        assert!(!is_engine_failure(&cancelled));
+
+        let rejected = anyhow::Error::new(CloudError::Rejected("Invalid API key".into()));
+        assert!(!is_engine_failure(&rejected));
+
+        let out_of_memory = anyhow::anyhow!("Failed to allocate memory");
+        assert!(is_engine_failure(&out_of_memory));
+    }
+
+    fn pass(words: &[(&str, i64, i64)]) -> Transcript {
+        let segments: Vec<TimedSegment> = words
+            .iter()
+            .map(|&(text, start_ms, end_ms)| TimedSegment {
+                start_ms,
+                end_ms,
+                text: text.to_string(),
+                confidence: None,
+            })
+            .collect();
+        Transcript {
+            text: words.iter().map(|w| w.0).collect::<Vec<_>>().join(" "),
+            segments,
+            language: None,
+        }
+    }
+
+    #[test]
+    fn commits_words_two_passes_agree_on() {
+        let mut state = StreamingState::default();
+        let first = state.advance(
+            pass(&[("Send", 0, 400), ("the", 400, 600), ("rapport", 600, 3800)]),
+            16000 * 4,
+        );
+        assert_eq!(first.committed, "");
+        assert_eq!(first.tail, "Send the rapport");
+
+        // "rapport" became "report" and still ends too close to the live edge
+        let second = state.advance(
+            pass(&[("send", 0, 400), ("the", 400, 600), ("report", 600, 4200)]),
+            16000 * 5,
+        );
+        assert_eq!(second.committed, "send the");
+        assert_eq!(second.tail, "report");
+        assert_eq!(state.committed_samples, 600 * 16);
+        assert!(state.previous_tail.is_empty());
+    }
+
+    #[test]
+    fn long_tail_commits_without_agreement() {
+        let mut state = StreamingState::default();
+        let progress = state.advance(
+            pass(&[("One", 0, 10_000), ("two", 10_000, 19_000)]),
+            MAX_TAIL_SAMPLES + 16000,
+        );
+        assert_eq!(progress.committed, "One two");
+        assert_eq!(progress.tail, "");
+        assert_eq!(state.committed_samples, 19_000 * 16);
+    }
+
+    #[test]
+    fn committed_segments_are_timed_from_the_start() {
+        let mut state = StreamingState::default();
+        state.advance(pass(&[("One", 0, 10_000)]), MAX_TAIL_SAMPLES + 16000);
+        // The second pass only covers the audio after "One"
+        state.advance(
+            pass(&[("two", 500, 9_000), ("three", 9_000, 19_000)]),
+            MAX_TAIL_SAMPLES + 16000,
+        );
+        let timings: Vec<_> = state
+            .committed_segments
+            .iter()
+            .map(|segment| (segment.text.as_str(), segment.start_ms, segment.end_ms))
+            .collect();
+        assert_eq!(
+            timings,
+            [
+                ("One", 0, 10_000),
+                ("two", 10_500, 19_000),
+                ("three", 19_000, 29_000)
+            ]
+        );
+        assert_eq!(state.committed_samples, 29_000 * 16);
+    }
+
+    #[test]
+    fn words_repeated_across_the_commit_boundary_are_dropped() {
+        let mut state = StreamingState::default();
+        state.advance(
+            pass(&[("Send the", 0, 10_000), ("report", 10_000, 19_000)]),
+            MAX_TAIL_SAMPLES + 16000,
+        );
+        // The pass after the commit heard the cut-off "report" again
+        let progress = state.advance(
+            pass(&[("Report to", 0, 900), ("Bob", 900, 1_200)]),
+            16000 * 4,
+        );
+        assert_eq!(progress.committed, "Send the report");
+        assert_eq!(progress.tail, "to Bob");
+
+        // Repeats further back than the boundary are kept
+        let mut transcript = pass(&[("the report", 0, 900)]);
+        drop_overlap("Send the report now", &mut transcript);
+        assert_eq!(transcript.text, "the report");
+    }
+
+    #[test]
+    fn context_is_the_end_of_the_committed_text() {
+        let mut state = StreamingState::default();
+        assert_eq!(state.context(), None);
+
+        state.committed = "Short and sweet.".to_string();
+        assert_eq!(state.context().as_deref(), Some("Short and sweet."));
+
+        state.committed = format!("{} last words.", "word ".repeat(100));
+        let context = state.context().unwrap();
+        assert!(context.chars().count() <= MAX_CONTEXT_CHARS);
+        assert!(context.starts_with("word "));
+        assert!(context.ends_with("last words."));
+    }
+
+    #[test]
+    fn long_audio_is_cut_in_pauses() {
+        let second = 16000;
+        // Speech throughout but for a pause from 50s to 52s
+        let speech = [0..50 * second, 52 * second..150 * second];
+        let chunks = long_audio_chunks(150 * second, &speech);
+        assert_eq!(
+            chunks,
+            [
+                0..51 * second,
+                // No pause near the end, so cut at full length with overlap
+                51 * second..111 * second,
+                109 * second..150 * second,
+            ]
+        );
+    }
+
+    #[test]
+    fn partial_window_stretches_for_slow_passes() {
+        // Fast passes keep the target
+        assert_eq!(next_partial_window(800, 800, 200), 800);
+        // A slow pass moves halfway to a quarter more than it took
+        assert_eq!(next_partial_window(800, 800, 1600), 1400);
+        assert_eq!(next_partial_window(1400, 800, 1600), 1700);
+        // and it relaxes back once passes speed up
+        assert_eq!(next_partial_window(1700, 800, 300), 1250);
+        assert_eq!(next_partial_window(800, 800, 60_000), 2900);
+    }
+
+    #[test]
+    fn text_rules_rewrite_the_text_and_its_segments() {
+        let mut settings = crate::settings::get_default_settings();
+        settings.text_normalization = vec!["en".to_string()];
+        settings.profanity_filter = ProfanityFilter::Keep;
+        let mut transcript = pass(&[("five percent", 0, 800)]);
+        transcript.language = Some("en".to_string());
+
+        apply_text_rules(&mut transcript, &settings, false, true);
+        assert_eq!(transcript.text, "5%");
+        assert_eq!(transcript.segments[0].text, "5%");
+    }
+}
@@ -0,0 +1,303 @@
+//! Turn-taking for the transcription engine.
+//!
+//! Dictation, live previews, file imports and retranscriptions all share one
+//! loaded model. Each of them registers a job here and waits for its turn
+//! before every pass through the engine; when the engine frees up, the
+//! waiting job with the highest priority goes next, oldest first among
+//! equals. A job of many passes, like a file transcribed one speaker turn at
+//! a time, gives dictation a chance to cut in between passes.
+//!
+//! A job can be cancelled while it waits or while it runs. A pass that is
+//! already in the engine can't be interrupted, but its result is dropped.
+
+use anyhow::Result;
+use serde::Serialize;
+use std::cmp::Reverse;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum JobPriority {
+    /// Files and retranscriptions, which nobody is watching word by word.
+    Background,
+    /// Live previews of the dictation in progress.
+    Partial,
+    /// Work the user is waiting on: dictation, captions, meetings.
+    Interactive,
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum JobState {
+    /// Registered, but not asking for the engine right now.
+    Idle,
+    Waiting,
+    Running,
+}
+
+/// A job as listed for the user.
+#[derive(Serialize, Debug, Clone)]
+pub struct JobInfo {
+    pub id: u64,
+    pub label: String,
+    pub priority: JobPriority,
+    pub state: JobState,
+}
+
+struct Entry {
+    id: u64,
+    label: String,
+    priority: JobPriority,
+    waiting: bool,
+    cancelled: Arc<AtomicBool>,
+}
+
+#[derive(Default)]
+struct QueueState {
+    next_id: u64,
+    /// Registered jobs, oldest first.
+    jobs: Vec<Entry>,
+    running: Option<u64>,
+}
+
+impl QueueState {
+    /// The waiting job that gets the engine next.
+    fn next_up(&self) -> Option<u64> {
+        self.jobs
+            .iter()
+            .filter(|entry| entry.waiting)
+            .max_by_key(|entry| (entry.priority, Reverse(entry.id)))
+            .map(|entry| entry.id)
+    }
+}
+
+#[derive(Default)]
+pub struct TranscriptionQueue {
+    state: Mutex<QueueState>,
+    turn_changed: Condvar,
+}
+
+impl TranscriptionQueue {
+    /// Register a job. It stays listed until the returned handle is dropped.
+    pub fn submit(self: &Arc<Self>, priority: JobPriority, label: &str) -> TranscriptionJob {
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let mut state = self.state.lock().unwrap();
+        state.next_id += 1;
+        let id = state.next_id;
+        state.jobs.push(Entry {
+            id,
+            label: label.to_string(),
+            priority,
+            waiting: false,
+            cancelled: cancelled.clone(),
+        });
+        TranscriptionJob {
+            id,
+            queue: self.clone(),
+            cancelled,
+        }
+    }
+
+    /// Cancel job `id`. Returns false if there is no such job.
+    pub fn cancel(&self, id: u64) -> bool {
+        let state = self.state.lock().unwrap();
+        let Some(entry) = state.jobs.iter().find(|entry| entry.id == id) else {
+            return false;
+        };
+        entry.cancelled.store(true, Ordering::SeqCst);
+        self.turn_changed.notify_all();
+        true
+    }
+
+    pub fn jobs(&self) -> Vec<JobInfo> {
+        let state = self.state.lock().unwrap();
+        state
+            .jobs
+            .iter()
+            .map(|entry| JobInfo {
+                id: entry.id,
+                label: entry.label.clone(),
+                priority: entry.priority,
+                state: if state.running == Some(entry.id) {
+                    JobState::Running
+                } else if entry.waiting {
+                    JobState::Waiting
+                } else {
+                    JobState::Idle
+                },
+            })
+            .collect()
+    }
+
+    fn set_waiting(state: &mut QueueState, id: u64, waiting: bool) {
+        if let Some(entry) = state.jobs.iter_mut().find(|entry| entry.id == id) {
+            entry.waiting = waiting;
+        }
+    }
+}
+
+/// Error for a pass of a job that was cancelled, so callers can tell it
+/// from the engine failing.
+#[derive(Debug)]
+pub struct JobCancelled {
+    pub id: u64,
+}
+
+impl fmt::Display for JobCancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Transcription job {} was cancelled", self.id)
+    }
+}
+
+impl std::error::Error for JobCancelled {}
+
+/// Handle of a registered job. Dropping it takes the job off the queue.
+pub struct TranscriptionJob {
+    id: u64,
+    queue: Arc<TranscriptionQueue>,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl TranscriptionJob {
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Error for a pass of this job that was cancelled.
+    pub fn cancelled_error(&self) -> anyhow::Error {
+        JobCancelled { id: self.id }.into()
+    }
+
+    /// Wait until this job may use the engine. The engine is held until the
+    /// returned turn is dropped.
+    pub fn turn(&self) -> Result<Turn<'_>> {
+        let mut state = self.queue.state.lock().unwrap();
+        TranscriptionQueue::set_waiting(&mut state, self.id, true);
+        loop {
+            if self.is_cancelled() {
+                TranscriptionQueue::set_waiting(&mut state, self.id, false);
+                // Someone else may be next now
+                self.queue.turn_changed.notify_all();
+                return Err(self.cancelled_error());
+            }
+            if state.running.is_none() && state.next_up() == Some(self.id) {
+                TranscriptionQueue::set_waiting(&mut state, self.id, false);
+                state.running = Some(self.id);
+                return Ok(Turn { job: self });
+            }
+            state = self.queue.turn_changed.wait(state).unwrap();
+        }
+    }
+}
+
+impl Drop for TranscriptionJob {
+    fn drop(&mut self) {
+        let mut state = self.queue.state.lock().unwrap();
+        state.jobs.retain(|entry| entry.id != self.id);
+        self.queue.turn_changed.notify_all();
+    }
+}
+
+/// A job's hold on the engine.
+pub struct Turn<'a> {
+    job: &'a TranscriptionJob,
+}
+
+impl Drop for Turn<'_> {
+    fn drop(&mut self) {
+        let mut state = self.job.queue.state.lock().unwrap();
+        if state.running == Some(self.job.id) {
+            state.running = None;
+        }
+        self.job.queue.turn_changed.notify_all();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+    use std::time::Duration;
+
+    /// Wait until `count` jobs are waiting for the engine.
+    fn wait_for_waiting(queue: &TranscriptionQueue, count: usize) {
+        while queue
+            .jobs()
+            .iter()
+            .filter(|job| job.state == JobState::Waiting)
+            .count()
+            < count
+        {
+            thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    #[test]
+    fn higher_priority_goes_first_then_oldest() {
+        let queue = Arc::new(TranscriptionQueue::default());
+        let holder = queue.submit(JobPriority::Interactive, "holder");
+        let turn = holder.turn().unwrap();
+
+        let order = Arc::new(Mutex::new(Vec::new()));
+        let handles: Vec<_> = [
+            (JobPriority::Background, "file"),
+            (JobPriority::Interactive, "dictation"),
+            (JobPriority::Partial, "preview"),
+            (JobPriority::Interactive, "second dictation"),
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(i, (priority, label))| {
+            // Submit in order so ids, and ties, are deterministic
+            let job = queue.submit(priority, label);
+            let order = order.clone();
+            let queue = queue.clone();
+            let handle = thread::spawn(move || {
+                let _turn = job.turn().unwrap();
+                order.lock().unwrap().push(label);
+            });
+            wait_for_waiting(&queue, i + 1);
+            handle
+        })
+        .collect();
+
+        drop(turn);
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(
+            *order.lock().unwrap(),
+            ["dictation", "second dictation", "preview", "file"]
+        );
+    }
+
+    #[test]
+    fn cancelling_a_waiting_job_releases_it() {
+        let queue = Arc::new(TranscriptionQueue::default());
+        let holder = queue.submit(JobPriority::Interactive, "holder");
+        let _turn = holder.turn().unwrap();
+
+        let job = queue.submit(JobPriority::Background, "file");
+        let id = job.id();
+        let handle = thread::spawn(move || job.turn().map(|_| ()).is_err());
+        wait_for_waiting(&queue, 1);
+
+        assert!(queue.cancel(id));
+        assert!(handle.join().unwrap());
+        assert!(!queue.cancel(id), "dropped jobs are no longer listed");
+    }
+
+    #[test]
+    fn idle_jobs_dont_hold_up_others() {
+        let queue = Arc::new(TranscriptionQueue::default());
+        let _idle = queue.submit(JobPriority::Interactive, "between passes");
+        let file = queue.submit(JobPriority::Background, "file");
+        assert!(file.turn().is_ok());
+    }
+}
@@ -4,10 +4,13 @@
 
 use anyhow::{Context, Result};
 use log::{debug, info, warn};
+use std::collections::HashMap;
 use std::sync::Mutex;
 use tts::{Features, Tts, Voice};
 use whichlang::detect_language;
 
+use crate::helpers::{language_detect, pronunciation};
+
 /// Manager for system TTS with automatic language detection
 pub struct TtsManager {
     system_tts: Mutex<Option<Tts>>,
@@ -50,25 +53,7 @@ impl TtsManager {
 
     /// Detect the language of the given text
     fn detect_language(&self, text: &str) -> Option<String> {
-        let detected = detect_language(text);
-        let lang_code = match detected {
-            whichlang::Lang::Ara => "ar",
-            whichlang::Lang::Cmn => "zh",
-            whichlang::Lang::Deu => "de",
-            whichlang::Lang::Eng => "en",
-            whichlang::Lang::Fra => "fr",
-            whichlang::Lang::Hin => "hi",
-            whichlang::Lang::Ita => "it",
-            whichlang::Lang::Jpn => "ja",
-            whichlang::Lang::Kor => "ko",
-            whichlang::Lang::Nld => "nl",
-            whichlang::Lang::Por => "pt",
-            whichlang::Lang::Rus => "ru",
-            whichlang::Lang::Spa => "es",
-            whichlang::Lang::Swe => "sv",
-            whichlang::Lang::Tur => "tr",
-            whichlang::Lang::Vie => "vi",
-        };
+        let lang_code = language_detect::lang_code(detect_language(text));
         info!(
             "Detected language: {} for text: {}...",
             lang_code,
@@ -99,11 +84,14 @@ impl TtsManager {
         })
     }
 
-    /// Speak text using system TTS with automatic language detection and voice selection
-    pub fn speak(&self, text: &str) -> Result<()> {
+    /// Speak text using system TTS with automatic language detection and voice selection.
+    /// Words in `pronunciations` are read as their respellings; the language is
+    /// still detected from the text as written.
+    pub fn speak(&self, text: &str, pronunciations: &HashMap<String, String>) -> Result<()> {
         if !self.is_ready() {
             self.initialize()?;
         }
+        let spoken = pronunciation::apply(text, pronunciations);
 
         // Detect language and find appropriate voice
         if let Some(detected_lang) = self.detect_language(text) {
@@ -120,8 +108,8 @@ impl TtsManager {
                     warn!("Failed to set auto-detected voice: {}, using default", e);
                 }
 
-                debug!("Speaking with auto-detected voice: {}", text);
-                tts.speak(text, false).context("Failed to speak text")?;
+                debug!("Speaking with auto-detected voice: {}", spoken);
+                tts.speak(spoken, false).context("Failed to speak text")?;
                 return Ok(());
             }
         }
@@ -130,7 +118,7 @@ impl TtsManager {
         debug!("Falling back to default voice");
         let mut guard = self.system_tts.lock().unwrap();
         let tts = guard.as_mut().context("TTS not initialized")?;
-        tts.speak(text, false).context("Failed to speak text")?;
+        tts.speak(spoken, false).context("Failed to speak text")?;
         Ok(())
     }
 }
@@ -0,0 +1,211 @@
+//! Voice profile for dictation.
+//!
+//! The user enrolls by reading a short passage aloud. The speaker embedding of
+//! that recording, from the same 3D-Speaker model diarization uses, is kept on
+//! disk. With the voice filter on, dictations are cut into short windows and
+//! only the windows that sound like the enrolled voice are transcribed, so a
+//! TV or a colleague in the background doesn't end up in the text.
+
+use anyhow::{anyhow, Context, Result};
+use chrono::Utc;
+use log::{debug, info};
+use serde::{Deserialize, Serialize};
+use sherpa_rs::speaker_id::{EmbeddingExtractor, ExtractorConfig};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use tauri::AppHandle;
+
+use super::model::ModelManager;
+
+/// Binding id the enrollment recording runs under.
+pub const ENROLLMENT_BINDING_ID: &str = "voice_enrollment";
+
+const EMBEDDING_MODEL_ID: &str = "diarization-embedding";
+const SAMPLE_RATE: u32 = 16000;
+/// Shortest enrollment that gives a usable embedding.
+const MIN_ENROLLMENT_SAMPLES: usize = 16000 * 5;
+/// Dictations are judged 1.5 s at a time.
+const WINDOW_SAMPLES: usize = 16000 * 3 / 2;
+/// A trailing window shorter than this follows the decision for the one before.
+const MIN_WINDOW_SAMPLES: usize = 16000 / 2;
+/// Cosine similarity from which a window counts as the enrolled speaker.
+const MATCH_THRESHOLD: f32 = 0.5;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VoiceProfile {
+    pub embedding: Vec<f32>,
+    pub enrolled_at: i64,
+    /// Length of the enrollment recording.
+    pub duration_secs: f32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct VoiceProfileStatus {
+    pub enrolled: bool,
+    pub enrolled_at: Option<i64>,
+    pub duration_secs: Option<f32>,
+    /// Whether the speaker embedding model is downloaded.
+    pub model_available: bool,
+}
+
+pub struct VoiceProfileManager {
+    model_manager: Arc<ModelManager>,
+    path: PathBuf,
+    profile: Mutex<Option<VoiceProfile>>,
+}
+
+impl VoiceProfileManager {
+    pub fn new(app_handle: &AppHandle, model_manager: Arc<ModelManager>) -> Result<Self> {
+        let path = crate::profile::data_dir(app_handle)?.join("voice_profile.json");
+        let profile = if path.exists() {
+            let json = fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read voice profile at {:?}", path))?;
+            Some(serde_json::from_str(&json).context("Failed to parse voice profile")?)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            model_manager,
+            path,
+            profile: Mutex::new(profile),
+        })
+    }
+
+    pub fn status(&self) -> VoiceProfileStatus {
+        let profile = self.profile.lock().unwrap();
+        VoiceProfileStatus {
+            enrolled: profile.is_some(),
+            enrolled_at: profile.as_ref().map(|p| p.enrolled_at),
+            duration_secs: profile.as_ref().map(|p| p.duration_secs),
+            model_available: self
+                .model_manager
+                .get_model_path(EMBEDDING_MODEL_ID)
+                .is_ok(),
+        }
+    }
+
+    /// Build the profile from a recording of the user reading, replacing any
+    /// earlier one.
+    pub fn enroll(&self, samples: &[f32]) -> Result<VoiceProfileStatus> {
+        if samples.len() < MIN_ENROLLMENT_SAMPLES {
+            return Err(anyhow!(
+                "Read for at least {} seconds to enroll",
+                MIN_ENROLLMENT_SAMPLES / SAMPLE_RATE as usize
+            ));
+        }
+        let embedding = self
+            .extractor()?
+            .compute_speech_embedding(samples.to_vec(), SAMPLE_RATE)
+            .map_err(|e| anyhow!("Failed to compute voice embedding: {}", e))?;
+        let profile = VoiceProfile {
+            embedding,
+            enrolled_at: Utc::now().timestamp(),
+            duration_secs: samples.len() as f32 / SAMPLE_RATE as f32,
+        };
+
+        fs::write(&self.path, serde_json::to_string(&profile)?)
+            .with_context(|| format!("Failed to write voice profile to {:?}", self.path))?;
+        info!(
+            "Enrolled voice profile from {:.1}s of audio",
+            profile.duration_secs
+        );
+        *self.profile.lock().unwrap() = Some(profile);
+        Ok(self.status())
+    }
+
+    pub fn delete(&self) -> Result<()> {
+        if self.path.exists() {
+            fs::remove_file(&self.path)
+                .with_context(|| format!("Failed to delete voice profile at {:?}", self.path))?;
+        }
+        *self.profile.lock().unwrap() = None;
+        Ok(())
+    }
+
+    /// Keep only the parts of a dictation spoken by the enrolled voice.
+    /// Returns the samples untouched when no profile is enrolled.
+    pub fn filter(&self, samples: &[f32]) -> Result<Vec<f32>> {
+        let Some(profile) = self.profile.lock().unwrap().clone() else {
+            return Ok(samples.to_vec());
+        };
+        let mut extractor = self.extractor()?;
+
+        let mut keep = Vec::new();
+        for window in samples.chunks(WINDOW_SAMPLES) {
+            if window.len() < MIN_WINDOW_SAMPLES {
+                keep.push(keep.last().copied().unwrap_or(true));
+                continue;
+            }
+            let embedding = extractor
+                .compute_speech_embedding(window.to_vec(), SAMPLE_RATE)
+                .map_err(|e| anyhow!("Failed to compute voice embedding: {}", e))?;
+            keep.push(cosine_similarity(&embedding, &profile.embedding) >= MATCH_THRESHOLD);
+        }
+
+        let kept = keep_windows(samples, &keep);
+        debug!(
+            "Voice filter kept {:.1}s of {:.1}s",
+            kept.len() as f32 / SAMPLE_RATE as f32,
+            samples.len() as f32 / SAMPLE_RATE as f32
+        );
+        Ok(kept)
+    }
+
+    fn extractor(&self) -> Result<EmbeddingExtractor> {
+        let model = self
+            .model_manager
+            .get_model_path(EMBEDDING_MODEL_ID)
+            .context("Speaker embedding model not available")?;
+        EmbeddingExtractor::new(ExtractorConfig {
+            model: model.to_string_lossy().to_string(),
+            ..Default::default()
+        })
+        .map_err(|e| anyhow!("Failed to load speaker embedding model: {}", e))
+    }
+}
+
+fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm = |v: &[f32]| v.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let denom = norm(a) * norm(b);
+    if denom == 0.0 {
+        0.0
+    } else {
+        dot / denom
+    }
+}
+
+/// The windows of `samples` marked in `keep`, joined back together.
+fn keep_windows(samples: &[f32], keep: &[bool]) -> Vec<f32> {
+    samples
+        .chunks(WINDOW_SAMPLES)
+        .zip(keep)
+        .filter(|(_, keep)| **keep)
+        .flat_map(|(window, _)| window.iter().copied())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cosine_similarity_of_directions() {
+        assert!((cosine_similarity(&[1.0, 2.0], &[2.0, 4.0]) - 1.0).abs() < 1e-6);
+        assert!(cosine_similarity(&[1.0, 0.0], &[0.0, 1.0]).abs() < 1e-6);
+        assert_eq!(cosine_similarity(&[0.0, 0.0], &[1.0, 1.0]), 0.0);
+    }
+
+    #[test]
+    fn keeps_marked_windows_in_order() {
+        let samples: Vec<f32> = (0..WINDOW_SAMPLES * 3)
+            .map(|i| (i / WINDOW_SAMPLES) as f32)
+            .collect();
+        let kept = keep_windows(&samples, &[true, false, true]);
+        assert_eq!(kept.len(), WINDOW_SAMPLES * 2);
+        assert_eq!(kept[0], 0.0);
+        assert_eq!(kept[WINDOW_SAMPLES], 2.0);
+    }
+}
@@ -1,4 +1,7 @@
-use crate::settings::{self, OverlayPosition};
+use crate::helpers::{event_throttle, focus_mode, native_indicator};
+use crate::managers::transcription::StreamingProgress;
+use crate::settings::{self, AppSettings, OverlayPosition};
+use crate::tray::TrayIconState;
 #[cfg(not(target_os = "linux"))]
 use enigo::{Enigo, Mouse};
 use log::{debug, info, warn};
@@ -222,6 +225,7 @@ pub fn create_recording_overlay(app_handle: &AppHandle) {
             }
             Err(e) => {
                 warn!("[Overlay] Failed to create recording overlay window: {}", e);
+                native_indicator::enable(app_handle, e.to_string());
             }
         }
     } else {
@@ -229,18 +233,29 @@ pub fn create_recording_overlay(app_handle: &AppHandle) {
     }
 }
 
+/// Whether the overlay is turned off, either by position or by an active OS focus mode.
+fn overlay_disabled(settings: &AppSettings) -> bool {
+    settings.overlay_position == OverlayPosition::None
+        || focus_mode::should_suppress_feedback(settings)
+}
+
 /// Shows the recording overlay window with fade-in animation.
 /// Uses `run_on_main_thread` so that GTK/layer-shell operations happen on the
 /// correct thread (required on Wayland).
-pub fn show_recording_overlay(app_handle: &AppHandle) {
+pub fn show_recording_overlay(app_handle: &AppHandle, binding_id: &str) {
     let app_handle = app_handle.clone();
+    let binding_id = binding_id.to_string();
     let app_handle_inner = app_handle.clone();
 
     let _ = app_handle.run_on_main_thread(move || {
         let app_handle = app_handle_inner;
         // Check if overlay should be shown based on position setting
         let settings = settings::get_settings(&app_handle);
-        if settings.overlay_position == OverlayPosition::None {
+        if overlay_disabled(&settings) {
+            return;
+        }
+        if native_indicator::is_active() {
+            native_indicator::show(&app_handle, TrayIconState::Recording);
             return;
         }
 
@@ -285,12 +300,22 @@ pub fn show_recording_overlay(app_handle: &AppHandle) {
                 OverlayPosition::Top => "top",
                 OverlayPosition::Bottom | OverlayPosition::None => "bottom",
             };
+            // The shortcut that stops this recording, for the hint
+            let payload = serde_json::json!({
+                "state": "recording",
+                "shortcut": settings
+                    .bindings
+                    .get(&binding_id)
+                    .map(|binding| binding.current_binding.as_str()),
+                "mode": if settings.push_to_talk { "hold" } else { "toggle" },
+                "show_hint": settings.overlay_shortcut_hint
+            });
             let overlay_clone = overlay_window.clone();
             let pos = position.to_string();
             std::thread::spawn(move || {
                 std::thread::sleep(std::time::Duration::from_millis(50));
                 let _ = overlay_clone.emit("overlay-position", &pos);
-                let _ = overlay_clone.emit("show-overlay", "recording");
+                let _ = overlay_clone.emit("show-overlay", payload);
             });
         }
     });
@@ -307,7 +332,11 @@ pub fn show_transcribing_overlay(app_handle: &AppHandle) {
         let app_handle = app_handle_inner;
         // Check if overlay should be shown based on position setting
         let settings = settings::get_settings(&app_handle);
-        if settings.overlay_position == OverlayPosition::None {
+        if overlay_disabled(&settings) {
+            return;
+        }
+        if native_indicator::is_active() {
+            native_indicator::show(&app_handle, TrayIconState::Transcribing);
             return;
         }
 
@@ -340,7 +369,11 @@ pub fn show_transcribing_overlay(app_handle: &AppHandle) {
 pub fn show_warning_overlay(app_handle: &AppHandle, message: &str) {
     // Check if overlay should be shown based on position setting
     let settings = settings::get_settings(app_handle);
-    if settings.overlay_position == OverlayPosition::None {
+    if overlay_disabled(&settings) {
+        return;
+    }
+    if native_indicator::is_active() {
+        native_indicator::notify(app_handle, message);
         return;
     }
 
@@ -376,10 +409,44 @@ pub fn show_warning_overlay(app_handle: &AppHandle, message: &str) {
     }
 }
 
+/// Shows the pre-recording countdown with the number of seconds remaining.
+/// Stays visible until the recording overlay replaces it or the overlay is hidden.
+pub fn show_countdown_overlay(app_handle: &AppHandle, remaining: u32) {
+    let settings = settings::get_settings(app_handle);
+    if overlay_disabled(&settings) {
+        return;
+    }
+
+    update_overlay_position(app_handle);
+
+    if let Some(overlay_window) = app_handle.get_webview_window("recording_overlay") {
+        #[cfg(target_os = "linux")]
+        if crate::wayland::is_wayland() {
+            crate::wayland::present_gnome_overlay(&overlay_window);
+        }
+        let position = match settings.overlay_position {
+            OverlayPosition::Top => "top",
+            OverlayPosition::Bottom | OverlayPosition::None => "bottom",
+        };
+        let _ = overlay_window.emit("overlay-position", position);
+        let _ = overlay_window.emit(
+            "show-overlay",
+            serde_json::json!({
+                "state": "countdown",
+                "message": remaining.to_string()
+            }),
+        );
+    }
+}
+
 /// Shows a tool result overlay with a custom message (auto-hides after 3 seconds)
 pub fn show_tool_overlay(app_handle: &AppHandle, message: &str) {
     let settings = settings::get_settings(app_handle);
-    if settings.overlay_position == OverlayPosition::None {
+    if overlay_disabled(&settings) {
+        return;
+    }
+    if native_indicator::is_active() {
+        native_indicator::notify(app_handle, message);
         return;
     }
 
@@ -412,6 +479,49 @@ pub fn show_tool_overlay(app_handle: &AppHandle, message: &str) {
     }
 }
 
+/// Shows a held low-confidence dictation with its choices. The overlay takes
+/// clicks until it is hidden again.
+pub fn show_low_confidence_overlay(
+    app_handle: &AppHandle,
+    event: &crate::managers::confidence_review::LowConfidenceEvent,
+) {
+    let settings = settings::get_settings(app_handle);
+    if overlay_disabled(&settings) {
+        return;
+    }
+    if native_indicator::is_active() {
+        native_indicator::notify(app_handle, "Low confidence transcription held");
+        return;
+    }
+
+    update_overlay_position(app_handle);
+
+    if let Some(overlay_window) = app_handle.get_webview_window("recording_overlay") {
+        #[cfg(target_os = "linux")]
+        if crate::wayland::is_wayland() {
+            crate::wayland::present_gnome_overlay(&overlay_window);
+        }
+        let position = match settings.overlay_position {
+            OverlayPosition::Top => "top",
+            OverlayPosition::Bottom | OverlayPosition::None => "bottom",
+        };
+        if let Err(e) = overlay_window.set_ignore_cursor_events(false) {
+            warn!("[Overlay] Failed to make overlay clickable: {}", e);
+        }
+        let _ = overlay_window.emit("overlay-position", position);
+        let _ = overlay_window.emit(
+            "show-overlay",
+            serde_json::json!({
+                "state": "confidence",
+                "message": event.text,
+                "confidence": event.confidence,
+                "low_confidence_words": event.low_confidence_words,
+                "larger_model": event.larger_model
+            }),
+        );
+    }
+}
+
 /// Updates the overlay window position and size for the current monitor (multi-monitor support)
 pub fn update_overlay_position(app_handle: &AppHandle) {
     if let Some(overlay_window) = app_handle.get_webview_window("recording_overlay") {
@@ -431,30 +541,61 @@ pub fn update_overlay_position(app_handle: &AppHandle) {
 pub fn hide_recording_overlay(app_handle: &AppHandle) {
     // Always hide the overlay regardless of settings - if setting was changed while recording,
     // we still want to hide it properly
+    native_indicator::hide();
     if let Some(overlay_window) = app_handle.get_webview_window("recording_overlay") {
         // Emit event to trigger fade-out animation (window stays visible for CSS/Framer Motion transitions)
         let _ = overlay_window.emit("hide-overlay", ());
+        // Back to click-through in case a low-confidence review made it clickable
+        let _ = overlay_window.set_ignore_cursor_events(true);
+    }
+}
+
+/// Briefly flash the overlay as silent start/stop feedback.
+pub fn flash_overlay(app_handle: &AppHandle) {
+    let settings = settings::get_settings(app_handle);
+    if overlay_disabled(&settings) {
+        return;
+    }
+    if let Some(overlay_window) = app_handle.get_webview_window("recording_overlay") {
+        let _ = overlay_window.emit("overlay-flash", ());
+    }
+}
+
+/// Replace the shortcut hint under the recording overlay with `message`,
+/// keeping the recording visible. Cleared when the next recording starts.
+pub fn show_recording_hint(app_handle: &AppHandle, message: &str) {
+    let settings = settings::get_settings(app_handle);
+    if overlay_disabled(&settings) {
+        return;
+    }
+    if native_indicator::is_active() {
+        native_indicator::notify(app_handle, message);
+        return;
+    }
+    if let Some(overlay_window) = app_handle.get_webview_window("recording_overlay") {
+        let _ = overlay_window.emit("recording-hint", message);
     }
 }
 
 pub fn emit_levels(app_handle: &AppHandle, levels: &Vec<f32>) {
     // emit levels to main app
-    let _ = app_handle.emit("mic-level", levels);
+    event_throttle::emit(app_handle, "mic-level", levels);
 
     // also emit to the recording overlay if it's open
-    if let Some(overlay_window) = app_handle.get_webview_window("recording_overlay") {
-        let _ = overlay_window.emit("mic-level", levels);
-    }
+    event_throttle::emit_to_window(app_handle, "recording_overlay", "mic-level", levels);
 }
 
-pub fn emit_transcription_progress(app_handle: &AppHandle, text: &str) {
+pub fn emit_transcription_progress(app_handle: &AppHandle, progress: &StreamingProgress) {
     // emit to main app
-    let _ = app_handle.emit("transcription-progress", text);
+    event_throttle::emit(app_handle, "transcription-progress", progress);
 
     // also emit to the recording overlay if it's open
-    if let Some(overlay_window) = app_handle.get_webview_window("recording_overlay") {
-        let _ = overlay_window.emit("transcription-progress", text);
-    }
+    event_throttle::emit_to_window(
+        app_handle,
+        "recording_overlay",
+        "transcription-progress",
+        progress,
+    );
 }
 
 #[cfg(test)]
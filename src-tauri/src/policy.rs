@@ -0,0 +1,167 @@
+//! Managed settings policy.
+//!
+//! For managed deployments, an administrator can lock settings with a
+//! read-only policy file holding a JSON object of setting names and values,
+//! like `{ "input_tracking_enabled": false }`. Locked settings override the
+//! user's, whatever the app or the settings file says. The file is read once
+//! at launch, from `ECHO_POLICY_FILE` when set, otherwise from:
+//!
+//! - macOS: `/Library/Application Support/Echo/policy.json`
+//! - Windows: `%ProgramData%\Echo\policy.json`
+//! - Linux: `/etc/echo/policy.json`
+
+use log::{info, warn};
+use once_cell::sync::OnceCell;
+use serde_json::{Map, Value};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::AppHandle;
+
+use crate::settings::{self, AppSettings};
+
+/// Overrides the system policy location.
+const POLICY_ENV: &str = "ECHO_POLICY_FILE";
+
+static POLICY: OnceCell<Map<String, Value>> = OnceCell::new();
+
+/// Read the policy file and write the locked values into the settings store.
+/// Must run before settings are read.
+pub fn init(app: &AppHandle) {
+    let policy = policy_path()
+        .map(|path| read_policy(&path))
+        .unwrap_or_default();
+    if policy.is_empty() {
+        return;
+    }
+    let _ = POLICY.set(policy);
+    info!("Settings policy locks: {}", locked_keys().join(", "));
+    // Writing back puts the locked values in the store the UI reads
+    settings::update_settings(app, |_| {});
+}
+
+/// Names of the settings the policy locks.
+pub fn locked_keys() -> Vec<String> {
+    POLICY
+        .get()
+        .map(|policy| policy.keys().cloned().collect())
+        .unwrap_or_default()
+}
+
+/// `settings` with the locked values in place.
+pub fn apply(settings: AppSettings) -> AppSettings {
+    match POLICY.get() {
+        Some(policy) => overlay(settings, policy),
+        None => settings,
+    }
+}
+
+fn overlay(settings: AppSettings, policy: &Map<String, Value>) -> AppSettings {
+    let mut value = match serde_json::to_value(&settings) {
+        Ok(value) => value,
+        Err(_) => return settings,
+    };
+    if let Value::Object(map) = &mut value {
+        for (key, locked) in policy {
+            map.insert(key.clone(), locked.clone());
+        }
+    }
+    serde_json::from_value(value).unwrap_or(settings)
+}
+
+fn policy_path() -> Option<PathBuf> {
+    if let Some(path) = std::env::var_os(POLICY_ENV) {
+        return Some(PathBuf::from(path));
+    }
+    #[cfg(target_os = "macos")]
+    {
+        Some(PathBuf::from(
+            "/Library/Application Support/Echo/policy.json",
+        ))
+    }
+    #[cfg(target_os = "windows")]
+    {
+        std::env::var_os("ProgramData")
+            .map(|dir| PathBuf::from(dir).join("Echo").join("policy.json"))
+    }
+    #[cfg(target_os = "linux")]
+    {
+        Some(PathBuf::from("/etc/echo/policy.json"))
+    }
+    #[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
+    {
+        None
+    }
+}
+
+/// The policy at `path`, keeping only entries that name a setting and hold
+/// a valid value for it. A missing file is an empty policy.
+fn read_policy(path: &Path) -> Map<String, Value> {
+    let Ok(contents) = fs::read_to_string(path) else {
+        return Map::new();
+    };
+    let entries = match serde_json::from_str::<Value>(&contents) {
+        Ok(Value::Object(entries)) => entries,
+        _ => {
+            warn!("Ignoring settings policy {:?}: not a JSON object", path);
+            return Map::new();
+        }
+    };
+    validate(entries, &settings::get_default_settings())
+}
+
+fn validate(entries: Map<String, Value>, defaults: &AppSettings) -> Map<String, Value> {
+    let known = match serde_json::to_value(defaults) {
+        Ok(Value::Object(known)) => known,
+        _ => return Map::new(),
+    };
+    entries
+        .into_iter()
+        .filter(|(key, value)| {
+            if !known.contains_key(key) {
+                warn!("Ignoring unknown setting '{}' in settings policy", key);
+                return false;
+            }
+            let mut single = known.clone();
+            single.insert(key.clone(), value.clone());
+            let valid = serde_json::from_value::<AppSettings>(Value::Object(single)).is_ok();
+            if !valid {
+                warn!("Ignoring invalid value for '{}' in settings policy", key);
+            }
+            valid
+        })
+        .collect()
+}
+
+/// Names of the settings locked by the administrator's policy.
+#[tauri::command]
+pub fn get_locked_settings() -> Vec<String> {
+    locked_keys()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn only_known_settings_with_valid_values_are_locked() {
+        let defaults = settings::get_default_settings();
+        let entries = json!({
+            "input_tracking_enabled": false,
+            "history_limit": "lots",
+            "no_such_setting": true,
+        });
+        let Value::Object(entries) = entries else {
+            unreachable!()
+        };
+        let policy = validate(entries, &defaults);
+        assert_eq!(
+            policy.keys().collect::<Vec<_>>(),
+            ["input_tracking_enabled"]
+        );
+
+        let mut settings = defaults;
+        settings.input_tracking_enabled = true;
+        assert!(!overlay(settings, &policy).input_tracking_enabled);
+    }
+}
@@ -0,0 +1,236 @@
+//! Data profiles.
+//!
+//! Each profile has its own settings, history database, input tracking
+//! database and recordings, so personal and work data (or several people on
+//! one machine) stay apart. Downloaded models are shared. The default profile
+//! lives directly in the app data directory, other profiles under
+//! `profiles/<name>/`.
+//!
+//! The profile is chosen at launch: `ECHO_PROFILE`, then `--profile <name>`,
+//! then the profile last switched to. Switching restarts the app, since every
+//! manager opens its files at startup.
+
+use log::{info, warn};
+use once_cell::sync::OnceCell;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager};
+
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// Overrides `--profile`; also how a switch survives the restart.
+const PROFILE_ENV: &str = "ECHO_PROFILE";
+const PROFILE_ARG: &str = "--profile";
+const PROFILES_DIR: &str = "profiles";
+/// Remembers the profile last switched to, in the app data directory.
+const PROFILES_FILE: &str = "profiles.json";
+
+static ACTIVE: OnceCell<String> = OnceCell::new();
+
+#[derive(Default, Serialize, Deserialize)]
+struct ProfilesFile {
+    active: Option<String>,
+}
+
+/// Resolve the active profile and create its data directory. Must run before
+/// settings are read or any manager is created.
+pub fn init(app: &AppHandle) {
+    let root = app.path().app_data_dir().ok();
+    let requested = std::env::var(PROFILE_ENV)
+        .ok()
+        .or_else(|| profile_arg(std::env::args()))
+        .or_else(|| {
+            root.as_deref()
+                .and_then(|root| read_profiles_file(root).active)
+        });
+
+    let name = match requested {
+        Some(name) if is_valid_name(&name) => name,
+        Some(name) => {
+            warn!("Invalid profile name '{}', using the default profile", name);
+            DEFAULT_PROFILE.to_string()
+        }
+        None => DEFAULT_PROFILE.to_string(),
+    };
+
+    if let Some(root) = root {
+        let dir = profile_dir(&root, &name);
+        if let Err(e) = fs::create_dir_all(&dir) {
+            warn!("Failed to create profile directory {:?}: {}", dir, e);
+        }
+    }
+    info!("Using profile '{}'", name);
+    let _ = ACTIVE.set(name);
+}
+
+/// Name of the active profile.
+pub fn active() -> &'static str {
+    ACTIVE.get().map_or(DEFAULT_PROFILE, String::as_str)
+}
+
+/// Directory holding the active profile's data.
+pub fn data_dir(app: &AppHandle) -> tauri::Result<PathBuf> {
+    Ok(profile_dir(&app.path().app_data_dir()?, active()))
+}
+
+/// Settings store path of the active profile, relative to the app data
+/// directory as the store plugin expects.
+pub fn settings_store_path() -> PathBuf {
+    profile_dir(Path::new(""), active()).join(crate::settings::SETTINGS_STORE_PATH)
+}
+
+/// All profiles with a data directory, the default one first.
+pub fn list(app: &AppHandle) -> Vec<String> {
+    let mut profiles = vec![DEFAULT_PROFILE.to_string()];
+    let Ok(root) = app.path().app_data_dir() else {
+        return profiles;
+    };
+    let mut named: Vec<String> = fs::read_dir(root.join(PROFILES_DIR))
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .filter(|entry| entry.path().is_dir())
+                .filter_map(|entry| entry.file_name().into_string().ok())
+                .filter(|name| is_valid_name(name) && name != DEFAULT_PROFILE)
+                .collect()
+        })
+        .unwrap_or_default();
+    named.sort();
+    profiles.extend(named);
+    profiles
+}
+
+/// Make `name` the active profile (creating it if needed) and restart.
+pub fn switch(app: &AppHandle, name: &str) -> Result<(), String> {
+    if !is_valid_name(name) {
+        return Err(format!(
+            "Invalid profile name '{}': use letters, digits, '-' and '_'",
+            name
+        ));
+    }
+    if name == active() {
+        return Ok(());
+    }
+
+    let root = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data directory: {}", e))?;
+    fs::create_dir_all(profile_dir(&root, name))
+        .map_err(|e| format!("Failed to create profile: {}", e))?;
+    let file = ProfilesFile {
+        active: Some(name.to_string()),
+    };
+    let json = serde_json::to_string_pretty(&file).map_err(|e| e.to_string())?;
+    fs::write(root.join(PROFILES_FILE), json)
+        .map_err(|e| format!("Failed to save active profile: {}", e))?;
+
+    info!("Switching to profile '{}', restarting", name);
+    // The restarted process inherits the environment, so this also wins over
+    // a `--profile` argument it is relaunched with
+    std::env::set_var(PROFILE_ENV, name);
+    crate::shutdown::run(app);
+    app.restart()
+}
+
+fn profile_dir(root: &Path, name: &str) -> PathBuf {
+    if name == DEFAULT_PROFILE {
+        root.to_path_buf()
+    } else {
+        root.join(PROFILES_DIR).join(name)
+    }
+}
+
+fn read_profiles_file(root: &Path) -> ProfilesFile {
+    fs::read_to_string(root.join(PROFILES_FILE))
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+/// Value of `--profile <name>` or `--profile=<name>`.
+fn profile_arg(args: impl IntoIterator<Item = String>) -> Option<String> {
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if arg == PROFILE_ARG {
+            return args.next();
+        }
+        if let Some(name) = arg.strip_prefix("--profile=") {
+            return Some(name.to_string());
+        }
+    }
+    None
+}
+
+/// Profile names become directory names, so keep them to a safe set.
+pub(crate) fn is_valid_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.len() <= 64
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+#[tauri::command]
+pub fn list_profiles(app: AppHandle) -> Vec<String> {
+    list(&app)
+}
+
+#[tauri::command]
+pub fn get_active_profile() -> String {
+    active().to_string()
+}
+
+#[tauri::command]
+pub fn get_settings_store_path() -> String {
+    settings_store_path().to_string_lossy().into_owned()
+}
+
+#[tauri::command]
+pub fn switch_profile(app: AppHandle, name: String) -> Result<(), String> {
+    switch(&app, &name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn parses_profile_argument() {
+        assert_eq!(
+            profile_arg(args(&["echo", "--profile", "work"])),
+            Some("work".to_string())
+        );
+        assert_eq!(
+            profile_arg(args(&["echo", "--profile=personal"])),
+            Some("personal".to_string())
+        );
+        assert_eq!(profile_arg(args(&["echo", "file.wav"])), None);
+        assert_eq!(profile_arg(args(&["echo", "--profile"])), None);
+    }
+
+    #[test]
+    fn rejects_unsafe_names() {
+        assert!(is_valid_name("work"));
+        assert!(is_valid_name("team_2-a"));
+        assert!(!is_valid_name(""));
+        assert!(!is_valid_name("../other"));
+        assert!(!is_valid_name("my profile"));
+        assert!(!is_valid_name(&"x".repeat(65)));
+    }
+
+    #[test]
+    fn default_profile_uses_the_app_data_root() {
+        let root = Path::new("/data");
+        assert_eq!(profile_dir(root, DEFAULT_PROFILE), PathBuf::from("/data"));
+        assert_eq!(
+            profile_dir(root, "work"),
+            PathBuf::from("/data/profiles/work")
+        );
+    }
+}
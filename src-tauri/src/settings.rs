@@ -4,15 +4,30 @@ use log::warn;
 use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::sync::Mutex;
+use std::sync::{Mutex, RwLock};
 use tauri::AppHandle;
 use tauri_plugin_log::LogLevel;
 use tauri_plugin_store::StoreExt;
 
+use crate::audio_toolkit::audio::GainSettings;
+pub use crate::audio_toolkit::audio::RecordingFormat;
+pub use crate::audio_toolkit::backend::InferenceBackend;
+use crate::managers::history::RecordingMode;
+
 /// Global mutex that serialises all settings reads-and-writes so no
 /// concurrent command can read stale state and clobber another command's update.
 static SETTINGS_LOCK: Lazy<Mutex<()>> = Lazy::new(|| Mutex::new(()));
 
+type SettingsListener = Box<dyn Fn(&AppHandle, &AppSettings) + Send + Sync>;
+
+/// Callbacks run after every settings write, see [`subscribe`].
+static SETTINGS_LISTENERS: Lazy<RwLock<Vec<SettingsListener>>> =
+    Lazy::new(|| RwLock::new(Vec::new()));
+
+/// Version of the settings layout. Bump it alongside a migration in
+/// `apply_settings_migrations_from_raw`.
+pub const SETTINGS_VERSION: u32 = 1;
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ShortcutBinding {
     pub id: String,
@@ -29,6 +44,46 @@ pub struct LLMPrompt {
     pub prompt: String,
 }
 
+/// Text with `{{slot}}` placeholders filled by consecutive dictations.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DictationTemplate {
+    pub id: String,
+    pub name: String,
+    pub template: String,
+}
+
+/// What a spoken dictation command does.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DictationCommandAction {
+    /// Put punctuation or a line break in place of the phrase.
+    Insert { text: String },
+    /// Remove the word before the phrase.
+    DeleteWord,
+    /// Remove the sentence before the phrase.
+    DeleteSentence,
+}
+
+/// A phrase that, spoken while dictating, is replaced by what it stands for.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DictationCommand {
+    pub phrase: String,
+    pub action: DictationCommandAction,
+}
+
+/// A spoken phrase that runs an action instead of being pasted.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct VoiceCommandRule {
+    /// Words to say. A `{name}` placeholder stands for one or more words.
+    pub phrase: String,
+    /// Id of the action to run, as listed by the action registry.
+    pub action: String,
+    /// Parameters for the action. `{name}` in a value is replaced by the
+    /// words the placeholder stood for.
+    #[serde(default)]
+    pub params: HashMap<String, String>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct PostProcessProvider {
     pub id: String,
@@ -40,6 +95,15 @@ pub struct PostProcessProvider {
     pub models_endpoint: Option<String>,
 }
 
+/// A cloud speech-to-text service recordings can be sent to instead of the
+/// local model.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TranscriptionProvider {
+    pub id: String,
+    pub label: String,
+    pub base_url: String,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
 pub enum OverlayPosition {
@@ -82,6 +146,16 @@ pub enum ClipboardHandling {
     CopyToClipboard,
 }
 
+/// What happens to blocklisted words in transcripts.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ProfanityFilter {
+    Keep,
+    /// Keep the first letter and star out the rest.
+    Mask,
+    Remove,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum RecordingRetentionPeriod {
@@ -92,6 +166,19 @@ pub enum RecordingRetentionPeriod {
     Months3,
 }
 
+/// Data the read-only data API can withhold from clients.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum DataApiField {
+    /// Transcribed and post-processed text.
+    Text,
+    Title,
+    /// The post-processing prompt used for an entry.
+    Prompt,
+    /// Names of the apps dictations were pasted into.
+    AppName,
+}
+
 impl Default for ModelUnloadTimeout {
     fn default() -> Self {
         ModelUnloadTimeout::Never
@@ -120,6 +207,12 @@ impl Default for ClipboardHandling {
     }
 }
 
+impl Default for ProfanityFilter {
+    fn default() -> Self {
+        ProfanityFilter::Keep
+    }
+}
+
 impl ModelUnloadTimeout {
     pub fn to_minutes(self) -> Option<u64> {
         match self {
@@ -144,6 +237,26 @@ impl ModelUnloadTimeout {
     }
 }
 
+/// Hours of the day, in local time, during which the model is kept loaded.
+/// The window runs from `start_hour` up to `end_hour` and wraps past midnight
+/// when `end_hour` is the smaller.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WarmHours {
+    pub start_hour: u32,
+    pub end_hour: u32,
+}
+
+impl WarmHours {
+    /// Whether `hour` (0 to 23) falls in the window.
+    pub fn contains(self, hour: u32) -> bool {
+        if self.start_hour <= self.end_hour {
+            (self.start_hour..self.end_hour).contains(&hour)
+        } else {
+            hour >= self.start_hour || hour < self.end_hour
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum SoundTheme {
@@ -242,9 +355,16 @@ pub struct AppSettings {
     pub input_tracking_enabled: bool,
     #[serde(default)]
     pub input_tracking_excluded_apps: Vec<String>,
+    /// Case-insensitive regexes matched against the focused window's title or URL.
+    #[serde(default)]
+    pub input_tracking_excluded_window_patterns: Vec<String>,
     /// Idle timeout in seconds for input tracking. None or 0 means disabled (only count on app switch/click).
     #[serde(default = "default_input_tracking_idle_timeout")]
     pub input_tracking_idle_timeout: Option<u64>,
+    /// Read the clipboard on Cmd/Ctrl+V so pasted text becomes part of the
+    /// tracked entry instead of ending it.
+    #[serde(default = "default_input_tracking_capture_paste")]
+    pub input_tracking_capture_paste: bool,
     #[serde(default)]
     pub tts_enabled: bool,
     #[serde(default)]
@@ -259,6 +379,257 @@ pub struct AppSettings {
     pub meeting_diarization_enabled: bool,
     #[serde(default = "default_diarization_threshold")]
     pub meeting_diarization_threshold: f32,
+    /// Seconds of trailing silence after which a toggle recording stops itself.
+    /// None or 0 means disabled.
+    #[serde(default)]
+    pub auto_stop_silence_secs: Option<u64>,
+    /// Recordings shorter than this are treated as accidental taps and discarded.
+    #[serde(default = "default_min_recording_duration_ms")]
+    pub min_recording_duration_ms: u64,
+    /// Show a 3-2-1 countdown in the overlay before recording starts.
+    #[serde(default)]
+    pub recording_countdown_enabled: bool,
+    /// Push-to-talk releases are held back this long so key bounce doesn't
+    /// split one dictation into several. 0 disables the debounce.
+    #[serde(default)]
+    pub ptt_release_debounce_ms: u64,
+    /// Feed recent text from the focused field to Whisper as its initial prompt,
+    /// so dictation continues in the document's style and terminology.
+    #[serde(default)]
+    pub context_prompt_enabled: bool,
+    /// Keep the overlay and feedback sounds quiet while an OS focus /
+    /// do-not-disturb mode is active.
+    #[serde(default)]
+    pub respect_focus_mode: bool,
+    /// On battery power, use `battery_model`, skip streaming partials and
+    /// lengthen the model idle-unload timeout.
+    #[serde(default)]
+    pub battery_saver_enabled: bool,
+    /// Lighter model to load while on battery. Falls back to `selected_model`
+    /// when unset or not downloaded.
+    #[serde(default)]
+    pub battery_model: Option<String>,
+    /// Compile a Markdown digest of the previous day's activity once a day.
+    #[serde(default)]
+    pub daily_digest_enabled: bool,
+    /// Folder to also write digests to. Digests are always kept in history.
+    #[serde(default)]
+    pub daily_digest_folder: Option<String>,
+    /// Preface digests with a short summary from the post-processing LLM.
+    #[serde(default)]
+    pub daily_digest_llm_summary: bool,
+    /// Run audio capture at realtime priority and partial transcriptions and
+    /// background jobs at low priority. Applies to threads started afterwards.
+    #[serde(default = "default_thread_priorities_enabled")]
+    pub thread_priorities_enabled: bool,
+    /// Serve history and stats read-only on localhost for dashboards.
+    #[serde(default)]
+    pub data_api_enabled: bool,
+    #[serde(default = "default_data_api_port")]
+    pub data_api_port: u16,
+    /// Bearer token clients must send. Generated when the API is first enabled.
+    #[serde(default)]
+    pub data_api_token: String,
+    /// Fields the data API never returns, whatever the client asks for.
+    #[serde(default)]
+    pub data_api_redacted_fields: Vec<DataApiField>,
+    /// Feature flag overrides by flag id. Flags without an entry use their
+    /// built-in default.
+    #[serde(default)]
+    pub feature_flags: HashMap<String, bool>,
+    /// Emit questions detected in live captions as `interview-question` events.
+    #[serde(default)]
+    pub interview_mode_enabled: bool,
+    /// Switch the post-process prompt when a dictation opens with
+    /// "echo, <prompt name>".
+    #[serde(default)]
+    pub voice_prompt_switching_enabled: bool,
+    #[serde(default = "default_dictation_templates")]
+    pub dictation_templates: Vec<DictationTemplate>,
+    /// Hold dictations whose estimated confidence is below
+    /// `low_confidence_threshold` and ask before pasting them.
+    #[serde(default)]
+    pub low_confidence_review_enabled: bool,
+    #[serde(default = "default_low_confidence_threshold")]
+    pub low_confidence_threshold: f64,
+    /// Strip silence with the VAD before transcription.
+    #[serde(default = "default_vad_trim_enabled")]
+    pub vad_trim_enabled: bool,
+    /// Output device per feedback sound (`start`, `stop`). Sounds without an
+    /// entry play on `selected_output_device`.
+    #[serde(default)]
+    pub feedback_sound_devices: HashMap<String, String>,
+    /// Flash the overlay when recording starts and stops.
+    #[serde(default)]
+    pub visual_feedback: bool,
+    /// Tap the trackpad when recording starts and stops (macOS).
+    #[serde(default)]
+    pub haptic_feedback: bool,
+    /// Label speakers when transcribing audio and video files. Uses the
+    /// meeting diarization models and threshold.
+    #[serde(default)]
+    pub file_diarization_enabled: bool,
+    /// Cancel a recording with a warning when the microphone turns out to be
+    /// muted right after it starts.
+    #[serde(default = "default_mute_warning_enabled")]
+    pub mute_warning_enabled: bool,
+    /// Transcribe with a cloud provider, falling back to the local model when
+    /// it can't be reached.
+    #[serde(default)]
+    pub cloud_transcription_enabled: bool,
+    #[serde(default = "default_transcription_provider_id")]
+    pub transcription_provider_id: String,
+    #[serde(default = "default_transcription_providers")]
+    pub transcription_providers: Vec<TranscriptionProvider>,
+    #[serde(default = "default_transcription_api_keys")]
+    pub transcription_api_keys: HashMap<String, String>,
+    #[serde(default = "default_transcription_models")]
+    pub transcription_models: HashMap<String, String>,
+    /// Only transcribe the parts of a dictation spoken by the enrolled voice.
+    #[serde(default)]
+    pub voice_filter_enabled: bool,
+    /// Noise profile whose noise is subtracted from dictations, if any.
+    #[serde(default)]
+    pub active_noise_profile: Option<String>,
+    /// Hardware local models run on.
+    #[serde(default)]
+    pub inference_backend: InferenceBackend,
+    /// Cap on CPU threads for local inference; `None` lets the engine decide.
+    #[serde(default)]
+    pub inference_threads: Option<u32>,
+    /// Names, jargon or example text given to Whisper as its initial prompt,
+    /// so it recognizes them.
+    #[serde(default)]
+    pub initial_prompt: String,
+    /// Also give Whisper the custom words as part of its initial prompt.
+    #[serde(default)]
+    pub initial_prompt_custom_words: bool,
+    /// Tell Whisper which app is frontmost when the recording starts.
+    #[serde(default)]
+    pub initial_prompt_active_app: bool,
+    /// Languages whose punctuation and capitalization rules are applied to
+    /// transcripts in them, as ISO 639-1 codes.
+    #[serde(default)]
+    pub locale_formatting: Vec<String>,
+    /// Smaller model live previews run on while dictating, so they keep up;
+    /// the final transcription still uses the selected model. `None` runs
+    /// previews on the selected model.
+    #[serde(default)]
+    pub preview_model_id: Option<String>,
+    /// Turn spoken commands like "new line" or "delete that" into
+    /// punctuation, line breaks and edits.
+    #[serde(default)]
+    pub dictation_commands_enabled: bool,
+    /// Spoken commands by ISO 639-1 language code.
+    #[serde(default = "default_dictation_commands")]
+    pub dictation_commands: HashMap<String, Vec<DictationCommand>>,
+    /// Run the matching action when a whole dictation is one of
+    /// `voice_command_rules`, like "open terminal", instead of pasting it.
+    /// Unlike `voice_commands_enabled`, no LLM is involved.
+    #[serde(default)]
+    pub voice_command_rules_enabled: bool,
+    #[serde(default = "default_voice_command_rules")]
+    pub voice_command_rules: Vec<VoiceCommandRule>,
+    /// Show how to stop the recording ("release ⌥Space to stop") in the
+    /// overlay.
+    #[serde(default = "default_overlay_shortcut_hint")]
+    pub overlay_shortcut_hint: bool,
+    /// Languages whose spoken numbers, prices and dates are written with
+    /// digits in transcripts, as ISO 639-1 codes.
+    #[serde(default)]
+    pub text_normalization: Vec<String>,
+    /// Before pasting, check that a text field has focus, and copy the text
+    /// to the clipboard instead when none does.
+    #[serde(default = "default_verify_paste_target")]
+    pub verify_paste_target: bool,
+    /// Extra custom words per app, keyed by bundle id or app name, applied
+    /// on top of `custom_words` when that app is frontmost at paste time.
+    #[serde(default)]
+    pub app_custom_words: HashMap<String, Vec<String>>,
+    /// Retention periods for entries from particular sources, like imported
+    /// files, overriding `recording_retention_period` for them.
+    #[serde(default)]
+    pub source_retention: HashMap<RecordingMode, RecordingRetentionPeriod>,
+    /// Hours during which the model is loaded ahead of the first dictation
+    /// and never unloaded for inactivity.
+    #[serde(default)]
+    pub model_warm_hours: Option<WarmHours>,
+    #[serde(default)]
+    pub profanity_filter: ProfanityFilter,
+    /// Words the profanity filter catches. A trailing `*` matches any word
+    /// starting with what comes before it.
+    #[serde(default = "default_profanity_words")]
+    pub profanity_words: Vec<String>,
+    /// Split, capitalize and punctuate sentences from engines that don't
+    /// punctuate on their own (Parakeet).
+    #[serde(default = "default_punctuation_restoration")]
+    pub punctuation_restoration: bool,
+    /// Interval live previews aim for between partial transcriptions, in
+    /// milliseconds. Passes that take longer stretch it.
+    #[serde(default = "default_streaming_target_latency_ms")]
+    pub streaming_target_latency_ms: u64,
+    /// Add words that dictations keep getting corrected to, in history or
+    /// right after pasting, to the custom words.
+    #[serde(default = "default_learn_from_corrections")]
+    pub learn_from_corrections: bool,
+    /// Respellings read back by text-to-speech in place of words the system
+    /// voices mispronounce, keyed by the word or phrase.
+    #[serde(default)]
+    pub tts_pronunciations: HashMap<String, String>,
+    /// Announce recording started and stopped and the transcription being
+    /// pasted to the screen reader.
+    #[serde(default = "default_accessibility_announcements")]
+    pub accessibility_announcements: bool,
+    /// Denoise the microphone live while recording, for fans and keyboards
+    /// in the background. Unlike noise profiles it needs no calibration.
+    #[serde(default)]
+    pub noise_suppression_enabled: bool,
+    /// Fixed gain applied to the microphone, in dB, for quiet microphones.
+    #[serde(default)]
+    pub input_gain_db: f32,
+    /// Level the microphone automatically towards a steady speech volume.
+    #[serde(default)]
+    pub auto_gain_enabled: bool,
+    /// With the always-on microphone, start each recording with the couple
+    /// of seconds heard before the shortcut.
+    #[serde(default = "default_pre_roll_enabled")]
+    pub pre_roll_enabled: bool,
+    /// Don't keep dictations in history and pause input tracking. Automation
+    /// rules can turn it on outside working hours.
+    #[serde(default)]
+    pub privacy_mode: bool,
+    /// Minutes after which a recording stops and is transcribed, so one left
+    /// running by accident doesn't grow for hours. None or 0 means no limit.
+    #[serde(default = "default_max_recording_minutes")]
+    pub max_recording_minutes: Option<u64>,
+    /// Ids of the text plugins to run on every dictation, in order.
+    #[serde(default)]
+    pub text_plugins: Vec<String>,
+    /// Apps, by bundle id or name, that dictations are never pasted into;
+    /// the text is copied to the clipboard instead.
+    #[serde(default)]
+    pub no_paste_apps: Vec<String>,
+    /// Case-insensitive regexes matched against the focused window's title or
+    /// URL, for windows that dictations are never pasted into.
+    #[serde(default)]
+    pub no_paste_window_patterns: Vec<String>,
+    /// File format new history recordings are saved in. Existing recordings
+    /// keep theirs.
+    #[serde(default)]
+    pub recording_format: RecordingFormat,
+    /// Close the always-on microphone after this many minutes without a
+    /// recording; the next recording opens it again. None keeps it open.
+    #[serde(default)]
+    pub always_on_idle_release_minutes: Option<u64>,
+    /// Folder whose new recordings are transcribed into history in the
+    /// background. None turns the watch folder off.
+    #[serde(default)]
+    pub import_watch_folder: Option<String>,
+    /// Retention periods for entries recorded during focus sessions of a
+    /// given name, overriding `source_retention` and
+    /// `recording_retention_period` for them.
+    #[serde(default)]
+    pub tag_retention: HashMap<String, RecordingRetentionPeriod>,
 }
 
 fn default_audio_feedback_volume() -> f32 {
@@ -316,14 +687,30 @@ fn default_recording_retention_period() -> RecordingRetentionPeriod {
     RecordingRetentionPeriod::PreserveLimit
 }
 
+fn default_input_tracking_capture_paste() -> bool {
+    true
+}
+
 fn default_input_tracking_idle_timeout() -> Option<u64> {
     Some(2) // Default 2 seconds
 }
 
+fn default_min_recording_duration_ms() -> u64 {
+    300
+}
+
 fn default_voice_commands_enabled() -> bool {
     true
 }
 
+fn default_thread_priorities_enabled() -> bool {
+    true
+}
+
+fn default_data_api_port() -> u16 {
+    47813
+}
+
 fn default_meeting_chunk_duration_secs() -> u32 {
     30
 }
@@ -408,6 +795,228 @@ fn default_post_process_prompts() -> Vec<LLMPrompt> {
     }]
 }
 
+fn default_low_confidence_threshold() -> f64 {
+    0.5
+}
+
+fn default_vad_trim_enabled() -> bool {
+    true
+}
+
+fn default_mute_warning_enabled() -> bool {
+    true
+}
+
+fn default_transcription_provider_id() -> String {
+    "openai".to_string()
+}
+
+fn default_transcription_providers() -> Vec<TranscriptionProvider> {
+    vec![
+        TranscriptionProvider {
+            id: "openai".to_string(),
+            label: "OpenAI".to_string(),
+            base_url: "https://api.openai.com/v1".to_string(),
+        },
+        TranscriptionProvider {
+            id: "groq".to_string(),
+            label: "Groq".to_string(),
+            base_url: "https://api.groq.com/openai/v1".to_string(),
+        },
+        TranscriptionProvider {
+            id: "deepgram".to_string(),
+            label: "Deepgram".to_string(),
+            base_url: "https://api.deepgram.com/v1".to_string(),
+        },
+    ]
+}
+
+fn default_transcription_api_keys() -> HashMap<String, String> {
+    let mut map = HashMap::new();
+    for provider in default_transcription_providers() {
+        map.insert(provider.id, String::new());
+    }
+    map
+}
+
+fn default_transcription_models() -> HashMap<String, String> {
+    let mut map = HashMap::new();
+    map.insert("openai".to_string(), "whisper-1".to_string());
+    map.insert("groq".to_string(), "whisper-large-v3-turbo".to_string());
+    map.insert("deepgram".to_string(), "nova-3".to_string());
+    map
+}
+
+fn default_dictation_templates() -> Vec<DictationTemplate> {
+    vec![DictationTemplate {
+        id: "default_email".to_string(),
+        name: "Email".to_string(),
+        template: "{{greeting}},\n\n{{body}}\n\n{{signature}}".to_string(),
+    }]
+}
+
+fn default_dictation_commands() -> HashMap<String, Vec<DictationCommand>> {
+    use DictationCommandAction::{DeleteSentence, DeleteWord};
+    let insert = |text: &str| DictationCommandAction::Insert {
+        text: text.to_string(),
+    };
+    let table = |entries: Vec<(&str, DictationCommandAction)>| {
+        entries
+            .into_iter()
+            .map(|(phrase, action)| DictationCommand {
+                phrase: phrase.to_string(),
+                action,
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let mut map = HashMap::new();
+    map.insert(
+        "en".to_string(),
+        table(vec![
+            ("period", insert(".")),
+            ("full stop", insert(".")),
+            ("comma", insert(",")),
+            ("question mark", insert("?")),
+            ("exclamation mark", insert("!")),
+            ("exclamation point", insert("!")),
+            ("colon", insert(":")),
+            ("semicolon", insert(";")),
+            ("new line", insert("\n")),
+            ("new paragraph", insert("\n\n")),
+            ("delete last word", DeleteWord),
+            ("delete that", DeleteSentence),
+            ("scratch that", DeleteSentence),
+        ]),
+    );
+    map.insert(
+        "fr".to_string(),
+        table(vec![
+            ("point final", insert(".")),
+            ("virgule", insert(",")),
+            ("point d'interrogation", insert("?")),
+            ("point d'exclamation", insert("!")),
+            ("deux points", insert(":")),
+            ("point virgule", insert(";")),
+            ("à la ligne", insert("\n")),
+            ("nouveau paragraphe", insert("\n\n")),
+            ("efface le dernier mot", DeleteWord),
+            ("efface ça", DeleteSentence),
+        ]),
+    );
+    map.insert(
+        "de".to_string(),
+        table(vec![
+            ("punkt", insert(".")),
+            ("komma", insert(",")),
+            ("fragezeichen", insert("?")),
+            ("ausrufezeichen", insert("!")),
+            ("doppelpunkt", insert(":")),
+            ("semikolon", insert(";")),
+            ("neue zeile", insert("\n")),
+            ("neuer absatz", insert("\n\n")),
+            ("letztes wort löschen", DeleteWord),
+            ("lösch das", DeleteSentence),
+        ]),
+    );
+    map.insert(
+        "es".to_string(),
+        table(vec![
+            ("punto", insert(".")),
+            ("coma", insert(",")),
+            ("signo de interrogación", insert("?")),
+            ("signo de exclamación", insert("!")),
+            ("dos puntos", insert(":")),
+            ("punto y coma", insert(";")),
+            ("nueva línea", insert("\n")),
+            ("nuevo párrafo", insert("\n\n")),
+            ("borra la última palabra", DeleteWord),
+            ("borra eso", DeleteSentence),
+        ]),
+    );
+    map
+}
+
+fn default_overlay_shortcut_hint() -> bool {
+    true
+}
+
+fn default_verify_paste_target() -> bool {
+    true
+}
+
+fn default_punctuation_restoration() -> bool {
+    true
+}
+
+fn default_streaming_target_latency_ms() -> u64 {
+    800
+}
+
+fn default_learn_from_corrections() -> bool {
+    true
+}
+
+fn default_accessibility_announcements() -> bool {
+    true
+}
+
+fn default_pre_roll_enabled() -> bool {
+    true
+}
+
+fn default_max_recording_minutes() -> Option<u64> {
+    Some(10)
+}
+
+fn default_profanity_words() -> Vec<String> {
+    [
+        "fuck*",
+        "motherfuck*",
+        "shit*",
+        "bullshit*",
+        "bitch*",
+        "asshole*",
+        "bastard*",
+        "cunt*",
+        "dick",
+        "dickhead*",
+        "prick*",
+        "twat*",
+        "wank*",
+        "piss",
+        "pissed",
+    ]
+    .iter()
+    .map(|word| word.to_string())
+    .collect()
+}
+
+fn default_voice_command_rules() -> Vec<VoiceCommandRule> {
+    [
+        ("open {app}", "app.launch", Some(("name", "{app}"))),
+        (
+            "switch prompt to {prompt}",
+            "post_processing.select_prompt",
+            Some(("id", "{prompt}")),
+        ),
+        ("start meeting", "meeting.start", None),
+        ("stop meeting", "meeting.stop", None),
+        ("open scratchpad", "scratchpad.open", None),
+        ("show settings", "app.show_settings", None),
+    ]
+    .into_iter()
+    .map(|(phrase, action, param)| VoiceCommandRule {
+        phrase: phrase.to_string(),
+        action: action.to_string(),
+        params: param
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .into_iter()
+            .collect(),
+    })
+    .collect()
+}
+
 pub const SETTINGS_STORE_PATH: &str = "settings_store.json";
 
 /// Get the default shortcut for the current platform and display server.
@@ -442,6 +1051,28 @@ fn get_default_shortcut() -> &'static str {
     }
 }
 
+fn get_default_continue_shortcut() -> &'static str {
+    #[cfg(target_os = "macos")]
+    {
+        "option+shift+space"
+    }
+    #[cfg(not(target_os = "macos"))]
+    {
+        "ctrl+alt+space"
+    }
+}
+
+fn continue_dictation_binding() -> ShortcutBinding {
+    let shortcut = get_default_continue_shortcut();
+    ShortcutBinding {
+        id: crate::actions::CONTINUE_BINDING.to_string(),
+        name: "Continue Dictation".to_string(),
+        description: "Adds your speech to the end of the last dictation.".to_string(),
+        default_binding: shortcut.to_string(),
+        current_binding: shortcut.to_string(),
+    }
+}
+
 pub fn get_default_settings() -> AppSettings {
     let default_shortcut = get_default_shortcut();
 
@@ -456,6 +1087,10 @@ pub fn get_default_settings() -> AppSettings {
             current_binding: default_shortcut.to_string(),
         },
     );
+    bindings.insert(
+        crate::actions::CONTINUE_BINDING.to_string(),
+        continue_dictation_binding(),
+    );
 
     AppSettings {
         bindings,
@@ -494,7 +1129,9 @@ pub fn get_default_settings() -> AppSettings {
         mute_while_recording: false,
         input_tracking_enabled: false,
         input_tracking_excluded_apps: Vec::new(),
+        input_tracking_excluded_window_patterns: Vec::new(),
         input_tracking_idle_timeout: default_input_tracking_idle_timeout(),
+        input_tracking_capture_paste: default_input_tracking_capture_paste(),
         tts_enabled: false,
         meeting_system_audio_enabled: false,
         meeting_system_audio_device: None,
@@ -502,10 +1139,90 @@ pub fn get_default_settings() -> AppSettings {
         meeting_chunk_duration_secs: default_meeting_chunk_duration_secs(),
         meeting_diarization_enabled: false,
         meeting_diarization_threshold: default_diarization_threshold(),
+        auto_stop_silence_secs: None,
+        min_recording_duration_ms: default_min_recording_duration_ms(),
+        recording_countdown_enabled: false,
+        ptt_release_debounce_ms: 0,
+        context_prompt_enabled: false,
+        respect_focus_mode: false,
+        battery_saver_enabled: false,
+        battery_model: None,
+        daily_digest_enabled: false,
+        daily_digest_folder: None,
+        daily_digest_llm_summary: false,
+        thread_priorities_enabled: default_thread_priorities_enabled(),
+        data_api_enabled: false,
+        data_api_port: default_data_api_port(),
+        data_api_token: String::new(),
+        data_api_redacted_fields: Vec::new(),
+        feature_flags: HashMap::new(),
+        interview_mode_enabled: false,
+        voice_prompt_switching_enabled: false,
+        dictation_templates: default_dictation_templates(),
+        low_confidence_review_enabled: false,
+        low_confidence_threshold: default_low_confidence_threshold(),
+        vad_trim_enabled: default_vad_trim_enabled(),
+        feedback_sound_devices: HashMap::new(),
+        visual_feedback: false,
+        haptic_feedback: false,
+        file_diarization_enabled: false,
+        mute_warning_enabled: default_mute_warning_enabled(),
+        cloud_transcription_enabled: false,
+        transcription_provider_id: default_transcription_provider_id(),
+        transcription_providers: default_transcription_providers(),
+        transcription_api_keys: default_transcription_api_keys(),
+        transcription_models: default_transcription_models(),
+        voice_filter_enabled: false,
+        active_noise_profile: None,
+        inference_backend: InferenceBackend::Auto,
+        inference_threads: None,
+        initial_prompt: String::new(),
+        initial_prompt_custom_words: false,
+        initial_prompt_active_app: false,
+        locale_formatting: Vec::new(),
+        preview_model_id: None,
+        dictation_commands_enabled: false,
+        dictation_commands: default_dictation_commands(),
+        voice_command_rules_enabled: false,
+        voice_command_rules: default_voice_command_rules(),
+        overlay_shortcut_hint: default_overlay_shortcut_hint(),
+        text_normalization: Vec::new(),
+        verify_paste_target: default_verify_paste_target(),
+        app_custom_words: HashMap::new(),
+        source_retention: HashMap::new(),
+        model_warm_hours: None,
+        profanity_filter: ProfanityFilter::default(),
+        profanity_words: default_profanity_words(),
+        punctuation_restoration: default_punctuation_restoration(),
+        streaming_target_latency_ms: default_streaming_target_latency_ms(),
+        learn_from_corrections: default_learn_from_corrections(),
+        tts_pronunciations: HashMap::new(),
+        accessibility_announcements: default_accessibility_announcements(),
+        noise_suppression_enabled: false,
+        input_gain_db: 0.0,
+        auto_gain_enabled: false,
+        pre_roll_enabled: default_pre_roll_enabled(),
+        privacy_mode: false,
+        max_recording_minutes: default_max_recording_minutes(),
+        text_plugins: Vec::new(),
+        no_paste_apps: Vec::new(),
+        no_paste_window_patterns: Vec::new(),
+        recording_format: RecordingFormat::Wav,
+        always_on_idle_release_minutes: None,
+        import_watch_folder: None,
+        tag_retention: HashMap::new(),
     }
 }
 
 impl AppSettings {
+    /// Input gain for microphone recordings.
+    pub fn input_gain(&self) -> GainSettings {
+        GainSettings {
+            gain_db: self.input_gain_db,
+            automatic: self.auto_gain_enabled,
+        }
+    }
+
     pub fn active_post_process_provider(&self) -> Option<&PostProcessProvider> {
         self.post_process_providers
             .iter()
@@ -526,6 +1243,23 @@ impl AppSettings {
             .iter_mut()
             .find(|provider| provider.id == provider_id)
     }
+
+    pub fn active_transcription_provider(&self) -> Option<&TranscriptionProvider> {
+        self.transcription_providers
+            .iter()
+            .find(|provider| provider.id == self.transcription_provider_id)
+    }
+
+    /// Language to transcribe in, as the ISO 639-1 code engines and cloud
+    /// providers take, or None to detect it. Both Chinese scripts are
+    /// transcribed as `zh` and converted afterwards.
+    pub fn transcription_language(&self) -> Option<&str> {
+        match self.selected_language.as_str() {
+            "auto" => None,
+            "zh-Hans" | "zh-Hant" => Some("zh"),
+            other => Some(other),
+        }
+    }
 }
 
 fn apply_settings_migrations_from_raw(
@@ -560,9 +1294,21 @@ fn apply_settings_migrations_from_raw(
         updated = true;
     }
 
+    // Migration: Add the continue dictation binding for existing users
+    if !settings
+        .bindings
+        .contains_key(crate::actions::CONTINUE_BINDING)
+    {
+        settings.bindings.insert(
+            crate::actions::CONTINUE_BINDING.to_string(),
+            continue_dictation_binding(),
+        );
+        updated = true;
+    }
+
     // Migration: Remove invalid bindings that don't have corresponding actions
     // This cleans up stale bindings like 'cancel' from older versions
-    let valid_binding_ids = ["transcribe", "test"];
+    let valid_binding_ids = ["transcribe", crate::actions::CONTINUE_BINDING, "test"];
     let original_count = settings.bindings.len();
     settings.bindings.retain(|id, _| {
         let is_valid = valid_binding_ids.contains(&id.as_str());
@@ -609,7 +1355,7 @@ fn apply_settings_migrations_from_raw(
 pub fn load_or_create_app_settings(app: &AppHandle) -> AppSettings {
     // Initialize store
     let store = app
-        .store(SETTINGS_STORE_PATH)
+        .store(crate::profile::settings_store_path())
         .expect("Failed to initialize store");
 
     let settings = if let Some(settings_value) = store.get("settings") {
@@ -637,15 +1383,15 @@ pub fn load_or_create_app_settings(app: &AppHandle) -> AppSettings {
         default_settings
     };
 
-    settings
+    crate::policy::apply(settings)
 }
 
 pub fn get_settings(app: &AppHandle) -> AppSettings {
     let store = app
-        .store(SETTINGS_STORE_PATH)
+        .store(crate::profile::settings_store_path())
         .expect("Failed to initialize store");
 
-    if let Some(settings_value) = store.get("settings") {
+    let settings = if let Some(settings_value) = store.get("settings") {
         match serde_json::from_value::<AppSettings>(settings_value.clone()) {
             Ok(settings) => settings,
             Err(_) => {
@@ -658,17 +1404,55 @@ pub fn get_settings(app: &AppHandle) -> AppSettings {
         let default_settings = get_default_settings();
         store.set("settings", serde_json::to_value(&default_settings).unwrap());
         default_settings
-    }
+    };
+
+    crate::policy::apply(settings)
 }
 
 pub fn write_settings(app: &AppHandle, settings: AppSettings) {
+    store_settings(app, settings);
+    notify_listeners(app);
+}
+
+fn store_settings(app: &AppHandle, settings: AppSettings) {
     let store = app
-        .store(SETTINGS_STORE_PATH)
+        .store(crate::profile::settings_store_path())
         .expect("Failed to initialize store");
 
+    let settings = crate::policy::apply(settings);
     store.set("settings", serde_json::to_value(&settings).unwrap());
 }
 
+/// Call `listener` with the current settings after every write, so a manager
+/// can apply changes live whichever command, rule or import made them.
+///
+/// Listeners run on the writing thread once the settings lock is released.
+/// They must not write settings themselves.
+pub fn subscribe<F>(listener: F)
+where
+    F: Fn(&AppHandle, &AppSettings) + Send + Sync + 'static,
+{
+    SETTINGS_LISTENERS
+        .write()
+        .expect("settings listeners lock poisoned")
+        .push(Box::new(listener));
+}
+
+fn notify_listeners(app: &AppHandle) {
+    let listeners = SETTINGS_LISTENERS
+        .read()
+        .expect("settings listeners lock poisoned");
+    if listeners.is_empty() {
+        return;
+    }
+    // Read back rather than pass the written value: with concurrent writers
+    // the last notification then always carries the latest settings
+    let settings = get_settings(app);
+    for listener in listeners.iter() {
+        listener(app, &settings);
+    }
+}
+
 /// Atomically read-modify-write settings under the global lock.
 ///
 /// The closure receives a mutable reference to the current `AppSettings`.
@@ -677,10 +1461,13 @@ pub fn update_settings<F>(app: &AppHandle, f: F)
 where
     F: FnOnce(&mut AppSettings),
 {
-    let _guard = SETTINGS_LOCK.lock().expect("settings lock poisoned");
-    let mut settings = get_settings(app);
-    f(&mut settings);
-    write_settings(app, settings);
+    {
+        let _guard = SETTINGS_LOCK.lock().expect("settings lock poisoned");
+        let mut settings = get_settings(app);
+        f(&mut settings);
+        store_settings(app, settings);
+    }
+    notify_listeners(app);
 }
 
 /// Like [`update_settings`] but the closure can fail.
@@ -690,10 +1477,13 @@ pub fn try_update_settings<F>(app: &AppHandle, f: F) -> Result<(), String>
 where
     F: FnOnce(&mut AppSettings) -> Result<(), String>,
 {
-    let _guard = SETTINGS_LOCK.lock().expect("settings lock poisoned");
-    let mut settings = get_settings(app);
-    f(&mut settings)?;
-    write_settings(app, settings);
+    {
+        let _guard = SETTINGS_LOCK.lock().expect("settings lock poisoned");
+        let mut settings = get_settings(app);
+        f(&mut settings)?;
+        store_settings(app, settings);
+    }
+    notify_listeners(app);
     Ok(())
 }
 
@@ -725,6 +1515,38 @@ pub fn get_recording_retention_period(app: &AppHandle) -> RecordingRetentionPeri
 mod tests {
     use super::*;
 
+    #[test]
+    fn warm_hours_can_wrap_past_midnight() {
+        let morning = WarmHours {
+            start_hour: 8,
+            end_hour: 18,
+        };
+        assert!(morning.contains(8));
+        assert!(morning.contains(17));
+        assert!(!morning.contains(18));
+        assert!(!morning.contains(3));
+
+        let night = WarmHours {
+            start_hour: 22,
+            end_hour: 2,
+        };
+        assert!(night.contains(23));
+        assert!(night.contains(1));
+        assert!(!night.contains(2));
+        assert!(!night.contains(12));
+    }
+
+    #[test]
+    fn chinese_scripts_are_transcribed_as_chinese() {
+        let mut settings = get_default_settings();
+        settings.selected_language = "auto".to_string();
+        assert_eq!(settings.transcription_language(), None);
+        settings.selected_language = "zh-Hant".to_string();
+        assert_eq!(settings.transcription_language(), Some("zh"));
+        settings.selected_language = "fr".to_string();
+        assert_eq!(settings.transcription_language(), Some("fr"));
+    }
+
     #[test]
     fn enabled_false_survives_serialization() {
         let mut settings = get_default_settings();
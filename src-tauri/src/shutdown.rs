@@ -0,0 +1,119 @@
+//! Ordered application shutdown.
+//!
+//! Runs once when the app exits, instead of relying on managers being dropped
+//! in whatever order Tauri releases its state: the input tracker buffer is
+//! flushed, an in-flight transcription gets a chance to finish (a recording
+//! still in progress is saved to history so it can be retranscribed), the
+//! microphone and live captions streams are closed, the data API stops
+//! serving, and queued database writes are committed before the connections
+//! are closed.
+
+use crate::actions::TRANSCRIPTION_TASK;
+use crate::managers::audio::AudioRecordingManager;
+use crate::managers::captions::CaptionsManager;
+use crate::managers::data_api::DataApiManager;
+use crate::managers::db_writer::DbWriter;
+use crate::managers::history::HistoryManager;
+use crate::managers::input_tracker::InputTrackerManager;
+use log::{info, warn};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager};
+
+/// Upper bound for each shutdown step, so a stuck step can't hang the exit.
+const STEP_TIMEOUT: Duration = Duration::from_secs(5);
+
+static SHUTTING_DOWN: AtomicBool = AtomicBool::new(false);
+
+/// Run the shutdown sequence. Later calls are no-ops.
+pub fn run(app: &AppHandle) {
+    if SHUTTING_DOWN.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    let start = Instant::now();
+    info!("Shutting down...");
+
+    flush_input_tracker(app);
+    finish_transcription(app);
+    stop_audio(app);
+    close_databases(app);
+
+    info!("Shutdown completed in {:?}", start.elapsed());
+}
+
+fn flush_input_tracker(app: &AppHandle) {
+    let Some(tracker) = app.try_state::<Arc<Mutex<InputTrackerManager>>>() else {
+        return;
+    };
+    let Ok(mut tracker) = tracker.lock() else {
+        warn!("Input tracker lock poisoned, skipping flush");
+        return;
+    };
+    if !tracker.flush_and_stop(STEP_TIMEOUT) {
+        warn!("Input tracker did not flush its buffer in time");
+    }
+}
+
+/// Save a recording that is still in progress, then wait for the transcription
+/// task of the last stopped recording.
+fn finish_transcription(app: &AppHandle) {
+    if let Some(rm) = app.try_state::<Arc<AudioRecordingManager>>() {
+        // Privacy mode keeps dictations out of history
+        let private = crate::settings::get_settings(app).privacy_mode;
+        for binding_id in rm.active_bindings() {
+            let Some(samples) = rm.stop_recording(&binding_id) else {
+                continue;
+            };
+            if samples.is_empty() || private {
+                continue;
+            }
+            let Some(hm) = app.try_state::<Arc<HistoryManager>>() else {
+                continue;
+            };
+            // Saved without text; it can be retranscribed from history
+            let source = rm.recording_source();
+            let save = hm.save_transcription(samples, String::new(), None, None, source);
+            match tauri::async_runtime::block_on(tokio::time::timeout(STEP_TIMEOUT, save)) {
+                Ok(Ok(_)) => info!("Saved in-progress recording for '{}'", binding_id),
+                Ok(Err(e)) => warn!("Failed to save in-progress recording: {}", e),
+                Err(_) => warn!("Timed out saving in-progress recording"),
+            }
+        }
+        rm.cancel_recording();
+    }
+
+    let task = TRANSCRIPTION_TASK
+        .lock()
+        .ok()
+        .and_then(|mut task| task.take());
+    if let Some(mut handle) = task {
+        info!("Waiting for in-flight transcription...");
+        let finished =
+            tauri::async_runtime::block_on(tokio::time::timeout(STEP_TIMEOUT, &mut handle)).is_ok();
+        if !finished {
+            warn!("In-flight transcription did not finish in time, aborting it");
+            handle.abort();
+        }
+    }
+}
+
+fn stop_audio(app: &AppHandle) {
+    if let Some(captions) = app.try_state::<Arc<CaptionsManager>>() {
+        captions.stop();
+    }
+    if let Some(rm) = app.try_state::<Arc<AudioRecordingManager>>() {
+        rm.stop_microphone_stream();
+    }
+}
+
+fn close_databases(app: &AppHandle) {
+    if let Some(data_api) = app.try_state::<Arc<DataApiManager>>() {
+        data_api.stop();
+    }
+    if let Some(writer) = app.try_state::<Arc<DbWriter>>() {
+        if !writer.shutdown(STEP_TIMEOUT) {
+            warn!("Database writer did not finish in time");
+        }
+    }
+}
@@ -2,7 +2,6 @@ use async_openai::types::{ChatCompletionTool, ChatCompletionToolType, FunctionOb
 use log::{debug, error, info};
 use std::path::PathBuf;
 use tauri::AppHandle;
-use tauri::Manager;
 
 use crate::settings::{self, SoundTheme};
 
@@ -167,7 +166,7 @@ fn execute_create_note(app: &AppHandle, arguments_json: &str) -> ToolResult {
         };
     }
 
-    let notes_dir: PathBuf = match app.path().app_data_dir() {
+    let notes_dir: PathBuf = match crate::profile::data_dir(app) {
         Ok(dir) => dir.join("notes"),
         Err(e) => {
             error!("[Tools] Failed to get app data dir: {}", e);
@@ -222,7 +221,12 @@ fn execute_open_application(arguments_json: &str) -> ToolResult {
         }
     };
 
-    let app_name = args.app_name.trim();
+    open_application(&args.app_name)
+}
+
+/// Launch the application called `app_name`.
+pub fn open_application(app_name: &str) -> ToolResult {
+    let app_name = app_name.trim();
     if app_name.is_empty() {
         return ToolResult {
             display_message: "Application name cannot be empty".to_string(),
@@ -73,6 +73,13 @@ pub fn change_tray_icon(app: &AppHandle, icon: TrayIconState) {
     update_tray_menu(app, &icon);
 }
 
+/// Set the tray tooltip, or clear it with `None`.
+pub fn set_tray_tooltip(app: &AppHandle, tooltip: Option<&str>) {
+    if let Some(tray) = app.try_state::<TrayIcon>() {
+        let _ = tray.set_tooltip(tooltip);
+    }
+}
+
 pub fn update_tray_menu(app: &AppHandle, state: &TrayIconState) {
     // Platform-specific accelerators
     #[cfg(target_os = "macos")]
@@ -96,6 +103,8 @@ pub fn update_tray_menu(app: &AppHandle, state: &TrayIconState) {
     .expect("failed to create check updates item");
     let quit_i = MenuItem::with_id(app, "quit", "Quit", true, quit_accelerator)
         .expect("failed to create quit item");
+    let scratchpad_i = MenuItem::with_id(app, "scratchpad", "Scratchpad", true, None::<&str>)
+        .expect("failed to create scratchpad item");
     let separator = || PredefinedMenuItem::separator(app).expect("failed to create separator");
 
     let menu = match state {
@@ -122,6 +131,7 @@ pub fn update_tray_menu(app: &AppHandle, state: &TrayIconState) {
             &[
                 &version_i,
                 &separator(),
+                &scratchpad_i,
                 &settings_i,
                 &check_updates_i,
                 &separator(),
@@ -1,6 +1,5 @@
-use crate::actions::{OPERATION_GENERATION, TRANSCRIPTION_TASK};
+use crate::actions::{ACTION_MAP, OPERATION_GENERATION, TRANSCRIPTION_TASK};
 use crate::managers::audio::AudioRecordingManager;
-use crate::ManagedToggleState;
 use log::{info, warn};
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
@@ -19,6 +18,7 @@ pub fn cancel_current_operation(app: &AppHandle) {
 
     // Increment generation to invalidate any in-flight stop/transcription tasks
     OPERATION_GENERATION.fetch_add(1, Ordering::SeqCst);
+    crate::helpers::pipeline_trace::take_pipeline().record("outcome", "cancelled");
 
     // Abort any in-flight transcription task
     if let Ok(mut task) = TRANSCRIPTION_TASK.lock() {
@@ -27,38 +27,33 @@ pub fn cancel_current_operation(app: &AppHandle) {
         }
     }
 
-    // Cancel any ongoing recording FIRST (before resetting toggle states)
-    // This ensures the audio stream is stopped before any cleanup
+    // Cancel any ongoing or pending recording. Toggle state is derived from the
+    // recording manager, so this also resets every binding to inactive without
+    // triggering a transcription.
     let audio_manager = app.state::<Arc<AudioRecordingManager>>();
     audio_manager.cancel_recording();
 
-    // Reset all shortcut toggle states WITHOUT calling stop actions
-    // We don't want to trigger transcription during cancellation
-    let toggle_state_manager = app.state::<ManagedToggleState>();
-    if let Ok(mut states) = toggle_state_manager.lock() {
-        // For each currently active toggle, just reset its state
-        let active_bindings: Vec<String> = states
-            .active_toggles
-            .iter()
-            .filter(|(_, &is_active)| is_active)
-            .map(|(binding_id, _)| binding_id.clone())
-            .collect();
-
-        for binding_id in active_bindings {
-            info!("Resetting toggle state for binding: {}", binding_id);
-            // Just reset the toggle state - don't call action.stop()
-            // because that would trigger a transcription
-            if let Some(is_active) = states.active_toggles.get_mut(&binding_id) {
-                *is_active = false;
-            }
-        }
-    } else {
-        warn!("Warning: Failed to lock toggle state manager during cancellation");
-    }
-
     // Hide overlay and update tray icon to idle state
     hide_recording_overlay(app);
     change_tray_icon(app, crate::tray::TrayIconState::Idle);
 
     info!("Operation cancellation completed - returned to idle state");
 }
+
+/// Stop an active toggle recording from the backend (e.g. auto-stop on silence),
+/// running the binding's normal stop action so the audio still gets transcribed.
+pub fn stop_binding_recording(app: &AppHandle, binding_id: &str, reason: &str) {
+    let Some(action) = ACTION_MAP.get(binding_id) else {
+        warn!(
+            "No action defined in ACTION_MAP for binding ID '{}'",
+            binding_id
+        );
+        return;
+    };
+
+    info!(
+        "Stopping recording for binding '{}': {}",
+        binding_id, reason
+    );
+    action.stop(app, binding_id, reason);
+}
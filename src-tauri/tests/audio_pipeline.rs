@@ -0,0 +1,72 @@
+//! Golden tests for the audio pipeline, driven by `tests/fixtures/audio`.
+//!
+//! The decode test runs everywhere. The transcription test needs a Whisper
+//! model and is ignored by default:
+//!
+//! ```bash
+//! ECHO_FIXTURE_MODEL=/path/to/ggml-tiny.en.bin cargo test --test audio_pipeline -- --ignored
+//! ```
+
+use echo_app_lib::audio_toolkit::fixture::{self, FixtureResult};
+use std::path::PathBuf;
+use transcribe_rs::engines::whisper::{WhisperEngine, WhisperInferenceParams};
+use transcribe_rs::TranscriptionEngine;
+
+fn assert_all_passed(results: &[FixtureResult]) {
+    let failures: Vec<String> = results
+        .iter()
+        .filter(|result| !result.passed)
+        .map(|result| format!("{}: {}", result.file, result.failures.join("; ")))
+        .collect();
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[test]
+fn decodes_and_resamples_fixture_clips() {
+    let dir = fixture::bundled_dir();
+    let clips = fixture::load_manifest(&dir).unwrap();
+    assert!(!clips.is_empty());
+
+    let results: Vec<FixtureResult> = clips
+        .iter()
+        .map(|clip| fixture::run_clip(&dir, clip, None).unwrap())
+        .collect();
+    assert_all_passed(&results);
+}
+
+#[test]
+#[ignore = "needs a Whisper model in ECHO_FIXTURE_MODEL"]
+fn transcribes_speech_clips() {
+    let model = PathBuf::from(
+        std::env::var("ECHO_FIXTURE_MODEL").expect("set ECHO_FIXTURE_MODEL to a Whisper model"),
+    );
+    let mut engine = WhisperEngine::new();
+    engine.load_model(&model).unwrap();
+    let mut transcribe = |samples: Vec<f32>| {
+        let params = WhisperInferenceParams {
+            language: Some("en".to_string()),
+            ..Default::default()
+        };
+        engine
+            .transcribe_samples(samples, Some(params))
+            .map(|result| result.text)
+            .map_err(|e| anyhow::anyhow!("Whisper transcription failed: {}", e))
+    };
+
+    let dir = fixture::bundled_dir();
+    let clips = fixture::load_manifest(&dir).unwrap();
+    let speech: Vec<_> = clips
+        .iter()
+        .filter(|clip| clip.expected_text.is_some())
+        .collect();
+    assert!(
+        !speech.is_empty(),
+        "No verified speech clip in the fixture manifest yet, see tests/fixtures/audio/README.md"
+    );
+
+    let results: Vec<FixtureResult> = speech
+        .into_iter()
+        .map(|clip| fixture::run_clip(&dir, clip, Some(&mut transcribe)).unwrap())
+        .collect();
+    assert_all_passed(&results);
+}
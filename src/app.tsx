@@ -2,7 +2,7 @@ import { invoke } from "@tauri-apps/api/core";
 import { listen } from "@tauri-apps/api/event";
 import { Upload } from "lucide-react";
 import { useEffect, useRef, useState } from "react";
-import { Toaster } from "sonner";
+import { Toaster, toast } from "sonner";
 import "./app.css";
 import { AppHeader } from "@/components/app-header";
 import { GlassWindow } from "@/components/ui/glass-window";
@@ -34,7 +34,9 @@ function App() {
   const debugMode = useSetting("debug_mode");
   const updateSetting = useSettingsStore((s) => s.updateSetting);
   const initialize = useSettingsStore((s) => s.initialize);
+  const refreshSettings = useSettingsStore((s) => s.refreshSettings);
   const [isDragging, setIsDragging] = useState(false);
+  const [announcement, setAnnouncement] = useState("");
   const hasSignaledReady = useRef(false);
 
   useFileTranscriptionListener();
@@ -135,6 +137,111 @@ function App() {
     };
   }, []);
 
+  // Tell the user when a transcription was copied instead of pasted
+  useEffect(() => {
+    const unlisten = listen<{ reason: string; message: string }>(
+      "paste-fallback",
+      (event) => {
+        toast.warning(event.payload.message);
+      }
+    );
+
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  // Tell the user which custom words were learned from their corrections
+  useEffect(() => {
+    const unlisten = listen<string[]>("custom-words-learned", (event) => {
+      toast.success(`Added to custom words: ${event.payload.join(", ")}`);
+      refreshSettings();
+    });
+
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, [refreshSettings]);
+
+  // Tell the user when the selected model failed and another one stood in
+  useEffect(() => {
+    const unlisten = listen<{ model_name: string; message: string }>(
+      "transcription-fallback",
+      (event) => {
+        toast.warning(event.payload.message);
+      }
+    );
+
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  // Tell the user when their microphone went away and another took over
+  useEffect(() => {
+    const unlisten = listen<{ message: string }>("device-changed", (event) => {
+      toast.warning(event.payload.message);
+    });
+
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  // Offer to recover recordings a crash interrupted
+  useEffect(() => {
+    const recover = async () => {
+      try {
+        const ids = await invoke<number[]>("recover_crashed_recordings");
+        if (ids.length === 0) {
+          toast.info("The interrupted recording had no speech");
+          return;
+        }
+        const plural = ids.length === 1 ? "" : "s";
+        toast.success(
+          `Recovered ${ids.length} recording${plural} into history`
+        );
+      } catch (error) {
+        toast.error(`Failed to recover recordings: ${error}`);
+      }
+    };
+
+    invoke<string[]>("get_crashed_recordings")
+      .then((files) => {
+        if (files.length === 0) {
+          return;
+        }
+        toast.warning(
+          `Echo closed during ${files.length === 1 ? "a recording" : `${files.length} recordings`}. Transcribe what was recorded into history?`,
+          {
+            action: { label: "Recover", onClick: recover },
+            cancel: {
+              label: "Discard",
+              onClick: () => invoke("discard_crashed_recordings"),
+            },
+            duration: Number.POSITIVE_INFINITY,
+          }
+        );
+      })
+      .catch((error) =>
+        console.error("Failed to check for interrupted recordings:", error)
+      );
+  }, []);
+
+  // Read recording and paste announcements out in screen readers that
+  // don't get them from the system (Narrator, Orca)
+  useEffect(() => {
+    const unlisten = listen<string>("accessibility-announcement", (event) => {
+      // Clear first so the same message is announced again
+      setAnnouncement("");
+      setTimeout(() => setAnnouncement(event.payload), 50);
+    });
+
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
   if (isInitializing) {
     return (
       <GlassWindow data-tauri-drag-region>
@@ -170,6 +277,9 @@ function App() {
       </SidebarLayout>
       <TranscriptionResultDialog />
       <ErrorDialog />
+      <div aria-live="assertive" className="sr-only" role="status">
+        {announcement}
+      </div>
       {isDragging && (
         <div className="pointer-events-none fixed inset-0 z-40 flex items-center justify-center bg-background/40 backdrop-blur-sm">
           <div className="flex flex-col items-center gap-3">
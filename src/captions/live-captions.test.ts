@@ -0,0 +1,32 @@
+import { describe, expect, it } from "bun:test";
+import { applyCaption } from "./live-captions";
+
+const line = (text: string, is_final: boolean, timestamp = 0) => ({
+  text,
+  timestamp,
+  is_final,
+});
+
+describe("applyCaption", () => {
+  it("appends to an empty list", () => {
+    expect(applyCaption([], line("hello", false))).toEqual([
+      line("hello", false),
+    ]);
+  });
+
+  it("replaces the in-progress line with its update", () => {
+    const lines = [line("first", true), line("hel", false)];
+    expect(applyCaption(lines, line("hello there", false))).toEqual([
+      line("first", true),
+      line("hello there", false),
+    ]);
+  });
+
+  it("keeps finalized lines and starts a new one after them", () => {
+    const lines = [line("first", true)];
+    expect(applyCaption(lines, line("second", false))).toEqual([
+      line("first", true),
+      line("second", false),
+    ]);
+  });
+});
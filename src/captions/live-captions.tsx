@@ -0,0 +1,99 @@
+import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
+import { History } from "lucide-react";
+import { useEffect, useRef, useState } from "react";
+import { cn } from "@/lib/utils";
+
+interface CaptionLine {
+  text: string;
+  timestamp: number;
+  is_final: boolean;
+}
+
+/** Finalized lines shown below the current line when history is hidden */
+const ROLLING_LINES = 2;
+
+/** Replace the in-progress line with the latest update, or append a new one */
+export const applyCaption = (
+  lines: CaptionLine[],
+  caption: CaptionLine
+): CaptionLine[] => {
+  const last = lines.at(-1);
+  const rest = last && !last.is_final ? lines.slice(0, -1) : lines;
+  return [...rest, caption];
+};
+
+const LiveCaptions = () => {
+  const [lines, setLines] = useState<CaptionLine[]>([]);
+  const [showHistory, setShowHistory] = useState(false);
+  const scrollRef = useRef<HTMLDivElement>(null);
+
+  useEffect(() => {
+    invoke<CaptionLine[]>("get_caption_history")
+      .then(setLines)
+      .catch(() => setLines([]));
+
+    const unlisten = listen<CaptionLine>("live-caption", (event) => {
+      setLines((current) => applyCaption(current, event.payload));
+    });
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  useEffect(() => {
+    const el = scrollRef.current;
+    if (el && lines.length > 0) {
+      el.scrollTop = el.scrollHeight;
+    }
+  }, [lines]);
+
+  const visible = showHistory ? lines : lines.slice(-(ROLLING_LINES + 1));
+
+  return (
+    <div className="flex h-full w-full flex-col bg-background/95 text-foreground">
+      <div className="flex items-center justify-end px-2 pt-1">
+        <button
+          aria-pressed={showHistory}
+          className={cn(
+            "rounded p-1 text-muted-foreground hover:text-foreground",
+            showHistory && "bg-muted text-foreground"
+          )}
+          onClick={() => setShowHistory((value) => !value)}
+          title={showHistory ? "Hide history" : "Show history"}
+          type="button"
+        >
+          <History className="size-4" />
+        </button>
+      </div>
+      <div
+        className={cn(
+          "flex min-h-0 flex-1 flex-col gap-1 px-4 pb-3",
+          showHistory ? "overflow-y-auto" : "justify-end overflow-hidden"
+        )}
+        ref={scrollRef}
+      >
+        {visible.length === 0 ? (
+          <p className="text-muted-foreground text-sm">Listening…</p>
+        ) : (
+          visible.map((line, index) => (
+            <p
+              className={cn(
+                "font-medium text-xl leading-snug",
+                !line.is_final && "text-foreground/80",
+                line.is_final &&
+                  index < visible.length - 1 &&
+                  "text-muted-foreground"
+              )}
+              key={`${line.timestamp}-${index}`}
+            >
+              {line.text}
+            </p>
+          ))
+        )}
+      </div>
+    </div>
+  );
+};
+
+export default LiveCaptions;
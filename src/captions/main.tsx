@@ -0,0 +1,18 @@
+import React from "react";
+import ReactDOM from "react-dom/client";
+import { ThemeProvider } from "@/providers/theme-provider";
+import LiveCaptions from "./live-captions";
+import "../app.css";
+
+const rootElement = document.getElementById("root");
+if (!rootElement) {
+  throw new Error("Missing root element");
+}
+
+ReactDOM.createRoot(rootElement).render(
+  <React.StrictMode>
+    <ThemeProvider defaultTheme="system" storageKey="echo-ui-theme">
+      <LiveCaptions />
+    </ThemeProvider>
+  </React.StrictMode>
+);
@@ -7,6 +7,7 @@ import { Button } from "@/components/ui/button";
 import { cn } from "@/lib/utils";
 
 interface FileTranscriptionProgress {
+  jobId?: number;
   message: string;
   progress: number;
   status: string;
@@ -97,6 +98,7 @@ export const FileUploadZone: React.FC<FileUploadZoneProps> = ({
       const validExtensions = [
         "wav",
         "wave",
+        "flac",
         "mp3",
         "m4a",
         "aac",
@@ -113,7 +115,7 @@ export const FileUploadZone: React.FC<FileUploadZoneProps> = ({
 
       if (!(fileExtension && validExtensions.includes(fileExtension))) {
         setError(
-          `Unsupported file format: .${fileExtension}. Please upload audio files (wav, mp3, m4a, ogg) or video files (mp4, mov, mkv, webm).`
+          `Unsupported file format: .${fileExtension}. Please upload audio files (wav, flac, mp3, m4a, ogg) or video files (mp4, mov, mkv, webm).`
         );
         return;
       }
@@ -232,6 +234,17 @@ export const FileUploadZone: React.FC<FileUploadZoneProps> = ({
     [processFile]
   );
 
+  const cancelTranscription = async () => {
+    if (progress?.jobId === undefined) {
+      return;
+    }
+    try {
+      await invoke("cancel_transcription_job", { id: progress.jobId });
+    } catch (err) {
+      console.error("Failed to cancel transcription:", err);
+    }
+  };
+
   const cancelUpload = () => {
     setCurrentFile(null);
     setIsProcessing(false);
@@ -265,7 +278,7 @@ export const FileUploadZone: React.FC<FileUploadZoneProps> = ({
             Supports WAV, MP3, M4A, OGG, MP4, MOV (max 100MB)
           </p>
           <input
-            accept=".wav,.wave,.mp3,.m4a,.aac,.ogg,.oga,.mp4,.mov,.avi,.mkv,.webm,.flv,audio/*,video/*"
+            accept=".wav,.wave,.flac,.mp3,.m4a,.aac,.ogg,.oga,.mp4,.mov,.avi,.mkv,.webm,.flv,audio/*,video/*"
             className="absolute inset-0 h-full w-full cursor-pointer opacity-0"
             disabled={isProcessing}
             onChange={handleFileSelect}
@@ -294,15 +307,29 @@ export const FileUploadZone: React.FC<FileUploadZoneProps> = ({
             <p className="font-medium text-sm">
               {currentFile?.name || "Processing..."}
             </p>
-            <p className="text-muted-foreground text-xs">
-              {Math.round(progress.progress * 100)}%
-            </p>
+            <div className="flex items-center gap-2">
+              {progress.progress >= 0 && (
+                <p className="text-muted-foreground text-xs">
+                  {Math.round(progress.progress * 100)}%
+                </p>
+              )}
+              {progress.jobId !== undefined && (
+                <Button
+                  onClick={cancelTranscription}
+                  size="icon-xs"
+                  title="Cancel transcription"
+                  variant="ghost"
+                >
+                  <X className="h-4 w-4" />
+                </Button>
+              )}
+            </div>
           </div>
 
           <div className="mb-2 h-2 overflow-hidden rounded-full bg-border">
             <div
               className="h-full bg-brand transition-all duration-300"
-              style={{ width: `${progress.progress * 100}%` }}
+              style={{ width: `${Math.max(progress.progress, 0) * 100}%` }}
             />
           </div>
 
@@ -0,0 +1,73 @@
+import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
+import { MessageCircleQuestion } from "lucide-react";
+import { useEffect, useState } from "react";
+import { SettingContainer } from "@/components/ui/setting-container";
+import { Switch } from "@/components/ui/switch";
+import type { InterviewQuestion } from "@/lib/types";
+import {
+  useIsSettingUpdating,
+  useSetting,
+  useSettingsStore,
+} from "@/stores/settings-store";
+
+/** Questions listed, newest first */
+const VISIBLE_QUESTIONS = 10;
+
+export const InterviewQuestions = () => {
+  const enabled = useSetting("interview_mode_enabled") ?? false;
+  const updating = useIsSettingUpdating("interview_mode_enabled");
+  const updateSetting = useSettingsStore((s) => s.updateSetting);
+  const [questions, setQuestions] = useState<InterviewQuestion[]>([]);
+
+  useEffect(() => {
+    invoke<InterviewQuestion[]>("get_interview_questions")
+      .then(setQuestions)
+      .catch(() => setQuestions([]));
+
+    const unlisten = listen<InterviewQuestion>(
+      "interview-question",
+      (event) => {
+        setQuestions((current) => [...current, event.payload]);
+      }
+    );
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  return (
+    <div className="flex flex-col gap-2">
+      <SettingContainer
+        description="Pick out questions asked in the captioned audio and list them here as they come in."
+        descriptionMode="tooltip"
+        title="Interview Mode"
+      >
+        <Switch
+          checked={enabled}
+          disabled={updating}
+          onCheckedChange={(value) =>
+            updateSetting("interview_mode_enabled", value)
+          }
+        />
+      </SettingContainer>
+
+      {enabled && questions.length > 0 && (
+        <ul className="flex flex-col gap-1">
+          {questions
+            .slice(-VISIBLE_QUESTIONS)
+            .reverse()
+            .map((question, index) => (
+              <li
+                className="flex items-start gap-2 rounded-md bg-muted/50 px-3 py-2 text-sm"
+                key={`${question.timestamp}-${index}`}
+              >
+                <MessageCircleQuestion className="mt-0.5 size-4 shrink-0 text-muted-foreground" />
+                <span>{question.text}</span>
+              </li>
+            ))}
+        </ul>
+      )}
+    </div>
+  );
+};
@@ -0,0 +1,52 @@
+import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
+import { Captions } from "lucide-react";
+import { useCallback, useEffect, useState } from "react";
+import { toast } from "sonner";
+import { Button } from "@/components/ui/button";
+
+export const LiveCaptionsToggle = () => {
+  const [running, setRunning] = useState(false);
+  const [busy, setBusy] = useState(false);
+
+  useEffect(() => {
+    invoke<boolean>("get_live_captions_status")
+      .then(setRunning)
+      .catch(() => setRunning(false));
+
+    const unlisten = listen<boolean>("live-captions-state-changed", (event) => {
+      setRunning(event.payload);
+    });
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  const handleToggle = useCallback(async () => {
+    setBusy(true);
+    try {
+      await invoke(running ? "stop_live_captions" : "start_live_captions");
+    } catch (error) {
+      toast.error(`Live captions: ${String(error)}`);
+    } finally {
+      setBusy(false);
+    }
+  }, [running]);
+
+  return (
+    <div className="flex items-center justify-between gap-3">
+      <p className="text-muted-foreground text-sm">
+        Caption system audio in an always-on-top window.
+      </p>
+      <Button
+        disabled={busy}
+        onClick={handleToggle}
+        size="sm"
+        variant={running ? "destructive" : "outline"}
+      >
+        <Captions className="mr-1.5 size-3.5" />
+        {running ? "Stop Live Captions" : "Live Captions"}
+      </Button>
+    </div>
+  );
+};
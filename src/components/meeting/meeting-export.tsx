@@ -5,7 +5,7 @@ import { Button } from "@/components/ui/button";
 import type { ExportFormat } from "@/lib/types";
 import { useMeetingStore } from "@/stores/meeting-store";
 
-const FORMATS: { label: string; value: ExportFormat }[] = [
+export const EXPORT_FORMATS: { label: string; value: ExportFormat }[] = [
   { label: "SRT", value: "srt" },
   { label: "VTT", value: "vtt" },
   { label: "TXT", value: "txt" },
@@ -47,7 +47,7 @@ export const MeetingExport = ({
 
   return (
     <div className="flex items-center gap-1.5">
-      {FORMATS.map((f) => (
+      {EXPORT_FORMATS.map((f) => (
         <Button
           disabled={exporting}
           key={f.value}
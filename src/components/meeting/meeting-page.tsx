@@ -1,16 +1,21 @@
 import { useCallback } from "react";
+import { useFeatureFlags } from "@/hooks/use-feature-flags";
 import { useMeetingStore } from "@/stores/meeting-store";
+import { InterviewQuestions } from "./interview-questions";
+import { LiveCaptionsToggle } from "./live-captions-toggle";
 import { MeetingControls } from "./meeting-controls";
 import { MeetingDetail } from "./meeting-detail";
 import { MeetingList } from "./meeting-list";
 import { MeetingSettings } from "./meeting-settings";
 import { MeetingTranscript } from "./meeting-transcript";
+import { ScheduledRecordings } from "./scheduled-recordings";
 
 export const MeetingPage = () => {
   const status = useMeetingStore((s) => s.status);
   const liveSegments = useMeetingStore((s) => s.liveSegments);
   const selectedMeeting = useMeetingStore((s) => s.selectedMeeting);
   const selectMeeting = useMeetingStore((s) => s.selectMeeting);
+  const { isEnabled } = useFeatureFlags();
 
   const handleSelect = useCallback(
     (id: number) => {
@@ -53,6 +58,21 @@ export const MeetingPage = () => {
           <MeetingControls />
         </div>
 
+        {/* Scheduled recordings */}
+        <div className="flex flex-col gap-3">
+          <h2 className="font-semibold text-lg">Scheduled Recordings</h2>
+          <ScheduledRecordings />
+        </div>
+
+        {/* Live captions (beta) */}
+        {isEnabled("live_captions") && (
+          <div className="flex flex-col gap-3">
+            <h2 className="font-semibold text-lg">Live Captions</h2>
+            <LiveCaptionsToggle />
+            <InterviewQuestions />
+          </div>
+        )}
+
         {/* Settings */}
         <div>
           <h3 className="mb-2 font-medium text-muted-foreground text-sm">
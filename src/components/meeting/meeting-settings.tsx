@@ -1,6 +1,13 @@
 import { invoke } from "@tauri-apps/api/core";
 import { listen } from "@tauri-apps/api/event";
-import { Download, Loader2, Monitor, Sparkles, Users } from "lucide-react";
+import {
+  Download,
+  FileAudio,
+  Loader2,
+  Monitor,
+  Sparkles,
+  Users,
+} from "lucide-react";
 import { useEffect, useState } from "react";
 import { MicrophoneSelector } from "@/components/settings/microphone-selector";
 import { SettingContainer } from "@/components/ui/setting-container";
@@ -55,6 +62,8 @@ export const MeetingSettings = () => {
     useSetting("meeting_system_audio_enabled") ?? false;
   const autoSummary = useSetting("meeting_auto_summary") ?? false;
   const diarizationEnabled = useSetting("meeting_diarization_enabled") ?? false;
+  const fileDiarizationEnabled =
+    useSetting("file_diarization_enabled") ?? false;
   const updatingSystemAudio = useIsSettingUpdating(
     "meeting_system_audio_enabled"
   );
@@ -62,8 +71,12 @@ export const MeetingSettings = () => {
   const updatingDiarization = useIsSettingUpdating(
     "meeting_diarization_enabled"
   );
+  const updatingFileDiarization = useIsSettingUpdating(
+    "file_diarization_enabled"
+  );
   const updateSetting = useSettingsStore((s) => s.updateSetting);
-  const diarizationStatus = useDiarizationStatus(diarizationEnabled);
+  const anyDiarization = diarizationEnabled || fileDiarizationEnabled;
+  const diarizationStatus = useDiarizationStatus(anyDiarization);
 
   const modelsReady =
     diarizationStatus?.segmentation_downloaded &&
@@ -76,7 +89,7 @@ export const MeetingSettings = () => {
     <div className="flex flex-col gap-1 rounded-lg border border-border/20">
       <MicrophoneSelector descriptionMode="tooltip" grouped />
       <SettingContainer
-        description="Capture system/output audio in addition to your microphone"
+        description="Record system audio alongside your microphone, each as its own channel, so the transcript labels you as Me and the other side as Them"
         descriptionMode="tooltip"
         grouped
         icon={<Monitor className="h-4 w-4" />}
@@ -106,7 +119,22 @@ export const MeetingSettings = () => {
             }
           />
         </SettingContainer>
-        {diarizationEnabled && diarizationStatus && !modelsReady && (
+        <SettingContainer
+          description="Also label speakers when transcribing audio and video files, so history and exports show Speaker 1 / Speaker 2"
+          descriptionMode="tooltip"
+          grouped
+          icon={<FileAudio className="h-4 w-4" />}
+          title="Speaker detection for files"
+        >
+          <Switch
+            checked={fileDiarizationEnabled}
+            disabled={updatingFileDiarization}
+            onCheckedChange={(enabled) =>
+              updateSetting("file_diarization_enabled", enabled)
+            }
+          />
+        </SettingContainer>
+        {anyDiarization && diarizationStatus && !modelsReady && (
           <div className="flex items-center gap-2 px-4 pb-2 text-muted-foreground text-xs">
             {modelsDownloading ? (
               <>
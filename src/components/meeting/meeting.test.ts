@@ -40,7 +40,13 @@ describe("formatElapsed", () => {
 
 describe("MeetingStatusSchema", () => {
   it("accepts valid statuses", () => {
-    for (const status of ["recording", "processing", "complete", "error"]) {
+    for (const status of [
+      "recording",
+      "processing",
+      "complete",
+      "error",
+      "cancelled",
+    ]) {
       expect(MeetingStatusSchema.safeParse(status).success).toBe(true);
     }
   });
@@ -0,0 +1,193 @@
+import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
+import { CalendarClock, Trash2, X } from "lucide-react";
+import { useCallback, useEffect, useState } from "react";
+import { toast } from "sonner";
+import { Button } from "@/components/ui/button";
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui/select";
+import {
+  type AudioSource,
+  AudioSourceSchema,
+  type ScheduledRecording,
+  type ScheduleStatus,
+} from "@/lib/types";
+
+const inputClassName =
+  "h-9 rounded-md border border-border/40 bg-transparent px-3 text-sm outline-none placeholder:text-muted-foreground focus:border-foreground/30";
+
+const STATUS_LABELS: Record<ScheduleStatus, string> = {
+  pending: "Upcoming",
+  recording: "Recording",
+  complete: "Done",
+  missed: "Missed",
+  cancelled: "Cancelled",
+  failed: "Failed",
+};
+
+/** `date` as local time, in the format a datetime-local input expects. */
+const localDateTimeValue = (date: Date) => {
+  const offsetMs = date.getTimezoneOffset() * 60_000;
+  return new Date(date.getTime() - offsetMs).toISOString().slice(0, 16);
+};
+
+export const ScheduledRecordings = () => {
+  const [recordings, setRecordings] = useState<ScheduledRecording[]>([]);
+  const [title, setTitle] = useState("");
+  const [startAt, setStartAt] = useState(() => localDateTimeValue(new Date()));
+  const [durationMins, setDurationMins] = useState(60);
+  const [source, setSource] = useState<AudioSource>("system");
+
+  const refresh = useCallback(() => {
+    invoke<ScheduledRecording[]>("list_scheduled_recordings")
+      .then(setRecordings)
+      .catch((error) =>
+        console.error("Failed to load scheduled recordings:", error)
+      );
+  }, []);
+
+  useEffect(() => {
+    refresh();
+    const unlisten = listen("scheduled-recordings-changed", refresh);
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, [refresh]);
+
+  const handleSchedule = async () => {
+    const start = new Date(startAt);
+    if (Number.isNaN(start.getTime())) {
+      toast.error("Pick a start time");
+      return;
+    }
+    try {
+      await invoke("schedule_recording", {
+        title: title || null,
+        startTime: Math.floor(start.getTime() / 1000),
+        durationSecs: durationMins * 60,
+        source,
+      });
+      setTitle("");
+      toast.success("Recording scheduled");
+    } catch (error) {
+      toast.error(String(error));
+    }
+  };
+
+  const handleCancel = async (id: number) => {
+    try {
+      await invoke("cancel_scheduled_recording", { id });
+    } catch (error) {
+      toast.error(String(error));
+    }
+  };
+
+  const handleDelete = async (id: number) => {
+    try {
+      await invoke("delete_scheduled_recording", { id });
+    } catch (error) {
+      toast.error(String(error));
+    }
+  };
+
+  return (
+    <div className="flex flex-col gap-3">
+      <p className="text-muted-foreground text-sm">
+        Record a webinar or call at a set time. It's transcribed and summarized
+        when the recording ends. Echo must be running at the start time.
+      </p>
+      <div className="flex flex-wrap items-center gap-2">
+        <input
+          className={inputClassName}
+          onChange={(e) => setTitle(e.target.value)}
+          placeholder="Title (optional)"
+          type="text"
+          value={title}
+        />
+        <input
+          className={inputClassName}
+          onChange={(e) => setStartAt(e.target.value)}
+          type="datetime-local"
+          value={startAt}
+        />
+        <input
+          className={`${inputClassName} w-20`}
+          min={1}
+          onChange={(e) => setDurationMins(Number(e.target.value) || 1)}
+          title="Duration in minutes"
+          type="number"
+          value={durationMins}
+        />
+        <span className="text-muted-foreground text-sm">min</span>
+        <Select
+          onValueChange={(value) => {
+            const parsed = AudioSourceSchema.safeParse(value);
+            if (parsed.success) {
+              setSource(parsed.data);
+            }
+          }}
+          value={source}
+        >
+          <SelectTrigger className="w-36">
+            <SelectValue />
+          </SelectTrigger>
+          <SelectContent>
+            <SelectItem value="system">System audio</SelectItem>
+            <SelectItem value="mic">Microphone</SelectItem>
+          </SelectContent>
+        </Select>
+        <Button onClick={handleSchedule} size="sm" variant="outline">
+          <CalendarClock className="mr-1.5 size-3.5" />
+          Schedule
+        </Button>
+      </div>
+
+      {recordings.length > 0 && (
+        <ul className="flex flex-col divide-y divide-border/20 rounded-lg border border-border/20">
+          {recordings.map((recording) => (
+            <li
+              className="flex items-center justify-between gap-3 px-4 py-2"
+              key={recording.id}
+            >
+              <div className="min-w-0">
+                <p className="truncate text-sm">{recording.title}</p>
+                <p className="text-muted-foreground text-xs">
+                  {new Date(recording.start_time * 1000).toLocaleString()} ·{" "}
+                  {Math.round(recording.duration_secs / 60)} min ·{" "}
+                  {recording.source === "system" ? "System audio" : "Microphone"}{" "}
+                  · {STATUS_LABELS[recording.status]}
+                  {recording.error && ` — ${recording.error}`}
+                </p>
+              </div>
+              {recording.status === "pending" ||
+              recording.status === "recording" ? (
+                <Button
+                  onClick={() => handleCancel(recording.id)}
+                  size="sm"
+                  variant="ghost"
+                >
+                  <X className="mr-1.5 size-3.5" />
+                  {recording.status === "recording" ? "Stop" : "Cancel"}
+                </Button>
+              ) : (
+                <Button
+                  onClick={() => handleDelete(recording.id)}
+                  size="icon-sm"
+                  title="Remove from list"
+                  variant="ghost"
+                >
+                  <Trash2 className="size-3.5" />
+                </Button>
+              )}
+            </li>
+          ))}
+        </ul>
+      )}
+    </div>
+  );
+};
@@ -3,16 +3,24 @@ import { openUrl } from "@tauri-apps/plugin-opener";
 import { ExternalLink, Github, Heart, Info } from "lucide-react";
 import type React from "react";
 import { useEffect, useState } from "react";
+import { FeatureFlags } from "@/components/settings/about/feature-flags";
+import { WhatsNew } from "@/components/settings/about/whats-new";
+import { AlwaysOnIdleRelease } from "@/components/settings/always-on-idle-release";
 import { AlwaysOnMicrophone } from "@/components/settings/always-on-microphone";
 import { AppDataDirectory } from "@/components/settings/app-data-directory";
 import { ClamshellMicrophoneSelector } from "@/components/settings/clamshell-microphone-selector";
 import { LogDirectory } from "@/components/settings/debug/log-directory";
 import { LogLevelSelector } from "@/components/settings/debug/log-level-selector";
+import { RuntimeInfo } from "@/components/settings/debug/runtime-info";
 import { WordCorrectionThreshold } from "@/components/settings/debug/word-correction-threshold";
+import { HistoryImport } from "@/components/settings/history-import";
 import { HistoryLimit } from "@/components/settings/history-limit";
 import { MuteWhileRecording } from "@/components/settings/mute-while-recording";
+import { PreRoll } from "@/components/settings/pre-roll";
+import { RecordingFormatSelector } from "@/components/settings/recording-format";
 import { RecordingRetentionPeriodSelector } from "@/components/settings/recording-retention-period";
 import { SoundPicker } from "@/components/settings/sound-picker";
+import { SourceRetention } from "@/components/settings/source-retention";
 import { Button } from "@/components/ui/button";
 import { CollapsibleSettingsGroup } from "@/components/ui/collapsible-settings-group";
 import {
@@ -158,6 +166,16 @@ export const AboutDialog: React.FC<AboutDialogProps> = ({ trigger }) => {
             </SettingContainer>
           </CollapsibleSettingsGroup>
 
+          {/* What's New Section */}
+          <CollapsibleSettingsGroup defaultOpen={false} title="What's New">
+            <WhatsNew />
+          </CollapsibleSettingsGroup>
+
+          {/* Beta Features Section */}
+          <CollapsibleSettingsGroup defaultOpen={false} title="Beta Features">
+            <FeatureFlags />
+          </CollapsibleSettingsGroup>
+
           {/* Debug Section */}
           <CollapsibleSettingsGroup
             defaultOpen={false}
@@ -189,13 +207,19 @@ export const AboutDialog: React.FC<AboutDialogProps> = ({ trigger }) => {
               descriptionMode="tooltip"
               grouped={true}
             />
+            <RecordingFormatSelector descriptionMode="tooltip" grouped={true} />
+            <SourceRetention descriptionMode="tooltip" grouped={true} />
+            <HistoryImport descriptionMode="tooltip" grouped={true} />
             <AlwaysOnMicrophone descriptionMode="tooltip" grouped={true} />
+            <AlwaysOnIdleRelease descriptionMode="tooltip" grouped={true} />
+            <PreRoll descriptionMode="tooltip" grouped={true} />
             <ClamshellMicrophoneSelector
               descriptionMode="tooltip"
               grouped={true}
             />
             <LogDirectory descriptionMode="tooltip" grouped={true} />
             <LogLevelSelector descriptionMode="tooltip" grouped={true} />
+            <RuntimeInfo grouped={true} />
             <MuteWhileRecording descriptionMode="tooltip" grouped={true} />
           </CollapsibleSettingsGroup>
         </div>
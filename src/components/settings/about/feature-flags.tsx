@@ -0,0 +1,50 @@
+import { RotateCcw } from "lucide-react";
+import { toast } from "sonner";
+import { Button } from "@/components/ui/button";
+import { SettingContainer } from "@/components/ui/setting-container";
+import { Switch } from "@/components/ui/switch";
+import { useFeatureFlags } from "@/hooks/use-feature-flags";
+import type { FeatureFlagId } from "@/lib/types";
+
+export const FeatureFlags = () => {
+  const { flags, setFlag } = useFeatureFlags();
+
+  const update = async (flag: FeatureFlagId, enabled: boolean | null) => {
+    try {
+      await setFlag(flag, enabled);
+    } catch (error) {
+      toast.error(`Failed to update feature: ${String(error)}`);
+    }
+  };
+
+  return (
+    <>
+      {flags.map((flag) => (
+        <SettingContainer
+          description={flag.description}
+          descriptionMode="tooltip"
+          grouped={true}
+          key={flag.id}
+          title={flag.name}
+        >
+          <div className="flex items-center gap-2">
+            {flag.overridden && (
+              <Button
+                onClick={() => update(flag.id, null)}
+                size="icon-sm"
+                title="Reset to default"
+                variant="ghost"
+              >
+                <RotateCcw className="size-3.5" />
+              </Button>
+            )}
+            <Switch
+              checked={flag.enabled}
+              onCheckedChange={(value) => update(flag.id, value)}
+            />
+          </div>
+        </SettingContainer>
+      ))}
+    </>
+  );
+};
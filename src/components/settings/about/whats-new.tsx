@@ -0,0 +1,62 @@
+import { invoke } from "@tauri-apps/api/core";
+import { useEffect, useState } from "react";
+import type { ChangelogRelease } from "@/lib/types";
+
+/** How many releases to show when no previous version is given. */
+const RECENT_RELEASES = 3;
+
+interface WhatsNewProps {
+  /** Show releases newer than this version; the latest few without one */
+  sinceVersion?: string;
+}
+
+export const WhatsNew = ({ sinceVersion }: WhatsNewProps) => {
+  const [releases, setReleases] = useState<ChangelogRelease[]>([]);
+
+  useEffect(() => {
+    invoke<ChangelogRelease[]>("get_changelog_since", {
+      version: sinceVersion ?? null,
+    })
+      .then((result) =>
+        setReleases(sinceVersion ? result : result.slice(0, RECENT_RELEASES))
+      )
+      .catch((error) => console.error("Failed to load changelog:", error));
+  }, [sinceVersion]);
+
+  if (releases.length === 0) {
+    return (
+      <p className="px-4 py-2 text-muted-foreground text-sm">
+        No release notes available.
+      </p>
+    );
+  }
+
+  return (
+    <div className="flex flex-col gap-4 px-4 py-2">
+      {releases.map((release) => (
+        <div className="flex flex-col gap-2" key={release.version}>
+          <div className="flex items-baseline gap-2">
+            <span className="font-medium text-sm">{release.version}</span>
+            {release.date && (
+              <span className="text-muted-foreground text-xs">
+                {release.date}
+              </span>
+            )}
+          </div>
+          {release.sections.map((section) => (
+            <div key={section.title}>
+              <p className="font-medium text-muted-foreground text-xs">
+                {section.title}
+              </p>
+              <ul className="list-disc pl-5 text-sm">
+                {section.items.map((item) => (
+                  <li key={item}>{item.replaceAll("**", "")}</li>
+                ))}
+              </ul>
+            </div>
+          ))}
+        </div>
+      ))}
+    </div>
+  );
+};
@@ -0,0 +1,75 @@
+import { MicOff } from "lucide-react";
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui/select";
+import { SettingContainer } from "@/components/ui/setting-container";
+import {
+  useIsSettingUpdating,
+  useSetting,
+  useSettingsStore,
+} from "@/stores/settings-store";
+
+interface AlwaysOnIdleReleaseProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+const KEEP_OPEN = "0";
+
+const releaseOptions = [
+  { value: "5", label: "After 5 minutes" },
+  { value: "15", label: "After 15 minutes" },
+  { value: "30", label: "After 30 minutes" },
+  { value: "60", label: "After 1 hour" },
+  { value: KEEP_OPEN, label: "Never" },
+];
+
+export const AlwaysOnIdleRelease = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}: AlwaysOnIdleReleaseProps) => {
+  const alwaysOn = useSetting("always_on_microphone") ?? false;
+  const minutes = useSetting("always_on_idle_release_minutes");
+  const updating = useIsSettingUpdating("always_on_idle_release_minutes");
+  const updateSetting = useSettingsStore((s) => s.updateSetting);
+
+  if (!alwaysOn) {
+    return null;
+  }
+
+  return (
+    <SettingContainer
+      description="Close the always-on microphone when nothing was recorded for a while, so the microphone indicator goes off. The next shortcut press opens it again, without the pre-roll for that first recording."
+      descriptionMode={descriptionMode}
+      grouped={grouped}
+      icon={<MicOff className="h-4 w-4" />}
+      title="Release Idle Microphone"
+    >
+      <Select
+        disabled={updating}
+        onValueChange={(value) =>
+          updateSetting(
+            "always_on_idle_release_minutes",
+            value === KEEP_OPEN ? null : Number(value)
+          )
+        }
+        value={minutes ? String(minutes) : KEEP_OPEN}
+      >
+        <SelectTrigger className="w-44">
+          <SelectValue />
+        </SelectTrigger>
+        <SelectContent>
+          {releaseOptions.map((option) => (
+            <SelectItem key={option.value} value={option.value}>
+              {option.label}
+            </SelectItem>
+          ))}
+        </SelectContent>
+      </Select>
+    </SettingContainer>
+  );
+};
@@ -0,0 +1,117 @@
+import { invoke } from "@tauri-apps/api/core";
+import { AppWindow, PlusIcon, Trash2 } from "lucide-react";
+import { useState } from "react";
+import { toast } from "sonner";
+import { Button } from "@/components/ui/button";
+import { Input } from "@/components/ui/input";
+import { SettingContainer } from "@/components/ui/setting-container";
+import { cn } from "@/lib/utils";
+import { useSetting, useSettingsStore } from "@/stores/settings-store";
+
+interface AppCustomWordsProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+const parseWords = (value: string) =>
+  value
+    .split(",")
+    .map((word) => word.trim())
+    .filter(Boolean);
+
+export const AppCustomWords = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}: AppCustomWordsProps) => {
+  const lists = useSetting("app_custom_words") ?? {};
+  const refreshSettings = useSettingsStore((s) => s.refreshSettings);
+  const [appId, setAppId] = useState("");
+  const [words, setWords] = useState("");
+
+  const save = async (app: string, next: string[]) => {
+    try {
+      await invoke("set_app_custom_words", { appId: app, words: next });
+      await refreshSettings();
+      return true;
+    } catch (error) {
+      toast.error(String(error));
+      return false;
+    }
+  };
+
+  const add = async () => {
+    const app = appId.trim();
+    const merged = [...new Set([...(lists[app] ?? []), ...parseWords(words)])];
+    if (await save(app, merged)) {
+      setAppId("");
+      setWords("");
+    }
+  };
+
+  return (
+    <>
+      <SettingContainer
+        description="Custom words used only while a given app is frontmost, on top of the global list. Enter the app's name or bundle id, like com.microsoft.VSCode."
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+        icon={<AppWindow className="h-4 w-4" />}
+        layout="stacked"
+        title="Per-App Custom Words"
+      >
+        <div className="flex flex-wrap items-center gap-2">
+          <Input
+            className="w-48 shrink-0"
+            onChange={(e) => setAppId(e.target.value)}
+            placeholder="App name or bundle id"
+            value={appId}
+          />
+          <Input
+            className="min-w-40 flex-1"
+            onChange={(e) => setWords(e.target.value)}
+            placeholder="Kubernetes, Tauri, Zod"
+            value={words}
+          />
+          <Button
+            aria-label="Add words"
+            disabled={!(appId.trim() && parseWords(words).length > 0)}
+            onClick={add}
+            size="icon"
+            variant="outline"
+          >
+            <PlusIcon className="h-4 w-4" />
+          </Button>
+        </div>
+      </SettingContainer>
+      {Object.keys(lists).length > 0 && (
+        <div
+          className={cn(
+            "space-y-2 p-2 px-4",
+            !grouped && "rounded-lg border border-border/20"
+          )}
+        >
+          {Object.entries(lists).map(([app, appWords]) => (
+            <div
+              className="flex items-center justify-between gap-2 text-sm"
+              key={app}
+            >
+              <div className="min-w-0">
+                <div className="truncate font-medium">{app}</div>
+                <div className="truncate text-muted-foreground text-xs">
+                  {appWords.join(", ")}
+                </div>
+              </div>
+              <Button
+                aria-label={`Remove words for ${app}`}
+                onClick={() => save(app, [])}
+                size="icon"
+                variant="ghost"
+              >
+                <Trash2 className="h-4 w-4" />
+              </Button>
+            </div>
+          ))}
+        </div>
+      )}
+    </>
+  );
+};
@@ -1,13 +1,32 @@
 import { AudioFeedback } from "@/components/settings/audio-feedback";
+import { AutoStopSilence } from "@/components/settings/auto-stop-silence";
+import { AutomaticGain } from "@/components/settings/automatic-gain";
+import { AutomationRules } from "@/components/settings/automation-rules";
 import { AutostartToggle } from "@/components/settings/autostart-toggle";
 import { ClipboardHandlingSetting } from "@/components/settings/clipboard-handling";
+import { DataApiSettings } from "@/components/settings/data-api-settings";
 import { EchoShortcut } from "@/components/settings/echo-shortcut";
+import { FeedbackSoundDevices } from "@/components/settings/feedback-sound-devices";
+import { InputGain } from "@/components/settings/input-gain";
+import { MaxRecordingDuration } from "@/components/settings/max-recording-duration";
+import { MicrophoneCheck } from "@/components/settings/microphone-check";
 import { MicrophoneSelector } from "@/components/settings/microphone-selector";
+import { MuteWarning } from "@/components/settings/mute-warning";
+import { NoPasteTargets } from "@/components/settings/no-paste-targets";
+import { NoiseProfiles } from "@/components/settings/noise-profiles";
+import { NoiseSuppression } from "@/components/settings/noise-suppression";
 import { OutputDeviceSelector } from "@/components/settings/output-device-selector";
+import { OverlayShortcutHint } from "@/components/settings/overlay-shortcut-hint";
 import { PasteMethodSetting } from "@/components/settings/paste-method";
+import { PrivacyMode } from "@/components/settings/privacy-mode";
+import { ProfileSelector } from "@/components/settings/profile-selector";
 import { PushToTalk } from "@/components/settings/push-to-talk";
+import { ScreenReaderAnnouncements } from "@/components/settings/screen-reader-announcements";
 import { ShowOverlay } from "@/components/settings/show-overlay";
+import { SilentFeedback } from "@/components/settings/silent-feedback";
 import { StartHidden } from "@/components/settings/start-hidden";
+import { VerifyPasteTarget } from "@/components/settings/verify-paste-target";
+import { VoiceProfile } from "@/components/settings/voice-profile";
 import { VolumeSlider } from "@/components/settings/volume-slider";
 import { CollapsibleSettingsGroup } from "@/components/ui/collapsible-settings-group";
 import { useSetting } from "@/stores/settings-store";
@@ -20,12 +39,31 @@ export const AppSettings = () => {
       <CollapsibleSettingsGroup defaultOpen={true} title="Startup">
         <StartHidden descriptionMode="tooltip" grouped={true} />
         <AutostartToggle descriptionMode="tooltip" grouped={true} />
+        <ProfileSelector descriptionMode="tooltip" grouped={true} />
+        <PrivacyMode descriptionMode="tooltip" grouped={true} />
+        <AutomationRules descriptionMode="tooltip" grouped={true} />
       </CollapsibleSettingsGroup>
 
       <CollapsibleSettingsGroup defaultOpen={true} title="Recording">
         <EchoShortcut descriptionMode="tooltip" grouped={true} />
+        <EchoShortcut
+          bindingId="continue_dictation"
+          description="Record more and add it to the end of your last dictation, pasting only the new part"
+          descriptionMode="tooltip"
+          grouped={true}
+          title="Continue Dictation"
+        />
         <PushToTalk descriptionMode="tooltip" grouped={true} />
+        <AutoStopSilence descriptionMode="tooltip" grouped={true} />
+        <MaxRecordingDuration descriptionMode="tooltip" grouped={true} />
         <MicrophoneSelector descriptionMode="tooltip" grouped={true} />
+        <MicrophoneCheck descriptionMode="tooltip" grouped={true} />
+        <InputGain descriptionMode="tooltip" grouped={true} />
+        <AutomaticGain descriptionMode="tooltip" grouped={true} />
+        <MuteWarning descriptionMode="tooltip" grouped={true} />
+        <NoiseSuppression descriptionMode="tooltip" grouped={true} />
+        <NoiseProfiles descriptionMode="tooltip" grouped={true} />
+        <VoiceProfile descriptionMode="tooltip" grouped={true} />
       </CollapsibleSettingsGroup>
 
       <CollapsibleSettingsGroup defaultOpen={true} title="Audio Feedback">
@@ -35,13 +73,27 @@ export const AppSettings = () => {
           disabled={!audioFeedbackEnabled}
           grouped={true}
         />
+        <FeedbackSoundDevices
+          descriptionMode="tooltip"
+          disabled={!audioFeedbackEnabled}
+          grouped={true}
+        />
         <VolumeSlider disabled={!audioFeedbackEnabled} />
+        <SilentFeedback descriptionMode="tooltip" grouped={true} />
+        <ScreenReaderAnnouncements descriptionMode="tooltip" grouped={true} />
       </CollapsibleSettingsGroup>
 
       <CollapsibleSettingsGroup defaultOpen={true} title="Output">
         <ShowOverlay descriptionMode="tooltip" grouped={true} />
+        <OverlayShortcutHint descriptionMode="tooltip" grouped={true} />
         <PasteMethodSetting descriptionMode="tooltip" grouped={true} />
         <ClipboardHandlingSetting descriptionMode="tooltip" grouped={true} />
+        <VerifyPasteTarget descriptionMode="tooltip" grouped={true} />
+        <NoPasteTargets descriptionMode="tooltip" grouped={true} />
+      </CollapsibleSettingsGroup>
+
+      <CollapsibleSettingsGroup defaultOpen={false} title="Integrations">
+        <DataApiSettings descriptionMode="tooltip" grouped={true} />
       </CollapsibleSettingsGroup>
     </div>
   );
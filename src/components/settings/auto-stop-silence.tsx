@@ -0,0 +1,71 @@
+import { TimerOff } from "lucide-react";
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui/select";
+import { SettingContainer } from "@/components/ui/setting-container";
+import {
+  useIsSettingUpdating,
+  useSetting,
+  useSettingsStore,
+} from "@/stores/settings-store";
+
+interface AutoStopSilenceProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+const OFF = "0";
+
+const silenceOptions = [
+  { value: OFF, label: "Off" },
+  { value: "2", label: "2 seconds" },
+  { value: "3", label: "3 seconds" },
+  { value: "5", label: "5 seconds" },
+  { value: "10", label: "10 seconds" },
+];
+
+export const AutoStopSilence = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}: AutoStopSilenceProps) => {
+  const seconds = useSetting("auto_stop_silence_secs");
+  const pushToTalk = useSetting("push_to_talk");
+  const updating = useIsSettingUpdating("auto_stop_silence_secs");
+  const updateSetting = useSettingsStore((s) => s.updateSetting);
+
+  return (
+    <SettingContainer
+      description="Stop a toggle recording by itself once you've stopped talking for this long, as if you'd pressed the shortcut. Push-to-talk already stops when you let go."
+      descriptionMode={descriptionMode}
+      grouped={grouped}
+      icon={<TimerOff className="h-4 w-4" />}
+      title="Stop on Silence"
+    >
+      <Select
+        disabled={pushToTalk || updating}
+        onValueChange={(value) =>
+          updateSetting(
+            "auto_stop_silence_secs",
+            value === OFF ? null : Number(value)
+          )
+        }
+        value={seconds ? String(seconds) : OFF}
+      >
+        <SelectTrigger className="w-36">
+          <SelectValue />
+        </SelectTrigger>
+        <SelectContent>
+          {silenceOptions.map((option) => (
+            <SelectItem key={option.value} value={option.value}>
+              {option.label}
+            </SelectItem>
+          ))}
+        </SelectContent>
+      </Select>
+    </SettingContainer>
+  );
+};
@@ -0,0 +1,38 @@
+import { Gauge } from "lucide-react";
+import { SettingContainer } from "@/components/ui/setting-container";
+import { Switch } from "@/components/ui/switch";
+import {
+  useIsSettingUpdating,
+  useSetting,
+  useSettingsStore,
+} from "@/stores/settings-store";
+
+interface AutomaticGainProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const AutomaticGain = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}: AutomaticGainProps) => {
+  const enabled = useSetting("auto_gain_enabled");
+  const updating = useIsSettingUpdating("auto_gain_enabled");
+  const updateSetting = useSettingsStore((s) => s.updateSetting);
+
+  return (
+    <SettingContainer
+      description="Level your voice automatically, bringing a quiet or distant voice up to a steady volume without boosting the silence between words. Applies from the next recording."
+      descriptionMode={descriptionMode}
+      grouped={grouped}
+      icon={<Gauge className="h-4 w-4" />}
+      title="Automatic Gain"
+    >
+      <Switch
+        checked={enabled}
+        disabled={updating}
+        onCheckedChange={(value) => updateSetting("auto_gain_enabled", value)}
+      />
+    </SettingContainer>
+  );
+};
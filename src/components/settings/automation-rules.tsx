@@ -0,0 +1,334 @@
+import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
+import { Clock, Plus, Trash2 } from "lucide-react";
+import { useCallback, useEffect, useState } from "react";
+import { toast } from "sonner";
+import { Button } from "@/components/ui/button";
+import { Input } from "@/components/ui/input";
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui/select";
+import { SettingContainer } from "@/components/ui/setting-container";
+import { Switch } from "@/components/ui/switch";
+import { Toggle } from "@/components/ui/toggle";
+import type { AutomationRule, RuleActions } from "@/lib/types";
+import { cn } from "@/lib/utils";
+
+interface AutomationRulesProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+const DAY_LABELS = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+const WEEKDAYS = [0, 1, 2, 3, 4];
+const NO_CHANGE = "unchanged";
+
+const NO_ACTIONS: RuleActions = {
+  input_tracking: null,
+  privacy_mode: null,
+  profile: null,
+};
+
+/** "09:30" to minutes after midnight, and back */
+const toMinutes = (time: string) => {
+  const [hours, minutes] = time.split(":").map(Number);
+  return hours * 60 + minutes;
+};
+const pad = (value: number) => String(value).padStart(2, "0");
+const toTime = (minutes: number) =>
+  `${pad(Math.floor(minutes / 60))}:${pad(minutes % 60)}`;
+
+const describeActions = (actions: RuleActions) => {
+  const parts: string[] = [];
+  if (actions.input_tracking !== null) {
+    parts.push(`input tracking ${actions.input_tracking ? "on" : "off"}`);
+  }
+  if (actions.privacy_mode !== null) {
+    parts.push(`privacy mode ${actions.privacy_mode ? "on" : "off"}`);
+  }
+  if (actions.profile !== null) {
+    parts.push(`${actions.profile} profile`);
+  }
+  return parts.join(", ") || "no change";
+};
+
+const ruleInput = (rule: AutomationRule) => {
+  const { id: _id, ...input } = rule;
+  return input;
+};
+
+const onOffValue = (value: boolean | null) => {
+  if (value === null) {
+    return NO_CHANGE;
+  }
+  return value ? "on" : "off";
+};
+
+interface OnOffSelectProps {
+  label: string;
+  onChange: (value: boolean | null) => void;
+  value: boolean | null;
+}
+
+const OnOffSelect = ({ label, onChange, value }: OnOffSelectProps) => (
+  <Select
+    onValueChange={(next) =>
+      onChange(next === NO_CHANGE ? null : next === "on")
+    }
+    value={onOffValue(value)}
+  >
+    <SelectTrigger aria-label={label} className="w-44">
+      <SelectValue />
+    </SelectTrigger>
+    <SelectContent>
+      <SelectItem value={NO_CHANGE}>{label}: no change</SelectItem>
+      <SelectItem value="on">{label}: on</SelectItem>
+      <SelectItem value="off">{label}: off</SelectItem>
+    </SelectContent>
+  </Select>
+);
+
+interface ActionsPickerProps {
+  actions: RuleActions;
+  label: string;
+  onChange: (actions: RuleActions) => void;
+  profiles: string[];
+}
+
+const ActionsPicker = ({
+  actions,
+  label,
+  onChange,
+  profiles,
+}: ActionsPickerProps) => (
+  <div className="flex flex-wrap items-center gap-2">
+    <span className="w-20 text-muted-foreground text-sm">{label}</span>
+    <OnOffSelect
+      label="Input tracking"
+      onChange={(value) => onChange({ ...actions, input_tracking: value })}
+      value={actions.input_tracking}
+    />
+    <OnOffSelect
+      label="Privacy mode"
+      onChange={(value) => onChange({ ...actions, privacy_mode: value })}
+      value={actions.privacy_mode}
+    />
+    <Select
+      onValueChange={(value) =>
+        onChange({ ...actions, profile: value === NO_CHANGE ? null : value })
+      }
+      value={actions.profile ?? NO_CHANGE}
+    >
+      <SelectTrigger aria-label="Profile" className="w-40">
+        <SelectValue />
+      </SelectTrigger>
+      <SelectContent>
+        <SelectItem value={NO_CHANGE}>Profile: no change</SelectItem>
+        {profiles.map((name) => (
+          <SelectItem key={name} value={name}>
+            Profile: {name}
+          </SelectItem>
+        ))}
+      </SelectContent>
+    </Select>
+  </div>
+);
+
+export const AutomationRules = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}: AutomationRulesProps) => {
+  const [rules, setRules] = useState<AutomationRule[]>([]);
+  const [profiles, setProfiles] = useState<string[]>([]);
+  const [name, setName] = useState("");
+  const [days, setDays] = useState<number[]>(WEEKDAYS);
+  const [start, setStart] = useState("09:00");
+  const [end, setEnd] = useState("18:00");
+  const [actions, setActions] = useState<RuleActions>(NO_ACTIONS);
+  const [otherwise, setOtherwise] = useState<RuleActions>(NO_ACTIONS);
+
+  const refresh = useCallback(() => {
+    invoke<AutomationRule[]>("list_automation_rules")
+      .then(setRules)
+      .catch((error) =>
+        console.error("Failed to load automation rules:", error)
+      );
+  }, []);
+
+  useEffect(() => {
+    refresh();
+    invoke<string[]>("list_profiles")
+      .then(setProfiles)
+      .catch(() => setProfiles(["default"]));
+    const unlisten = listen("automation-rules-changed", refresh);
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, [refresh]);
+
+  const toggleDay = (day: number, pressed: boolean) =>
+    setDays((current) =>
+      pressed
+        ? [...current, day].sort((a, b) => a - b)
+        : current.filter((other) => other !== day)
+    );
+
+  const handleCreate = async () => {
+    try {
+      await invoke("create_automation_rule", {
+        rule: {
+          name,
+          enabled: true,
+          days,
+          start_minute: toMinutes(start),
+          end_minute: toMinutes(end),
+          actions,
+          otherwise,
+        },
+      });
+      setName("");
+      setActions(NO_ACTIONS);
+      setOtherwise(NO_ACTIONS);
+    } catch (error) {
+      toast.error(String(error));
+    }
+  };
+
+  const handleToggle = async (rule: AutomationRule, enabled: boolean) => {
+    try {
+      await invoke("update_automation_rule", {
+        id: rule.id,
+        rule: { ...ruleInput(rule), enabled },
+      });
+    } catch (error) {
+      toast.error(String(error));
+    }
+  };
+
+  const handleDelete = async (id: number) => {
+    try {
+      await invoke("delete_automation_rule", { id });
+    } catch (error) {
+      toast.error(String(error));
+    }
+  };
+
+  return (
+    <>
+      <SettingContainer
+        description="Change settings by time of day, like input tracking and the work profile on weekdays from 9 to 18 and privacy mode otherwise. Rules apply when their window opens or closes, so you can still change things by hand in between. Switching profile restarts Echo."
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+        icon={<Clock className="h-4 w-4" />}
+        layout="stacked"
+        title="Automation Rules"
+      >
+        <div className="flex flex-col gap-2">
+          <div className="flex flex-wrap items-center gap-2">
+            <Input
+              className="w-40"
+              onChange={(e) => setName(e.target.value)}
+              placeholder="Rule name"
+              value={name}
+            />
+            <Input
+              aria-label="From"
+              className="w-28"
+              onChange={(e) => setStart(e.target.value)}
+              type="time"
+              value={start}
+            />
+            <span className="text-muted-foreground text-sm">to</span>
+            <Input
+              aria-label="To"
+              className="w-28"
+              onChange={(e) => setEnd(e.target.value)}
+              type="time"
+              value={end}
+            />
+          </div>
+          <div className="flex flex-wrap gap-1">
+            {DAY_LABELS.map((label, day) => (
+              <Toggle
+                key={label}
+                onPressedChange={(pressed) => toggleDay(day, pressed)}
+                pressed={days.includes(day)}
+                size="sm"
+                variant="outline"
+              >
+                {label}
+              </Toggle>
+            ))}
+          </div>
+          <ActionsPicker
+            actions={actions}
+            label="During"
+            onChange={setActions}
+            profiles={profiles}
+          />
+          <ActionsPicker
+            actions={otherwise}
+            label="Otherwise"
+            onChange={setOtherwise}
+            profiles={profiles}
+          />
+          <div>
+            <Button
+              disabled={!(name.trim() && days.length > 0)}
+              onClick={handleCreate}
+              size="sm"
+              variant="outline"
+            >
+              <Plus className="mr-1.5 size-3.5" />
+              Add rule
+            </Button>
+          </div>
+        </div>
+      </SettingContainer>
+      {rules.length > 0 && (
+        <div
+          className={cn(
+            "space-y-2 p-2 px-4",
+            !grouped && "rounded-lg border border-border/20"
+          )}
+        >
+          {rules.map((rule) => (
+            <div
+              className="flex items-center justify-between gap-2 text-sm"
+              key={rule.id}
+            >
+              <div className="min-w-0">
+                <p className="truncate font-medium">{rule.name}</p>
+                <p className="text-muted-foreground text-xs">
+                  {rule.days.map((day) => DAY_LABELS[day]).join(", ")} ·{" "}
+                  {toTime(rule.start_minute)}–{toTime(rule.end_minute)} ·{" "}
+                  {describeActions(rule.actions)}; otherwise{" "}
+                  {describeActions(rule.otherwise)}
+                </p>
+              </div>
+              <div className="flex shrink-0 items-center gap-2">
+                <Switch
+                  aria-label={`Enable ${rule.name}`}
+                  checked={rule.enabled}
+                  onCheckedChange={(enabled) => handleToggle(rule, enabled)}
+                />
+                <Button
+                  aria-label={`Delete ${rule.name}`}
+                  onClick={() => handleDelete(rule.id)}
+                  size="icon"
+                  variant="ghost"
+                >
+                  <Trash2 className="h-4 w-4" />
+                </Button>
+              </div>
+            </div>
+          ))}
+        </div>
+      )}
+    </>
+  );
+};
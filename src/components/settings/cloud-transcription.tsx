@@ -0,0 +1,115 @@
+import { invoke } from "@tauri-apps/api/core";
+import { Cloud } from "lucide-react";
+import { toast } from "sonner";
+import { ApiKeyField } from "@/components/settings/post-processing-settings-api/api-key-field";
+import { ProviderSelect } from "@/components/settings/post-processing-settings-api/provider-select";
+import { Input } from "@/components/ui/input";
+import { SettingContainer } from "@/components/ui/setting-container";
+import { Switch } from "@/components/ui/switch";
+import {
+  useIsSettingUpdating,
+  useSetting,
+  useSettingsStore,
+} from "@/stores/settings-store";
+
+interface CloudTranscriptionProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const CloudTranscription = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}: CloudTranscriptionProps) => {
+  const enabled = useSetting("cloud_transcription_enabled");
+  const providerId = useSetting("transcription_provider_id");
+  const providers = useSetting("transcription_providers") || [];
+  const apiKeys = useSetting("transcription_api_keys") || {};
+  const models = useSetting("transcription_models") || {};
+  const updating = useIsSettingUpdating("cloud_transcription_enabled");
+  const updateSetting = useSettingsStore((s) => s.updateSetting);
+  const refreshSettings = useSettingsStore((s) => s.refreshSettings);
+
+  const save = async (command: string, args: Record<string, string>) => {
+    try {
+      await invoke(command, { providerId, ...args });
+      await refreshSettings();
+    } catch (error) {
+      toast.error(String(error));
+    }
+  };
+
+  return (
+    <>
+      <SettingContainer
+        description="Send recordings to a cloud speech service instead of transcribing on this machine. The local model takes over when the service can't be reached."
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+        icon={<Cloud className="h-4 w-4" />}
+        title="Cloud Transcription"
+      >
+        <Switch
+          checked={enabled}
+          disabled={updating}
+          onCheckedChange={(value) =>
+            updateSetting("cloud_transcription_enabled", value)
+          }
+        />
+      </SettingContainer>
+
+      {enabled && (
+        <>
+          <SettingContainer
+            description="The service recordings are uploaded to."
+            descriptionMode={descriptionMode}
+            grouped={grouped}
+            title="Provider"
+          >
+            <ProviderSelect
+              onChange={(value) =>
+                updateSetting("transcription_provider_id", value)
+              }
+              options={providers.map((provider) => ({
+                label: provider.label,
+                value: provider.id,
+              }))}
+              value={providerId}
+            />
+          </SettingContainer>
+          <SettingContainer
+            description="The provider's API key. It is stored with your settings on this machine."
+            descriptionMode={descriptionMode}
+            grouped={grouped}
+            title="API Key"
+          >
+            <ApiKeyField
+              disabled={false}
+              onBlur={(value) =>
+                save("change_transcription_api_key_setting", { apiKey: value })
+              }
+              placeholder="sk-..."
+              value={apiKeys[providerId] ?? ""}
+            />
+          </SettingContainer>
+          <SettingContainer
+            description="The provider's speech model, such as whisper-1 for OpenAI or nova-3 for Deepgram."
+            descriptionMode={descriptionMode}
+            grouped={grouped}
+            title="Model"
+          >
+            <Input
+              className="max-w-xs"
+              defaultValue={models[providerId] ?? ""}
+              key={providerId}
+              onBlur={(e) =>
+                save("change_transcription_model_setting", {
+                  model: e.target.value.trim(),
+                })
+              }
+            />
+          </SettingContainer>
+        </>
+      )}
+    </>
+  );
+};
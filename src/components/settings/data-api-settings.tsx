@@ -0,0 +1,145 @@
+import { invoke } from "@tauri-apps/api/core";
+import { EyeOff, KeyRound, Server } from "lucide-react";
+import { useCallback, useEffect, useState } from "react";
+import { toast } from "sonner";
+import { Button } from "@/components/ui/button";
+import { SettingContainer } from "@/components/ui/setting-container";
+import { Switch } from "@/components/ui/switch";
+import { TextDisplay } from "@/components/ui/text-display";
+import type { DataApiField } from "@/lib/types";
+import {
+  useIsSettingUpdating,
+  useSetting,
+  useSettingsStore,
+} from "@/stores/settings-store";
+
+interface DataApiStatus {
+  running: boolean;
+  url: string | null;
+  token: string;
+}
+
+interface DataApiSettingsProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+const REDACTABLE_FIELDS: { field: DataApiField; label: string }[] = [
+  { field: "text", label: "Text" },
+  { field: "title", label: "Titles" },
+  { field: "prompt", label: "Prompts" },
+  { field: "app_name", label: "App names" },
+];
+
+export const DataApiSettings = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}: DataApiSettingsProps) => {
+  const enabled = useSetting("data_api_enabled") ?? false;
+  const redacted = useSetting("data_api_redacted_fields") ?? [];
+  const updating = useIsSettingUpdating("data_api_enabled");
+  const updateSetting = useSettingsStore((s) => s.updateSetting);
+  const [status, setStatus] = useState<DataApiStatus | null>(null);
+
+  const refreshStatus = useCallback(() => {
+    invoke<DataApiStatus>("get_data_api_status")
+      .then(setStatus)
+      .catch(() => setStatus(null));
+  }, []);
+
+  useEffect(() => {
+    if (!updating) {
+      refreshStatus();
+    }
+  }, [updating, refreshStatus]);
+
+  const regenerateToken = async () => {
+    try {
+      await invoke("regenerate_data_api_token");
+      refreshStatus();
+      toast.success("Token regenerated");
+    } catch {
+      toast.error("Failed to regenerate token");
+    }
+  };
+
+  const toggleField = (field: DataApiField, hide: boolean) => {
+    const fields = redacted.filter((f) => f !== field);
+    updateSetting(
+      "data_api_redacted_fields",
+      hide ? [...fields, field] : fields
+    );
+  };
+
+  return (
+    <>
+      <SettingContainer
+        description="Serve history and stats read-only on localhost for dashboards such as Obsidian. Requests must include the token."
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+        icon={<Server className="h-4 w-4" />}
+        title="Data API"
+        tooltipPosition="bottom"
+      >
+        <Switch
+          checked={enabled}
+          disabled={updating}
+          onCheckedChange={(value) => updateSetting("data_api_enabled", value)}
+        />
+      </SettingContainer>
+      {enabled && status && (
+        <>
+          <TextDisplay
+            copyable={true}
+            description="Base URL. Endpoints: /history and /stats."
+            descriptionMode={descriptionMode}
+            grouped={grouped}
+            label="Address"
+            monospace={true}
+            value={status.url ?? ""}
+          />
+          <SettingContainer
+            description="Send as 'Authorization: Bearer <token>'. Regenerating locks out existing clients."
+            descriptionMode={descriptionMode}
+            grouped={grouped}
+            icon={<KeyRound className="h-4 w-4" />}
+            title="Token"
+          >
+            <div className="flex items-center gap-2">
+              <Button
+                onClick={() => navigator.clipboard.writeText(status.token)}
+                size="sm"
+                variant="outline"
+              >
+                Copy
+              </Button>
+              <Button onClick={regenerateToken} size="sm" variant="ghost">
+                Regenerate
+              </Button>
+            </div>
+          </SettingContainer>
+          <SettingContainer
+            description="Fields the API never returns, whatever the client asks for."
+            descriptionMode={descriptionMode}
+            grouped={grouped}
+            icon={<EyeOff className="h-4 w-4" />}
+            layout="stacked"
+            title="Redacted Fields"
+          >
+            <div className="flex flex-wrap gap-4">
+              {REDACTABLE_FIELDS.map(({ field, label }) => (
+                <label className="flex items-center gap-2 text-sm" key={field}>
+                  <Switch
+                    checked={redacted.includes(field)}
+                    onCheckedChange={(hide) => toggleField(field, hide)}
+                  />
+                  {label}
+                </label>
+              ))}
+            </div>
+          </SettingContainer>
+        </>
+      )}
+    </>
+  );
+};
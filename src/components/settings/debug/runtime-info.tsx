@@ -0,0 +1,70 @@
+import { invoke } from "@tauri-apps/api/core";
+import type React from "react";
+import { useEffect, useState } from "react";
+import { SettingContainer } from "@/components/ui/setting-container";
+import type { AppRuntimeInfo } from "@/lib/types";
+
+interface RuntimeInfoProps {
+  descriptionMode?: "tooltip" | "inline";
+  grouped?: boolean;
+}
+
+const yesNo = (value: boolean) => (value ? "yes" : "no");
+
+export const RuntimeInfo: React.FC<RuntimeInfoProps> = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}) => {
+  const [info, setInfo] = useState<AppRuntimeInfo | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    invoke<AppRuntimeInfo>("get_app_runtime_info")
+      .then(setInfo)
+      .catch((err) => setError(String(err)));
+  }, []);
+
+  const rows: [string, string][] = info
+    ? [
+        ["App", `${info.app_version} (${info.os} ${info.arch})`],
+        [
+          "History database",
+          `schema ${info.history_schema_version ?? "unreadable"} of ${info.history_schema_target}`,
+        ],
+        [
+          "Input database",
+          info.input_schema_version === null
+            ? "not created"
+            : `version ${info.input_schema_version}`,
+        ],
+        ["Model catalog", `version ${info.model_catalog_version}`],
+        ["Settings", `version ${info.settings_version}`],
+        ["Wayland", yesNo(info.wayland)],
+        ["Accessibility permission", yesNo(info.accessibility_granted)],
+        ["FFmpeg", info.ffmpeg_found ? "found" : "not found"],
+      ]
+    : [];
+
+  return (
+    <SettingContainer
+      description="Versions and platform capabilities to include when reporting an issue"
+      descriptionMode={descriptionMode}
+      grouped={grouped}
+      layout="stacked"
+      title="Runtime Info"
+    >
+      {error ? (
+        <p className="text-red-600 text-sm">{error}</p>
+      ) : (
+        <div className="space-y-1 text-sm">
+          {rows.map(([label, value]) => (
+            <div className="flex justify-between gap-4" key={label}>
+              <span className="text-muted-foreground">{label}</span>
+              <span className="font-mono text-xs">{value}</span>
+            </div>
+          ))}
+        </div>
+      )}
+    </SettingContainer>
+  );
+};
@@ -0,0 +1,212 @@
+import { invoke } from "@tauri-apps/api/core";
+import { Languages, MessageSquareCode, PlusIcon, Trash2 } from "lucide-react";
+import { useState } from "react";
+import { toast } from "sonner";
+import { Button } from "@/components/ui/button";
+import { Input } from "@/components/ui/input";
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui/select";
+import { SettingContainer } from "@/components/ui/setting-container";
+import { Switch } from "@/components/ui/switch";
+import { LANGUAGES } from "@/lib/constants/languages";
+import type { DictationCommand, DictationCommandAction } from "@/lib/types";
+import { cn } from "@/lib/utils";
+import {
+  useIsSettingUpdating,
+  useSetting,
+  useSettingsStore,
+} from "@/stores/settings-store";
+
+interface DictationCommandsProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+type ActionType = DictationCommandAction["type"];
+
+const actionTypes: ActionType[] = ["insert", "delete_word", "delete_sentence"];
+
+const isActionType = (value: string): value is ActionType =>
+  actionTypes.some((type) => type === value);
+
+// Commands are looked up by the ISO 639-1 code of the transcript
+const commandLanguages = LANGUAGES.filter(
+  (language) => language.value !== "auto" && !language.value.includes("-")
+);
+
+// Line breaks are typed as \n
+const showText = (text: string) => text.replace(/\n/g, "\\n");
+const parseText = (text: string) => text.replace(/\\n/g, "\n");
+
+const describeAction = (action: DictationCommandAction) => {
+  switch (action.type) {
+    case "insert":
+      return `Insert "${showText(action.text)}"`;
+    case "delete_word":
+      return "Delete the last word";
+    default:
+      return "Delete the last sentence";
+  }
+};
+
+export const DictationCommands = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}: DictationCommandsProps) => {
+  const enabled = useSetting("dictation_commands_enabled") ?? false;
+  const allCommands = useSetting("dictation_commands") ?? {};
+  const updating = useIsSettingUpdating("dictation_commands_enabled");
+  const updateSetting = useSettingsStore((s) => s.updateSetting);
+  const refreshSettings = useSettingsStore((s) => s.refreshSettings);
+  const [language, setLanguage] = useState("en");
+  const [phrase, setPhrase] = useState("");
+  const [actionType, setActionType] = useState<ActionType>("insert");
+  const [text, setText] = useState("");
+
+  const commands = allCommands[language] ?? [];
+  const canAdd =
+    Boolean(phrase.trim()) && (actionType !== "insert" || Boolean(text));
+
+  const save = async (next: DictationCommand[]) => {
+    try {
+      await invoke("set_dictation_commands", { language, commands: next });
+      await refreshSettings();
+      return true;
+    } catch (error) {
+      toast.error(String(error));
+      return false;
+    }
+  };
+
+  const add = async () => {
+    const action: DictationCommandAction =
+      actionType === "insert"
+        ? { type: "insert", text: parseText(text) }
+        : { type: actionType };
+    if (await save([...commands, { phrase: phrase.trim(), action }])) {
+      setPhrase("");
+      setText("");
+    }
+  };
+
+  return (
+    <>
+      <SettingContainer
+        description='Say "comma", "new line" or "delete that" while dictating to insert punctuation and line breaks or remove what you just said.'
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+        icon={<MessageSquareCode className="h-4 w-4" />}
+        title="Dictation Commands"
+      >
+        <Switch
+          checked={enabled}
+          disabled={updating}
+          onCheckedChange={(value) =>
+            updateSetting("dictation_commands_enabled", value)
+          }
+        />
+      </SettingContainer>
+      <SettingContainer
+        description="Commands are matched in the language the dictation was spoken in."
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+        icon={<Languages className="h-4 w-4" />}
+        title="Command Language"
+      >
+        <Select onValueChange={setLanguage} value={language}>
+          <SelectTrigger>
+            <SelectValue />
+          </SelectTrigger>
+          <SelectContent>
+            {commandLanguages.map((option) => (
+              <SelectItem key={option.value} value={option.value}>
+                {option.label}
+              </SelectItem>
+            ))}
+          </SelectContent>
+        </Select>
+      </SettingContainer>
+
+      <div
+        className={cn(
+          "space-y-2 p-2 px-4",
+          !grouped && "rounded-lg border border-border/20"
+        )}
+      >
+        {commands.length === 0 && (
+          <div className="text-muted-foreground text-sm">
+            No commands for this language
+          </div>
+        )}
+        {commands.map((command, index) => (
+          <div
+            className="flex items-center justify-between gap-2 text-sm"
+            key={command.phrase}
+          >
+            <div className="min-w-0">
+              <div className="truncate font-medium">"{command.phrase}"</div>
+              <div className="truncate text-muted-foreground text-xs">
+                {describeAction(command.action)}
+              </div>
+            </div>
+            <Button
+              aria-label={`Delete "${command.phrase}"`}
+              onClick={() => save(commands.filter((_, i) => i !== index))}
+              size="icon"
+              variant="ghost"
+            >
+              <Trash2 className="h-4 w-4" />
+            </Button>
+          </div>
+        ))}
+
+        <div className="flex items-center gap-2 pt-2">
+          <Input
+            onChange={(e) => setPhrase(e.target.value)}
+            placeholder="Spoken phrase"
+            value={phrase}
+          />
+          <Select
+            onValueChange={(value) => {
+              if (isActionType(value)) {
+                setActionType(value);
+              }
+            }}
+            value={actionType}
+          >
+            <SelectTrigger className="w-44 shrink-0">
+              <SelectValue />
+            </SelectTrigger>
+            <SelectContent>
+              <SelectItem value="insert">Insert</SelectItem>
+              <SelectItem value="delete_word">Delete word</SelectItem>
+              <SelectItem value="delete_sentence">Delete sentence</SelectItem>
+            </SelectContent>
+          </Select>
+          {actionType === "insert" && (
+            <Input
+              className="w-24 shrink-0"
+              onChange={(e) => setText(e.target.value)}
+              placeholder="; or \n"
+              value={text}
+            />
+          )}
+          <Button
+            aria-label="Add command"
+            disabled={!canAdd}
+            onClick={add}
+            size="icon"
+            variant="outline"
+          >
+            <PlusIcon className="h-4 w-4" />
+          </Button>
+        </div>
+      </div>
+    </>
+  );
+};
@@ -0,0 +1,211 @@
+import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
+import {
+  LayoutTemplate,
+  Pencil,
+  Play,
+  PlusIcon,
+  Square,
+  Trash2,
+} from "lucide-react";
+import { useEffect, useState } from "react";
+import { toast } from "sonner";
+import { Button } from "@/components/ui/button";
+import { Input } from "@/components/ui/input";
+import { SettingContainer } from "@/components/ui/setting-container";
+import { Textarea } from "@/components/ui/textarea";
+import type { DictationTemplate, TemplateProgress } from "@/lib/types";
+import { cn } from "@/lib/utils";
+import { useSetting, useSettingsStore } from "@/stores/settings-store";
+
+interface DictationTemplatesProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+interface Draft {
+  id: string | null;
+  name: string;
+  template: string;
+}
+
+const EMPTY_DRAFT: Draft = { id: null, name: "", template: "" };
+
+export const DictationTemplates = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}: DictationTemplatesProps) => {
+  const templates = useSetting("dictation_templates") || [];
+  const refreshSettings = useSettingsStore((s) => s.refreshSettings);
+  const [progress, setProgress] = useState<TemplateProgress | null>(null);
+  const [draft, setDraft] = useState<Draft | null>(null);
+
+  useEffect(() => {
+    invoke<TemplateProgress | null>("get_dictation_template_progress")
+      .then(setProgress)
+      .catch((error) =>
+        console.error("Failed to load template progress:", error)
+      );
+    const unlisten = listen<TemplateProgress | null>(
+      "dictation-template-changed",
+      (event) => setProgress(event.payload)
+    );
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  const start = async (template: DictationTemplate) => {
+    try {
+      const started = await invoke<TemplateProgress>(
+        "start_dictation_template",
+        { id: template.id }
+      );
+      toast.success(`Dictate the ${started.slot} for "${template.name}"`);
+    } catch (error) {
+      toast.error(String(error));
+    }
+  };
+
+  const save = async () => {
+    if (!draft) {
+      return;
+    }
+    try {
+      await invoke("save_dictation_template", {
+        id: draft.id,
+        name: draft.name,
+        template: draft.template,
+      });
+      await refreshSettings();
+      setDraft(null);
+    } catch (error) {
+      toast.error(String(error));
+    }
+  };
+
+  const remove = async (id: string) => {
+    try {
+      await invoke("delete_dictation_template", { id });
+      await refreshSettings();
+    } catch (error) {
+      toast.error(String(error));
+    }
+  };
+
+  return (
+    <>
+      <SettingContainer
+        description="Fill a template with consecutive dictations. Each {{slot}} is filled by one dictation, in order, and the finished text is pasted once every slot has been spoken."
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+        icon={<LayoutTemplate className="h-4 w-4" />}
+        title="Dictation Templates"
+      >
+        <Button
+          onClick={() => setDraft(EMPTY_DRAFT)}
+          size="sm"
+          variant="outline"
+        >
+          <PlusIcon className="h-4 w-4" />
+          New template
+        </Button>
+      </SettingContainer>
+
+      <div
+        className={cn(
+          "space-y-2 p-2 px-4",
+          !grouped && "rounded-lg border border-border/20"
+        )}
+      >
+        {templates.map((template) => {
+          const active = progress?.template_id === template.id;
+          return (
+            <div
+              className="flex items-center justify-between gap-2 text-sm"
+              key={template.id}
+            >
+              <div className="min-w-0">
+                <div className="truncate font-medium">{template.name}</div>
+                <div className="truncate text-muted-foreground text-xs">
+                  {active && progress
+                    ? `Next: ${progress.slot} (${progress.index + 1}/${progress.total})`
+                    : template.template.replace(/\n/g, " ")}
+                </div>
+              </div>
+              <div className="flex shrink-0 gap-1">
+                {active ? (
+                  <Button
+                    aria-label="Stop template"
+                    onClick={() => invoke("cancel_dictation_template")}
+                    size="icon"
+                    variant="ghost"
+                  >
+                    <Square className="h-4 w-4" />
+                  </Button>
+                ) : (
+                  <Button
+                    aria-label={`Start ${template.name}`}
+                    onClick={() => start(template)}
+                    size="icon"
+                    variant="ghost"
+                  >
+                    <Play className="h-4 w-4" />
+                  </Button>
+                )}
+                <Button
+                  aria-label={`Edit ${template.name}`}
+                  onClick={() => setDraft(template)}
+                  size="icon"
+                  variant="ghost"
+                >
+                  <Pencil className="h-4 w-4" />
+                </Button>
+                <Button
+                  aria-label={`Delete ${template.name}`}
+                  onClick={() => remove(template.id)}
+                  size="icon"
+                  variant="ghost"
+                >
+                  <Trash2 className="h-4 w-4" />
+                </Button>
+              </div>
+            </div>
+          );
+        })}
+
+        {draft && (
+          <div className="space-y-2 pt-2">
+            <Input
+              onChange={(e) => setDraft({ ...draft, name: e.target.value })}
+              placeholder="Template name"
+              value={draft.name}
+            />
+            <Textarea
+              onChange={(e) =>
+                setDraft({ ...draft, template: e.target.value })
+              }
+              placeholder={"{{greeting}},\n\n{{body}}\n\n{{signature}}"}
+              rows={5}
+              value={draft.template}
+            />
+            <div className="flex justify-end gap-2">
+              <Button onClick={() => setDraft(null)} size="sm" variant="ghost">
+                Cancel
+              </Button>
+              <Button
+                disabled={
+                  !(draft.name.trim() && draft.template.includes("{{"))
+                }
+                onClick={save}
+                size="sm"
+              >
+                Save
+              </Button>
+            </div>
+          </div>
+        )}
+      </div>
+    </>
+  );
+};
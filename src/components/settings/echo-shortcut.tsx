@@ -32,13 +32,19 @@ interface WaylandShortcutInfo {
 }
 
 interface EchoShortcutProps {
+  bindingId?: string;
+  description?: string;
   descriptionMode?: "inline" | "tooltip";
   grouped?: boolean;
+  title?: string;
 }
 
 export const EchoShortcut: React.FC<EchoShortcutProps> = ({
+  bindingId = "transcribe",
+  description = "Set the keyboard shortcut to start and stop speech-to-text recording",
   descriptionMode = "tooltip",
   grouped = false,
+  title = "Echo Shortcut",
 }) => {
   const bindings = useSetting("bindings") ?? {};
   const isLoading = useSettingsStore((s) => s.isLoading);
@@ -367,16 +373,16 @@ export const EchoShortcut: React.FC<EchoShortcutProps> = ({
 
   return (
     <SettingContainer
-      description="Set the keyboard shortcut to start and stop speech-to-text recording"
+      description={description}
       descriptionMode={descriptionMode}
       grouped={grouped}
       icon={<Keyboard className="h-4 w-4" />}
-      title="Echo Shortcut"
+      title={title}
       tooltipPosition="bottom"
     >
       {(() => {
-        const primaryBinding = Object.values(bindings)[0];
-        const primaryId = Object.keys(bindings)[0];
+        const primaryBinding = bindings[bindingId];
+        const primaryId = bindingId;
 
         if (!(primaryBinding && primaryId)) {
           return (
@@ -0,0 +1,84 @@
+import { invoke } from "@tauri-apps/api/core";
+import { Headphones } from "lucide-react";
+import { toast } from "sonner";
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui/select";
+import { SettingContainer } from "@/components/ui/setting-container";
+import { useSetting, useSettingsStore } from "@/stores/settings-store";
+
+interface FeedbackSoundDevicesProps {
+  descriptionMode?: "inline" | "tooltip";
+  disabled?: boolean;
+  grouped?: boolean;
+}
+
+/** Select value meaning "no override, use the feedback output device". */
+const INHERIT = "default";
+
+const SOUNDS = [
+  { id: "start", label: "Start Sound Device" },
+  { id: "stop", label: "Stop Sound Device" },
+] as const;
+
+export const FeedbackSoundDevices = ({
+  descriptionMode = "tooltip",
+  disabled = false,
+  grouped = false,
+}: FeedbackSoundDevicesProps) => {
+  const soundDevices = useSetting("feedback_sound_devices") ?? {};
+  const outputDevices = useSettingsStore((s) => s.outputDevices);
+  const refreshSettings = useSettingsStore((s) => s.refreshSettings);
+  const playTestSound = useSettingsStore((s) => s.playTestSound);
+
+  const handleSelect = async (
+    soundType: "start" | "stop",
+    deviceName: string
+  ) => {
+    try {
+      await invoke("set_feedback_sound_device", { soundType, deviceName });
+      await refreshSettings();
+      playTestSound(soundType);
+    } catch (error) {
+      toast.error(String(error));
+    }
+  };
+
+  return (
+    <>
+      {SOUNDS.map((sound) => (
+        <SettingContainer
+          description="Play this sound on its own device, such as headphones, instead of the feedback output device"
+          descriptionMode={descriptionMode}
+          disabled={disabled}
+          grouped={grouped}
+          icon={<Headphones className="h-4 w-4" />}
+          key={sound.id}
+          title={sound.label}
+        >
+          <Select
+            disabled={disabled || outputDevices.length === 0}
+            onValueChange={(value) => handleSelect(sound.id, value)}
+            value={soundDevices[sound.id] ?? INHERIT}
+          >
+            <SelectTrigger className="flex-1">
+              <SelectValue placeholder="Same as output device" />
+            </SelectTrigger>
+            <SelectContent>
+              <SelectItem value={INHERIT}>Same as output device</SelectItem>
+              {outputDevices.map((device) => (
+                <SelectItem key={device.name} value={device.name}>
+                  {device.name === "Default" ? "System default" : device.name}
+                </SelectItem>
+              ))}
+            </SelectContent>
+          </Select>
+        </SettingContainer>
+      ))}
+    </>
+  );
+};
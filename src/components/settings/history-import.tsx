@@ -0,0 +1,106 @@
+import { invoke } from "@tauri-apps/api/core";
+import { FolderInput, FolderSync } from "lucide-react";
+import { useEffect, useState } from "react";
+import { toast } from "sonner";
+import { Button } from "@/components/ui/button";
+import { Input } from "@/components/ui/input";
+import { SettingContainer } from "@/components/ui/setting-container";
+import {
+  useIsSettingUpdating,
+  useSetting,
+  useSettingsStore,
+} from "@/stores/settings-store";
+
+interface HistoryImportProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const HistoryImport = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}: HistoryImportProps) => {
+  const watchFolder = useSetting("import_watch_folder") ?? "";
+  const updating = useIsSettingUpdating("import_watch_folder");
+  const updateSetting = useSettingsStore((s) => s.updateSetting);
+  const [folder, setFolder] = useState(watchFolder);
+  const [bundle, setBundle] = useState("");
+  const [importing, setImporting] = useState(false);
+
+  useEffect(() => {
+    setFolder(watchFolder);
+  }, [watchFolder]);
+
+  const saveWatchFolder = () => {
+    const value = folder.trim();
+    if (value !== watchFolder) {
+      updateSetting("import_watch_folder", value || null);
+    }
+  };
+
+  const importBundle = async () => {
+    const path = bundle.trim();
+    if (!path) {
+      return;
+    }
+    setImporting(true);
+    try {
+      const queued = await invoke<number>("import_history_bundle", { path });
+      toast.success(
+        `Queued ${queued} recording${queued === 1 ? "" : "s"} for import`
+      );
+      setBundle("");
+    } catch (error) {
+      toast.error(String(error));
+    } finally {
+      setImporting(false);
+    }
+  };
+
+  return (
+    <>
+      <SettingContainer
+        description="Recordings added to this folder are transcribed into history in the background, after any dictation. Handled files are moved to its Imported or Failed subfolder."
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+        icon={<FolderSync className="h-4 w-4" />}
+        title="Import Watch Folder"
+      >
+        <Input
+          className="w-full md:w-64"
+          disabled={updating}
+          onBlur={saveWatchFolder}
+          onChange={(e) => setFolder(e.target.value)}
+          placeholder="Folder path"
+          type="text"
+          value={folder}
+        />
+      </SettingContainer>
+      <SettingContainer
+        description="Transcribe every recording in a folder (and its subfolders) into history in the background."
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+        icon={<FolderInput className="h-4 w-4" />}
+        title="Import Recordings"
+      >
+        <div className="flex items-center gap-2">
+          <Input
+            className="w-full md:w-48"
+            onChange={(e) => setBundle(e.target.value)}
+            placeholder="Folder path"
+            type="text"
+            value={bundle}
+          />
+          <Button
+            disabled={importing || !bundle.trim()}
+            onClick={importBundle}
+            size="sm"
+            variant="outline"
+          >
+            Import
+          </Button>
+        </div>
+      </SettingContainer>
+    </>
+  );
+};
@@ -1,32 +1,110 @@
-import { Check, Copy, RefreshCw, RotateCcw, Star, Trash2 } from "lucide-react";
+import { invoke } from "@tauri-apps/api/core";
+import {
+  Check,
+  Copy,
+  Download,
+  Pencil,
+  RefreshCw,
+  RotateCcw,
+  Star,
+  Trash2,
+} from "lucide-react";
 import type React from "react";
-import { useEffect, useState } from "react";
+import { useCallback, useEffect, useState } from "react";
+import { toast } from "sonner";
+import { EXPORT_FORMATS } from "@/components/meeting/meeting-export";
 import { AudioPlayer } from "@/components/ui/audio-player";
 import { Button } from "@/components/ui/button";
 import { ButtonGroup } from "@/components/ui/button-group";
+import { Textarea } from "@/components/ui/textarea";
 import {
   Tooltip,
   TooltipContent,
   TooltipProvider,
   TooltipTrigger,
 } from "@/components/ui/tooltip";
+import type {
+  ExportFormat,
+  SpeakerSegment,
+  TimedSegment,
+} from "@/lib/types";
+
+export type RecordingMode =
+  | "push_to_talk"
+  | "toggle"
+  | "always_on"
+  | "file"
+  | "system_audio";
 
 export interface HistoryEntry {
   file_name: string;
   id: number;
+  language?: string | null;
+  previous_transcription_text?: string | null;
+  recording_mode?: RecordingMode | null;
+  sample_rate?: number | null;
   saved: boolean;
+  source_device?: string | null;
   timestamp: number;
+  timestamps?: TimedSegment[] | null;
   title: string;
   transcription_text: string;
 }
 
+export interface DiffSpan {
+  op: "equal" | "insert" | "delete";
+  text: string;
+}
+
+const DIFF_SPAN_CLASSES: Record<DiffSpan["op"], string> = {
+  equal: "",
+  insert: "text-green-600",
+  delete: "text-red-600 line-through",
+};
+
+/** Spans keyed by the word they start at, for rendering */
+const keyByWord = (spans: DiffSpan[]) => {
+  let word = 0;
+  return spans.map((span) => {
+    const key = `${span.op}-${word}`;
+    word += span.text.split(" ").length;
+    return { ...span, key };
+  });
+};
+
+const RECORDING_MODE_LABELS: Record<RecordingMode, string> = {
+  push_to_talk: "Push-to-talk",
+  toggle: "Toggle",
+  always_on: "Always-on",
+  file: "File",
+  system_audio: "System audio",
+};
+
+/** "Push-to-talk · MacBook Pro Microphone · 48 kHz · EN", or null for older entries. */
+const describeSource = (entry: HistoryEntry): string | null => {
+  const parts = [
+    entry.recording_mode ? RECORDING_MODE_LABELS[entry.recording_mode] : null,
+    entry.source_device,
+    entry.sample_rate ? `${entry.sample_rate / 1000} kHz` : null,
+    entry.language?.toUpperCase(),
+  ].filter(Boolean);
+  return parts.length > 0 ? parts.join(" · ") : null;
+};
+
+// Matches LOW_SEGMENT_CONFIDENCE in the backend
+const LOW_SEGMENT_CONFIDENCE = 0.5;
+
+const isLowConfidence = (segment: TimedSegment) =>
+  segment.confidence != null && segment.confidence < LOW_SEGMENT_CONFIDENCE;
+
 export interface HistoryEntryProps {
   deleteAudio: (id: number) => Promise<void>;
   entry: HistoryEntry;
   getAudioUrl: (fileName: string) => Promise<string | null>;
   onCopyText: () => void;
+  onEditText: (id: number, text: string) => Promise<void>;
   onReprocess: (id: number) => Promise<void>;
-  onRetranscribe: (id: number) => Promise<void>;
+  onRetranscribe: (id: number) => Promise<DiffSpan[]>;
   onToggleSaved: () => void;
 }
 
@@ -34,6 +112,7 @@ export const HistoryEntryComponent: React.FC<HistoryEntryProps> = ({
   entry,
   onToggleSaved,
   onCopyText,
+  onEditText,
   onRetranscribe,
   onReprocess,
   getAudioUrl,
@@ -44,6 +123,33 @@ export const HistoryEntryComponent: React.FC<HistoryEntryProps> = ({
   const [confirmDelete, setConfirmDelete] = useState(false);
   const [isRetranscribing, setIsRetranscribing] = useState(false);
   const [isReprocessing, setIsReprocessing] = useState(false);
+  const [speakerSegments, setSpeakerSegments] = useState<SpeakerSegment[]>(
+    []
+  );
+  const [exporting, setExporting] = useState(false);
+  const [draft, setDraft] = useState<string | null>(null);
+  const [isSavingEdit, setIsSavingEdit] = useState(false);
+  const [changes, setChanges] = useState<DiffSpan[] | null>(null);
+  const canExport =
+    speakerSegments.length > 0 || (entry.timestamps?.length ?? 0) > 0;
+  const source = describeSource(entry);
+  const hasFlaggedWords = entry.timestamps?.some(isLowConfidence) ?? false;
+
+  const loadSpeakerSegments = useCallback(async () => {
+    try {
+      const segments = await invoke<SpeakerSegment[]>(
+        "get_history_speaker_segments",
+        { historyId: entry.id }
+      );
+      setSpeakerSegments(segments);
+    } catch (error) {
+      console.error("Failed to load speaker segments:", error);
+    }
+  }, [entry.id]);
+
+  useEffect(() => {
+    loadSpeakerSegments();
+  }, [loadSpeakerSegments]);
 
   useEffect(() => {
     const loadAudio = async () => {
@@ -82,7 +188,14 @@ export const HistoryEntryComponent: React.FC<HistoryEntryProps> = ({
 
     setIsRetranscribing(true);
     try {
-      await onRetranscribe(entry.id);
+      const diff = await onRetranscribe(entry.id);
+      const changed = diff.some((span) => span.op !== "equal");
+      setChanges(changed ? diff : null);
+      if (!changed) {
+        toast.info("The new transcription is the same as before");
+      }
+      // Retranscribing replaces the speaker-labeled text
+      await loadSpeakerSegments();
     } catch (error) {
       console.error("Failed to retranscribe entry:", error);
     } finally {
@@ -105,10 +218,59 @@ export const HistoryEntryComponent: React.FC<HistoryEntryProps> = ({
     }
   };
 
+  const handleSaveEdit = async () => {
+    if (draft === null || isSavingEdit) {
+      return;
+    }
+    if (draft.trim() === entry.transcription_text) {
+      setDraft(null);
+      return;
+    }
+
+    setIsSavingEdit(true);
+    try {
+      await onEditText(entry.id, draft);
+      setDraft(null);
+    } catch (error) {
+      toast.error(String(error));
+    } finally {
+      setIsSavingEdit(false);
+    }
+  };
+
+  const handleExport = async (format: ExportFormat) => {
+    setExporting(true);
+    try {
+      const content = await invoke<string>("export_history_entry", {
+        id: entry.id,
+        format,
+      });
+      const ext = format === "markdown" ? "md" : format;
+      const blob = new Blob([content], { type: "text/plain" });
+      const url = URL.createObjectURL(blob);
+      const a = document.createElement("a");
+      a.href = url;
+      a.download = `${entry.title}.${ext}`;
+      document.body.appendChild(a);
+      a.click();
+      document.body.removeChild(a);
+      URL.revokeObjectURL(url);
+    } catch {
+      toast.error("Failed to export transcription");
+    } finally {
+      setExporting(false);
+    }
+  };
+
   return (
     <div className="flex flex-col gap-3 px-4 py-4">
       <div className="flex items-center justify-between">
-        <p className="font-medium text-sm">{entry.title}</p>
+        <div className="min-w-0">
+          <p className="font-medium text-sm">{entry.title}</p>
+          {source && (
+            <p className="truncate text-muted-foreground text-xs">{source}</p>
+          )}
+        </div>
         <TooltipProvider>
           <ButtonGroup>
             <Tooltip>
@@ -145,6 +307,19 @@ export const HistoryEntryComponent: React.FC<HistoryEntryProps> = ({
               </TooltipTrigger>
               <TooltipContent>Reprocess with AI & TTS</TooltipContent>
             </Tooltip>
+            <Tooltip>
+              <TooltipTrigger asChild>
+                <Button
+                  disabled={draft !== null || speakerSegments.length > 0}
+                  onClick={() => setDraft(entry.transcription_text)}
+                  size="icon-xs"
+                  variant="secondary"
+                >
+                  <Pencil height={16} width={16} />
+                </Button>
+              </TooltipTrigger>
+              <TooltipContent>Correct transcription</TooltipContent>
+            </Tooltip>
             <Tooltip>
               <TooltipTrigger asChild>
                 <Button
@@ -203,9 +378,99 @@ export const HistoryEntryComponent: React.FC<HistoryEntryProps> = ({
           </ButtonGroup>
         </TooltipProvider>
       </div>
-      <p className="pb-2 text-sm text-text/90 italic">
-        {entry.transcription_text}
-      </p>
+      {draft !== null ? (
+        <div className="flex flex-col gap-2 pb-2">
+          <Textarea
+            autoFocus
+            disabled={isSavingEdit}
+            onChange={(e) => setDraft(e.target.value)}
+            value={draft}
+          />
+          <div className="flex justify-end gap-1.5">
+            <Button
+              disabled={isSavingEdit}
+              onClick={() => setDraft(null)}
+              size="sm"
+              variant="ghost"
+            >
+              Cancel
+            </Button>
+            <Button
+              disabled={isSavingEdit || !draft.trim()}
+              onClick={handleSaveEdit}
+              size="sm"
+            >
+              Save
+            </Button>
+          </div>
+        </div>
+      ) : speakerSegments.length > 0 ? (
+        <div className="flex flex-col gap-1.5 pb-2 text-sm text-text/90">
+          {speakerSegments.map((segment) => (
+            <p key={segment.start_ms}>
+              <span className="font-medium">{segment.speaker_label}:</span>{" "}
+              {segment.text}
+            </p>
+          ))}
+        </div>
+      ) : hasFlaggedWords ? (
+        <p className="pb-2 text-sm text-text/90 italic">
+          {entry.timestamps?.map((segment, i) => (
+            <span key={segment.start_ms}>
+              {i > 0 && " "}
+              {isLowConfidence(segment) ? (
+                <span
+                  className="underline decoration-amber-500 decoration-wavy"
+                  title={`Probably misheard (${Math.round((segment.confidence ?? 0) * 100)}% sure)`}
+                >
+                  {segment.text}
+                </span>
+              ) : (
+                segment.text
+              )}
+            </span>
+          ))}
+        </p>
+      ) : (
+        <p className="pb-2 text-sm text-text/90 italic">
+          {entry.transcription_text}
+        </p>
+      )}
+      {changes && (
+        <div className="flex flex-col gap-1.5 rounded-md border border-border/20 p-2">
+          <div className="flex items-center justify-between">
+            <p className="text-muted-foreground text-xs">
+              Changed by retranscribing
+            </p>
+            <Button onClick={() => setChanges(null)} size="sm" variant="ghost">
+              Hide
+            </Button>
+          </div>
+          <p className="text-sm">
+            {keyByWord(changes).map((span) => (
+              <span className={DIFF_SPAN_CLASSES[span.op]} key={span.key}>
+                {span.text}{" "}
+              </span>
+            ))}
+          </p>
+        </div>
+      )}
+      {canExport && (
+        <div className="flex items-center gap-1.5">
+          {EXPORT_FORMATS.map((f) => (
+            <Button
+              disabled={exporting}
+              key={f.value}
+              onClick={() => handleExport(f.value)}
+              size="sm"
+              variant="outline"
+            >
+              <Download className="mr-1 size-3" />
+              {f.label}
+            </Button>
+          ))}
+        </div>
+      )}
       {audioUrl && <AudioPlayer className="w-full" src={audioUrl} />}
     </div>
   );
@@ -5,6 +5,7 @@ import { type ComponentProps, useCallback, useEffect, useState } from "react";
 import { Button } from "@/components/ui/button";
 import { cn } from "@/lib/utils";
 import {
+  type DiffSpan,
   type HistoryEntry,
   HistoryEntryComponent,
 } from "./history-entry-component";
@@ -90,13 +91,21 @@ export const HistorySettings = () => {
 
   const retranscribeEntry = async (id: number) => {
     try {
-      await invoke("retranscribe_history_entry", { id });
+      const result = await invoke<{ diff: DiffSpan[] }>(
+        "retranscribe_history_entry",
+        { id }
+      );
+      return result.diff;
     } catch (error) {
       console.error("Failed to retranscribe entry:", error);
       throw error;
     }
   };
 
+  const editEntryText = async (id: number, text: string) => {
+    await invoke("update_history_entry_text", { id, text });
+  };
+
   const reprocessEntry = async (id: number) => {
     try {
       await invoke("reprocess_history_entry", { id });
@@ -146,6 +155,7 @@ export const HistorySettings = () => {
             getAudioUrl={getAudioUrl}
             key={entry.id}
             onCopyText={() => copyToClipboard(entry.transcription_text)}
+            onEditText={editEntryText}
             onReprocess={reprocessEntry}
             onRetranscribe={retranscribeEntry}
             onToggleSaved={() => toggleSaved(entry.id)}
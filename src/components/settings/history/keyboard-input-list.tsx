@@ -1,7 +1,8 @@
 import { invoke } from "@tauri-apps/api/core";
 import { listen } from "@tauri-apps/api/event";
-import { Keyboard, Trash2 } from "lucide-react";
+import { Download, Keyboard, Trash2 } from "lucide-react";
 import { useCallback, useEffect, useState } from "react";
+import { toast } from "sonner";
 import { Button } from "@/components/ui/button";
 import { type InputEntry, KeyboardInputEntry } from "./keyboard-input-entry";
 
@@ -71,6 +72,27 @@ export const KeyboardInputList = () => {
     }
   };
 
+  // Anonymized exports keep apps and timing but mask every typed character
+  const handleExport = async (mode: "full" | "anonymized") => {
+    try {
+      const content = await invoke<string>("export_input_entries", { mode });
+      const blob = new Blob([content], { type: "application/json" });
+      const url = URL.createObjectURL(blob);
+      const a = document.createElement("a");
+      a.href = url;
+      a.download =
+        mode === "anonymized"
+          ? "keyboard-inputs-anonymized.json"
+          : "keyboard-inputs.json";
+      document.body.appendChild(a);
+      a.click();
+      document.body.removeChild(a);
+      URL.revokeObjectURL(url);
+    } catch {
+      toast.error("Failed to export keyboard inputs");
+    }
+  };
+
   if (loading) {
     return (
       <div className="px-4 py-3 text-center text-text/60">
@@ -99,14 +121,33 @@ export const KeyboardInputList = () => {
         <p className="text-text/60 text-xs">
           {entries.length} recorded {entries.length === 1 ? "input" : "inputs"}
         </p>
-        <Button
-          onClick={handleClearAll}
-          size="sm"
-          variant={confirmClearAll ? "ghostDestructive" : "ghost"}
-        >
-          <Trash2 className="mr-1 h-3 w-3" />
-          {confirmClearAll ? "Click to confirm" : "Clear All"}
-        </Button>
+        <div className="flex items-center gap-1">
+          <Button
+            onClick={() => handleExport("anonymized")}
+            size="sm"
+            title="Replace typed text with placeholders, keeping apps and timing"
+            variant="ghost"
+          >
+            <Download className="mr-1 h-3 w-3" />
+            Export Anonymized
+          </Button>
+          <Button
+            onClick={() => handleExport("full")}
+            size="sm"
+            variant="ghost"
+          >
+            <Download className="mr-1 h-3 w-3" />
+            Export
+          </Button>
+          <Button
+            onClick={handleClearAll}
+            size="sm"
+            variant={confirmClearAll ? "ghostDestructive" : "ghost"}
+          >
+            <Trash2 className="mr-1 h-3 w-3" />
+            {confirmClearAll ? "Click to confirm" : "Clear All"}
+          </Button>
+        </div>
       </div>
       <div className="divide-y divide-border/10">
         {entries.map((entry) => (
@@ -0,0 +1,127 @@
+import { invoke } from "@tauri-apps/api/core";
+import { Cpu } from "lucide-react";
+import { useEffect, useState } from "react";
+import { toast } from "sonner";
+import { Input } from "@/components/ui/input";
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui/select";
+import { SettingContainer } from "@/components/ui/setting-container";
+import { type InferenceBackend, InferenceBackendSchema } from "@/lib/types";
+import { useSetting, useSettingsStore } from "@/stores/settings-store";
+
+interface BackendInfo {
+  available: boolean;
+  backend: InferenceBackend;
+  detail: string | null;
+  name: string;
+}
+
+interface InferenceBackendSettingProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const InferenceBackendSetting = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}: InferenceBackendSettingProps) => {
+  const backend = useSetting("inference_backend") ?? "auto";
+  const threads = useSetting("inference_threads") ?? null;
+  const updateSetting = useSettingsStore((s) => s.updateSetting);
+  const [backends, setBackends] = useState<BackendInfo[]>([]);
+  const [threadInput, setThreadInput] = useState(threads?.toString() ?? "");
+
+  useEffect(() => {
+    invoke<BackendInfo[]>("get_inference_backends")
+      .then(setBackends)
+      .catch(() => setBackends([]));
+  }, []);
+
+  useEffect(() => {
+    setThreadInput(threads?.toString() ?? "");
+  }, [threads]);
+
+  const apply = async (
+    nextBackend: InferenceBackend,
+    nextThreads: number | null
+  ) => {
+    try {
+      await invoke("set_inference_backend", {
+        backend: nextBackend,
+        threads: nextThreads,
+      });
+      updateSetting("inference_backend", nextBackend);
+      updateSetting("inference_threads", nextThreads);
+    } catch (error) {
+      toast.error(String(error));
+    }
+  };
+
+  const commitThreads = () => {
+    const parsed = Number.parseInt(threadInput, 10);
+    const next = Number.isNaN(parsed) || parsed <= 0 ? null : parsed;
+    if (next !== threads) {
+      apply(backend, next);
+    }
+  };
+
+  return (
+    <>
+      <SettingContainer
+        description="Hardware local models run on. Automatic uses a GPU when one is usable. Switching away from a GPU that was already used takes effect after restarting Echo."
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+        icon={<Cpu className="h-4 w-4" />}
+        title="Inference Backend"
+      >
+        <Select
+          onValueChange={(value) => {
+            const parsed = InferenceBackendSchema.safeParse(value);
+            if (parsed.success) {
+              apply(parsed.data, threads);
+            }
+          }}
+          value={backend}
+        >
+          <SelectTrigger className="w-full md:w-56">
+            <SelectValue />
+          </SelectTrigger>
+          <SelectContent>
+            {backends.map((info) => (
+              <SelectItem
+                disabled={!info.available}
+                key={info.backend}
+                value={info.backend}
+              >
+                {info.detail ? `${info.name} (${info.detail})` : info.name}
+              </SelectItem>
+            ))}
+          </SelectContent>
+        </Select>
+      </SettingContainer>
+      {backend === "cpu" && (
+        <SettingContainer
+          description="Maximum number of CPU threads used for transcription. Leave empty to let the engine decide."
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+          title="CPU Threads"
+        >
+          <Input
+            className="w-24"
+            min={1}
+            onBlur={commitThreads}
+            onChange={(event) => setThreadInput(event.target.value)}
+            placeholder="Auto"
+            type="number"
+            value={threadInput}
+          />
+        </SettingContainer>
+      )}
+    </>
+  );
+};
@@ -0,0 +1,115 @@
+import {
+  AppWindow,
+  BookText,
+  MessageSquareQuote,
+  TextCursor,
+} from "lucide-react";
+import { useEffect, useState } from "react";
+import { SettingContainer } from "@/components/ui/setting-container";
+import { Switch } from "@/components/ui/switch";
+import { Textarea } from "@/components/ui/textarea";
+import {
+  useIsSettingUpdating,
+  useSetting,
+  useSettingsStore,
+} from "@/stores/settings-store";
+
+interface InitialPromptProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const InitialPrompt = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}: InitialPromptProps) => {
+  const prompt = useSetting("initial_prompt") ?? "";
+  const includeCustomWords = useSetting("initial_prompt_custom_words");
+  const includeActiveApp = useSetting("initial_prompt_active_app");
+  const includeFocusedText = useSetting("context_prompt_enabled");
+  const updatingPrompt = useIsSettingUpdating("initial_prompt");
+  const updatingCustomWords = useIsSettingUpdating(
+    "initial_prompt_custom_words"
+  );
+  const updatingActiveApp = useIsSettingUpdating("initial_prompt_active_app");
+  const updatingFocusedText = useIsSettingUpdating("context_prompt_enabled");
+  const updateSetting = useSettingsStore((s) => s.updateSetting);
+  const [draft, setDraft] = useState(prompt);
+
+  useEffect(() => {
+    setDraft(prompt);
+  }, [prompt]);
+
+  const savePrompt = () => {
+    if (draft.trim() !== prompt) {
+      updateSetting("initial_prompt", draft.trim());
+    }
+  };
+
+  return (
+    <>
+      <SettingContainer
+        description="Names, jargon or a sample sentence Whisper reads before each recording, so it spells them the way you do. Parakeet models ignore it."
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+        icon={<MessageSquareQuote className="h-4 w-4" />}
+        layout="stacked"
+        title="Whisper Prompt"
+      >
+        <Textarea
+          disabled={updatingPrompt}
+          onBlur={savePrompt}
+          onChange={(e) => setDraft(e.target.value)}
+          placeholder="Acme Corp, Kubernetes, Dr. Nguyen"
+          rows={3}
+          value={draft}
+        />
+      </SettingContainer>
+      <SettingContainer
+        description="Add your custom words to the prompt."
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+        icon={<BookText className="h-4 w-4" />}
+        title="Prompt With Custom Words"
+      >
+        <Switch
+          checked={includeCustomWords}
+          disabled={updatingCustomWords}
+          onCheckedChange={(value) =>
+            updateSetting("initial_prompt_custom_words", value)
+          }
+        />
+      </SettingContainer>
+      <SettingContainer
+        description="Tell Whisper which app you are dictating into."
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+        icon={<AppWindow className="h-4 w-4" />}
+        title="Prompt With Active App"
+      >
+        <Switch
+          checked={includeActiveApp}
+          disabled={updatingActiveApp}
+          onCheckedChange={(value) =>
+            updateSetting("initial_prompt_active_app", value)
+          }
+        />
+      </SettingContainer>
+      <SettingContainer
+        description="Add the text before the cursor in the focused field, so dictation continues in its style. macOS only."
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+        icon={<TextCursor className="h-4 w-4" />}
+        title="Prompt With Focused Text"
+      >
+        <Switch
+          checked={includeFocusedText}
+          disabled={updatingFocusedText}
+          onCheckedChange={(value) =>
+            updateSetting("context_prompt_enabled", value)
+          }
+        />
+      </SettingContainer>
+    </>
+  );
+};
@@ -0,0 +1,32 @@
+import { SlidersVertical } from "lucide-react";
+import { Slider } from "@/components/ui/slider";
+import { useSetting, useSettingsStore } from "@/stores/settings-store";
+
+interface InputGainProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const InputGain = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}: InputGainProps) => {
+  const gainDb = useSetting("input_gain_db") ?? 0;
+  const updateSetting = useSettingsStore((s) => s.updateSetting);
+
+  return (
+    <Slider
+      description="Boost a quiet microphone before it is transcribed and shown in the level meter. Applies from the next recording."
+      descriptionMode={descriptionMode}
+      formatValue={(v) => `${v > 0 ? "+" : ""}${v} dB`}
+      grouped={grouped}
+      icon={<SlidersVertical className="h-4 w-4" />}
+      label="Input Gain"
+      max={24}
+      min={-12}
+      onChange={(value) => updateSetting("input_gain_db", value)}
+      step={1}
+      value={gainDb}
+    />
+  );
+};
@@ -0,0 +1,40 @@
+import { GraduationCap } from "lucide-react";
+import { SettingContainer } from "@/components/ui/setting-container";
+import { Switch } from "@/components/ui/switch";
+import {
+  useIsSettingUpdating,
+  useSetting,
+  useSettingsStore,
+} from "@/stores/settings-store";
+
+interface LearnFromCorrectionsProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const LearnFromCorrections = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}: LearnFromCorrectionsProps) => {
+  const enabled = useSetting("learn_from_corrections");
+  const updating = useIsSettingUpdating("learn_from_corrections");
+  const updateSetting = useSettingsStore((s) => s.updateSetting);
+
+  return (
+    <SettingContainer
+      description="When you correct the same word in three dictations, in history or right after pasting with input tracking on, add it to your custom words."
+      descriptionMode={descriptionMode}
+      grouped={grouped}
+      icon={<GraduationCap className="h-4 w-4" />}
+      title="Learn From Corrections"
+    >
+      <Switch
+        checked={enabled}
+        disabled={updating}
+        onCheckedChange={(value) =>
+          updateSetting("learn_from_corrections", value)
+        }
+      />
+    </SettingContainer>
+  );
+};
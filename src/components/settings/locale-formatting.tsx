@@ -0,0 +1,65 @@
+import { invoke } from "@tauri-apps/api/core";
+import { Languages } from "lucide-react";
+import { useEffect, useState } from "react";
+import { toast } from "sonner";
+import { SettingContainer } from "@/components/ui/setting-container";
+import { Switch } from "@/components/ui/switch";
+import { useSetting, useSettingsStore } from "@/stores/settings-store";
+
+interface LanguageRules {
+  code: string;
+  description: string;
+  name: string;
+}
+
+interface LocaleFormattingProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const LocaleFormatting = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}: LocaleFormattingProps) => {
+  const enabled = useSetting("locale_formatting") ?? [];
+  const refreshSettings = useSettingsStore((s) => s.refreshSettings);
+  const [rules, setRules] = useState<LanguageRules[]>([]);
+
+  useEffect(() => {
+    invoke<LanguageRules[]>("get_locale_formatting_rules")
+      .then(setRules)
+      .catch(() => setRules([]));
+  }, []);
+
+  const toggle = async (language: string, value: boolean) => {
+    try {
+      await invoke("change_locale_formatting_setting", {
+        language,
+        enabled: value,
+      });
+      await refreshSettings();
+    } catch (error) {
+      toast.error(String(error));
+    }
+  };
+
+  return (
+    <>
+      {rules.map((language) => (
+        <SettingContainer
+          description={`${language.description}. Applied to transcripts in ${language.name}.`}
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+          icon={<Languages className="h-4 w-4" />}
+          key={language.code}
+          title={`${language.name} Typography`}
+        >
+          <Switch
+            checked={enabled.includes(language.code)}
+            onCheckedChange={(value) => toggle(language.code, value)}
+          />
+        </SettingContainer>
+      ))}
+    </>
+  );
+};
@@ -0,0 +1,58 @@
+import { ShieldAlert } from "lucide-react";
+import { SettingContainer } from "@/components/ui/setting-container";
+import { Slider } from "@/components/ui/slider";
+import { Switch } from "@/components/ui/switch";
+import {
+  useIsSettingUpdating,
+  useSetting,
+  useSettingsStore,
+} from "@/stores/settings-store";
+
+interface LowConfidenceReviewProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const LowConfidenceReview = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}: LowConfidenceReviewProps) => {
+  const enabled = useSetting("low_confidence_review_enabled");
+  const threshold = useSetting("low_confidence_threshold");
+  const updating = useIsSettingUpdating("low_confidence_review_enabled");
+  const updateSetting = useSettingsStore((s) => s.updateSetting);
+
+  return (
+    <>
+      <SettingContainer
+        description="When a transcription looks misheard (unusual speaking rate, repeated phrases, near-silent audio), hold it and let you paste it anyway, record again, or re-run it with a more accurate downloaded model."
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+        icon={<ShieldAlert className="h-4 w-4" />}
+        title="Review Low-Confidence Dictations"
+      >
+        <Switch
+          checked={enabled}
+          disabled={updating}
+          onCheckedChange={(value) =>
+            updateSetting("low_confidence_review_enabled", value)
+          }
+        />
+      </SettingContainer>
+      {enabled && (
+        <Slider
+          description="Dictations with an estimated confidence below this are held for review. Higher values hold more dictations."
+          descriptionMode={descriptionMode}
+          formatValue={(v) => `${Math.round(v * 100)}%`}
+          grouped={grouped}
+          label="Confidence Threshold"
+          max={1.0}
+          min={0.0}
+          onChange={(value) => updateSetting("low_confidence_threshold", value)}
+          step={0.05}
+          value={threshold ?? 0.5}
+        />
+      )}
+    </>
+  );
+};
@@ -0,0 +1,70 @@
+import { Hourglass } from "lucide-react";
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui/select";
+import { SettingContainer } from "@/components/ui/setting-container";
+import {
+  useIsSettingUpdating,
+  useSetting,
+  useSettingsStore,
+} from "@/stores/settings-store";
+
+interface MaxRecordingDurationProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+const NO_LIMIT = "0";
+
+const limitOptions = [
+  { value: "5", label: "5 minutes" },
+  { value: "10", label: "10 minutes" },
+  { value: "30", label: "30 minutes" },
+  { value: "60", label: "1 hour" },
+  { value: NO_LIMIT, label: "No limit" },
+];
+
+export const MaxRecordingDuration = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}: MaxRecordingDurationProps) => {
+  const minutes = useSetting("max_recording_minutes");
+  const updating = useIsSettingUpdating("max_recording_minutes");
+  const updateSetting = useSettingsStore((s) => s.updateSetting);
+
+  return (
+    <SettingContainer
+      description="Stop and transcribe a recording once it runs this long, so one left on by accident doesn't keep going for hours. The overlay warns 30 seconds before."
+      descriptionMode={descriptionMode}
+      grouped={grouped}
+      icon={<Hourglass className="h-4 w-4" />}
+      title="Recording Limit"
+    >
+      <Select
+        disabled={updating}
+        onValueChange={(value) =>
+          updateSetting(
+            "max_recording_minutes",
+            value === NO_LIMIT ? null : Number(value)
+          )
+        }
+        value={minutes ? String(minutes) : NO_LIMIT}
+      >
+        <SelectTrigger className="w-36">
+          <SelectValue />
+        </SelectTrigger>
+        <SelectContent>
+          {limitOptions.map((option) => (
+            <SelectItem key={option.value} value={option.value}>
+              {option.label}
+            </SelectItem>
+          ))}
+        </SelectContent>
+      </Select>
+    </SettingContainer>
+  );
+};
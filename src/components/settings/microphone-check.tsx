@@ -0,0 +1,127 @@
+import { invoke } from "@tauri-apps/api/core";
+import { Loader2, Medal } from "lucide-react";
+import { useState } from "react";
+import { toast } from "sonner";
+import { Button } from "@/components/ui/button";
+import { SettingContainer } from "@/components/ui/setting-container";
+import { cn } from "@/lib/utils";
+import {
+  useIsSettingUpdating,
+  useSetting,
+  useSettingsStore,
+} from "@/stores/settings-store";
+
+interface InputQuality {
+  clipping: number;
+  noise_floor_db: number;
+  score: number;
+  signal_db: number;
+  snr_db: number;
+}
+
+interface InputDeviceScore {
+  error: string | null;
+  is_default: boolean;
+  name: string;
+  quality: InputQuality | null;
+}
+
+interface MicrophoneCheckProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+const describeQuality = (quality: InputQuality) => {
+  const notes = [
+    `${Math.round(quality.snr_db)} dB above the noise`,
+    `peaks at ${Math.round(quality.signal_db)} dB`,
+  ];
+  if (quality.clipping > 0) {
+    notes.push("clipping");
+  }
+  return notes.join(" · ");
+};
+
+export const MicrophoneCheck = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}: MicrophoneCheckProps) => {
+  const selected = useSetting("selected_microphone") ?? "Default";
+  const updating = useIsSettingUpdating("selected_microphone");
+  const updateSetting = useSettingsStore((s) => s.updateSetting);
+  const [scores, setScores] = useState<InputDeviceScore[]>([]);
+  const [testing, setTesting] = useState(false);
+
+  const test = async () => {
+    setTesting(true);
+    try {
+      setScores(await invoke<InputDeviceScore[]>("score_input_devices"));
+    } catch (error) {
+      toast.error(String(error));
+    } finally {
+      setTesting(false);
+    }
+  };
+
+  const recommended = scores[0]?.quality ? scores[0].name : null;
+
+  return (
+    <>
+      <SettingContainer
+        description="Records a second from each microphone and ranks them by how clearly they pick you up over the room. Keep talking while it runs."
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+        icon={<Medal className="h-4 w-4" />}
+        title="Find the Best Microphone"
+      >
+        <Button disabled={testing} onClick={test} size="sm" variant="outline">
+          {testing && <Loader2 className="mr-1.5 size-3.5 animate-spin" />}
+          {testing ? "Listening..." : "Test microphones"}
+        </Button>
+      </SettingContainer>
+      {scores.length > 0 && (
+        <div
+          className={cn(
+            "space-y-2 p-2 px-4",
+            !grouped && "rounded-lg border border-border/20"
+          )}
+        >
+          {scores.map((device) => (
+            <div
+              className="flex items-center justify-between gap-2 text-sm"
+              key={device.name}
+            >
+              <div className="min-w-0">
+                <p className="truncate font-medium">
+                  {device.name}
+                  {device.name === recommended && (
+                    <span className="ml-2 text-muted-foreground text-xs">
+                      Recommended
+                    </span>
+                  )}
+                </p>
+                <p className="text-muted-foreground text-xs">
+                  {device.quality
+                    ? describeQuality(device.quality)
+                    : device.error}
+                </p>
+              </div>
+              <Button
+                disabled={
+                  !device.quality || updating || device.name === selected
+                }
+                onClick={() =>
+                  updateSetting("selected_microphone", device.name)
+                }
+                size="sm"
+                variant="ghost"
+              >
+                {device.name === selected ? "In use" : "Use"}
+              </Button>
+            </div>
+          ))}
+        </div>
+      )}
+    </>
+  );
+};
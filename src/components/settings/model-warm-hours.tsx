@@ -0,0 +1,88 @@
+import { invoke } from "@tauri-apps/api/core";
+import { Sunrise } from "lucide-react";
+import { toast } from "sonner";
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui/select";
+import { SettingContainer } from "@/components/ui/setting-container";
+import { Switch } from "@/components/ui/switch";
+import type { WarmHours } from "@/lib/types";
+import { useSetting, useSettingsStore } from "@/stores/settings-store";
+
+interface ModelWarmHoursProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+const DEFAULT_HOURS: WarmHours = { start_hour: 8, end_hour: 18 };
+
+const HOURS = Array.from({ length: 24 }, (_, hour) => hour);
+
+const formatHour = (hour: number) => `${String(hour).padStart(2, "0")}:00`;
+
+export const ModelWarmHours = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}: ModelWarmHoursProps) => {
+  const hours = useSetting("model_warm_hours") ?? null;
+  const refreshSettings = useSettingsStore((s) => s.refreshSettings);
+
+  const save = async (next: WarmHours | null) => {
+    try {
+      await invoke("set_model_warm_hours", { hours: next });
+      await refreshSettings();
+    } catch (error) {
+      toast.error(String(error));
+    }
+  };
+
+  const hourSelect = (value: number, onChange: (hour: number) => void) => (
+    <Select
+      onValueChange={(hour) => onChange(Number(hour))}
+      value={String(value)}
+    >
+      <SelectTrigger className="w-24">
+        <SelectValue />
+      </SelectTrigger>
+      <SelectContent>
+        {HOURS.map((hour) => (
+          <SelectItem key={hour} value={String(hour)}>
+            {formatHour(hour)}
+          </SelectItem>
+        ))}
+      </SelectContent>
+    </Select>
+  );
+
+  return (
+    <SettingContainer
+      description="Load the model ahead of time and keep it loaded during these hours, so the first dictation of the day doesn't wait for it"
+      descriptionMode={descriptionMode}
+      grouped={grouped}
+      icon={<Sunrise className="h-4 w-4" />}
+      title="Keep Model Warm"
+    >
+      <div className="flex items-center gap-2">
+        {hours && (
+          <>
+            {hourSelect(hours.start_hour, (start_hour) =>
+              save({ ...hours, start_hour })
+            )}
+            <span className="text-muted-foreground text-sm">to</span>
+            {hourSelect(hours.end_hour, (end_hour) =>
+              save({ ...hours, end_hour })
+            )}
+          </>
+        )}
+        <Switch
+          checked={hours !== null}
+          onCheckedChange={(enabled) => save(enabled ? DEFAULT_HOURS : null)}
+        />
+      </div>
+    </SettingContainer>
+  );
+};
@@ -0,0 +1,40 @@
+import { MicOff } from "lucide-react";
+import { SettingContainer } from "@/components/ui/setting-container";
+import { Switch } from "@/components/ui/switch";
+import {
+  useIsSettingUpdating,
+  useSetting,
+  useSettingsStore,
+} from "@/stores/settings-store";
+
+interface MuteWarningProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const MuteWarning = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}: MuteWarningProps) => {
+  const enabled = useSetting("mute_warning_enabled");
+  const updating = useIsSettingUpdating("mute_warning_enabled");
+  const updateSetting = useSettingsStore((s) => s.updateSetting);
+
+  return (
+    <SettingContainer
+      description="Stop a recording right away and show a warning when the microphone is muted, by the system or a headset mute button, instead of recording silence."
+      descriptionMode={descriptionMode}
+      grouped={grouped}
+      icon={<MicOff className="h-4 w-4" />}
+      title="Muted Microphone Warning"
+    >
+      <Switch
+        checked={enabled}
+        disabled={updating}
+        onCheckedChange={(value) =>
+          updateSetting("mute_warning_enabled", value)
+        }
+      />
+    </SettingContainer>
+  );
+};
@@ -0,0 +1,251 @@
+import { invoke } from "@tauri-apps/api/core";
+import { Loader2, Plus, ShieldBan, X } from "lucide-react";
+import { useState } from "react";
+import { Badge } from "@/components/ui/badge";
+import { Button } from "@/components/ui/button";
+import {
+  Command,
+  CommandEmpty,
+  CommandGroup,
+  CommandInput,
+  CommandItem,
+  CommandList,
+} from "@/components/ui/command";
+import { Input } from "@/components/ui/input";
+import {
+  Popover,
+  PopoverContent,
+  PopoverTrigger,
+} from "@/components/ui/popover";
+import { SettingContainer } from "@/components/ui/setting-container";
+import { cn } from "@/lib/utils";
+import {
+  useIsSettingUpdating,
+  useSetting,
+  useSettingsStore,
+} from "@/stores/settings-store";
+
+type InstalledApp = [string, string]; // [name, bundle_id]
+
+interface NoPasteTargetsProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+const isValidPattern = (pattern: string) => {
+  try {
+    new RegExp(pattern, "i");
+    return true;
+  } catch {
+    return false;
+  }
+};
+
+export const NoPasteTargets = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}: NoPasteTargetsProps) => {
+  const apps = useSetting("no_paste_apps") ?? [];
+  const patterns = useSetting("no_paste_window_patterns") ?? [];
+  const isUpdatingApps = useIsSettingUpdating("no_paste_apps");
+  const isUpdatingPatterns = useIsSettingUpdating("no_paste_window_patterns");
+  const updateSetting = useSettingsStore((s) => s.updateSetting);
+  const [installedApps, setInstalledApps] = useState<InstalledApp[]>([]);
+  const [open, setOpen] = useState(false);
+  const [loadingApps, setLoadingApps] = useState(false);
+  const [appsLoaded, setAppsLoaded] = useState(false);
+  const [pattern, setPattern] = useState("");
+
+  // Lazy load apps only when popover opens
+  const fetchApps = async () => {
+    if (appsLoaded || loadingApps) {
+      return;
+    }
+
+    setLoadingApps(true);
+    try {
+      setInstalledApps(await invoke<InstalledApp[]>("get_installed_apps"));
+      setAppsLoaded(true);
+    } catch (error) {
+      console.error("Failed to fetch installed apps:", error);
+    } finally {
+      setLoadingApps(false);
+    }
+  };
+
+  const handleOpenChange = (isOpen: boolean) => {
+    setOpen(isOpen);
+    if (isOpen && !appsLoaded) {
+      fetchApps();
+    }
+  };
+
+  const addApp = (bundleId: string) => {
+    if (!apps.includes(bundleId)) {
+      updateSetting("no_paste_apps", [...apps, bundleId]);
+    }
+    setOpen(false);
+  };
+
+  const addPattern = () => {
+    const trimmed = pattern.trim();
+    if (!trimmed || patterns.includes(trimmed)) {
+      return;
+    }
+    updateSetting("no_paste_window_patterns", [...patterns, trimmed]);
+    setPattern("");
+  };
+
+  const getAppName = (bundleId: string) => {
+    const app = installedApps.find(([, id]) => id === bundleId);
+    return app ? app[0] : bundleId;
+  };
+
+  const patternInvalid = pattern.trim() !== "" && !isValidPattern(pattern);
+
+  return (
+    <>
+      <SettingContainer
+        description="Apps and windows Echo never pastes into, like a terminal with a production SSH session. Dictations are copied to the clipboard instead, with a notification. Window patterns are case-insensitive regexes matched against the window title or URL."
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+        icon={<ShieldBan className="h-4 w-4" />}
+        layout="stacked"
+        title="Never Paste Into"
+      >
+        <div className="flex flex-wrap items-center gap-2">
+          <Input
+            aria-invalid={patternInvalid}
+            className="min-w-48 flex-1"
+            disabled={isUpdatingPatterns}
+            onChange={(e) => setPattern(e.target.value)}
+            onKeyDown={(e) => {
+              if (e.key === "Enter" && !patternInvalid) {
+                addPattern();
+              }
+            }}
+            placeholder="Window pattern, e.g. ssh .*prod"
+            value={pattern}
+          />
+          <Button
+            disabled={!pattern.trim() || patternInvalid || isUpdatingPatterns}
+            onClick={addPattern}
+            size="sm"
+            variant="outline"
+          >
+            Add Window
+          </Button>
+          <Popover onOpenChange={handleOpenChange} open={open}>
+            <PopoverTrigger asChild>
+              <Button disabled={isUpdatingApps} size="sm" variant="outline">
+                <Plus className="mr-1.5 h-4 w-4" />
+                Add App
+              </Button>
+            </PopoverTrigger>
+            <PopoverContent align="end" className="w-[300px] p-0">
+              <Command>
+                <CommandInput placeholder="Search applications..." />
+                <CommandList>
+                  {loadingApps ? (
+                    <div className="flex items-center justify-center py-6">
+                      <Loader2 className="h-4 w-4 animate-spin text-muted-foreground" />
+                      <span className="ml-2 text-muted-foreground text-sm">
+                        Loading apps...
+                      </span>
+                    </div>
+                  ) : (
+                    <>
+                      <CommandEmpty>No applications found.</CommandEmpty>
+                      <CommandGroup>
+                        {installedApps
+                          .filter(([, bundleId]) => !apps.includes(bundleId))
+                          .map(([name, bundleId]) => (
+                            <CommandItem
+                              key={bundleId}
+                              onSelect={() => addApp(bundleId)}
+                              value={`${name} ${bundleId}`}
+                            >
+                              <div className="flex flex-col">
+                                <span>{name}</span>
+                                <span className="text-muted-foreground text-xs">
+                                  {bundleId}
+                                </span>
+                              </div>
+                            </CommandItem>
+                          ))}
+                      </CommandGroup>
+                    </>
+                  )}
+                </CommandList>
+              </Command>
+            </PopoverContent>
+          </Popover>
+        </div>
+        {patternInvalid && (
+          <p className="mt-1 text-destructive text-xs">
+            Not a valid regular expression
+          </p>
+        )}
+      </SettingContainer>
+      {(apps.length > 0 || patterns.length > 0) && (
+        <div
+          className={cn(
+            "p-2 px-4",
+            !grouped && "rounded-lg border border-border/20"
+          )}
+        >
+          <div className="flex flex-wrap gap-1.5">
+            {apps.map((bundleId) => (
+              <Badge
+                className="flex items-center gap-1 pr-1"
+                key={`app:${bundleId}`}
+                variant="secondary"
+              >
+                <span className="max-w-[150px] truncate">
+                  {getAppName(bundleId)}
+                </span>
+                <button
+                  aria-label={`Remove ${getAppName(bundleId)}`}
+                  className="ml-0.5 rounded-full p-0.5 hover:bg-muted-foreground/20"
+                  disabled={isUpdatingApps}
+                  onClick={() =>
+                    updateSetting(
+                      "no_paste_apps",
+                      apps.filter((id) => id !== bundleId)
+                    )
+                  }
+                  type="button"
+                >
+                  <X className="h-3 w-3" />
+                </button>
+              </Badge>
+            ))}
+            {patterns.map((windowPattern) => (
+              <Badge
+                className="flex items-center gap-1 pr-1 font-mono"
+                key={`window:${windowPattern}`}
+                variant="outline"
+              >
+                <span className="max-w-[150px] truncate">{windowPattern}</span>
+                <button
+                  aria-label={`Remove ${windowPattern}`}
+                  className="ml-0.5 rounded-full p-0.5 hover:bg-muted-foreground/20"
+                  disabled={isUpdatingPatterns}
+                  onClick={() =>
+                    updateSetting(
+                      "no_paste_window_patterns",
+                      patterns.filter((p) => p !== windowPattern)
+                    )
+                  }
+                  type="button"
+                >
+                  <X className="h-3 w-3" />
+                </button>
+              </Badge>
+            ))}
+          </div>
+        </div>
+      )}
+    </>
+  );
+};
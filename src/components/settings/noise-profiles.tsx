@@ -0,0 +1,152 @@
+import { invoke } from "@tauri-apps/api/core";
+import { AudioWaveform, Mic } from "lucide-react";
+import { useCallback, useEffect, useState } from "react";
+import { toast } from "sonner";
+import { Button } from "@/components/ui/button";
+import { Input } from "@/components/ui/input";
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui/select";
+import { SettingContainer } from "@/components/ui/setting-container";
+import {
+  useIsSettingUpdating,
+  useSetting,
+  useSettingsStore,
+} from "@/stores/settings-store";
+
+interface NoiseProfileSummary {
+  calibrated_at: number;
+  name: string;
+  noise_level_db: number;
+  strength: number;
+}
+
+interface NoiseProfilesProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+const OFF = "none";
+
+export const NoiseProfiles = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}: NoiseProfilesProps) => {
+  const active = useSetting("active_noise_profile") ?? null;
+  const updating = useIsSettingUpdating("active_noise_profile");
+  const updateSetting = useSettingsStore((s) => s.updateSetting);
+  const refreshSettings = useSettingsStore((s) => s.refreshSettings);
+  const [profiles, setProfiles] = useState<NoiseProfileSummary[]>([]);
+  const [name, setName] = useState("");
+  const [calibrating, setCalibrating] = useState(false);
+
+  const refreshProfiles = useCallback(() => {
+    invoke<NoiseProfileSummary[]>("get_noise_profiles")
+      .then(setProfiles)
+      .catch(() => setProfiles([]));
+  }, []);
+
+  useEffect(() => {
+    refreshProfiles();
+  }, [refreshProfiles]);
+
+  const calibrate = async () => {
+    setCalibrating(true);
+    try {
+      const profile = await invoke<NoiseProfileSummary>(
+        "calibrate_noise_profile",
+        { name: name.trim() }
+      );
+      toast.success(
+        `Calibrated "${profile.name}" at ${Math.round(profile.noise_level_db)} dB`
+      );
+      setName("");
+      refreshProfiles();
+      await refreshSettings();
+    } catch (error) {
+      toast.error(String(error));
+    } finally {
+      setCalibrating(false);
+    }
+  };
+
+  const deleteActive = async () => {
+    if (!active) {
+      return;
+    }
+    try {
+      await invoke("delete_noise_profile", { name: active });
+      refreshProfiles();
+      await refreshSettings();
+    } catch (error) {
+      toast.error(String(error));
+    }
+  };
+
+  return (
+    <>
+      <SettingContainer
+        description="Subtract the background noise of the environment you're in before transcribing. Louder environments get stronger suppression."
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+        icon={<AudioWaveform className="h-4 w-4" />}
+        title="Noise Suppression"
+      >
+        <div className="flex items-center gap-2">
+          <Select
+            disabled={updating || profiles.length === 0}
+            onValueChange={(value) =>
+              updateSetting("active_noise_profile", value === OFF ? null : value)
+            }
+            value={active ?? OFF}
+          >
+            <SelectTrigger>
+              <SelectValue />
+            </SelectTrigger>
+            <SelectContent>
+              <SelectItem value={OFF}>Off</SelectItem>
+              {profiles.map((profile) => (
+                <SelectItem key={profile.name} value={profile.name}>
+                  {profile.name}
+                </SelectItem>
+              ))}
+            </SelectContent>
+          </Select>
+          {active && (
+            <Button onClick={deleteActive} size="sm" variant="ghost">
+              Remove
+            </Button>
+          )}
+        </div>
+      </SettingContainer>
+      <SettingContainer
+        description="Stay quiet for five seconds while the microphone listens to the room. Calibrating an existing name replaces it."
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+        icon={<Mic className="h-4 w-4" />}
+        title="Calibrate Environment"
+      >
+        <div className="flex items-center gap-2">
+          <Input
+            disabled={calibrating}
+            onChange={(event) => setName(event.target.value)}
+            placeholder="Office, home, café..."
+            value={name}
+          />
+          <Button
+            disabled={calibrating || name.trim() === ""}
+            onClick={calibrate}
+            size="sm"
+            variant="outline"
+          >
+            {calibrating ? "Listening..." : "Calibrate"}
+          </Button>
+        </div>
+      </SettingContainer>
+    </>
+  );
+};
@@ -0,0 +1,40 @@
+import { Fan } from "lucide-react";
+import { SettingContainer } from "@/components/ui/setting-container";
+import { Switch } from "@/components/ui/switch";
+import {
+  useIsSettingUpdating,
+  useSetting,
+  useSettingsStore,
+} from "@/stores/settings-store";
+
+interface NoiseSuppressionProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const NoiseSuppression = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}: NoiseSuppressionProps) => {
+  const enabled = useSetting("noise_suppression_enabled");
+  const updating = useIsSettingUpdating("noise_suppression_enabled");
+  const updateSetting = useSettingsStore((s) => s.updateSetting);
+
+  return (
+    <SettingContainer
+      description="Clean up steady background noise like fans and keyboards while recording, without calibrating a noise profile. Applies from the next recording."
+      descriptionMode={descriptionMode}
+      grouped={grouped}
+      icon={<Fan className="h-4 w-4" />}
+      title="Noise Suppression"
+    >
+      <Switch
+        checked={enabled}
+        disabled={updating}
+        onCheckedChange={(value) =>
+          updateSetting("noise_suppression_enabled", value)
+        }
+      />
+    </SettingContainer>
+  );
+};
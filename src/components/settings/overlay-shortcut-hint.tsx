@@ -0,0 +1,40 @@
+import { Keyboard } from "lucide-react";
+import { SettingContainer } from "@/components/ui/setting-container";
+import { Switch } from "@/components/ui/switch";
+import {
+  useIsSettingUpdating,
+  useSetting,
+  useSettingsStore,
+} from "@/stores/settings-store";
+
+interface OverlayShortcutHintProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const OverlayShortcutHint = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}: OverlayShortcutHintProps) => {
+  const enabled = useSetting("overlay_shortcut_hint") ?? true;
+  const updating = useIsSettingUpdating("overlay_shortcut_hint");
+  const updateSetting = useSettingsStore((s) => s.updateSetting);
+
+  return (
+    <SettingContainer
+      description='Show how to stop the recording in the overlay, like "Release ⌥Space to stop".'
+      descriptionMode={descriptionMode}
+      grouped={grouped}
+      icon={<Keyboard className="h-4 w-4" />}
+      title="Shortcut Hint"
+    >
+      <Switch
+        checked={enabled}
+        disabled={updating}
+        onCheckedChange={(value) =>
+          updateSetting("overlay_shortcut_hint", value)
+        }
+      />
+    </SettingContainer>
+  );
+};
@@ -1,4 +1,5 @@
 import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
 import {
   AlertTriangle,
   AppWindow,
@@ -20,6 +21,7 @@ import { BaseUrlField } from "@/components/settings/post-processing-settings-api
 import { ModelSelect } from "@/components/settings/post-processing-settings-api/model-select";
 import { ProviderSelect } from "@/components/settings/post-processing-settings-api/provider-select";
 import { usePostProcessProviderState } from "@/components/settings/post-processing-settings-api/use-post-process-provider-state";
+import { TextPlugins } from "@/components/settings/text-plugins";
 import { Badge } from "@/components/ui/badge";
 import { Button } from "@/components/ui/button";
 import { Input } from "@/components/ui/input";
@@ -156,6 +158,29 @@ const VoiceCommandsToggle = () => {
   );
 };
 
+const VoicePromptSwitchingToggle = () => {
+  const enabled = useSetting("voice_prompt_switching_enabled") ?? false;
+  const isUpdating = useIsSettingUpdating("voice_prompt_switching_enabled");
+  const updateSetting = useSettingsStore((s) => s.updateSetting);
+
+  return (
+    <SettingContainer
+      description={`Start a dictation with "echo" and a prompt name (e.g. "echo, formal mode") to switch to that prompt. The rest of the dictation is processed with it.`}
+      descriptionMode="tooltip"
+      grouped={true}
+      title="Switch Prompts by Voice"
+    >
+      <Switch
+        checked={enabled}
+        disabled={isUpdating}
+        onCheckedChange={(value) =>
+          updateSetting("voice_prompt_switching_enabled", value)
+        }
+      />
+    </SettingContainer>
+  );
+};
+
 const ToolCallingSection = ({
   toolSupport,
 }: {
@@ -260,7 +285,7 @@ const PostProcessingEnableToggle = () => {
 
   return (
     <SettingContainer
-      description="Enable LLM post-processing to refine transcriptions using custom prompts."
+      description="Enable LLM post-processing to refine transcriptions using custom prompts. Press Shift+Esc while it runs to paste the raw transcription instead."
       descriptionMode="tooltip"
       grouped={true}
       title="Enable Post Processing"
@@ -478,6 +503,16 @@ const PostProcessingSettingsPromptsComponent = () => {
   const selectedPrompt =
     prompts.find((prompt) => prompt.id === selectedPromptId) || null;
 
+  // Prompts can also be switched by voice from the transcription pipeline
+  useEffect(() => {
+    const unlisten = listen("post-process-prompt-changed", () => {
+      refreshSettings();
+    });
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, [refreshSettings]);
+
   useEffect(() => {
     if (isCreating) {
       return;
@@ -908,6 +943,11 @@ export const PostProcessingSettings = () => (
 
     <SettingsGroup title="Prompt">
       <PostProcessingSettingsPrompts />
+      <VoicePromptSwitchingToggle />
+    </SettingsGroup>
+
+    <SettingsGroup title="Plugins">
+      <TextPlugins descriptionMode="tooltip" grouped={true} />
     </SettingsGroup>
   </div>
 );
@@ -0,0 +1,37 @@
+import { SettingContainer } from "@/components/ui/setting-container";
+import { Switch } from "@/components/ui/switch";
+import {
+  useIsSettingUpdating,
+  useSetting,
+  useSettingsStore,
+} from "@/stores/settings-store";
+
+interface PreRollProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const PreRoll = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}: PreRollProps) => {
+  const enabled = useSetting("pre_roll_enabled");
+  const alwaysOnMode = useSetting("always_on_microphone");
+  const updating = useIsSettingUpdating("pre_roll_enabled");
+  const updateSetting = useSettingsStore((s) => s.updateSetting);
+
+  return (
+    <SettingContainer
+      description="Start each recording with the couple of seconds heard before the shortcut, so the first words aren't cut off. Needs the always-on microphone."
+      descriptionMode={descriptionMode}
+      grouped={grouped}
+      title="Pre-Roll"
+    >
+      <Switch
+        checked={enabled}
+        disabled={!alwaysOnMode || updating}
+        onCheckedChange={(value) => updateSetting("pre_roll_enabled", value)}
+      />
+    </SettingContainer>
+  );
+};
@@ -0,0 +1,74 @@
+import { invoke } from "@tauri-apps/api/core";
+import { Gauge } from "lucide-react";
+import { useEffect, useState } from "react";
+import { toast } from "sonner";
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui/select";
+import { SettingContainer } from "@/components/ui/setting-container";
+import type { ModelInfo } from "@/lib/types";
+import { useSetting, useSettingsStore } from "@/stores/settings-store";
+
+interface PreviewModelProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+const SAME_MODEL = "same";
+
+export const PreviewModel = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}: PreviewModelProps) => {
+  const previewModel = useSetting("preview_model_id") ?? null;
+  const selectedModel = useSetting("selected_model");
+  const refreshSettings = useSettingsStore((s) => s.refreshSettings);
+  const [models, setModels] = useState<ModelInfo[]>([]);
+
+  useEffect(() => {
+    invoke<ModelInfo[]>("get_available_models")
+      .then((list) => setModels(list.filter((model) => model.is_downloaded)))
+      .catch(() => setModels([]));
+  }, []);
+
+  const choose = async (value: string) => {
+    try {
+      await invoke("set_preview_model", {
+        modelId: value === SAME_MODEL ? null : value,
+      });
+      await refreshSettings();
+    } catch (error) {
+      toast.error(String(error));
+    }
+  };
+
+  return (
+    <SettingContainer
+      description="Run the live preview while you dictate on a smaller, faster model. The final transcription still uses the selected model."
+      descriptionMode={descriptionMode}
+      grouped={grouped}
+      icon={<Gauge className="h-4 w-4" />}
+      title="Preview Model"
+    >
+      <Select onValueChange={choose} value={previewModel ?? SAME_MODEL}>
+        <SelectTrigger>
+          <SelectValue />
+        </SelectTrigger>
+        <SelectContent>
+          <SelectItem value={SAME_MODEL}>Same as selected model</SelectItem>
+          {models
+            .filter((model) => model.id !== selectedModel)
+            .map((model) => (
+              <SelectItem key={model.id} value={model.id}>
+                {model.name}
+              </SelectItem>
+            ))}
+        </SelectContent>
+      </Select>
+    </SettingContainer>
+  );
+};
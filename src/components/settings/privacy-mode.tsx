@@ -0,0 +1,38 @@
+import { EyeOff } from "lucide-react";
+import { SettingContainer } from "@/components/ui/setting-container";
+import { Switch } from "@/components/ui/switch";
+import {
+  useIsSettingUpdating,
+  useSetting,
+  useSettingsStore,
+} from "@/stores/settings-store";
+
+interface PrivacyModeProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const PrivacyMode = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}: PrivacyModeProps) => {
+  const enabled = useSetting("privacy_mode");
+  const updating = useIsSettingUpdating("privacy_mode");
+  const updateSetting = useSettingsStore((s) => s.updateSetting);
+
+  return (
+    <SettingContainer
+      description="Dictations are pasted but not kept in history, and input tracking is paused. Automation rules can turn this on outside working hours."
+      descriptionMode={descriptionMode}
+      grouped={grouped}
+      icon={<EyeOff className="h-4 w-4" />}
+      title="Privacy Mode"
+    >
+      <Switch
+        checked={enabled}
+        disabled={updating}
+        onCheckedChange={(value) => updateSetting("privacy_mode", value)}
+      />
+    </SettingContainer>
+  );
+};
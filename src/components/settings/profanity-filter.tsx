@@ -0,0 +1,149 @@
+import { PlusIcon, ShieldAlert, XIcon } from "lucide-react";
+import type React from "react";
+import { useState } from "react";
+import { Button } from "@/components/ui/button";
+import { ButtonGroup } from "@/components/ui/button-group";
+import { Input } from "@/components/ui/input";
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui/select";
+import { SettingContainer } from "@/components/ui/setting-container";
+import {
+  type ProfanityFilter as ProfanityFilterMode,
+  ProfanityFilterSchema,
+} from "@/lib/types";
+import { cn } from "@/lib/utils";
+import {
+  useIsSettingUpdating,
+  useSetting,
+  useSettingsStore,
+} from "@/stores/settings-store";
+
+interface ProfanityFilterProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+const modeOptions: { value: ProfanityFilterMode; label: string }[] = [
+  { value: "keep", label: "Keep" },
+  { value: "mask", label: "Mask (f***)" },
+  { value: "remove", label: "Remove" },
+];
+
+export const ProfanityFilter = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}: ProfanityFilterProps) => {
+  const mode = useSetting("profanity_filter") ?? "keep";
+  const words = useSetting("profanity_words") ?? [];
+  const updatingMode = useIsSettingUpdating("profanity_filter");
+  const updatingWords = useIsSettingUpdating("profanity_words");
+  const updateSetting = useSettingsStore((s) => s.updateSetting);
+  const [newWord, setNewWord] = useState("");
+
+  const handleAddWord = () => {
+    const word = newWord.trim().toLowerCase();
+    if (word && !word.includes(" ") && !words.includes(word)) {
+      updateSetting("profanity_words", [...words, word]);
+      setNewWord("");
+    }
+  };
+
+  const handleKeyPress = (e: React.KeyboardEvent) => {
+    if (e.key === "Enter") {
+      e.preventDefault();
+      handleAddWord();
+    }
+  };
+
+  return (
+    <>
+      <SettingContainer
+        description="Mask or remove blocklisted words before the text is pasted and saved to history. End a word with * to catch every word starting with it."
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+        icon={<ShieldAlert className="h-4 w-4" />}
+        title="Profanity Filter"
+      >
+        <Select
+          disabled={updatingMode}
+          onValueChange={(value) => {
+            const parsed = ProfanityFilterSchema.safeParse(value);
+            if (parsed.success) {
+              updateSetting("profanity_filter", parsed.data);
+            }
+          }}
+          value={mode}
+        >
+          <SelectTrigger className="w-full md:w-56">
+            <SelectValue />
+          </SelectTrigger>
+          <SelectContent>
+            {modeOptions.map((option) => (
+              <SelectItem key={option.value} value={option.value}>
+                {option.label}
+              </SelectItem>
+            ))}
+          </SelectContent>
+        </Select>
+      </SettingContainer>
+      {mode !== "keep" && (
+        <div
+          className={cn(
+            "space-y-2 p-2 px-4",
+            !grouped && "rounded-lg border border-border/20"
+          )}
+        >
+          <ButtonGroup className="w-full">
+            <Input
+              className="min-w-0"
+              disabled={updatingWords}
+              onChange={(e) => setNewWord(e.target.value)}
+              onKeyDown={handleKeyPress}
+              placeholder="Add a word"
+              type="text"
+              value={newWord}
+              variant="button"
+            />
+            <Button
+              aria-label="Add word"
+              disabled={
+                !newWord.trim() || newWord.includes(" ") || updatingWords
+              }
+              onClick={handleAddWord}
+              size="icon"
+              variant="default"
+            >
+              <PlusIcon className="h-4 w-4" />
+            </Button>
+          </ButtonGroup>
+          <ButtonGroup className="w-full flex-wrap gap-1">
+            {words.map((word) => (
+              <Button
+                aria-label={`Remove ${word}`}
+                className="gap-1 text-muted-foreground hover:text-foreground"
+                disabled={updatingWords}
+                key={word}
+                onClick={() =>
+                  updateSetting(
+                    "profanity_words",
+                    words.filter((w) => w !== word)
+                  )
+                }
+                size="xs"
+                variant="ghost"
+              >
+                <span>{word}</span>
+                <XIcon className="h-3 w-3" />
+              </Button>
+            ))}
+          </ButtonGroup>
+        </div>
+      )}
+    </>
+  );
+};
@@ -0,0 +1,105 @@
+import { invoke } from "@tauri-apps/api/core";
+import { Plus, UserRound } from "lucide-react";
+import { useEffect, useState } from "react";
+import { toast } from "sonner";
+import { Button } from "@/components/ui/button";
+import { Input } from "@/components/ui/input";
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui/select";
+import { SettingContainer } from "@/components/ui/setting-container";
+
+interface ProfileSelectorProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+/** Profile names become directory names on disk */
+const PROFILE_NAME_PATTERN = /^[A-Za-z0-9_-]{1,64}$/;
+
+export const ProfileSelector = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}: ProfileSelectorProps) => {
+  const [profiles, setProfiles] = useState<string[]>([]);
+  const [active, setActive] = useState("default");
+  const [newName, setNewName] = useState("");
+  const [switching, setSwitching] = useState(false);
+
+  useEffect(() => {
+    invoke<string[]>("list_profiles")
+      .then(setProfiles)
+      .catch(() => setProfiles(["default"]));
+    invoke<string>("get_active_profile")
+      .then(setActive)
+      .catch(() => setActive("default"));
+  }, []);
+
+  const switchProfile = async (name: string) => {
+    if (name === active) {
+      return;
+    }
+    setSwitching(true);
+    try {
+      // The app restarts into the new profile
+      await invoke("switch_profile", { name });
+    } catch (error) {
+      toast.error(`Failed to switch profile: ${error}`);
+      setSwitching(false);
+    }
+  };
+
+  const trimmedName = newName.trim();
+  const canCreate =
+    PROFILE_NAME_PATTERN.test(trimmedName) && !profiles.includes(trimmedName);
+
+  return (
+    <SettingContainer
+      description="Each profile keeps its own settings, history and recordings. Models are shared. Switching restarts Echo. You can also launch Echo with --profile <name>."
+      descriptionMode={descriptionMode}
+      grouped={grouped}
+      icon={<UserRound className="h-4 w-4" />}
+      layout="stacked"
+      title="Profile"
+    >
+      <div className="flex items-center gap-2">
+        <Select
+          disabled={switching}
+          onValueChange={switchProfile}
+          value={active}
+        >
+          <SelectTrigger className="w-40">
+            <SelectValue />
+          </SelectTrigger>
+          <SelectContent>
+            {profiles.map((name) => (
+              <SelectItem key={name} value={name}>
+                {name}
+              </SelectItem>
+            ))}
+          </SelectContent>
+        </Select>
+        <Input
+          className="w-40"
+          disabled={switching}
+          onChange={(e) => setNewName(e.target.value)}
+          placeholder="New profile"
+          value={newName}
+        />
+        <Button
+          disabled={!canCreate || switching}
+          onClick={() => switchProfile(trimmedName)}
+          size="sm"
+          variant="outline"
+        >
+          <Plus className="mr-1.5 size-3.5" />
+          Create
+        </Button>
+      </div>
+    </SettingContainer>
+  );
+};
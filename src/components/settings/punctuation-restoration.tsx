@@ -0,0 +1,40 @@
+import { Pilcrow } from "lucide-react";
+import { SettingContainer } from "@/components/ui/setting-container";
+import { Switch } from "@/components/ui/switch";
+import {
+  useIsSettingUpdating,
+  useSetting,
+  useSettingsStore,
+} from "@/stores/settings-store";
+
+interface PunctuationRestorationProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const PunctuationRestoration = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}: PunctuationRestorationProps) => {
+  const enabled = useSetting("punctuation_restoration");
+  const updating = useIsSettingUpdating("punctuation_restoration");
+  const updateSetting = useSettingsStore((s) => s.updateSetting);
+
+  return (
+    <SettingContainer
+      description="Parakeet models don't punctuate. Split their text into sentences where you pause, with capitals and a closing period or question mark."
+      descriptionMode={descriptionMode}
+      grouped={grouped}
+      icon={<Pilcrow className="h-4 w-4" />}
+      title="Restore Punctuation"
+    >
+      <Switch
+        checked={enabled}
+        disabled={updating}
+        onCheckedChange={(value) =>
+          updateSetting("punctuation_restoration", value)
+        }
+      />
+    </SettingContainer>
+  );
+};
@@ -0,0 +1,66 @@
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui/select";
+import { SettingContainer } from "@/components/ui/setting-container";
+import { type RecordingFormat, RecordingFormatSchema } from "@/lib/types";
+import {
+  useIsSettingUpdating,
+  useSetting,
+  useSettingsStore,
+} from "@/stores/settings-store";
+
+interface RecordingFormatProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+const formatOptions: { value: RecordingFormat; label: string }[] = [
+  { value: "wav", label: "WAV (uncompressed)" },
+  { value: "flac", label: "FLAC (lossless, about half the size)" },
+];
+
+export const RecordingFormatSelector = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}: RecordingFormatProps) => {
+  const selectedFormat = useSetting("recording_format") || "wav";
+  const updating = useIsSettingUpdating("recording_format");
+  const updateSetting = useSettingsStore((s) => s.updateSetting);
+
+  return (
+    <SettingContainer
+      description="File format for new recordings in history. Existing recordings keep their format and still play and retranscribe."
+      descriptionMode={descriptionMode}
+      grouped={grouped}
+      title="Recording Format"
+    >
+      <Select
+        disabled={updating}
+        onValueChange={(val) => {
+          const parsed = RecordingFormatSchema.safeParse(val);
+          if (parsed.success) {
+            updateSetting("recording_format", parsed.data);
+          }
+        }}
+        value={selectedFormat}
+      >
+        <SelectTrigger className="w-full md:w-72">
+          <SelectValue />
+        </SelectTrigger>
+        <SelectContent>
+          {formatOptions.map((option) => (
+            <SelectItem key={option.value} value={option.value}>
+              {option.label}
+            </SelectItem>
+          ))}
+        </SelectContent>
+      </Select>
+    </SettingContainer>
+  );
+};
+
+RecordingFormatSelector.displayName = "RecordingFormatSelector";
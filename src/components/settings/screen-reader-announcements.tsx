@@ -0,0 +1,40 @@
+import { Speech } from "lucide-react";
+import { SettingContainer } from "@/components/ui/setting-container";
+import { Switch } from "@/components/ui/switch";
+import {
+  useIsSettingUpdating,
+  useSetting,
+  useSettingsStore,
+} from "@/stores/settings-store";
+
+interface ScreenReaderAnnouncementsProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const ScreenReaderAnnouncements = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}: ScreenReaderAnnouncementsProps) => {
+  const enabled = useSetting("accessibility_announcements");
+  const updating = useIsSettingUpdating("accessibility_announcements");
+  const updateSetting = useSettingsStore((s) => s.updateSetting);
+
+  return (
+    <SettingContainer
+      description="Have VoiceOver, Narrator or Orca announce when recording starts and stops and when the transcription is pasted, without relying on the overlay."
+      descriptionMode={descriptionMode}
+      grouped={grouped}
+      icon={<Speech className="h-4 w-4" />}
+      title="Screen Reader Announcements"
+    >
+      <Switch
+        checked={enabled}
+        disabled={updating}
+        onCheckedChange={(value) =>
+          updateSetting("accessibility_announcements", value)
+        }
+      />
+    </SettingContainer>
+  );
+};
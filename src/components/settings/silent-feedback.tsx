@@ -0,0 +1,63 @@
+import { Sparkles, Vibrate } from "lucide-react";
+import { SettingContainer } from "@/components/ui/setting-container";
+import { Switch } from "@/components/ui/switch";
+import { getNormalizedOsPlatform } from "@/lib/os";
+import {
+  useIsSettingUpdating,
+  useSetting,
+  useSettingsStore,
+} from "@/stores/settings-store";
+
+interface SilentFeedbackProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const SilentFeedback = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}: SilentFeedbackProps) => {
+  const visualFeedback = useSetting("visual_feedback");
+  const hapticFeedback = useSetting("haptic_feedback");
+  const updatingVisual = useIsSettingUpdating("visual_feedback");
+  const updatingHaptic = useIsSettingUpdating("haptic_feedback");
+  const updateSetting = useSettingsStore((s) => s.updateSetting);
+  const isMac = getNormalizedOsPlatform() === "mac";
+
+  return (
+    <>
+      <SettingContainer
+        description="Briefly flash the overlay when recording starts and stops. Works with or without sounds, for quiet environments."
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+        icon={<Sparkles className="h-4 w-4" />}
+        title="Visual Feedback"
+      >
+        <Switch
+          checked={visualFeedback}
+          disabled={updatingVisual}
+          onCheckedChange={(enabled) =>
+            updateSetting("visual_feedback", enabled)
+          }
+        />
+      </SettingContainer>
+      {isMac && (
+        <SettingContainer
+          description="Tap the trackpad when recording starts and stops. Needs a Force Touch trackpad and is only felt while your finger rests on it."
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+          icon={<Vibrate className="h-4 w-4" />}
+          title="Haptic Feedback"
+        >
+          <Switch
+            checked={hapticFeedback}
+            disabled={updatingHaptic}
+            onCheckedChange={(enabled) =>
+              updateSetting("haptic_feedback", enabled)
+            }
+          />
+        </SettingContainer>
+      )}
+    </>
+  );
+};
@@ -0,0 +1,152 @@
+import { invoke } from "@tauri-apps/api/core";
+import { useEffect, useState } from "react";
+import { toast } from "sonner";
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui/select";
+import { SettingContainer } from "@/components/ui/setting-container";
+import type { RecordingRetentionPeriod } from "@/lib/types";
+import { useSetting, useSettingsStore } from "@/stores/settings-store";
+
+interface SourceRetentionProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+const SOURCES = [
+  { mode: "push_to_talk", label: "Push-to-talk" },
+  { mode: "toggle", label: "Toggle" },
+  { mode: "always_on", label: "Always-on" },
+  { mode: "file", label: "Imported files" },
+  { mode: "system_audio", label: "System audio" },
+] as const;
+
+const GLOBAL = "global";
+
+interface FocusSessionStats {
+  session: { name: string };
+}
+
+export const SourceRetention = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}: SourceRetentionProps) => {
+  const sourceRetention = useSetting("source_retention") ?? {};
+  const tagRetention = useSetting("tag_retention") ?? {};
+  const historyLimit = useSetting("history_limit") ?? 5;
+  const refreshSettings = useSettingsStore((s) => s.refreshSettings);
+  const [sessionNames, setSessionNames] = useState<string[]>([]);
+
+  useEffect(() => {
+    invoke<FocusSessionStats[]>("list_focus_sessions")
+      .then((sessions) =>
+        setSessionNames(sessions.map((stats) => stats.session.name))
+      )
+      .catch((error) => toast.error(String(error)));
+  }, []);
+
+  // Session names that were given a period stay listed after their sessions
+  // are gone
+  const tags = [...new Set([...sessionNames, ...Object.keys(tagRetention)])];
+
+  const periodOptions: { value: RecordingRetentionPeriod; label: string }[] = [
+    { value: "never", label: "Never" },
+    {
+      value: "preserve_limit",
+      label: `Preserve ${historyLimit} Recording${historyLimit === 1 ? "" : "s"}`,
+    },
+    { value: "days3", label: "After 3 Days" },
+    { value: "weeks2", label: "After 2 Weeks" },
+    { value: "months3", label: "After 3 Months" },
+  ];
+
+  const update = async (mode: string, value: string) => {
+    try {
+      await invoke("update_source_retention_period", {
+        mode,
+        period: value === GLOBAL ? null : value,
+      });
+      await refreshSettings();
+    } catch (error) {
+      toast.error(String(error));
+    }
+  };
+
+  const updateTag = async (tag: string, value: string) => {
+    try {
+      await invoke("update_tag_retention_period", {
+        tag,
+        period: value === GLOBAL ? null : value,
+      });
+      await refreshSettings();
+    } catch (error) {
+      toast.error(String(error));
+    }
+  };
+
+  const periodSelect = (
+    value: string,
+    onChange: (value: string) => void,
+    globalLabel: string
+  ) => (
+    <Select onValueChange={onChange} value={value}>
+      <SelectTrigger className="w-56">
+        <SelectValue />
+      </SelectTrigger>
+      <SelectContent>
+        <SelectItem value={GLOBAL}>{globalLabel}</SelectItem>
+        {periodOptions.map((option) => (
+          <SelectItem key={option.value} value={option.value}>
+            {option.label}
+          </SelectItem>
+        ))}
+      </SelectContent>
+    </Select>
+  );
+
+  return (
+    <SettingContainer
+      description="Delete recordings from some sources or focus sessions on a different schedule, like keeping imported files forever while quick dictations go after 3 days. A focus session's schedule comes before its source's."
+      descriptionMode={descriptionMode}
+      grouped={grouped}
+      layout="stacked"
+      title="Delete Recordings by Source"
+    >
+      <div className="space-y-2">
+        {SOURCES.map(({ mode, label }) => (
+          <div
+            className="flex items-center justify-between gap-2 text-sm"
+            key={mode}
+          >
+            <span>{label}</span>
+            {periodSelect(
+              sourceRetention[mode] ?? GLOBAL,
+              (value) => update(mode, value),
+              "Same as all recordings"
+            )}
+          </div>
+        ))}
+        {tags.length > 0 && (
+          <p className="pt-2 text-muted-foreground text-xs">Focus sessions</p>
+        )}
+        {tags.map((tag) => (
+          <div
+            className="flex items-center justify-between gap-2 text-sm"
+            key={tag}
+          >
+            <span className="truncate">{tag}</span>
+            {periodSelect(
+              tagRetention[tag] ?? GLOBAL,
+              (value) => updateTag(tag, value),
+              "Same as its source"
+            )}
+          </div>
+        ))}
+      </div>
+    </SettingContainer>
+  );
+};
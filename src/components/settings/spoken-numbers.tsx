@@ -0,0 +1,65 @@
+import { invoke } from "@tauri-apps/api/core";
+import { Hash } from "lucide-react";
+import { useEffect, useState } from "react";
+import { toast } from "sonner";
+import { SettingContainer } from "@/components/ui/setting-container";
+import { Switch } from "@/components/ui/switch";
+import { useSetting, useSettingsStore } from "@/stores/settings-store";
+
+interface LanguageRules {
+  code: string;
+  description: string;
+  name: string;
+}
+
+interface SpokenNumbersProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const SpokenNumbers = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}: SpokenNumbersProps) => {
+  const enabled = useSetting("text_normalization") ?? [];
+  const refreshSettings = useSettingsStore((s) => s.refreshSettings);
+  const [rules, setRules] = useState<LanguageRules[]>([]);
+
+  useEffect(() => {
+    invoke<LanguageRules[]>("get_text_normalization_rules")
+      .then(setRules)
+      .catch(() => setRules([]));
+  }, []);
+
+  const toggle = async (language: string, value: boolean) => {
+    try {
+      await invoke("change_text_normalization_setting", {
+        language,
+        enabled: value,
+      });
+      await refreshSettings();
+    } catch (error) {
+      toast.error(String(error));
+    }
+  };
+
+  return (
+    <>
+      {rules.map((language) => (
+        <SettingContainer
+          description={`${language.description}: "twenty five dollars" becomes "$25". Applied to transcripts in ${language.name}.`}
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+          icon={<Hash className="h-4 w-4" />}
+          key={language.code}
+          title={`${language.name} Numbers`}
+        >
+          <Switch
+            checked={enabled.includes(language.code)}
+            onCheckedChange={(value) => toggle(language.code, value)}
+          />
+        </SettingContainer>
+      ))}
+    </>
+  );
+};
@@ -0,0 +1,55 @@
+import { listen } from "@tauri-apps/api/event";
+import { Timer } from "lucide-react";
+import { useEffect, useState } from "react";
+import { Slider } from "@/components/ui/slider";
+import { useSetting, useSettingsStore } from "@/stores/settings-store";
+
+interface StreamingLatencyProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+interface StreamingWindowEvent {
+  target_ms: number;
+  window_ms: number;
+}
+
+export const StreamingLatency = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}: StreamingLatencyProps) => {
+  const target = useSetting("streaming_target_latency_ms") ?? 800;
+  const updateSetting = useSettingsStore((s) => s.updateSetting);
+  const [windowMs, setWindowMs] = useState<number | null>(null);
+
+  useEffect(() => {
+    const unlisten = listen<StreamingWindowEvent>(
+      "streaming-window-changed",
+      (event) => setWindowMs(event.payload.window_ms)
+    );
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  const stretched =
+    windowMs !== null && windowMs > target
+      ? ` Your last dictation stretched it to ${windowMs} ms.`
+      : "";
+
+  return (
+    <Slider
+      description={`How often the live preview updates while you dictate. Lower is snappier but busier; passes slower than this stretch the interval so they don't pile up.${stretched}`}
+      descriptionMode={descriptionMode}
+      formatValue={(v) => `${v} ms`}
+      grouped={grouped}
+      icon={<Timer className="h-4 w-4" />}
+      label="Live Preview Interval"
+      max={2000}
+      min={300}
+      onChange={(value) => updateSetting("streaming_target_latency_ms", value)}
+      step={100}
+      value={target}
+    />
+  );
+};
@@ -0,0 +1,192 @@
+import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
+import { FolderOpen, Puzzle, RefreshCw, Trash2 } from "lucide-react";
+import { useCallback, useEffect, useState } from "react";
+import { toast } from "sonner";
+import { Button } from "@/components/ui/button";
+import { Input } from "@/components/ui/input";
+import { SettingContainer } from "@/components/ui/setting-container";
+import { Switch } from "@/components/ui/switch";
+import { cn } from "@/lib/utils";
+
+interface TextPlugin {
+  description: string;
+  enabled: boolean;
+  id: string;
+  inputs: ("language" | "app")[];
+  module: string;
+  name: string;
+  output: "text";
+  version: string;
+}
+
+interface TextPluginsProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const TextPlugins = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}: TextPluginsProps) => {
+  const [plugins, setPlugins] = useState<TextPlugin[]>([]);
+  const [path, setPath] = useState("");
+  const [sample, setSample] = useState("");
+  const [results, setResults] = useState<Record<string, string>>({});
+
+  const refresh = useCallback(() => {
+    invoke<TextPlugin[]>("list_text_plugins")
+      .then(setPlugins)
+      .catch((error) => console.error("Failed to load text plugins:", error));
+  }, []);
+
+  useEffect(() => {
+    refresh();
+    const unlisten = listen("text-plugins-changed", refresh);
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, [refresh]);
+
+  const run = async <T,>(command: string, args?: Record<string, unknown>) => {
+    try {
+      return await invoke<T>(command, args);
+    } catch (error) {
+      toast.error(String(error));
+      return null;
+    }
+  };
+
+  const handleInstall = async () => {
+    const manifest = await run<TextPlugin>("install_text_plugin", {
+      path: path.trim(),
+    });
+    if (manifest) {
+      toast.success(`Installed ${manifest.name}`);
+      setPath("");
+    }
+  };
+
+  const handleReload = async () => {
+    const reloaded = await run<TextPlugin[]>("reload_text_plugins");
+    if (reloaded) {
+      setPlugins(reloaded);
+    }
+  };
+
+  const handleTry = async (id: string) => {
+    const output = await run<string>("run_text_plugin", { id, text: sample });
+    if (output !== null) {
+      setResults((current) => ({ ...current, [id]: output }));
+    }
+  };
+
+  return (
+    <>
+      <SettingContainer
+        description="Transform every dictation with WebAssembly plugins before it's pasted, after AI post-processing. Plugins run sandboxed, without access to your files or the network. Install one from its folder or manifest.json, or drop it in the plugins folder and reload."
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+        icon={<Puzzle className="h-4 w-4" />}
+        layout="stacked"
+        title="Text Plugins"
+      >
+        <div className="flex flex-wrap items-center gap-2">
+          <Input
+            className="min-w-60 flex-1"
+            onChange={(e) => setPath(e.target.value)}
+            placeholder="/path/to/plugin/manifest.json"
+            value={path}
+          />
+          <Button
+            disabled={!path.trim()}
+            onClick={handleInstall}
+            size="sm"
+            variant="outline"
+          >
+            Install
+          </Button>
+          <Button
+            aria-label="Open plugins folder"
+            onClick={() => run("open_text_plugins_folder")}
+            size="icon"
+            variant="ghost"
+          >
+            <FolderOpen className="h-4 w-4" />
+          </Button>
+          <Button
+            aria-label="Reload plugins"
+            onClick={handleReload}
+            size="icon"
+            variant="ghost"
+          >
+            <RefreshCw className="h-4 w-4" />
+          </Button>
+        </div>
+      </SettingContainer>
+      {plugins.length > 0 && (
+        <div
+          className={cn(
+            "space-y-3 p-2 px-4",
+            !grouped && "rounded-lg border border-border/20"
+          )}
+        >
+          <Input
+            onChange={(e) => setSample(e.target.value)}
+            placeholder="Sample text to try a plugin on"
+            value={sample}
+          />
+          {plugins.map((plugin) => (
+            <div className="space-y-1 text-sm" key={plugin.id}>
+              <div className="flex items-center justify-between gap-2">
+                <div className="min-w-0">
+                  <p className="truncate font-medium">
+                    {plugin.name}
+                    <span className="ml-2 text-muted-foreground text-xs">
+                      {plugin.version}
+                    </span>
+                  </p>
+                  {plugin.description && (
+                    <p className="text-muted-foreground text-xs">
+                      {plugin.description}
+                    </p>
+                  )}
+                </div>
+                <div className="flex shrink-0 items-center gap-2">
+                  <Button
+                    disabled={!sample.trim()}
+                    onClick={() => handleTry(plugin.id)}
+                    size="sm"
+                    variant="ghost"
+                  >
+                    Try
+                  </Button>
+                  <Switch
+                    aria-label={`Enable ${plugin.name}`}
+                    checked={plugin.enabled}
+                    onCheckedChange={(enabled) =>
+                      run("set_text_plugin_enabled", { id: plugin.id, enabled })
+                    }
+                  />
+                  <Button
+                    aria-label={`Remove ${plugin.name}`}
+                    onClick={() => run("remove_text_plugin", { id: plugin.id })}
+                    size="icon"
+                    variant="ghost"
+                  >
+                    <Trash2 className="h-4 w-4" />
+                  </Button>
+                </div>
+              </div>
+              {results[plugin.id] !== undefined && (
+                <p className="rounded bg-muted/50 px-2 py-1 text-xs">
+                  {results[plugin.id]}
+                </p>
+              )}
+            </div>
+          ))}
+        </div>
+      )}
+    </>
+  );
+};
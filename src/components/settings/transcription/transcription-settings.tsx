@@ -1,7 +1,24 @@
+import { AppCustomWords } from "@/components/settings/app-custom-words";
+import { CloudTranscription } from "@/components/settings/cloud-transcription";
 import { CustomWords } from "@/components/settings/custom-words";
+import { DictationCommands } from "@/components/settings/dictation-commands";
+import { DictationTemplates } from "@/components/settings/dictation-templates";
+import { InferenceBackendSetting } from "@/components/settings/inference-backend";
+import { InitialPrompt } from "@/components/settings/initial-prompt";
 import { LanguageSelector } from "@/components/settings/language-selector";
+import { LearnFromCorrections } from "@/components/settings/learn-from-corrections";
+import { LocaleFormatting } from "@/components/settings/locale-formatting";
+import { LowConfidenceReview } from "@/components/settings/low-confidence-review";
 import { ModelUnloadTimeoutSetting } from "@/components/settings/model-unload-timeout";
+import { ModelWarmHours } from "@/components/settings/model-warm-hours";
+import { PreviewModel } from "@/components/settings/preview-model";
+import { ProfanityFilter } from "@/components/settings/profanity-filter";
+import { PunctuationRestoration } from "@/components/settings/punctuation-restoration";
+import { SpokenNumbers } from "@/components/settings/spoken-numbers";
+import { StreamingLatency } from "@/components/settings/streaming-latency";
 import { TranslateToEnglish } from "@/components/settings/translate-to-english";
+import { VadTrim } from "@/components/settings/vad-trim";
+import { VoiceCommandRules } from "@/components/settings/voice-command-rules";
 import { CollapsibleSettingsGroup } from "@/components/ui/collapsible-settings-group";
 
 export const TranscriptionSettings = () => (
@@ -9,11 +26,40 @@ export const TranscriptionSettings = () => (
     <CollapsibleSettingsGroup defaultOpen={true} title="Language">
       <LanguageSelector descriptionMode="tooltip" grouped={true} />
       <TranslateToEnglish descriptionMode="tooltip" grouped={true} />
+      <LocaleFormatting descriptionMode="tooltip" grouped={true} />
+      <SpokenNumbers descriptionMode="tooltip" grouped={true} />
+      <PunctuationRestoration descriptionMode="tooltip" grouped={true} />
+      <ProfanityFilter descriptionMode="tooltip" grouped={true} />
+      <DictationCommands descriptionMode="tooltip" grouped={true} />
     </CollapsibleSettingsGroup>
 
     <CollapsibleSettingsGroup defaultOpen={true} title="Accuracy">
       <CustomWords descriptionMode="tooltip" grouped={true} />
+      <AppCustomWords descriptionMode="tooltip" grouped={true} />
+      <LearnFromCorrections descriptionMode="tooltip" grouped={true} />
+      <InitialPrompt descriptionMode="tooltip" grouped={true} />
+      <VadTrim descriptionMode="tooltip" grouped={true} />
+      <LowConfidenceReview descriptionMode="tooltip" grouped={true} />
       <ModelUnloadTimeoutSetting descriptionMode="tooltip" grouped={true} />
+      <ModelWarmHours descriptionMode="tooltip" grouped={true} />
+    </CollapsibleSettingsGroup>
+
+    <CollapsibleSettingsGroup defaultOpen={false} title="Performance">
+      <InferenceBackendSetting descriptionMode="tooltip" grouped={true} />
+      <PreviewModel descriptionMode="tooltip" grouped={true} />
+      <StreamingLatency descriptionMode="tooltip" grouped={true} />
+    </CollapsibleSettingsGroup>
+
+    <CollapsibleSettingsGroup defaultOpen={false} title="Cloud">
+      <CloudTranscription descriptionMode="tooltip" grouped={true} />
+    </CollapsibleSettingsGroup>
+
+    <CollapsibleSettingsGroup defaultOpen={false} title="Templates">
+      <DictationTemplates descriptionMode="tooltip" grouped={true} />
+    </CollapsibleSettingsGroup>
+
+    <CollapsibleSettingsGroup defaultOpen={false} title="Voice Commands">
+      <VoiceCommandRules descriptionMode="tooltip" grouped={true} />
     </CollapsibleSettingsGroup>
   </div>
 );
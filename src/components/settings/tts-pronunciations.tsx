@@ -0,0 +1,118 @@
+import { BookA, PlusIcon, Trash2 } from "lucide-react";
+import { useState } from "react";
+import { Button } from "@/components/ui/button";
+import { Input } from "@/components/ui/input";
+import { SettingContainer } from "@/components/ui/setting-container";
+import { cn } from "@/lib/utils";
+import {
+  useIsSettingUpdating,
+  useSetting,
+  useSettingsStore,
+} from "@/stores/settings-store";
+
+interface TtsPronunciationsProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const TtsPronunciations = ({
+  descriptionMode = "inline",
+  grouped = false,
+}: TtsPronunciationsProps) => {
+  const pronunciations = useSetting("tts_pronunciations") ?? {};
+  const updating = useIsSettingUpdating("tts_pronunciations");
+  const updateSetting = useSettingsStore((s) => s.updateSetting);
+  const [word, setWord] = useState("");
+  const [respelling, setRespelling] = useState("");
+
+  const add = () => {
+    if (!(word.trim() && respelling.trim())) {
+      return;
+    }
+    updateSetting("tts_pronunciations", {
+      ...pronunciations,
+      [word.trim()]: respelling.trim(),
+    });
+    setWord("");
+    setRespelling("");
+  };
+
+  const remove = (key: string) => {
+    const { [key]: _removed, ...rest } = pronunciations;
+    updateSetting("tts_pronunciations", rest);
+  };
+
+  return (
+    <>
+      <SettingContainer
+        description="Words the voice mispronounces, read as you spell them instead. Write the sound, like “win” for Nguyen; system voices read plain text, not IPA."
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+        icon={<BookA className="h-4 w-4" />}
+        layout="stacked"
+        title="Pronunciations"
+      >
+        <div className="flex flex-wrap items-center gap-2">
+          <Input
+            className="w-48 shrink-0"
+            disabled={updating}
+            onChange={(e) => setWord(e.target.value)}
+            placeholder="Word or name"
+            value={word}
+          />
+          <Input
+            className="min-w-40 flex-1"
+            disabled={updating}
+            onChange={(e) => setRespelling(e.target.value)}
+            onKeyDown={(e) => {
+              if (e.key === "Enter") {
+                e.preventDefault();
+                add();
+              }
+            }}
+            placeholder="Say it like"
+            value={respelling}
+          />
+          <Button
+            aria-label="Add pronunciation"
+            disabled={!(word.trim() && respelling.trim()) || updating}
+            onClick={add}
+            size="icon"
+            variant="outline"
+          >
+            <PlusIcon className="h-4 w-4" />
+          </Button>
+        </div>
+      </SettingContainer>
+      {Object.keys(pronunciations).length > 0 && (
+        <div
+          className={cn(
+            "space-y-2 p-2 px-4",
+            !grouped && "rounded-lg border border-border/20"
+          )}
+        >
+          {Object.entries(pronunciations).map(([key, spoken]) => (
+            <div
+              className="flex items-center justify-between gap-2 text-sm"
+              key={key}
+            >
+              <div className="min-w-0 truncate">
+                <span className="font-medium">{key}</span>
+                <span className="text-muted-foreground"> → {spoken}</span>
+              </div>
+              <Button
+                aria-label={`Remove pronunciation of ${key}`}
+                disabled={updating}
+                onClick={() => remove(key)}
+                size="icon"
+                variant="ghost"
+              >
+                <Trash2 className="h-4 w-4" />
+              </Button>
+            </div>
+          ))}
+        </div>
+      )}
+    </>
+  );
+};
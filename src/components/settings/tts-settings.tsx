@@ -1,6 +1,7 @@
 import { invoke } from "@tauri-apps/api/core";
 import { CheckCircle, Loader2, Play, Volume2, XCircle } from "lucide-react";
 import { useState } from "react";
+import { TtsPronunciations } from "@/components/settings/tts-pronunciations";
 import { Button } from "@/components/ui/button";
 import { Switch } from "@/components/ui/switch";
 import { useSetting, useSettingsStore } from "@/stores/settings-store";
@@ -69,6 +70,8 @@ export function TtsSettings() {
             </div>
           </div>
 
+          <TtsPronunciations />
+
           {/* Preview Section */}
           <div className="rounded-lg border border-border/40 bg-card px-4 py-3">
             <div className="space-y-3">
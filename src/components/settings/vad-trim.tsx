@@ -0,0 +1,38 @@
+import { Scissors } from "lucide-react";
+import { SettingContainer } from "@/components/ui/setting-container";
+import { Switch } from "@/components/ui/switch";
+import {
+  useIsSettingUpdating,
+  useSetting,
+  useSettingsStore,
+} from "@/stores/settings-store";
+
+interface VadTrimProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const VadTrim = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}: VadTrimProps) => {
+  const enabled = useSetting("vad_trim_enabled");
+  const updating = useIsSettingUpdating("vad_trim_enabled");
+  const updateSetting = useSettingsStore((s) => s.updateSetting);
+
+  return (
+    <SettingContainer
+      description="Remove silence before transcribing, so recordings and imported files transcribe faster and the model doesn't invent text for quiet stretches."
+      descriptionMode={descriptionMode}
+      grouped={grouped}
+      icon={<Scissors className="h-4 w-4" />}
+      title="Trim Silence"
+    >
+      <Switch
+        checked={enabled}
+        disabled={updating}
+        onCheckedChange={(value) => updateSetting("vad_trim_enabled", value)}
+      />
+    </SettingContainer>
+  );
+};
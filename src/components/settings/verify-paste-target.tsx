@@ -0,0 +1,38 @@
+import { TextCursorInput } from "lucide-react";
+import { SettingContainer } from "@/components/ui/setting-container";
+import { Switch } from "@/components/ui/switch";
+import {
+  useIsSettingUpdating,
+  useSetting,
+  useSettingsStore,
+} from "@/stores/settings-store";
+
+interface VerifyPasteTargetProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const VerifyPasteTarget = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}: VerifyPasteTargetProps) => {
+  const enabled = useSetting("verify_paste_target") ?? true;
+  const updating = useIsSettingUpdating("verify_paste_target");
+  const updateSetting = useSettingsStore((s) => s.updateSetting);
+
+  return (
+    <SettingContainer
+      description="Before pasting, check that a text field has focus. When none does, the text is copied to the clipboard instead of sending a paste shortcut to whatever window is in front. macOS only."
+      descriptionMode={descriptionMode}
+      grouped={grouped}
+      icon={<TextCursorInput className="h-4 w-4" />}
+      title="Check Paste Target"
+    >
+      <Switch
+        checked={enabled}
+        disabled={updating}
+        onCheckedChange={(value) => updateSetting("verify_paste_target", value)}
+      />
+    </SettingContainer>
+  );
+};
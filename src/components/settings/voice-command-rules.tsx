@@ -0,0 +1,186 @@
+import { invoke } from "@tauri-apps/api/core";
+import { PlusIcon, Trash2, Zap } from "lucide-react";
+import { useEffect, useState } from "react";
+import { toast } from "sonner";
+import { Button } from "@/components/ui/button";
+import { Input } from "@/components/ui/input";
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui/select";
+import { SettingContainer } from "@/components/ui/setting-container";
+import { Switch } from "@/components/ui/switch";
+import type { ActionDef, VoiceCommandRule } from "@/lib/types";
+import { cn } from "@/lib/utils";
+import {
+  useIsSettingUpdating,
+  useSetting,
+  useSettingsStore,
+} from "@/stores/settings-store";
+
+interface VoiceCommandRulesProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+const describeParams = (params: Record<string, string>) =>
+  Object.entries(params)
+    .map(([name, value]) => `${name}: ${value}`)
+    .join(", ");
+
+export const VoiceCommandRules = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}: VoiceCommandRulesProps) => {
+  const enabled = useSetting("voice_command_rules_enabled") ?? false;
+  const rules = useSetting("voice_command_rules") ?? [];
+  const updating = useIsSettingUpdating("voice_command_rules_enabled");
+  const updateSetting = useSettingsStore((s) => s.updateSetting);
+  const refreshSettings = useSettingsStore((s) => s.refreshSettings);
+  const [actions, setActions] = useState<ActionDef[]>([]);
+  const [phrase, setPhrase] = useState("");
+  const [actionId, setActionId] = useState("");
+  const [params, setParams] = useState<Record<string, string>>({});
+
+  useEffect(() => {
+    invoke<ActionDef[]>("list_actions")
+      .then(setActions)
+      .catch((error) => toast.error(String(error)));
+  }, []);
+
+  const action = actions.find((a) => a.id === actionId);
+  const canAdd =
+    Boolean(phrase.trim()) &&
+    action !== undefined &&
+    action.params.every((p) => !p.required || Boolean(params[p.name]));
+
+  const actionName = (id: string) =>
+    actions.find((a) => a.id === id)?.name ?? id;
+
+  const save = async (next: VoiceCommandRule[]) => {
+    try {
+      await invoke("set_voice_command_rules", { rules: next });
+      await refreshSettings();
+      return true;
+    } catch (error) {
+      toast.error(String(error));
+      return false;
+    }
+  };
+
+  const add = async () => {
+    const filled = Object.fromEntries(
+      Object.entries(params).filter(([, value]) => value.trim())
+    );
+    const rule = { phrase: phrase.trim(), action: actionId, params: filled };
+    if (await save([...rules, rule])) {
+      setPhrase("");
+      setParams({});
+    }
+  };
+
+  return (
+    <>
+      <SettingContainer
+        description='Say a whole phrase like "open Terminal" or "switch prompt to email" to run an action instead of pasting the text. {name} in a phrase stands for any words.'
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+        icon={<Zap className="h-4 w-4" />}
+        title="Voice Command Rules"
+      >
+        <Switch
+          checked={enabled}
+          disabled={updating}
+          onCheckedChange={(value) =>
+            updateSetting("voice_command_rules_enabled", value)
+          }
+        />
+      </SettingContainer>
+
+      <div
+        className={cn(
+          "space-y-2 p-2 px-4",
+          !grouped && "rounded-lg border border-border/20"
+        )}
+      >
+        {rules.length === 0 && (
+          <div className="text-muted-foreground text-sm">No rules</div>
+        )}
+        {rules.map((rule, index) => (
+          <div
+            className="flex items-center justify-between gap-2 text-sm"
+            key={`${rule.phrase}-${rule.action}`}
+          >
+            <div className="min-w-0">
+              <div className="truncate font-medium">"{rule.phrase}"</div>
+              <div className="truncate text-muted-foreground text-xs">
+                {actionName(rule.action)}
+                {Object.keys(rule.params).length > 0 &&
+                  ` (${describeParams(rule.params)})`}
+              </div>
+            </div>
+            <Button
+              aria-label={`Delete "${rule.phrase}"`}
+              onClick={() => save(rules.filter((_, i) => i !== index))}
+              size="icon"
+              variant="ghost"
+            >
+              <Trash2 className="h-4 w-4" />
+            </Button>
+          </div>
+        ))}
+
+        <div className="flex flex-wrap items-center gap-2 pt-2">
+          <Input
+            className="min-w-40 flex-1"
+            onChange={(e) => setPhrase(e.target.value)}
+            placeholder="open {app}"
+            value={phrase}
+          />
+          <Select
+            onValueChange={(value) => {
+              setActionId(value);
+              setParams({});
+            }}
+            value={actionId}
+          >
+            <SelectTrigger className="w-56 shrink-0">
+              <SelectValue placeholder="Action" />
+            </SelectTrigger>
+            <SelectContent>
+              {actions.map((a) => (
+                <SelectItem key={a.id} value={a.id}>
+                  {a.name}
+                </SelectItem>
+              ))}
+            </SelectContent>
+          </Select>
+          {action?.params.map((param) => (
+            <Input
+              className="w-32 shrink-0"
+              key={param.name}
+              onChange={(e) =>
+                setParams({ ...params, [param.name]: e.target.value })
+              }
+              placeholder={param.kind === "boolean" ? "true" : param.name}
+              title={param.description}
+              value={params[param.name] ?? ""}
+            />
+          ))}
+          <Button
+            aria-label="Add rule"
+            disabled={!canAdd}
+            onClick={add}
+            size="icon"
+            variant="outline"
+          >
+            <PlusIcon className="h-4 w-4" />
+          </Button>
+        </div>
+      </div>
+    </>
+  );
+};
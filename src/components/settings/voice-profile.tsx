@@ -0,0 +1,186 @@
+import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
+import { AudioLines, UserCheck } from "lucide-react";
+import { useCallback, useEffect, useState } from "react";
+import { toast } from "sonner";
+import { Button } from "@/components/ui/button";
+import { SettingContainer } from "@/components/ui/setting-container";
+import { Switch } from "@/components/ui/switch";
+import {
+  useIsSettingUpdating,
+  useSetting,
+  useSettingsStore,
+} from "@/stores/settings-store";
+
+interface VoiceProfileStatus {
+  duration_secs: number | null;
+  enrolled: boolean;
+  enrolled_at: number | null;
+  model_available: boolean;
+}
+
+interface VoiceProfileProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+const ENROLLMENT_PASSAGE =
+  "The quick brown fox jumps over the lazy dog. I'm recording this so my computer learns how my voice sounds. When I dictate, only what I say should end up on the screen, not the radio, the television or anyone talking nearby.";
+
+export const VoiceProfile = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}: VoiceProfileProps) => {
+  const enabled = useSetting("voice_filter_enabled") ?? false;
+  const updating = useIsSettingUpdating("voice_filter_enabled");
+  const updateSetting = useSettingsStore((s) => s.updateSetting);
+  const [status, setStatus] = useState<VoiceProfileStatus | null>(null);
+  const [enrolling, setEnrolling] = useState(false);
+  const [saving, setSaving] = useState(false);
+
+  const refreshStatus = useCallback(() => {
+    invoke<VoiceProfileStatus>("get_voice_profile_status")
+      .then(setStatus)
+      .catch(() => setStatus(null));
+  }, []);
+
+  useEffect(() => {
+    if (!updating) {
+      refreshStatus();
+    }
+  }, [updating, refreshStatus]);
+
+  // Enrollment becomes possible once the speaker model finishes downloading
+  useEffect(() => {
+    const unlisten = listen("model-download-complete", refreshStatus);
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, [refreshStatus]);
+
+  // Don't leave the microphone recording if the page is left mid-enrollment
+  useEffect(() => {
+    if (!enrolling) {
+      return;
+    }
+    return () => {
+      invoke("cancel_voice_enrollment").catch(() => {});
+    };
+  }, [enrolling]);
+
+  const startEnrollment = async () => {
+    try {
+      await invoke("start_voice_enrollment");
+      setEnrolling(true);
+    } catch (error) {
+      toast.error(String(error));
+    }
+  };
+
+  const finishEnrollment = async () => {
+    setSaving(true);
+    try {
+      const next = await invoke<VoiceProfileStatus>("finish_voice_enrollment");
+      setStatus(next);
+      toast.success("Voice profile saved");
+    } catch (error) {
+      toast.error(String(error));
+    } finally {
+      setSaving(false);
+      setEnrolling(false);
+    }
+  };
+
+  const cancelEnrollment = () => {
+    invoke("cancel_voice_enrollment").catch(() => {});
+    setEnrolling(false);
+  };
+
+  const deleteProfile = async () => {
+    try {
+      await invoke("delete_voice_profile");
+      refreshStatus();
+    } catch (error) {
+      toast.error(String(error));
+    }
+  };
+
+  const enrolledOn = status?.enrolled_at
+    ? new Date(status.enrolled_at * 1000).toLocaleDateString()
+    : null;
+
+  return (
+    <>
+      <SettingContainer
+        description="Only transcribe the parts of a dictation that sound like your enrolled voice, so a TV or people talking nearby are ignored."
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+        icon={<UserCheck className="h-4 w-4" />}
+        title="Voice Filter"
+      >
+        <Switch
+          checked={enabled}
+          disabled={updating}
+          onCheckedChange={(value) =>
+            updateSetting("voice_filter_enabled", value)
+          }
+        />
+      </SettingContainer>
+      {enabled && status && (
+        <SettingContainer
+          description={
+            status.model_available
+              ? "Read the passage aloud at your usual dictation distance, for at least five seconds."
+              : "Downloading the speaker model. Enrollment is available once it's ready."
+          }
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+          icon={<AudioLines className="h-4 w-4" />}
+          layout={enrolling ? "stacked" : "horizontal"}
+          title={
+            status.enrolled && enrolledOn
+              ? `Voice Profile (enrolled ${enrolledOn})`
+              : "Voice Profile"
+          }
+        >
+          {enrolling ? (
+            <div className="flex flex-col gap-2">
+              <p className="rounded-md bg-mid-gray/10 p-3 text-sm leading-relaxed">
+                {ENROLLMENT_PASSAGE}
+              </p>
+              <div className="flex items-center gap-2">
+                <Button disabled={saving} onClick={finishEnrollment} size="sm">
+                  {saving ? "Saving..." : "Done"}
+                </Button>
+                <Button
+                  disabled={saving}
+                  onClick={cancelEnrollment}
+                  size="sm"
+                  variant="ghost"
+                >
+                  Cancel
+                </Button>
+              </div>
+            </div>
+          ) : (
+            <div className="flex items-center gap-2">
+              <Button
+                disabled={!status.model_available}
+                onClick={startEnrollment}
+                size="sm"
+                variant="outline"
+              >
+                {status.enrolled ? "Re-enroll" : "Enroll"}
+              </Button>
+              {status.enrolled && (
+                <Button onClick={deleteProfile} size="sm" variant="ghost">
+                  Remove
+                </Button>
+              )}
+            </div>
+          )}
+        </SettingContainer>
+      )}
+    </>
+  );
+};
@@ -0,0 +1,33 @@
+import { invoke } from "@tauri-apps/api/core";
+import { useCallback, useEffect, useState } from "react";
+import type { FeatureFlagId, FeatureFlagState } from "@/lib/types";
+
+export const useFeatureFlags = () => {
+  const [flags, setFlags] = useState<FeatureFlagState[]>([]);
+
+  useEffect(() => {
+    invoke<FeatureFlagState[]>("get_feature_flags")
+      .then(setFlags)
+      .catch((error) => console.error("Failed to load feature flags:", error));
+  }, []);
+
+  // Pass null to return the flag to its built-in default
+  const setFlag = useCallback(
+    async (flag: FeatureFlagId, enabled: boolean | null) => {
+      const updated = await invoke<FeatureFlagState[]>("set_feature_flag", {
+        flag,
+        enabled,
+      });
+      setFlags(updated);
+    },
+    []
+  );
+
+  const isEnabled = useCallback(
+    (flag: FeatureFlagId) =>
+      flags.find((state) => state.id === flag)?.enabled ?? false,
+    [flags]
+  );
+
+  return { flags, setFlag, isEnabled };
+};
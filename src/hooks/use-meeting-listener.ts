@@ -27,7 +27,7 @@ export function useMeetingListener() {
             return;
           }
           const status = event.payload;
-          if (status === "complete") {
+          if (status === "complete" || status === "cancelled") {
             store.getState().setStatus("idle");
             store.getState().loadMeetings();
           } else if (status === "processing") {
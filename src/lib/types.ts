@@ -34,6 +34,15 @@ export const ModelUnloadTimeoutSchema = z.enum([
 ]);
 export type ModelUnloadTimeout = z.infer<typeof ModelUnloadTimeoutSchema>;
 
+export const InferenceBackendSchema = z.enum([
+  "auto",
+  "metal",
+  "cuda",
+  "vulkan",
+  "cpu",
+]);
+export type InferenceBackend = z.infer<typeof InferenceBackendSchema>;
+
 export const PasteMethodSchema = z.enum([
   "ctrl_v",
   "direct",
@@ -48,6 +57,15 @@ export const ClipboardHandlingSchema = z.enum([
 ]);
 export type ClipboardHandling = z.infer<typeof ClipboardHandlingSchema>;
 
+export const ProfanityFilterSchema = z.enum(["keep", "mask", "remove"]);
+export type ProfanityFilter = z.infer<typeof ProfanityFilterSchema>;
+
+export const WarmHoursSchema = z.object({
+  start_hour: z.number(),
+  end_hour: z.number(),
+});
+export type WarmHours = z.infer<typeof WarmHoursSchema>;
+
 export const RecordingRetentionPeriodSchema = z.enum([
   "never",
   "preserve_limit",
@@ -59,6 +77,17 @@ export type RecordingRetentionPeriod = z.infer<
   typeof RecordingRetentionPeriodSchema
 >;
 
+export const RecordingFormatSchema = z.enum(["wav", "flac"]);
+export type RecordingFormat = z.infer<typeof RecordingFormatSchema>;
+
+export const DataApiFieldSchema = z.enum([
+  "text",
+  "title",
+  "prompt",
+  "app_name",
+]);
+export type DataApiField = z.infer<typeof DataApiFieldSchema>;
+
 export const LLMPromptSchema = z.object({
   id: z.string(),
   name: z.string(),
@@ -67,6 +96,75 @@ export const LLMPromptSchema = z.object({
 
 export type LLMPrompt = z.infer<typeof LLMPromptSchema>;
 
+export const DictationTemplateSchema = z.object({
+  id: z.string(),
+  name: z.string(),
+  template: z.string(),
+});
+
+export type DictationTemplate = z.infer<typeof DictationTemplateSchema>;
+
+export const DictationCommandActionSchema = z.discriminatedUnion("type", [
+  z.object({ type: z.literal("insert"), text: z.string() }),
+  z.object({ type: z.literal("delete_word") }),
+  z.object({ type: z.literal("delete_sentence") }),
+]);
+
+export type DictationCommandAction = z.infer<
+  typeof DictationCommandActionSchema
+>;
+
+export const DictationCommandSchema = z.object({
+  phrase: z.string(),
+  action: DictationCommandActionSchema,
+});
+
+export type DictationCommand = z.infer<typeof DictationCommandSchema>;
+
+export const VoiceCommandRuleSchema = z.object({
+  phrase: z.string(),
+  action: z.string(),
+  params: z.record(z.string(), z.string()).optional().default({}),
+});
+
+export type VoiceCommandRule = z.infer<typeof VoiceCommandRuleSchema>;
+
+export interface ActionParam {
+  name: string;
+  kind: "string" | "boolean";
+  required: boolean;
+  description: string;
+}
+
+export interface ActionDef {
+  id: string;
+  name: string;
+  category: string;
+  params: ActionParam[];
+}
+
+export interface AppRuntimeInfo {
+  app_version: string;
+  os: string;
+  arch: string;
+  history_schema_version: number | null;
+  history_schema_target: number;
+  input_schema_version: number | null;
+  model_catalog_version: number;
+  settings_version: number;
+  wayland: boolean;
+  accessibility_granted: boolean;
+  ffmpeg_found: boolean;
+}
+
+export interface TemplateProgress {
+  template_id: string;
+  name: string;
+  slot: string;
+  index: number;
+  total: number;
+}
+
 export const PostProcessProviderSchema = z.object({
   id: z.string(),
   label: z.string(),
@@ -81,6 +179,14 @@ export const PostProcessProviderSchema = z.object({
 
 export type PostProcessProvider = z.infer<typeof PostProcessProviderSchema>;
 
+export const TranscriptionProviderSchema = z.object({
+  id: z.string(),
+  label: z.string(),
+  base_url: z.string(),
+});
+
+export type TranscriptionProvider = z.infer<typeof TranscriptionProviderSchema>;
+
 export const SettingsSchema = z.object({
   bindings: ShortcutBindingsMapSchema,
   push_to_talk: z.boolean(),
@@ -128,7 +234,12 @@ export const SettingsSchema = z.object({
   mute_while_recording: z.boolean().optional().default(false),
   input_tracking_enabled: z.boolean().optional().default(false),
   input_tracking_excluded_apps: z.array(z.string()).optional().default([]),
+  input_tracking_excluded_window_patterns: z
+    .array(z.string())
+    .optional()
+    .default([]),
   input_tracking_idle_timeout: z.number().nullable().optional().default(2),
+  input_tracking_capture_paste: z.boolean().optional().default(true),
   tts_enabled: z.boolean().optional().default(false),
   meeting_system_audio_enabled: z.boolean().optional().default(false),
   meeting_system_audio_device: z.string().nullable().optional(),
@@ -136,6 +247,96 @@ export const SettingsSchema = z.object({
   meeting_chunk_duration_secs: z.number().optional().default(30),
   meeting_diarization_enabled: z.boolean().optional().default(false),
   meeting_diarization_threshold: z.number().optional().default(0.5),
+  auto_stop_silence_secs: z.number().nullable().optional(),
+  min_recording_duration_ms: z.number().optional().default(300),
+  recording_countdown_enabled: z.boolean().optional().default(false),
+  ptt_release_debounce_ms: z.number().optional().default(0),
+  context_prompt_enabled: z.boolean().optional().default(false),
+  respect_focus_mode: z.boolean().optional().default(false),
+  battery_saver_enabled: z.boolean().optional().default(false),
+  battery_model: z.string().nullable().optional(),
+  daily_digest_enabled: z.boolean().optional().default(false),
+  daily_digest_folder: z.string().nullable().optional(),
+  daily_digest_llm_summary: z.boolean().optional().default(false),
+  thread_priorities_enabled: z.boolean().optional().default(true),
+  data_api_enabled: z.boolean().optional().default(false),
+  data_api_port: z.number().optional().default(47_813),
+  data_api_token: z.string().optional().default(""),
+  data_api_redacted_fields: z.array(DataApiFieldSchema).optional().default([]),
+  feature_flags: z.record(z.string(), z.boolean()).optional().default({}),
+  interview_mode_enabled: z.boolean().optional().default(false),
+  voice_prompt_switching_enabled: z.boolean().optional().default(false),
+  dictation_templates: z.array(DictationTemplateSchema).optional().default([]),
+  low_confidence_review_enabled: z.boolean().optional().default(false),
+  low_confidence_threshold: z.number().optional().default(0.5),
+  vad_trim_enabled: z.boolean().optional().default(true),
+  feedback_sound_devices: z.record(z.string(), z.string()).optional().default({}),
+  visual_feedback: z.boolean().optional().default(false),
+  haptic_feedback: z.boolean().optional().default(false),
+  file_diarization_enabled: z.boolean().optional().default(false),
+  mute_warning_enabled: z.boolean().optional().default(true),
+  cloud_transcription_enabled: z.boolean().optional().default(false),
+  transcription_provider_id: z.string().optional().default("openai"),
+  transcription_providers: z
+    .array(TranscriptionProviderSchema)
+    .optional()
+    .default([]),
+  transcription_api_keys: z
+    .record(z.string(), z.string())
+    .optional()
+    .default({}),
+  transcription_models: z.record(z.string(), z.string()).optional().default({}),
+  voice_filter_enabled: z.boolean().optional().default(false),
+  active_noise_profile: z.string().nullable().optional(),
+  inference_backend: InferenceBackendSchema.optional().default("auto"),
+  inference_threads: z.number().nullable().optional(),
+  initial_prompt: z.string().optional().default(""),
+  initial_prompt_custom_words: z.boolean().optional().default(false),
+  initial_prompt_active_app: z.boolean().optional().default(false),
+  locale_formatting: z.array(z.string()).optional().default([]),
+  preview_model_id: z.string().nullable().optional(),
+  dictation_commands_enabled: z.boolean().optional().default(false),
+  dictation_commands: z
+    .record(z.string(), z.array(DictationCommandSchema))
+    .optional()
+    .default({}),
+  voice_command_rules_enabled: z.boolean().optional().default(false),
+  voice_command_rules: z.array(VoiceCommandRuleSchema).optional().default([]),
+  overlay_shortcut_hint: z.boolean().optional().default(true),
+  text_normalization: z.array(z.string()).optional().default([]),
+  verify_paste_target: z.boolean().optional().default(true),
+  app_custom_words: z
+    .record(z.string(), z.array(z.string()))
+    .optional()
+    .default({}),
+  source_retention: z
+    .record(z.string(), RecordingRetentionPeriodSchema)
+    .optional()
+    .default({}),
+  model_warm_hours: WarmHoursSchema.nullable().optional(),
+  profanity_filter: ProfanityFilterSchema.optional().default("keep"),
+  profanity_words: z.array(z.string()).optional().default([]),
+  punctuation_restoration: z.boolean().optional().default(true),
+  streaming_target_latency_ms: z.number().optional().default(800),
+  learn_from_corrections: z.boolean().optional().default(true),
+  tts_pronunciations: z.record(z.string(), z.string()).optional().default({}),
+  accessibility_announcements: z.boolean().optional().default(true),
+  noise_suppression_enabled: z.boolean().optional().default(false),
+  input_gain_db: z.number().optional().default(0),
+  auto_gain_enabled: z.boolean().optional().default(false),
+  pre_roll_enabled: z.boolean().optional().default(true),
+  privacy_mode: z.boolean().optional().default(false),
+  max_recording_minutes: z.number().nullable().optional().default(10),
+  text_plugins: z.array(z.string()).optional().default([]),
+  no_paste_apps: z.array(z.string()).optional().default([]),
+  no_paste_window_patterns: z.array(z.string()).optional().default([]),
+  recording_format: RecordingFormatSchema.optional().default("wav"),
+  always_on_idle_release_minutes: z.number().nullable().optional().default(null),
+  import_watch_folder: z.string().nullable().optional().default(null),
+  tag_retention: z
+    .record(z.string(), RecordingRetentionPeriodSchema)
+    .optional()
+    .default({}),
 });
 
 export const BindingResponseSchema = z.object({
@@ -171,6 +372,7 @@ export const FileTranscriptionProgressSchema = z.object({
   status: z.string(),
   progress: z.number(),
   message: z.string(),
+  jobId: z.number().optional(),
 });
 
 export type FileTranscriptionProgress = z.infer<
@@ -184,6 +386,7 @@ export const MeetingStatusSchema = z.enum([
   "processing",
   "complete",
   "error",
+  "cancelled",
 ]);
 export type MeetingStatus = z.infer<typeof MeetingStatusSchema>;
 
@@ -202,6 +405,22 @@ export const MeetingSegmentSchema = z.object({
 });
 export type MeetingSegment = z.infer<typeof MeetingSegmentSchema>;
 
+export const SpeakerSegmentSchema = z.object({
+  speaker_label: z.string(),
+  start_ms: z.number(),
+  end_ms: z.number(),
+  text: z.string(),
+});
+export type SpeakerSegment = z.infer<typeof SpeakerSegmentSchema>;
+
+export const TimedSegmentSchema = z.object({
+  start_ms: z.number(),
+  end_ms: z.number(),
+  text: z.string(),
+  confidence: z.number().nullable().optional(),
+});
+export type TimedSegment = z.infer<typeof TimedSegmentSchema>;
+
 export const MeetingSchema = z.object({
   id: z.number(),
   title: z.string(),
@@ -214,3 +433,80 @@ export const MeetingSchema = z.object({
   status: MeetingStatusSchema,
 });
 export type Meeting = z.infer<typeof MeetingSchema>;
+
+export const FeatureFlagIdSchema = z.enum(["ai_tools", "live_captions"]);
+export type FeatureFlagId = z.infer<typeof FeatureFlagIdSchema>;
+
+export const FeatureFlagStateSchema = z.object({
+  id: FeatureFlagIdSchema,
+  name: z.string(),
+  description: z.string(),
+  enabled: z.boolean(),
+  default_enabled: z.boolean(),
+  overridden: z.boolean(),
+});
+export type FeatureFlagState = z.infer<typeof FeatureFlagStateSchema>;
+
+export const ChangelogSectionSchema = z.object({
+  title: z.string(),
+  items: z.array(z.string()),
+});
+export type ChangelogSection = z.infer<typeof ChangelogSectionSchema>;
+
+export const ChangelogReleaseSchema = z.object({
+  version: z.string(),
+  date: z.string().nullable(),
+  sections: z.array(ChangelogSectionSchema),
+});
+export type ChangelogRelease = z.infer<typeof ChangelogReleaseSchema>;
+
+export const AudioSourceSchema = z.enum(["mic", "system"]);
+export type AudioSource = z.infer<typeof AudioSourceSchema>;
+
+export const ScheduleStatusSchema = z.enum([
+  "pending",
+  "recording",
+  "complete",
+  "missed",
+  "cancelled",
+  "failed",
+]);
+export type ScheduleStatus = z.infer<typeof ScheduleStatusSchema>;
+
+export const ScheduledRecordingSchema = z.object({
+  id: z.number(),
+  title: z.string(),
+  start_time: z.number(),
+  duration_secs: z.number(),
+  source: AudioSourceSchema,
+  status: ScheduleStatusSchema,
+  meeting_id: z.number().nullable(),
+  error: z.string().nullable(),
+  created_at: z.number(),
+});
+export type ScheduledRecording = z.infer<typeof ScheduledRecordingSchema>;
+
+export const RuleActionsSchema = z.object({
+  input_tracking: z.boolean().nullable(),
+  privacy_mode: z.boolean().nullable(),
+  profile: z.string().nullable(),
+});
+export type RuleActions = z.infer<typeof RuleActionsSchema>;
+
+export const AutomationRuleSchema = z.object({
+  id: z.number(),
+  name: z.string(),
+  enabled: z.boolean(),
+  days: z.array(z.number()),
+  start_minute: z.number(),
+  end_minute: z.number(),
+  actions: RuleActionsSchema,
+  otherwise: RuleActionsSchema,
+});
+export type AutomationRule = z.infer<typeof AutomationRuleSchema>;
+
+export const InterviewQuestionSchema = z.object({
+  text: z.string(),
+  timestamp: z.number(),
+});
+export type InterviewQuestion = z.infer<typeof InterviewQuestionSchema>;
@@ -3,19 +3,133 @@ import { listen, type UnlistenFn } from "@tauri-apps/api/event";
 import { useEffect, useRef, useState } from "react";
 import "./recording-overlay.css";
 import EchoLogo from "@/components/icons/echo-logo";
+import { getNormalizedOsPlatform } from "@/lib/os";
+import type { TemplateProgress } from "@/lib/types";
 import { cn } from "@/lib/utils";
 
-type OverlayState = "recording" | "transcribing" | "warning" | "tool";
+type OverlayState =
+  | "recording"
+  | "transcribing"
+  | "warning"
+  | "tool"
+  | "countdown"
+  | "confidence";
 
 interface WarningPayload {
   message: string;
-  state: "warning" | "tool";
+  state: "warning" | "tool" | "countdown" | "confidence";
+  confidence?: number;
+  larger_model?: string | null;
+  low_confidence_words?: string[];
 }
 
+// The shortcut that stops the recording, for the hint under the notch
+interface RecordingPayload {
+  state: "recording";
+  shortcut: string | null;
+  mode: "hold" | "toggle";
+  show_hint: boolean;
+}
+
+type ShowOverlayPayload = OverlayState | WarningPayload | RecordingPayload;
+
+// Streaming text: frozen words followed by the part that may still change
+interface StreamingProgress {
+  committed: string;
+  tail: string;
+}
+
+type ReviewChoice = "paste_anyway" | "re_record" | "larger_model";
+
+interface ReviewState {
+  confidence: number;
+  largerModel: string | null;
+  lowConfidenceWords: string[];
+}
+
+const MAC_KEY_SYMBOLS: Record<string, string> = {
+  alt: "⌥",
+  cmd: "⌘",
+  command: "⌘",
+  control: "⌃",
+  ctrl: "⌃",
+  option: "⌥",
+  shift: "⇧",
+  super: "⌘",
+};
+
+const capitalize = (key: string) =>
+  key.charAt(0).toUpperCase() + key.slice(1);
+
+// "option+space" reads "⌥Space" on macOS and "Option+Space" elsewhere
+const formatShortcut = (shortcut: string) => {
+  const keys = shortcut
+    .split("+")
+    .map((key) => key.trim())
+    .filter(Boolean);
+  if (getNormalizedOsPlatform() === "mac") {
+    return keys
+      .map((key) => MAC_KEY_SYMBOLS[key.toLowerCase()] ?? capitalize(key))
+      .join("");
+  }
+  return keys.map(capitalize).join("+");
+};
+
+const shortcutHint = ({ shortcut, mode, show_hint }: RecordingPayload) => {
+  if (!(show_hint && shortcut)) {
+    return "";
+  }
+  const keys = formatShortcut(shortcut);
+  return mode === "hold" ? `Release ${keys} to stop` : `Press ${keys} to stop`;
+};
+
+const normalizeWord = (word: string) =>
+  word.toLowerCase().replace(/[^\p{L}\p{N}']/gu, "");
+
+// Held text with the words that were probably misheard underlined
+const HighlightedText = ({
+  text,
+  words,
+}: {
+  text: string;
+  words: string[];
+}) => {
+  // Whisper flags whole segments, so split them into words
+  const flagged = new Set(
+    words
+      .flatMap((segment) => segment.split(/\s+/))
+      .map(normalizeWord)
+      .filter(Boolean)
+  );
+  let offset = 0;
+  const parts = text.split(/(\s+)/).map((part) => {
+    const start = offset;
+    offset += part.length;
+    return { part, start };
+  });
+  return (
+    <>
+      {parts.map(({ part, start }) =>
+        flagged.has(normalizeWord(part)) ? (
+          <span
+            className="underline decoration-amber-400 decoration-wavy"
+            key={start}
+          >
+            {part}
+          </span>
+        ) : (
+          part
+        )
+      )}
+    </>
+  );
+};
+
 const NOTCH_HEIGHT = 42;
 const NOTCH_WIDTH = 310;
 const EXPANDED_HEIGHT = 76;
 const TOP_OVERFLOW = 100;
+const REVIEW_ACTIONS_HEIGHT = 36;
 const BAR_DELAYS = [0, 150, 300, 450];
 
 /** Find peak amplitude within a slice of the levels array */
@@ -55,18 +169,46 @@ export const updateBars = (
   }
 };
 
+const EMPTY_STREAMING: StreamingProgress = { committed: "", tail: "" };
+
 const RecordingOverlay = () => {
   const [isVisible, setIsVisible] = useState(false);
   const [state, setState] = useState<OverlayState>("recording");
   const [position, setPosition] = useState<"top" | "bottom">("top");
   const [warningMessage, setWarningMessage] = useState("");
-  const [streamingText, setStreamingText] = useState("");
+  const [hint, setHint] = useState("");
+  const [streaming, setStreaming] =
+    useState<StreamingProgress>(EMPTY_STREAMING);
+  const [review, setReview] = useState<ReviewState | null>(null);
+  const [isFlashing, setIsFlashing] = useState(false);
+  const flashTimeoutRef = useRef<ReturnType<typeof setTimeout> | null>(null);
+  const [templateProgress, setTemplateProgress] =
+    useState<TemplateProgress | null>(null);
   const textScrollRef = useRef<HTMLDivElement>(null);
   const barsRef = useRef<HTMLDivElement>(null);
   const hasBeenShown = useRef(false);
 
+  const hasMessage =
+    state === "warning" ||
+    state === "tool" ||
+    state === "countdown" ||
+    state === "confidence";
+  const isReviewing = state === "confidence" && review !== null;
+  const reviewHeight = isReviewing ? REVIEW_ACTIONS_HEIGHT : 0;
+  // While a dictation template is being filled, prompt for the current slot
+  const slotPrompt =
+    templateProgress && state === "recording"
+      ? `${templateProgress.name}: ${templateProgress.slot} (${templateProgress.index + 1}/${templateProgress.total})`
+      : "";
+  const streamingText = [streaming.committed, streaming.tail]
+    .filter(Boolean)
+    .join(" ");
+  const recordingHint = state === "recording" ? hint : "";
   const hasText =
-    Boolean(streamingText) || state === "warning" || state === "tool";
+    Boolean(streamingText) ||
+    hasMessage ||
+    Boolean(slotPrompt) ||
+    Boolean(recordingHint);
 
   // Store unlisten fns in a ref so the synchronous cleanup can call them
   const unlistenRef = useRef<UnlistenFn[]>([]);
@@ -75,7 +217,7 @@ const RecordingOverlay = () => {
     let cancelled = false;
 
     const setup = async () => {
-      const unlistenShow = await listen<OverlayState | WarningPayload>(
+      const unlistenShow = await listen<ShowOverlayPayload>(
         "show-overlay",
         (event) => {
           if (typeof event.payload === "string") {
@@ -83,16 +225,32 @@ const RecordingOverlay = () => {
             setState(newState);
             setWarningMessage("");
             if (newState === "recording") {
-              setStreamingText("");
+              setStreaming(EMPTY_STREAMING);
             }
+          } else if (event.payload.state === "recording") {
+            setState("recording");
+            setWarningMessage("");
+            setStreaming(EMPTY_STREAMING);
+            setHint(shortcutHint(event.payload));
           } else if (
             typeof event.payload === "object" &&
             event.payload !== null &&
             (event.payload.state === "warning" ||
-              event.payload.state === "tool")
+              event.payload.state === "tool" ||
+              event.payload.state === "countdown" ||
+              event.payload.state === "confidence")
           ) {
             setState(event.payload.state);
             setWarningMessage(event.payload.message || "Please wait...");
+            setReview(
+              event.payload.state === "confidence"
+                ? {
+                    confidence: event.payload.confidence ?? 0,
+                    largerModel: event.payload.larger_model ?? null,
+                    lowConfidenceWords: event.payload.low_confidence_words ?? [],
+                  }
+                : null
+            );
           }
           hasBeenShown.current = true;
           setIsVisible(true);
@@ -111,10 +269,10 @@ const RecordingOverlay = () => {
         }
       });
 
-      const unlistenProgress = await listen<string>(
+      const unlistenProgress = await listen<StreamingProgress>(
         "transcription-progress",
         (event) => {
-          setStreamingText(event.payload);
+          setStreaming(event.payload);
         }
       );
 
@@ -127,6 +285,30 @@ const RecordingOverlay = () => {
         }
       );
 
+      // Replaces the shortcut hint, e.g. when the recording limit is near
+      const unlistenHint = await listen<string>("recording-hint", (event) => {
+        setHint(event.payload);
+      });
+
+      // Silent start/stop feedback: a brief flash of the notch
+      const unlistenFlash = await listen("overlay-flash", () => {
+        if (flashTimeoutRef.current) {
+          clearTimeout(flashTimeoutRef.current);
+        }
+        setIsFlashing(true);
+        flashTimeoutRef.current = setTimeout(() => setIsFlashing(false), 300);
+      });
+
+      const unlistenTemplate = await listen<TemplateProgress | null>(
+        "dictation-template-changed",
+        (event) => {
+          setTemplateProgress(event.payload);
+        }
+      );
+      invoke<TemplateProgress | null>("get_dictation_template_progress")
+        .then(setTemplateProgress)
+        .catch(() => {});
+
       // If cleanup already ran while we were awaiting, detach immediately
       const fns = [
         unlistenShow,
@@ -134,6 +316,9 @@ const RecordingOverlay = () => {
         unlistenMic,
         unlistenProgress,
         unlistenPosition,
+        unlistenTemplate,
+        unlistenFlash,
+        unlistenHint,
       ];
       if (cancelled) {
         for (const fn of fns) {
@@ -223,6 +408,13 @@ const RecordingOverlay = () => {
 
   const isProcessing = state === "transcribing" && isVisible;
 
+  const resolveReview = (choice: ReviewChoice) => {
+    invoke("resolve_low_confidence", { choice }).catch((error) => {
+      console.error("Failed to resolve low-confidence dictation:", error);
+      invoke("cancel_operation");
+    });
+  };
+
   const isBottom = position === "bottom";
 
   return (
@@ -240,13 +432,14 @@ const RecordingOverlay = () => {
           !isVisible && hasBeenShown.current && "notch-hide",
           !(isVisible || hasBeenShown.current) &&
             "scale-x-60 scale-y-80 opacity-0 blur-lg",
-          isProcessing && "notch-breathing"
+          isProcessing && "notch-breathing",
+          isFlashing && "notch-flash"
         )}
         style={{
           width: `${NOTCH_WIDTH}px`,
           maxHeight:
             hasText && isVisible
-              ? `${EXPANDED_HEIGHT + TOP_OVERFLOW}px`
+              ? `${EXPANDED_HEIGHT + reviewHeight + TOP_OVERFLOW}px`
               : `${NOTCH_HEIGHT + TOP_OVERFLOW}px`,
           ...(isBottom
             ? {
@@ -298,11 +491,61 @@ const RecordingOverlay = () => {
               "-webkit-linear-gradient(left, transparent, black 12px, black calc(100% - 12px), transparent)",
           }}
         >
-          {state === "warning" || state === "tool"
-            ? warningMessage
-            : streamingText}
+          {hasMessage &&
+            (isReviewing && review ? (
+              <HighlightedText
+                text={warningMessage}
+                words={review.lowConfidenceWords}
+              />
+            ) : (
+              warningMessage
+            ))}
+          {!hasMessage && streamingText && (
+            <>
+              {streaming.committed}
+              {streaming.committed && streaming.tail && " "}
+              <span className="text-white/50">{streaming.tail}</span>
+            </>
+          )}
+          {!(hasMessage || streamingText) && slotPrompt}
+          {!(hasMessage || streamingText || slotPrompt) && recordingHint && (
+            <span className="text-white/50">{recordingHint}</span>
+          )}
         </div>
 
+        {/* Low-confidence review: choices for the held dictation */}
+        {isReviewing && review && (
+          <div className="flex shrink-0 items-center gap-1.5 px-5 pb-3 text-[11px]">
+            <span className="mr-auto text-white/50">
+              {Math.round(review.confidence * 100)}% sure
+            </span>
+            <button
+              className="rounded-full bg-white/10 px-2.5 py-1 hover:bg-white/20"
+              onClick={() => resolveReview("paste_anyway")}
+              type="button"
+            >
+              Paste
+            </button>
+            <button
+              className="rounded-full bg-white/10 px-2.5 py-1 hover:bg-white/20"
+              onClick={() => resolveReview("re_record")}
+              type="button"
+            >
+              Re-record
+            </button>
+            {review.largerModel && (
+              <button
+                className="max-w-[110px] truncate rounded-full bg-white/10 px-2.5 py-1 hover:bg-white/20"
+                onClick={() => resolveReview("larger_model")}
+                title={`Re-run with ${review.largerModel}`}
+                type="button"
+              >
+                {review.largerModel}
+              </button>
+            )}
+          </div>
+        )}
+
         {/* Progress sweep line during transcription */}
         {isProcessing && <div className="notch-progress-line" />}
       </div>
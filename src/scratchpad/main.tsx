@@ -0,0 +1,18 @@
+import React from "react";
+import ReactDOM from "react-dom/client";
+import { ThemeProvider } from "@/providers/theme-provider";
+import Scratchpad from "./scratchpad";
+import "../app.css";
+
+const rootElement = document.getElementById("root");
+if (!rootElement) {
+  throw new Error("Missing root element");
+}
+
+ReactDOM.createRoot(rootElement).render(
+  <React.StrictMode>
+    <ThemeProvider defaultTheme="system" storageKey="echo-ui-theme">
+      <Scratchpad />
+    </ThemeProvider>
+  </React.StrictMode>
+);
@@ -0,0 +1,103 @@
+import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
+import { Copy, Trash2 } from "lucide-react";
+import { useCallback, useEffect, useRef, useState } from "react";
+import { Toaster, toast } from "sonner";
+import { Button } from "@/components/ui/button";
+import { Textarea } from "@/components/ui/textarea";
+
+/** Delay before local edits are written back to the backend buffer */
+const SAVE_DEBOUNCE_MS = 400;
+
+const Scratchpad = () => {
+  const [content, setContent] = useState("");
+  const saveTimer = useRef<ReturnType<typeof setTimeout> | null>(null);
+
+  useEffect(() => {
+    invoke<string>("get_scratchpad")
+      .then(setContent)
+      .catch(() => setContent(""));
+
+    const unlisten = listen<string>("scratchpad-updated", (event) => {
+      // Skip while an edit is pending so typing isn't overwritten
+      if (!saveTimer.current) {
+        setContent(event.payload);
+      }
+    });
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  const handleChange = useCallback((value: string) => {
+    setContent(value);
+    if (saveTimer.current) {
+      clearTimeout(saveTimer.current);
+    }
+    saveTimer.current = setTimeout(() => {
+      saveTimer.current = null;
+      invoke("scratchpad_replace", { text: value }).catch(() =>
+        toast.error("Failed to save scratchpad")
+      );
+    }, SAVE_DEBOUNCE_MS);
+  }, []);
+
+  const handleCopy = useCallback(async (clear: boolean) => {
+    try {
+      await invoke("copy_scratchpad", { clear });
+      toast.success(clear ? "Copied and cleared" : "Copied to clipboard");
+    } catch {
+      toast.error("Failed to copy scratchpad");
+    }
+  }, []);
+
+  const handleClear = useCallback(() => {
+    handleChange("");
+  }, [handleChange]);
+
+  return (
+    <div className="flex h-full w-full flex-col gap-2 bg-background p-3 text-foreground">
+      <Textarea
+        className="min-h-0 flex-1 resize-none text-base"
+        onChange={(e) => handleChange(e.target.value)}
+        placeholder="Dictations are collected here while this window is open."
+        value={content}
+      />
+      <div className="flex items-center justify-between gap-2">
+        <span className="text-muted-foreground text-xs">
+          {content.trim() ? content.trim().split(/\s+/).length : 0} words
+        </span>
+        <div className="flex gap-2">
+          <Button
+            disabled={!content}
+            onClick={handleClear}
+            size="sm"
+            variant="ghost"
+          >
+            <Trash2 className="mr-1.5 size-3.5" />
+            Clear
+          </Button>
+          <Button
+            disabled={!content}
+            onClick={() => handleCopy(true)}
+            size="sm"
+            variant="outline"
+          >
+            Copy &amp; Clear
+          </Button>
+          <Button
+            disabled={!content}
+            onClick={() => handleCopy(false)}
+            size="sm"
+          >
+            <Copy className="mr-1.5 size-3.5" />
+            Copy
+          </Button>
+        </div>
+      </div>
+      <Toaster position="bottom-center" />
+    </div>
+  );
+};
+
+export default Scratchpad;
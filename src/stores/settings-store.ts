@@ -8,8 +8,12 @@ import type { AudioDevice, Settings } from "@/lib/types";
 let cachedStorePromise: ReturnType<typeof LoadFn> | null = null;
 const getSettingsStore = () => {
   if (!cachedStorePromise) {
-    cachedStorePromise = import("@tauri-apps/plugin-store").then(({ load }) =>
-      load("settings_store.json", {
+    // Each data profile keeps its settings in its own store file
+    cachedStorePromise = Promise.all([
+      import("@tauri-apps/plugin-store"),
+      invoke<string>("get_settings_store_path"),
+    ]).then(([{ load }, path]) =>
+      load(path, {
         defaults: DEFAULT_SETTINGS,
         autoSave: false,
       })
@@ -110,8 +114,77 @@ const settingUpdaters: {
     invoke("change_debug_logging_setting", { enabled: value }),
   log_level: (value) => invoke("set_log_level", { level: value }),
   custom_words: (value) => invoke("update_custom_words", { words: value }),
+  initial_prompt: (value) =>
+    invoke("change_initial_prompt_setting", { prompt: value }),
+  initial_prompt_custom_words: (value) =>
+    invoke("change_initial_prompt_custom_words_setting", { enabled: value }),
+  initial_prompt_active_app: (value) =>
+    invoke("change_initial_prompt_active_app_setting", { enabled: value }),
+  context_prompt_enabled: (value) =>
+    invoke("change_context_prompt_setting", { enabled: value }),
+  dictation_commands_enabled: (value) =>
+    invoke("change_dictation_commands_enabled_setting", { enabled: value }),
+  voice_command_rules_enabled: (value) =>
+    invoke("change_voice_command_rules_enabled_setting", { enabled: value }),
+  verify_paste_target: (value) =>
+    invoke("change_verify_paste_target_setting", { enabled: value }),
+  no_paste_apps: (value) =>
+    invoke("change_no_paste_apps_setting", { apps: value }),
+  no_paste_window_patterns: (value) =>
+    invoke("change_no_paste_windows_setting", { patterns: value }),
+  profanity_filter: (value) =>
+    invoke("change_profanity_filter_setting", { mode: value }),
+  profanity_words: (value) => invoke("set_profanity_words", { words: value }),
+  punctuation_restoration: (value) =>
+    invoke("change_punctuation_restoration_setting", { enabled: value }),
+  streaming_target_latency_ms: (value) =>
+    invoke("change_streaming_target_latency_setting", { latencyMs: value }),
+  learn_from_corrections: (value) =>
+    invoke("change_learn_from_corrections_setting", { enabled: value }),
+  tts_pronunciations: (value) =>
+    invoke("set_tts_pronunciations", { pronunciations: value }),
+  overlay_shortcut_hint: (value) =>
+    invoke("change_overlay_shortcut_hint_setting", { enabled: value }),
   word_correction_threshold: (value) =>
     invoke("change_word_correction_threshold_setting", { threshold: value }),
+  low_confidence_review_enabled: (value) =>
+    invoke("change_low_confidence_review_setting", { enabled: value }),
+  low_confidence_threshold: (value) =>
+    invoke("change_low_confidence_threshold_setting", { threshold: value }),
+  vad_trim_enabled: (value) =>
+    invoke("change_vad_trim_setting", { enabled: value }),
+  visual_feedback: (value) =>
+    invoke("change_visual_feedback_setting", { enabled: value }),
+  haptic_feedback: (value) =>
+    invoke("change_haptic_feedback_setting", { enabled: value }),
+  accessibility_announcements: (value) =>
+    invoke("change_accessibility_announcements_setting", { enabled: value }),
+  noise_suppression_enabled: (value) =>
+    invoke("change_noise_suppression_setting", { enabled: value }),
+  input_gain_db: (value) =>
+    invoke("change_input_gain_setting", { gainDb: value }),
+  auto_gain_enabled: (value) =>
+    invoke("change_auto_gain_setting", { enabled: value }),
+  pre_roll_enabled: (value) =>
+    invoke("change_pre_roll_setting", { enabled: value }),
+  privacy_mode: (value) =>
+    invoke("change_privacy_mode_setting", { enabled: value }),
+  auto_stop_silence_secs: (value) =>
+    invoke("change_auto_stop_silence_setting", { seconds: value }),
+  max_recording_minutes: (value) =>
+    invoke("change_max_recording_setting", { minutes: value }),
+  always_on_idle_release_minutes: (value) =>
+    invoke("change_always_on_idle_release_setting", { minutes: value }),
+  mute_warning_enabled: (value) =>
+    invoke("change_mute_warning_setting", { enabled: value }),
+  voice_filter_enabled: (value) =>
+    invoke("change_voice_filter_setting", { enabled: value }),
+  active_noise_profile: (value) =>
+    invoke("change_active_noise_profile_setting", { name: value }),
+  cloud_transcription_enabled: (value) =>
+    invoke("change_cloud_transcription_setting", { enabled: value }),
+  transcription_provider_id: (value) =>
+    invoke("set_transcription_provider", { providerId: value }),
   paste_method: (value) =>
     invoke("change_paste_method_setting", { method: value }),
   clipboard_handling: (value) =>
@@ -119,6 +192,10 @@ const settingUpdaters: {
   history_limit: (value) => invoke("update_history_limit", { limit: value }),
   recording_retention_period: (value) =>
     invoke("update_recording_retention_period", { period: value }),
+  recording_format: (value) =>
+    invoke("change_recording_format_setting", { format: value }),
+  import_watch_folder: (value) =>
+    invoke("change_import_watch_folder_setting", { folder: value }),
   post_process_selected_prompt_id: (value) =>
     invoke("set_post_process_selected_prompt", { id: value }),
   mute_while_recording: (value) =>
@@ -141,10 +218,22 @@ const settingUpdaters: {
     }),
   meeting_diarization_enabled: (value) =>
     invoke("change_meeting_diarization_setting", { enabled: value }),
+  file_diarization_enabled: (value) =>
+    invoke("change_file_diarization_setting", { enabled: value }),
+  interview_mode_enabled: (value) =>
+    invoke("change_interview_mode_setting", { enabled: value }),
   post_process_enabled: (value) =>
     invoke("change_post_process_enabled_setting", { enabled: value }),
   voice_commands_enabled: (value) =>
     invoke("change_voice_commands_enabled_setting", { enabled: value }),
+  voice_prompt_switching_enabled: (value) =>
+    invoke("change_voice_prompt_switching_setting", { enabled: value }),
+  data_api_enabled: (value) =>
+    invoke("change_data_api_setting", { enabled: value }),
+  data_api_port: (value) =>
+    invoke("change_data_api_port_setting", { port: value }),
+  data_api_redacted_fields: (value) =>
+    invoke("change_data_api_redacted_fields_setting", { fields: value }),
 };
 
 interface SettingsStore {
@@ -160,6 +249,8 @@ interface SettingsStore {
   initialize: () => Promise<void>;
   isLoading: boolean;
   isUpdating: Record<string, boolean>;
+  /** Settings locked by an administrator's policy file. */
+  lockedSettings: string[];
   modelToolSupport: Record<string, boolean | null>;
   outputDevices: AudioDevice[];
   playTestSound: (soundType: "start" | "stop") => Promise<void>;
@@ -191,6 +282,7 @@ export const useSettingsStore = create<SettingsStore>((set, get) => ({
   settings: null,
   isLoading: true,
   isUpdating: {},
+  lockedSettings: [],
   audioDevices: [],
   outputDevices: [],
   customSounds: { start: false, stop: false },
@@ -310,12 +402,21 @@ export const useSettingsStore = create<SettingsStore>((set, get) => ({
       refreshAudioDevices(),
       refreshOutputDevices(),
       checkCustomSounds(),
+      invoke<string[]>("get_locked_settings")
+        .then((lockedSettings) => set({ lockedSettings }))
+        .catch((error) =>
+          console.error("Failed to load locked settings:", error)
+        ),
     ]);
   },
 
   updateSetting: async (key, value) => {
-    const { settings } = get();
+    const { settings, lockedSettings } = get();
     const updateKey = String(key);
+    if (lockedSettings.includes(updateKey)) {
+      console.warn(`Setting ${updateKey} is locked by policy`);
+      return;
+    }
     const originalValue = settings?.[key];
 
     // Batch isUpdating + optimistic settings update into a single set()
@@ -567,9 +668,14 @@ export function useSetting<K extends keyof Settings>(
   return useSettingsStore((s) => s.settings?.[key]);
 }
 
-/** Subscribe to isUpdating for a specific key. */
+/**
+ * Subscribe to isUpdating for a specific key. Settings locked by policy
+ * always count as updating, so their controls stay disabled.
+ */
 export function useIsSettingUpdating(key: string): boolean {
-  return useSettingsStore((s) => Boolean(s.isUpdating[key]));
+  return useSettingsStore(
+    (s) => Boolean(s.isUpdating[key]) || s.lockedSettings.includes(key)
+  );
 }
 
 /** Stable action references — never triggers re-renders after mount. */
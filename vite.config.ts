@@ -27,6 +27,8 @@ export default defineConfig(async () => ({
       input: {
         main: resolve(import.meta.dirname, "index.html"),
         overlay: resolve(import.meta.dirname, "src/overlay/index.html"),
+        captions: resolve(import.meta.dirname, "src/captions/index.html"),
+        scratchpad: resolve(import.meta.dirname, "src/scratchpad/index.html"),
         "startup-loading-screen": resolve(
           import.meta.dirname,
           "startup-loading-screen.html"